@@ -0,0 +1,95 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use markdown_use_case_manager::config::{Config, StorageBackend};
+use markdown_use_case_manager::core::{
+    synthetic_use_cases, MarkdownGenerator, OutputManager, OverviewGenerator, RepositoryFactory,
+    UseCase,
+};
+use tempfile::TempDir;
+
+/// Size of the synthetic project used as the performance budget reference
+const PROJECT_SIZE: usize = 1000;
+
+/// Config with all output directories inside the temp dir
+fn setup_config(backend: StorageBackend, temp_dir: &TempDir) -> Config {
+    let mut config = Config::default();
+    config.storage.backend = backend;
+    config.directories.use_case_dir = temp_dir
+        .path()
+        .join("use-cases")
+        .to_string_lossy()
+        .to_string();
+    config.directories.data_dir = temp_dir.path().join("data").to_string_lossy().to_string();
+    std::fs::create_dir_all(&config.directories.use_case_dir)
+        .expect("Failed to create use case dir");
+    config
+}
+
+fn create_project(config: &Config) -> Vec<UseCase> {
+    synthetic_use_cases(
+        PROJECT_SIZE,
+        &config.project.id_prefix,
+        &config.templates.default_methodology,
+    )
+}
+
+/// Benchmark load_all on a pre-populated 1k use case project
+fn bench_load_all(c: &mut Criterion, backend: StorageBackend) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let config = setup_config(backend, &temp_dir);
+    let repository = RepositoryFactory::create(&config).expect("Failed to create repository");
+    for use_case in &create_project(&config) {
+        repository.save(use_case).expect("Pre-save failed");
+    }
+
+    c.bench_function(&format!("load_all_{}_{}", backend, PROJECT_SIZE), |b| {
+        b.iter(|| std::hint::black_box(repository.load_all()).expect("Load all failed"))
+    });
+}
+
+/// Benchmark rendering every view of a 1k use case project
+fn bench_render(c: &mut Criterion) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let config = setup_config(StorageBackend::Toml, &temp_dir);
+    let use_cases = create_project(&config);
+    let generator = MarkdownGenerator::new(config);
+
+    c.bench_function(&format!("render_{}", PROJECT_SIZE), |b| {
+        b.iter(|| {
+            for use_case in &use_cases {
+                for (_, view) in OutputManager::generate_all_filenames(use_case) {
+                    std::hint::black_box(generator.generate(use_case, None, Some(&view)))
+                        .expect("Render failed");
+                }
+            }
+        })
+    });
+}
+
+/// Benchmark overview generation for a 1k use case project
+fn bench_overview(c: &mut Criterion) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let config = setup_config(StorageBackend::Toml, &temp_dir);
+    let use_cases = create_project(&config);
+    let generator = OverviewGenerator::new(config);
+
+    c.bench_function(&format!("overview_{}", PROJECT_SIZE), |b| {
+        b.iter(|| generator.generate(&use_cases).expect("Overview failed"))
+    });
+}
+
+fn bench_repositories(c: &mut Criterion) {
+    bench_load_all(c, StorageBackend::Toml);
+    bench_load_all(c, StorageBackend::Sqlite);
+}
+
+fn bench_generation(c: &mut Criterion) {
+    bench_render(c);
+    bench_overview(c);
+}
+
+criterion_group!(
+    name = generation_benchmarks;
+    config = Criterion::default().sample_size(10);
+    targets = bench_repositories, bench_generation
+);
+criterion_main!(generation_benchmarks);
@@ -62,10 +62,7 @@ fn bench_save(c: &mut Criterion, backend: StorageBackend, use_case_count: usize)
     let (_config, repository) = setup_backend(backend, &temp_dir);
     let use_cases = create_test_use_cases(use_case_count);
 
-    let backend_name = match backend {
-        StorageBackend::Toml => "toml",
-        StorageBackend::Sqlite => "sqlite",
-    };
+    let backend_name = backend.to_string();
 
     c.bench_function(
         &format!("save_{}_{}_use_cases", backend_name, use_case_count),
@@ -90,10 +87,7 @@ fn bench_load_all(c: &mut Criterion, backend: StorageBackend, use_case_count: us
         repository.save(use_case).expect("Pre-save failed");
     }
 
-    let backend_name = match backend {
-        StorageBackend::Toml => "toml",
-        StorageBackend::Sqlite => "sqlite",
-    };
+    let backend_name = backend.to_string();
 
     c.bench_function(
         &format!("load_all_{}_{}_use_cases", backend_name, use_case_count),
@@ -116,10 +110,7 @@ fn bench_load_by_id(c: &mut Criterion, backend: StorageBackend, use_case_count:
         repository.save(use_case).expect("Pre-save failed");
     }
 
-    let backend_name = match backend {
-        StorageBackend::Toml => "toml",
-        StorageBackend::Sqlite => "sqlite",
-    };
+    let backend_name = backend.to_string();
 
     c.bench_function(
         &format!("load_by_id_{}_{}_use_cases", backend_name, use_case_count),
@@ -145,10 +136,7 @@ fn bench_find_by_category(c: &mut Criterion, backend: StorageBackend, use_case_c
         repository.save(use_case).expect("Pre-save failed");
     }
 
-    let backend_name = match backend {
-        StorageBackend::Toml => "toml",
-        StorageBackend::Sqlite => "sqlite",
-    };
+    let backend_name = backend.to_string();
 
     c.bench_function(
         &format!(
@@ -252,16 +240,19 @@ mod tests {
 fn bench_small_datasets(c: &mut Criterion) {
     bench_backend(c, StorageBackend::Toml, 10);
     bench_backend(c, StorageBackend::Sqlite, 10);
+    bench_backend(c, StorageBackend::Events, 10);
 }
 
 fn bench_medium_datasets(c: &mut Criterion) {
     bench_backend(c, StorageBackend::Toml, 100);
     bench_backend(c, StorageBackend::Sqlite, 100);
+    bench_backend(c, StorageBackend::Events, 100);
 }
 
 fn bench_large_datasets(c: &mut Criterion) {
     bench_backend(c, StorageBackend::Toml, 500);
     bench_backend(c, StorageBackend::Sqlite, 500);
+    bench_backend(c, StorageBackend::Events, 500);
 }
 
 criterion_group!(
@@ -0,0 +1,147 @@
+//! Command aliases: `[alias]` in `mucm.toml` and the built-in shortcuts.
+//!
+//! The first argument that is not a flag names the command. When it is not a
+//! mucm command but an alias, it is replaced by the words of the alias and the
+//! remaining arguments follow, so `mucm n "Log in" -c Auth` with
+//! `n = "create --views feature:normal"` runs
+//! `mucm create --views feature:normal "Log in" -c Auth`. Aliases never shadow
+//! commands; an alias of the project replaces a built-in shortcut of the same
+//! name, and an alias may expand to another alias.
+
+use anyhow::{bail, Context, Result};
+use clap::CommandFactory;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+use super::args::Cli;
+use super::interactive::session_log::parse_command;
+
+/// Shortcuts available in every project
+///
+/// A shortcut only renames its command: `rm` takes the arguments of, and
+/// deletes exactly as, `use-case scenario delete`.
+const BUILT_IN_ALIASES: &[(&str, &str)] = &[
+    ("ls", "list"),
+    ("mk", "create"),
+    ("rm", "use-case scenario delete"),
+    ("persona", "actor"),
+];
+
+/// Replace the alias naming the command in `args` (program name first) by its expansion
+///
+/// # Errors
+/// Returns an error when an alias cannot be split into arguments or expands
+/// back to itself.
+pub fn expand_aliases(
+    mut args: Vec<OsString>,
+    aliases: &BTreeMap<String, String>,
+) -> Result<Vec<OsString>> {
+    let Some(position) = args
+        .iter()
+        .skip(1)
+        .position(|arg| !arg.to_string_lossy().starts_with('-'))
+        .map(|index| index + 1)
+    else {
+        return Ok(args);
+    };
+
+    let cli = Cli::command();
+    let mut expanded = Vec::new();
+    while let Some(name) = args[position].to_str().map(str::to_string) {
+        if name == "help" || cli.find_subcommand(&name).is_some() {
+            break;
+        }
+        let Some(expansion) = aliases.get(&name).map(String::as_str).or_else(|| {
+            BUILT_IN_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == name)
+                .map(|(_, expansion)| *expansion)
+        }) else {
+            break;
+        };
+        if expanded.contains(&name) {
+            bail!(
+                "Alias '{}' expands to itself ({} -> {})",
+                name,
+                expanded.join(" -> "),
+                name
+            );
+        }
+
+        let words = parse_command(&format!("mucm {}", expansion))
+            .with_context(|| format!("Invalid alias '{}' in [alias]", name))?
+            .unwrap_or_default();
+        if words.is_empty() {
+            bail!("Alias '{}' in [alias] is empty", name);
+        }
+        args.splice(position..=position, words.into_iter().map(OsString::from));
+        expanded.push(name);
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(line: &str, aliases: &[(&str, &str)]) -> Result<String> {
+        let args = line.split(' ').map(OsString::from).collect();
+        let aliases = aliases
+            .iter()
+            .map(|(name, expansion)| (name.to_string(), expansion.to_string()))
+            .collect();
+        let expanded = expand_aliases(args, &aliases)?;
+        Ok(expanded
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join(" "))
+    }
+
+    #[test]
+    fn test_aliases_expand_before_the_remaining_arguments() {
+        let aliases = [
+            ("n", "create --views 'feature:normal,business:normal'"),
+            ("nn", "n --description Draft"),
+            ("ls", "list --needs-review"),
+            ("list", "status"),
+        ];
+
+        assert_eq!(
+            expand("mucm --no-color n Login -c Auth", &aliases).unwrap(),
+            "mucm --no-color create --views feature:normal,business:normal Login -c Auth"
+        );
+        assert_eq!(
+            expand("mucm nn Login", &aliases).unwrap(),
+            "mucm create --views feature:normal,business:normal --description Draft Login"
+        );
+        // Project aliases replace built-ins, but never commands
+        assert_eq!(
+            expand("mucm ls", &aliases).unwrap(),
+            "mucm list --needs-review"
+        );
+        assert_eq!(expand("mucm list", &aliases).unwrap(), "mucm list");
+        assert_eq!(
+            expand("mucm rm UC-AUT-001 UC-AUT-001-S01", &[]).unwrap(),
+            "mucm use-case scenario delete UC-AUT-001 UC-AUT-001-S01"
+        );
+        assert_eq!(
+            expand("mucm rm UC-AUT-001", &[("rm", "delete")]).unwrap(),
+            "mucm delete UC-AUT-001"
+        );
+        assert_eq!(
+            expand("mucm persona reassign --from a --to b", &[]).unwrap(),
+            "mucm actor reassign --from a --to b"
+        );
+        assert_eq!(expand("mucm unknown", &[]).unwrap(), "mucm unknown");
+    }
+
+    #[test]
+    fn test_alias_loops_are_reported() {
+        let error = expand("mucm a", &[("a", "b"), ("b", "a --x")]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Alias 'a' expands to itself (a -> b -> a)"
+        );
+    }
+}
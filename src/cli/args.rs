@@ -8,6 +8,18 @@ pub struct Cli {
     #[arg(short, long)]
     pub interactive: bool,
 
+    /// Disable colored output (the NO_COLOR environment variable is honored too)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Print the planned operations of a mutating command as JSON instead of running it
+    #[arg(long, global = true)]
+    pub explain: bool,
+
+    /// Report where the command spent its time (load, validate, render per template, write)
+    #[arg(long, global = true)]
+    pub timings: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -22,7 +34,7 @@ pub enum Commands {
         /// Documentation methodologies (feature, business, developer, tester) - can specify multiple
         #[arg(short, long)]
         methodology: Option<String>,
-        /// Storage backend (toml or sqlite)
+        /// Storage backend (toml, sqlite or events)
         #[arg(long, short = 's', default_value = "toml")]
         storage: String,
         /// Finalize initialization by copying templates (run after reviewing config)
@@ -31,6 +43,12 @@ pub enum Commands {
         #[arg(long)]
         finalize: bool,
     },
+    /// Create a populated example project to explore before starting your own
+    Demo {
+        /// Directory to create the project in (must not exist or be empty)
+        #[arg(default_value = "mucm-demo")]
+        directory: String,
+    },
     /// Create a new use case
     Create {
         /// Use case title
@@ -56,21 +74,41 @@ pub enum Commands {
         command: UseCaseCommands,
     },
     /// List all use cases
-    List,
+    List {
+        /// Only show use cases whose review date has arrived
+        #[arg(long)]
+        needs_review: bool,
+        /// Only show use cases matching a saved filter ([filters.<name>] in mucm.toml)
+        #[arg(long)]
+        filter_name: Option<String>,
+    },
     /// List available programming languages for templates
-    Languages,
+    Languages {
+        /// Show aliases, file extensions and template paths; inside a project also
+        /// whether the template was customized locally and which use cases have tests
+        #[arg(long)]
+        details: bool,
+    },
     /// List available methodologies
     Methodologies,
     /// Show methodology information
+    ///
+    /// Lists the usage guidance and, per documentation level, its templates
+    /// and its custom fields (inherited ones included) with their types.
     MethodologyInfo {
         /// Methodology name to get info for
         name: String,
+        /// Print the methodology details as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Regenerate markdown documentation from TOML files
     ///
     /// Without arguments, regenerates all use cases with their current methodology.
     /// With a use case ID, regenerates just that use case.
     /// With --methodology, changes the methodology during regeneration.
+    /// With --overview, generates named overviews (template-assets/overviews/<NAME>.hbs)
+    /// into <NAME>-overview.md instead.
     Regenerate {
         /// Use case ID (e.g., UC-SEC-001). If omitted, regenerates all use cases.
         use_case_id: Option<String>,
@@ -81,9 +119,78 @@ pub enum Commands {
         /// Regenerate all use cases (explicit flag, same as omitting use_case_id)
         #[arg(long, short)]
         all: bool,
+        /// Regenerate only this view (e.g. business:detailed) of the use case,
+        /// leaving its other views and the overview untouched
+        #[arg(long, value_name = "METHODOLOGY:LEVEL", requires = "use_case_id", conflicts_with_all = ["methodology", "all"])]
+        view: Option<String>,
+        /// Generate the named overview NAME (e.g. executive); can be repeated
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["use_case_id", "methodology", "view"])]
+        overview: Vec<String>,
+    },
+    /// Show project status and health score
+    ///
+    /// The health score (0-100) weights the share of use cases without
+    /// validation issues, not stale, with enough scenarios, and with a test
+    /// file matching their scenarios; see [health] in the configuration.
+    Status {
+        /// Print the status and health breakdown as JSON for dashboards
+        #[arg(long)]
+        json: bool,
+    },
+    /// Regenerate mucm-manifest.json (machine-readable project index)
+    ///
+    /// The manifest is also refreshed whenever the overview is regenerated.
+    Manifest,
+    /// Export the documentation in another format
+    ///
+    /// Regenerates the markdown, then renders it in the requested format:
+    /// - html: the use cases, actors, and the overview as standalone HTML pages
+    ///   (layout: template-assets/html-page.hbs) with navigation between the
+    ///   overview, the categories, and the actors
+    /// - pdf: one or all use cases in a printable document with a sign-off
+    ///   page (layout: template-assets/pdf-document.hbs), converted by the
+    ///   command of `[export] pdf_command` (wkhtmltopdf by default)
+    /// - json: every use case (with scenarios and methodology fields),
+    ///   persona, and actor in one document for other tooling
+    /// - csv: one row per use case (ID, title, category, priority, status,
+    ///   scenario count, views) for spreadsheets and dashboards
+    /// - xlsx: an Excel workbook with a summary sheet and one filterable sheet
+    ///   per category
+    /// - reqif: the use cases and their pre/postconditions as ReqIF spec
+    ///   objects, with references as relations, for requirements tools
+    /// - mdbook: a SUMMARY.md in the use case directory and a book.toml next
+    ///   to it, so `mdbook serve` renders the documentation in place
+    ///
+    /// The format can also be given in place of the use case ID (`mucm export mdbook`).
+    Export {
+        /// Use case ID (e.g., UC-SEC-001) to export alone; pdf only. If omitted, exports all use cases.
+        use_case_id: Option<String>,
+        /// Output format (html, pdf, json, csv, xlsx, reqif, mdbook) [default: html]
+        #[arg(long)]
+        format: Option<String>,
+        /// Where to write, relative to the project root: the pages directory for
+        /// html (default: site), the PDF file for pdf (default: use-cases.pdf or <ID>.pdf),
+        /// the document for json (default: use-cases.json), the file for csv
+        /// or xlsx (default: use-cases.csv, use-cases.xlsx), the document for
+        /// reqif (default: use-cases.reqif), the directory of book.toml for
+        /// mdbook (default: the parent of the use case directory)
+        #[arg(long)]
+        output: Option<String>,
+        /// Only export the scenarios with this tag (e.g. smoke) and their use
+        /// cases; pdf includes those use cases whole. Not for html or mdbook
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// Publish the documentation to a wiki
+    Publish {
+        #[command(subcommand)]
+        command: PublishCommands,
+    },
+    /// Synchronize use cases with an issue tracker
+    Sync {
+        #[command(subcommand)]
+        command: SyncCommands,
     },
-    /// Show project status
-    Status,
     /// Manage use case preconditions
     Precondition {
         #[command(subcommand)]
@@ -95,6 +202,7 @@ pub enum Commands {
         command: PostconditionCommands,
     },
     /// Manage use case references
+    #[command(alias = "ref")]
     Reference {
         #[command(subcommand)]
         command: ReferenceCommands,
@@ -104,6 +212,12 @@ pub enum Commands {
         #[command(subcommand)]
         command: ActorCommands,
     },
+    /// Check use case references and ADR links for targets that do not exist
+    ///
+    /// Also reports views whose methodology template is not installed and
+    /// methodology fields of methodologies that are not installed.
+    /// Exits with an error when any problem is found, so it can run in CI.
+    Validate,
     /// Clean up orphaned methodology fields from TOML files
     ///
     /// Scans all use case TOML files and removes methodology sections that are no longer
@@ -116,10 +230,463 @@ pub enum Commands {
         #[arg(long, short = 'n')]
         dry_run: bool,
     },
+    /// Repair data files that fail to load
+    ///
+    /// Files with a recoverable problem (encoding, missing required fields) are
+    /// fixed in place; the rest are moved to a `.quarantine` directory inside the
+    /// data directory so the rest of the project loads cleanly.
+    Repair,
+    /// Show where a use case, scenario, or generated file comes from
+    ///
+    /// Prints the data file, the views and template files (project override or
+    /// source template) that produce the documentation, and the backups taken
+    /// when commands changed the data.
+    Explain {
+        /// Use case ID, scenario ID, or data/generated file path
+        target: String,
+    },
+    /// Search use case data for a pattern
+    ///
+    /// Searches the TOML data rather than the generated files, printing each
+    /// matching line once with the use case ID and the field it is in.
+    Grep {
+        /// Regular expression to search for
+        pattern: String,
+        /// Match case-insensitively
+        #[arg(short, long)]
+        ignore_case: bool,
+        /// Treat the pattern as literal text instead of a regular expression
+        #[arg(short = 'F', long)]
+        fixed_strings: bool,
+    },
+    /// Manage backups of the data files
+    ///
+    /// Changed TOML data files are backed up automatically (see `[backup]` in the
+    /// config); these commands snapshot, list, and restore backups by hand.
+    Backup {
+        #[command(subcommand)]
+        command: BackupCommands,
+    },
+    /// Reconcile generated test files with use case scenarios
+    ///
+    /// Parses the generated test files and reports scenarios that have no test
+    /// function and test functions that no longer match any scenario.
+    Reconcile {
+        /// Use case ID (e.g., UC-SEC-001). If omitted, checks all use cases.
+        use_case_id: Option<String>,
+        /// Scaffold stub scenarios for tests that have no matching scenario
+        #[arg(long)]
+        create_missing_scenarios: bool,
+    },
+    /// Generate files on demand
+    Generate {
+        #[command(subcommand)]
+        command: GenerateCommands,
+    },
+    /// Inspect and rename the recorded test names of scenarios
+    ///
+    /// Generated tests keep the name they were first generated with, even when
+    /// the scenario's title or ID changes. Names are recorded in
+    /// `.mucm-test-names.toml` in the test directory.
+    TestNames {
+        #[command(subcommand)]
+        command: TestNameCommands,
+    },
+    /// Manage project-wide settings
+    Project {
+        #[command(subcommand)]
+        command: ProjectCommands,
+    },
+    /// Inspect the project configuration (mucm.toml)
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+    /// Experiment on a throwaway copy of the project
+    ///
+    /// Create a sandbox, cd into it and run any command there; the real
+    /// project is untouched until the sandbox's changes are applied.
+    Sandbox {
+        #[command(subcommand)]
+        command: SandboxCommands,
+    },
+    /// Run a read-only command against the project as it was at a date
+    ///
+    /// Needs the event-log backend (storage.backend = "events").
+    At {
+        /// Date (YYYY-MM-DD, end of that day in UTC) or RFC 3339 timestamp
+        date: String,
+        #[command(subcommand)]
+        command: AtCommands,
+    },
+    /// Undo the latest change recorded in the event log
+    ///
+    /// Each run steps one change further back. Needs the event-log backend
+    /// (storage.backend = "events").
+    Undo,
+    /// Build reports that span projects
+    Report {
+        #[command(subcommand)]
+        command: ReportCommands,
+    },
+    /// Copy a use case under a new ID as a starting point
+    ///
+    /// Views, custom fields, and references are copied; scenarios start over as planned.
+    Clone {
+        /// Use case ID to copy (e.g., UC-AUT-001)
+        use_case_id: String,
+        /// Title of the new use case
+        title: String,
+        /// Category of the new use case (defaults to the original's)
+        #[arg(long)]
+        category: Option<String>,
+        /// Don't copy scenarios
+        #[arg(long)]
+        no_scenarios: bool,
+        /// Don't copy preconditions and postconditions
+        #[arg(long)]
+        no_conditions: bool,
+    },
+    /// Move scenarios out of a use case into a new use case
+    ///
+    /// The new use case extends the original one, and references to the moved
+    /// scenarios are updated everywhere.
+    Split {
+        /// Use case ID to split (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// Comma-separated scenario IDs to move (e.g., UC-SEC-001-S03,UC-SEC-001-S04)
+        #[arg(long, value_delimiter = ',', required = true)]
+        scenarios: Vec<String>,
+        /// Title of the new use case
+        #[arg(long)]
+        title: String,
+        /// Category of the new use case (defaults to the original's)
+        #[arg(long)]
+        category: Option<String>,
+    },
+    /// Merge a use case into another one and delete it
+    ///
+    /// References to the merged use case and its scenarios are updated everywhere.
+    Merge {
+        /// Use case ID to keep (e.g., UC-SEC-001)
+        target_id: String,
+        /// Use case ID to merge in and remove (e.g., UC-SEC-002)
+        source_id: String,
+        /// How to resolve differing values: ask, keep-target, use-source, or keep-both
+        #[arg(long, default_value = "ask")]
+        on_conflict: String,
+    },
+    /// Acceptance checklists (<ID>-checklist.md next to the use case's markdown)
+    Checklist {
+        #[command(subcommand)]
+        command: ChecklistCommands,
+    },
+    /// Estimate the scenarios of a use case
+    ///
+    /// Asks for every estimation field declared in the `[estimation]` section
+    /// of the use case's methodologies, scenario by scenario, and stores the
+    /// aggregated estimates on the use case.
+    Estimate {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+    },
+    /// Import use cases from other sources
+    Import {
+        #[command(subcommand)]
+        command: ImportCommands,
+    },
+    /// Apply a batch of create, update and delete operations from a file
+    ///
+    /// The file (TOML or JSON) lists `operations`, each with an `op` of
+    /// "create", "update" or "delete". The project is loaded and saved once and
+    /// each use case is regenerated once, which is much faster than running the
+    /// commands one by one. Failing operations are reported and skipped.
+    Batch {
+        /// Batch file (.toml or .json)
+        file: String,
+    },
+    /// Re-run the commands recorded in an interactive session log
+    ///
+    /// Interactive mode records the CLI equivalent of every change in
+    /// `.config/.mucm/sessions/`. Each `mucm ...` line is run in order in the
+    /// current project; `#` lines (changes without a CLI equivalent) are skipped.
+    Replay {
+        /// Session log file
+        log: String,
+        /// Print the commands without running them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Measure save, load and render times on a synthetic project
+    #[command(hide = true)]
+    Bench {
+        /// Number of synthetic use cases to generate
+        #[arg(long, default_value_t = 1000)]
+        use_cases: usize,
+        /// Storage backend to measure (toml or sqlite)
+        #[arg(long, default_value = "toml")]
+        backend: String,
+    },
+    /// Start the language server for editor integration (speaks LSP over stdio)
+    Lsp,
     /// Enter interactive mode
     Interactive,
 }
 
+#[derive(Debug, Subcommand)]
+pub enum ProjectCommands {
+    /// Rename the project and optionally re-prefix every use case ID
+    ///
+    /// References are rewritten to the new IDs and all documentation is regenerated.
+    Rename {
+        /// New project name
+        name: Option<String>,
+        /// New project description
+        #[arg(long)]
+        description: Option<String>,
+        /// New ID prefix (e.g., PAY turns UC-AUT-001 into PAY-AUT-001)
+        #[arg(long)]
+        id_prefix: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommands {
+    /// Check mucm.toml for unknown keys, invalid paths, and missing templates
+    ///
+    /// Exits with an error when the configuration has errors; warnings are
+    /// only reported. The same checks run before every other command.
+    Validate,
+    /// Set a config value, keeping the file's comments
+    ///
+    /// The value is read as TOML (true, 42, ["a", "b"]) when that fits the key,
+    /// and as text otherwise.
+    Set {
+        /// Dotted key (e.g. directories.use_case_dir, generation.autolink_ids)
+        key: String,
+        /// New value
+        value: String,
+        /// For directories.*: move the existing files to the new location and
+        /// regenerate the documentation
+        #[arg(long)]
+        migrate: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AtCommands {
+    /// List the use cases that existed at that time
+    List,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SandboxCommands {
+    /// Copy the project into a temp directory and print its path
+    Create,
+    /// List files changed in this sandbox compared to its project
+    Diff,
+    /// Copy this sandbox's changes back into its project
+    Apply {
+        /// Remove the sandbox afterwards
+        #[arg(long)]
+        discard: bool,
+    },
+    /// Delete this sandbox without applying anything
+    Discard,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum GenerateCommands {
+    /// Generate test files for use cases
+    ///
+    /// Files with manual modifications are never overwritten unless --force is
+    /// given, regardless of generation.overwrite_test_documentation.
+    Tests {
+        /// Use case ID (e.g., UC-SEC-001). If omitted, covers all use cases.
+        use_case_id: Option<String>,
+        /// Only generate files that are missing or lack tests for some scenarios
+        #[arg(long)]
+        only_missing: bool,
+        /// Also overwrite files with manual modifications
+        #[arg(long)]
+        force: bool,
+        /// Generate the suite of a scenario tag (e.g. smoke) under tags/<tag>/,
+        /// holding only the scenarios with that tag
+        #[arg(long, conflicts_with = "only_missing")]
+        tag: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ChecklistCommands {
+    /// Write the checklist of conditions and scenarios with `- [ ]` items
+    ///
+    /// Scenario items are ticked when the scenario is tested or deployed.
+    /// Condition items ticked by hand stay ticked when the checklist is rewritten.
+    Generate {
+        /// Use case ID (e.g., UC-SEC-001). If omitted, covers all use cases.
+        use_case_id: Option<String>,
+    },
+    /// Tick a scenario: set its status to tested and update the checklist
+    Tick {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// Scenario ID, full (UC-SEC-001-S02) or short (S02)
+        scenario_id: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TestNameCommands {
+    /// List the test name of every scenario
+    List {
+        /// Use case ID (e.g., UC-SEC-001). If omitted, lists all scenarios.
+        use_case_id: Option<String>,
+    },
+    /// Rename a scenario's test; the old name is kept as a deprecation shim
+    Rename {
+        /// Scenario ID (e.g., UC-SEC-001-S01)
+        scenario_id: String,
+        /// New test name
+        name: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BackupCommands {
+    /// Snapshot all data files now
+    Now,
+    /// List backup snapshots, newest first
+    List,
+    /// Restore data files from a snapshot and regenerate documentation
+    Restore {
+        /// Snapshot name as shown by `mucm backup list`
+        snapshot: String,
+        /// Restore only this use case (e.g., UC-AUT-001)
+        #[arg(long)]
+        use_case: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PublishCommands {
+    /// Create or update one Confluence page per use case, under a page per category
+    ///
+    /// Regenerates the markdown first. Uses the `[confluence]` settings and the
+    /// API token in the environment variable of `confluence.token_env`; the page
+    /// IDs are stored in each use case's metadata for later updates.
+    Confluence,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SyncCommands {
+    /// Create or update one Jira issue per use case and pull the issue statuses back
+    ///
+    /// Uses the `[jira]` settings and the API token in the environment variable
+    /// of `jira.token_env`. Issue keys are stored in the `jira` methodology
+    /// fields; use case references become issue links.
+    Jira,
+    /// Create or update one GitHub issue per use case
+    ///
+    /// Uses the `[github]` settings and the access token in the environment
+    /// variable of `github.token_env`. Each issue is recorded in the use case
+    /// references as a `github_issue` reference to `owner/repo#number`.
+    Github {
+        /// Update the scenario statuses from the issue states (closed issues
+        /// become implemented, reopened ones in progress)
+        #[arg(long)]
+        pull_status: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ReportCommands {
+    /// Combine status, stats, and an index of several projects (read-only)
+    Aggregate {
+        /// Project root directories to include
+        #[arg(long, num_args = 1.., required = true)]
+        projects: Vec<String>,
+        /// Write the markdown report to this file instead of printing it
+        #[arg(long)]
+        output: Option<String>,
+        /// Only include use cases matching this saved filter of each project
+        #[arg(long)]
+        filter_name: Option<String>,
+    },
+    /// Summarize use cases added, removed, and materially changed since a git revision
+    ///
+    /// Compares the data files with their versions at the revision (e.g. a
+    /// release tag) and groups the changes by category, ready to paste into
+    /// a release announcement.
+    ReleaseNotes {
+        /// Git revision to compare against (e.g., v1.1)
+        #[arg(long)]
+        since: String,
+        /// Write the markdown to this file instead of printing it
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// List use case ID sequences per category with gaps and duplicates
+    Ids {
+        /// Renumber use cases so every sequence runs 1..n, rewriting references
+        /// (requires allow_renumbering = true under [project])
+        #[arg(long)]
+        renumber: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ImportCommands {
+    /// Import use case TOML data files (e.g. from another MUCM project)
+    Toml {
+        /// Data file or directory to import (directories are searched for UC-*.toml)
+        path: String,
+        /// What to do when an imported ID already exists: ask, skip, overwrite, re-id, or merge
+        #[arg(long, default_value = "ask")]
+        on_conflict: String,
+    },
+    /// Import use cases from a JSON export (mucm export --format json)
+    ///
+    /// The document is checked against the use case model before anything is written.
+    Json {
+        /// JSON document to import
+        path: String,
+        /// What to do when an imported ID already exists: ask, skip, overwrite, re-id, or merge
+        #[arg(long, default_value = "ask")]
+        on_conflict: String,
+    },
+    /// Import Gherkin feature files (Feature → use case, Scenario → scenario)
+    ///
+    /// Re-importing updates the use cases created by earlier imports instead of duplicating them.
+    Gherkin {
+        /// Feature file or directory (searched recursively for *.feature)
+        path: String,
+        /// Category for new use cases (defaults to the feature file's directory name)
+        #[arg(long)]
+        category: Option<String>,
+    },
+    /// Import hand-written use case markdown (# heading → use case, flows → scenarios)
+    ///
+    /// Best effort: unrecognized sections are kept in the description. Files imported before are skipped.
+    Markdown {
+        /// Markdown file or directory (searched recursively for *.md)
+        path: String,
+        /// Category for new use cases (defaults to a Category field, else the file's directory name)
+        #[arg(long)]
+        category: Option<String>,
+    },
+    /// Import a requirement traceability matrix (one row → one use case)
+    ///
+    /// A TOML mapping file names the column of each field. Rows imported before are skipped.
+    Rtm {
+        /// Spreadsheet holding the matrix (xlsx, xlsm, xlsb, xls, or ods)
+        path: String,
+        /// TOML file mapping the columns to use case fields
+        #[arg(long)]
+        mapping: String,
+    },
+}
+
 #[derive(Debug, Subcommand)]
 pub enum PreconditionCommands {
     /// Add a precondition to a use case
@@ -192,6 +759,34 @@ pub enum ReferenceCommands {
         /// Target use case ID to remove
         target_id: String,
     },
+    /// Manage links to architecture decision records (ADRs)
+    Adr {
+        #[command(subcommand)]
+        command: AdrCommands,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AdrCommands {
+    /// Link an ADR file to a use case
+    Add {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// ADR file, relative to the project root (e.g., docs/adr/0007.md)
+        path: String,
+    },
+    /// List the ADRs linked to a use case
+    List {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+    },
+    /// Unlink an ADR file from a use case
+    Remove {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// ADR file as linked
+        path: String,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -204,6 +799,10 @@ pub enum ActorCommands {
         name: String,
         /// Persona function/role (e.g., "System Administrator", "End User")
         function: String,
+        /// Value of a persona field from `[actor.persona_fields]`, as NAME=VALUE
+        /// (repeatable; comma-separate array items)
+        #[arg(long = "field", value_name = "NAME=VALUE")]
+        fields: Vec<String>,
     },
     /// Create a new system actor
     CreateSystem {
@@ -241,6 +840,35 @@ pub enum ActorCommands {
         /// Actor ID
         id: String,
     },
+    /// Place an actor under a parent actor (e.g. a role under its department)
+    ///
+    /// Actor pages and the overview count the use cases of each actor's
+    /// subtree; run `mucm regenerate` to update them.
+    SetParent {
+        /// Actor ID
+        id: String,
+        /// Parent actor ID
+        #[arg(required_unless_present = "none")]
+        parent: Option<String>,
+        /// Remove the actor's parent, making it a top-level actor
+        #[arg(long, conflicts_with = "parent")]
+        none: bool,
+    },
+    /// Move every scenario assigned to one persona over to another
+    ///
+    /// The affected use cases are saved and their documentation regenerated
+    /// in one pass.
+    Reassign {
+        /// Persona the scenarios are assigned to now
+        #[arg(long)]
+        from: String,
+        /// Persona to assign them to
+        #[arg(long)]
+        to: String,
+        /// Only reassign scenarios of use cases in this category
+        #[arg(long)]
+        category: Option<String>,
+    },
     /// List all use cases that reference this actor
     UseCases {
         /// Actor ID
@@ -260,6 +888,22 @@ pub enum UseCaseCommands {
         #[command(subcommand)]
         command: UseCaseScenarioCommands,
     },
+    /// Manage the methodology views of a use case
+    View {
+        #[command(subcommand)]
+        command: UseCaseViewCommands,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UseCaseViewCommands {
+    /// Make a view primary; it is also generated as the canonical <ID>.md
+    Primary {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// Methodology of the view (e.g., feature)
+        methodology: String,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -279,6 +923,9 @@ pub enum UseCaseScenarioCommands {
         /// Optional persona ID to assign
         #[arg(short, long)]
         persona: Option<String>,
+        /// Tag to add (e.g. smoke or @smoke); repeat for several tags
+        #[arg(long = "tag")]
+        tags: Vec<String>,
     },
     /// Edit an existing scenario
     Edit {
@@ -311,6 +958,19 @@ pub enum UseCaseScenarioCommands {
         /// Use case ID (e.g., UC-SEC-001)
         use_case_id: String,
     },
+    /// Move a scenario before or after another scenario
+    Move {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// Scenario ID to move (e.g., UC-SEC-001-S02)
+        scenario_id: String,
+        /// Place the scenario directly before this scenario
+        #[arg(long, conflicts_with = "after", required_unless_present = "after")]
+        before: Option<String>,
+        /// Place the scenario directly after this scenario
+        #[arg(long)]
+        after: Option<String>,
+    },
     /// Manage scenario steps
     Step {
         #[command(subcommand)]
@@ -332,6 +992,39 @@ pub enum UseCaseScenarioCommands {
         /// Scenario ID (e.g., UC-SEC-001-S01)
         scenario_id: String,
     },
+    /// Add tags to a scenario (e.g. smoke, regression)
+    Tag {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// Scenario ID (e.g., UC-SEC-001-S01)
+        scenario_id: String,
+        /// Tags to add, with or without the leading '@'
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// Remove tags from a scenario
+    Untag {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// Scenario ID (e.g., UC-SEC-001-S01)
+        scenario_id: String,
+        /// Tags to remove, with or without the leading '@'
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// Make a scenario inherit the use case preconditions and/or postconditions
+    InheritConditions {
+        /// Use case ID (e.g., UC-SEC-001)
+        use_case_id: String,
+        /// Scenario ID (e.g., UC-SEC-001-S01)
+        scenario_id: String,
+        /// Which conditions to inherit (pre, post, both)
+        #[arg(long, default_value = "both")]
+        conditions: String,
+        /// Stop inheriting them instead
+        #[arg(long)]
+        off: bool,
+    },
     /// Manage scenario references
     Reference {
         #[command(subcommand)]
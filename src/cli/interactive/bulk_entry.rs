@@ -0,0 +1,141 @@
+//! # Bulk Scenario Entry
+//!
+//! Parses a pasted outline of scenarios and their steps, so many steps can be
+//! entered in one prompt instead of one prompt per step.
+//!
+//! ```text
+//! User logs in [main]
+//! 1. User enters email and password
+//! 2. System validates the credentials
+//! Wrong password [exception]
+//! - User enters a wrong password
+//! - System shows an error
+//! ```
+//!
+//! Lines starting with a list marker (`1.`, `1)`, `-`, `*`) are steps of the
+//! scenario above them; any other line starts a new scenario. A trailing
+//! `[type]` sets the scenario type (main, alternative, exception, extension),
+//! and a leading `Scenario:` is ignored. Blank lines and `#` comments are skipped.
+
+use anyhow::{bail, Result};
+use std::str::FromStr;
+
+use crate::core::ScenarioType;
+
+/// A scenario parsed from the outline, not yet created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioDraft {
+    pub title: String,
+    /// Scenario type as accepted by `ScenarioController::create_scenario`
+    pub scenario_type: String,
+    pub steps: Vec<String>,
+}
+
+/// Parse an outline of scenarios and steps
+pub fn parse_scenario_outline(text: &str) -> Result<Vec<ScenarioDraft>> {
+    let mut drafts: Vec<ScenarioDraft> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(step) = strip_list_marker(line) {
+            let Some(draft) = drafts.last_mut() else {
+                bail!(
+                    "Line {}: step '{}' comes before any scenario title",
+                    index + 1,
+                    step
+                );
+            };
+            if !step.is_empty() {
+                draft.steps.push(step.to_string());
+            }
+            continue;
+        }
+
+        let title = line
+            .strip_prefix("Scenario:")
+            .map(str::trim)
+            .unwrap_or(line);
+        let (title, scenario_type) = split_type(title)
+            .map_err(|message| anyhow::anyhow!("Line {}: {}", index + 1, message))?;
+        if title.is_empty() {
+            bail!("Line {}: scenario title is empty", index + 1);
+        }
+        drafts.push(ScenarioDraft {
+            title: title.to_string(),
+            scenario_type,
+            steps: Vec::new(),
+        });
+    }
+    Ok(drafts)
+}
+
+/// Text of a step line without its list marker; None if the line is no step
+fn strip_list_marker(line: &str) -> Option<&str> {
+    if let Some(rest) = line.strip_prefix(['-', '*']) {
+        return Some(rest.trim());
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..].strip_prefix(['.', ')']).map(str::trim)
+}
+
+/// Split a trailing `[type]` off a title; untyped scenarios are main scenarios
+fn split_type(title: &str) -> std::result::Result<(&str, String), String> {
+    let Some(open) = title.rfind('[').filter(|_| title.ends_with(']')) else {
+        return Ok((title, "main".to_string()));
+    };
+    let scenario_type = title[open + 1..title.len() - 1].trim();
+    ScenarioType::from_str(scenario_type)?;
+    Ok((title[..open].trim(), scenario_type.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_outline_with_types_and_markers() {
+        let text = "\
+Scenario: User logs in
+1. User enters email and password
+2) System validates the credentials
+
+# errors
+Wrong password [exception]
+- User enters a wrong password
+* System shows an error
+";
+        let drafts = parse_scenario_outline(text).unwrap();
+        assert_eq!(drafts.len(), 2);
+        assert_eq!(drafts[0].title, "User logs in");
+        assert_eq!(drafts[0].scenario_type, "main");
+        assert_eq!(
+            drafts[0].steps,
+            vec![
+                "User enters email and password",
+                "System validates the credentials"
+            ]
+        );
+        assert_eq!(drafts[1].title, "Wrong password");
+        assert_eq!(drafts[1].scenario_type, "exception");
+        assert_eq!(drafts[1].steps.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_outline_errors() {
+        let error = parse_scenario_outline("1. Orphan step").unwrap_err();
+        assert!(error.to_string().contains("Line 1"));
+
+        let error = parse_scenario_outline("Login\nLogout [sideways]").unwrap_err();
+        assert!(error.to_string().contains("Line 2"));
+
+        // A number that is not a list marker belongs to the title
+        let drafts = parse_scenario_outline("2024 migration").unwrap();
+        assert_eq!(drafts[0].title, "2024 migration");
+    }
+}
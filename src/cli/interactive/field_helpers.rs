@@ -5,11 +5,17 @@
 //! use cases, personas, scenarios, and other entities.
 
 use anyhow::Result;
-use inquire::{Confirm, Select, Text};
+use inquire::{Confirm, MultiSelect, Select, Text};
 use serde_json::Value as JsonValue;
 
+use crate::config::{PersonaFieldConfig, PersonaFieldType};
+use crate::core::utils::{markdown_table, table_columns};
+
 use super::ui::UI;
 
+/// Choice that leaves an optional field with options empty
+const NO_CHOICE: &str = "(none)";
+
 /// Helper functions for interactive field editing
 pub struct FieldHelpers;
 
@@ -92,6 +98,107 @@ impl FieldHelpers {
         }
     }
 
+    /// Edit a table field as a grid of rows with add row/edit cell/delete row options
+    ///
+    /// # Arguments
+    /// * `label` - Display name for the table field
+    /// * `columns` - Column names declared for the field (taken from the rows when empty)
+    /// * `current_value` - Current JSON value of the field (if any)
+    ///
+    /// # Returns
+    /// * `Ok(Some(rows))` - Modified rows as a JSON array of objects keyed by column
+    /// * `Ok(None)` - No changes made (user kept original)
+    pub fn edit_table(
+        label: &str,
+        columns: &[String],
+        current_value: Option<&JsonValue>,
+    ) -> Result<Option<String>> {
+        let original_rows: Vec<JsonValue> = current_value
+            .and_then(|v| v.as_array())
+            .map(|rows| rows.iter().filter(|row| row.is_object()).cloned().collect())
+            .unwrap_or_default();
+        let mut columns = table_columns(columns, &original_rows);
+        if columns.is_empty() {
+            columns.push("Value".to_string());
+        }
+        let mut rows = original_rows.clone();
+
+        loop {
+            if rows.is_empty() {
+                UI::show_info(&format!("\n📋 {} (no rows)", label))?;
+            } else {
+                UI::show_info(&format!(
+                    "\n📋 {}\n\n{}",
+                    label,
+                    markdown_table(&columns, &rows)
+                ))?;
+            }
+
+            let options = if rows.is_empty() {
+                vec!["Add row", "Done (keep empty)"]
+            } else {
+                vec!["Add row", "Edit cell", "Delete row", "Done"]
+            };
+
+            match Select::new("Action:", options).prompt()? {
+                "Add row" => {
+                    let mut row = serde_json::Map::new();
+                    for column in &columns {
+                        let cell = Text::new(&format!("  {}:", column)).prompt()?;
+                        row.insert(column.clone(), JsonValue::String(cell.trim().to_string()));
+                    }
+                    if row.values().any(|cell| cell.as_str() != Some("")) {
+                        rows.push(JsonValue::Object(row));
+                    }
+                }
+                "Edit cell" => {
+                    let index = Self::select_row(&columns, &rows, "Select row to edit:")?;
+                    let column = Select::new("Select column:", columns.clone()).prompt()?;
+                    let current = rows[index]
+                        .get(&column)
+                        .and_then(|cell| cell.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    let cell = Text::new(&format!("  {}:", column))
+                        .with_default(&current)
+                        .prompt()?;
+                    if let Some(row) = rows[index].as_object_mut() {
+                        row.insert(column, JsonValue::String(cell.trim().to_string()));
+                    }
+                }
+                "Delete row" => {
+                    let index = Self::select_row(&columns, &rows, "Select row to delete:")?;
+                    rows.remove(index);
+                }
+                "Done" | "Done (keep empty)" => break,
+                _ => {}
+            }
+        }
+
+        if rows == original_rows {
+            Ok(None)
+        } else {
+            Ok(Some(JsonValue::Array(rows).to_string()))
+        }
+    }
+
+    /// Pick a table row, listed by its cells; returns its index
+    fn select_row(columns: &[String], rows: &[JsonValue], prompt: &str) -> Result<usize> {
+        let labels: Vec<String> = rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let cells: Vec<&str> = columns
+                    .iter()
+                    .map(|column| row.get(column).and_then(|c| c.as_str()).unwrap_or(""))
+                    .collect();
+                format!("{}. {}", index + 1, cells.join(" | "))
+            })
+            .collect();
+        let choice = Select::new(prompt, labels).raw_prompt()?;
+        Ok(choice.index)
+    }
+
     /// Prompt for a number with validation
     ///
     /// # Arguments
@@ -224,7 +331,7 @@ impl FieldHelpers {
         match field_type {
             "array" => {
                 let current_items = current_value
-                    .map(|v| Self::parse_json_array(v))
+                    .map(Self::parse_json_array)
                     .unwrap_or_default();
 
                 if let Some(items) = Self::edit_array(label, current_items)? {
@@ -243,7 +350,7 @@ impl FieldHelpers {
 
                 Self::edit_boolean(&format!("{}: ", label), current_bool, help)
             }
-            "text" | _ => {
+            _ => {
                 // "text" and default to string
                 let current = current_value.and_then(|v| v.as_str()).unwrap_or("");
 
@@ -251,6 +358,88 @@ impl FieldHelpers {
             }
         }
     }
+
+    /// Edit a persona field following its `[actor.persona_fields]` schema
+    ///
+    /// Fields with options are picked from a list (several items for arrays),
+    /// others use the input of their type. Required fields are asked again
+    /// until they have a value.
+    ///
+    /// # Arguments
+    /// * `name` - Field name, shown when the field has no label
+    /// * `field` - Schema of the field
+    /// * `current_value` - Current JSON value of the field (if any)
+    ///
+    /// # Returns
+    /// * `Ok(Some(value))` - New value as string if changed
+    /// * `Ok(None)` - No change made
+    pub fn edit_persona_field(
+        name: &str,
+        field: &PersonaFieldConfig,
+        current_value: Option<&JsonValue>,
+    ) -> Result<Option<String>> {
+        let label = field.label_or(name);
+        let help = field
+            .description
+            .clone()
+            .or_else(|| field.example.as_ref().map(|e| format!("e.g. {}", e)))
+            .unwrap_or_else(|| format!("{} field", field.field_type.name()));
+
+        loop {
+            let new_value = if field.options.is_empty() {
+                Self::edit_by_type(field.field_type.name(), label, current_value, &help)?
+            } else if field.field_type == PersonaFieldType::Array {
+                let current = current_value
+                    .map(Self::parse_json_array)
+                    .unwrap_or_default();
+                let selected: Vec<usize> = field
+                    .options
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, option)| current.contains(option))
+                    .map(|(index, _)| index)
+                    .collect();
+                let chosen = MultiSelect::new(&format!("{}:", label), field.options.clone())
+                    .with_default(&selected)
+                    .with_help_message(&help)
+                    .prompt()?;
+                (chosen != current).then(|| Self::array_to_storage(&chosen))
+            } else {
+                let current = current_value
+                    .map(|v| v.as_str().map_or_else(|| v.to_string(), str::to_string))
+                    .unwrap_or_default();
+                let mut options = field.options.clone();
+                if !field.required {
+                    options.push(NO_CHOICE.to_string());
+                }
+                let start = options.iter().position(|o| *o == current).unwrap_or(0);
+                let choice = Select::new(&format!("{}:", label), options)
+                    .with_starting_cursor(start)
+                    .with_help_message(&help)
+                    .prompt()?;
+                let choice = if choice == NO_CHOICE {
+                    String::new()
+                } else {
+                    choice
+                };
+                (choice != current).then_some(choice)
+            };
+
+            let empty = match &new_value {
+                Some(value) => value.trim().is_empty(),
+                None => current_value.is_none_or(|v| {
+                    v.is_null()
+                        || v.as_str().is_some_and(str::is_empty)
+                        || v == &JsonValue::Array(vec![])
+                }),
+            };
+            if field.required && empty {
+                UI::show_error(&format!("{} is required", label))?;
+                continue;
+            }
+            return Ok(new_value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -6,9 +6,11 @@
 use anyhow::Result;
 
 use crate::cli::interactive::menus::settings::Settings;
+use crate::cli::interactive::session_log;
 use crate::cli::interactive::ui::UI;
 use crate::cli::interactive::workflows::actor::ActorWorkflow;
 use crate::cli::interactive::workflows::initialization::Initialization;
+use crate::cli::interactive::workflows::project::ProjectWorkflow;
 use crate::cli::interactive::workflows::use_case::UseCaseWorkflow;
 use crate::cli::standard::CliRunner;
 
@@ -23,6 +25,13 @@ pub fn run_interactive_session() -> Result<()> {
         return Ok(());
     }
 
+    // Remember this project for the project switcher
+    let current_dir = std::env::current_dir()?;
+    if let Err(e) = CliRunner::remember_project(&current_dir) {
+        UI::show_warning(&format!("Could not record recent project: {}", e))?;
+    }
+
+    session_log::start();
     UI::show_welcome()?;
 
     loop {
@@ -43,6 +52,7 @@ pub fn run_interactive_session() -> Result<()> {
 /// - Manage Use Cases: All use case operations (create, edit, list, status)
 /// - Manage Actors: All actor operations (personas and system actors)
 /// - Project Settings: Configuration
+/// - Switch Project: Open another recently used project
 fn create_main_menu_options() -> Vec<MenuOption<CliRunner>> {
     vec![
         MenuOption::new("📝 Manage Use Cases", |_| {
@@ -63,7 +73,16 @@ fn create_main_menu_options() -> Vec<MenuOption<CliRunner>> {
             }
             Ok(false) // Don't exit
         }),
+        MenuOption::new("🔀 Switch Project", |runner| {
+            if let Err(e) = ProjectWorkflow::switch_project(runner) {
+                UI::show_error(&format!("Error switching project: {}", e))?;
+            }
+            Ok(false) // Don't exit
+        }),
         MenuOption::new("🚪 Exit", |_| {
+            if let Some(summary) = session_log::summary() {
+                UI::show_session_summary(&summary)?;
+            }
             UI::show_goodbye()?;
             Ok(true) // Exit the session
         }),
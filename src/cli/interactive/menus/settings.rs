@@ -10,6 +10,7 @@ use crate::config::Config;
 
 use crate::cli::interactive::workflows::config::ConfigWorkflow;
 use crate::cli::interactive::workflows::methodology::MethodologyWorkflow;
+use crate::cli::interactive::workflows::template::TemplateWorkflow;
 
 use super::common::{display_menu, MenuOption};
 
@@ -61,6 +62,11 @@ impl Settings {
                 *config = Config::load()?;
                 Ok(false) // Continue menu
             }),
+            MenuOption::new("Template Customization", |_config| {
+                TemplateWorkflow::customize_template()?;
+                UI::pause_for_input()?;
+                Ok(false) // Continue menu
+            }),
             MenuOption::new("View Configuration", |config| {
                 ConfigWorkflow::view_config(config)?;
                 Ok(false) // Continue menu
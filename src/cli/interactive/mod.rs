@@ -9,6 +9,8 @@
 //! - `runner.rs`: Business logic coordinator for interactive workflows
 //! - `selectors.rs`: Pure data selection functions for UI presentation
 //! - `ui.rs`: Presentation layer for interactive prompts and displays
+//! - `session_log.rs`: CLI equivalents of interactive actions, for `mucm replay`
+//! - `bulk_entry.rs`: Parser for pasted scenario and step outlines
 //! - `menus/`: Menu navigation and selection systems
 //!   - `menu.rs`: Main menu navigation and selection logic
 //!   - `settings.rs`: Settings configuration submenu
@@ -27,10 +29,12 @@
 //! - **Main Menu**: Central navigation hub for all interactive features
 
 // Private modules - used only within CLI interactive
+mod bulk_entry;
 mod field_helpers;
 mod menus;
 mod runner;
 mod selectors;
+pub mod session_log;
 mod ui;
 mod workflows;
 
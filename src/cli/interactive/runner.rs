@@ -19,8 +19,9 @@
 
 use anyhow::Result;
 
-use crate::controller::{PersonaController, ProjectController, UseCaseController};
-use crate::core::{FieldCollection, MethodologyFieldCollector};
+use crate::cli::interactive::session_log;
+use crate::controller::{DisplayResult, PersonaController, ProjectController, UseCaseController};
+use crate::core::{FieldCollection, MethodologyFieldCollector, TemplateDraft, TemplatePreview};
 
 /// Interactive runner that coordinates interactive CLI workflows
 pub struct InteractiveRunner {
@@ -90,7 +91,7 @@ impl InteractiveRunner {
         views: &[(String, String)],
     ) -> Result<FieldCollection> {
         let collector = MethodologyFieldCollector::new()?;
-        collector.collect_fields_for_views(views)
+        Ok(collector.collect_fields_for_views(views)?)
     }
 
     /// Get installed/configured methodologies in the project (for creating use cases)
@@ -107,6 +108,7 @@ impl InteractiveRunner {
     }
 
     /// Initialize project for interactive mode.
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_project(
         &mut self,
         language: Option<String>,
@@ -195,15 +197,28 @@ impl InteractiveRunner {
             .collect::<Vec<_>>()
             .join(",");
 
+        let mut args = vec![
+            "create".to_string(),
+            title.clone(),
+            "--category".to_string(),
+            category.clone(),
+        ];
+        if let Some(description) = description.as_ref().filter(|d| !d.trim().is_empty()) {
+            args.extend(["--description".to_string(), description.clone()]);
+        }
+        args.extend(["--views".to_string(), views_string.clone()]);
+        let has_fields = !extra_fields.is_empty();
+
         let result = controller.create_use_case(
             title.clone(),
             category,
             description,
             None,
             Some(views_string),
-            Some(priority),
+            Some(priority.clone()),
             Some(extra_fields),
         )?;
+        session_log::record(args);
 
         // Extract use case ID from message (format: "Created use case: UC-XXX-XXX with views: ...")
         let use_case_id = if let Some(id_part) = result.message.split("Created use case: ").nth(1) {
@@ -212,6 +227,13 @@ impl InteractiveRunner {
             title
         };
 
+        if has_fields || !priority.eq_ignore_ascii_case("medium") {
+            session_log::note(&format!(
+                "{} was created with priority {} and additional fields",
+                use_case_id, priority
+            ));
+        }
+
         Ok((use_case_id, result.message))
     }
 
@@ -224,19 +246,34 @@ impl InteractiveRunner {
     /// Show project status
     pub fn show_status(&mut self) -> Result<()> {
         let controller = self.ensure_use_case_controller()?;
-        controller.show_status()
+        controller.show_status(false)
     }
 
-    /// Create a persona interactively
+    /// Create a persona interactively with values for its custom fields
     pub fn create_persona_interactive(
         &mut self,
         id: String,
         name: String,
         function: String,
-    ) -> Result<String> {
+        fields: std::collections::HashMap<String, String>,
+    ) -> Result<DisplayResult> {
         let controller = self.ensure_persona_controller()?;
-        let result = controller.create_persona(id, name, function)?;
-        Ok(result.message)
+        let result = controller.create_persona_with_fields(
+            id.clone(),
+            name.clone(),
+            function.clone(),
+            fields,
+        )?;
+        if result.success {
+            session_log::record([
+                "actor",
+                "create-persona",
+                id.as_str(),
+                name.as_str(),
+                function.as_str(),
+            ]);
+        }
+        Ok(result)
     }
 
     /// Create a system actor interactively
@@ -248,11 +285,23 @@ impl InteractiveRunner {
         emoji: Option<String>,
     ) -> Result<String> {
         let controller = self.ensure_persona_controller()?;
+        let mut args = vec![
+            "actor".to_string(),
+            "create-system".to_string(),
+            id.clone(),
+            name.clone(),
+            "--actor-type".to_string(),
+            actor_type.clone(),
+        ];
+        if let Some(emoji) = &emoji {
+            args.extend(["--emoji".to_string(), emoji.clone()]);
+        }
         let result = controller.create_system_actor(id, name, actor_type, emoji)?;
+        session_log::record(args);
         Ok(result.message)
     }
 
-    /// Create a persona with additional fields
+    // Create a persona with additional fields
     // pub fn create_persona_with_fields(
     //     &mut self,
     //     id: String,
@@ -289,6 +338,7 @@ impl InteractiveRunner {
         use crate::cli::standard::handle_actor_command;
         let command = ActorCommands::Delete { id: id.to_string() };
         handle_actor_command(command)?;
+        session_log::record(["actor", "delete", id]);
         Ok(())
     }
 
@@ -333,16 +383,19 @@ impl InteractiveRunner {
         &mut self,
         actor_id: String,
         fields: std::collections::HashMap<String, String>,
-    ) -> Result<String> {
+    ) -> Result<DisplayResult> {
         let controller = self.ensure_persona_controller()?;
-        let result = controller.update_persona_fields(actor_id, fields)?;
-        Ok(result.message)
+        let result = controller.update_persona_fields(actor_id.clone(), fields)?;
+        if result.success {
+            session_log::note(&format!("updated custom fields of actor {}", actor_id));
+        }
+        Ok(result)
     }
 
     /// Get actor field configuration
     pub fn get_actor_field_config(
         &mut self,
-    ) -> Result<std::collections::HashMap<String, crate::core::CustomFieldConfig>> {
+    ) -> Result<std::collections::HashMap<String, crate::config::PersonaFieldConfig>> {
         let controller = self.ensure_persona_controller()?;
         Ok(controller.get_persona_field_config())
     }
@@ -367,14 +420,16 @@ impl InteractiveRunner {
     /// Update actor name (works for all actor types)
     pub fn update_actor_entity_name(&mut self, actor_id: String, name: String) -> Result<String> {
         let controller = self.ensure_actor_controller()?;
-        let result = controller.update_actor_name(actor_id, name)?;
+        let result = controller.update_actor_name(actor_id.clone(), name.clone())?;
+        session_log::note(&format!("renamed actor {} to '{}'", actor_id, name));
         Ok(result.message)
     }
 
     /// Update actor emoji (works for all actor types)
     pub fn update_actor_entity_emoji(&mut self, actor_id: String, emoji: String) -> Result<String> {
         let controller = self.ensure_actor_controller()?;
-        let result = controller.update_emoji(actor_id, emoji)?;
+        let result = controller.update_emoji(actor_id.clone(), emoji.clone())?;
+        session_log::record(["actor", "update-emoji", actor_id.as_str(), emoji.as_str()]);
         Ok(result.message)
     }
 
@@ -412,6 +467,7 @@ impl InteractiveRunner {
             description,
             priority,
         )?;
+        session_log::note(&format!("edited the basic fields of {}", use_case_id));
         Ok(result.message)
     }
 
@@ -428,6 +484,10 @@ impl InteractiveRunner {
             methodology.to_string(),
             fields,
         )?;
+        session_log::note(&format!(
+            "edited the {} fields of {}",
+            methodology, use_case_id
+        ));
         Ok(result.message)
     }
 
@@ -444,6 +504,10 @@ impl InteractiveRunner {
             methodology.to_string(),
             level.to_string(),
         )?;
+        session_log::note(&format!(
+            "added view {}:{} to {}",
+            methodology, level, use_case_id
+        ));
         Ok(result.message)
     }
 
@@ -455,6 +519,22 @@ impl InteractiveRunner {
     ) -> Result<String> {
         let controller = self.ensure_use_case_controller()?;
         let result = controller.remove_view(use_case_id.to_string(), methodology.to_string())?;
+        session_log::note(&format!(
+            "removed the {} view from {}",
+            methodology, use_case_id
+        ));
+        Ok(result.message)
+    }
+
+    /// Make a view the primary view of a use case
+    pub fn set_primary_view(&mut self, use_case_id: &str, methodology: &str) -> Result<String> {
+        let controller = self.ensure_use_case_controller()?;
+        let result =
+            controller.set_primary_view(use_case_id.to_string(), methodology.to_string())?;
+        session_log::note(&format!(
+            "made the {} view primary for {}",
+            methodology, use_case_id
+        ));
         Ok(result.message)
     }
 
@@ -474,6 +554,36 @@ impl InteractiveRunner {
 
     // ========== Actor Selection Methods ==========
 
+    // ========== Template Customization Methods ==========
+
+    /// Get the use case template of a methodology level for customization
+    pub fn get_template_draft(&mut self, methodology: &str, level: &str) -> Result<TemplateDraft> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.template_draft(methodology, level)
+    }
+
+    /// Render a sample use case with a candidate template
+    pub fn preview_template(
+        &mut self,
+        methodology: &str,
+        level: &str,
+        source: &str,
+    ) -> Result<TemplatePreview> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.preview_template(methodology, level, source)
+    }
+
+    /// Save a candidate template as the project version of the level's template
+    pub fn save_template(
+        &mut self,
+        methodology: &str,
+        level: &str,
+        source: &str,
+    ) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.save_template(methodology, level, source)
+    }
+
     /// Get available actors for selection (personas + system actors)
     ///
     /// # Returns
@@ -0,0 +1,259 @@
+//! # Session Log
+//!
+//! Records the non-interactive equivalent of every change made in interactive
+//! mode, one `mucm ...` command per line, so a session can be reviewed and
+//! re-applied to another project with `mucm replay <log>`.
+//!
+//! Recording only happens after [`start`], which the interactive session calls
+//! on startup. The log is written to
+//! `.config/.mucm/sessions/session-<timestamp>.log` as soon as the first
+//! command is recorded, and rewritten after every command so
+//! an interrupted session keeps what it did. Changes without a CLI equivalent
+//! are recorded as `#` comments, which replay skips.
+
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::config::Config;
+
+/// Directory inside the config directory holding session logs
+const SESSIONS_DIR: &str = "sessions";
+
+/// A session log being recorded
+#[derive(Debug)]
+struct SessionLog {
+    active: bool,
+    path: Option<PathBuf>,
+    lines: Vec<String>,
+    commands: usize,
+}
+
+static SESSION: Mutex<SessionLog> = Mutex::new(SessionLog {
+    active: false,
+    path: None,
+    lines: Vec::new(),
+    commands: 0,
+});
+
+/// Start recording the actions of this process
+pub fn start() {
+    if let Ok(mut session) = SESSION.lock() {
+        session.active = true;
+    }
+}
+
+/// Record the CLI invocation equivalent to an interactive action
+///
+/// `args` are the arguments after `mucm`. Failing to write the log never
+/// fails the action itself; the entry stays in memory for the summary.
+pub fn record<I, S>(args: I)
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let args: Vec<String> = args.into_iter().map(Into::into).collect();
+    append_line(format_command(&args), true);
+}
+
+/// Record a change that has no CLI equivalent, as a comment
+pub fn note(text: &str) {
+    append_line(format!("# {}", text), false);
+}
+
+fn append_line(line: String, command: bool) {
+    let Ok(mut session) = SESSION.lock() else {
+        return;
+    };
+    if !session.active {
+        return;
+    }
+    if session.path.is_none() {
+        let stamp = Local::now().format("%Y%m%d-%H%M%S");
+        let dir = PathBuf::from(Config::CONFIG_DIR).join(SESSIONS_DIR);
+        session.path = std::env::current_dir()
+            .map(|cwd| cwd.join(dir).join(format!("session-{}.log", stamp)))
+            .ok();
+        session.lines.push(format!(
+            "# mucm interactive session started {}",
+            Local::now().format("%Y-%m-%d %H:%M:%S")
+        ));
+    }
+    session.lines.push(line);
+    if command {
+        session.commands += 1;
+    }
+    if let Some(path) = &session.path {
+        let _ = write_log(path, &session.lines);
+    }
+}
+
+fn write_log(path: &Path, lines: &[String]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format!("{}\n", lines.join("\n")))
+        .with_context(|| format!("Failed to write session log {}", path.display()))
+}
+
+/// What a session recorded, for the end-of-session summary
+#[derive(Debug, Clone)]
+pub struct SessionSummary {
+    /// Log file, if the working directory could be determined
+    pub path: Option<PathBuf>,
+    /// Recorded lines (commands and comments), without the header
+    pub lines: Vec<String>,
+    /// Number of replayable commands
+    pub commands: usize,
+}
+
+/// Summary of the recorded session; None when nothing was recorded
+pub fn summary() -> Option<SessionSummary> {
+    let session = SESSION.lock().ok()?;
+    if session.lines.is_empty() {
+        return None;
+    }
+    Some(SessionSummary {
+        path: session.path.clone(),
+        lines: session.lines.iter().skip(1).cloned().collect(),
+        commands: session.commands,
+    })
+}
+
+/// Format arguments as a `mucm` command line, quoting where needed
+pub fn format_command(args: &[String]) -> String {
+    let mut line = String::from("mucm");
+    for arg in args {
+        line.push(' ');
+        line.push_str(&quote(arg));
+    }
+    line
+}
+
+fn quote(arg: &str) -> String {
+    let plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:,=@+%".contains(c));
+    if plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Split one log line into arguments (without the leading `mucm`)
+///
+/// Understands single quotes, double quotes and backslash escapes. Returns
+/// None for blank lines and `#` comments.
+pub fn parse_command(line: &str) -> Result<Option<Vec<String>>> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok(None);
+    }
+
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut chars = trimmed.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_arg = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => bail!("Unterminated single quote"),
+                    }
+                }
+            }
+            '"' => {
+                in_arg = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c) => current.push(c),
+                            None => bail!("Unterminated double quote"),
+                        },
+                        Some(c) => current.push(c),
+                        None => bail!("Unterminated double quote"),
+                    }
+                }
+            }
+            '\\' => {
+                in_arg = true;
+                if let Some(c) = chars.next() {
+                    current.push(c);
+                }
+            }
+            c if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            c => {
+                in_arg = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_arg {
+        args.push(current);
+    }
+
+    if args.first().map(String::as_str) != Some("mucm") {
+        bail!("Expected a mucm command, found '{}'", trimmed);
+    }
+    args.remove(0);
+    Ok(Some(args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_and_parse_round_trip() {
+        let args: Vec<String> = [
+            "create",
+            "User's login",
+            "--category",
+            "Auth",
+            "--views",
+            "feature:normal",
+            "",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+        let line = format_command(&args);
+        assert_eq!(
+            line,
+            "mucm create 'User'\\''s login' --category Auth --views feature:normal ''"
+        );
+        assert_eq!(parse_command(&line).unwrap(), Some(args));
+    }
+
+    #[test]
+    fn test_parse_comments_and_errors() {
+        assert_eq!(parse_command("  # no CLI equivalent").unwrap(), None);
+        assert_eq!(parse_command("").unwrap(), None);
+        assert_eq!(
+            parse_command(r#"mucm precondition add UC-AUT-001 "User \"admin\" exists""#).unwrap(),
+            Some(vec![
+                "precondition".to_string(),
+                "add".to_string(),
+                "UC-AUT-001".to_string(),
+                "User \"admin\" exists".to_string(),
+            ])
+        );
+        assert!(parse_command("rm -rf /").is_err());
+        assert!(parse_command("mucm create 'open").is_err());
+    }
+}
@@ -273,7 +273,7 @@ mod workflow_tests {
         let mut runner = InteractiveRunner::new();
 
         // Create use cases with different methodologies
-        let methodologies = vec!["business", "developer", "feature", "tester"];
+        let methodologies = ["business", "developer", "feature", "tester"];
 
         for (i, methodology) in methodologies.iter().enumerate() {
             let result = runner.create_use_case_with_views(
@@ -298,6 +298,7 @@ mod persona_workflow_tests {
     use crate::config::{Config, ConfigFileManager, StorageBackend};
     use crate::core::RepositoryFactory;
     use serial_test::serial;
+    use std::collections::HashMap;
     use std::{env, fs};
     use tempfile::TempDir;
 
@@ -341,6 +342,7 @@ mod persona_workflow_tests {
             "dev-user".to_string(),
             "Developer User".to_string(),
             "Test Function".to_string(),
+            HashMap::new(),
         );
 
         assert!(
@@ -348,7 +350,7 @@ mod persona_workflow_tests {
             "Failed to create persona: {:?}",
             result.err()
         );
-        let message = result.unwrap();
+        let message = result.unwrap().message;
         assert!(
             message.contains("Created persona"),
             "Message was: {}",
@@ -380,6 +382,7 @@ mod persona_workflow_tests {
             "test-user".to_string(),
             "Test User".to_string(),
             "Test Function".to_string(),
+            HashMap::new(),
         );
 
         assert!(result.is_ok());
@@ -401,6 +404,7 @@ mod persona_workflow_tests {
             "duplicate".to_string(),
             "First User".to_string(),
             "Test Function".to_string(),
+            HashMap::new(),
         );
         assert!(result.is_ok());
 
@@ -410,6 +414,7 @@ mod persona_workflow_tests {
             "duplicate".to_string(),
             "Second User".to_string(),
             "Test Function".to_string(),
+            HashMap::new(),
         );
         assert!(
             result.is_ok(),
@@ -447,6 +452,7 @@ mod persona_workflow_tests {
                 "user1".to_string(),
                 "User One".to_string(),
                 "Test Function".to_string(),
+                HashMap::new(),
             )
             .unwrap();
 
@@ -466,6 +472,7 @@ mod persona_workflow_tests {
                     format!("user{}", i),
                     format!("User {}", i),
                     "Test Function".to_string(),
+                    HashMap::new(),
                 )
                 .unwrap();
         }
@@ -485,6 +492,7 @@ mod persona_workflow_tests {
                 "show-test".to_string(),
                 "Show Test User".to_string(),
                 "Test Function".to_string(),
+                HashMap::new(),
             )
             .unwrap();
 
@@ -503,6 +511,7 @@ mod persona_workflow_tests {
                 "to-delete".to_string(),
                 "Delete Test".to_string(),
                 "Test Function".to_string(),
+                HashMap::new(),
             )
             .unwrap();
 
@@ -538,6 +547,7 @@ mod persona_workflow_tests {
             "cycle-test".to_string(),
             "Cycle Test User".to_string(),
             "Test Function".to_string(),
+            HashMap::new(),
         );
         assert!(result.is_ok());
 
@@ -574,6 +584,7 @@ mod persona_workflow_tests {
                     "toml-user".to_string(),
                     "TOML User".to_string(),
                     "Test Function".to_string(),
+                    HashMap::new(),
                 )
                 .unwrap();
 
@@ -11,6 +11,8 @@ use crossterm::{
 };
 use std::io::stdout;
 
+use super::session_log::SessionSummary;
+
 /// UI utility functions for the interactive mode
 pub struct UI;
 
@@ -142,6 +144,24 @@ impl UI {
         Ok(())
     }
 
+    /// Show what the session recorded and where the log was written
+    pub fn show_session_summary(summary: &SessionSummary) -> Result<()> {
+        Self::show_section_header("Session Summary", "📜")?;
+        for line in &summary.lines {
+            println!("  {}", line);
+        }
+        println!();
+        match &summary.path {
+            Some(path) => Self::show_info(&format!(
+                "{} command(s) recorded in {}\nRe-apply them to another project with: mucm replay {}",
+                summary.commands,
+                path.display(),
+                path.display()
+            )),
+            None => Self::show_info(&format!("{} command(s) recorded", summary.commands)),
+        }
+    }
+
     /// Show an info message
     pub fn show_info(message: &str) -> Result<()> {
         execute!(
@@ -64,7 +64,16 @@ impl ActorWorkflow {
         // Create the actor based on type
         let mut runner = InteractiveRunner::new();
         let result = if actor_type == "Persona" {
-            runner.create_persona_interactive(id.clone(), name, function)?
+            // Personas are created with their custom fields, so required ones get a value
+            let fields = Self::prompt_persona_fields(&mut runner, &HashMap::new())?;
+            let result = runner.create_persona_interactive(id.clone(), name, function, fields)?;
+            if result.success {
+                UI::show_success(&result.message)?;
+            } else {
+                UI::show_error(&result.message)?;
+            }
+            UI::pause_for_input()?;
+            return Ok(());
         } else {
             // For system actors, ask for emoji (optional)
             let default_emoji = match actor_type {
@@ -322,10 +331,7 @@ impl ActorWorkflow {
     ) -> Result<()> {
         UI::show_section_header("Edit Custom Fields", "🎯")?;
 
-        // Get field configuration
-        let field_config = runner.get_actor_field_config()?;
-
-        if field_config.is_empty() {
+        if runner.get_actor_field_config()?.is_empty() {
             UI::show_info("No custom fields defined in project configuration.")?;
             UI::pause_for_input()?;
             return Ok(());
@@ -333,29 +339,7 @@ impl ActorWorkflow {
 
         // Get current values
         let current_values = runner.get_actor_field_values(actor_id)?;
-
-        // Prompt for each field
-        let mut updated_fields = HashMap::new();
-
-        UI::show_info("Edit fields (smart input based on field type):")?;
-
-        for (field_name, field_def) in &field_config {
-            let current_json = current_values.get(field_name);
-            let help_msg = field_def
-                .description
-                .clone()
-                .unwrap_or_else(|| format!("{} field", field_def.field_type));
-
-            // Use FieldHelpers to handle different field types automatically
-            if let Some(new_value) = FieldHelpers::edit_by_type(
-                &field_def.field_type,
-                &field_name,
-                current_json,
-                &help_msg,
-            )? {
-                updated_fields.insert(field_name.clone(), new_value);
-            }
-        }
+        let updated_fields = Self::prompt_persona_fields(runner, &current_values)?;
 
         if updated_fields.is_empty() {
             UI::show_info("No changes made.")?;
@@ -364,11 +348,43 @@ impl ActorWorkflow {
 
         let result = runner.update_actor_fields(actor_id.to_string(), updated_fields)?;
 
-        UI::show_success(&result)?;
+        if result.success {
+            UI::show_success(&result.message)?;
+        } else {
+            UI::show_error(&result.message)?;
+        }
         UI::pause_for_input()?;
         Ok(())
     }
 
+    /// Prompt for each configured persona field, in name order
+    ///
+    /// Returns the fields whose value changed, as text.
+    fn prompt_persona_fields(
+        runner: &mut InteractiveRunner,
+        current_values: &HashMap<String, serde_json::Value>,
+    ) -> Result<HashMap<String, String>> {
+        let field_config = runner.get_actor_field_config()?;
+        let mut names: Vec<&String> = field_config.keys().collect();
+        names.sort();
+
+        let mut updated_fields = HashMap::new();
+        if !names.is_empty() {
+            UI::show_info("Edit fields (smart input based on field type):")?;
+        }
+        for name in names {
+            // FieldHelpers picks the input from the field's type and options
+            if let Some(new_value) = FieldHelpers::edit_persona_field(
+                name,
+                &field_config[name],
+                current_values.get(name),
+            )? {
+                updated_fields.insert(name.clone(), new_value);
+            }
+        }
+        Ok(updated_fields)
+    }
+
     /// Interactive actor management menu
     pub fn manage_actors() -> Result<()> {
         UI::clear_screen()?;
@@ -6,6 +6,7 @@
 use anyhow::Result;
 use inquire::{Confirm, Select, Text};
 
+use crate::cli::interactive::session_log;
 use crate::cli::interactive::ui::UI;
 use crate::controller::UseCaseController;
 
@@ -256,9 +257,10 @@ impl ConditionsWorkflow {
         };
 
         let mut controller = UseCaseController::new()?;
-        let result = controller.add_precondition(use_case_id.to_string(), condition_str)?;
+        let result = controller.add_precondition(use_case_id.to_string(), condition_str.clone())?;
 
         if result.success {
+            session_log::record(["precondition", "add", use_case_id, condition_str.as_str()]);
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -325,9 +327,14 @@ impl ConditionsWorkflow {
         }
 
         // Update precondition
-        let result = controller.edit_precondition(use_case_id.to_string(), index, new_text)?;
+        let result =
+            controller.edit_precondition(use_case_id.to_string(), index, new_text.clone())?;
 
         if result.success {
+            session_log::note(&format!(
+                "edited precondition {} of {}: '{}'",
+                index, use_case_id, new_text
+            ));
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -394,6 +401,12 @@ impl ConditionsWorkflow {
         let result = controller.remove_precondition(use_case_id.to_string(), index)?;
 
         if result.success {
+            session_log::record([
+                "precondition".to_string(),
+                "remove".to_string(),
+                use_case_id.to_string(),
+                index.to_string(),
+            ]);
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -459,6 +472,10 @@ impl ConditionsWorkflow {
             controller.reorder_preconditions(use_case_id.to_string(), from_index, to_index)?;
 
         if result.success {
+            session_log::note(&format!(
+                "moved precondition {} of {} to position {}",
+                from_index, use_case_id, to_index
+            ));
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -488,6 +505,7 @@ impl ConditionsWorkflow {
         let result = controller.clear_preconditions(use_case_id.to_string())?;
 
         if result.success {
+            session_log::note(&format!("cleared all preconditions of {}", use_case_id));
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -573,9 +591,11 @@ impl ConditionsWorkflow {
         };
 
         let mut controller = UseCaseController::new()?;
-        let result = controller.add_postcondition(use_case_id.to_string(), condition_str)?;
+        let result =
+            controller.add_postcondition(use_case_id.to_string(), condition_str.clone())?;
 
         if result.success {
+            session_log::record(["postcondition", "add", use_case_id, condition_str.as_str()]);
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -642,9 +662,14 @@ impl ConditionsWorkflow {
         }
 
         // Update postcondition
-        let result = controller.edit_postcondition(use_case_id.to_string(), index, new_text)?;
+        let result =
+            controller.edit_postcondition(use_case_id.to_string(), index, new_text.clone())?;
 
         if result.success {
+            session_log::note(&format!(
+                "edited postcondition {} of {}: '{}'",
+                index, use_case_id, new_text
+            ));
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -714,6 +739,12 @@ impl ConditionsWorkflow {
         let result = controller.remove_postcondition(use_case_id.to_string(), index)?;
 
         if result.success {
+            session_log::record([
+                "postcondition".to_string(),
+                "remove".to_string(),
+                use_case_id.to_string(),
+                index.to_string(),
+            ]);
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -779,6 +810,10 @@ impl ConditionsWorkflow {
             controller.reorder_postconditions(use_case_id.to_string(), from_index, to_index)?;
 
         if result.success {
+            session_log::note(&format!(
+                "moved postcondition {} of {} to position {}",
+                from_index, use_case_id, to_index
+            ));
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -808,6 +843,7 @@ impl ConditionsWorkflow {
         let result = controller.clear_postconditions(use_case_id.to_string())?;
 
         if result.success {
+            session_log::note(&format!("cleared all postconditions of {}", use_case_id));
             UI::show_success(&result.message)?;
         } else {
             UI::show_error(&result.message)?;
@@ -238,6 +238,7 @@ impl Initialization {
 }
 
 /// Show configuration summary
+#[allow(clippy::too_many_arguments)]
 fn show_configuration_summary(
     language: &Option<String>,
     selected_methodologies: &[String],
@@ -264,6 +265,7 @@ fn show_configuration_summary(
 }
 
 /// Create project configuration with directories
+#[allow(clippy::too_many_arguments)]
 fn create_config_with_directories(
     runner: &mut InteractiveRunner,
     language: Option<String>,
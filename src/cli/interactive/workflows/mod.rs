@@ -9,5 +9,7 @@ pub mod conditions;
 pub mod config;
 pub mod initialization;
 pub mod methodology;
+pub mod project;
 pub mod scenario;
+pub mod template;
 pub mod use_case;
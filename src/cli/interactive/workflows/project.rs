@@ -0,0 +1,68 @@
+//! # Project Workflow
+//!
+//! Interactive project switching for users working across several repositories.
+//! Lists the recently opened MUCM projects (kept in the user-level config) and
+//! switches the session to another project without restarting.
+
+use anyhow::Result;
+use inquire::{Select, Text};
+use std::path::PathBuf;
+
+use crate::cli::interactive::{session_log, ui::UI};
+use crate::cli::standard::CliRunner;
+
+const OTHER_PROJECT: &str = "📂 Other project...";
+const BACK: &str = "Back";
+
+/// Project workflow handler
+pub struct ProjectWorkflow;
+
+impl ProjectWorkflow {
+    /// Interactive project switcher
+    ///
+    /// Offers the recent projects (except the current one) and a path prompt,
+    /// then switches the runner to the chosen project.
+    pub fn switch_project(runner: &mut CliRunner) -> Result<()> {
+        UI::show_section_header("Switch Project", "🔀")?;
+
+        let current = std::env::current_dir()?.canonicalize()?;
+        let recent: Vec<PathBuf> = CliRunner::recent_projects()?
+            .into_iter()
+            .filter(|project| *project != current && project.is_dir())
+            .collect();
+
+        let mut options: Vec<String> = recent
+            .iter()
+            .map(|project| project.display().to_string())
+            .collect();
+        options.push(OTHER_PROJECT.to_string());
+        options.push(BACK.to_string());
+
+        let choice = Select::new("Switch to which project?", options)
+            .with_help_message(&format!("Current project: {}", current.display()))
+            .prompt()?;
+
+        let root = match choice.as_str() {
+            BACK => return Ok(()),
+            OTHER_PROJECT => {
+                let path = Text::new("Project root directory:")
+                    .with_help_message("Directory containing .config/.mucm")
+                    .prompt()?;
+                if path.trim().is_empty() {
+                    return Ok(());
+                }
+                PathBuf::from(path.trim())
+            }
+            _ => PathBuf::from(&choice),
+        };
+
+        let context = runner.switch_project(&root)?;
+        session_log::note(&format!("switched to project {}", root.display()));
+        UI::show_success(&format!(
+            "Switched to project '{}' ({})",
+            context.config().project.name,
+            root.display()
+        ))?;
+        Ok(())
+    }
+}
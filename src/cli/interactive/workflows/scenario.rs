@@ -6,7 +6,8 @@
 use anyhow::Result;
 use inquire::{Confirm, Select, Text};
 
-use crate::cli::interactive::{runner::InteractiveRunner, ui::UI};
+use crate::cli::interactive::bulk_entry::{parse_scenario_outline, ScenarioDraft};
+use crate::cli::interactive::{runner::InteractiveRunner, session_log, ui::UI};
 use crate::controller::ScenarioController;
 
 /// Scenario workflow handler
@@ -44,6 +45,7 @@ impl ScenarioWorkflow {
             // Show action menu
             let actions = vec![
                 "Create new scenario",
+                "Bulk add scenarios",
                 "Edit scenario",
                 "Delete scenario",
                 "Back to use case menu",
@@ -55,6 +57,9 @@ impl ScenarioWorkflow {
                 "Create new scenario" => {
                     Self::create_scenario(use_case_id)?;
                 }
+                "Bulk add scenarios" => {
+                    Self::bulk_add_scenarios(use_case_id)?;
+                }
                 "Edit scenario" => {
                     Self::edit_scenario(use_case_id)?;
                 }
@@ -74,6 +79,173 @@ impl ScenarioWorkflow {
         Self::create_scenario(use_case_id)
     }
 
+    /// Create several scenarios and their steps from one pasted outline
+    ///
+    /// See `bulk_entry` for the outline format. The parsed scenarios are
+    /// previewed and only created after confirmation.
+    fn bulk_add_scenarios(use_case_id: &str) -> Result<()> {
+        UI::show_section_header("Bulk Add Scenarios", "📋")?;
+        println!("  Type or paste scenarios with their numbered steps, for example:\n");
+        println!("    User logs in [main]");
+        println!("    1. User enters email and password");
+        println!("    2. System validates the credentials");
+        println!("    Wrong password [exception]");
+        println!("    1. System shows an error\n");
+        println!("  Finish with a line containing only '.' (or Ctrl-D).\n");
+
+        let text = Self::read_multiline()?;
+        let drafts = match parse_scenario_outline(&text) {
+            Ok(drafts) => drafts,
+            Err(e) => {
+                UI::show_error(&e.to_string())?;
+                return Ok(());
+            }
+        };
+        if drafts.is_empty() {
+            UI::show_info("Nothing to add.")?;
+            UI::pause_for_input()?;
+            return Ok(());
+        }
+
+        println!("\n  Parsed scenarios:");
+        for draft in &drafts {
+            println!(
+                "    • {} [{}] ({} steps)",
+                draft.title,
+                draft.scenario_type,
+                draft.steps.len()
+            );
+        }
+        let step_count: usize = drafts.iter().map(|draft| draft.steps.len()).sum();
+        let confirm = Confirm::new(&format!(
+            "Create {} scenario(s) with {} step(s)?",
+            drafts.len(),
+            step_count
+        ))
+        .with_default(true)
+        .prompt()?;
+        if !confirm {
+            UI::show_info("Bulk entry cancelled.")?;
+            UI::pause_for_input()?;
+            return Ok(());
+        }
+
+        let mut controller = ScenarioController::new()?;
+        for draft in &drafts {
+            Self::create_from_draft(&mut controller, use_case_id, draft)?;
+        }
+        UI::show_success(&format!(
+            "✅ Added {} scenario(s) with {} step(s) to {}",
+            drafts.len(),
+            step_count,
+            use_case_id
+        ))?;
+        UI::pause_for_input()?;
+        Ok(())
+    }
+
+    /// Read lines from stdin until a line containing only '.' or end of input
+    fn read_multiline() -> Result<String> {
+        use std::io::BufRead;
+
+        let mut text = String::new();
+        for line in std::io::stdin().lock().lines() {
+            let line = line?;
+            if line.trim() == "." {
+                break;
+            }
+            text.push_str(&line);
+            text.push('\n');
+        }
+        Ok(text)
+    }
+
+    /// Create one parsed scenario and append its steps
+    fn create_from_draft(
+        controller: &mut ScenarioController,
+        use_case_id: &str,
+        draft: &ScenarioDraft,
+    ) -> Result<()> {
+        let result = controller.create_scenario(
+            use_case_id.to_string(),
+            draft.title.clone(),
+            draft.scenario_type.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        session_log::record([
+            "use-case",
+            "scenario",
+            "add",
+            use_case_id,
+            draft.title.as_str(),
+            "--scenario-type",
+            draft.scenario_type.as_str(),
+        ]);
+
+        // Format: "✅ Created scenario: UC-XXX-S## - Title"
+        let scenario_id = result
+            .message
+            .split(':')
+            .nth(1)
+            .and_then(|part| part.trim().split(" - ").next())
+            .map(|id| id.trim().to_string())
+            .unwrap_or_default();
+        if scenario_id.is_empty() {
+            anyhow::bail!("Could not determine the ID of scenario '{}'", draft.title);
+        }
+
+        for step in &draft.steps {
+            controller.add_step(
+                use_case_id.to_string(),
+                scenario_id.clone(),
+                step.clone(),
+                None,
+                None,
+                None,
+            )?;
+            Self::record_step_add(use_case_id, &scenario_id, step, None, false);
+        }
+        println!("  ✓ {} - {}", scenario_id, draft.title);
+        Ok(())
+    }
+
+    /// Record an added step in the session log
+    ///
+    /// `mucm use-case scenario step add` has no actor options, so steps with
+    /// actors are noted rather than recorded as a command.
+    fn record_step_add(
+        use_case_id: &str,
+        scenario_id: &str,
+        description: &str,
+        order: Option<u32>,
+        has_actors: bool,
+    ) {
+        if has_actors {
+            session_log::note(&format!(
+                "added step '{}' with actors to {}",
+                description, scenario_id
+            ));
+            return;
+        }
+        let mut args = vec![
+            "use-case".to_string(),
+            "scenario".to_string(),
+            "step".to_string(),
+            "add".to_string(),
+            use_case_id.to_string(),
+            scenario_id.to_string(),
+            description.to_string(),
+        ];
+        if let Some(order) = order {
+            args.extend(["--order".to_string(), order.to_string()]);
+        }
+        session_log::record(args);
+    }
+
     /// Create a new scenario interactively
     fn create_scenario(use_case_id: &str) -> Result<()> {
         UI::show_section_header("Create Scenario", "➕")?;
@@ -99,17 +271,38 @@ impl ScenarioWorkflow {
         // Note: Actors are automatically derived from scenario steps, not manually assigned
         // Create the scenario
         let mut controller = ScenarioController::new()?;
+        let has_conditions = preconditions.is_some() || postconditions.is_some();
         let result = controller.create_scenario(
             use_case_id.to_string(),
             title.clone(),
             scenario_type.to_string(),
-            description,
+            description.clone(),
             None, // persona_id removed from interactive workflow
             preconditions,
             postconditions,
+            None,
         )?;
 
         UI::show_success(&result.message)?;
+        let mut args = vec![
+            "use-case".to_string(),
+            "scenario".to_string(),
+            "add".to_string(),
+            use_case_id.to_string(),
+            title.clone(),
+            "--scenario-type".to_string(),
+            scenario_type.to_string(),
+        ];
+        if let Some(description) = description.filter(|d| !d.trim().is_empty()) {
+            args.extend(["--description".to_string(), description]);
+        }
+        session_log::record(args);
+        if has_conditions {
+            session_log::note(&format!(
+                "scenario conditions of '{}' in {} were set interactively",
+                title, use_case_id
+            ));
+        }
 
         // Extract scenario_id from success message (format: "✅ Created scenario: UC-XXX-S## - Title")
         let scenario_id = result
@@ -148,14 +341,16 @@ impl ScenarioWorkflow {
                     )
                     .prompt()?;
 
+                let has_actors = actor.is_some() || receiver.is_some();
                 let step_result = controller.add_step(
                     use_case_id.to_string(),
                     scenario_id.to_string(),
-                    description,
+                    description.clone(),
                     None,
                     actor,
                     receiver,
                 )?;
+                Self::record_step_add(use_case_id, scenario_id, &description, None, has_actors);
 
                 UI::show_success(&step_result.message)?;
 
@@ -331,11 +526,20 @@ impl ScenarioWorkflow {
                     controller.edit_scenario(
                         use_case_id.to_string(),
                         scenario_id.to_string(),
-                        Some(new_title),
+                        Some(new_title.clone()),
                         None,
                         None,
                         None,
                     )?;
+                    session_log::record([
+                        "use-case".to_string(),
+                        "scenario".to_string(),
+                        "edit".to_string(),
+                        use_case_id.to_string(),
+                        scenario_id.to_string(),
+                        "--title".to_string(),
+                        new_title.to_string(),
+                    ]);
 
                     UI::show_success("✓ Title updated")?;
                 }
@@ -348,10 +552,19 @@ impl ScenarioWorkflow {
                         use_case_id.to_string(),
                         scenario_id.to_string(),
                         None,
-                        Some(new_desc),
+                        Some(new_desc.clone()),
                         None,
                         None,
                     )?;
+                    session_log::record([
+                        "use-case".to_string(),
+                        "scenario".to_string(),
+                        "edit".to_string(),
+                        use_case_id.to_string(),
+                        scenario_id.to_string(),
+                        "--description".to_string(),
+                        new_desc.to_string(),
+                    ]);
 
                     UI::show_success("✓ Description updated")?;
                 }
@@ -367,6 +580,15 @@ impl ScenarioWorkflow {
                         Some(new_type.to_string()),
                         None,
                     )?;
+                    session_log::record([
+                        "use-case".to_string(),
+                        "scenario".to_string(),
+                        "edit".to_string(),
+                        use_case_id.to_string(),
+                        scenario_id.to_string(),
+                        "--scenario-type".to_string(),
+                        new_type.to_string(),
+                    ]);
 
                     UI::show_success("✓ Type updated")?;
                 }
@@ -383,6 +605,15 @@ impl ScenarioWorkflow {
                         None,
                         Some(new_status.to_string()),
                     )?;
+                    session_log::record([
+                        "use-case".to_string(),
+                        "scenario".to_string(),
+                        "edit".to_string(),
+                        use_case_id.to_string(),
+                        scenario_id.to_string(),
+                        "--status".to_string(),
+                        new_status.to_string(),
+                    ]);
 
                     UI::show_success("✓ Status updated")?;
                 }
@@ -440,6 +671,7 @@ impl ScenarioWorkflow {
         if confirm == "Yes" {
             let result =
                 controller.delete_scenario(use_case_id.to_string(), scenario_id.to_string())?;
+            session_log::record(["use-case", "scenario", "delete", use_case_id, scenario_id]);
             UI::show_success(&result.message)?;
         } else {
             println!("\n✓ Deletion cancelled.");
@@ -509,14 +741,16 @@ impl ScenarioWorkflow {
                         )
                         .prompt()?;
 
+                    let has_actors = actor.is_some() || receiver.is_some();
                     let result = controller.add_step(
                         use_case_id.to_string(),
                         scenario_id.to_string(),
-                        description,
+                        description.clone(),
                         None,
                         actor,
                         receiver,
                     )?;
+                    Self::record_step_add(use_case_id, scenario_id, &description, None, has_actors);
 
                     UI::show_success(&result.message)?;
                 }
@@ -543,8 +777,18 @@ impl ScenarioWorkflow {
                         use_case_id.to_string(),
                         scenario_id.to_string(),
                         step_order,
-                        new_description,
+                        new_description.clone(),
                     )?;
+                    session_log::record([
+                        "use-case".to_string(),
+                        "scenario".to_string(),
+                        "step".to_string(),
+                        "edit".to_string(),
+                        use_case_id.to_string(),
+                        scenario_id.to_string(),
+                        step_order.to_string(),
+                        new_description,
+                    ]);
 
                     UI::show_success(&result.message)?;
                 }
@@ -598,14 +842,22 @@ impl ScenarioWorkflow {
                         .prompt()?;
 
                     // Add step at specified position
+                    let has_actors = actor.is_some() || receiver.is_some();
                     let result = controller.add_step(
                         use_case_id.to_string(),
                         scenario_id.to_string(),
-                        description,
+                        description.clone(),
                         Some(insert_order),
                         actor,
                         receiver,
                     )?;
+                    Self::record_step_add(
+                        use_case_id,
+                        scenario_id,
+                        &description,
+                        Some(insert_order),
+                        has_actors,
+                    );
 
                     UI::show_success(&result.message)?;
                 }
@@ -637,6 +889,15 @@ impl ScenarioWorkflow {
                         scenario_id.to_string(),
                         step_order,
                     )?;
+                    session_log::record([
+                        "use-case".to_string(),
+                        "scenario".to_string(),
+                        "step".to_string(),
+                        "remove".to_string(),
+                        use_case_id.to_string(),
+                        scenario_id.to_string(),
+                        step_order.to_string(),
+                    ]);
 
                     UI::show_success(&result.message)?;
                 }
@@ -730,6 +991,10 @@ impl ScenarioWorkflow {
                         scenario_id.to_string(),
                         reorderings,
                     )?;
+                    session_log::note(&format!(
+                        "moved step {} of {} to position {}",
+                        step_order, scenario_id, new_order
+                    ));
 
                     UI::show_success(&result.message)?;
                 }
@@ -794,8 +1059,12 @@ impl ScenarioWorkflow {
                     let result = controller.add_precondition(
                         use_case_id.to_string(),
                         scenario_id.to_string(),
-                        condition,
+                        condition.clone(),
                     )?;
+                    session_log::note(&format!(
+                        "added precondition '{}' to scenario {}",
+                        condition, scenario_id
+                    ));
 
                     UI::show_success(&result.message)?;
                 }
@@ -830,8 +1099,12 @@ impl ScenarioWorkflow {
                     let result = controller.remove_precondition(
                         use_case_id.to_string(),
                         scenario_id.to_string(),
-                        condition_text,
+                        condition_text.clone(),
                     )?;
+                    session_log::note(&format!(
+                        "removed precondition '{}' from scenario {}",
+                        condition_text, scenario_id
+                    ));
 
                     UI::show_success(&result.message)?;
                 }
@@ -845,8 +1118,12 @@ impl ScenarioWorkflow {
                     let result = controller.add_postcondition(
                         use_case_id.to_string(),
                         scenario_id.to_string(),
-                        condition,
+                        condition.clone(),
                     )?;
+                    session_log::note(&format!(
+                        "added postcondition '{}' to scenario {}",
+                        condition, scenario_id
+                    ));
 
                     UI::show_success(&result.message)?;
                 }
@@ -881,8 +1158,12 @@ impl ScenarioWorkflow {
                     let result = controller.remove_postcondition(
                         use_case_id.to_string(),
                         scenario_id.to_string(),
-                        condition_text,
+                        condition_text.clone(),
                     )?;
+                    session_log::note(&format!(
+                        "removed postcondition '{}' from scenario {}",
+                        condition_text, scenario_id
+                    ));
 
                     UI::show_success(&result.message)?;
                 }
@@ -0,0 +1,146 @@
+//! # Template Workflow
+//!
+//! Interactive customization of the use case template of a methodology level.
+//! Copies the template to the project, lists the variables it can use, opens it
+//! in the user's editor and previews it on a sample use case before saving.
+
+use anyhow::{Context, Result};
+use inquire::Select;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::cli::interactive::{runner::InteractiveRunner, ui::UI};
+
+/// Template customization workflow handler
+pub struct TemplateWorkflow;
+
+impl TemplateWorkflow {
+    /// Walk the user through customizing the template of a methodology level
+    pub fn customize_template() -> Result<()> {
+        UI::show_section_header("Template Customization", "🎨")?;
+
+        let mut runner = InteractiveRunner::new();
+
+        let methodologies: Vec<String> = runner
+            .get_installed_methodologies()?
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        if methodologies.is_empty() {
+            UI::show_warning("No methodologies installed.")?;
+            return Ok(());
+        }
+        let methodology = Select::new("Select methodology:", methodologies).prompt()?;
+
+        let levels: Vec<String> = runner
+            .get_methodology_levels(&methodology)?
+            .into_iter()
+            .map(|level| level.name.to_lowercase())
+            .collect();
+        let level = Select::new("Select level:", levels).prompt()?;
+
+        let draft = runner.get_template_draft(&methodology, &level)?;
+        if draft.overridden {
+            UI::show_info(&format!(
+                "Editing the project template {}",
+                draft.path.display()
+            ))?;
+        } else {
+            UI::show_info(&format!(
+                "The installed template will be copied to {}",
+                draft.path.display()
+            ))?;
+        }
+
+        match runner.preview_template(&methodology, &level, &draft.source) {
+            Ok(preview) => {
+                println!("\n  Variables available to the template:");
+                for (path, kind) in &preview.variables {
+                    println!("    {:<40} {}", path, kind);
+                }
+                println!();
+            }
+            Err(e) => UI::show_warning(&format!("The current template does not render: {}", e))?,
+        }
+
+        let draft_file = std::env::temp_dir().join(format!(
+            "mucm-{}-{}-{}.hbs",
+            methodology,
+            level,
+            std::process::id()
+        ));
+        fs::write(&draft_file, &draft.source)
+            .with_context(|| format!("Failed to write {}", draft_file.display()))?;
+
+        let result = Self::edit_loop(&mut runner, &methodology, &level, &draft_file);
+        let _ = fs::remove_file(&draft_file);
+        result
+    }
+
+    /// Edit, preview and decide until the template is saved or discarded
+    fn edit_loop(
+        runner: &mut InteractiveRunner,
+        methodology: &str,
+        level: &str,
+        draft_file: &Path,
+    ) -> Result<()> {
+        loop {
+            Self::open_editor(draft_file)?;
+            let source = fs::read_to_string(draft_file)
+                .with_context(|| format!("Failed to read {}", draft_file.display()))?;
+
+            let renders = match runner.preview_template(methodology, level, &source) {
+                Ok(preview) => {
+                    UI::show_section_header(&format!("Preview with {}", preview.sample_id), "👀")?;
+                    println!("{}", preview.output);
+                    true
+                }
+                Err(e) => {
+                    UI::show_error(&format!("Template does not render: {}", e))?;
+                    false
+                }
+            };
+
+            let mut options = Vec::new();
+            if renders {
+                options.push("Save");
+            }
+            options.extend(["Edit again", "Discard"]);
+
+            match Select::new("What would you like to do?", options).prompt()? {
+                "Save" => {
+                    let result = runner.save_template(methodology, level, &source)?;
+                    if result.success {
+                        UI::show_success(&result.message)?;
+                        return Ok(());
+                    }
+                    UI::show_error(&result.message)?;
+                }
+                "Discard" => {
+                    UI::show_info("Template left unchanged")?;
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Open a file in $VISUAL or $EDITOR, falling back to vi
+    fn open_editor(path: &Path) -> Result<()> {
+        let editor = std::env::var("VISUAL")
+            .or_else(|_| std::env::var("EDITOR"))
+            .unwrap_or_else(|_| "vi".to_string());
+        let mut parts = editor.split_whitespace();
+        let program = parts.next().unwrap_or("vi");
+        let status = Command::new(program)
+            .args(parts)
+            .arg(path)
+            .status()
+            .with_context(|| format!("Failed to start editor '{}'", editor))?;
+        if !status.success() {
+            anyhow::bail!("Editor '{}' exited with {}", editor, status);
+        }
+        Ok(())
+    }
+}
@@ -7,7 +7,9 @@ use anyhow::{Context, Result};
 use inquire::{Confirm, Select, Text};
 use std::collections::HashMap;
 
-use crate::cli::interactive::{field_helpers::FieldHelpers, runner::InteractiveRunner, ui::UI};
+use crate::cli::interactive::{
+    field_helpers::FieldHelpers, runner::InteractiveRunner, session_log, ui::UI,
+};
 
 /// Use case workflow handler
 pub struct UseCaseWorkflow;
@@ -176,7 +178,7 @@ impl UseCaseWorkflow {
             for methodology in &field.methodologies {
                 fields_by_methodology
                     .entry(methodology.clone())
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(field);
             }
         }
@@ -240,15 +242,18 @@ impl UseCaseWorkflow {
                                 }
                             }
 
-                            if items.is_empty() && field.required {
-                                None // Will be handled by required field logic below
-                            } else if items.is_empty() {
+                            if items.is_empty() {
+                                // A required field is handled by the logic below
                                 None
                             } else {
                                 // Join items with newlines for array storage
                                 Some(items.join("\n"))
                             }
                         }
+                        "table" => {
+                            // For table fields, fill in a grid row by row
+                            FieldHelpers::edit_table(&field.label, &field.columns, None)?
+                        }
                         "number" => {
                             // For number fields, validate input
                             loop {
@@ -348,7 +353,14 @@ impl UseCaseWorkflow {
                             break;
                         }
 
-                        uc_controller.add_precondition(use_case_id.clone(), condition_text)?;
+                        uc_controller
+                            .add_precondition(use_case_id.clone(), condition_text.clone())?;
+                        session_log::record([
+                            "precondition",
+                            "add",
+                            use_case_id.as_str(),
+                            condition_text.as_str(),
+                        ]);
 
                         let add_more = Confirm::new("Add another precondition?")
                             .with_default(true)
@@ -374,7 +386,14 @@ impl UseCaseWorkflow {
                             break;
                         }
 
-                        uc_controller.add_postcondition(use_case_id.clone(), condition_text)?;
+                        uc_controller
+                            .add_postcondition(use_case_id.clone(), condition_text.clone())?;
+                        session_log::record([
+                            "postcondition",
+                            "add",
+                            use_case_id.as_str(),
+                            condition_text.as_str(),
+                        ]);
 
                         let add_more = Confirm::new("Add another postcondition?")
                             .with_default(true)
@@ -533,7 +552,13 @@ impl UseCaseWorkflow {
             let mut uc_controller = UseCaseController::new()?;
 
             for condition_text in preconditions {
-                uc_controller.add_precondition(use_case_id.clone(), condition_text)?;
+                uc_controller.add_precondition(use_case_id.clone(), condition_text.clone())?;
+                session_log::record([
+                    "precondition",
+                    "add",
+                    use_case_id.as_str(),
+                    condition_text.as_str(),
+                ]);
             }
         }
 
@@ -543,7 +568,13 @@ impl UseCaseWorkflow {
             let mut uc_controller = UseCaseController::new()?;
 
             for condition_text in postconditions {
-                uc_controller.add_postcondition(use_case_id.clone(), condition_text)?;
+                uc_controller.add_postcondition(use_case_id.clone(), condition_text.clone())?;
+                session_log::record([
+                    "postcondition",
+                    "add",
+                    use_case_id.as_str(),
+                    condition_text.as_str(),
+                ]);
             }
         }
 
@@ -799,12 +830,17 @@ impl UseCaseWorkflow {
             let help_msg = field_def.description.clone().unwrap_or_default();
 
             // Use FieldHelpers to handle different field types automatically
-            if let Some(new_value) = FieldHelpers::edit_by_type(
-                &field_def.field_type,
-                &field_def.label,
-                current_json,
-                &help_msg,
-            )? {
+            let new_value = if field_def.field_type == "table" {
+                FieldHelpers::edit_table(&field_def.label, &field_def.columns, current_json)?
+            } else {
+                FieldHelpers::edit_by_type(
+                    &field_def.field_type,
+                    &field_def.label,
+                    current_json,
+                    &help_msg,
+                )?
+            };
+            if let Some(new_value) = new_value {
                 updated_fields.insert(field_name.clone(), new_value);
             }
         }
@@ -833,14 +869,15 @@ impl UseCaseWorkflow {
         UI::show_info("Current views:")?;
         for view in &use_case.views {
             println!(
-                "  • {}:{} {}",
+                "  • {}:{} {}{}",
                 view.methodology,
                 view.level,
-                if view.enabled { "" } else { "(disabled)" }
+                if view.enabled { "" } else { "(disabled)" },
+                if view.primary { "(primary)" } else { "" }
             );
         }
 
-        let options = vec!["Add New View", "Remove View", "Back"];
+        let options = vec!["Add New View", "Remove View", "Set Primary View", "Back"];
 
         let choice = Select::new("What would you like to do?", options).prompt()?;
 
@@ -947,6 +984,23 @@ impl UseCaseWorkflow {
 
                 UI::pause_for_input()?;
             }
+            "Set Primary View" => {
+                let view_options: Vec<String> = use_case
+                    .enabled_views()
+                    .map(|v| format!("{}:{}", v.methodology, v.level))
+                    .collect();
+
+                let selected = Select::new(
+                    &format!("Select the view to generate as {}.md:", use_case_id),
+                    view_options,
+                )
+                .prompt()?;
+
+                let methodology = selected.split(':').next().context("Invalid view format")?;
+                let result = runner.set_primary_view(use_case_id, methodology)?;
+                UI::show_success(&result)?;
+                UI::pause_for_input()?;
+            }
             _ => {}
         }
 
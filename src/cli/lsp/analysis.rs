@@ -0,0 +1,476 @@
+//! Document analysis for the language server.
+//!
+//! All functions here are pure: they take document text plus the loaded use
+//! cases and return LSP-shaped JSON values, which keeps them easy to test.
+
+use chrono::{NaiveDate, Utc};
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::core::utils::suggest_alternatives;
+use crate::core::{
+    ActorEntity, ScenarioType, Status, TomlUseCaseRepository, UseCase, ValidationIssue,
+};
+
+/// LSP diagnostic severities
+const SEVERITY_ERROR: u8 = 1;
+const SEVERITY_WARNING: u8 = 2;
+
+/// LSP completion item kinds
+const KIND_VALUE: u8 = 12;
+const KIND_REFERENCE: u8 = 18;
+const KIND_ENUM_MEMBER: u8 = 20;
+
+/// What the analysis knows about the project the documents belong to
+pub struct ProjectIndex {
+    /// Use cases loaded from the project (empty outside a MUCM project)
+    pub use_cases: Vec<UseCase>,
+    /// Personas, offered as completions
+    pub personas: Vec<ActorEntity>,
+    /// `project.id_prefix`, the first part of every use case ID
+    pub id_prefix: String,
+}
+
+impl Default for ProjectIndex {
+    fn default() -> Self {
+        Self {
+            use_cases: Vec::new(),
+            personas: Vec::new(),
+            id_prefix: "UC".to_string(),
+        }
+    }
+}
+
+impl ProjectIndex {
+    /// Use case and scenario IDs with the project's prefix
+    fn id_regex(&self) -> Regex {
+        Regex::new(&format!(
+            r"\b{}-[A-Z0-9]+-\d+(?:-S\d+)?\b",
+            regex::escape(&self.id_prefix)
+        ))
+        .expect("valid use case id regex")
+    }
+
+    /// Whether `uri` is a use case data file (`<ID>.toml`)
+    pub fn is_use_case_file(&self, uri: &str) -> bool {
+        uri.rsplit('/')
+            .next()
+            .and_then(|name| name.strip_suffix(".toml"))
+            .is_some_and(|stem| {
+                self.id_regex()
+                    .find(stem)
+                    .is_some_and(|m| m.as_str() == stem)
+            })
+    }
+}
+
+/// Convert a byte offset into an LSP (line, character) position
+fn position_at(text: &str, offset: usize) -> Value {
+    let offset = offset.min(text.len());
+    let before = &text[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let character = before[line_start..].encode_utf16().count();
+    json!({ "line": line, "character": character })
+}
+
+fn range_at(text: &str, start: usize, end: usize) -> Value {
+    json!({ "start": position_at(text, start), "end": position_at(text, end) })
+}
+
+fn diagnostic(text: &str, (start, end): (usize, usize), severity: u8, message: String) -> Value {
+    json!({
+        "range": range_at(text, start, end),
+        "severity": severity,
+        "source": "mucm",
+        "message": message,
+    })
+}
+
+/// Compute diagnostics for a document
+///
+/// TOML documents are checked for syntax; use case data files also for the
+/// use case schema and, through `validate`, the project's validation rules.
+pub fn diagnostics(
+    text: &str,
+    uri: &str,
+    project: &ProjectIndex,
+    validate: impl Fn(&UseCase) -> Vec<ValidationIssue>,
+) -> Vec<Value> {
+    let mut result = Vec::new();
+    let mut issues = Vec::new();
+
+    if uri.ends_with(".toml") {
+        match toml::from_str::<toml::Table>(text) {
+            Ok(table) if project.is_use_case_file(uri) => {
+                result.extend(review_diagnostic(text, &table, Utc::now().date_naive()));
+                match TomlUseCaseRepository::parse(text) {
+                    Ok((use_case, _)) => issues = validate(&use_case),
+                    Err(e) => {
+                        // The position is in the converted JSON, not in this file
+                        let message = e.to_string();
+                        let message = match message.rfind(" at line ") {
+                            Some(index) => message[..index].to_string(),
+                            None => message,
+                        };
+                        let span = locate(text, &quoted_words(&message));
+                        result.push(diagnostic(text, span, SEVERITY_ERROR, message));
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(e) => {
+                let span = e.span().map(|s| (s.start, s.end)).unwrap_or((0, 0));
+                result.push(diagnostic(
+                    text,
+                    span,
+                    SEVERITY_ERROR,
+                    e.message().to_string(),
+                ));
+            }
+        }
+    }
+
+    // Use case references are only checked once the project has been loaded
+    if !project.use_cases.is_empty() {
+        let known_ids: Vec<String> = project.use_cases.iter().map(|uc| uc.id.clone()).collect();
+        for m in project.id_regex().find_iter(text) {
+            let use_case_id = use_case_part(m.as_str());
+            if !known_ids.iter().any(|id| id == use_case_id) {
+                result.push(diagnostic(
+                    text,
+                    (m.start(), m.end()),
+                    SEVERITY_WARNING,
+                    suggest_alternatives(use_case_id, &known_ids, "Use case"),
+                ));
+            }
+        }
+    }
+
+    // Unknown references were reported above, where they are written
+    for issue in issues {
+        if result
+            .iter()
+            .any(|d| d["message"] == issue.message.as_str())
+        {
+            continue;
+        }
+        let mut words = quoted_words(&issue.message);
+        words.extend(
+            issue
+                .hint
+                .as_deref()
+                .and_then(|hint| hint.split_whitespace().last()),
+        );
+        let span = locate(text, &words);
+        let message = match &issue.hint {
+            Some(hint) => format!("{}\n{}", issue.message, hint),
+            None => issue.message.clone(),
+        };
+        result.push(diagnostic(text, span, SEVERITY_WARNING, message));
+    }
+
+    result
+}
+
+/// Words in backticks or single quotes, which name what a message is about
+fn quoted_words(message: &str) -> Vec<&str> {
+    ['`', '\'']
+        .iter()
+        .flat_map(|quote| message.split(*quote).skip(1).step_by(2))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Span of the first of `words` found in `text`, or of the first line
+fn locate(text: &str, words: &[&str]) -> (usize, usize) {
+    words
+        .iter()
+        .find_map(|word| text.find(word).map(|start| (start, start + word.len())))
+        .unwrap_or_else(|| (0, text.find('\n').unwrap_or(text.len())))
+}
+
+/// Warn when a use case data file's `review_by` date has arrived
+fn review_diagnostic(text: &str, table: &toml::Table, today: NaiveDate) -> Option<Value> {
+    let review_by = table.get("metadata")?.get("review_by")?.as_str()?;
+    let date: NaiveDate = review_by.parse().ok()?;
+    if date > today {
+        return None;
+    }
+
+    let start = text.find("review_by")?;
+    let end = text[start..].find('\n').map_or(text.len(), |i| start + i);
+    Some(json!({
+        "range": range_at(text, start, end),
+        "severity": SEVERITY_WARNING,
+        "source": "mucm",
+        "message": format!("Review date {} has passed; review this use case and save it to reschedule", date),
+    }))
+}
+
+/// Completion items for use case IDs, personas, statuses, and scenario types
+pub fn completions(project: &ProjectIndex) -> Vec<Value> {
+    let mut items: Vec<Value> = project
+        .use_cases
+        .iter()
+        .map(|uc| {
+            json!({
+                "label": uc.id,
+                "kind": KIND_REFERENCE,
+                "detail": uc.title,
+            })
+        })
+        .collect();
+
+    for persona in &project.personas {
+        items.push(json!({
+            "label": persona.id,
+            "kind": KIND_VALUE,
+            "detail": format!("persona: {}", persona.name),
+        }));
+    }
+
+    let statuses = [
+        Status::Planned,
+        Status::InProgress,
+        Status::Implemented,
+        Status::Tested,
+        Status::Deployed,
+        Status::Deprecated,
+    ];
+    for status in statuses {
+        items.push(json!({
+            "label": status.display_name().to_lowercase(),
+            "kind": KIND_ENUM_MEMBER,
+            "detail": "status",
+        }));
+    }
+
+    let scenario_types = [
+        ScenarioType::HappyPath,
+        ScenarioType::AlternativeFlow,
+        ScenarioType::ExceptionFlow,
+        ScenarioType::Extension,
+    ];
+    for scenario_type in scenario_types {
+        if let Ok(Value::String(label)) = serde_json::to_value(scenario_type) {
+            items.push(json!({
+                "label": label,
+                "kind": KIND_ENUM_MEMBER,
+                "detail": "scenario_type",
+            }));
+        }
+    }
+
+    items
+}
+
+/// Hover content for the use case ID under the cursor
+pub fn hover(text: &str, line: usize, character: usize, project: &ProjectIndex) -> Option<Value> {
+    let word = word_at(text, line, character)?;
+    let use_case_id = use_case_part(&word);
+    let use_case = project.use_cases.iter().find(|uc| uc.id == use_case_id)?;
+
+    let mut contents = format!(
+        "**{}** — {}\n\nCategory: {}  \nStatus: {}  \nPriority: {}  \nScenarios: {}",
+        use_case.id,
+        use_case.title,
+        use_case.category,
+        use_case.status(),
+        use_case.priority,
+        use_case.scenarios.len()
+    );
+    if let Some(scenario) = use_case.scenarios.iter().find(|s| s.id == word) {
+        contents.push_str(&format!(
+            "\n\n**{}** — {} ({})",
+            scenario.id, scenario.title, scenario.status
+        ));
+    }
+
+    Some(json!({ "contents": { "kind": "markdown", "value": contents } }))
+}
+
+/// Strip a scenario suffix (`-S01`) from an ID
+fn use_case_part(id: &str) -> &str {
+    match id.rfind("-S") {
+        Some(index) if id[index + 2..].chars().all(|c| c.is_ascii_digit()) => &id[..index],
+        _ => id,
+    }
+}
+
+/// Extract the identifier-like word at an LSP position
+///
+/// `character` counts UTF-16 code units, as LSP positions do.
+fn word_at(text: &str, line: usize, character: usize) -> Option<String> {
+    let line_text = text.lines().nth(line)?;
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+
+    let mut units = 0;
+    let cursor = line_text
+        .char_indices()
+        .find(|(_, c)| {
+            let past = units >= character;
+            units += c.len_utf16();
+            past
+        })
+        .map_or(line_text.len(), |(index, _)| index);
+    let start = line_text[..cursor]
+        .char_indices()
+        .rfind(|(_, c)| !is_word(*c))
+        .map_or(0, |(index, c)| index + c.len_utf8());
+    let end = line_text[cursor..]
+        .find(|c: char| !is_word(c))
+        .map_or(line_text.len(), |index| cursor + index);
+
+    if start == end {
+        None
+    } else {
+        Some(line_text[start..end].to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA_FILE: &str = "file:///data/authentication/UC-AUT-001.toml";
+
+    fn project() -> ProjectIndex {
+        ProjectIndex {
+            use_cases: vec![UseCase::new(
+                "UC-AUT-001".to_string(),
+                "Login".to_string(),
+                "Authentication".to_string(),
+                String::new(),
+                "high".to_string(),
+            )
+            .unwrap()],
+            personas: vec![ActorEntity::new(
+                "shopper".to_string(),
+                "Shopper".to_string(),
+                crate::core::ActorType::Persona,
+                "🙂".to_string(),
+            )],
+            id_prefix: "UC".to_string(),
+        }
+    }
+
+    fn no_issues(_: &UseCase) -> Vec<ValidationIssue> {
+        Vec::new()
+    }
+
+    #[test]
+    fn test_syntax_error_is_reported() {
+        let result = diagnostics(
+            "title = \"unterminated\n",
+            DATA_FILE,
+            &ProjectIndex::default(),
+            no_issues,
+        );
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0]["severity"], SEVERITY_ERROR);
+    }
+
+    #[test]
+    fn test_unknown_reference_is_reported() {
+        let text = "target_id = \"UC-AUT-002\"\nother = \"UC-AUT-001-S01\"\n";
+        let result = diagnostics(text, "file:///notes.toml", &project(), no_issues);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0]["severity"], SEVERITY_WARNING);
+        assert_eq!(result[0]["range"]["start"]["line"], 0);
+        assert_eq!(result[0]["range"]["start"]["character"], 13);
+    }
+
+    #[test]
+    fn test_past_review_date_is_reported() {
+        let text = "id = \"UC-AUT-001\"\n\n[metadata]\nreview_by = \"2026-01-31\"\n";
+        let table: toml::Table = toml::from_str(text).unwrap();
+        let due = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        let result = review_diagnostic(text, &table, due).unwrap();
+        assert_eq!(result["severity"], SEVERITY_WARNING);
+        assert_eq!(result["range"]["start"]["line"], 3);
+
+        let early = NaiveDate::from_ymd_opt(2026, 1, 30).unwrap();
+        assert!(review_diagnostic(text, &table, early).is_none());
+    }
+
+    #[test]
+    fn test_hover_on_use_case_id() {
+        let text = "see UC-AUT-001 for details";
+        let result = hover(text, 0, 6, &project()).unwrap();
+        let value = result["contents"]["value"].as_str().unwrap();
+        assert!(value.contains("Login"));
+        assert!(hover(text, 0, 0, &project()).is_none());
+    }
+
+    #[test]
+    fn test_hover_counts_utf16_units() {
+        // The emoji is two UTF-16 code units but one char
+        let text = "😀 UC-AUT-001";
+        assert_eq!(word_at(text, 0, 3), Some("UC-AUT-001".to_string()));
+        assert_eq!(word_at(text, 0, 13), Some("UC-AUT-001".to_string()));
+        assert_eq!(word_at(text, 0, 1), None);
+    }
+
+    #[test]
+    fn test_completions_include_ids_and_statuses() {
+        let labels: Vec<String> = completions(&project())
+            .iter()
+            .map(|item| item["label"].as_str().unwrap().to_string())
+            .collect();
+        assert!(labels.contains(&"UC-AUT-001".to_string()));
+        assert!(labels.contains(&"in_progress".to_string()));
+        assert!(labels.contains(&"happy_path".to_string()));
+        assert!(labels.contains(&"shopper".to_string()));
+    }
+
+    #[test]
+    fn test_schema_errors_are_reported_in_use_case_files() {
+        let text =
+            "id = \"UC-AUT-001\"\ntitle = \"Login\"\ncategory = \"auth\"\npriority = \"urgent\"\n";
+        let result = diagnostics(text, DATA_FILE, &project(), no_issues);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0]["severity"], SEVERITY_ERROR);
+        let message = result[0]["message"].as_str().unwrap();
+        assert!(message.contains("urgent") && !message.contains("column"));
+        assert_eq!(result[0]["range"]["start"]["line"], 3);
+
+        // Other TOML files only need to parse
+        assert!(diagnostics(text, "file:///mucm.toml", &project(), no_issues).is_empty());
+    }
+
+    #[test]
+    fn test_validation_issues_are_reported() {
+        let text = "id = \"UC-AUT-001\"\ntitle = \"Login\"\ncategory = \"auth\"\ndescription = \"\"\n\
+                    priority = \"High\"\nadrs = [\"adr/0007.md\"]\n\n\
+                    [metadata]\ncreated_at = \"2026-01-01T00:00:00Z\"\nupdated_at = \"2026-01-01T00:00:00Z\"\n";
+        let result = diagnostics(text, DATA_FILE, &project(), |use_case| {
+            vec![ValidationIssue {
+                use_case_id: use_case.id.clone(),
+                message: "ADR adr/0007.md does not exist".to_string(),
+                hint: Some("mucm reference adr remove UC-AUT-001 adr/0007.md".to_string()),
+            }]
+        });
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0]["severity"], SEVERITY_WARNING);
+        assert_eq!(result[0]["range"]["start"]["line"], 5);
+    }
+
+    #[test]
+    fn test_ids_use_the_project_prefix() {
+        let mut project = project();
+        project.id_prefix = "REQ".to_string();
+        let text = "target_id = \"REQ-AUT-002\"\nother = \"UC-AUT-002\"\n";
+        let result = diagnostics(text, "file:///notes.md", &project, no_issues);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0]["range"]["start"]["line"], 0);
+        assert!(project.is_use_case_file("file:///data/auth/REQ-AUT-001.toml"));
+        assert!(!project.is_use_case_file("file:///data/auth/UC-AUT-001.toml"));
+    }
+
+    #[test]
+    fn test_use_case_part() {
+        assert_eq!(use_case_part("UC-AUT-001-S02"), "UC-AUT-001");
+        assert_eq!(use_case_part("UC-AUT-001"), "UC-AUT-001");
+    }
+}
@@ -0,0 +1,23 @@
+//! # Language Server
+//!
+//! Minimal Language Server Protocol (LSP) implementation for editing use case
+//! TOML data files. Started with `mucm lsp` and spoken to over stdio.
+//!
+//! ## Features
+//!
+//! - **Diagnostics**: TOML syntax errors, use case schema errors and
+//!   validation issues in data files, and references to unknown use case IDs
+//! - **Completion**: Use case IDs, personas, status values, and scenario types
+//! - **Hover**: Title, category, status, and scenario count for use case IDs
+//!
+//! ## Modules
+//!
+//! - `transport`: JSON-RPC message framing over stdio
+//! - `analysis`: Pure functions computing diagnostics, completions, and hovers
+//! - `server`: Request dispatch and document state
+
+mod analysis;
+mod server;
+mod transport;
+
+pub use server::run_lsp_server;
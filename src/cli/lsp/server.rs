@@ -0,0 +1,244 @@
+//! Language server request dispatch and document state.
+
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::Path;
+
+use super::analysis::{self, ProjectIndex};
+use super::transport::{read_message, write_message};
+use crate::core::{ProjectLock, UseCaseCoordinator};
+
+/// JSON-RPC error code for unknown methods
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// State held by the language server between requests
+struct LspServer {
+    /// Open documents by URI
+    documents: HashMap<String, String>,
+    /// The loaded project (None outside a MUCM project), for validation
+    coordinator: Option<UseCaseCoordinator>,
+    /// Use cases, personas and ID prefix of the loaded project
+    project: ProjectIndex,
+    /// Project revision the use cases were loaded at
+    revision: u64,
+    shutdown_requested: bool,
+}
+
+impl LspServer {
+    fn new() -> Self {
+        Self {
+            documents: HashMap::new(),
+            coordinator: None,
+            project: ProjectIndex::default(),
+            revision: 0,
+            shutdown_requested: false,
+        }
+    }
+
+    /// Reload use cases from the project; keeps the previous state if loading fails
+    fn reload_project(&mut self) {
+        self.revision = ProjectLock::revision(Path::new("."));
+        if let Ok(coordinator) = UseCaseCoordinator::load() {
+            self.project = ProjectIndex {
+                use_cases: coordinator.get_all_use_cases().to_vec(),
+                personas: coordinator.project().personas().cloned().collect(),
+                id_prefix: coordinator.config().project.id_prefix.clone(),
+            };
+            self.coordinator = Some(coordinator);
+        }
+    }
+
+    /// Reload the use cases if a command changed them since they were loaded.
+    ///
+    /// Returns fresh diagnostics for the open documents after a reload.
+    fn refresh_if_changed(&mut self) -> Vec<Value> {
+        if ProjectLock::revision(Path::new(".")) == self.revision {
+            return Vec::new();
+        }
+        self.reload_project();
+        self.documents
+            .keys()
+            .map(|uri| self.publish_diagnostics(uri))
+            .collect()
+    }
+
+    /// Handle a request, returning its result (or an error object)
+    fn handle_request(&mut self, method: &str, params: &Value) -> Result<Value, Value> {
+        match method {
+            "initialize" => {
+                self.reload_project();
+                Ok(json!({
+                    "capabilities": {
+                        "textDocumentSync": 1,
+                        "completionProvider": { "triggerCharacters": ["-", "\""] },
+                        "hoverProvider": true,
+                    },
+                    "serverInfo": { "name": "mucm", "version": env!("CARGO_PKG_VERSION") },
+                }))
+            }
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Ok(Value::Null)
+            }
+            "textDocument/completion" => Ok(json!(analysis::completions(&self.project))),
+            "textDocument/hover" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+                let line = params["position"]["line"].as_u64().unwrap_or(0) as usize;
+                let character = params["position"]["character"].as_u64().unwrap_or(0) as usize;
+                Ok(self
+                    .documents
+                    .get(uri)
+                    .and_then(|text| analysis::hover(text, line, character, &self.project))
+                    .unwrap_or(Value::Null))
+            }
+            _ => Err(json!({
+                "code": METHOD_NOT_FOUND,
+                "message": format!("Method not supported: {}", method),
+            })),
+        }
+    }
+
+    /// Handle a notification, returning notifications to send back
+    fn handle_notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let uri = params["textDocument"]["uri"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+
+        match method {
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string();
+                self.documents.insert(uri.clone(), text);
+                vec![self.publish_diagnostics(&uri)]
+            }
+            "textDocument/didChange" => {
+                // Full document sync: the last change holds the whole text
+                if let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                {
+                    self.documents.insert(uri.clone(), text.to_string());
+                }
+                vec![self.publish_diagnostics(&uri)]
+            }
+            "textDocument/didSave" => {
+                // Saved data may add or rename use cases
+                self.reload_project();
+                vec![self.publish_diagnostics(&uri)]
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                vec![json!({
+                    "jsonrpc": "2.0",
+                    "method": "textDocument/publishDiagnostics",
+                    "params": { "uri": uri, "diagnostics": [] },
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Value {
+        let diagnostics = self
+            .documents
+            .get(uri)
+            .map(|text| {
+                analysis::diagnostics(text, uri, &self.project, |use_case| {
+                    self.coordinator
+                        .as_ref()
+                        .map(|coordinator| coordinator.validate_use_case(use_case))
+                        .unwrap_or_default()
+                })
+            })
+            .unwrap_or_default();
+
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        })
+    }
+}
+
+/// Run the language server over stdio until the client sends `exit`.
+pub fn run_lsp_server() -> Result<()> {
+    let stdin = io::stdin();
+    let mut reader = stdin.lock();
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+    let mut server = LspServer::new();
+
+    while let Some(message) = read_message(&mut reader)? {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+
+        if method == "exit" {
+            break;
+        }
+
+        // Commands run in other terminals may have changed the use cases
+        for notification in server.refresh_if_changed() {
+            write_message(&mut writer, &notification)?;
+        }
+
+        match message.get("id") {
+            Some(id) => {
+                let response = match server.handle_request(method, params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+                };
+                write_message(&mut writer, &response)?;
+            }
+            None => {
+                for notification in server.handle_notification(method, params) {
+                    write_message(&mut writer, &notification)?;
+                }
+            }
+        }
+    }
+
+    writer.flush()?;
+    if server.shutdown_requested {
+        Ok(())
+    } else {
+        anyhow::bail!("Language server exited without a shutdown request")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_did_open_publishes_diagnostics() {
+        let mut server = LspServer::new();
+        let params = json!({
+            "textDocument": { "uri": "file:///UC-AUT-001.toml", "text": "title = " }
+        });
+
+        let notifications = server.handle_notification("textDocument/didOpen", &params);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(
+            notifications[0]["params"]["diagnostics"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_unknown_method_returns_error() {
+        let mut server = LspServer::new();
+        let error = server
+            .handle_request("workspace/symbol", &Value::Null)
+            .unwrap_err();
+        assert_eq!(error["code"], METHOD_NOT_FOUND);
+    }
+}
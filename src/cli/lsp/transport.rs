@@ -0,0 +1,62 @@
+//! JSON-RPC message framing (`Content-Length` headers) over stdio.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+
+/// Read one framed JSON-RPC message.
+///
+/// Returns `Ok(None)` when the input stream is closed.
+pub fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<Value>> {
+    let mut content_length: Option<usize> = None;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            content_length = Some(
+                value
+                    .trim()
+                    .parse()
+                    .context("Invalid Content-Length header")?,
+            );
+        }
+    }
+
+    let length = content_length.context("Missing Content-Length header")?;
+    let mut body = vec![0u8; length];
+    reader.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+/// Write one framed JSON-RPC message.
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> Result<()> {
+    let body = serde_json::to_string(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_round_trip() {
+        let message = json!({"jsonrpc": "2.0", "id": 1, "method": "initialize"});
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &message).unwrap();
+
+        let mut reader = Cursor::new(buffer);
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+}
@@ -5,53 +5,148 @@
 /// argument parsing and coordinates between interactive and regular command modes.
 ///
 /// ## Modules
+/// - `aliases`: Expands command aliases before parsing.
 /// - `args`: Defines CLI argument structures using Clap.
 /// - `commands`: Thin command handlers that delegate to business logic.
 /// - `interactive`: Interactive session management for guided usage.
+/// - `lsp`: Language server for editing use case data in an editor.
 /// - `runner`: Core business logic and file operations.
 ///
 /// ## Flow
-/// 1. Parse CLI arguments with Clap.
+/// 1. Expand command aliases, then parse CLI arguments with Clap.
 /// 2. Check for interactive mode (flag, subcommand, or no args).
 /// 3. For regular commands, create a runner and dispatch to handlers.
 /// 4. Handlers perform CLI-specific tasks and call runner methods.
 // Private modules
+mod aliases;
 mod args;
 mod interactive;
+mod lsp;
 mod standard;
 
 use anyhow::Result;
 use clap::Parser;
 
+use crate::config::{Config, ConfigValidator};
 use crate::controller::DisplayResult;
-use crate::presentation::DisplayResultFormatter;
-use args::{Cli, Commands};
+use crate::core::utils::{
+    enable_timings, set_progress_reporter, span, timing_summary, timings_enabled,
+};
+use crate::core::ProjectLock;
+use crate::presentation::{
+    apply_color_mode, paint, set_icon_theme, set_progress_enabled, DisplayResultFormatter, Style,
+    TerminalProgress,
+};
+use aliases::expand_aliases;
+use args::{Cli, Commands, ConfigCommands};
 use interactive::run_interactive_session;
+use lsp::run_lsp_server;
 use standard::{
-    handle_actor_command, handle_cleanup_command, handle_create_command, handle_init_command,
-    handle_languages_command, handle_list_command, handle_list_methodologies_command,
-    handle_methodology_info_command, handle_postcondition_add_command,
+    handle_actor_command, handle_at_command, handle_backup_command, handle_batch_command,
+    handle_bench_command, handle_checklist_command, handle_cleanup_command, handle_clone_command,
+    handle_config_command, handle_create_command, handle_demo_command, handle_estimate_command,
+    handle_explain_command, handle_export_command, handle_generate_command, handle_grep_command,
+    handle_import_command, handle_init_command, handle_languages_command, handle_list_command,
+    handle_list_methodologies_command, handle_manifest_command, handle_merge_command,
+    handle_methodology_info_command, handle_plan_command, handle_postcondition_add_command,
     handle_postcondition_list_command, handle_postcondition_remove_command,
     handle_precondition_add_command, handle_precondition_list_command,
-    handle_precondition_remove_command, handle_reference_add_command,
+    handle_precondition_remove_command, handle_project_command, handle_publish_command,
+    handle_reconcile_command, handle_reference_add_command, handle_reference_adr_command,
     handle_reference_list_command, handle_reference_remove_command, handle_regenerate_command,
-    handle_status_command, handle_usecase_scenario_command, CliRunner,
+    handle_repair_command, handle_replay_command, handle_report_command, handle_sandbox_command,
+    handle_split_command, handle_status_command, handle_sync_command, handle_test_names_command,
+    handle_undo_command, handle_usecase_scenario_command, handle_usecase_view_command,
+    handle_validate_command, CliRunner, CommandFailed,
 };
+use std::path::Path;
+use std::process::ExitCode;
 
 /// Execute a command with proper error handling and colored output
-fn execute_command<F>(command_fn: F)
+///
+/// Returns the exit status instead of exiting, so the project lock and other
+/// guards held by `run` are released before the process ends.
+fn execute_command<F>(command_fn: F) -> ExitCode
 where
     F: FnOnce() -> Result<()>,
 {
-    match command_fn() {
-        Ok(()) => {}
+    let result = {
+        let _span = span(|| "other".to_string());
+        command_fn()
+    };
+    report_timings();
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
         Err(e) => {
-            DisplayResultFormatter::display(&DisplayResult::error(e.to_string()));
-            std::process::exit(1);
+            // Handlers that display their own failure return `CommandFailed`
+            if !e.is::<CommandFailed>() {
+                DisplayResultFormatter::display(&DisplayResult::error(e.to_string()));
+            }
+            ExitCode::FAILURE
         }
     }
 }
 
+/// Print the time spent in each phase of the command when `--timings` is on.
+///
+/// Written to stderr so JSON output stays parseable. Each phase excludes the
+/// phases nested in it, so the rows add up to the total.
+fn report_timings() {
+    if !timings_enabled() {
+        return;
+    }
+    let summary = timing_summary();
+    let width = summary
+        .iter()
+        .map(|entry| entry.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("total".len());
+    eprintln!("{}", paint("⏱️  Timings", Style::Heading));
+    for entry in &summary {
+        eprintln!(
+            "   {:<width$} {:>10.2?}  {}×",
+            entry.name,
+            entry.duration,
+            entry.count,
+            width = width
+        );
+    }
+    let total: std::time::Duration = summary.iter().map(|entry| entry.duration).sum();
+    eprintln!("   {:<width$} {:>10.2?}", "total", total, width = width);
+}
+
+/// Report problems in the project config before running a command.
+///
+/// Warnings are printed and the command goes ahead; errors stop it.
+fn check_config() {
+    if !Config::config_path().exists() {
+        return;
+    }
+    let Ok(diagnostics) = ConfigValidator::validate_dir(Path::new(".")) else {
+        return;
+    };
+
+    for diagnostic in &diagnostics {
+        let style = if diagnostic.is_error() {
+            Style::Error
+        } else {
+            Style::Warning
+        };
+        eprintln!("{}", paint(&diagnostic.to_string(), style));
+    }
+    if diagnostics.iter().any(|d| d.is_error()) {
+        eprintln!(
+            "{}",
+            paint(
+                &format!("Fix {} to continue", Config::config_path().display()),
+                Style::Error
+            )
+        );
+        std::process::exit(1);
+    }
+}
+
 /// Main CLI entry point.
 ///
 /// Parses command-line arguments and dispatches to the appropriate handler.
@@ -64,15 +159,50 @@ where
 ///
 /// For regular commands, creates a CliRunner instance and delegates to
 /// command-specific handlers in the `commands` module.
-pub fn run() -> Result<()> {
-    let cli = Cli::parse();
+pub fn run() -> Result<ExitCode> {
+    let aliases = Config::load()
+        .map(|config| config.alias)
+        .unwrap_or_default();
+    let cli = Cli::parse_from(expand_aliases(std::env::args_os().collect(), &aliases)?);
+    if cli.timings {
+        enable_timings();
+    }
+
+    // Apply presentation settings from the project config, if there is one
+    let presentation = Config::load()
+        .map(|config| config.presentation)
+        .unwrap_or_default();
+    set_icon_theme(presentation.icon_theme);
+    apply_color_mode(presentation.color, cli.no_color);
+
+    // Progress bars go to stderr, and not at all next to machine-readable output
+    set_progress_reporter(Box::new(TerminalProgress));
+    set_progress_enabled(
+        !cli.explain
+            && !matches!(
+                cli.command,
+                Some(
+                    Commands::Status { json: true }
+                        | Commands::MethodologyInfo { json: true, .. }
+                        | Commands::Lsp
+                )
+            ),
+    );
+
+    // `init` may be repairing the config and `config validate` reports by itself
+    if !matches!(
+        cli.command,
+        Some(Commands::Init { .. } | Commands::Config { .. })
+    ) {
+        check_config();
+    }
 
     // Check if interactive mode is requested
     if cli.interactive
         || matches!(cli.command, Some(Commands::Interactive))
         || cli.command.is_none()
     {
-        return run_interactive_session();
+        return run_interactive_session().map(|()| ExitCode::SUCCESS);
     }
 
     // Handle regular commands
@@ -83,6 +213,44 @@ pub fn run() -> Result<()> {
         anyhow::bail!("No command specified. Use --help for available commands.");
     };
 
+    // A command that changes the project holds its lock from loading the use
+    // cases to its last write, so a concurrent mucm process cannot overwrite
+    // its changes. Read-only commands only take the repository's shared lock
+    // per read, so they never wait for a long command. `replay` runs each
+    // command in a child process that takes the lock itself.
+    let read_only = cli.explain
+        || matches!(
+            command,
+            Commands::List { .. }
+                | Commands::Languages { .. }
+                | Commands::Methodologies
+                | Commands::MethodologyInfo { .. }
+                | Commands::Status { .. }
+                | Commands::Validate
+                | Commands::Explain { .. }
+                | Commands::Grep { .. }
+                | Commands::At { .. }
+                | Commands::Lsp
+                | Commands::Config {
+                    command: ConfigCommands::Validate
+                }
+        );
+    let _lock = match command {
+        _ if read_only => None,
+        Commands::Init { .. } | Commands::Demo { .. } | Commands::Replay { .. } => None,
+        _ if Path::new(Config::CONFIG_DIR).is_dir() => match ProjectLock::acquire(Path::new(".")) {
+            Ok(lock) => Some(lock),
+            Err(e) => return Ok(execute_command(|| Err(e.into()))),
+        },
+        _ => None,
+    };
+
+    if cli.explain {
+        return Ok(execute_command(|| {
+            handle_plan_command(&mut runner, command)
+        }));
+    }
+
     match command {
         Commands::Init {
             language,
@@ -99,100 +267,101 @@ pub fn run() -> Result<()> {
                         .collect()
                 })
                 .unwrap_or_else(|| vec!["feature".to_string()]);
-            execute_command(|| {
+            Ok(execute_command(|| {
                 handle_init_command(&mut runner, language, methodologies, storage, finalize)
-            });
-            Ok(())
+            }))
         }
+        Commands::Demo { directory } => Ok(execute_command(|| {
+            handle_demo_command(&mut runner, directory)
+        })),
         Commands::Create {
             title,
             category,
             description,
             methodology,
             views,
-        } => {
-            execute_command(|| {
-                handle_create_command(
-                    &mut runner,
-                    title,
-                    category,
-                    description,
-                    methodology,
-                    views,
-                )
-            });
-            Ok(())
-        }
-        Commands::List => {
-            execute_command(|| handle_list_command(&mut runner));
-            Ok(())
-        }
-        Commands::Languages => {
-            execute_command(|| handle_languages_command());
-            Ok(())
-        }
-        Commands::Methodologies => {
-            execute_command(|| handle_list_methodologies_command(&mut runner));
-            Ok(())
-        }
-        Commands::MethodologyInfo { name } => {
-            execute_command(|| handle_methodology_info_command(&mut runner, name));
-            Ok(())
-        }
+        } => Ok(execute_command(|| {
+            handle_create_command(
+                &mut runner,
+                title,
+                category,
+                description,
+                methodology,
+                views,
+            )
+        })),
+        Commands::List {
+            needs_review,
+            filter_name,
+        } => Ok(execute_command(|| {
+            handle_list_command(&mut runner, needs_review, filter_name)
+        })),
+        Commands::Languages { details } => Ok(execute_command(|| {
+            handle_languages_command(&mut runner, details)
+        })),
+        Commands::Methodologies => Ok(execute_command(|| {
+            handle_list_methodologies_command(&mut runner)
+        })),
+        Commands::MethodologyInfo { name, json } => Ok(execute_command(|| {
+            handle_methodology_info_command(&mut runner, name, json)
+        })),
         Commands::Regenerate {
             use_case_id,
             methodology,
             all,
-        } => {
-            execute_command(|| {
-                handle_regenerate_command(&mut runner, use_case_id, methodology, all)
-            });
-            Ok(())
-        }
-        Commands::Status => {
-            execute_command(|| handle_status_command(&mut runner));
-            Ok(())
+            view,
+            overview,
+        } => Ok(execute_command(|| {
+            handle_regenerate_command(&mut runner, use_case_id, methodology, all, view, overview)
+        })),
+        Commands::Status { json } => {
+            Ok(execute_command(|| handle_status_command(&mut runner, json)))
         }
+        Commands::Manifest => Ok(execute_command(|| handle_manifest_command(&mut runner))),
+        Commands::Export {
+            use_case_id,
+            format,
+            output,
+            tag,
+        } => Ok(execute_command(|| {
+            handle_export_command(&mut runner, format, output, use_case_id, tag)
+        })),
+        Commands::Publish { command } => Ok(execute_command(|| {
+            handle_publish_command(&mut runner, command)
+        })),
+        Commands::Sync { command } => Ok(execute_command(|| {
+            handle_sync_command(&mut runner, command)
+        })),
         Commands::Precondition { command } => match command {
             args::PreconditionCommands::Add {
                 use_case_id,
                 precondition,
-            } => {
-                execute_command(|| {
-                    handle_precondition_add_command(&mut runner, use_case_id, precondition)
-                });
-                Ok(())
-            }
-            args::PreconditionCommands::List { use_case_id } => {
-                execute_command(|| handle_precondition_list_command(&mut runner, use_case_id));
-                Ok(())
-            }
+            } => Ok(execute_command(|| {
+                handle_precondition_add_command(&mut runner, use_case_id, precondition)
+            })),
+            args::PreconditionCommands::List { use_case_id } => Ok(execute_command(|| {
+                handle_precondition_list_command(&mut runner, use_case_id)
+            })),
             args::PreconditionCommands::Remove { use_case_id, index } => {
-                execute_command(|| {
+                Ok(execute_command(|| {
                     handle_precondition_remove_command(&mut runner, use_case_id, index)
-                });
-                Ok(())
+                }))
             }
         },
         Commands::Postcondition { command } => match command {
             args::PostconditionCommands::Add {
                 use_case_id,
                 postcondition,
-            } => {
-                execute_command(|| {
-                    handle_postcondition_add_command(&mut runner, use_case_id, postcondition)
-                });
-                Ok(())
-            }
-            args::PostconditionCommands::List { use_case_id } => {
-                execute_command(|| handle_postcondition_list_command(&mut runner, use_case_id));
-                Ok(())
-            }
+            } => Ok(execute_command(|| {
+                handle_postcondition_add_command(&mut runner, use_case_id, postcondition)
+            })),
+            args::PostconditionCommands::List { use_case_id } => Ok(execute_command(|| {
+                handle_postcondition_list_command(&mut runner, use_case_id)
+            })),
             args::PostconditionCommands::Remove { use_case_id, index } => {
-                execute_command(|| {
+                Ok(execute_command(|| {
                     handle_postcondition_remove_command(&mut runner, use_case_id, index)
-                });
-                Ok(())
+                }))
             }
         },
         Commands::Reference { command } => match command {
@@ -201,49 +370,137 @@ pub fn run() -> Result<()> {
                 target_id,
                 relationship,
                 description,
-            } => {
-                execute_command(|| {
-                    handle_reference_add_command(
-                        &mut runner,
-                        use_case_id,
-                        target_id,
-                        relationship,
-                        description,
-                    )
-                });
-                Ok(())
-            }
-            args::ReferenceCommands::List { use_case_id } => {
-                execute_command(|| handle_reference_list_command(&mut runner, use_case_id));
-                Ok(())
-            }
+            } => Ok(execute_command(|| {
+                handle_reference_add_command(
+                    &mut runner,
+                    use_case_id,
+                    target_id,
+                    relationship,
+                    description,
+                )
+            })),
+            args::ReferenceCommands::List { use_case_id } => Ok(execute_command(|| {
+                handle_reference_list_command(&mut runner, use_case_id)
+            })),
             args::ReferenceCommands::Remove {
                 use_case_id,
                 target_id,
-            } => {
-                execute_command(|| {
-                    handle_reference_remove_command(&mut runner, use_case_id, target_id)
-                });
-                Ok(())
-            }
+            } => Ok(execute_command(|| {
+                handle_reference_remove_command(&mut runner, use_case_id, target_id)
+            })),
+            args::ReferenceCommands::Adr { command } => Ok(execute_command(|| {
+                handle_reference_adr_command(&mut runner, command)
+            })),
         },
         Commands::UseCase { command } => match command {
-            args::UseCaseCommands::Scenario { command } => {
-                handle_usecase_scenario_command(&mut runner, command)?;
-                Ok(())
-            }
+            args::UseCaseCommands::Scenario { command } => Ok(execute_command(|| {
+                handle_usecase_scenario_command(&mut runner, command)
+            })),
+            args::UseCaseCommands::View { command } => Ok(execute_command(|| {
+                handle_usecase_view_command(&mut runner, command)
+            })),
         },
-        Commands::Actor { command } => handle_actor_command(command),
+        Commands::Actor { command } => Ok(execute_command(|| handle_actor_command(command))),
         Commands::Cleanup {
             use_case_id,
             dry_run,
-        } => {
-            execute_command(|| handle_cleanup_command(&mut runner, use_case_id, dry_run));
-            Ok(())
+        } => Ok(execute_command(|| {
+            handle_cleanup_command(&mut runner, use_case_id, dry_run)
+        })),
+        Commands::Repair => Ok(execute_command(|| handle_repair_command(&mut runner))),
+        Commands::Explain { target } => Ok(execute_command(|| {
+            handle_explain_command(&mut runner, target)
+        })),
+        Commands::Grep {
+            pattern,
+            ignore_case,
+            fixed_strings,
+        } => Ok(execute_command(|| {
+            handle_grep_command(&mut runner, pattern, ignore_case, fixed_strings)
+        })),
+        Commands::Backup { command } => Ok(execute_command(|| {
+            handle_backup_command(&mut runner, command)
+        })),
+        Commands::Reconcile {
+            use_case_id,
+            create_missing_scenarios,
+        } => Ok(execute_command(|| {
+            handle_reconcile_command(&mut runner, use_case_id, create_missing_scenarios)
+        })),
+        Commands::Generate { command } => Ok(execute_command(|| {
+            handle_generate_command(&mut runner, command)
+        })),
+        Commands::TestNames { command } => Ok(execute_command(|| {
+            handle_test_names_command(&mut runner, command)
+        })),
+        Commands::Project { command } => Ok(execute_command(|| {
+            handle_project_command(&mut runner, command)
+        })),
+        Commands::Config { command } => Ok(execute_command(|| {
+            handle_config_command(&mut runner, command)
+        })),
+        Commands::Sandbox { command } => Ok(execute_command(|| {
+            handle_sandbox_command(&mut runner, command)
+        })),
+        Commands::At { date, command } => Ok(execute_command(|| {
+            handle_at_command(&mut runner, date, command)
+        })),
+        Commands::Undo => Ok(execute_command(|| handle_undo_command(&mut runner))),
+        Commands::Report { command } => Ok(execute_command(|| {
+            handle_report_command(&mut runner, command)
+        })),
+        Commands::Clone {
+            use_case_id,
+            title,
+            category,
+            no_scenarios,
+            no_conditions,
+        } => Ok(execute_command(|| {
+            handle_clone_command(
+                &mut runner,
+                use_case_id,
+                title,
+                category,
+                !no_scenarios,
+                !no_conditions,
+            )
+        })),
+        Commands::Split {
+            use_case_id,
+            scenarios,
+            title,
+            category,
+        } => Ok(execute_command(|| {
+            handle_split_command(&mut runner, use_case_id, scenarios, title, category)
+        })),
+        Commands::Merge {
+            target_id,
+            source_id,
+            on_conflict,
+        } => Ok(execute_command(|| {
+            handle_merge_command(&mut runner, target_id, source_id, on_conflict)
+        })),
+        Commands::Validate => Ok(execute_command(|| handle_validate_command(&mut runner))),
+        Commands::Checklist { command } => Ok(execute_command(|| {
+            handle_checklist_command(&mut runner, command)
+        })),
+        Commands::Estimate { use_case_id } => Ok(execute_command(|| {
+            handle_estimate_command(&mut runner, use_case_id)
+        })),
+        Commands::Import { command } => Ok(execute_command(|| {
+            handle_import_command(&mut runner, command)
+        })),
+        Commands::Batch { file } => Ok(execute_command(|| handle_batch_command(&mut runner, file))),
+        Commands::Replay { log, dry_run } => {
+            Ok(execute_command(|| handle_replay_command(log, dry_run)))
         }
+        Commands::Bench { use_cases, backend } => Ok(execute_command(|| {
+            handle_bench_command(&mut runner, use_cases, backend)
+        })),
+        Commands::Lsp => run_lsp_server().map(|()| ExitCode::SUCCESS),
         Commands::Interactive => {
             // This case is handled above, but included for completeness
-            run_interactive_session()
+            run_interactive_session().map(|()| ExitCode::SUCCESS)
         }
     }
 }
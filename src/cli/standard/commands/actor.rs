@@ -1,10 +1,12 @@
 //! CLI commands for managing actors (personas and system actors)
 
+use super::CommandFailed;
 use crate::cli::args::ActorCommands;
 use crate::controller::ActorController;
 use crate::core::{ActorType, Persona};
 use crate::presentation::DisplayResultFormatter;
-use anyhow::Result;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
 use std::str::FromStr;
 
 /// Handle actor commands
@@ -12,8 +14,22 @@ pub fn handle_actor_command(command: ActorCommands) -> Result<()> {
     let controller = ActorController::new()?;
 
     match command {
-        ActorCommands::CreatePersona { id, name, function } => {
-            let result = controller.create_persona(id, name, function)?;
+        ActorCommands::CreatePersona {
+            id,
+            name,
+            function,
+            fields,
+        } => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    field
+                        .split_once('=')
+                        .map(|(name, value)| (name.trim().to_string(), value.to_string()))
+                        .with_context(|| format!("Expected NAME=VALUE, got '{}'", field))
+                })
+                .collect::<Result<HashMap<_, _>>>()?;
+            let result = controller.create_persona_with_fields(id, name, function, fields)?;
             DisplayResultFormatter::display(&result);
             Ok(())
         }
@@ -37,6 +53,11 @@ pub fn handle_actor_command(command: ActorCommands) -> Result<()> {
             DisplayResultFormatter::display(&result);
             Ok(())
         }
+        ActorCommands::SetParent { id, parent, .. } => {
+            let result = controller.set_actor_parent(id, parent)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
         ActorCommands::List { actor_type } => {
             let type_filter = if let Some(type_str) = actor_type {
                 Some(ActorType::from_str(&type_str).map_err(|e| anyhow::anyhow!(e))?)
@@ -46,6 +67,16 @@ pub fn handle_actor_command(command: ActorCommands) -> Result<()> {
             list_actors_with_controller(&controller, type_filter)
         }
         ActorCommands::Show { id } => show_actor_with_controller(&controller, &id),
+        ActorCommands::Reassign { from, to, category } => {
+            let mut uc_controller = crate::controller::UseCaseController::new()?;
+            let result = uc_controller.reassign_persona(&from, &to, category.as_deref())?;
+            DisplayResultFormatter::display(&result);
+            if result.success {
+                Ok(())
+            } else {
+                Err(CommandFailed.into())
+            }
+        }
         ActorCommands::UseCases { id } => list_use_cases_for_actor(&id),
         ActorCommands::Delete { id } => {
             let result = controller.delete_actor(id)?;
@@ -162,10 +193,7 @@ fn list_use_cases_for_actor(id: &str) -> Result<()> {
             uc.scenarios.iter().any(|s| {
                 s.steps.iter().any(|step| {
                     actor_matches(&step.actor, id)
-                        || step
-                            .receiver
-                            .as_ref()
-                            .map_or(false, |r| actor_matches(r, id))
+                        || step.receiver.as_ref().is_some_and(|r| actor_matches(r, id))
                 })
             })
         })
@@ -190,10 +218,7 @@ fn list_use_cases_for_actor(id: &str) -> Result<()> {
             .filter(|s| {
                 s.steps.iter().any(|step| {
                     actor_matches(&step.actor, id)
-                        || step
-                            .receiver
-                            .as_ref()
-                            .map_or(false, |r| actor_matches(r, id))
+                        || step.receiver.as_ref().is_some_and(|r| actor_matches(r, id))
                 })
             })
             .collect();
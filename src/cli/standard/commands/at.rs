@@ -0,0 +1,29 @@
+//! Time-travel and undo command handlers for the event-log backend.
+
+use anyhow::Result;
+
+use crate::cli::args::AtCommands;
+use crate::cli::standard::CliRunner;
+use crate::presentation::DisplayResultFormatter;
+
+/// Handles the 'at' CLI subcommands.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `date` - The point in time to look at.
+/// * `command` - The read-only subcommand to run at that time.
+pub fn handle_at_command(runner: &mut CliRunner, date: String, command: AtCommands) -> Result<()> {
+    match command {
+        AtCommands::List => runner.list_use_cases_at(date),
+    }
+}
+
+/// Handles the 'undo' CLI command.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+pub fn handle_undo_command(runner: &mut CliRunner) -> Result<()> {
+    let result = runner.undo()?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
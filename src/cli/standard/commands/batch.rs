@@ -0,0 +1,16 @@
+/// Batch command handler for applying many operations at once.
+use anyhow::Result;
+
+use crate::cli::standard::runner::CliRunner;
+use crate::presentation::DisplayResultFormatter;
+
+/// Handle the batch command.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `file` - TOML or JSON batch file
+pub fn handle_batch_command(runner: &mut CliRunner, file: String) -> Result<()> {
+    let result = runner.run_batch(file)?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//! Acceptance checklist command handlers.
+
+use anyhow::Result;
+
+use crate::cli::args::ChecklistCommands;
+use crate::cli::standard::CliRunner;
+use crate::presentation::DisplayResultFormatter;
+
+/// Handle the checklist command and its subcommands.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `command` - The checklist subcommand to run
+pub fn handle_checklist_command(runner: &mut CliRunner, command: ChecklistCommands) -> Result<()> {
+    let result = match command {
+        ChecklistCommands::Generate { use_case_id } => runner.generate_checklists(use_case_id)?,
+        ChecklistCommands::Tick {
+            use_case_id,
+            scenario_id,
+        } => runner.tick_checklist(use_case_id, scenario_id)?,
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
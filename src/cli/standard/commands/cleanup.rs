@@ -1,4 +1,4 @@
-/// Cleanup command handlers for removing orphaned methodology fields.
+/// Cleanup command handlers for removing orphaned methodology fields and repairing data files.
 use anyhow::Result;
 
 use crate::cli::standard::runner::CliRunner;
@@ -22,3 +22,15 @@ pub fn handle_cleanup_command(
     DisplayResultFormatter::display(&result);
     Ok(())
 }
+
+/// Handle the repair command to recover data files that fail to load.
+///
+/// Fixable files are rewritten in place; the others are quarantined.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+pub fn handle_repair_command(runner: &mut CliRunner) -> Result<()> {
+    let result = runner.repair()?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
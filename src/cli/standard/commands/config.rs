@@ -0,0 +1,37 @@
+//! Config command handlers.
+
+use super::CommandFailed;
+use anyhow::Result;
+
+use crate::cli::args::ConfigCommands;
+use crate::cli::standard::CliRunner;
+use crate::presentation::DisplayResultFormatter;
+
+/// Handles the 'config' CLI subcommands, exiting with an error when the config has errors.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `command` - The config subcommand to execute.
+pub fn handle_config_command(runner: &mut CliRunner, command: ConfigCommands) -> Result<()> {
+    match command {
+        ConfigCommands::Validate => {
+            let result = runner.validate_config()?;
+            DisplayResultFormatter::display(&result);
+
+            if result.success {
+                Ok(())
+            } else {
+                Err(CommandFailed.into())
+            }
+        }
+        ConfigCommands::Set {
+            key,
+            value,
+            migrate,
+        } => {
+            let result = runner.set_config(key, value, migrate)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Estimate command handler, walking through the scenarios of a use case.
+
+use anyhow::Result;
+use inquire::{Select, Text};
+use std::io::IsTerminal;
+
+use crate::cli::standard::CliRunner;
+use crate::core::EstimateRequest;
+use crate::presentation::{paint, DisplayResultFormatter, Style};
+
+/// Handle the estimate command.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `use_case_id` - The use case to estimate
+pub fn handle_estimate_command(runner: &mut CliRunner, use_case_id: String) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!("mucm estimate asks for every estimate and needs a terminal");
+    }
+
+    let mut current_scenario = String::new();
+    let result = runner.estimate_use_case(use_case_id, |request| {
+        if request.scenario_id != current_scenario {
+            println!(
+                "\n{} {}",
+                paint(&request.scenario_id, Style::Identifier),
+                paint(&request.scenario_title, Style::Title)
+            );
+            current_scenario = request.scenario_id.clone();
+        }
+        prompt_estimate(request)
+    })?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Ask for one estimate; None keeps the current value (or leaves it unset)
+fn prompt_estimate(request: &EstimateRequest) -> Result<Option<f64>> {
+    let label = request.config.label_or(&request.field);
+    let keep = match request.current {
+        Some(current) => format!("Keep {}", current),
+        None => "Skip".to_string(),
+    };
+
+    if !request.config.scale.is_empty() {
+        let mut options: Vec<String> = request
+            .config
+            .scale
+            .iter()
+            .map(|value| value.to_string())
+            .collect();
+        options.push(keep.clone());
+        let mut prompt = Select::new(label, options);
+        if let Some(description) = &request.config.description {
+            prompt = prompt.with_help_message(description);
+        }
+        let answer = prompt.prompt()?;
+        return Ok(if answer == keep {
+            None
+        } else {
+            answer.parse().ok()
+        });
+    }
+
+    let help = match &request.config.description {
+        Some(description) => format!("{} (leave empty: {})", description, keep),
+        None => format!("Leave empty: {}", keep),
+    };
+    let answer = Text::new(label)
+        .with_help_message(&help)
+        .with_validator(|input: &str| {
+            let input = input.trim();
+            if input.is_empty() || matches!(input.parse::<f64>(), Ok(v) if v >= 0.0) {
+                Ok(inquire::validator::Validation::Valid)
+            } else {
+                Ok(inquire::validator::Validation::Invalid(
+                    "Enter a non-negative number".into(),
+                ))
+            }
+        })
+        .prompt()?;
+    let answer = answer.trim();
+    Ok(if answer.is_empty() {
+        None
+    } else {
+        Some(answer.parse()?)
+    })
+}
@@ -0,0 +1,16 @@
+/// Explain command handler for tracing use cases and generated files to their sources.
+use anyhow::Result;
+
+use crate::cli::standard::runner::CliRunner;
+use crate::presentation::DisplayResultFormatter;
+
+/// Handle the explain command.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `target` - Use case ID, scenario ID, or file path to explain
+pub fn handle_explain_command(runner: &mut CliRunner, target: String) -> Result<()> {
+    let result = runner.explain(target)?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use super::CommandFailed;
+use crate::cli::args::AdrCommands;
 use crate::cli::standard::CliRunner;
 use crate::controller::DisplayResult;
 use crate::presentation::DisplayResultFormatter;
@@ -19,7 +21,7 @@ pub fn handle_precondition_add_command(
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -35,7 +37,7 @@ pub fn handle_precondition_list_command(runner: &mut CliRunner, use_case_id: Str
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -55,7 +57,7 @@ pub fn handle_precondition_remove_command(
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -75,7 +77,7 @@ pub fn handle_postcondition_add_command(
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -94,7 +96,7 @@ pub fn handle_postcondition_list_command(
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -114,7 +116,7 @@ pub fn handle_postcondition_remove_command(
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -136,7 +138,7 @@ pub fn handle_reference_add_command(
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -152,7 +154,7 @@ pub fn handle_reference_list_command(runner: &mut CliRunner, use_case_id: String
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -172,6 +174,27 @@ pub fn handle_reference_remove_command(
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
+    }
+}
+
+/// Handles the 'reference adr' CLI commands.
+pub fn handle_reference_adr_command(runner: &mut CliRunner, command: AdrCommands) -> Result<()> {
+    let result = match command {
+        AdrCommands::Add { use_case_id, path } => runner.add_adr(use_case_id, path),
+        AdrCommands::List { use_case_id } => runner.list_adrs(use_case_id),
+        AdrCommands::Remove { use_case_id, path } => runner.remove_adr(use_case_id, path),
+    };
+    let result = match result {
+        Ok(display_result) => display_result,
+        Err(e) => DisplayResult::error(e.to_string()),
+    };
+
+    DisplayResultFormatter::display(&result);
+
+    if result.success {
+        Ok(())
+    } else {
+        Err(CommandFailed.into())
     }
 }
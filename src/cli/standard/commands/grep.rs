@@ -0,0 +1,20 @@
+/// Grep command handler for searching use case data.
+use anyhow::Result;
+
+use crate::cli::standard::runner::CliRunner;
+
+/// Handle the grep command.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `pattern` - Pattern to search for
+/// * `ignore_case` - Match case-insensitively
+/// * `fixed_strings` - Treat the pattern as literal text
+pub fn handle_grep_command(
+    runner: &mut CliRunner,
+    pattern: String,
+    ignore_case: bool,
+    fixed_strings: bool,
+) -> Result<()> {
+    runner.grep(pattern, ignore_case, fixed_strings)
+}
@@ -0,0 +1,103 @@
+//! Import command handlers, including interactive ID conflict resolution.
+
+use anyhow::Result;
+use inquire::{Confirm, Select};
+use std::io::IsTerminal;
+
+use crate::cli::args::ImportCommands;
+use crate::cli::standard::CliRunner;
+use crate::core::{ConflictResolution, UseCase};
+use crate::presentation::DisplayResultFormatter;
+
+/// Handle import subcommands.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `command` - The import subcommand to execute
+pub fn handle_import_command(runner: &mut CliRunner, command: ImportCommands) -> Result<()> {
+    match command {
+        ImportCommands::Toml { path, on_conflict } => {
+            let result = runner.import_use_cases(path, conflict_resolver(&on_conflict)?)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+        ImportCommands::Json { path, on_conflict } => {
+            let result = runner.import_json_use_cases(path, conflict_resolver(&on_conflict)?)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+        ImportCommands::Gherkin { path, category } => {
+            let result = runner.import_gherkin(path, category)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+        ImportCommands::Markdown { path, category } => {
+            let result = runner.import_markdown(path, category)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+        ImportCommands::Rtm { path, mapping } => {
+            let result = runner.import_rtm(path, mapping)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+    }
+}
+
+/// Decides how an incoming use case whose ID already exists is imported
+type ConflictResolver = Box<dyn FnMut(&UseCase, &UseCase) -> Result<ConflictResolution>>;
+
+/// Resolver of ID conflicts for `--on-conflict`: a prompt for `ask`, else the given policy
+fn conflict_resolver(on_conflict: &str) -> Result<ConflictResolver> {
+    if on_conflict.eq_ignore_ascii_case("ask") {
+        let mut prompt = ConflictPrompt::default();
+        return Ok(Box::new(move |existing, incoming| {
+            prompt.resolve(existing, incoming)
+        }));
+    }
+    let policy: ConflictResolution = on_conflict
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))?;
+    Ok(Box::new(move |_, _| Ok(policy)))
+}
+
+/// Asks the user how to resolve each ID conflict, remembering an "apply to all" answer
+#[derive(Default)]
+struct ConflictPrompt {
+    apply_to_all: Option<ConflictResolution>,
+}
+
+impl ConflictPrompt {
+    fn resolve(&mut self, existing: &UseCase, incoming: &UseCase) -> Result<ConflictResolution> {
+        if let Some(resolution) = self.apply_to_all {
+            return Ok(resolution);
+        }
+
+        if !std::io::stdin().is_terminal() {
+            anyhow::bail!(
+                "{} already exists and no terminal is available to ask. \
+                 Pass --on-conflict skip|overwrite|re-id|merge",
+                incoming.id
+            );
+        }
+
+        let question = format!(
+            "{} already exists (existing: \"{}\", {} scenario(s); incoming: \"{}\", {} scenario(s)). What should happen?",
+            incoming.id,
+            existing.title,
+            existing.scenarios.len(),
+            incoming.title,
+            incoming.scenarios.len()
+        );
+        let resolution = Select::new(&question, ConflictResolution::ALL.to_vec()).prompt()?;
+
+        if Confirm::new("Apply this choice to all remaining conflicts?")
+            .with_default(false)
+            .prompt()?
+        {
+            self.apply_to_all = Some(resolution);
+        }
+
+        Ok(resolution)
+    }
+}
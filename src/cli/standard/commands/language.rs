@@ -7,10 +7,18 @@ use anyhow::Result;
 /// that can be used when initializing or configuring use case projects.
 /// The output is printed to stdout for user reference.
 ///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `details` - Whether to show the templates and generated tests of each language.
+///
 /// # Returns
 /// Returns `Ok(())` on successful display, or an error if language retrieval fails.
-pub fn handle_languages_command() -> Result<()> {
-    let result = CliRunner::show_languages()?;
+pub fn handle_languages_command(runner: &mut CliRunner, details: bool) -> Result<()> {
+    let result = if details {
+        runner.show_language_details()?
+    } else {
+        CliRunner::show_languages()?
+    };
     println!("{}", result);
     Ok(())
 }
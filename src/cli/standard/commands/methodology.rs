@@ -1,3 +1,4 @@
+use super::CommandFailed;
 use crate::cli::standard::CliRunner;
 use crate::controller::DisplayResult;
 use crate::presentation::DisplayResultFormatter;
@@ -29,11 +30,16 @@ pub fn handle_list_methodologies_command(runner: &mut CliRunner) -> Result<()> {
 /// # Arguments
 /// * `runner` - A mutable reference to the CLI runner responsible for retrieving methodology info.
 /// * `name` - The name of the methodology to get information about.
+/// * `json` - Whether to print the information as JSON.
 ///
 /// # Returns
 /// Returns `Ok(())` on successful display, or an error if the methodology is not found or retrieval fails.
-pub fn handle_methodology_info_command(runner: &mut CliRunner, name: String) -> Result<()> {
-    let result = runner.get_methodology_info(name)?;
+pub fn handle_methodology_info_command(
+    runner: &mut CliRunner,
+    name: String,
+    json: bool,
+) -> Result<()> {
+    let result = runner.get_methodology_info(name, json)?;
     println!("{}", result);
     Ok(())
 }
@@ -45,12 +51,15 @@ pub fn handle_methodology_info_command(runner: &mut CliRunner, name: String) ->
 /// - No arguments or --all flag: Regenerates all use cases with their current methodologies.
 /// - With use_case_id only: Regenerates a single use case with its current methodology.
 /// - With use_case_id and --methodology: Regenerates a single use case with a different methodology.
+/// - With use_case_id and --view: Regenerates only that view's file of the use case.
 ///
 /// # Arguments
 /// * `runner` - A mutable reference to the CLI runner responsible for regeneration.
 /// * `use_case_id` - Optional ID of the specific use case to regenerate.
 /// * `methodology` - Optional name of the methodology to use for regeneration.
 /// * `all` - Flag indicating whether to regenerate all use cases.
+/// * `view` - Optional single view (`methodology:level`) to regenerate.
+/// * `overviews` - Named overviews to generate instead of use case documentation.
 ///
 /// # Returns
 /// Returns `Ok(())` on successful regeneration, or an error if regeneration fails or invalid arguments are provided.
@@ -59,7 +68,29 @@ pub fn handle_regenerate_command(
     use_case_id: Option<String>,
     methodology: Option<String>,
     all: bool,
+    view: Option<String>,
+    overviews: Vec<String>,
 ) -> Result<()> {
+    if let (Some(id), Some(view)) = (&use_case_id, view) {
+        let result = runner.regenerate_view(id.clone(), view)?;
+        DisplayResultFormatter::display(&result);
+        return if result.success {
+            Ok(())
+        } else {
+            return Err(CommandFailed.into());
+        };
+    }
+
+    if !overviews.is_empty() {
+        let result = runner.regenerate_overviews(overviews)?;
+        DisplayResultFormatter::display(&result);
+        return if result.success {
+            Ok(())
+        } else {
+            return Err(CommandFailed.into());
+        };
+    }
+
     match (use_case_id, methodology, all) {
         // No args or --all flag: regenerate all use cases
         (None, None, _) | (None, Some(_), true) => match runner.regenerate_all_use_cases() {
@@ -69,7 +100,7 @@ pub fn handle_regenerate_command(
             }
             Err(e) => {
                 DisplayResultFormatter::display(&DisplayResult::error(e.to_string()));
-                std::process::exit(1);
+                Err(CommandFailed.into())
             }
         },
         // Use case ID + methodology: regenerate with different methodology
@@ -80,12 +111,12 @@ pub fn handle_regenerate_command(
                     if result.success {
                         Ok(())
                     } else {
-                        std::process::exit(1);
+                        Err(CommandFailed.into())
                     }
                 }
                 Err(e) => {
                     DisplayResultFormatter::display(&DisplayResult::error(e.to_string()));
-                    std::process::exit(1);
+                    Err(CommandFailed.into())
                 }
             }
         }
@@ -97,7 +128,7 @@ pub fn handle_regenerate_command(
             }
             Err(e) => {
                 DisplayResultFormatter::display(&DisplayResult::error(e.to_string()));
-                std::process::exit(1);
+                Err(CommandFailed.into())
             }
         },
         // --all with methodology but no ID: error (doesn't make sense)
@@ -105,7 +136,7 @@ pub fn handle_regenerate_command(
             DisplayResultFormatter::display(&DisplayResult::error(
                 "Cannot specify --methodology without a use case ID. To regenerate all, use: mucm regenerate".to_string()
             ));
-            std::process::exit(1);
+            Err(CommandFailed.into())
         }
     }
 }
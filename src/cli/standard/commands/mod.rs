@@ -6,25 +6,77 @@
 /// focused on user interaction while the runner manages domain operations.
 // Private modules
 mod actor;
+mod at;
+mod batch;
+mod checklist;
 mod cleanup;
+mod config;
+mod estimate;
+mod explain;
 mod fields;
+mod grep;
+mod import;
 mod language;
 mod methodology;
+mod plan;
 mod project;
+mod reconcile;
+mod replay;
+mod restructure;
+mod sandbox;
 mod usecase;
+mod validate;
 
 // Explicit public exports
 pub use actor::handle_actor_command;
-pub use cleanup::handle_cleanup_command;
+pub use at::{handle_at_command, handle_undo_command};
+pub use batch::handle_batch_command;
+pub use checklist::handle_checklist_command;
+pub use cleanup::{handle_cleanup_command, handle_repair_command};
+pub use config::handle_config_command;
+pub use estimate::handle_estimate_command;
+pub use explain::handle_explain_command;
 pub use fields::{
     handle_postcondition_add_command, handle_postcondition_list_command,
     handle_postcondition_remove_command, handle_precondition_add_command,
     handle_precondition_list_command, handle_precondition_remove_command,
-    handle_reference_add_command, handle_reference_list_command, handle_reference_remove_command,
+    handle_reference_add_command, handle_reference_adr_command, handle_reference_list_command,
+    handle_reference_remove_command,
 };
+pub use grep::handle_grep_command;
+pub use import::handle_import_command;
 pub use language::handle_languages_command;
 pub use methodology::{
     handle_list_methodologies_command, handle_methodology_info_command, handle_regenerate_command,
 };
-pub use project::{handle_init_command, handle_status_command};
-pub use usecase::{handle_create_command, handle_list_command, handle_usecase_scenario_command};
+pub use plan::handle_plan_command;
+pub use project::{
+    handle_backup_command, handle_bench_command, handle_demo_command, handle_export_command,
+    handle_init_command, handle_manifest_command, handle_project_command, handle_publish_command,
+    handle_report_command, handle_status_command, handle_sync_command,
+};
+pub use reconcile::{handle_generate_command, handle_reconcile_command, handle_test_names_command};
+pub use replay::handle_replay_command;
+pub use restructure::{handle_clone_command, handle_merge_command, handle_split_command};
+pub use sandbox::handle_sandbox_command;
+pub use usecase::{
+    handle_create_command, handle_list_command, handle_usecase_scenario_command,
+    handle_usecase_view_command,
+};
+pub use validate::handle_validate_command;
+
+use std::fmt;
+
+/// Error of a command that already displayed why it failed
+///
+/// The dispatcher exits with a failure status without printing it again.
+#[derive(Debug)]
+pub struct CommandFailed;
+
+impl fmt::Display for CommandFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "command failed")
+    }
+}
+
+impl std::error::Error for CommandFailed {}
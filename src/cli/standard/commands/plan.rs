@@ -0,0 +1,148 @@
+//! Handler for the global `--explain` flag on mutating commands.
+
+use super::CommandFailed;
+use anyhow::Result;
+
+use crate::cli::args::{
+    AdrCommands, Commands, PostconditionCommands, PreconditionCommands, ReferenceCommands,
+    ScenarioStepCommands, UseCaseCommands, UseCaseScenarioCommands,
+    UseCaseScenarioReferenceCommands, UseCaseViewCommands,
+};
+use crate::cli::standard::CliRunner;
+use crate::controller::DisplayResult;
+use crate::core::PlannedMutation;
+use crate::presentation::DisplayResultFormatter;
+
+/// Prints the planned operations of a command as JSON instead of running it,
+/// exiting with an error when the command cannot be planned.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `command` - The command to plan.
+pub fn handle_plan_command(runner: &mut CliRunner, command: Commands) -> Result<()> {
+    let result = match command {
+        Commands::Create {
+            category,
+            methodology,
+            views,
+            ..
+        } => runner.plan_create(category, methodology, views)?,
+        Commands::Regenerate {
+            use_case_id,
+            methodology: None,
+            view: None,
+            overview,
+            ..
+        } if overview.is_empty() => {
+            runner.plan("regenerate", PlannedMutation::Regenerate { use_case_id })?
+        }
+        command => match use_case_edit(&command) {
+            Some((name, use_case_id)) => {
+                runner.plan(name, PlannedMutation::Update { use_case_id })?
+            }
+            None => DisplayResult::error(
+                "--explain is supported for create, regenerate, and commands that edit one \
+                 use case (use-case scenario/view, precondition, postcondition, reference)"
+                    .to_string(),
+            ),
+        },
+    };
+
+    if result.success {
+        println!("{}", result.message);
+        Ok(())
+    } else {
+        DisplayResultFormatter::display(&result);
+        Err(CommandFailed.into())
+    }
+}
+
+/// Command name and use case ID of a command that edits a single use case
+fn use_case_edit(command: &Commands) -> Option<(&'static str, String)> {
+    let edit = match command {
+        Commands::UseCase {
+            command: UseCaseCommands::Scenario { command },
+        } => match command {
+            UseCaseScenarioCommands::Add { use_case_id, .. } => {
+                ("use-case scenario add", use_case_id)
+            }
+            UseCaseScenarioCommands::Edit { use_case_id, .. } => {
+                ("use-case scenario edit", use_case_id)
+            }
+            UseCaseScenarioCommands::Delete { use_case_id, .. } => {
+                ("use-case scenario delete", use_case_id)
+            }
+            UseCaseScenarioCommands::Move { use_case_id, .. } => {
+                ("use-case scenario move", use_case_id)
+            }
+            UseCaseScenarioCommands::AssignPersona { use_case_id, .. } => {
+                ("use-case scenario assign-persona", use_case_id)
+            }
+            UseCaseScenarioCommands::UnassignPersona { use_case_id, .. } => {
+                ("use-case scenario unassign-persona", use_case_id)
+            }
+            UseCaseScenarioCommands::Tag { use_case_id, .. } => {
+                ("use-case scenario tag", use_case_id)
+            }
+            UseCaseScenarioCommands::Untag { use_case_id, .. } => {
+                ("use-case scenario untag", use_case_id)
+            }
+            UseCaseScenarioCommands::InheritConditions { use_case_id, .. } => {
+                ("use-case scenario inherit-conditions", use_case_id)
+            }
+            UseCaseScenarioCommands::Step { command } => match command {
+                ScenarioStepCommands::Add { use_case_id, .. } => {
+                    ("use-case scenario step add", use_case_id)
+                }
+                ScenarioStepCommands::Edit { use_case_id, .. } => {
+                    ("use-case scenario step edit", use_case_id)
+                }
+                ScenarioStepCommands::Remove { use_case_id, .. } => {
+                    ("use-case scenario step remove", use_case_id)
+                }
+            },
+            UseCaseScenarioCommands::Reference { command } => match command {
+                UseCaseScenarioReferenceCommands::Add { use_case_id, .. } => {
+                    ("use-case scenario reference add", use_case_id)
+                }
+                UseCaseScenarioReferenceCommands::Remove { use_case_id, .. } => {
+                    ("use-case scenario reference remove", use_case_id)
+                }
+                UseCaseScenarioReferenceCommands::List { .. } => return None,
+            },
+            UseCaseScenarioCommands::List { .. } => return None,
+        },
+        Commands::UseCase {
+            command:
+                UseCaseCommands::View {
+                    command: UseCaseViewCommands::Primary { use_case_id, .. },
+                },
+        } => ("use-case view primary", use_case_id),
+        Commands::Precondition { command } => match command {
+            PreconditionCommands::Add { use_case_id, .. } => ("precondition add", use_case_id),
+            PreconditionCommands::Remove { use_case_id, .. } => {
+                ("precondition remove", use_case_id)
+            }
+            PreconditionCommands::List { .. } => return None,
+        },
+        Commands::Postcondition { command } => match command {
+            PostconditionCommands::Add { use_case_id, .. } => ("postcondition add", use_case_id),
+            PostconditionCommands::Remove { use_case_id, .. } => {
+                ("postcondition remove", use_case_id)
+            }
+            PostconditionCommands::List { .. } => return None,
+        },
+        Commands::Reference { command } => match command {
+            ReferenceCommands::Add { use_case_id, .. } => ("reference add", use_case_id),
+            ReferenceCommands::Remove { use_case_id, .. } => ("reference remove", use_case_id),
+            ReferenceCommands::Adr { command } => match command {
+                AdrCommands::Add { use_case_id, .. } => ("reference adr add", use_case_id),
+                AdrCommands::Remove { use_case_id, .. } => ("reference adr remove", use_case_id),
+                AdrCommands::List { .. } => return None,
+            },
+            ReferenceCommands::List { .. } => return None,
+        },
+        _ => return None,
+    };
+    Some((edit.0, edit.1.trim().to_string()))
+}
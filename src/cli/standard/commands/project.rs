@@ -1,8 +1,14 @@
+use crate::cli::args::{
+    BackupCommands, ProjectCommands, PublishCommands, ReportCommands, SyncCommands,
+};
 use crate::cli::standard::CliRunner;
 use crate::controller::DisplayResult;
 use crate::presentation::DisplayResultFormatter;
 use anyhow::Result;
 
+/// Formats `mucm export` accepts, also in place of the use case ID
+const EXPORT_FORMATS: [&str; 7] = ["html", "pdf", "json", "csv", "xlsx", "reqif", "mdbook"];
+
 /// Handles the 'init' CLI command.
 ///
 /// Initializes a new use case manager project in the current directory.
@@ -15,7 +21,7 @@ use anyhow::Result;
 /// * `runner` - A mutable reference to the CLI runner responsible for project initialization.
 /// * `language` - Optional programming language to configure for the project.
 /// * `methodologies` - List of methodologies to enable for the project.
-/// * `storage` - Storage backend to use (toml or sqlite).
+/// * `storage` - Storage backend to use (toml, sqlite or events).
 /// * `finalize` - Whether to finalize the initialization (true) or perform initial setup (false).
 ///
 /// # Returns
@@ -47,13 +53,181 @@ pub fn handle_init_command(
 ///
 /// Displays the current status of the use case manager project,
 /// including information about initialized state, configured settings,
-/// and available use cases. The status output is printed to stdout.
+/// available use cases, and the health score with its breakdown.
+/// The status output is printed to stdout.
 ///
 /// # Arguments
 /// * `runner` - A mutable reference to the CLI runner responsible for retrieving project status.
+/// * `json` - Whether to print the status as JSON.
 ///
 /// # Returns
 /// Returns `Ok(())` on successful status display, or an error if status retrieval fails.
-pub fn handle_status_command(runner: &mut CliRunner) -> Result<()> {
-    runner.show_status()
+pub fn handle_status_command(runner: &mut CliRunner, json: bool) -> Result<()> {
+    runner.show_status(json)
+}
+
+/// Handles the 'manifest' CLI command.
+///
+/// Regenerates `mucm-manifest.json`, the machine-readable index of use cases,
+/// file paths, statuses, and relations consumed by editor extensions and dashboards.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+///
+/// # Returns
+/// Returns `Ok(())` on success, or an error if the manifest cannot be written.
+pub fn handle_manifest_command(runner: &mut CliRunner) -> Result<()> {
+    let result = runner.generate_manifest()?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handles the 'export' CLI command.
+///
+/// Regenerates the markdown documentation and renders it in the requested
+/// format: HTML pages with navigation, or a PDF of one or all use cases.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `format` - The output format; html when unset, unless `use_case_id` names a format.
+/// * `output` - The output directory or file, relative to the project root.
+/// * `use_case_id` - The use case to export alone (PDF only).
+/// * `tag` - Only export the scenarios with this tag and their use cases.
+///
+/// # Returns
+/// Returns `Ok(())` on success, or an error if the export fails.
+pub fn handle_export_command(
+    runner: &mut CliRunner,
+    format: Option<String>,
+    output: Option<String>,
+    use_case_id: Option<String>,
+    tag: Option<String>,
+) -> Result<()> {
+    // `mucm export mdbook` names the format where the use case ID goes
+    let (format, use_case_id) = match (format, use_case_id) {
+        (None, Some(id)) if EXPORT_FORMATS.contains(&id.to_lowercase().as_str()) => (id, None),
+        (format, use_case_id) => (format.unwrap_or_else(|| "html".to_string()), use_case_id),
+    };
+    let result = runner.export_docs(format, output, use_case_id, tag)?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handles the 'publish' CLI subcommands.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `command` - The publish subcommand to execute.
+pub fn handle_publish_command(runner: &mut CliRunner, command: PublishCommands) -> Result<()> {
+    let result = match command {
+        PublishCommands::Confluence => runner.publish_confluence()?,
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handles the 'sync' CLI subcommands.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `command` - The sync subcommand to execute.
+pub fn handle_sync_command(runner: &mut CliRunner, command: SyncCommands) -> Result<()> {
+    let result = match command {
+        SyncCommands::Jira => runner.sync_jira()?,
+        SyncCommands::Github { pull_status } => runner.sync_github(pull_status)?,
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handles the 'project' CLI subcommands.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `command` - The project subcommand to execute.
+pub fn handle_project_command(runner: &mut CliRunner, command: ProjectCommands) -> Result<()> {
+    match command {
+        ProjectCommands::Rename {
+            name,
+            description,
+            id_prefix,
+        } => {
+            let result = runner.rename_project(name, description, id_prefix)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+    }
+}
+
+/// Handles the 'report' CLI subcommands.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `command` - The report subcommand to execute.
+pub fn handle_report_command(runner: &mut CliRunner, command: ReportCommands) -> Result<()> {
+    match command {
+        ReportCommands::Aggregate {
+            projects,
+            output,
+            filter_name,
+        } => {
+            let result = runner.aggregate_report(projects, output, filter_name)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+        ReportCommands::ReleaseNotes { since, output } => {
+            let result = runner.release_notes(since, output)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+        ReportCommands::Ids { renumber } => {
+            let result = runner.id_report(renumber)?;
+            DisplayResultFormatter::display(&result);
+            Ok(())
+        }
+    }
+}
+
+/// Handles the 'backup' CLI subcommands.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `command` - The backup subcommand to execute.
+pub fn handle_backup_command(runner: &mut CliRunner, command: BackupCommands) -> Result<()> {
+    let result = match command {
+        BackupCommands::Now => runner.backup_now()?,
+        BackupCommands::List => runner.list_backups()?,
+        BackupCommands::Restore { snapshot, use_case } => {
+            runner.restore_backup(snapshot, use_case)?
+        }
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handles the hidden `bench` command.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `use_cases` - Number of synthetic use cases to generate.
+/// * `backend` - Storage backend to measure.
+pub fn handle_bench_command(
+    runner: &mut CliRunner,
+    use_cases: usize,
+    backend: String,
+) -> Result<()> {
+    let result = runner.bench(use_cases, backend)?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handles the `demo` command.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `directory` - Directory to create the example project in.
+pub fn handle_demo_command(runner: &mut CliRunner, directory: String) -> Result<()> {
+    let result = runner.demo(directory)?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
 }
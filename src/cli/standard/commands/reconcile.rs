@@ -0,0 +1,57 @@
+/// Reconcile command handler for comparing generated test files with scenarios.
+use anyhow::Result;
+
+use crate::cli::args::{GenerateCommands, TestNameCommands};
+use crate::cli::standard::runner::CliRunner;
+use crate::presentation::DisplayResultFormatter;
+
+/// Handle the reconcile command.
+///
+/// Parses the generated test files and reports scenarios without tests and
+/// tests without scenarios, optionally scaffolding the missing scenarios.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `use_case_id` - Optional specific use case to check (checks all if None)
+/// * `create_missing_scenarios` - If true, creates stub scenarios for untracked tests
+pub fn handle_reconcile_command(
+    runner: &mut CliRunner,
+    use_case_id: Option<String>,
+    create_missing_scenarios: bool,
+) -> Result<()> {
+    let result = runner.reconcile_tests(use_case_id, create_missing_scenarios)?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handle the test-names subcommands.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `command` - The test-names subcommand to execute
+pub fn handle_test_names_command(runner: &mut CliRunner, command: TestNameCommands) -> Result<()> {
+    let result = match command {
+        TestNameCommands::List { use_case_id } => runner.list_test_names(use_case_id)?,
+        TestNameCommands::Rename { scenario_id, name } => runner.rename_test(scenario_id, name)?,
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handle the generate subcommands.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `command` - The generate subcommand to execute
+pub fn handle_generate_command(runner: &mut CliRunner, command: GenerateCommands) -> Result<()> {
+    let result = match command {
+        GenerateCommands::Tests {
+            use_case_id,
+            only_missing,
+            force,
+            tag,
+        } => runner.generate_tests(use_case_id, only_missing, force, tag)?,
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
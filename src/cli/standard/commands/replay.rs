@@ -0,0 +1,64 @@
+/// Replay command handler for re-applying interactive session logs.
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::process::Command;
+
+use crate::cli::interactive::session_log::{format_command, parse_command};
+
+/// Commands that cannot appear in a session log
+const NOT_REPLAYABLE: &[&str] = &["replay", "interactive", "lsp"];
+
+/// Handle the replay command.
+///
+/// Every `mucm ...` line of the log is run in order, in the current directory,
+/// by the running `mucm` binary. Comment lines are skipped. The whole log is
+/// parsed before anything runs, and replay stops at the first failing command.
+///
+/// # Arguments
+/// * `log` - Path of the session log
+/// * `dry_run` - Print the commands without running them
+pub fn handle_replay_command(log: String, dry_run: bool) -> Result<()> {
+    let content =
+        fs::read_to_string(&log).with_context(|| format!("Failed to read session log {}", log))?;
+
+    let mut commands = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let parsed = parse_command(line).with_context(|| format!("{}:{}", log, index + 1))?;
+        if let Some(args) = parsed {
+            if let Some(command) = args
+                .first()
+                .filter(|command| NOT_REPLAYABLE.contains(&command.as_str()))
+            {
+                bail!("{}:{}: '{}' cannot be replayed", log, index + 1, command);
+            }
+            commands.push((index + 1, args));
+        }
+    }
+
+    if commands.is_empty() {
+        println!("No commands to replay in {}", log);
+        return Ok(());
+    }
+
+    let binary = std::env::current_exe().context("Failed to locate the mucm binary")?;
+    for (line, args) in &commands {
+        println!("▶ {}", format_command(args));
+        if dry_run {
+            continue;
+        }
+        let status = Command::new(&binary)
+            .args(args)
+            .status()
+            .with_context(|| format!("Failed to run line {}", line))?;
+        if !status.success() {
+            bail!("Replay stopped: line {} of {} failed", line, log);
+        }
+    }
+
+    if dry_run {
+        println!("\n{} command(s) would be replayed", commands.len());
+    } else {
+        println!("\n✅ Replayed {} command(s) from {}", commands.len(), log);
+    }
+    Ok(())
+}
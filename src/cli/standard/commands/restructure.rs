@@ -0,0 +1,132 @@
+//! Clone, split, and merge command handlers, including interactive merge conflict resolution.
+
+use anyhow::Result;
+use inquire::{Confirm, Select};
+use std::io::IsTerminal;
+
+use crate::cli::standard::CliRunner;
+use crate::core::{MergeChoice, MergeConflict};
+use crate::presentation::DisplayResultFormatter;
+
+/// Handle the clone command.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `use_case_id` - The use case to copy
+/// * `title` - Title of the new use case
+/// * `category` - Optional category of the new use case
+/// * `include_scenarios` - Copy scenarios
+/// * `include_conditions` - Copy preconditions and postconditions
+pub fn handle_clone_command(
+    runner: &mut CliRunner,
+    use_case_id: String,
+    title: String,
+    category: Option<String>,
+    include_scenarios: bool,
+    include_conditions: bool,
+) -> Result<()> {
+    let result = runner.clone_use_case(
+        use_case_id,
+        title,
+        category,
+        include_scenarios,
+        include_conditions,
+    )?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handle the split command.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `use_case_id` - The use case to split
+/// * `scenario_ids` - Scenarios to move into the new use case
+/// * `title` - Title of the new use case
+/// * `category` - Optional category of the new use case
+pub fn handle_split_command(
+    runner: &mut CliRunner,
+    use_case_id: String,
+    scenario_ids: Vec<String>,
+    title: String,
+    category: Option<String>,
+) -> Result<()> {
+    let result = runner.split_use_case(use_case_id, scenario_ids, title, category)?;
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Handle the merge command.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+/// * `target_id` - The use case that is kept
+/// * `source_id` - The use case that is merged in and removed
+/// * `on_conflict` - `ask` or a fixed [`MergeChoice`] policy
+pub fn handle_merge_command(
+    runner: &mut CliRunner,
+    target_id: String,
+    source_id: String,
+    on_conflict: String,
+) -> Result<()> {
+    let result = if on_conflict.eq_ignore_ascii_case("ask") {
+        let mut prompt = MergePrompt::default();
+        runner.merge_use_cases(target_id, source_id, |conflict| prompt.resolve(conflict))?
+    } else {
+        let policy: MergeChoice = on_conflict
+            .parse()
+            .map_err(|e: String| anyhow::anyhow!(e))?;
+        runner.merge_use_cases(target_id, source_id, |conflict| {
+            // Fields can't hold both values, so keep-both keeps the target's
+            Ok(match policy {
+                MergeChoice::KeepBoth if !conflict.can_keep_both => MergeChoice::KeepTarget,
+                choice => choice,
+            })
+        })?
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
+
+/// Asks the user how to resolve each merge conflict, remembering an "apply to all" answer
+#[derive(Default)]
+struct MergePrompt {
+    apply_to_all: Option<MergeChoice>,
+}
+
+impl MergePrompt {
+    fn resolve(&mut self, conflict: &MergeConflict) -> Result<MergeChoice> {
+        if let Some(choice) = self.apply_to_all {
+            if choice != MergeChoice::KeepBoth || conflict.can_keep_both {
+                return Ok(choice);
+            }
+        }
+
+        if !std::io::stdin().is_terminal() {
+            anyhow::bail!(
+                "The {} differs and no terminal is available to ask. \
+                 Pass --on-conflict keep-target|use-source|keep-both",
+                conflict.subject
+            );
+        }
+
+        let question = format!(
+            "The {} differs (target: \"{}\"; merged-in: \"{}\"). What should happen?",
+            conflict.subject, conflict.target_value, conflict.source_value
+        );
+        let mut options = vec![MergeChoice::KeepTarget, MergeChoice::UseSource];
+        if conflict.can_keep_both {
+            options.push(MergeChoice::KeepBoth);
+        }
+        let choice = Select::new(&question, options).prompt()?;
+
+        if Confirm::new("Apply this choice to all remaining conflicts?")
+            .with_default(false)
+            .prompt()?
+        {
+            self.apply_to_all = Some(choice);
+        }
+
+        Ok(choice)
+    }
+}
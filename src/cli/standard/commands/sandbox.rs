@@ -0,0 +1,23 @@
+//! Sandbox command handlers.
+
+use anyhow::Result;
+
+use crate::cli::args::SandboxCommands;
+use crate::cli::standard::CliRunner;
+use crate::presentation::DisplayResultFormatter;
+
+/// Handles the 'sandbox' CLI subcommands.
+///
+/// # Arguments
+/// * `runner` - A mutable reference to the CLI runner.
+/// * `command` - The sandbox subcommand to execute.
+pub fn handle_sandbox_command(runner: &mut CliRunner, command: SandboxCommands) -> Result<()> {
+    let result = match command {
+        SandboxCommands::Create => runner.create_sandbox()?,
+        SandboxCommands::Diff => runner.sandbox_diff()?,
+        SandboxCommands::Apply { discard } => runner.apply_sandbox(discard)?,
+        SandboxCommands::Discard => runner.discard_sandbox()?,
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
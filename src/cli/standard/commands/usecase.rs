@@ -1,3 +1,4 @@
+use super::CommandFailed;
 use crate::cli::standard::CliRunner;
 use crate::controller::DisplayResult;
 use crate::presentation::DisplayResultFormatter;
@@ -54,7 +55,7 @@ pub fn handle_create_command(
     if result.success {
         Ok(())
     } else {
-        std::process::exit(1);
+        Err(CommandFailed.into())
     }
 }
 
@@ -64,13 +65,28 @@ pub fn handle_create_command(
 /// including their titles, categories, and current status.
 /// The formatted list is printed to stdout for user reference.
 ///
+/// With `needs_review`, only use cases whose review date has arrived are shown.
+/// With `filter_name`, only use cases matching that saved filter are shown.
+///
 /// # Arguments
 /// * `runner` - A mutable reference to the CLI runner responsible for listing use cases.
+/// * `needs_review` - Whether to list only use cases due for review.
+/// * `filter_name` - Optional saved filter from the config.
 ///
 /// # Returns
 /// Returns `Ok(())` on successful display, or an error if retrieval fails.
-pub fn handle_list_command(runner: &mut CliRunner) -> Result<()> {
-    runner.list_use_cases()
+pub fn handle_list_command(
+    runner: &mut CliRunner,
+    needs_review: bool,
+    filter_name: Option<String>,
+) -> Result<()> {
+    if let Some(filter_name) = filter_name {
+        runner.list_filtered_use_cases(filter_name, needs_review)
+    } else if needs_review {
+        runner.list_use_cases_needing_review()
+    } else {
+        runner.list_use_cases()
+    }
 }
 
 /// Handle use case scenario commands
@@ -93,6 +109,7 @@ pub fn handle_usecase_scenario_command(
             scenario_type,
             description,
             persona,
+            tags,
         } => {
             let result = controller.create_scenario(
                 use_case_id,
@@ -102,6 +119,7 @@ pub fn handle_usecase_scenario_command(
                 persona,
                 None, // preconditions
                 None, // postconditions
+                Some(tags),
             )?;
             DisplayResultFormatter::display(&result);
         }
@@ -134,6 +152,20 @@ pub fn handle_usecase_scenario_command(
             let result = controller.list_scenarios(use_case_id)?;
             DisplayResultFormatter::display(&result);
         }
+        UseCaseScenarioCommands::Move {
+            use_case_id,
+            scenario_id,
+            before,
+            after,
+        } => {
+            let (target_id, before) = match (before, after) {
+                (Some(target_id), _) => (target_id, true),
+                (None, Some(target_id)) => (target_id, false),
+                (None, None) => anyhow::bail!("Specify --before or --after"),
+            };
+            let result = controller.move_scenario(use_case_id, scenario_id, target_id, before)?;
+            DisplayResultFormatter::display(&result);
+        }
         UseCaseScenarioCommands::Step { command } => {
             handle_scenario_step_command(&mut controller, command)?;
         }
@@ -152,6 +184,32 @@ pub fn handle_usecase_scenario_command(
             let result = controller.unassign_persona(use_case_id, scenario_id)?;
             DisplayResultFormatter::display(&result);
         }
+        UseCaseScenarioCommands::Tag {
+            use_case_id,
+            scenario_id,
+            tags,
+        } => {
+            let result = controller.tag_scenario(use_case_id, scenario_id, tags)?;
+            DisplayResultFormatter::display(&result);
+        }
+        UseCaseScenarioCommands::Untag {
+            use_case_id,
+            scenario_id,
+            tags,
+        } => {
+            let result = controller.untag_scenario(use_case_id, scenario_id, tags)?;
+            DisplayResultFormatter::display(&result);
+        }
+        UseCaseScenarioCommands::InheritConditions {
+            use_case_id,
+            scenario_id,
+            conditions,
+            off,
+        } => {
+            let result =
+                controller.inherit_conditions(use_case_id, scenario_id, conditions, !off)?;
+            DisplayResultFormatter::display(&result);
+        }
         UseCaseScenarioCommands::Reference { command } => {
             handle_scenario_reference_command(&mut controller, command)?;
         }
@@ -278,3 +336,20 @@ fn handle_scenario_reference_command(
 
     Ok(())
 }
+
+/// Handle use case view commands (`mucm use-case view <subcommand>`)
+pub fn handle_usecase_view_command(
+    runner: &mut CliRunner,
+    command: crate::cli::args::UseCaseViewCommands,
+) -> Result<()> {
+    use crate::cli::args::UseCaseViewCommands;
+
+    let result = match command {
+        UseCaseViewCommands::Primary {
+            use_case_id,
+            methodology,
+        } => runner.set_primary_view(use_case_id, methodology)?,
+    };
+    DisplayResultFormatter::display(&result);
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! Validate command handler.
+
+use super::CommandFailed;
+use anyhow::Result;
+
+use crate::cli::standard::CliRunner;
+use crate::presentation::DisplayResultFormatter;
+
+/// Handle the validate command, exiting with an error when problems are found.
+///
+/// # Arguments
+/// * `runner` - CLI runner instance
+pub fn handle_validate_command(runner: &mut CliRunner) -> Result<()> {
+    let result = runner.validate()?;
+    DisplayResultFormatter::display(&result);
+
+    if result.success {
+        Ok(())
+    } else {
+        Err(CommandFailed.into())
+    }
+}
@@ -26,12 +26,20 @@ pub use runner::CliRunner;
 
 // Re-export command functions for the main CLI dispatcher
 pub use commands::{
-    handle_actor_command, handle_cleanup_command, handle_create_command, handle_init_command,
-    handle_languages_command, handle_list_command, handle_list_methodologies_command,
-    handle_methodology_info_command, handle_postcondition_add_command,
+    handle_actor_command, handle_at_command, handle_backup_command, handle_batch_command,
+    handle_bench_command, handle_checklist_command, handle_cleanup_command, handle_clone_command,
+    handle_config_command, handle_create_command, handle_demo_command, handle_estimate_command,
+    handle_explain_command, handle_export_command, handle_generate_command, handle_grep_command,
+    handle_import_command, handle_init_command, handle_languages_command, handle_list_command,
+    handle_list_methodologies_command, handle_manifest_command, handle_merge_command,
+    handle_methodology_info_command, handle_plan_command, handle_postcondition_add_command,
     handle_postcondition_list_command, handle_postcondition_remove_command,
     handle_precondition_add_command, handle_precondition_list_command,
-    handle_precondition_remove_command, handle_reference_add_command,
+    handle_precondition_remove_command, handle_project_command, handle_publish_command,
+    handle_reconcile_command, handle_reference_add_command, handle_reference_adr_command,
     handle_reference_list_command, handle_reference_remove_command, handle_regenerate_command,
-    handle_status_command, handle_usecase_scenario_command,
+    handle_repair_command, handle_replay_command, handle_report_command, handle_sandbox_command,
+    handle_split_command, handle_status_command, handle_sync_command, handle_test_names_command,
+    handle_undo_command, handle_usecase_scenario_command, handle_usecase_view_command,
+    handle_validate_command, CommandFailed,
 };
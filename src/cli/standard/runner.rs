@@ -10,9 +10,14 @@
 ///
 /// The runner maintains lazy-loaded controllers to avoid unnecessary initialization
 /// and provides a clean, error-handling facade for CLI command handlers.
-use anyhow::Result;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
 
+use crate::config::{Config, ProjectContext, UserConfig};
 use crate::controller::{DisplayResult, ProjectController, UseCaseController};
+use crate::core::{
+    ConflictResolution, EstimateRequest, MergeChoice, MergeConflict, PlannedMutation, UseCase,
+};
 
 /// CLI runner that delegates to controllers
 /// This is a thin adapter between CLI interface and business logic
@@ -77,7 +82,7 @@ impl CliRunner {
     /// # Arguments
     /// * `language` - Optional programming language for code templates
     /// * `methodologies` - List of methodologies to enable
-    /// * `storage` - Storage backend to use (toml or sqlite)
+    /// * `storage` - Storage backend to use (toml, sqlite or events)
     ///
     /// # Returns
     /// Returns a DisplayResult with success message.
@@ -232,16 +237,69 @@ impl CliRunner {
         controller.list_use_cases()
     }
 
+    /// List the use cases as they were at a point in time.
+    ///
+    /// # Arguments
+    /// * `date` - A date (YYYY-MM-DD) or RFC 3339 timestamp
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, or an error if the date is invalid or the
+    /// project does not use the event-log backend.
+    pub fn list_use_cases_at(&mut self, date: String) -> Result<()> {
+        let date = Self::sanitize_required_string(date);
+        let controller = self.ensure_use_case_controller()?;
+        controller.list_use_cases_at(&date)
+    }
+
+    /// Undo the latest change recorded in the event log.
+    ///
+    /// # Returns
+    /// DisplayResult describing what was undone
+    pub fn undo(&mut self) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.undo()
+    }
+
+    /// List use cases whose review date has arrived.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, or an error if listing fails.
+    pub fn list_use_cases_needing_review(&mut self) -> Result<()> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.list_use_cases_needing_review()
+    }
+
+    /// List the use cases matching a saved filter.
+    ///
+    /// # Arguments
+    /// * `filter_name` - Name of a `[filters.<name>]` table in the config
+    /// * `needs_review` - Whether to list only use cases due for review
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, or an error if the filter is unknown.
+    pub fn list_filtered_use_cases(
+        &mut self,
+        filter_name: String,
+        needs_review: bool,
+    ) -> Result<()> {
+        let filter_name = Self::sanitize_required_string(filter_name);
+        let controller = self.ensure_use_case_controller()?;
+        controller.list_filtered_use_cases(&filter_name, needs_review)
+    }
+
     /// Display the current project status.
     ///
     /// Shows information about the project's initialization state,
-    /// configuration, and available use cases.
+    /// configuration, available use cases, and health score.
+    ///
+    /// # Arguments
+    /// * `json` - Print the status as JSON for dashboards
     ///
     /// # Returns
     /// Returns `Ok(())` on success, or an error if status retrieval fails.
-    pub fn show_status(&mut self) -> Result<()> {
+    pub fn show_status(&mut self, json: bool) -> Result<()> {
         let controller = self.ensure_use_case_controller()?;
-        controller.show_status()
+        controller.show_status(json)
     }
 
     /// Display available programming languages.
@@ -254,6 +312,21 @@ impl CliRunner {
         ProjectController::show_languages()
     }
 
+    /// Display the details of the programming languages.
+    ///
+    /// Inside a project, also shows the local template copies and the use
+    /// cases with generated tests.
+    ///
+    /// # Returns
+    /// Returns the formatted language details.
+    pub fn show_language_details(&mut self) -> Result<String> {
+        if Config::config_path().exists() {
+            let controller = self.ensure_use_case_controller()?;
+            return controller.show_language_details();
+        }
+        ProjectController::show_language_details()
+    }
+
     /// List all available methodologies.
     ///
     /// Retrieves and formats information about all supported documentation
@@ -278,17 +351,20 @@ impl CliRunner {
 
     /// Get detailed information about a specific methodology.
     ///
-    /// Retrieves comprehensive information about the requested methodology,
-    /// including its display name, description, when to use it, and key features.
+    /// Retrieves comprehensive information about the requested methodology:
+    /// its description, usage guidance, and for each documentation level its
+    /// templates and custom fields (inherited ones included).
     ///
     /// # Arguments
     /// * `methodology` - The name of the methodology to query
+    /// * `json` - Format the details as JSON instead of text with field tables
     ///
     /// # Returns
-    /// Returns a formatted string with methodology details, or a not-found message.
-    pub fn get_methodology_info(&mut self, methodology: String) -> Result<String> {
+    /// Returns the formatted methodology details, or a not-found message.
+    pub fn get_methodology_info(&mut self, methodology: String, json: bool) -> Result<String> {
         use crate::config::Config;
-        use crate::core::{Methodology, MethodologyRegistry};
+        use crate::core::MethodologyRegistry;
+        use crate::presentation::MethodologyFormatter;
 
         let sanitized_methodology = Self::sanitize_required_string(methodology);
 
@@ -297,38 +373,10 @@ impl CliRunner {
         let registry = MethodologyRegistry::new_dynamic(&templates_dir)?;
 
         match registry.get(&sanitized_methodology) {
-            Some(methodology) => {
-                let mut result = format!(
-                    "=== {} ===\n\n{}\n\n",
-                    methodology.title(),
-                    methodology.description()
-                );
-
-                result.push_str("When to Use:\n");
-                for item in methodology.when_to_use() {
-                    result.push_str(&format!("  • {}\n", item));
-                }
-
-                result.push_str("\nKey Features:\n");
-                for item in methodology.key_features() {
-                    result.push_str(&format!("  • {}\n", item));
-                }
-
-                result.push_str("\nAvailable Levels:\n");
-                for level in methodology.levels() {
-                    result.push_str(&format!(
-                        "  • {} ({}): {}\n",
-                        level.name, level.filename, level.description
-                    ));
-                }
-
-                result.push_str(&format!(
-                    "\nPreferred Style: {}\n",
-                    methodology.preferred_style()
-                ));
-
-                Ok(result)
-            }
+            Some(methodology) if json => Ok(serde_json::to_string_pretty(
+                &MethodologyFormatter::info_json(methodology),
+            )?),
+            Some(methodology) => Ok(MethodologyFormatter::info_text(methodology)),
             None => Ok(format!(
                 "Methodology '{}' not found.",
                 sanitized_methodology
@@ -374,6 +422,22 @@ impl CliRunner {
         controller.regenerate_use_case(&Self::sanitize_required_string(use_case_id))
     }
 
+    /// Regenerate the documentation of one view of a use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `view` - The view to regenerate, as `methodology:level`
+    ///
+    /// # Returns
+    /// DisplayResult naming the regenerated file
+    pub fn regenerate_view(&mut self, use_case_id: String, view: String) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.regenerate_view(
+            &Self::sanitize_required_string(use_case_id),
+            &Self::sanitize_required_string(view),
+        )
+    }
+
     /// Regenerate documentation for all use cases.
     ///
     /// Regenerates markdown documentation for all use cases in the project
@@ -386,6 +450,22 @@ impl CliRunner {
         controller.regenerate_all_use_cases()
     }
 
+    /// Generate named overviews, each into its own file.
+    ///
+    /// # Arguments
+    /// * `names` - Names of overview templates in `template-assets/overviews/`
+    ///
+    /// # Returns
+    /// DisplayResult listing the written files
+    pub fn regenerate_overviews(&mut self, names: Vec<String>) -> Result<DisplayResult> {
+        let names = names
+            .into_iter()
+            .map(Self::sanitize_required_string)
+            .collect();
+        let controller = self.ensure_use_case_controller()?;
+        controller.regenerate_overviews(names)
+    }
+
     /// Add a precondition to a use case.
     ///
     /// Adds a new precondition to the specified use case.
@@ -587,11 +667,58 @@ impl CliRunner {
         )
     }
 
-    /// List use cases that use a specific persona
+    /// Link an architecture decision record to a use case.
     ///
     /// # Arguments
-    /// * `persona_id` - The persona identifier to search for
+    /// * `use_case_id` - The ID of the use case
+    /// * `path` - ADR file, relative to the project root
+    ///
+    /// # Returns
+    /// DisplayResult with success message
+    pub fn add_adr(&mut self, use_case_id: String, path: String) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.add_adr(
+            Self::sanitize_required_string(use_case_id),
+            Self::sanitize_required_string(path),
+        )
+    }
+
+    /// List the architecture decision records linked to a use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    ///
+    /// # Returns
+    /// DisplayResult with the ADR list
+    pub fn list_adrs(&mut self, use_case_id: String) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.list_adrs(Self::sanitize_required_string(use_case_id))
+    }
+
+    /// Unlink an architecture decision record from a use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `path` - ADR file as linked
+    ///
+    /// # Returns
+    /// DisplayResult with success message
+    pub fn remove_adr(&mut self, use_case_id: String, path: String) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.remove_adr(
+            Self::sanitize_required_string(use_case_id),
+            Self::sanitize_required_string(path),
+        )
+    }
+
+    /// Validate the project's references and ADR links.
     ///
+    /// # Returns
+    /// DisplayResult listing the issues; an error result when there are any
+    pub fn validate(&mut self) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.validate()
+    }
 
     /// Clean up orphaned methodology fields from use cases
     ///
@@ -614,6 +741,711 @@ impl CliRunner {
         let controller = self.ensure_use_case_controller()?;
         controller.cleanup_methodology_fields(use_case_id, dry_run)
     }
+
+    /// Recover data files that fail to load, quarantining the rest.
+    ///
+    /// # Returns
+    /// DisplayResult with the repair summary
+    pub fn repair(&mut self) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.repair()
+    }
+
+    /// Explain where a use case, scenario, or generated file comes from.
+    ///
+    /// # Arguments
+    /// * `target` - Use case ID, scenario ID, or file path
+    ///
+    /// # Returns
+    /// DisplayResult with the provenance report
+    pub fn explain(&mut self, target: String) -> Result<DisplayResult> {
+        let target = Self::sanitize_required_string(target);
+        let controller = self.ensure_use_case_controller()?;
+        controller.explain(target)
+    }
+
+    /// Search the data of every use case.
+    ///
+    /// # Arguments
+    /// * `pattern` - Regular expression, or literal text with `fixed_strings`
+    /// * `ignore_case` - Match case-insensitively
+    /// * `fixed_strings` - Treat the pattern as literal text
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, or an error if the pattern is invalid.
+    pub fn grep(&mut self, pattern: String, ignore_case: bool, fixed_strings: bool) -> Result<()> {
+        let pattern = Self::sanitize_required_string(pattern);
+        let controller = self.ensure_use_case_controller()?;
+        controller.grep(pattern, ignore_case, fixed_strings)
+    }
+
+    /// Snapshot all data files into a new backup.
+    ///
+    /// # Returns
+    /// DisplayResult with the snapshot location
+    pub fn backup_now(&mut self) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.backup_now()
+    }
+
+    /// List backup snapshots.
+    ///
+    /// # Returns
+    /// DisplayResult with the snapshots, newest first
+    pub fn list_backups(&mut self) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.list_backups()
+    }
+
+    /// Restore data files from a backup snapshot.
+    ///
+    /// # Arguments
+    /// * `snapshot` - Snapshot name
+    /// * `use_case_id` - Restore only this use case
+    ///
+    /// # Returns
+    /// DisplayResult with the restored files
+    pub fn restore_backup(
+        &mut self,
+        snapshot: String,
+        use_case_id: Option<String>,
+    ) -> Result<DisplayResult> {
+        let snapshot = Self::sanitize_required_string(snapshot);
+        let use_case_id = Self::sanitize_optional_string(use_case_id);
+        let controller = self.ensure_use_case_controller()?;
+        controller.restore_backup(snapshot, use_case_id)
+    }
+
+    /// Regenerate the JSON manifest for editor extensions and dashboards.
+    ///
+    /// # Returns
+    /// DisplayResult with the manifest path
+    pub fn generate_manifest(&mut self) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.generate_manifest()
+    }
+
+    /// Export the documentation as HTML pages or as a PDF document.
+    ///
+    /// # Returns
+    /// DisplayResult with what was written and where
+    pub fn export_docs(
+        &mut self,
+        format: String,
+        output: Option<String>,
+        use_case_id: Option<String>,
+        tag: Option<String>,
+    ) -> Result<DisplayResult> {
+        let format = Self::sanitize_required_string(format);
+        let output = Self::sanitize_optional_string(output);
+        let use_case_id = Self::sanitize_optional_string(use_case_id);
+        let tag = Self::sanitize_optional_string(tag);
+        let controller = self.ensure_use_case_controller()?;
+        controller.export_docs(format, output, use_case_id, tag)
+    }
+
+    /// Publish the documentation to Confluence.
+    ///
+    /// # Returns
+    /// DisplayResult with the number of pages created and updated
+    pub fn publish_confluence(&mut self) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.publish_confluence()
+    }
+
+    /// Synchronize the use cases with Jira issues.
+    ///
+    /// # Returns
+    /// DisplayResult with the sync summary
+    pub fn sync_jira(&mut self) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.sync_jira()
+    }
+
+    /// Synchronize the use cases with GitHub issues.
+    ///
+    /// # Arguments
+    /// * `pull_status` - Update the scenario statuses from the issue states
+    ///
+    /// # Returns
+    /// DisplayResult with the sync summary
+    pub fn sync_github(&mut self, pull_status: bool) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.sync_github(pull_status)
+    }
+
+    /// Import use cases from TOML data files
+    ///
+    /// # Arguments
+    /// * `path` - Data file or directory to import from
+    /// * `resolve` - Decides what to do when an incoming ID already exists
+    ///
+    /// # Returns
+    /// DisplayResult with the import summary
+    pub fn import_use_cases<F>(&mut self, path: String, resolve: F) -> Result<DisplayResult>
+    where
+        F: FnMut(&UseCase, &UseCase) -> Result<ConflictResolution>,
+    {
+        let path = Self::sanitize_required_string(path);
+        let controller = self.ensure_use_case_controller()?;
+        controller.import_use_cases(&path, resolve)
+    }
+
+    /// Import use cases from a JSON export document
+    ///
+    /// # Arguments
+    /// * `path` - Document written by `mucm export --format json`
+    /// * `resolve` - Called for every incoming ID that already exists
+    ///
+    /// # Returns
+    /// DisplayResult with the import summary
+    pub fn import_json_use_cases<F>(&mut self, path: String, resolve: F) -> Result<DisplayResult>
+    where
+        F: FnMut(&UseCase, &UseCase) -> Result<ConflictResolution>,
+    {
+        let path = Self::sanitize_required_string(path);
+        let controller = self.ensure_use_case_controller()?;
+        controller.import_json_use_cases(&path, resolve)
+    }
+
+    /// Apply the operations of a batch file
+    ///
+    /// # Arguments
+    /// * `file` - TOML or JSON batch file
+    ///
+    /// # Returns
+    /// DisplayResult with the batch report
+    pub fn run_batch(&mut self, file: String) -> Result<DisplayResult> {
+        let file = Self::sanitize_required_string(file);
+        let controller = self.ensure_use_case_controller()?;
+        controller.run_batch(&file)
+    }
+
+    /// Import Gherkin feature files
+    ///
+    /// # Arguments
+    /// * `path` - Feature file or directory of feature files
+    /// * `category` - Optional category for new use cases
+    ///
+    /// # Returns
+    /// DisplayResult with the import summary
+    pub fn import_gherkin(
+        &mut self,
+        path: String,
+        category: Option<String>,
+    ) -> Result<DisplayResult> {
+        let path = Self::sanitize_required_string(path);
+        let category = Self::sanitize_optional_string(category);
+        let controller = self.ensure_use_case_controller()?;
+        controller.import_gherkin(&path, category.as_deref())
+    }
+
+    /// Import hand-written use case markdown
+    ///
+    /// # Arguments
+    /// * `path` - Markdown file or directory of markdown files
+    /// * `category` - Optional category for new use cases
+    ///
+    /// # Returns
+    /// DisplayResult with the import summary
+    pub fn import_markdown(
+        &mut self,
+        path: String,
+        category: Option<String>,
+    ) -> Result<DisplayResult> {
+        let path = Self::sanitize_required_string(path);
+        let category = Self::sanitize_optional_string(category);
+        let controller = self.ensure_use_case_controller()?;
+        controller.import_markdown(&path, category.as_deref())
+    }
+
+    /// Import the rows of a requirement traceability matrix
+    ///
+    /// # Arguments
+    /// * `path` - Spreadsheet holding the matrix
+    /// * `mapping` - TOML file mapping its columns to use case fields
+    ///
+    /// # Returns
+    /// DisplayResult with the import summary
+    pub fn import_rtm(&mut self, path: String, mapping: String) -> Result<DisplayResult> {
+        let path = Self::sanitize_required_string(path);
+        let mapping = Self::sanitize_required_string(mapping);
+        let controller = self.ensure_use_case_controller()?;
+        controller.import_rtm(&path, &mapping)
+    }
+
+    /// Designate the primary view of a use case
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case
+    /// * `methodology` - Methodology of the view to make primary
+    ///
+    /// # Returns
+    /// DisplayResult with success or error message
+    pub fn set_primary_view(
+        &mut self,
+        use_case_id: String,
+        methodology: String,
+    ) -> Result<DisplayResult> {
+        let use_case_id = Self::sanitize_required_string(use_case_id);
+        let methodology = Self::sanitize_required_string(methodology);
+        let controller = self.ensure_use_case_controller()?;
+        controller.set_primary_view(use_case_id, methodology)
+    }
+
+    /// Move scenarios out of a use case into a new use case
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to split
+    /// * `scenario_ids` - Scenarios to move
+    /// * `title` - Title of the new use case
+    /// * `category` - Optional category of the new use case
+    ///
+    /// # Returns
+    /// DisplayResult with the new use case ID
+    pub fn split_use_case(
+        &mut self,
+        use_case_id: String,
+        scenario_ids: Vec<String>,
+        title: String,
+        category: Option<String>,
+    ) -> Result<DisplayResult> {
+        let use_case_id = Self::sanitize_required_string(use_case_id);
+        let scenario_ids = scenario_ids
+            .into_iter()
+            .map(Self::sanitize_required_string)
+            .filter(|id| !id.is_empty())
+            .collect();
+        let title = Self::sanitize_required_string(title);
+        let category = Self::sanitize_optional_string(category);
+        let controller = self.ensure_use_case_controller()?;
+        controller.split_use_case(use_case_id, scenario_ids, title, category)
+    }
+
+    /// Merge one use case into another
+    ///
+    /// # Arguments
+    /// * `target_id` - The use case that is kept
+    /// * `source_id` - The use case that is merged in and removed
+    /// * `resolve` - Decides each conflicting value
+    ///
+    /// # Returns
+    /// DisplayResult summarizing the merge
+    pub fn merge_use_cases<F>(
+        &mut self,
+        target_id: String,
+        source_id: String,
+        resolve: F,
+    ) -> Result<DisplayResult>
+    where
+        F: FnMut(&MergeConflict) -> Result<MergeChoice>,
+    {
+        let target_id = Self::sanitize_required_string(target_id);
+        let source_id = Self::sanitize_required_string(source_id);
+        let controller = self.ensure_use_case_controller()?;
+        controller.merge_use_cases(target_id, source_id, resolve)
+    }
+
+    /// Summarize use case changes since a git revision as release notes
+    ///
+    /// # Arguments
+    /// * `since` - Git revision of the baseline, e.g. a release tag
+    /// * `output` - Optional markdown file to write
+    ///
+    /// # Returns
+    /// DisplayResult with the release notes or a summary of the written file
+    pub fn release_notes(
+        &mut self,
+        since: String,
+        output: Option<String>,
+    ) -> Result<DisplayResult> {
+        let since = Self::sanitize_required_string(since);
+        let output = Self::sanitize_optional_string(output);
+        let controller = self.ensure_use_case_controller()?;
+        controller.release_notes(since, output)
+    }
+
+    /// Write acceptance checklists
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Use case to write the checklist of; all use cases if None
+    ///
+    /// # Returns
+    /// DisplayResult summarizing the written checklists
+    pub fn generate_checklists(&mut self, use_case_id: Option<String>) -> Result<DisplayResult> {
+        let use_case_id = Self::sanitize_optional_string(use_case_id);
+        let controller = self.ensure_use_case_controller()?;
+        controller.generate_checklists(use_case_id)
+    }
+
+    /// Tick a scenario in the acceptance checklist, marking it tested
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case holding the scenario
+    /// * `scenario` - Full scenario ID or short form (e.g. `S02`)
+    ///
+    /// # Returns
+    /// DisplayResult confirming the tick
+    pub fn tick_checklist(
+        &mut self,
+        use_case_id: String,
+        scenario: String,
+    ) -> Result<DisplayResult> {
+        let use_case_id = Self::sanitize_required_string(use_case_id);
+        let scenario = Self::sanitize_required_string(scenario);
+        let controller = self.ensure_use_case_controller()?;
+        controller.tick_checklist(use_case_id, scenario)
+    }
+
+    /// Estimate the scenarios of a use case
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to estimate
+    /// * `ask` - Returns the estimate for a scenario field, or None to keep the current one
+    ///
+    /// # Returns
+    /// DisplayResult with the aggregated estimates
+    pub fn estimate_use_case<F>(&mut self, use_case_id: String, ask: F) -> Result<DisplayResult>
+    where
+        F: FnMut(&EstimateRequest) -> Result<Option<f64>>,
+    {
+        let use_case_id = Self::sanitize_required_string(use_case_id);
+        let controller = self.ensure_use_case_controller()?;
+        controller.estimate_use_case(use_case_id, ask)
+    }
+
+    /// Build a combined report over several projects
+    ///
+    /// # Arguments
+    /// * `projects` - Root directories of the projects to aggregate
+    /// * `output` - Optional markdown file to write
+    /// * `filter_name` - Saved filter applied to every project
+    ///
+    /// # Returns
+    /// DisplayResult with the report or a summary of the written file
+    pub fn aggregate_report(
+        &mut self,
+        projects: Vec<String>,
+        output: Option<String>,
+        filter_name: Option<String>,
+    ) -> Result<DisplayResult> {
+        let projects = projects
+            .into_iter()
+            .map(Self::sanitize_required_string)
+            .collect();
+        let output = Self::sanitize_optional_string(output);
+        let filter_name = Self::sanitize_optional_string(filter_name);
+        ProjectController::aggregate_report(projects, output, filter_name)
+    }
+
+    /// Report use case ID sequences, optionally compacting them
+    ///
+    /// # Arguments
+    /// * `renumber` - Close gaps by renumbering use cases (needs `project.allow_renumbering`)
+    ///
+    /// # Returns
+    /// DisplayResult with the report or the renumbered IDs
+    pub fn id_report(&mut self, renumber: bool) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        if renumber {
+            controller.renumber_use_cases()
+        } else {
+            controller.id_report()
+        }
+    }
+
+    /// Validate the project configuration (mucm.toml)
+    ///
+    /// # Returns
+    /// DisplayResult listing the problems; an error result when there are errors
+    pub fn validate_config(&mut self) -> Result<DisplayResult> {
+        ProjectController::validate_config()
+    }
+
+    /// Set a config value, optionally moving a directory's files along
+    ///
+    /// # Arguments
+    /// * `key` - Dotted config key, e.g. "directories.use_case_dir"
+    /// * `value` - New value
+    /// * `migrate` - Move the existing files of a directory setting to the new location
+    ///
+    /// # Returns
+    /// DisplayResult confirming the change
+    pub fn set_config(
+        &mut self,
+        key: String,
+        value: String,
+        migrate: bool,
+    ) -> Result<DisplayResult> {
+        let key = Self::sanitize_required_string(key);
+        let value = Self::sanitize_required_string(value);
+        if migrate {
+            let controller = self.ensure_use_case_controller()?;
+            controller.move_directory(key, value)
+        } else {
+            ProjectController::set_config(key, value)
+        }
+    }
+
+    /// Plan a mutation of an existing use case, or a regeneration, as JSON
+    ///
+    /// # Arguments
+    /// * `command` - Command name recorded in the plan
+    /// * `mutation` - The mutation to plan
+    ///
+    /// # Returns
+    /// DisplayResult with the plan as JSON
+    pub fn plan(&mut self, command: &str, mutation: PlannedMutation) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.plan(command.to_string(), mutation)
+    }
+
+    /// Plan the creation of a use case as JSON
+    ///
+    /// # Arguments
+    /// * `category` - Category of the new use case
+    /// * `methodology` - Optional methodology for a single view
+    /// * `views` - Optional comma-separated methodology:level pairs
+    ///
+    /// # Returns
+    /// DisplayResult with the plan as JSON
+    pub fn plan_create(
+        &mut self,
+        category: String,
+        methodology: Option<String>,
+        views: Option<String>,
+    ) -> Result<DisplayResult> {
+        let category = Self::sanitize_required_string(category);
+        let methodology = Self::sanitize_optional_string(methodology);
+        let views = Self::sanitize_optional_string(views);
+        let controller = self.ensure_use_case_controller()?;
+        controller.plan_create(category, methodology, views)
+    }
+
+    /// Copy the project into a new sandbox
+    ///
+    /// # Returns
+    /// DisplayResult with the sandbox path
+    pub fn create_sandbox(&mut self) -> Result<DisplayResult> {
+        ProjectController::create_sandbox()
+    }
+
+    /// List the changes of the sandbox in the current directory
+    ///
+    /// # Returns
+    /// DisplayResult with one line per changed file
+    pub fn sandbox_diff(&mut self) -> Result<DisplayResult> {
+        ProjectController::sandbox_diff()
+    }
+
+    /// Copy the sandbox's changes back into its project
+    ///
+    /// # Arguments
+    /// * `discard` - Remove the sandbox afterwards
+    ///
+    /// # Returns
+    /// DisplayResult listing the applied changes
+    pub fn apply_sandbox(&mut self, discard: bool) -> Result<DisplayResult> {
+        ProjectController::apply_sandbox(discard)
+    }
+
+    /// Delete the sandbox in the current directory
+    ///
+    /// # Returns
+    /// DisplayResult confirming the removal
+    pub fn discard_sandbox(&mut self) -> Result<DisplayResult> {
+        ProjectController::discard_sandbox()
+    }
+
+    /// Create a populated example project
+    ///
+    /// # Arguments
+    /// * `directory` - Directory to create the project in
+    ///
+    /// # Returns
+    /// DisplayResult describing the created project
+    pub fn demo(&mut self, directory: String) -> Result<DisplayResult> {
+        let directory = Self::sanitize_required_string(directory);
+        ProjectController::demo(directory)
+    }
+
+    /// Benchmark generation on a synthetic project
+    ///
+    /// # Arguments
+    /// * `use_cases` - Number of synthetic use cases
+    /// * `backend` - Storage backend to measure
+    ///
+    /// # Returns
+    /// DisplayResult with the timing of each phase
+    pub fn bench(&mut self, use_cases: usize, backend: String) -> Result<DisplayResult> {
+        let backend = Self::sanitize_required_string(backend);
+        ProjectController::bench(use_cases, backend)
+    }
+
+    /// Rename the project and optionally re-prefix every use case ID
+    ///
+    /// # Arguments
+    /// * `name` - Optional new project name
+    /// * `description` - Optional new project description
+    /// * `id_prefix` - Optional new ID prefix
+    ///
+    /// # Returns
+    /// DisplayResult summarizing the rename
+    pub fn rename_project(
+        &mut self,
+        name: Option<String>,
+        description: Option<String>,
+        id_prefix: Option<String>,
+    ) -> Result<DisplayResult> {
+        let name = Self::sanitize_optional_string(name);
+        let description = Self::sanitize_optional_string(description);
+        let id_prefix = Self::sanitize_optional_string(id_prefix);
+        if name.is_none() && description.is_none() && id_prefix.is_none() {
+            return Ok(DisplayResult::error(
+                "Nothing to rename: pass a new name, --description, or --id-prefix".to_string(),
+            ));
+        }
+        let controller = self.ensure_use_case_controller()?;
+        controller.rename_project(name, description, id_prefix)
+    }
+
+    /// Copy a use case under a new ID
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to copy
+    /// * `title` - Title of the new use case
+    /// * `category` - Optional category of the new use case
+    /// * `include_scenarios` - Copy scenarios
+    /// * `include_conditions` - Copy preconditions and postconditions
+    ///
+    /// # Returns
+    /// DisplayResult with the new use case ID
+    pub fn clone_use_case(
+        &mut self,
+        use_case_id: String,
+        title: String,
+        category: Option<String>,
+        include_scenarios: bool,
+        include_conditions: bool,
+    ) -> Result<DisplayResult> {
+        let use_case_id = Self::sanitize_required_string(use_case_id);
+        let title = Self::sanitize_required_string(title);
+        let category = Self::sanitize_optional_string(category);
+        let controller = self.ensure_use_case_controller()?;
+        controller.clone_use_case(
+            use_case_id,
+            title,
+            category,
+            include_scenarios,
+            include_conditions,
+        )
+    }
+
+    /// List the recorded test names of scenarios
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Only this use case's scenarios (all if None)
+    ///
+    /// # Returns
+    /// DisplayResult with the test names
+    pub fn list_test_names(&mut self, use_case_id: Option<String>) -> Result<DisplayResult> {
+        let use_case_id = Self::sanitize_optional_string(use_case_id);
+        let controller = self.ensure_use_case_controller()?;
+        controller.list_test_names(use_case_id)
+    }
+
+    /// Rename the generated test of a scenario
+    ///
+    /// # Arguments
+    /// * `scenario_id` - Scenario whose test is renamed
+    /// * `name` - New test name
+    ///
+    /// # Returns
+    /// DisplayResult describing the rename
+    pub fn rename_test(&mut self, scenario_id: String, name: String) -> Result<DisplayResult> {
+        let scenario_id = Self::sanitize_required_string(scenario_id);
+        let name = Self::sanitize_required_string(name);
+        let controller = self.ensure_use_case_controller()?;
+        controller.rename_test(scenario_id, name)
+    }
+
+    /// Reconcile generated test files with use case scenarios
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Optional specific use case to check. If None, checks all use cases.
+    /// * `create_missing_scenarios` - If true, scaffolds scenarios for tests without one
+    ///
+    /// # Returns
+    /// DisplayResult with the reconciliation report
+    pub fn reconcile_tests(
+        &mut self,
+        use_case_id: Option<String>,
+        create_missing_scenarios: bool,
+    ) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.reconcile_tests(
+            Self::sanitize_optional_string(use_case_id),
+            create_missing_scenarios,
+        )
+    }
+
+    /// Generate test files, optionally only where scenarios lack tests
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Optional specific use case. If None, covers all use cases.
+    /// * `only_missing` - Only generate files missing tests for some scenarios
+    /// * `force` - Also overwrite files with manual modifications
+    /// * `tag` - Generate the suite of this scenario tag instead
+    ///
+    /// # Returns
+    /// DisplayResult with the generation summary
+    pub fn generate_tests(
+        &mut self,
+        use_case_id: Option<String>,
+        only_missing: bool,
+        force: bool,
+        tag: Option<String>,
+    ) -> Result<DisplayResult> {
+        let controller = self.ensure_use_case_controller()?;
+        controller.generate_tests(
+            Self::sanitize_optional_string(use_case_id),
+            only_missing,
+            force,
+            Self::sanitize_optional_string(tag),
+        )
+    }
+
+    /// Switch to the MUCM project at `root` without restarting.
+    ///
+    /// Changes the working directory to the project root, drops the loaded
+    /// controllers so the next command reads the new project, and records the
+    /// project in the user's recent projects.
+    ///
+    /// # Arguments
+    /// * `root` - Root directory of the project (containing `.config/.mucm`)
+    ///
+    /// # Returns
+    /// The context of the project switched to
+    pub fn switch_project(&mut self, root: &Path) -> Result<ProjectContext> {
+        let context = ProjectContext::load(root)?;
+        std::env::set_current_dir(root)
+            .with_context(|| format!("Failed to switch to {}", root.display()))?;
+        self.use_case_controller = None;
+        Self::remember_project(root)?;
+        Ok(context)
+    }
+
+    /// Record a project root in the user's recent projects.
+    pub fn remember_project(root: &Path) -> Result<()> {
+        let mut user_config = UserConfig::load()?;
+        user_config.record_project(root);
+        Ok(user_config.save()?)
+    }
+
+    /// Recently opened projects, most recent first.
+    pub fn recent_projects() -> Result<Vec<PathBuf>> {
+        Ok(UserConfig::load()?.recent_projects)
+    }
 }
 
 #[cfg(test)]
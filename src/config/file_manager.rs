@@ -1,7 +1,10 @@
 use crate::config::types::Config;
-use anyhow::{Context, Result};
+use crate::config::{ConfigDiagnostic, ConfigValidator};
+use crate::core::MucmError;
+use crate::core::{Context, Result};
 use std::fs;
 use std::path::Path;
+use toml_edit::{DocumentMut, Item, Table};
 
 /// Configuration file management utilities.
 ///
@@ -25,23 +28,162 @@ impl ConfigFileManager {
     /// * The file cannot be read (permission issues)
     /// * The TOML content is malformed or invalid
     pub fn load() -> Result<Config> {
-        let config_path = Config::config_path();
+        Self::load_from_dir(Path::new("."))
+    }
+
+    /// Load configuration of the project rooted at `base_dir`.
+    ///
+    /// Directory settings in the returned config are still relative to `base_dir`.
+    ///
+    /// # Errors (This function will return an error if)
+    /// * `base_dir` has no `.config/.mucm/mucm.toml`
+    /// * The file cannot be read or parsed
+    pub fn load_from_dir(base_dir: &Path) -> Result<Config> {
+        let config_path = base_dir.join(Config::config_path());
 
         if !config_path.exists() {
-            anyhow::bail!("No markdown use case manager project found. Run 'mucm init' first.");
+            return Err(MucmError::Validation(
+                "No markdown use case manager project found. Run 'mucm init' first.".to_string(),
+            ));
         }
 
         let content = fs::read_to_string(&config_path).context("Failed to read config file")?;
-        let config: Config = toml::from_str(&content).context("Failed to parse config file")?;
+        let config: Config = toml::from_str(&content).map_err(|e| {
+            MucmError::Storage(format!(
+                "Failed to parse config file: {}",
+                ConfigValidator::parse_error(&content, &e)
+            ))
+        })?;
 
         Ok(config)
     }
 
+    /// Set the dotted `key` (e.g. "directories.use_case_dir") to `value` and save the config.
+    ///
+    /// See [`Self::with_value`] for how the value is interpreted.
+    pub fn set_value(base_dir: &Path, key: &str, value: &str) -> Result<Config> {
+        let (content, config) = Self::with_value(base_dir, key, value)?;
+        Self::write_content(base_dir, &content)?;
+        Ok(config)
+    }
+
+    /// Set the dotted `key` to `value` in the config of the project at `base_dir`
+    /// without saving it, returning the new file content and the config it holds.
+    ///
+    /// `value` is read as a TOML value (`true`, `42`, `["a", "b"]`) when that
+    /// gives a valid config, and as a plain string otherwise. Comments and the
+    /// key's inline comment are kept.
+    ///
+    /// # Errors (This function will return an error if)
+    /// * `key` is not a known config key, or names a whole section
+    /// * The value is invalid for `key` (e.g. an unknown methodology)
+    pub fn with_value(base_dir: &Path, key: &str, value: &str) -> Result<(String, Config)> {
+        let config_path = base_dir.join(Config::config_path());
+        let content = fs::read_to_string(&config_path).context("Failed to read config file")?;
+
+        let string_value = toml_edit::Value::from(value);
+        let mut updated = match value.parse::<toml_edit::Value>() {
+            Ok(parsed) => {
+                let updated = Self::set_in_document(&content, key, parsed)?;
+                if toml::from_str::<Config>(&updated).is_ok() {
+                    updated
+                } else {
+                    Self::set_in_document(&content, key, string_value)?
+                }
+            }
+            Err(_) => Self::set_in_document(&content, key, string_value)?,
+        };
+        if !updated.ends_with('\n') {
+            updated.push('\n');
+        }
+
+        let diagnostics = ConfigValidator::validate_str(&updated, base_dir);
+        let concerns_key = |diagnostic: &&ConfigDiagnostic| {
+            key == diagnostic.key || key.starts_with(&format!("{}.", diagnostic.key))
+        };
+        if let Some(unknown) = diagnostics
+            .iter()
+            .filter(concerns_key)
+            .find(|d| !d.is_error())
+        {
+            let hint = unknown.hint.as_deref().unwrap_or_default();
+            return Err(MucmError::Validation(format!(
+                "Unknown config key '{}' ({})",
+                unknown.key, hint
+            )));
+        }
+        if let Some(error) = diagnostics
+            .iter()
+            .filter(concerns_key)
+            .find(|d| d.is_error())
+        {
+            return Err(MucmError::Validation(format!(
+                "Invalid value for {}: {}",
+                key, error.message
+            )));
+        }
+        let config: Config = toml::from_str(&updated).map_err(|e| {
+            MucmError::Storage(format!(
+                "Failed to parse config file: {}",
+                ConfigValidator::parse_error(&updated, &e)
+            ))
+        })?;
+
+        Ok((updated, config))
+    }
+
+    /// Write config file content, e.g. prepared by [`Self::with_value`]
+    pub fn write_content(base_dir: &Path, content: &str) -> Result<()> {
+        fs::write(base_dir.join(Config::config_path()), content)
+            .context("Failed to write config file")
+    }
+
+    /// Set the dotted `key` of the TOML document in `content` to `value`,
+    /// creating missing tables and keeping the key's decoration
+    fn set_in_document(content: &str, key: &str, mut value: toml_edit::Value) -> Result<String> {
+        let mut document: DocumentMut = content.parse().context("Failed to parse config file")?;
+        let mut parts: Vec<&str> = key.split('.').collect();
+        let name = parts
+            .pop()
+            .filter(|name| !name.is_empty() && !parts.is_empty());
+        let Some(name) = name else {
+            return Err(MucmError::Validation(format!(
+                "Invalid config key '{}': use section.key, e.g. directories.use_case_dir",
+                key
+            )));
+        };
+
+        let mut table = document.as_table_mut();
+        for part in parts {
+            table = table
+                .entry(part)
+                .or_insert_with(toml_edit::table)
+                .as_table_mut()
+                .with_context(|| format!("'{}' in '{}' is not a section", part, key))?;
+        }
+        match table.get_mut(name) {
+            Some(Item::Value(existing)) => {
+                *value.decor_mut() = existing.decor().clone();
+                *existing = value;
+            }
+            Some(Item::None) | None => {
+                table.insert(name, Item::Value(value));
+            }
+            Some(_) => {
+                return Err(MucmError::Validation(format!(
+                    "'{}' is a section; set one of its keys instead",
+                    key
+                )))
+            }
+        }
+        Ok(document.to_string())
+    }
+
     /// Save configuration to file in specified directory.
     ///
-    /// This method preserves comments and formatting by reading the existing file
-    /// and updating only the changed values. If the file doesn't exist, it will
-    /// serialize the full config.
+    /// This method preserves comments and formatting by editing the existing file
+    /// with `toml_edit` and updating only the changed values. If the file doesn't
+    /// exist, it will serialize the full config.
     ///
     /// # Arguments
     /// * `config` - The configuration to save
@@ -76,215 +218,84 @@ impl ConfigFileManager {
 
     /// Update config file while preserving comments and formatting.
     ///
-    /// This reads the existing config file and updates only the values that have changed,
-    /// preserving all comments, blank lines, and formatting.
+    /// The existing file is edited in place with `toml_edit`: only values that
+    /// differ from `new_config` are replaced (keeping their inline comments),
+    /// missing keys and sections are added, and keys the config no longer has
+    /// are removed. Everything else, including comments and blank lines, stays
+    /// untouched.
     fn update_config_preserving_comments(
         config_path: &Path,
         new_config: &Config,
     ) -> Result<String> {
-        let mut content =
-            fs::read_to_string(config_path).context("Failed to read existing config")?;
-
-        // Update project values
-        content = Self::update_toml_value(
-            &content,
-            "project",
-            "name",
-            &format!(r#""{}""#, new_config.project.name),
-        );
-        content = Self::update_toml_value(
-            &content,
-            "project",
-            "description",
-            &format!(r#""{}""#, new_config.project.description),
-        );
-
-        // Update directory settings
-        content = Self::update_toml_value(
-            &content,
-            "directories",
-            "use_case_dir",
-            &format!(r#""{}""#, new_config.directories.use_case_dir),
-        );
-        content = Self::update_toml_value(
-            &content,
-            "directories",
-            "test_dir",
-            &format!(r#""{}""#, new_config.directories.test_dir),
-        );
-        content = Self::update_toml_value(
-            &content,
-            "directories",
-            "data_dir",
-            &format!(r#""{}""#, new_config.directories.data_dir),
-        );
-
-        // Update template settings
-        let methodologies_str = new_config
-            .templates
-            .methodologies
-            .iter()
-            .map(|m| format!(r#""{}""#, m))
-            .collect::<Vec<_>>()
-            .join(", ");
-        content = Self::update_toml_value(
-            &content,
-            "templates",
-            "methodologies",
-            &format!("[{}]", methodologies_str),
-        );
-        content = Self::update_toml_value(
-            &content,
-            "templates",
-            "default_methodology",
-            &format!(r#""{}""#, new_config.templates.default_methodology),
-        );
-
-        // Update generation settings
-        content = Self::update_toml_value(
-            &content,
-            "generation",
-            "test_language",
-            &format!(r#""{}""#, new_config.generation.test_language),
-        );
-        content = Self::update_toml_value(
-            &content,
-            "generation",
-            "auto_generate_tests",
-            &new_config.generation.auto_generate_tests.to_string(),
-        );
-        content = Self::update_toml_value(
-            &content,
-            "generation",
-            "overwrite_test_documentation",
-            &new_config
-                .generation
-                .overwrite_test_documentation
-                .to_string(),
-        );
-
-        // Update metadata settings
-        content = Self::update_toml_value(
-            &content,
-            "metadata",
-            "created",
-            &new_config.metadata.created.to_string(),
-        );
-        content = Self::update_toml_value(
-            &content,
-            "metadata",
-            "last_updated",
-            &new_config.metadata.last_updated.to_string(),
-        );
+        let content = fs::read_to_string(config_path).context("Failed to read existing config")?;
+        Self::merge_config(&content, new_config)
+    }
 
-        // Update storage backend
-        let backend_str = match new_config.storage.backend {
-            crate::config::StorageBackend::Toml => "toml",
-            crate::config::StorageBackend::Sqlite => "sqlite",
-        };
-        content = Self::update_toml_value(
-            &content,
-            "storage",
-            "backend",
-            &format!(r#""{}""#, backend_str),
+    /// Apply `config` to the TOML document in `content` with surgical edits.
+    fn merge_config(content: &str, config: &Config) -> Result<String> {
+        let mut document: DocumentMut =
+            content.parse().context("Failed to parse existing config")?;
+        let old_values: toml::Table = toml::from_str(content).unwrap_or_default();
+
+        let serialized = toml::to_string_pretty(config).context("Failed to serialize config")?;
+        let new_document: DocumentMut = serialized.parse()?;
+        let new_values: toml::Table = toml::from_str(&serialized)?;
+
+        Self::merge_table(
+            document.as_table_mut(),
+            new_document.as_table(),
+            Some(&old_values),
+            &new_values,
         );
-
-        Ok(content)
+        Ok(document.to_string())
     }
 
-    /// Update a single TOML value while preserving everything else.
+    /// Bring `existing` in line with `new`, touching only what changed.
     ///
-    /// Finds lines like `key = old_value` within the specified section and replaces
-    /// with `key = new_value`, preserving any inline comments. Only updates the first
-    /// occurrence within the target section.
-    ///
-    /// # Arguments
-    /// * `content` - The full TOML content
-    /// * `section` - The TOML section name (e.g., "project", "templates")
-    /// * `key` - The key to update within that section
-    /// * `new_value` - The new value to set
-    fn update_toml_value(content: &str, section: &str, key: &str, new_value: &str) -> String {
-        let lines: Vec<&str> = content.lines().collect();
-        let mut result = String::new();
-        let mut i = 0;
-        let section_header = format!("[{}]", section);
-        let mut in_target_section = false;
-
-        while i < lines.len() {
-            let line = lines[i];
-            let trimmed = line.trim_start();
-
-            // Track which section we're in
-            if trimmed.starts_with('[') && trimmed.contains(']') {
-                // Entering a new section
-                in_target_section =
-                    trimmed == section_header || trimmed.starts_with(&format!("[{}.", section));
-            }
-
-            // Check if this line starts our key
-            if in_target_section
-                && trimmed.starts_with(key)
-                && (trimmed.chars().nth(key.len()) == Some(' ')
-                    || trimmed.chars().nth(key.len()) == Some('='))
-            {
-                // Find the = sign
-                if let Some(eq_pos) = line.find('=') {
-                    let after_eq = &line[eq_pos + 1..].trim_start();
-
-                    // Check if this is a multi-line array (starts with [)
-                    if after_eq.starts_with('[') && !after_eq.contains(']') {
-                        // Multi-line array - find the closing bracket
-                        let mut array_end = i;
-                        let mut bracket_count = 0;
-
-                        for j in i..lines.len() {
-                            let array_line = lines[j].trim();
-                            bracket_count += array_line.chars().filter(|&c| c == '[').count();
-                            bracket_count -= array_line.chars().filter(|&c| c == ']').count();
-
-                            if bracket_count == 0 && array_line.contains(']') {
-                                array_end = j;
-                                break;
-                            }
-                        }
-
-                        // Replace the entire array block
-                        let indent = line.len() - trimmed.len();
-                        let indent_str = " ".repeat(indent);
-                        result.push_str(&format!("{}{} = {}\n", indent_str, key, new_value));
-
-                        // Skip the old array lines
-                        i = array_end + 1;
-                        continue;
-                    } else {
-                        // Single-line value - handle normally
-                        let comment_pos = after_eq.find('#');
-
-                        let indent = line.len() - trimmed.len();
-                        let indent_str = " ".repeat(indent);
-
-                        if let Some(comment_start) = comment_pos {
-                            let comment = &after_eq[comment_start..];
-                            result.push_str(&format!(
-                                "{}{} = {} {}\n",
-                                indent_str, key, new_value, comment
-                            ));
-                        } else {
-                            result.push_str(&format!("{}{} = {}\n", indent_str, key, new_value));
-                        }
-                        i += 1;
-                        continue;
-                    }
+    /// `old_values` and `new_values` are the parsed values of both tables, used
+    /// to compare entries independently of their formatting.
+    fn merge_table(
+        existing: &mut Table,
+        new: &Table,
+        old_values: Option<&toml::Table>,
+        new_values: &toml::Table,
+    ) {
+        for (key, new_item) in new.iter() {
+            let old_value = old_values.and_then(|values| values.get(key));
+            let new_value = new_values.get(key);
+
+            match (existing.get_mut(key), new_item) {
+                (Some(Item::Table(table)), Item::Table(new_table)) => {
+                    let empty = toml::Table::new();
+                    Self::merge_table(
+                        table,
+                        new_table,
+                        old_value.and_then(toml::Value::as_table),
+                        new_value.and_then(toml::Value::as_table).unwrap_or(&empty),
+                    );
+                }
+                (Some(_), _) if old_value == new_value => {}
+                (Some(Item::Value(value)), Item::Value(replacement)) => {
+                    // Keep the key's inline comment and spacing
+                    let decor = value.decor().clone();
+                    *value = replacement.clone();
+                    *value.decor_mut() = decor;
+                }
+                (Some(item), _) => *item = new_item.clone(),
+                (None, _) => {
+                    existing.insert(key, new_item.clone());
                 }
             }
-
-            // Keep line as-is
-            result.push_str(line);
-            result.push('\n');
-            i += 1;
         }
 
-        result
+        let removed: Vec<String> = existing
+            .iter()
+            .filter(|(key, _)| !new.contains_key(key))
+            .map(|(key, _)| key.to_string())
+            .collect();
+        for key in removed {
+            existing.remove(&key);
+        }
     }
 
     /// Check if templates have already been copied to .config/.mucm/handlebars/
@@ -513,8 +524,11 @@ mod tests {
             perms.set_mode(0o000); // No permissions
             fs::set_permissions(&config_path, perms)?;
 
-            let result = ConfigFileManager::load();
-            assert!(result.is_err());
+            // Permissions don't bind root, so there is nothing to check there
+            if fs::read(&config_path).is_err() {
+                let result = ConfigFileManager::load();
+                assert!(result.is_err());
+            }
 
             // Restore permissions for cleanup
             let mut restore_perms = fs::metadata(&config_path)?.permissions();
@@ -531,4 +545,79 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_merge_config_inserts_missing_key() {
+        let content = "[project]\nname = \"Demo\"\n\n[storage]\nbackend = \"toml\"\n";
+        let mut config = Config::default();
+        config.project.name = "Demo".to_string();
+        config.project.id_prefix = "PAY".to_string();
+
+        let inserted = ConfigFileManager::merge_config(content, &config).unwrap();
+        let table: toml::Table = toml::from_str(&inserted).unwrap();
+        assert_eq!(table["project"]["id_prefix"].as_str(), Some("PAY"));
+        assert!(inserted.starts_with("[project]\nname = \"Demo\""));
+
+        config.project.id_prefix = "UC".to_string();
+        let updated = ConfigFileManager::merge_config(&inserted, &config).unwrap();
+        assert!(updated.contains("id_prefix = \"UC\""));
+        assert!(!updated.contains("PAY"));
+    }
+
+    #[test]
+    fn test_merge_config_keeps_comments() {
+        let mut config = Config::default();
+        let content = toml::to_string_pretty(&config)
+            .unwrap()
+            .replace(
+                "[templates]",
+                "# Methodologies our team agreed on\n[templates]",
+            )
+            .replace(
+                &format!("name = \"{}\"", config.project.name),
+                &format!("name = \"{}\" # shown in the overview", config.project.name),
+            );
+
+        config.project.name = "Renamed".to_string();
+        config.templates.methodologies.push("custom".to_string());
+        let merged = ConfigFileManager::merge_config(&content, &config).unwrap();
+
+        assert!(merged.contains("# Methodologies our team agreed on\n[templates]"));
+        assert!(merged.contains("name = \"Renamed\" # shown in the overview"));
+        let loaded: Config = toml::from_str(&merged).unwrap();
+        assert!(loaded
+            .templates
+            .methodologies
+            .contains(&"custom".to_string()));
+    }
+
+    #[test]
+    fn test_set_value_keeps_comments_and_types() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let config = Config::default();
+        let content = toml::to_string_pretty(&config)?.replace(
+            &format!("use_case_dir = \"{}\"", config.directories.use_case_dir),
+            &format!(
+                "use_case_dir = \"{}\" # generated docs",
+                config.directories.use_case_dir
+            ),
+        );
+        fs::create_dir_all(temp_dir.path().join(Config::CONFIG_DIR))?;
+        fs::write(temp_dir.path().join(Config::config_path()), content)?;
+
+        ConfigFileManager::set_value(temp_dir.path(), "directories.use_case_dir", "docs/uc")?;
+        let config =
+            ConfigFileManager::set_value(temp_dir.path(), "generation.autolink_ids", "true")?;
+
+        let saved = fs::read_to_string(temp_dir.path().join(Config::config_path()))?;
+        assert!(saved.contains("use_case_dir = \"docs/uc\" # generated docs"));
+        assert!(config.generation.autolink_ids);
+        assert_eq!(config.directories.use_case_dir, "docs/uc");
+
+        let unknown = ConfigFileManager::set_value(temp_dir.path(), "directories.use_cse_dir", "x");
+        assert!(unknown.unwrap_err().to_string().contains("use_case_dir"));
+        let invalid = ConfigFileManager::set_value(temp_dir.path(), "generation.badges", "loud");
+        assert!(invalid.is_err());
+        Ok(())
+    }
 }
@@ -9,6 +9,9 @@
 //! - **Types** (`types.rs`): Data structures for configuration
 //! - **File Management** (`file_manager.rs`): Loading/saving config files
 //! - **Template Management** (`template_manager.rs`): Template copying and processing
+//! - **Project Context** (`project_context.rs`): Project root and root-relative paths
+//! - **User Config** (`user_config.rs`): User-level settings such as recent projects
+//! - **Validation** (`validation.rs`): Diagnostics for unknown keys, paths and templates
 //! - **Main Interface** (`mod.rs`): Public API and high-level operations
 //!
 //! ## Configuration File
@@ -30,16 +33,30 @@
 
 // Private sub-modules
 mod file_manager;
+mod project_context;
 mod template_manager;
 mod types;
+mod user_config;
+mod validation;
 
 // Explicit public exports
 pub use file_manager::ConfigFileManager;
+pub use project_context::ProjectContext;
 pub use template_manager::TemplateManager;
-pub use types::{ActorConfig, Config, StorageBackend, StorageConfig};
+pub use types::{
+    ActorConfig, BackupConfig, BadgeStyle, ColorMode, Config, ConfluenceConfig, DiagramFormat,
+    DirectoryConfig, ExportConfig, FrontMatterConfig, GherkinConfig, GitHubConfig, HealthConfig,
+    IconTheme, IdStrategy, JiraConfig, LineEnding, OutputFormat, OverviewSection,
+    PersonaFieldConfig, PersonaFieldType, PresentationConfig, RenderLimitsConfig, SavedFilter,
+    ScenarioOrder, StorageBackend, StorageConfig,
+};
+pub use user_config::UserConfig;
+pub use validation::{ConfigDiagnostic, ConfigValidator, DiagnosticLevel};
 
 // Re-export from other modules
-use anyhow::{Context, Result};
+use crate::core::utils::span;
+use crate::core::MucmError;
+use crate::core::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -124,7 +141,7 @@ impl Config {
     /// * `test_language` - The programming language for test templates
     /// * `methodologies` - List of methodologies to enable
     /// * `default_methodology` - Optional default methodology override
-    /// * `storage` - Storage backend (toml or sqlite)
+    /// * `storage` - Storage backend (toml, sqlite or events)
     /// * `use_case_dir` - Directory for use case files
     /// * `test_dir` - Directory for test files
     /// * `actor_dir` - Directory for actor files (personas and system actors)
@@ -132,6 +149,7 @@ impl Config {
     ///
     /// # Returns
     /// A Config instance with custom directories
+    #[allow(clippy::too_many_arguments)]
     pub fn for_template_with_methodologies_storage_and_directories(
         test_language: Option<String>,
         methodologies: Vec<String>,
@@ -194,9 +212,7 @@ impl Config {
         let templates_dir = base_path.join(Self::CONFIG_DIR).join(Self::TEMPLATES_DIR);
 
         if !templates_dir.exists() {
-            anyhow::bail!(
-                "Project templates directory not found. Run 'mucm init' first or 'mucm finalize-init' to complete initialization."
-            );
+            return Err(MucmError::NotFound("Project templates directory not found. Run 'mucm init' first or 'mucm finalize-init' to complete initialization.".to_string()));
         }
 
         Ok(templates_dir)
@@ -235,9 +251,17 @@ impl Config {
     /// # Returns
     /// The loaded configuration, or an error if the file doesn't exist or is invalid
     pub fn load() -> Result<Self> {
+        let _span = span(|| "load".to_string());
         ConfigFileManager::load()
     }
 
+    /// Load configuration of the project rooted at `base_dir`.
+    ///
+    /// Directory settings stay relative to `base_dir`.
+    pub fn load_from_dir(base_dir: &Path) -> Result<Self> {
+        ConfigFileManager::load_from_dir(base_dir)
+    }
+
     /// Save configuration to file in specified directory.
     ///
     /// # Arguments
@@ -330,6 +354,10 @@ impl Config {
                     project: ProjectConfig {
                         name: "Default Project".to_string(),
                         description: "Default project description".to_string(),
+                        id_prefix: types::default_id_prefix(),
+                        allow_renumbering: false,
+                        id_strategy: IdStrategy::default(),
+                        id_pattern: None,
                     },
                     directories: DirectoryConfig {
                         use_case_dir: "use-cases".to_string(),
@@ -345,11 +373,13 @@ impl Config {
                             "tester".to_string(),
                         ],
                         default_methodology: "feature".to_string(),
+                        view_order: Vec::new(),
                     },
                     generation: GenerationConfig {
                         test_language: "none".to_string(),
                         auto_generate_tests: false,
                         overwrite_test_documentation: false,
+                        ..GenerationConfig::default()
                     },
                     storage: StorageConfig {
                         backend: StorageBackend::Toml,
@@ -357,8 +387,19 @@ impl Config {
                     metadata: MetadataConfig {
                         created: true,
                         last_updated: true,
+                        review_interval_days: None,
                     },
                     actor: ActorConfig::default(),
+                    presentation: PresentationConfig::default(),
+                    render_limits: RenderLimitsConfig::default(),
+                    backup: BackupConfig::default(),
+                    export: ExportConfig::default(),
+                    confluence: ConfluenceConfig::default(),
+                    jira: JiraConfig::default(),
+                    github: GitHubConfig::default(),
+                    health: HealthConfig::default(),
+                    filters: Default::default(),
+                    alias: Default::default(),
                 });
             }
         };
@@ -397,6 +438,7 @@ impl Config {
             test_language: config.generation.test_language.clone(),
             auto_generate_tests: false,
             overwrite_test_documentation: false,
+            ..config.generation.clone()
         };
 
         Ok(config)
@@ -450,11 +492,11 @@ mod tests {
             if let Some(ref registry) = language_registry {
                 if registry.get(lang).is_none() {
                     let available = registry.available_languages();
-                    anyhow::bail!(
+                    return Err(MucmError::Validation(format!(
                         "Unsupported language '{}'. Supported languages: {}",
                         lang,
                         available.join(", ")
-                    );
+                    )));
                 }
             }
             // If no registry available, skip validation (assume language is valid for testing)
@@ -462,7 +504,7 @@ mod tests {
 
         let config_dir = Path::new(".config/.mucm");
         if !config_dir.exists() {
-            fs::create_dir_all(&config_dir).context("Failed to create .config/.mucm directory")?;
+            fs::create_dir_all(config_dir).context("Failed to create .config/.mucm directory")?;
         }
 
         let mut config = Config::default();
@@ -822,7 +864,7 @@ mod tests {
             .and_then(|p| p.parent()) // debug
             .and_then(|p| p.parent()) // target
             .and_then(|p| p.parent()) // project root
-            .ok_or_else(|| anyhow::anyhow!("Could not determine project root"))?;
+            .ok_or_else(|| MucmError::Validation("Could not determine project root".to_string()))?;
         std::env::set_var("CARGO_MANIFEST_DIR", project_root);
 
         let mut config = Config::default();
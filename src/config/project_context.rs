@@ -0,0 +1,124 @@
+//! Project context: a project's root directory together with its configuration.
+//!
+//! Directory settings in `mucm.toml` are relative to the project root. The CLI
+//! runs from that root, so relative paths just work; library users that open a
+//! project elsewhere (or several projects at once) pass a `ProjectContext`
+//! instead of changing the process working directory.
+
+use crate::core::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use super::Config;
+
+/// A project root and the configuration loaded from it
+#[derive(Debug, Clone)]
+pub struct ProjectContext {
+    root: PathBuf,
+    config: Config,
+}
+
+impl ProjectContext {
+    /// Create a context for the project at `root` with an already loaded config.
+    pub fn new(root: impl Into<PathBuf>, config: Config) -> Self {
+        Self {
+            root: root.into(),
+            config,
+        }
+    }
+
+    /// Load the configuration of the project at `root`.
+    pub fn load(root: impl Into<PathBuf>) -> Result<Self> {
+        let root = root.into();
+        let config = Config::load_from_dir(&root)
+            .with_context(|| format!("{} is not a MUCM project", root.display()))?;
+        Ok(Self::new(root, config))
+    }
+
+    /// Context for the current working directory.
+    ///
+    /// The root is empty, so resolved paths stay exactly as written in the config.
+    pub fn current(config: Config) -> Self {
+        Self::new(PathBuf::new(), config)
+    }
+
+    /// Project root directory (empty for the current working directory)
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Configuration as written in `mucm.toml`, with directories relative to the root
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Resolve a project-relative path against the root. Absolute paths are kept.
+    pub fn resolve(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.root.join(path)
+    }
+
+    /// Directory holding the project's installed templates (`.config/.mucm/template-assets`)
+    pub fn templates_dir(&self) -> PathBuf {
+        self.resolve(Config::CONFIG_DIR).join(Config::TEMPLATES_DIR)
+    }
+
+    /// Configuration with every directory setting resolved against the root.
+    ///
+    /// This is the config to hand to repositories and generators, which use
+    /// directory settings as file system paths.
+    pub fn resolved_config(&self) -> Config {
+        let mut config = self.config.clone();
+        let directories = &mut config.directories;
+        for dir in [
+            &mut directories.use_case_dir,
+            &mut directories.test_dir,
+            &mut directories.actor_dir,
+            &mut directories.data_dir,
+            &mut config.backup.directory,
+            &mut config.generation.snippet_dir,
+        ] {
+            *dir = self.resolve(&*dir).to_string_lossy().into_owned();
+        }
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_context_keeps_relative_paths() {
+        let config = Config::default();
+        let context = ProjectContext::current(config.clone());
+
+        assert_eq!(
+            context.resolved_config().directories.data_dir,
+            config.directories.data_dir
+        );
+        assert_eq!(
+            context.templates_dir(),
+            Path::new(".config/.mucm/template-assets")
+        );
+    }
+
+    #[test]
+    fn test_resolved_config_rebases_directories() {
+        let context = ProjectContext::new("/projects/shop", Config::default());
+        let resolved = context.resolved_config();
+
+        for dir in [
+            &resolved.directories.use_case_dir,
+            &resolved.directories.test_dir,
+            &resolved.directories.actor_dir,
+            &resolved.directories.data_dir,
+            &resolved.backup.directory,
+            &resolved.generation.snippet_dir,
+        ] {
+            assert!(Path::new(dir).starts_with("/projects/shop"), "{}", dir);
+        }
+        assert_eq!(
+            context.config().directories.data_dir,
+            Config::default().directories.data_dir
+        );
+    }
+}
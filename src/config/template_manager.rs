@@ -33,7 +33,8 @@
 //! - `config.generation.test_language` determines which language templates to copy
 
 use crate::config::types::Config;
-use anyhow::{Context, Result};
+use crate::core::MucmError;
+use crate::core::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -53,6 +54,12 @@ impl TemplateManager {
     /// * The configuration cannot be serialized to TOML
     /// * The configuration file cannot be written
     pub fn create_config_from_template(config: &Config) -> Result<()> {
+        Self::create_config_from_template_in(config, Path::new("."))
+    }
+
+    /// Create a configuration file from the current config state in the
+    /// project rooted at `base_dir` (see `create_config_from_template`).
+    pub fn create_config_from_template_in(config: &Config, base_dir: &Path) -> Result<()> {
         // Load the template file to preserve comments and formatting
         let source_templates_dir = Self::find_source_templates_dir()?;
         let template_path = source_templates_dir.join("config.toml");
@@ -70,6 +77,10 @@ impl TemplateManager {
             r#"description = "A project managed with use case manager""#,
             &format!(r#"description = "{}""#, config.project.description),
         );
+        template_content = template_content.replace(
+            r#"id_prefix = "UC""#,
+            &format!(r#"id_prefix = "{}""#, config.project.id_prefix),
+        );
 
         // Methodologies - use regex to replace any array content
         let methodologies_str = config
@@ -109,6 +120,7 @@ impl TemplateManager {
         let backend_str = match config.storage.backend {
             crate::config::StorageBackend::Toml => "toml",
             crate::config::StorageBackend::Sqlite => "sqlite",
+            crate::config::StorageBackend::Events => "events",
         };
         template_content = template_content.replace(
             r#"backend = "toml""#,
@@ -134,7 +146,7 @@ impl TemplateManager {
         );
 
         // Write the config
-        let config_path = Config::config_path();
+        let config_path = base_dir.join(Config::config_path());
         fs::write(&config_path, &template_content).context("Failed to write config file")?;
 
         Ok(())
@@ -172,7 +184,7 @@ impl TemplateManager {
         let local_templates = Path::new("source-templates");
         if local_templates.exists() {
             // Found in dev location - install to user config for future use
-            Self::install_templates_to_user_config(&local_templates)?;
+            Self::install_templates_to_user_config(local_templates)?;
 
             // Return user config path if installation succeeded
             if let Some(proj_dirs) = ProjectDirs::from("", "", "mucm") {
@@ -231,10 +243,8 @@ impl TemplateManager {
             }
         }
 
-        anyhow::bail!(
-            "Source templates directory not found. Run from project root or ensure source-templates/ exists.\n\
-             Tip: Templates should be installed to ~/.config/mucm/templates/ automatically on first run."
-        )
+        Err(MucmError::NotFound("Source templates directory not found. Run from project root or ensure source-templates/ exists.\n\
+             Tip: Templates should be installed to ~/.config/mucm/templates/ automatically on first run.".to_string()))
     }
 
     /// Install templates to user config directory (~/.config/mucm/templates/)
@@ -250,8 +260,9 @@ impl TemplateManager {
     fn install_templates_to_user_config(source_path: &Path) -> Result<()> {
         use directories::ProjectDirs;
 
-        let proj_dirs = ProjectDirs::from("", "", "mucm")
-            .ok_or_else(|| anyhow::anyhow!("Could not determine user config directory"))?;
+        let proj_dirs = ProjectDirs::from("", "", "mucm").ok_or_else(|| {
+            MucmError::Validation("Could not determine user config directory".to_string())
+        })?;
 
         let user_templates_dir = proj_dirs.config_dir().join("templates");
 
@@ -305,10 +316,10 @@ impl TemplateManager {
         // Load the config from base_dir to see which methodologies to import
         let config_path = base_path.join(Config::CONFIG_DIR).join("mucm.toml");
         if !config_path.exists() {
-            anyhow::bail!(
+            return Err(MucmError::NotFound(format!(
                 "Config file not found at {:?} - run 'mucm init' first",
                 config_path
-            );
+            )));
         }
         let content = fs::read_to_string(&config_path).context("Failed to read config file")?;
         let config: Config = toml::from_str(&content).context("Failed to parse config file")?;
@@ -321,6 +332,9 @@ impl TemplateManager {
         // Copy scenarios directory
         Self::copy_scenarios(&source_templates_dir, &config_templates_dir)?;
 
+        // Copy named overview templates
+        Self::copy_overviews(&source_templates_dir, &config_templates_dir)?;
+
         // Copy methodologies
         Self::copy_methodologies(&source_templates_dir, &config, &config_templates_dir)?;
 
@@ -330,7 +344,7 @@ impl TemplateManager {
         Ok(())
     }
 
-    /// Copy root template files (overview.hbs, etc.).
+    /// Copy root template files (overview.hbs, actor.hbs, etc.).
     ///
     /// Copies template files that are not specific to any methodology or language,
     /// such as overview templates used for generating project documentation.
@@ -354,6 +368,54 @@ impl TemplateManager {
             }
         }
 
+        // Copy overview-index.hbs (index page of a sharded overview)
+        let index_src = source_templates_dir.join("overview-index.hbs");
+        if index_src.exists() {
+            let index_dst = config_templates_dir.join("overview-index.hbs");
+            if !index_dst.exists() {
+                fs::copy(&index_src, &index_dst)?;
+                println!("✓ Copied overview index template");
+            } else {
+                println!("⊙ Skipped overview index template (already exists)");
+            }
+        }
+
+        // Copy actor.hbs (pages of personas and system actors)
+        let actor_src = source_templates_dir.join("actor.hbs");
+        if actor_src.exists() {
+            let actor_dst = config_templates_dir.join("actor.hbs");
+            if !actor_dst.exists() {
+                fs::copy(&actor_src, &actor_dst)?;
+                println!("✓ Copied actor template");
+            } else {
+                println!("⊙ Skipped actor template (already exists)");
+            }
+        }
+
+        // Copy html-page.hbs (page layout of `mucm export --format html`)
+        let html_page_src = source_templates_dir.join("html-page.hbs");
+        if html_page_src.exists() {
+            let html_page_dst = config_templates_dir.join("html-page.hbs");
+            if !html_page_dst.exists() {
+                fs::copy(&html_page_src, &html_page_dst)?;
+                println!("✓ Copied HTML page template");
+            } else {
+                println!("⊙ Skipped HTML page template (already exists)");
+            }
+        }
+
+        // Copy pdf-document.hbs (document layout of `mucm export --format pdf`)
+        let pdf_document_src = source_templates_dir.join("pdf-document.hbs");
+        if pdf_document_src.exists() {
+            let pdf_document_dst = config_templates_dir.join("pdf-document.hbs");
+            if !pdf_document_dst.exists() {
+                fs::copy(&pdf_document_src, &pdf_document_dst)?;
+                println!("✓ Copied PDF document template");
+            } else {
+                println!("⊙ Skipped PDF document template (already exists)");
+            }
+        }
+
         Ok(())
     }
 
@@ -385,6 +447,33 @@ impl TemplateManager {
         Ok(())
     }
 
+    /// Copy named overview templates.
+    ///
+    /// Copies the overviews directory (executive, engineering, QA, ...) rendered
+    /// by `mucm regenerate --overview <name>`. Existing files are kept.
+    ///
+    /// # Arguments
+    /// * `source_templates_dir` - Path to the source templates directory
+    /// * `config_templates_dir` - Path to the destination templates directory
+    ///
+    /// # Errors
+    /// Returns an error if the overviews directory exists but cannot be copied.
+    fn copy_overviews(source_templates_dir: &Path, config_templates_dir: &Path) -> Result<()> {
+        let overviews_src = source_templates_dir.join("overviews");
+        if overviews_src.exists() {
+            let overviews_dst = config_templates_dir.join("overviews");
+            if !overviews_dst.exists() {
+                Self::copy_dir_recursive(&overviews_src, &overviews_dst)?;
+                println!("✓ Copied overview templates");
+            } else {
+                Self::copy_dir_incremental(&overviews_src, &overviews_dst)?;
+                println!("⊙ Updated overview templates (preserved existing files)");
+            }
+        }
+
+        Ok(())
+    }
+
     /// Copy methodology templates and configs.
     ///
     /// For each methodology specified in the configuration, this function
@@ -408,30 +497,27 @@ impl TemplateManager {
     ) -> Result<()> {
         let source_methodologies = source_templates_dir.join("methodologies");
         if !source_methodologies.exists() {
-            anyhow::bail!(
+            return Err(MucmError::NotFound(format!(
                 "Source methodologies directory not found at {:?}",
                 source_methodologies
-            );
+            )));
         }
 
         for methodology in &config.templates.methodologies {
             let source_method_dir = source_methodologies.join(methodology);
             if !source_method_dir.exists() {
-                anyhow::bail!(
-                    "Methodology '{}' not found in source-templates/methodologies/. \
+                return Err(MucmError::NotFound(format!("Methodology '{}' not found in source-templates/methodologies/. \
                      Available methodologies should be in source-templates/methodologies/{{name}}/ directories.",
-                    methodology
-                );
+                    methodology)));
             }
 
             // Validate that required methodology.toml file exists
             let methodology_file = source_method_dir.join("methodology.toml");
             if !methodology_file.exists() {
-                anyhow::bail!(
+                return Err(MucmError::Validation(format!(
                     "Methodology '{}' is missing methodology.toml file in {:?}",
-                    methodology,
-                    source_method_dir
-                );
+                    methodology, source_method_dir
+                )));
             }
 
             // Copy methodology templates to template-assets/methodologies/{methodology}/
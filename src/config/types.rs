@@ -28,22 +28,21 @@
 //! with methodology-specific fields and generation settings.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 /// Storage backend for use cases
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[derive(Default)]
 pub enum StorageBackend {
     /// TOML files (default, simple, git-friendly)
+    #[default]
     Toml,
     /// SQLite database (for advanced querying)
     Sqlite,
-}
-
-impl Default for StorageBackend {
-    fn default() -> Self {
-        StorageBackend::Toml
-    }
+    /// Append-only log of domain events with periodic snapshots (experimental)
+    Events,
 }
 
 impl std::fmt::Display for StorageBackend {
@@ -51,6 +50,7 @@ impl std::fmt::Display for StorageBackend {
         match self {
             StorageBackend::Toml => write!(f, "toml"),
             StorageBackend::Sqlite => write!(f, "sqlite"),
+            StorageBackend::Events => write!(f, "events"),
         }
     }
 }
@@ -62,8 +62,9 @@ impl FromStr for StorageBackend {
         match s.to_lowercase().as_str() {
             "toml" => Ok(StorageBackend::Toml),
             "sqlite" | "sql" | "db" => Ok(StorageBackend::Sqlite),
+            "events" => Ok(StorageBackend::Events),
             _ => Err(format!(
-                "Invalid storage backend: {}. Valid options: toml, sqlite",
+                "Invalid storage backend: {}. Valid options: toml, sqlite, events",
                 s
             )),
         }
@@ -97,6 +98,10 @@ mod storage_backend_tests {
             StorageBackend::from_str("db").unwrap(),
             StorageBackend::Sqlite
         );
+        assert_eq!(
+            StorageBackend::from_str("events").unwrap(),
+            StorageBackend::Events
+        );
         assert!(StorageBackend::from_str("invalid").is_err());
     }
 
@@ -184,6 +189,36 @@ pub struct Config {
     /// Actor configuration with custom persona fields
     #[serde(default, alias = "persona")]
     pub actor: ActorConfig,
+    /// Terminal and template presentation settings
+    #[serde(default)]
+    pub presentation: PresentationConfig,
+    /// Limits enforced while rendering templates
+    #[serde(default)]
+    pub render_limits: RenderLimitsConfig,
+    /// Automatic backups of changed data files
+    #[serde(default)]
+    pub backup: BackupConfig,
+    /// Settings of `mucm export`
+    #[serde(default)]
+    pub export: ExportConfig,
+    /// Settings of `mucm publish confluence`
+    #[serde(default)]
+    pub confluence: ConfluenceConfig,
+    /// Settings of `mucm sync jira`
+    #[serde(default)]
+    pub jira: JiraConfig,
+    /// Settings of `mucm sync github`
+    #[serde(default)]
+    pub github: GitHubConfig,
+    /// Thresholds and weights of the health score of `mucm status`
+    #[serde(default)]
+    pub health: HealthConfig,
+    /// Named use case filters, applied with `--filter-name`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub filters: BTreeMap<String, SavedFilter>,
+    /// Command aliases (`[alias]`): a name and the arguments it expands to
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub alias: BTreeMap<String, String>,
 }
 
 /// Project-level configuration settings.
@@ -196,6 +231,41 @@ pub struct ProjectConfig {
     pub name: String,
     /// Brief description of the project's purpose and scope
     pub description: String,
+    /// Prefix of generated use case IDs (e.g., "UC" gives UC-AUT-001)
+    #[serde(default = "default_id_prefix")]
+    pub id_prefix: String,
+    /// Allow `mucm report ids --renumber` to change existing use case IDs
+    /// Off by default, as IDs are often referenced outside the project
+    #[serde(default)]
+    pub allow_renumbering: bool,
+    /// How new use case IDs are assigned
+    /// Options: "sequential" (default), "ulid", "uuid_short", or "pattern"
+    #[serde(default)]
+    pub id_strategy: IdStrategy,
+    /// Shape of new IDs with `id_strategy = "pattern"`, e.g. "{prefix}-{category}-{seq:04}"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_pattern: Option<String>,
+}
+
+/// Default value for ProjectConfig::id_prefix
+pub(crate) fn default_id_prefix() -> String {
+    "UC".to_string()
+}
+
+/// How new use case IDs are assigned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdStrategy {
+    /// Numbered per category: UC-AUT-001, UC-AUT-002, ...
+    #[default]
+    Sequential,
+    /// Prefix and a ULID (UC-01JA2Z6V4KQ3M8...), sortable by creation time
+    Ulid,
+    /// Prefix and the first 8 hex digits of a random UUID (UC-3f9a6c1e)
+    #[serde(alias = "uuid-short")]
+    UuidShort,
+    /// Built from `project.id_pattern`
+    Pattern,
 }
 
 /// Directory configuration for file organization.
@@ -233,6 +303,10 @@ pub struct TemplateConfig {
     /// If not specified, will be set to the first available methodology
     #[serde(default)]
     pub default_methodology: String,
+    /// Methodology precedence for use cases with several views: views are
+    /// stored, listed and generated in this order, unlisted methodologies last
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub view_order: Vec<String>,
 }
 
 /// Configuration for code generation and test creation settings.
@@ -244,10 +318,158 @@ pub struct GenerationConfig {
     /// Programming language to use for generated test files
     /// Must be one of the supported languages (rust, python, javascript)
     pub test_language: String,
+    /// Framework variant of the test language (e.g. "pytest", "cucumber")
+    /// Variants are declared in the language's info.toml; omit for the base template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_framework: Option<String>,
     /// Whether to automatically generate test files when creating use cases
     pub auto_generate_tests: bool,
     /// Whether to overwrite existing test documentation files during regeneration
     pub overwrite_test_documentation: bool,
+    /// Status/priority badges rendered in use case headers
+    /// Options: "none" (default), "shields", or "html"
+    #[serde(default)]
+    pub badges: BadgeStyle,
+    /// Order of scenarios in generated documentation and tests
+    /// Options: "manual" (default) or "by_type"
+    #[serde(default)]
+    pub scenario_order: ScenarioOrder,
+    /// Actor, flow and sequence diagrams rendered in use case documentation
+    /// Options: "none" (default), "mermaid", or "plantuml"
+    #[serde(default)]
+    pub diagram_format: DiagramFormat,
+    /// Format of the generated use case documents
+    /// Options: "markdown" (default, `.md`) or "asciidoc" (`.adoc`)
+    #[serde(default)]
+    pub output_format: OutputFormat,
+    /// Turn use case IDs mentioned in descriptions, steps and conditions into
+    /// relative links to the referenced use case's markdown file
+    #[serde(default)]
+    pub autolink_ids: bool,
+    /// Turn actor and persona names mentioned in generated content into links
+    /// to their pages, with the actor's role shown on hover
+    #[serde(default)]
+    pub autolink_actors: bool,
+    /// With `autolink_actors`, also add a footnote summarizing each linked actor
+    #[serde(default)]
+    pub actor_footnotes: bool,
+    /// End each use case document with an "Edit this use case" link to its
+    /// TOML data file (TOML storage only)
+    #[serde(default)]
+    pub edit_links: bool,
+    /// URL of the edit link instead of a relative path, with `{path}` replaced
+    /// by the data file's path from the project root
+    /// (e.g. "https://github.com/org/repo/edit/main/{path}")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edit_url: Option<String>,
+    /// Split the overview into per-category pages plus an index once the project
+    /// has more use cases than this; 0 keeps a single overview page
+    #[serde(default = "default_overview_shard_threshold")]
+    pub overview_shard_threshold: usize,
+    /// Also write an `overview.md` into each category directory, summarizing
+    /// that category's use cases with links relative to the directory
+    #[serde(default)]
+    pub category_overviews: bool,
+    /// Line endings of generated documentation and test files
+    /// Options: "auto" (default, native to the platform), "lf", or "crlf"
+    #[serde(default)]
+    pub line_endings: LineEnding,
+    /// Field the overview groups use cases by instead of their category, as a
+    /// dotted path (e.g. "methodology_fields.business.domain", "tags", "owner")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overview_group_by: Option<String>,
+    /// Sections of the overview, in order; defaults to the default methodology's
+    /// `[overview] sections`, or summary, progress and table
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overview_sections: Option<Vec<OverviewSection>>,
+    /// Code injected at the named points of the test templates
+    /// ("imports", "fixtures", "helpers"), for every use case
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub snippets: BTreeMap<String, String>,
+    /// Directory of snippet files: `<point>.<ext>` for every use case and
+    /// `<category>/<point>.<ext>` for one category, appended to `snippets`
+    #[serde(default = "default_snippet_dir")]
+    pub snippet_dir: String,
+    /// Gherkin `.feature` files generated from the use case scenarios
+    #[serde(default)]
+    pub gherkin: GherkinConfig,
+    /// YAML front matter prepended to generated markdown documents
+    #[serde(default)]
+    pub front_matter: FrontMatterConfig,
+}
+
+/// Gherkin feature file generation (`[generation.gherkin]`).
+///
+/// When enabled, every use case gets a `.feature` file next to the others of
+/// its category, with one scenario per use case scenario, so Cucumber or
+/// SpecFlow suites can bind step definitions to them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GherkinConfig {
+    /// Whether feature files are written when use case documentation is generated
+    pub enabled: bool,
+    /// Directory of the feature files, one subdirectory per category
+    pub directory: String,
+}
+
+impl Default for GherkinConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: "features".to_string(),
+        }
+    }
+}
+
+/// YAML front matter of generated markdown (`[generation.front_matter]`).
+///
+/// When enabled, use case documents start with a front matter block built from
+/// the patterns below, and overview and actor pages with their title, so the
+/// output drops into a Docusaurus or Hugo site. Patterns may use `{id}`,
+/// `{title}`, `{category}`, `{category_slug}`, `{priority}`, `{status}`,
+/// `{methodology}`, `{level}` and `{view}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FrontMatterConfig {
+    /// Whether markdown documents get a front matter block
+    pub enabled: bool,
+    /// Pattern of the `title` key
+    pub title: String,
+    /// Add `sidebar_position`, the number at the end of the use case ID
+    pub sidebar_position: bool,
+    /// Pattern of the `slug` key, omitted when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// Patterns of the `tags` list; tags that come out empty are dropped
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Other keys and their patterns (e.g. `draft = "false"`, `weight = "{id}"`);
+    /// values that read as integers or booleans are written as such
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, String>,
+}
+
+impl Default for FrontMatterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            title: "{id}: {title}".to_string(),
+            sidebar_position: false,
+            slug: None,
+            tags: Vec::new(),
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+/// Default value for GenerationConfig::snippet_dir
+fn default_snippet_dir() -> String {
+    ".config/.mucm/snippets".to_string()
+}
+
+/// Default value for GenerationConfig::overview_shard_threshold
+fn default_overview_shard_threshold() -> usize {
+    500
 }
 
 impl Default for GenerationConfig {
@@ -260,12 +482,479 @@ impl Default for GenerationConfig {
     fn default() -> Self {
         Self {
             test_language: "python".to_string(),
+            test_framework: None,
             auto_generate_tests: false,
             overwrite_test_documentation: false,
+            badges: BadgeStyle::default(),
+            scenario_order: ScenarioOrder::default(),
+            diagram_format: DiagramFormat::default(),
+            output_format: OutputFormat::default(),
+            autolink_ids: false,
+            autolink_actors: false,
+            actor_footnotes: false,
+            edit_links: false,
+            edit_url: None,
+            overview_shard_threshold: default_overview_shard_threshold(),
+            category_overviews: false,
+            line_endings: LineEnding::default(),
+            overview_group_by: None,
+            overview_sections: None,
+            snippets: BTreeMap::new(),
+            snippet_dir: default_snippet_dir(),
+            gherkin: GherkinConfig::default(),
+            front_matter: FrontMatterConfig::default(),
+        }
+    }
+}
+
+/// Style of the status/priority badges rendered in use case headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BadgeStyle {
+    /// No badges
+    #[default]
+    None,
+    /// shields.io image badges (rendered by GitHub, GitLab, and most wikis)
+    Shields,
+    /// Inline HTML badges that need no network access
+    Html,
+}
+
+/// How scenarios are ordered when rendering a use case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioOrder {
+    /// Follow each scenario's `order` key, falling back to insertion order
+    #[default]
+    Manual,
+    /// Group happy path first, then alternative flows, extensions and exceptions;
+    /// `order` applies within each group
+    ByType,
+}
+
+/// Syntax of the diagrams rendered in use case documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagramFormat {
+    /// No diagrams
+    #[default]
+    None,
+    /// Mermaid code blocks (rendered by GitHub, GitLab, and mdBook plugins)
+    Mermaid,
+    /// PlantUML code blocks, for wikis that only render PlantUML
+    Plantuml,
+}
+
+/// Format of the generated use case documents.
+///
+/// Methodology templates are written in markdown; for AsciiDoc their output
+/// is converted, unless the level has its own `uc_<level>.adoc.hbs` template.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// Markdown documents (`.md`)
+    #[default]
+    Markdown,
+    /// AsciiDoc documents (`.adoc`), for Asciidoctor and Antora sites
+    #[serde(alias = "adoc")]
+    Asciidoc,
+}
+
+impl OutputFormat {
+    /// File extension of the documents, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "md",
+            OutputFormat::Asciidoc => "adoc",
+        }
+    }
+}
+
+/// A section of the project overview.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverviewSection {
+    /// Use case, scenario and category counts
+    Summary,
+    /// Use cases grouped by category (or `overview_group_by`)
+    Table,
+    /// Use cases per status and the share that is implemented
+    Progress,
+    /// Most recently updated use cases
+    RecentChanges,
+    /// High-priority use cases not implemented yet, and overdue reviews
+    Risks,
+    /// The actor hierarchy with use case counts rolled up per subtree
+    Actors,
+}
+
+impl OverviewSection {
+    /// Sections of an overview when neither config nor methodology choose
+    pub const DEFAULT: [OverviewSection; 4] = [
+        OverviewSection::Summary,
+        OverviewSection::Progress,
+        OverviewSection::Actors,
+        OverviewSection::Table,
+    ];
+
+    /// Name of the section in config files and templates
+    pub fn name(self) -> &'static str {
+        match self {
+            OverviewSection::Summary => "summary",
+            OverviewSection::Table => "table",
+            OverviewSection::Progress => "progress",
+            OverviewSection::RecentChanges => "recent_changes",
+            OverviewSection::Risks => "risks",
+            OverviewSection::Actors => "actors",
+        }
+    }
+}
+
+/// Line endings written to generated files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LineEnding {
+    /// CRLF on Windows, LF everywhere else
+    #[default]
+    Auto,
+    /// Unix line endings (`\n`)
+    Lf,
+    /// Windows line endings (`\r\n`)
+    Crlf,
+}
+
+impl LineEnding {
+    /// The line terminator this setting resolves to on the current platform
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Auto if cfg!(windows) => "\r\n",
+            LineEnding::Auto | LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
         }
     }
 }
 
+/// Presentation settings for terminal output and rendered templates.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PresentationConfig {
+    /// Icon set used by formatters and the `status_icon` template helper
+    /// Options: "unicode" (default), "ascii", or "none"
+    #[serde(default)]
+    pub icon_theme: IconTheme,
+    /// When to color terminal output
+    /// Options: "auto" (default), "always", or "never"; NO_COLOR and --no-color always win
+    #[serde(default)]
+    pub color: ColorMode,
+}
+
+/// Limits enforced by the template engine on every render.
+///
+/// Protects against templates that loop, recurse through partials, or produce
+/// runaway output. A value of 0 disables the corresponding limit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenderLimitsConfig {
+    /// Maximum wall-clock time of a single render, in milliseconds
+    #[serde(default = "default_max_render_ms")]
+    pub max_render_ms: u64,
+    /// Maximum size of a single rendered document, in bytes
+    #[serde(default = "default_max_output_bytes")]
+    pub max_output_bytes: u64,
+    /// Maximum nesting depth of partials; templates that include themselves always exceed it
+    #[serde(default = "default_max_partial_depth")]
+    pub max_partial_depth: usize,
+}
+
+/// Default value for RenderLimitsConfig::max_render_ms
+fn default_max_render_ms() -> u64 {
+    10_000
+}
+
+/// Default value for RenderLimitsConfig::max_output_bytes (16 MiB)
+fn default_max_output_bytes() -> u64 {
+    16 * 1024 * 1024
+}
+
+/// Default value for RenderLimitsConfig::max_partial_depth
+fn default_max_partial_depth() -> usize {
+    16
+}
+
+impl Default for RenderLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_render_ms: default_max_render_ms(),
+            max_output_bytes: default_max_output_bytes(),
+            max_partial_depth: default_max_partial_depth(),
+        }
+    }
+}
+
+/// Automatic backups of data files.
+///
+/// Every command that changes TOML data files first copies the previous
+/// versions into one timestamped snapshot under `directory`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupConfig {
+    /// Whether changed data files are backed up automatically
+    #[serde(default = "default_backup_enabled")]
+    pub enabled: bool,
+    /// Directory holding the snapshots, relative to the project root
+    #[serde(default = "default_backup_directory")]
+    pub directory: String,
+    /// Number of snapshots to keep; 0 keeps all
+    #[serde(default = "default_backup_keep")]
+    pub keep: usize,
+    /// Remove snapshots older than this many days; 0 keeps them regardless of age
+    #[serde(default = "default_backup_max_age_days")]
+    pub max_age_days: u32,
+}
+
+/// Default value for BackupConfig::enabled
+fn default_backup_enabled() -> bool {
+    true
+}
+
+/// Default value for BackupConfig::directory
+fn default_backup_directory() -> String {
+    ".config/.mucm/backups".to_string()
+}
+
+/// Default value for BackupConfig::keep
+fn default_backup_keep() -> usize {
+    20
+}
+
+/// Default value for BackupConfig::max_age_days
+fn default_backup_max_age_days() -> u32 {
+    30
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_backup_enabled(),
+            directory: default_backup_directory(),
+            keep: default_backup_keep(),
+            max_age_days: default_backup_max_age_days(),
+        }
+    }
+}
+
+/// Settings of `mucm export`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportConfig {
+    /// Command converting the HTML document into a PDF for `--format pdf`
+    /// Split on whitespace; `{input}` and `{output}` are replaced by the HTML
+    /// and PDF paths, e.g. "weasyprint {input} {output}"
+    #[serde(default = "default_pdf_command")]
+    pub pdf_command: String,
+}
+
+/// Default value for ExportConfig::pdf_command
+fn default_pdf_command() -> String {
+    "wkhtmltopdf --quiet --enable-local-file-access {input} {output}".to_string()
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self {
+            pdf_command: default_pdf_command(),
+        }
+    }
+}
+
+/// Settings of `mucm publish confluence`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfluenceConfig {
+    /// Base URL of the Confluence site, e.g. "https://example.atlassian.net/wiki"
+    #[serde(default)]
+    pub base_url: String,
+    /// Key of the space the pages are published in
+    #[serde(default)]
+    pub space_key: String,
+    /// Page the category pages are created under; the space root when empty
+    #[serde(default)]
+    pub parent_page_id: String,
+    /// User the API token belongs to (the account email on Confluence Cloud)
+    #[serde(default)]
+    pub user: String,
+    /// Environment variable holding the API token; the token is never stored
+    #[serde(default = "default_confluence_token_env")]
+    pub token_env: String,
+}
+
+/// Default value for ConfluenceConfig::token_env
+fn default_confluence_token_env() -> String {
+    "CONFLUENCE_API_TOKEN".to_string()
+}
+
+impl Default for ConfluenceConfig {
+    fn default() -> Self {
+        Self {
+            base_url: String::new(),
+            space_key: String::new(),
+            parent_page_id: String::new(),
+            user: String::new(),
+            token_env: default_confluence_token_env(),
+        }
+    }
+}
+
+/// Settings of `mucm sync jira`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JiraConfig {
+    /// Base URL of the Jira site, e.g. "https://example.atlassian.net"
+    pub base_url: String,
+    /// Key of the project the issues are created in (e.g. "SHOP")
+    pub project_key: String,
+    /// Type of the created issues
+    pub issue_type: String,
+    /// User the API token belongs to (the account email on Jira Cloud)
+    pub user: String,
+    /// Environment variable holding the API token; the token is never stored
+    pub token_env: String,
+    /// Issue link type used for references between use cases
+    pub link_type: String,
+    /// Jira status names and the use case status they stand for
+    /// (e.g. "In Review" = "implemented"); other statuses follow their category
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub status_map: BTreeMap<String, String>,
+}
+
+impl Default for JiraConfig {
+    fn default() -> Self {
+        Self {
+            base_url: String::new(),
+            project_key: String::new(),
+            issue_type: "Story".to_string(),
+            user: String::new(),
+            token_env: "JIRA_API_TOKEN".to_string(),
+            link_type: "Relates".to_string(),
+            status_map: BTreeMap::new(),
+        }
+    }
+}
+
+/// Settings of `mucm sync github`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitHubConfig {
+    /// Repository the issues are created in, as "owner/repo"
+    pub repository: String,
+    /// Base URL of the REST API (GitHub Enterprise: "https://<host>/api/v3")
+    pub api_url: String,
+    /// Environment variable holding the access token; the token is never stored
+    pub token_env: String,
+    /// Labels added to the created issues, next to the use case ID
+    pub labels: Vec<String>,
+}
+
+impl Default for GitHubConfig {
+    fn default() -> Self {
+        Self {
+            repository: String::new(),
+            api_url: "https://api.github.com".to_string(),
+            token_env: "GITHUB_TOKEN".to_string(),
+            labels: vec!["use-case".to_string()],
+        }
+    }
+}
+
+/// Thresholds and weights of the health score shown by `mucm status`.
+///
+/// Each component scores the share of use cases that pass its check; the
+/// health score is the average of the components, weighted by these weights.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthConfig {
+    /// Scenarios a use case needs to count as covered
+    pub min_scenarios: usize,
+    /// Days without an update after which a use case counts as stale
+    /// (0 = only review dates make a use case stale)
+    pub stale_after_days: u32,
+    /// Weight of use cases without validation issues
+    pub validation_weight: u32,
+    /// Weight of use cases that are not stale
+    pub staleness_weight: u32,
+    /// Weight of use cases with enough scenarios
+    pub coverage_weight: u32,
+    /// Weight of use cases whose test file matches their scenarios
+    /// (ignored when test generation is disabled)
+    pub drift_weight: u32,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            min_scenarios: 1,
+            stale_after_days: 180,
+            validation_weight: 30,
+            staleness_weight: 20,
+            coverage_weight: 30,
+            drift_weight: 20,
+        }
+    }
+}
+
+/// A named use case filter (`[filters.<name>]`).
+///
+/// Every criterion that is set must match; text comparisons ignore case.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedFilter {
+    /// Category name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Aggregated status, e.g. "in_progress" (or "in-progress")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Priority: low, medium, high, or critical
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    /// Methodology of one of the use case's views
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub methodology: Option<String>,
+    /// Text contained in the title or description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Only use cases whose review date has arrived
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub needs_review: bool,
+    /// Dotted field paths and the value they must have,
+    /// e.g. `"methodology_fields.business.domain" = "billing"` or `tags = "security"`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+}
+
+/// When to emit ANSI colors in terminal output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorMode {
+    /// Color only when stdout is a terminal
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
+/// Icon set for status markers and message prefixes.
+///
+/// `ascii` and `none` are meant for terminals and wikis that mangle emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IconTheme {
+    /// Emoji icons
+    #[default]
+    Unicode,
+    /// Plain ASCII markers such as `[~]`
+    Ascii,
+    /// No icons at all
+    None,
+}
+
 /// Configuration for automatically generated metadata fields.
 ///
 /// Controls which metadata fields are automatically populated when use cases
@@ -278,6 +967,11 @@ pub struct MetadataConfig {
     /// Whether to automatically update timestamp when use case is modified
     /// Updates a "last_updated" field with the current date/time
     pub last_updated: bool,
+    /// Days after the last update by which a use case should be reviewed again
+    /// Sets `review_by` when a use case is created or its content changes;
+    /// omit to disable review dates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_interval_days: Option<u32>,
 }
 
 /// Storage backend configuration settings.
@@ -315,7 +1009,7 @@ pub struct ActorConfig {
     /// Key is the field name, value is the field configuration
     /// Serialized as: persona_fields.fieldname = { ... }
     #[serde(default, rename = "persona_fields")]
-    pub persona_fields: std::collections::HashMap<String, crate::core::CustomFieldConfig>,
+    pub persona_fields: std::collections::HashMap<String, PersonaFieldConfig>,
     /// Whether to automatically create standard system actors during project initialization
     /// Default is true for new projects
     #[serde(default = "default_auto_create_standard_actors")]
@@ -329,7 +1023,7 @@ fn default_auto_create_standard_actors() -> bool {
 
 impl ActorConfig {
     /// Get a persona custom field by name
-    pub fn get_persona_field(&self, name: &str) -> Option<&crate::core::CustomFieldConfig> {
+    pub fn get_persona_field(&self, name: &str) -> Option<&PersonaFieldConfig> {
         self.persona_fields.get(name)
     }
 
@@ -343,3 +1037,71 @@ impl ActorConfig {
         self.persona_fields.keys().collect()
     }
 }
+
+/// Schema of a custom persona field, declared in `[actor.persona_fields]`.
+///
+/// Like methodology custom fields, with a typed `type` and optional `options`.
+///
+/// ```toml
+/// [actor.persona_fields]
+/// seniority = { type = "string", required = true, options = ["junior", "senior"] }
+/// devices = { type = "array", options = ["desktop", "phone", "tablet"] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersonaFieldConfig {
+    /// Label shown in prompts; defaults to the field name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Type of the value
+    #[serde(rename = "type")]
+    pub field_type: PersonaFieldType,
+    /// Whether every persona needs a non-empty value
+    #[serde(default)]
+    pub required: bool,
+    /// Value of new personas when none is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    /// Shown as help in prompts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Example value shown as help in prompts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<String>,
+    /// Allowed values (for arrays, allowed items); empty allows anything
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+}
+
+impl PersonaFieldConfig {
+    /// Label shown in prompts
+    pub fn label_or<'a>(&'a self, name: &'a str) -> &'a str {
+        self.label.as_deref().unwrap_or(name)
+    }
+}
+
+/// Type of a persona field's value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonaFieldType {
+    /// A single line of text
+    String,
+    /// Multi-line text
+    Text,
+    Number,
+    Boolean,
+    /// A list of strings
+    Array,
+}
+
+impl PersonaFieldType {
+    /// Name of the type in config files, e.g. "array"
+    pub fn name(self) -> &'static str {
+        match self {
+            PersonaFieldType::String => "string",
+            PersonaFieldType::Text => "text",
+            PersonaFieldType::Number => "number",
+            PersonaFieldType::Boolean => "boolean",
+            PersonaFieldType::Array => "array",
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! User-level settings shared by all projects.
+//!
+//! Stored in `config.toml` inside the platform config directory
+//! (`~/.config/mucm` on Linux), next to the installed templates. Currently it
+//! only remembers the recently opened projects for the interactive project
+//! switcher.
+
+use crate::core::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Number of recent projects kept
+pub const MAX_RECENT_PROJECTS: usize = 10;
+
+/// Settings of the current user, independent of any project
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserConfig {
+    /// Project roots, most recently opened first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recent_projects: Vec<PathBuf>,
+}
+
+impl UserConfig {
+    pub const FILE_NAME: &'static str = "config.toml";
+
+    /// Path of the user config file, if the platform has a config directory
+    pub fn path() -> Option<PathBuf> {
+        directories::ProjectDirs::from("", "", "mucm")
+            .map(|dirs| dirs.config_dir().join(Self::FILE_NAME))
+    }
+
+    /// Load the user config; a missing file (or config directory) is the default
+    pub fn load() -> Result<Self> {
+        match Self::path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Save the user config; does nothing without a config directory
+    pub fn save(&self) -> Result<()> {
+        match Self::path() {
+            Some(path) => self.save_to(&path),
+            None => Ok(()),
+        }
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Move a project root to the front of the recent projects
+    ///
+    /// The root is canonicalized so the same project opened through different
+    /// paths is listed once. Projects that no longer exist are dropped.
+    pub fn record_project(&mut self, root: &Path) {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        self.recent_projects
+            .retain(|project| *project != root && project.is_dir());
+        self.recent_projects.insert(0, root);
+        self.recent_projects.truncate(MAX_RECENT_PROJECTS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_record_project_moves_to_front_and_round_trips() {
+        let dir = TempDir::new().unwrap();
+        let first = dir.path().join("first");
+        let second = dir.path().join("second");
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+
+        let mut config = UserConfig::default();
+        config.record_project(&first);
+        config.record_project(&second);
+        config.record_project(&first);
+        config
+            .recent_projects
+            .push(dir.path().join("removed-project"));
+        config.record_project(&second);
+
+        let path = dir.path().join(UserConfig::FILE_NAME);
+        config.save_to(&path).unwrap();
+        let loaded = UserConfig::load_from(&path).unwrap();
+        assert_eq!(
+            loaded.recent_projects,
+            vec![
+                second.canonicalize().unwrap(),
+                first.canonicalize().unwrap()
+            ]
+        );
+    }
+}
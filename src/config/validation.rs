@@ -0,0 +1,690 @@
+//! # Configuration Validation
+//!
+//! Checks `mucm.toml` beyond what deserialization catches and turns serde
+//! errors into targeted diagnostics:
+//! - TOML syntax and type errors, pointing at the offending key and line
+//! - Unknown keys, with a did-you-mean suggestion
+//! - Invalid directory paths
+//! - Methodologies and languages that are not installed
+//!
+//! The CLI runs these checks before every command (errors stop the command)
+//! and on demand with `mucm config validate`.
+
+use crate::config::types::Config;
+use crate::config::TemplateManager;
+use crate::core::utils::{find_closest_matches, span};
+use crate::core::MucmError;
+use crate::core::{Context, Result};
+use crate::core::{LanguageRegistry, MethodologyRegistry, PatternIdGenerator, PersonaService};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Top-level sections of `mucm.toml`
+const SECTIONS: &[&str] = &[
+    "project",
+    "directories",
+    "templates",
+    "metadata",
+    "generation",
+    "storage",
+    "actor",
+    "persona",
+    "presentation",
+    "render_limits",
+    "backup",
+    "export",
+    "confluence",
+    "jira",
+    "github",
+    "health",
+    "filters",
+    "alias",
+    "extra_fields",
+];
+
+/// Keys of a section, or `None` when its keys are free-form
+fn section_keys(section: &str) -> Option<&'static [&'static str]> {
+    let keys: &[&str] = match section {
+        "project" => &[
+            "name",
+            "description",
+            "id_prefix",
+            "allow_renumbering",
+            "id_strategy",
+            "id_pattern",
+        ],
+        "directories" => &[
+            "use_case_dir",
+            "test_dir",
+            "actor_dir",
+            "persona_dir",
+            "data_dir",
+        ],
+        "templates" => &["methodologies", "default_methodology", "view_order"],
+        "metadata" => &["created", "last_updated", "review_interval_days"],
+        "generation" => &[
+            "test_language",
+            "test_framework",
+            "auto_generate_tests",
+            "overwrite_test_documentation",
+            "badges",
+            "scenario_order",
+            "diagram_format",
+            "output_format",
+            "autolink_ids",
+            "autolink_actors",
+            "actor_footnotes",
+            "edit_links",
+            "edit_url",
+            "overview_shard_threshold",
+            "category_overviews",
+            "line_endings",
+            "overview_group_by",
+            "overview_sections",
+            "snippets",
+            "snippet_dir",
+            "gherkin",
+            "front_matter",
+        ],
+        "storage" => &["backend"],
+        "actor" | "persona" => &["persona_fields", "auto_create_standard_actors"],
+        "presentation" => &["icon_theme", "color"],
+        "render_limits" => &["max_render_ms", "max_output_bytes", "max_partial_depth"],
+        "backup" => &["enabled", "directory", "keep", "max_age_days"],
+        "export" => &["pdf_command"],
+        "confluence" => &[
+            "base_url",
+            "space_key",
+            "parent_page_id",
+            "user",
+            "token_env",
+        ],
+        "jira" => &[
+            "base_url",
+            "project_key",
+            "issue_type",
+            "user",
+            "token_env",
+            "link_type",
+            "status_map",
+        ],
+        "github" => &["repository", "api_url", "token_env", "labels"],
+        "health" => &[
+            "min_scenarios",
+            "stale_after_days",
+            "validation_weight",
+            "staleness_weight",
+            "coverage_weight",
+            "drift_weight",
+        ],
+        _ => return None,
+    };
+    Some(keys)
+}
+
+/// Keys of a saved filter (`[filters.<name>]`)
+const FILTER_KEYS: &[&str] = &[
+    "category",
+    "status",
+    "priority",
+    "methodology",
+    "text",
+    "needs_review",
+    "fields",
+];
+
+/// How serious a configuration problem is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticLevel {
+    /// The configuration cannot be used as is
+    Error,
+    /// The configuration works, but probably not as intended
+    Warning,
+}
+
+/// A problem found in `mucm.toml`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDiagnostic {
+    pub level: DiagnosticLevel,
+    /// Dotted key the problem is about (e.g. "generation.test_language"), empty for the whole file
+    pub key: String,
+    pub message: String,
+    /// How to fix it, e.g. a did-you-mean suggestion
+    pub hint: Option<String>,
+}
+
+impl ConfigDiagnostic {
+    fn error(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            level: DiagnosticLevel::Error,
+            key: key.into(),
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn warning(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            level: DiagnosticLevel::Warning,
+            ..Self::error(key, message)
+        }
+    }
+
+    fn with_hint(mut self, hint: Option<String>) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    /// Whether this diagnostic makes the configuration unusable
+    pub fn is_error(&self) -> bool {
+        self.level == DiagnosticLevel::Error
+    }
+}
+
+impl fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.level {
+            DiagnosticLevel::Error => "error",
+            DiagnosticLevel::Warning => "warning",
+        };
+        if self.key.is_empty() {
+            write!(f, "{}: {}", level, self.message)?;
+        } else {
+            write!(f, "{}: {}: {}", level, self.key, self.message)?;
+        }
+        if let Some(hint) = &self.hint {
+            write!(f, "\n    → {}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates `mucm.toml` files.
+pub struct ConfigValidator;
+
+impl ConfigValidator {
+    /// Validate the config file of the project rooted at `base_dir`.
+    ///
+    /// # Errors (This function will return an error if)
+    /// * `base_dir` has no `.config/.mucm/mucm.toml`, or it cannot be read
+    pub fn validate_dir(base_dir: &Path) -> Result<Vec<ConfigDiagnostic>> {
+        let _span = span(|| "validate".to_string());
+        let config_path = base_dir.join(Config::config_path());
+        if !config_path.exists() {
+            return Err(MucmError::Validation(
+                "No markdown use case manager project found. Run 'mucm init' first.".to_string(),
+            ));
+        }
+        let content = fs::read_to_string(&config_path).context("Failed to read config file")?;
+        Ok(Self::validate_str(&content, base_dir))
+    }
+
+    /// Validate config file content; paths are resolved against `base_dir`.
+    ///
+    /// Errors come first. Installed methodologies and languages are only
+    /// checked once the content deserializes.
+    pub fn validate_str(content: &str, base_dir: &Path) -> Vec<ConfigDiagnostic> {
+        let table: toml::Table = match toml::from_str(content) {
+            Ok(table) => table,
+            Err(e) => return vec![Self::parse_error(content, &e)],
+        };
+
+        let mut diagnostics = unknown_keys(&table);
+        match toml::from_str::<Config>(content) {
+            Ok(config) => {
+                check_directories(&config, base_dir, &mut diagnostics);
+                check_id_pattern(&config, &mut diagnostics);
+                check_persona_fields(&config, &mut diagnostics);
+                check_templates(&config, base_dir, &mut diagnostics);
+            }
+            Err(e) => diagnostics.push(Self::parse_error(content, &e)),
+        }
+
+        diagnostics.sort_by_key(|diagnostic| !diagnostic.is_error());
+        diagnostics
+    }
+
+    /// Turn a TOML or deserialization error into a diagnostic naming the key and line
+    pub(crate) fn parse_error(content: &str, error: &toml::de::Error) -> ConfigDiagnostic {
+        let message = error.message().trim().to_string();
+        let Some(span) = error.span() else {
+            return ConfigDiagnostic::error("", message);
+        };
+
+        let line = content[..span.start.min(content.len())]
+            .matches('\n')
+            .count();
+        let key = key_on_line(content, line).unwrap_or_default();
+        let hint = suggest_variant(content, &span, &message)
+            .map(|suggestion| format!("did you mean `{}`? (line {})", suggestion, line + 1))
+            .unwrap_or_else(|| format!("line {}", line + 1));
+        ConfigDiagnostic::error(key, message).with_hint(Some(hint))
+    }
+}
+
+/// Report keys no section accepts
+fn unknown_keys(table: &toml::Table) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for (section, value) in table {
+        if !SECTIONS.contains(&section.as_str()) {
+            diagnostics.push(unknown_key(section, section, SECTIONS));
+            continue;
+        }
+        let Some(entries) = value.as_table() else {
+            continue;
+        };
+        if section == "filters" {
+            for (name, filter) in entries {
+                for key in filter.as_table().into_iter().flat_map(|t| t.keys()) {
+                    if !FILTER_KEYS.contains(&key.as_str()) {
+                        let path = format!("filters.{}.{}", name, key);
+                        diagnostics.push(unknown_key(&path, key, FILTER_KEYS));
+                    }
+                }
+            }
+        } else if let Some(known) = section_keys(section) {
+            for key in entries.keys() {
+                if !known.contains(&key.as_str()) {
+                    let path = format!("{}.{}", section, key);
+                    diagnostics.push(unknown_key(&path, key, known));
+                }
+            }
+        }
+    }
+    diagnostics
+}
+
+/// Warning for an unknown key, suggesting the closest known one
+fn unknown_key(path: &str, key: &str, known: &[&str]) -> ConfigDiagnostic {
+    let known: Vec<String> = known.iter().map(|k| k.to_string()).collect();
+    let hint = match find_closest_matches(key, &known, 3).first() {
+        Some(suggestion) => format!("did you mean `{}`?", suggestion),
+        None => format!("known keys: {}", known.join(", ")),
+    };
+    ConfigDiagnostic::warning(path, "unknown key, it is ignored").with_hint(Some(hint))
+}
+
+/// Check that every configured directory is a usable path
+fn check_directories(config: &Config, base_dir: &Path, diagnostics: &mut Vec<ConfigDiagnostic>) {
+    let directories = &config.directories;
+    for (key, dir) in [
+        ("directories.use_case_dir", &directories.use_case_dir),
+        ("directories.test_dir", &directories.test_dir),
+        ("directories.actor_dir", &directories.actor_dir),
+        ("directories.data_dir", &directories.data_dir),
+        ("backup.directory", &config.backup.directory),
+    ] {
+        if dir.trim().is_empty() {
+            diagnostics.push(ConfigDiagnostic::error(key, "directory must not be empty"));
+        } else if dir.contains('\0') {
+            diagnostics.push(ConfigDiagnostic::error(
+                key,
+                "directory contains a NUL character",
+            ));
+        } else if base_dir.join(dir).is_file() {
+            diagnostics.push(
+                ConfigDiagnostic::error(key, format!("'{}' is a file, not a directory", dir))
+                    .with_hint(Some(
+                        "point it to a directory or remove the file".to_string(),
+                    )),
+            );
+        }
+    }
+}
+
+/// Check that `project.id_pattern` can generate IDs
+fn check_id_pattern(config: &Config, diagnostics: &mut Vec<ConfigDiagnostic>) {
+    if let Some(pattern) = &config.project.id_pattern {
+        if let Err(e) = PatternIdGenerator::validate(pattern) {
+            diagnostics.push(ConfigDiagnostic::error("project.id_pattern", e.to_string()));
+        }
+    }
+}
+
+/// Check that persona field defaults match their type and options
+fn check_persona_fields(config: &Config, diagnostics: &mut Vec<ConfigDiagnostic>) {
+    let service = PersonaService::new(&config.actor.persona_fields);
+    for (name, field) in service.fields() {
+        let Some(default) = &field.default else {
+            continue;
+        };
+        if let Err(e) = service.parse_value(name, default) {
+            diagnostics.push(ConfigDiagnostic::error(
+                format!("actor.persona_fields.{}", name),
+                format!("invalid default: {}", e),
+            ));
+        }
+    }
+}
+
+/// Check that referenced methodologies and languages are installed
+fn check_templates(config: &Config, base_dir: &Path, diagnostics: &mut Vec<ConfigDiagnostic>) {
+    let Some(templates_dir) = templates_dir(base_dir) else {
+        return;
+    };
+
+    let methodologies = MethodologyRegistry::discover_available(&templates_dir).unwrap_or_default();
+    if !methodologies.is_empty() {
+        let templates = &config.templates;
+        let mut references: Vec<(String, &str, DiagnosticLevel)> = templates
+            .methodologies
+            .iter()
+            .map(|m| {
+                (
+                    "templates.methodologies".to_string(),
+                    m.as_str(),
+                    DiagnosticLevel::Error,
+                )
+            })
+            .collect();
+        if !templates.default_methodology.is_empty() {
+            references.push((
+                "templates.default_methodology".to_string(),
+                &templates.default_methodology,
+                DiagnosticLevel::Error,
+            ));
+        }
+        for methodology in &templates.view_order {
+            references.push((
+                "templates.view_order".to_string(),
+                methodology,
+                DiagnosticLevel::Warning,
+            ));
+        }
+        for (name, filter) in &config.filters {
+            if let Some(methodology) = &filter.methodology {
+                references.push((
+                    format!("filters.{}.methodology", name),
+                    methodology,
+                    DiagnosticLevel::Warning,
+                ));
+            }
+        }
+
+        for (key, methodology, level) in references {
+            if !methodologies.iter().any(|m| m == methodology) {
+                diagnostics.push(
+                    ConfigDiagnostic {
+                        level,
+                        ..ConfigDiagnostic::error(
+                            key,
+                            format!("unknown methodology '{}'", methodology),
+                        )
+                    }
+                    .with_hint(Some(did_you_mean(methodology, &methodologies))),
+                );
+            }
+        }
+
+        if !templates.default_methodology.is_empty()
+            && methodologies.contains(&templates.default_methodology)
+            && !templates
+                .methodologies
+                .contains(&templates.default_methodology)
+        {
+            diagnostics.push(ConfigDiagnostic::warning(
+                "templates.default_methodology",
+                format!(
+                    "'{}' is not listed in templates.methodologies",
+                    templates.default_methodology
+                ),
+            ));
+        }
+    }
+
+    let generation = &config.generation;
+    if generation.test_language == "none" {
+        return;
+    }
+    let Ok(registry) = LanguageRegistry::new_dynamic(&templates_dir) else {
+        return;
+    };
+    match registry.get(&generation.test_language) {
+        None => {
+            let languages = registry.available_languages();
+            diagnostics.push(
+                ConfigDiagnostic::error(
+                    "generation.test_language",
+                    format!("unknown language '{}'", generation.test_language),
+                )
+                .with_hint(Some(did_you_mean(&generation.test_language, &languages))),
+            );
+        }
+        Some(language) => {
+            let Some(framework) = &generation.test_framework else {
+                return;
+            };
+            let variants: Vec<String> = language
+                .variants()
+                .into_iter()
+                .map(|(name, _)| name.to_string())
+                .collect();
+            if !variants.contains(framework) {
+                let hint = if variants.is_empty() {
+                    format!("{} has no framework variants", language.name())
+                } else {
+                    did_you_mean(framework, &variants)
+                };
+                diagnostics.push(
+                    ConfigDiagnostic::error(
+                        "generation.test_framework",
+                        format!("unknown framework '{}' for {}", framework, language.name()),
+                    )
+                    .with_hint(Some(hint)),
+                );
+            }
+        }
+    }
+}
+
+/// Templates the project uses: its installed template assets, or the source
+/// templates before `mucm init --finalize` copied them
+fn templates_dir(base_dir: &Path) -> Option<PathBuf> {
+    let project_templates = base_dir
+        .join(Config::CONFIG_DIR)
+        .join(Config::TEMPLATES_DIR);
+    if project_templates.is_dir() {
+        return Some(project_templates);
+    }
+    TemplateManager::find_source_templates_dir().ok()
+}
+
+/// "did you mean" hint for `input`, or the list of valid values
+fn did_you_mean(input: &str, options: &[String]) -> String {
+    match find_closest_matches(input, options, 3).first() {
+        Some(suggestion) => format!("did you mean '{}'?", suggestion),
+        None => {
+            let mut options = options.to_vec();
+            options.sort();
+            format!("available: {}", options.join(", "))
+        }
+    }
+}
+
+/// Closest variant for serde's "unknown variant `x`, expected one of `a`, `b`" errors
+fn suggest_variant(content: &str, span: &std::ops::Range<usize>, message: &str) -> Option<String> {
+    let (_, expected) = message.split_once("expected one of")?;
+    let options: Vec<String> = expected
+        .split('`')
+        .skip(1)
+        .step_by(2)
+        .map(str::to_string)
+        .collect();
+    let value = content.get(span.clone())?.trim().trim_matches('"');
+    find_closest_matches(value, &options, 3).into_iter().next()
+}
+
+/// Dotted key of the setting on `line` (0-based): the enclosing `[table]`
+/// header plus the key on that line, or the header itself
+fn key_on_line(content: &str, line: usize) -> Option<String> {
+    let mut table = String::new();
+    for (index, text) in content.lines().enumerate().take(line + 1) {
+        let text = text.trim();
+        if text.starts_with('[') {
+            table = text
+                .trim_start_matches('[')
+                .split(']')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+        } else if index == line {
+            let key = text.split('=').next().unwrap_or_default().trim();
+            if !text.contains('=') || key.is_empty() || key.starts_with('#') {
+                break;
+            }
+            let key = key.trim_matches('"');
+            return Some(if table.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", table, key)
+            });
+        }
+    }
+    (!table.is_empty()).then_some(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SavedFilter;
+    use tempfile::TempDir;
+
+    fn validate(content: &str) -> Vec<ConfigDiagnostic> {
+        let dir = TempDir::new().unwrap();
+        ConfigValidator::validate_str(content, dir.path())
+    }
+
+    #[test]
+    fn test_default_config_is_valid() {
+        let mut config = Config::default();
+        config.generation.test_framework = None;
+        config.generation.overview_group_by = Some("tags".to_string());
+        config
+            .generation
+            .snippets
+            .insert("imports".into(), "x".into());
+        config.metadata.review_interval_days = Some(30);
+        config.templates.view_order = config.templates.methodologies.clone();
+        config.filters.insert(
+            "mine".to_string(),
+            SavedFilter {
+                category: Some("auth".to_string()),
+                ..SavedFilter::default()
+            },
+        );
+        let content = toml::to_string(&config).unwrap();
+
+        assert_eq!(validate(&content), Vec::new());
+    }
+
+    #[test]
+    fn test_unknown_key_suggests_closest() {
+        let mut content = toml::to_string(&Config::default()).unwrap();
+        content = content.replace("test_language", "test_langauge");
+
+        let diagnostics = validate(&content);
+        let unknown = diagnostics
+            .iter()
+            .find(|d| d.key == "generation.test_langauge")
+            .unwrap();
+        assert_eq!(unknown.level, DiagnosticLevel::Warning);
+        assert_eq!(
+            unknown.hint.as_deref(),
+            Some("did you mean `test_language`?")
+        );
+        // The renamed key is also missing now
+        assert!(diagnostics[0].is_error());
+        assert!(diagnostics[0].message.contains("test_language"));
+    }
+
+    #[test]
+    fn test_invalid_value_names_key_and_line() {
+        let content = toml::to_string(&Config::default())
+            .unwrap()
+            .replace("badges = \"none\"", "badges = \"sheilds\"");
+
+        let diagnostics = validate(&content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key, "generation.badges");
+        assert!(diagnostics[0]
+            .hint
+            .as_deref()
+            .unwrap()
+            .starts_with("did you mean `shields`?"));
+    }
+
+    #[test]
+    fn test_unknown_methodology_and_language() {
+        let mut config = Config::default();
+        config.templates.default_methodology = "featur".to_string();
+        config.generation.test_language = "cobol".to_string();
+        let content = toml::to_string(&config).unwrap();
+
+        let diagnostics = validate(&content);
+        let methodology = diagnostics
+            .iter()
+            .find(|d| d.key == "templates.default_methodology")
+            .unwrap();
+        assert!(methodology.is_error());
+        assert_eq!(methodology.hint.as_deref(), Some("did you mean 'feature'?"));
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.key == "generation.test_language" && d.is_error()));
+    }
+
+    #[test]
+    fn test_directory_that_is_a_file() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("docs"), "").unwrap();
+        let mut config = Config::default();
+        config.directories.use_case_dir = "docs".to_string();
+        config.directories.test_dir = " ".to_string();
+        let content = toml::to_string(&config).unwrap();
+
+        let diagnostics = ConfigValidator::validate_str(&content, dir.path());
+        let keys: Vec<&str> = diagnostics.iter().map(|d| d.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec!["directories.use_case_dir", "directories.test_dir"]
+        );
+    }
+
+    #[test]
+    fn test_id_pattern() {
+        let mut config = Config::default();
+        config.project.id_strategy = crate::config::IdStrategy::Pattern;
+        config.project.id_pattern = Some("{prefix}-{category}".to_string());
+        let content = toml::to_string(&config).unwrap();
+
+        let diagnostics = validate(&content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key, "project.id_pattern");
+        assert!(diagnostics[0].message.contains("{seq}"));
+    }
+
+    #[test]
+    fn test_persona_field_defaults() {
+        let content = format!(
+            "{}\n[actor.persona_fields]\nage = {{ type = \"number\", default = \"old\" }}\n",
+            toml::to_string(&Config::default()).unwrap()
+        );
+
+        let diagnostics = validate(&content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].key, "actor.persona_fields.age");
+        assert!(diagnostics[0].message.contains("expects a number"));
+    }
+
+    #[test]
+    fn test_key_on_line() {
+        let content = "[project]\nname = \"x\"\n\n[generation]\nbadges = 1\n";
+        assert_eq!(key_on_line(content, 1).as_deref(), Some("project.name"));
+        assert_eq!(key_on_line(content, 3).as_deref(), Some("generation"));
+        assert_eq!(
+            key_on_line(content, 4).as_deref(),
+            Some("generation.badges")
+        );
+    }
+}
@@ -10,16 +10,16 @@
 //! - Persona creation with Sommerville-aligned custom fields
 //! - System actor creation with emojis (Database, API, etc.)
 //! - Standard actor initialization
-//! - Actor updating (name, emoji, and custom fields)
+//! - Actor updating (name, emoji, parent, and custom fields)
 //! - Actor listing and retrieval
 //! - Actor deletion
 //! - Data retrieval for interactive selection prompts
 
-use crate::config::Config;
+use crate::config::{Config, PersonaFieldConfig};
 use crate::controller::dto::DisplayResult;
 use crate::core::{
-    ActorEntity, ActorRepository, ActorType, Persona, PersonaRepository, SqliteActorRepository,
-    TomlActorRepository,
+    ActorEntity, ActorHierarchy, ActorRepository, ActorType, Persona, PersonaRepository,
+    PersonaService, SqliteActorRepository, TomlActorRepository,
 };
 use anyhow::{Context, Result};
 use std::collections::HashMap;
@@ -74,8 +74,8 @@ impl ActorController {
 
                 (Box::new(actor_repo), Box::new(persona_repo))
             }
-            crate::config::StorageBackend::Toml => {
-                // For TOML, create two separate instances with the same config
+            crate::config::StorageBackend::Toml | crate::config::StorageBackend::Events => {
+                // For TOML (also used for actors by the event log), create two separate instances with the same config
                 let actor_repo = TomlActorRepository::new(config.clone());
                 let persona_repo = TomlActorRepository::new(config.clone());
                 (Box::new(actor_repo), Box::new(persona_repo))
@@ -92,7 +92,9 @@ impl ActorController {
     /// Create a new persona with basic information.
     ///
     /// Creates a persona with an ID and name, initializing custom fields
-    /// based on the project's persona configuration.
+    /// based on the project's persona configuration. Fails when a required
+    /// persona field has no default; use `create_persona_with_fields` to give
+    /// values.
     ///
     /// # Arguments
     /// * `id` - Unique identifier for the persona (e.g., "customer", "admin")
@@ -108,6 +110,33 @@ impl ActorController {
         id: String,
         name: String,
         function: String,
+    ) -> Result<DisplayResult> {
+        self.create_persona_with_fields(id, name, function, HashMap::new())
+    }
+
+    /// Create a new persona with values for its custom fields.
+    ///
+    /// Values are given as text and parsed by the field types of
+    /// `[actor.persona_fields]`; fields without a value get their default.
+    ///
+    /// # Arguments
+    /// * `id` - Unique identifier for the persona
+    /// * `name` - Display name for the persona
+    /// * `function` - Role of the persona
+    /// * `fields` - Map of field names to values (as strings)
+    ///
+    /// # Returns
+    /// DisplayResult with success message, or an error result for an invalid
+    /// ID, an existing ID, or field values that do not match the schema
+    ///
+    /// # Errors
+    /// Returns error if persona creation fails
+    pub fn create_persona_with_fields(
+        &self,
+        id: String,
+        name: String,
+        function: String,
+        fields: HashMap<String, String>,
     ) -> Result<DisplayResult> {
         // Validate ID format
         if let Err(e) = ActorEntity::validate_id(&id) {
@@ -123,12 +152,12 @@ impl ActorController {
         }
 
         // Create persona with config fields
-        let persona = Persona::from_config_fields(
-            id.clone(),
-            name,
-            function,
-            &self.config.actor.persona_fields,
-        );
+        let fields =
+            match PersonaService::new(&self.config.actor.persona_fields).initial_fields(&fields) {
+                Ok(fields) => fields,
+                Err(e) => return Ok(DisplayResult::error(e.to_string())),
+            };
+        let persona = Persona::with_fields(id.clone(), name, function, fields);
 
         // Save the persona
         self.persona_repository.save(&persona)?;
@@ -275,6 +304,48 @@ impl ActorController {
         )))
     }
 
+    /// Set or clear the parent of an actor.
+    ///
+    /// Parents form the actor hierarchy (e.g. department → role) that actor
+    /// pages and the overview roll use case counts up over.
+    ///
+    /// # Arguments
+    /// * `id` - The actor ID to update
+    /// * `parent` - ID of the new parent actor, or None to clear it
+    ///
+    /// # Returns
+    /// DisplayResult with success message, or an error message when the
+    /// parent doesn't exist or would create a cycle
+    ///
+    /// # Errors
+    /// Returns error if loading or saving actors fails
+    pub fn set_actor_parent(&self, id: String, parent: Option<String>) -> Result<DisplayResult> {
+        let actors = self.actor_repository.load_all_actors()?;
+        let hierarchy = ActorHierarchy::new(&actors);
+        let Some(actor) = hierarchy.get(&id) else {
+            return Ok(DisplayResult::error(format!("Actor '{}' not found", id)));
+        };
+        if let Some(parent) = &parent {
+            if let Err(e) = hierarchy.check_parent(&id, parent) {
+                return Ok(DisplayResult::error(e.to_string()));
+            }
+        }
+
+        let mut actor = actor.clone();
+        actor.set_parent(parent.clone());
+        actor.metadata.touch();
+        self.actor_repository.save_actor(&actor)?;
+
+        let message = match parent {
+            Some(parent) => format!("✅ '{}' is now under '{}'", id, parent),
+            None => format!("✅ '{}' is now a top-level actor", id),
+        };
+        Ok(DisplayResult::success(format!(
+            "{}\n💡 Run `mucm regenerate` to update actor pages and the overview",
+            message
+        )))
+    }
+
     /// Update an actor's name (works for all actor types).
     ///
     /// Updates the actor's display name. This works for any actor type
@@ -345,14 +416,16 @@ impl ActorController {
     /// Update persona custom fields.
     ///
     /// Updates or adds custom fields to a persona. Fields are merged with
-    /// existing values - only specified fields are updated.
+    /// existing values - only specified fields are updated. Values are parsed
+    /// by the field types of `[actor.persona_fields]`.
     ///
     /// # Arguments
     /// * `id` - The persona ID to update
     /// * `fields` - Map of field names to new values (as strings)
     ///
     /// # Returns
-    /// DisplayResult with success message
+    /// DisplayResult with success message, or an error result for values
+    /// that do not match the schema or leave a required field empty
     ///
     /// # Errors
     /// Returns error if persona not found or update fails
@@ -368,28 +441,15 @@ impl ActorController {
             .context(format!("Persona '{}' not found", id))?;
 
         // Update custom fields
+        let service = PersonaService::new(&self.config.actor.persona_fields);
         for (field_name, field_value) in fields {
-            // Parse field value as JSON to support different types
-            let json_value: serde_json::Value = if field_value.contains('\n') {
-                // Array format (newline-separated)
-                let items: Vec<String> = field_value
-                    .split('\n')
-                    .map(|s| s.to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                serde_json::json!(items)
-            } else if let Ok(num) = field_value.parse::<f64>() {
-                // Number
-                serde_json::json!(num)
-            } else if field_value == "true" || field_value == "false" {
-                // Boolean
-                serde_json::json!(field_value.parse::<bool>().unwrap())
-            } else {
-                // String
-                serde_json::json!(field_value)
+            match service.parse_value(&field_name, &field_value) {
+                Ok(value) => persona.extra.insert(field_name, value),
+                Err(e) => return Ok(DisplayResult::error(e.to_string())),
             };
-
-            persona.extra.insert(field_name, json_value);
+        }
+        if let Err(e) = service.check_required(&persona.extra) {
+            return Ok(DisplayResult::error(e.to_string()));
         }
 
         // Save updated persona
@@ -497,7 +557,7 @@ impl ActorController {
     /// # Errors
     /// Returns error if persona retrieval fails
     pub fn list_personas(&self) -> Result<Vec<Persona>> {
-        self.persona_repository.load_all()
+        Ok(self.persona_repository.load_all()?)
     }
 
     /// List all actors in the project.
@@ -561,7 +621,7 @@ impl ActorController {
     ///
     /// # Returns
     /// Map of field name to field configuration
-    pub fn get_persona_field_config(&self) -> HashMap<String, crate::core::CustomFieldConfig> {
+    pub fn get_persona_field_config(&self) -> HashMap<String, PersonaFieldConfig> {
         self.config.actor.persona_fields.clone()
     }
 
@@ -634,7 +694,10 @@ last_updated = true
 
 [actor.persona_fields]
 department = { type = "string", required = false }
-experience_level = { type = "string", required = false }
+experience_level = { type = "string", required = false, options = ["Junior", "Senior"] }
+years_experience = { type = "number" }
+is_manager = { type = "boolean" }
+skills = { type = "array" }
 "#;
 
         // Create .config/.mucm directory structure (where config is expected)
@@ -794,7 +857,45 @@ experience_level = { type = "string", required = false }
             persona.extra.get("is_manager"),
             Some(&serde_json::Value::Bool(true))
         );
-        assert!(persona.extra.get("skills").is_some());
+        assert_eq!(
+            persona.extra.get("skills"),
+            Some(&serde_json::json!([
+                "communication",
+                "leadership",
+                "problem-solving"
+            ]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_persona_fields_checks_the_schema() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        create_test_config(&temp_dir)?;
+
+        let controller = PersonaController::new()?;
+        controller.create_persona(
+            "test_user".to_string(),
+            "Test User".to_string(),
+            "Test Role".to_string(),
+        )?;
+
+        for (field, value, error) in [
+            ("years_experience", "many", "expects a number"),
+            (
+                "experience_level",
+                "expert",
+                "must be one of Junior, Senior",
+            ),
+            ("departmen", "Sales", "did you mean 'department'"),
+        ] {
+            let fields = HashMap::from([(field.to_string(), value.to_string())]);
+            let result = controller.update_persona_fields("test_user".to_string(), fields)?;
+            assert!(!result.success);
+            assert!(result.message.contains(error), "{}", result.message);
+        }
 
         Ok(())
     }
@@ -826,6 +927,34 @@ experience_level = { type = "string", required = false }
         Ok(())
     }
 
+    #[test]
+    #[serial]
+    fn test_set_actor_parent() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        create_test_config(&temp_dir)?;
+
+        let controller = PersonaController::new()?;
+        for (id, name) in [("sales", "Sales"), ("sales-rep", "Sales Rep")] {
+            controller.create_persona(id.to_string(), name.to_string(), "Role".to_string())?;
+        }
+
+        let result = controller.set_actor_parent("sales-rep".to_string(), Some("sales".into()))?;
+        assert!(result.success);
+        assert_eq!(controller.get_actor("sales-rep")?.parent(), Some("sales"));
+
+        // A department cannot sit under its own role
+        let result = controller.set_actor_parent("sales".to_string(), Some("sales-rep".into()))?;
+        assert!(!result.success);
+        let result = controller.set_actor_parent("sales".to_string(), Some("nobody".into()))?;
+        assert!(!result.success);
+
+        let result = controller.set_actor_parent("sales-rep".to_string(), None)?;
+        assert!(result.success);
+        assert_eq!(controller.get_actor("sales-rep")?.parent(), None);
+
+        Ok(())
+    }
+
     #[test]
     #[serial]
     fn test_list_personas() -> Result<()> {
@@ -21,10 +21,14 @@
 //! committing to template copying.
 
 use anyhow::Result;
+use std::path::Path;
 
 use super::dto::{DisplayResult, MethodologyInfo, SelectionOptions};
-use crate::config::Config;
-use crate::core::{DocumentationLevel, LanguageRegistry, Methodology, MethodologyRegistry};
+use crate::config::{Config, ConfigFileManager, ConfigValidator, StorageBackend};
+use crate::core::{
+    language_details, AggregateReport, BenchReport, DemoProject, DocumentationLevel,
+    LanguageDetails, LanguageRegistry, Methodology, MethodologyRegistry, Sandbox, SandboxChange,
+};
 
 /// Controller for project initialization and management operations.
 ///
@@ -215,6 +219,7 @@ impl ProjectController {
     ///
     /// # Errors
     /// Returns error if project is already initialized or initialization fails
+    #[allow(clippy::too_many_arguments)]
     pub fn init_project(
         language: Option<String>,
         methodologies: Option<Vec<String>>,
@@ -518,12 +523,21 @@ impl ProjectController {
 
         match LanguageRegistry::discover_available(&templates_dir) {
             Ok(languages) => {
+                let registry = LanguageRegistry::new_dynamic(&templates_dir).ok();
                 for lang in languages {
                     output.push_str(&format!("  - {}\n", lang));
+                    if let Some(language) = registry.as_ref().and_then(|r| r.get(&lang)) {
+                        for (variant, description) in language.variants() {
+                            output.push_str(&format!("      {}: {}\n", variant, description));
+                        }
+                    }
                 }
                 output.push_str(
                     "\nTo initialize with a specific language: mucm init -l <language>\n",
                 );
+                output.push_str(
+                    "To use a test framework variant, set generation.test_framework in mucm.toml\n",
+                );
                 output.push_str("To add a new language manually, create a directory: .config/.mucm/handlebars/lang-<language>/\n");
             }
             Err(e) => {
@@ -542,6 +556,54 @@ impl ProjectController {
         Ok(output)
     }
 
+    /// Show the test languages outside a project: aliases, extensions, variants and templates.
+    ///
+    /// # Returns
+    /// The formatted details of every language
+    pub fn show_language_details() -> Result<String> {
+        let templates_dir = Config::get_metadata_load_dir()?;
+        let details = language_details(&templates_dir, None, |_| Vec::new())?;
+        Ok(Self::format_language_details(&details, false))
+    }
+
+    /// Format language details, with the local template and tests when `in_project`
+    pub fn format_language_details(details: &[LanguageDetails], in_project: bool) -> String {
+        let mut output = String::from("Available programming languages:\n");
+        for language in details {
+            output.push_str(&format!("\n  {}\n", language.name));
+            if !language.aliases.is_empty() {
+                output.push_str(&format!("    Aliases:   {}\n", language.aliases.join(", ")));
+            }
+            output.push_str(&format!("    Extension: .{}\n", language.file_extension));
+            for (variant, description) in &language.variants {
+                output.push_str(&format!("    Variant:   {} - {}\n", variant, description));
+            }
+            output.push_str(&format!("    Template:  {}\n", language.template.display()));
+            if !in_project {
+                continue;
+            }
+            match &language.local_template {
+                Some(path) if language.customized => {
+                    output.push_str(&format!("    Local:     {} (customized)\n", path.display()))
+                }
+                Some(path) => {
+                    output.push_str(&format!("    Local:     {} (unchanged)\n", path.display()))
+                }
+                None => output.push_str("    Local:     none\n"),
+            }
+            if language.tested_use_cases.is_empty() {
+                output.push_str("    Tests:     none generated\n");
+            } else {
+                output.push_str(&format!(
+                    "    Tests:     {} use case(s): {}\n",
+                    language.tested_use_cases.len(),
+                    language.tested_use_cases.join(", ")
+                ));
+            }
+        }
+        output
+    }
+
     /// Add methodologies to the project configuration.
     ///
     /// Updates the mucm.toml config file to include the specified methodologies.
@@ -656,4 +718,286 @@ impl ProjectController {
 
         Ok(DisplayResult::success(message))
     }
+
+    /// Build a combined report over several projects, read-only.
+    ///
+    /// # Arguments
+    /// * `projects` - Root directories of the projects to aggregate
+    /// * `output` - Optional markdown file to write; the report is returned when omitted
+    /// * `filter_name` - Saved filter, looked up in each project's own config
+    ///
+    /// # Returns
+    /// DisplayResult with the report or a summary of the written file
+    pub fn aggregate_report(
+        projects: Vec<String>,
+        output: Option<String>,
+        filter_name: Option<String>,
+    ) -> Result<DisplayResult> {
+        let roots: Vec<std::path::PathBuf> =
+            projects.iter().map(std::path::PathBuf::from).collect();
+        let report = match AggregateReport::load(&roots, filter_name.as_deref()) {
+            Ok(report) => report,
+            Err(e) => return Ok(DisplayResult::error(format!("{:#}", e))),
+        };
+
+        let markdown = report.to_markdown();
+        match output {
+            Some(path) => {
+                std::fs::write(&path, markdown)?;
+                Ok(DisplayResult::success(format!(
+                    "📊 Wrote report for {} project(s) ({} use cases, {} scenarios) to {}",
+                    report.projects.len(),
+                    report.use_case_count(),
+                    report.scenario_count(),
+                    path
+                )))
+            }
+            None => Ok(DisplayResult::success(markdown)),
+        }
+    }
+
+    /// Validate the configuration of the project in the current directory.
+    ///
+    /// # Returns
+    /// DisplayResult listing the problems; an error result when there are errors
+    pub fn validate_config() -> Result<DisplayResult> {
+        let diagnostics = match ConfigValidator::validate_dir(Path::new(".")) {
+            Ok(diagnostics) => diagnostics,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        if diagnostics.is_empty() {
+            return Ok(DisplayResult::success(format!(
+                "✅ {} is valid",
+                Config::config_path().display()
+            )));
+        }
+
+        let errors = diagnostics.iter().filter(|d| d.is_error()).count();
+        let mut message = format!(
+            "Found {} error(s) and {} warning(s) in {}:",
+            errors,
+            diagnostics.len() - errors,
+            Config::config_path().display()
+        );
+        for diagnostic in &diagnostics {
+            message.push_str(&format!(
+                "\n  {}",
+                diagnostic.to_string().replace('\n', "\n  ")
+            ));
+        }
+        if errors > 0 {
+            Ok(DisplayResult::error(message))
+        } else {
+            Ok(DisplayResult::success(message))
+        }
+    }
+
+    /// Set a config value in the project in the current directory.
+    ///
+    /// Directory settings only change the config; existing files stay where
+    /// they are (see `UseCaseController::move_directory` to move them along).
+    ///
+    /// # Arguments
+    /// * `key` - Dotted config key, e.g. "generation.autolink_ids"
+    /// * `value` - New value, as TOML or plain text
+    ///
+    /// # Returns
+    /// DisplayResult confirming the change
+    pub fn set_config(key: String, value: String) -> Result<DisplayResult> {
+        let old_config = Config::load()?;
+        if let Err(e) = ConfigFileManager::set_value(Path::new("."), &key, &value) {
+            return Ok(DisplayResult::error(e.to_string()));
+        }
+
+        let mut message = format!("✅ Set {} = {}", key, value);
+        let old_dir = match key.as_str() {
+            "directories.use_case_dir" => Some(old_config.directories.use_case_dir),
+            "directories.test_dir" => Some(old_config.directories.test_dir),
+            "directories.actor_dir" | "directories.persona_dir" => {
+                Some(old_config.directories.actor_dir)
+            }
+            "directories.data_dir" => Some(old_config.directories.data_dir),
+            _ => None,
+        };
+        let left_behind = old_dir
+            .filter(|dir| std::fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_some()));
+        if let Some(dir) = left_behind {
+            message.push_str(&format!(
+                "\n⚠️  Existing files in {} were not moved; use --migrate to move them",
+                dir
+            ));
+        }
+        Ok(DisplayResult::success(message))
+    }
+
+    /// Copy the project in the current directory into a new sandbox.
+    ///
+    /// # Returns
+    /// DisplayResult with the sandbox path
+    pub fn create_sandbox() -> Result<DisplayResult> {
+        let sandbox = match Sandbox::create(Path::new(".")) {
+            Ok(sandbox) => sandbox,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        Ok(DisplayResult::success(format!(
+            "🧪 Created sandbox of {} at:\n   {}\n\n\
+             cd there and experiment; 'mucm sandbox diff' shows the changes and\n\
+             'mucm sandbox apply' copies them back.",
+            sandbox.origin().display(),
+            sandbox.root().display()
+        )))
+    }
+
+    /// List the changes of the sandbox in the current directory.
+    ///
+    /// # Returns
+    /// DisplayResult with one line per changed file
+    pub fn sandbox_diff() -> Result<DisplayResult> {
+        let sandbox = match Sandbox::open(Path::new(".")) {
+            Ok(sandbox) => sandbox,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        let changes = sandbox.diff()?;
+        if changes.is_empty() {
+            return Ok(DisplayResult::success(format!(
+                "No changes compared to {}",
+                sandbox.origin().display()
+            )));
+        }
+        Ok(DisplayResult::success(format!(
+            "{} file(s) changed compared to {}:\n{}",
+            changes.len(),
+            sandbox.origin().display(),
+            Self::format_sandbox_changes(&changes)
+        )))
+    }
+
+    /// Copy the changes of the sandbox in the current directory back into its project.
+    ///
+    /// # Arguments
+    /// * `discard` - Remove the sandbox afterwards
+    ///
+    /// # Returns
+    /// DisplayResult listing the applied changes
+    pub fn apply_sandbox(discard: bool) -> Result<DisplayResult> {
+        let sandbox = match Sandbox::open(Path::new(".")) {
+            Ok(sandbox) => sandbox,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        let changes = sandbox.apply()?;
+        let origin = sandbox.origin().to_path_buf();
+        let mut message = if changes.is_empty() {
+            format!("No changes to apply to {}", origin.display())
+        } else {
+            format!(
+                "✅ Applied {} change(s) to {}:\n{}",
+                changes.len(),
+                origin.display(),
+                Self::format_sandbox_changes(&changes)
+            )
+        };
+        if discard {
+            sandbox.discard()?;
+            message.push_str(&format!(
+                "\n🗑️  Removed the sandbox; cd {} to continue",
+                origin.display()
+            ));
+        }
+        Ok(DisplayResult::success(message))
+    }
+
+    /// Delete the sandbox in the current directory.
+    ///
+    /// # Returns
+    /// DisplayResult confirming the removal
+    pub fn discard_sandbox() -> Result<DisplayResult> {
+        let sandbox = match Sandbox::open(Path::new(".")) {
+            Ok(sandbox) => sandbox,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        let origin = sandbox.origin().to_path_buf();
+        sandbox.discard()?;
+        Ok(DisplayResult::success(format!(
+            "🗑️  Removed the sandbox; cd {} to continue",
+            origin.display()
+        )))
+    }
+
+    fn format_sandbox_changes(changes: &[SandboxChange]) -> String {
+        changes
+            .iter()
+            .map(|change| format!("  {} {}", change.kind.marker(), change.path.display()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Create a populated example project in `directory`.
+    ///
+    /// # Arguments
+    /// * `directory` - Directory to create the project in; must not exist or be empty
+    ///
+    /// # Returns
+    /// DisplayResult describing the project and how to explore it
+    pub fn demo(directory: String) -> Result<DisplayResult> {
+        let demo = match DemoProject::create(Path::new(&directory)) {
+            Ok(demo) => demo,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        Ok(DisplayResult::success(format!(
+            "📚 Created the demo project in {}:\n\
+             - {} use cases in {} categories ({})\n\
+             - {} scenarios with steps, personas, tags and references\n\
+             - {} personas\n\
+             - {} generated Python test files\n\n\
+             Explore it with:\n\
+             cd {} && mucm list\n\
+             then try mucm status or open the generated docs/use-cases/ folder.",
+            demo.root.display(),
+            demo.use_cases,
+            demo.categories.len(),
+            demo.categories.join(", "),
+            demo.scenarios,
+            demo.personas,
+            demo.test_files,
+            demo.root.display()
+        )))
+    }
+
+    /// Measure save, load and render times on a synthetic project.
+    ///
+    /// The project is generated in a scratch directory and removed afterwards.
+    /// Inside an initialized project its settings and templates are used.
+    ///
+    /// # Arguments
+    /// * `use_case_count` - Number of synthetic use cases to generate
+    /// * `backend` - Storage backend to measure
+    ///
+    /// # Returns
+    /// DisplayResult with the timing of each phase
+    pub fn bench(use_case_count: usize, backend: String) -> Result<DisplayResult> {
+        let backend = match backend.parse::<StorageBackend>() {
+            Ok(backend) => backend,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+
+        let scratch = std::env::temp_dir().join(format!("mucm-bench-{}", std::process::id()));
+        let mut config = Config::load().unwrap_or_default();
+        config.storage.backend = backend;
+        config.directories.use_case_dir = scratch.join("use-cases").to_string_lossy().into_owned();
+        config.directories.data_dir = scratch.join("data").to_string_lossy().into_owned();
+
+        let result = BenchReport::run(&config, use_case_count);
+        let _ = std::fs::remove_dir_all(&scratch);
+        let report = result?;
+
+        let mut message = format!(
+            "⏱️  {} synthetic use cases ({} backend)\n",
+            report.use_case_count, backend
+        );
+        for (phase, duration) in &report.timings {
+            message.push_str(&format!("   {:<10} {:>10.2?}\n", phase, duration));
+        }
+        message.push_str(&format!("   {:<10} {:>10.2?}", "total", report.total()));
+        Ok(DisplayResult::success(message))
+    }
 }
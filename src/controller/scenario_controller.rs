@@ -31,10 +31,11 @@ impl ScenarioController {
     /// * `persona_id` - Optional persona to assign to this scenario
     /// * `preconditions` - Optional preconditions for the scenario
     /// * `postconditions` - Optional postconditions for the scenario
-    /// * `actors` - Optional list of actor IDs involved in the scenario
+    /// * `tags` - Optional tags (e.g. "smoke"), with or without the leading '@'
     ///
     /// # Returns
     /// DisplayResult with the scenario ID
+    #[allow(clippy::too_many_arguments)]
     pub fn create_scenario(
         &mut self,
         use_case_id: String,
@@ -44,6 +45,7 @@ impl ScenarioController {
         persona_id: Option<String>,
         preconditions: Option<Vec<String>>,
         postconditions: Option<Vec<String>>,
+        tags: Option<Vec<String>>,
     ) -> Result<DisplayResult> {
         // Parse scenario type
         let parsed_type = ScenarioType::from_str(&scenario_type)
@@ -58,6 +60,7 @@ impl ScenarioController {
             preconditions.unwrap_or_default(),
             postconditions.unwrap_or_default(),
             Vec::new(), // actors will be derived from steps
+            &tags.unwrap_or_default(),
         )?;
 
         // Assign persona if provided
@@ -161,17 +164,48 @@ impl ScenarioController {
         let mut output = format!("Scenarios for {}:\n", use_case_id);
         for scenario in scenarios {
             output.push_str(&format!(
-                "  {} | {} | {} | {} steps\n",
+                "  {} | {} | {} | {} steps",
                 scenario.id,
                 scenario.title,
                 scenario.scenario_type,
                 scenario.steps.len()
             ));
+            if !scenario.tags.is_empty() {
+                output.push_str(&format!(" | {}", format_tags(&scenario.tags)));
+            }
+            output.push('\n');
         }
 
         Ok(DisplayResult::success(output))
     }
 
+    /// Move a scenario before or after another scenario
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `scenario_id` - The ID of the scenario to move
+    /// * `target_id` - The ID of the scenario to move next to
+    /// * `before` - Place the scenario before the target instead of after it
+    ///
+    /// # Returns
+    /// DisplayResult indicating success
+    pub fn move_scenario(
+        &mut self,
+        use_case_id: String,
+        scenario_id: String,
+        target_id: String,
+        before: bool,
+    ) -> Result<DisplayResult> {
+        self.app_service
+            .move_scenario(&use_case_id, &scenario_id, &target_id, before)?;
+
+        let position = if before { "before" } else { "after" };
+        Ok(DisplayResult::success(format!(
+            "✅ Moved scenario {} {} {}",
+            scenario_id, position, target_id
+        )))
+    }
+
     /// Get all scenarios for a use case (for programmatic use)
     ///
     /// # Arguments
@@ -180,7 +214,7 @@ impl ScenarioController {
     /// # Returns
     /// Vector of scenarios
     pub fn get_scenarios(&mut self, use_case_id: &str) -> Result<Vec<crate::core::Scenario>> {
-        self.app_service.get_scenarios(use_case_id)
+        Ok(self.app_service.get_scenarios(use_case_id)?)
     }
 
     /// Get details of a specific scenario
@@ -383,6 +417,58 @@ impl ScenarioController {
         )))
     }
 
+    /// Add tags to a scenario
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `scenario_id` - The ID of the scenario
+    /// * `tags` - Tags to add, with or without the leading '@'
+    ///
+    /// # Returns
+    /// DisplayResult with the scenario's tags
+    pub fn tag_scenario(
+        &mut self,
+        use_case_id: String,
+        scenario_id: String,
+        tags: Vec<String>,
+    ) -> Result<DisplayResult> {
+        let tags = self
+            .app_service
+            .add_scenario_tags(&use_case_id, &scenario_id, &tags)?;
+
+        Ok(DisplayResult::success(format!(
+            "✅ Tagged scenario {}: {}",
+            scenario_id,
+            format_tags(&tags)
+        )))
+    }
+
+    /// Remove tags from a scenario
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `scenario_id` - The ID of the scenario
+    /// * `tags` - Tags to remove, with or without the leading '@'
+    ///
+    /// # Returns
+    /// DisplayResult with the scenario's remaining tags
+    pub fn untag_scenario(
+        &mut self,
+        use_case_id: String,
+        scenario_id: String,
+        tags: Vec<String>,
+    ) -> Result<DisplayResult> {
+        let tags = self
+            .app_service
+            .remove_scenario_tags(&use_case_id, &scenario_id, &tags)?;
+
+        Ok(DisplayResult::success(format!(
+            "✅ Updated tags of scenario {}: {}",
+            scenario_id,
+            format_tags(&tags)
+        )))
+    }
+
     /// Add a reference to a scenario
     ///
     /// # Arguments
@@ -450,8 +536,9 @@ impl ScenarioController {
         use_case_id: String,
         scenario_id: String,
     ) -> Result<Vec<crate::core::ScenarioReference>> {
-        self.app_service
-            .get_scenario_references(&use_case_id, &scenario_id)
+        Ok(self
+            .app_service
+            .get_scenario_references(&use_case_id, &scenario_id)?)
     }
 
     /// Add a precondition to a scenario
@@ -469,18 +556,10 @@ impl ScenarioController {
         scenario_id: String,
         condition: String,
     ) -> Result<DisplayResult> {
-        let mut scenario = self.get_scenario(&use_case_id, &scenario_id)?;
-        scenario.add_precondition(condition.clone().into());
-
-        // Save via coordinator
-        self.app_service.edit_scenario(
-            &use_case_id,
-            &scenario_id,
-            Some(scenario.title),
-            Some(scenario.description),
-            Some(scenario.scenario_type),
-            Some(scenario.status),
-        )?;
+        self.app_service
+            .update_scenario_conditions(&use_case_id, &scenario_id, |scenario| {
+                scenario.add_precondition(condition.into())
+            })?;
 
         Ok(DisplayResult::success(format!(
             "✅ Added precondition to scenario {}",
@@ -503,18 +582,10 @@ impl ScenarioController {
         scenario_id: String,
         condition: String,
     ) -> Result<DisplayResult> {
-        let mut scenario = self.get_scenario(&use_case_id, &scenario_id)?;
-        scenario.add_postcondition(condition.clone().into());
-
-        // Save via coordinator
-        self.app_service.edit_scenario(
-            &use_case_id,
-            &scenario_id,
-            Some(scenario.title),
-            Some(scenario.description),
-            Some(scenario.scenario_type),
-            Some(scenario.status),
-        )?;
+        self.app_service
+            .update_scenario_conditions(&use_case_id, &scenario_id, |scenario| {
+                scenario.add_postcondition(condition.into())
+            })?;
 
         Ok(DisplayResult::success(format!(
             "✅ Added postcondition to scenario {}",
@@ -537,18 +608,10 @@ impl ScenarioController {
         scenario_id: String,
         condition: String,
     ) -> Result<DisplayResult> {
-        let mut scenario = self.get_scenario(&use_case_id, &scenario_id)?;
-        scenario.remove_precondition(&condition);
-
-        // Save via coordinator
-        self.app_service.edit_scenario(
-            &use_case_id,
-            &scenario_id,
-            Some(scenario.title),
-            Some(scenario.description),
-            Some(scenario.scenario_type),
-            Some(scenario.status),
-        )?;
+        self.app_service
+            .update_scenario_conditions(&use_case_id, &scenario_id, |scenario| {
+                scenario.remove_precondition(&condition)
+            })?;
 
         Ok(DisplayResult::success(format!(
             "✅ Removed precondition from scenario {}",
@@ -571,18 +634,10 @@ impl ScenarioController {
         scenario_id: String,
         condition: String,
     ) -> Result<DisplayResult> {
-        let mut scenario = self.get_scenario(&use_case_id, &scenario_id)?;
-        scenario.remove_postcondition(&condition);
-
-        // Save via coordinator
-        self.app_service.edit_scenario(
-            &use_case_id,
-            &scenario_id,
-            Some(scenario.title),
-            Some(scenario.description),
-            Some(scenario.scenario_type),
-            Some(scenario.status),
-        )?;
+        self.app_service
+            .update_scenario_conditions(&use_case_id, &scenario_id, |scenario| {
+                scenario.remove_postcondition(&condition)
+            })?;
 
         Ok(DisplayResult::success(format!(
             "✅ Removed postcondition from scenario {}",
@@ -590,6 +645,62 @@ impl ScenarioController {
         )))
     }
 
+    /// Make a scenario inherit (or stop inheriting) the use case conditions
+    ///
+    /// Inherited conditions are rendered before the scenario's own ones,
+    /// without duplicates, so they need not be copied into every scenario.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `scenario_id` - The ID of the scenario
+    /// * `conditions` - Which conditions: "pre", "post" or "both"
+    /// * `inherit` - Whether to inherit them
+    ///
+    /// # Returns
+    /// DisplayResult indicating success
+    pub fn inherit_conditions(
+        &mut self,
+        use_case_id: String,
+        scenario_id: String,
+        conditions: String,
+        inherit: bool,
+    ) -> Result<DisplayResult> {
+        let (pre, post, label) = match conditions.to_lowercase().as_str() {
+            "pre" | "preconditions" => (true, false, "preconditions"),
+            "post" | "postconditions" => (false, true, "postconditions"),
+            "both" | "all" => (true, true, "preconditions and postconditions"),
+            _ => {
+                return Ok(DisplayResult::error(format!(
+                    "Invalid conditions '{}'. Use pre, post or both",
+                    conditions
+                )))
+            }
+        };
+
+        self.app_service
+            .update_scenario_conditions(&use_case_id, &scenario_id, |scenario| {
+                if pre {
+                    scenario.inherit_preconditions = inherit;
+                }
+                if post {
+                    scenario.inherit_postconditions = inherit;
+                }
+            })?;
+
+        let message = if inherit {
+            format!(
+                "✅ Scenario {} now inherits the {} of {}",
+                scenario_id, label, use_case_id
+            )
+        } else {
+            format!(
+                "✅ Scenario {} no longer inherits the {} of {}",
+                scenario_id, label, use_case_id
+            )
+        };
+        Ok(DisplayResult::success(message))
+    }
+
     /// Get available actors (personas + system actors) for selection
     ///
     /// # Returns
@@ -643,6 +754,17 @@ impl ScenarioController {
     }
 }
 
+/// Tags as written in Gherkin, e.g. "@smoke @regression"
+fn format_tags(tags: &[String]) -> String {
+    if tags.is_empty() {
+        return "(none)".to_string();
+    }
+    tags.iter()
+        .map(|tag| format!("@{}", tag))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -706,6 +828,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -732,6 +855,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -760,6 +884,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -801,6 +926,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -824,4 +950,62 @@ mod tests {
         assert!(result.is_success());
         assert!(result.message.contains("Removed step"));
     }
+
+    #[test]
+    #[serial]
+    fn test_scenario_conditions_and_inheritance_are_saved() {
+        let (_temp_dir, mut controller) = setup_test_env();
+        let use_case_id = create_test_use_case(&mut controller);
+
+        // Reload the controller to pick up the newly created use case
+        let mut controller = ScenarioController::new().unwrap();
+
+        controller
+            .create_scenario(
+                use_case_id.clone(),
+                "Test Scenario".to_string(),
+                "main".to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let scenarios = controller.app_service.get_scenarios(&use_case_id).unwrap();
+        let scenario_id = scenarios[0].id.clone();
+
+        controller
+            .add_precondition(
+                use_case_id.clone(),
+                scenario_id.clone(),
+                "Cart is not empty".to_string(),
+            )
+            .unwrap();
+        let result = controller
+            .inherit_conditions(
+                use_case_id.clone(),
+                scenario_id.clone(),
+                "pre".to_string(),
+                true,
+            )
+            .unwrap();
+        assert!(result.is_success());
+        let result = controller
+            .inherit_conditions(
+                use_case_id.clone(),
+                scenario_id.clone(),
+                "sideways".to_string(),
+                true,
+            )
+            .unwrap();
+        assert!(!result.is_success());
+
+        // Reload to read what was saved
+        let mut controller = ScenarioController::new().unwrap();
+        let scenario = controller.get_scenario(&use_case_id, &scenario_id).unwrap();
+        assert_eq!(scenario.preconditions[0].text, "Cart is not empty");
+        assert!(scenario.inherit_preconditions);
+        assert!(!scenario.inherit_postconditions);
+    }
 }
@@ -1627,7 +1627,7 @@ mod project_controller_tests {
         assert!(
             lang_names
                 .iter()
-                .any(|&&ref l| l == "rust" || l == "python" || l == "javascript"),
+                .any(|&l| l == "rust" || l == "python" || l == "javascript"),
             "Should contain at least one common language"
         );
     }
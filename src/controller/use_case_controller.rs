@@ -22,10 +22,14 @@
 
 use crate::config::Config;
 use crate::controller::dto::{DisplayResult, SelectionOptions};
+use crate::controller::ProjectController;
 use crate::core::{
-    ReferenceType, ScenarioReference, ScenarioType, Status, UseCase, UseCaseCoordinator,
+    BatchOperation, ConflictResolution, EstimateRequest, ExecutionPlan, Explanation, ImportReport,
+    MergeChoice, MergeConflict, PlannedMutation, ReferenceType, ReleaseNoteKind, RepairOutcome,
+    ScenarioReference, ScenarioType, Status, TemplateDraft, TemplateFile, TemplatePreview,
+    TestFileAction, UseCase, UseCaseCoordinator,
 };
-use crate::presentation::{StatusFormatter, UseCaseFormatter};
+use crate::presentation::{Progress, StatusFormatter, UseCaseFormatter};
 use anyhow::Result;
 
 /// Controller for use case operations and management.
@@ -38,6 +42,15 @@ pub struct UseCaseController {
     app_service: UseCaseCoordinator,
 }
 
+impl Drop for UseCaseController {
+    /// Print the warnings the application service collected while saving
+    fn drop(&mut self) {
+        for warning in self.app_service.take_save_warnings() {
+            eprintln!("Warning: {}", warning);
+        }
+    }
+}
+
 impl UseCaseController {
     /// Create a new use case controller instance.
     ///
@@ -51,6 +64,16 @@ impl UseCaseController {
     /// Returns error if the application service cannot be loaded
     pub fn new() -> Result<Self> {
         let app_service = UseCaseCoordinator::load()?;
+        let failures = app_service.load_failures();
+        if !failures.is_empty() {
+            eprintln!(
+                "⚠️  {} data file(s) failed to load and were skipped (run 'mucm repair'):",
+                failures.len()
+            );
+            for failure in failures {
+                eprintln!("  • {}: {}", failure.path.display(), failure.error);
+            }
+        }
         Ok(Self { app_service })
     }
 
@@ -73,6 +96,7 @@ impl UseCaseController {
     ///
     /// # Errors
     /// Returns error if use case creation fails or parameters are invalid
+    #[allow(clippy::too_many_arguments)]
     pub fn create_use_case(
         &mut self,
         title: String,
@@ -99,7 +123,7 @@ impl UseCaseController {
         // All use cases now use the views-based API
         let result = if priority.is_some() || extra_fields.is_some() {
             let prio = priority.unwrap_or_else(|| "medium".to_string());
-            let fields = extra_fields.unwrap_or_else(|| std::collections::HashMap::new());
+            let fields = extra_fields.unwrap_or_default();
             self.app_service.create_use_case_with_views_and_fields(
                 title,
                 category,
@@ -145,22 +169,107 @@ impl UseCaseController {
         Ok(())
     }
 
+    /// Display the use cases as they were at a point in time.
+    ///
+    /// A plain date means the end of that day (UTC).
+    ///
+    /// # Errors
+    /// Returns error if the date is invalid or the project does not use the
+    /// event-log backend
+    pub fn list_use_cases_at(&mut self, date: &str) -> Result<()> {
+        let cutoff = parse_cutoff(date)?;
+        let use_cases = self.app_service.use_cases_at(cutoff)?;
+        UseCaseFormatter::display_list(&use_cases);
+        Ok(())
+    }
+
+    /// Undo the latest change recorded in the event log.
+    ///
+    /// # Returns
+    /// DisplayResult naming the use cases put back, or saying nothing was left to undo
+    pub fn undo(&mut self) -> Result<DisplayResult> {
+        match self.app_service.undo() {
+            Ok(Some(ids)) => Ok(DisplayResult::success(format!(
+                "✅ Undid the last change to {}",
+                ids.join(", ")
+            ))),
+            Ok(None) => Ok(DisplayResult::success("Nothing to undo".to_string())),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Display use cases whose review date has arrived.
+    ///
+    /// # Returns
+    /// Ok(()) on successful display
+    pub fn list_use_cases_needing_review(&mut self) -> Result<()> {
+        let today = chrono::Utc::now().date_naive();
+        let mut due: Vec<&UseCase> = self
+            .app_service
+            .get_all_use_cases()
+            .iter()
+            .filter(|uc| uc.metadata.is_review_due(today))
+            .collect();
+        due.sort_by_key(|uc| uc.metadata.review_by);
+        UseCaseFormatter::display_review_list(&due);
+        Ok(())
+    }
+
+    /// Display the use cases matching a saved filter.
+    ///
+    /// With `needs_review`, only those whose review date has arrived are shown.
+    ///
+    /// # Errors
+    /// Returns error if the filter does not exist or has invalid criteria
+    pub fn list_filtered_use_cases(&mut self, filter_name: &str, needs_review: bool) -> Result<()> {
+        let today = chrono::Utc::now().date_naive();
+        let use_cases: Vec<UseCase> = self
+            .app_service
+            .filter_use_cases(filter_name)?
+            .into_iter()
+            .filter(|uc| !needs_review || uc.metadata.is_review_due(today))
+            .cloned()
+            .collect();
+        UseCaseFormatter::display_list(&use_cases);
+        Ok(())
+    }
+
     /// Show project status and statistics.
     ///
     /// Displays comprehensive project status including use case counts,
-    /// categories, methodologies used, and other project metrics.
+    /// categories, methodologies used, and the health score with its
+    /// breakdown.
+    ///
+    /// # Arguments
+    /// * `json` - Print the status and health as JSON instead
     ///
     /// # Returns
     /// Ok(()) on successful display
     ///
     /// # Errors
     /// Returns error if status retrieval fails
-    pub fn show_status(&mut self) -> Result<()> {
+    pub fn show_status(&mut self, json: bool) -> Result<()> {
+        let health = self.app_service.health()?;
         let use_cases = self.app_service.get_all_use_cases();
-        StatusFormatter::display_project_status(use_cases);
+        if json {
+            let status = StatusFormatter::project_status_json(use_cases, &health);
+            println!("{}", serde_json::to_string_pretty(&status)?);
+        } else {
+            StatusFormatter::display_project_status(use_cases);
+            StatusFormatter::display_health(&health);
+        }
         Ok(())
     }
 
+    /// Show the test languages with the project's template copies and generated tests.
+    ///
+    /// # Returns
+    /// The formatted details of every language
+    pub fn show_language_details(&mut self) -> Result<String> {
+        let details = self.app_service.language_details()?;
+        Ok(ProjectController::format_language_details(&details, true))
+    }
+
     /// Get all categories currently in use.
     ///
     /// Retrieves a list of all categories that have use cases, useful for
@@ -239,6 +348,27 @@ impl UseCaseController {
         Ok(())
     }
 
+    /// Regenerate the documentation of one view of a use case.
+    ///
+    /// Rewrites only the file of the view, leaving the other views of the
+    /// use case and the overview as they are.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `view` - The view to regenerate, as `methodology:level`
+    ///
+    /// # Returns
+    /// DisplayResult naming the regenerated file
+    pub fn regenerate_view(&mut self, use_case_id: &str, view: &str) -> Result<DisplayResult> {
+        match self.app_service.regenerate_view(use_case_id, view) {
+            Ok(filename) => Ok(DisplayResult::success(format!(
+                "Regenerated {} ({})",
+                filename, view
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
     /// Regenerate markdown for all use cases.
     ///
     /// Regenerates the markdown documentation for all use cases in the project.
@@ -256,6 +386,26 @@ impl UseCaseController {
         Ok(())
     }
 
+    /// Generate named overviews, each into its own file.
+    ///
+    /// # Arguments
+    /// * `names` - Names of overview templates in `template-assets/overviews/`
+    ///
+    /// # Returns
+    /// DisplayResult listing the written files
+    pub fn regenerate_overviews(&mut self, names: Vec<String>) -> Result<DisplayResult> {
+        match self.app_service.generate_named_overviews(&names) {
+            Ok(paths) => {
+                let mut message = format!("📊 Generated {} overview(s):", paths.len());
+                for path in paths {
+                    message.push_str(&format!("\n  - {}", path.display()));
+                }
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
     /// Add a precondition to a use case.
     ///
     /// Adds a new precondition to the specified use case.
@@ -697,6 +847,139 @@ impl UseCaseController {
         }
     }
 
+    /// Link an architecture decision record to a use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `path` - ADR file, relative to the project root
+    ///
+    /// # Returns
+    /// DisplayResult with success message, warning when the file does not exist yet
+    pub fn add_adr(&mut self, use_case_id: String, path: String) -> Result<DisplayResult> {
+        match self.app_service.add_adr(&use_case_id, &path) {
+            Ok((path, true)) => Ok(DisplayResult::success(format!(
+                "Linked ADR {} to use case: {}",
+                path, use_case_id
+            ))),
+            Ok((path, false)) => Ok(DisplayResult::success(format!(
+                "Linked ADR {} to use case: {}\n⚠️  {} does not exist yet; `mucm validate` reports it until it does",
+                path, use_case_id, path
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// List the architecture decision records linked to a use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    ///
+    /// # Returns
+    /// DisplayResult with the ADR list, marking missing files
+    pub fn list_adrs(&mut self, use_case_id: String) -> Result<DisplayResult> {
+        match self.app_service.get_adrs(&use_case_id) {
+            Ok(adrs) => {
+                let mut result = format!("Architecture decisions for {}:\n", use_case_id);
+                if adrs.is_empty() {
+                    result.push_str("  No ADRs linked.");
+                }
+                for (path, exists) in &adrs {
+                    result.push_str(&format!("  - {}", path));
+                    if !exists {
+                        result.push_str(" (missing)");
+                    }
+                    result.push('\n');
+                }
+                Ok(DisplayResult::success(result))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Unlink an architecture decision record from a use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `path` - ADR file as linked
+    ///
+    /// # Returns
+    /// DisplayResult with success message
+    pub fn remove_adr(&mut self, use_case_id: String, path: String) -> Result<DisplayResult> {
+        match self.app_service.remove_adr(&use_case_id, &path) {
+            Ok(()) => Ok(DisplayResult::success(format!(
+                "Unlinked ADR {} from use case: {}",
+                path, use_case_id
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Validate the project's references, ADR links, views, and methodology fields.
+    ///
+    /// # Returns
+    /// DisplayResult listing the issues; an error result when there are any
+    pub fn validate(&self) -> Result<DisplayResult> {
+        let issues = self.app_service.validate();
+        if issues.is_empty() {
+            return Ok(DisplayResult::success(format!(
+                "✅ No problems found in {} use case(s)",
+                self.app_service.get_all_use_cases().len()
+            )));
+        }
+
+        let mut message = format!("Found {} problem(s):", issues.len());
+        for issue in &issues {
+            message.push_str(&format!("\n  {}: {}", issue.use_case_id, issue.message));
+            if let Some(hint) = &issue.hint {
+                message.push_str(&format!("\n    → {}", hint));
+            }
+        }
+        Ok(DisplayResult::error(message))
+    }
+
+    /// Reassign every scenario of a persona to another persona.
+    ///
+    /// # Arguments
+    /// * `from` - Persona the scenarios are assigned to now
+    /// * `to` - Persona to assign them to
+    /// * `category` - Only reassign in use cases of this category
+    ///
+    /// # Returns
+    /// DisplayResult listing the changed use cases
+    pub fn reassign_persona(
+        &mut self,
+        from: &str,
+        to: &str,
+        category: Option<&str>,
+    ) -> Result<DisplayResult> {
+        let changed = match self.app_service.reassign_persona(from, to, category) {
+            Ok(changed) => changed,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        if changed.is_empty() {
+            return Ok(DisplayResult::success(format!(
+                "No scenarios are assigned to persona '{}'{}",
+                from,
+                category
+                    .map(|category| format!(" in category {}", category))
+                    .unwrap_or_default()
+            )));
+        }
+
+        let total: usize = changed.iter().map(|(_, count)| count).sum();
+        let mut message = format!(
+            "✅ Reassigned {} scenario(s) in {} use case(s) from {} to {}",
+            total,
+            changed.len(),
+            from,
+            to
+        );
+        for (id, count) in &changed {
+            message.push_str(&format!("\n  • {}: {} scenario(s)", id, count));
+        }
+        Ok(DisplayResult::success(message))
+    }
+
     /// Add a scenario to a use case.
     ///
     /// Adds a new scenario to the specified use case.
@@ -735,6 +1018,7 @@ impl UseCaseController {
             vec![], // empty preconditions for now
             vec![], // empty postconditions for now
             vec![], // empty actors for now
+            &[],
         ) {
             Ok(scenario_id) => Ok(DisplayResult::success(format!(
                 "Added scenario '{}' to use case: {}",
@@ -1039,8 +1323,9 @@ impl UseCaseController {
             .app_service
             .find_scenario_id_by_title(&use_case_id, &scenario_title)?;
 
-        self.app_service
-            .get_scenario_references(&use_case_id, &scenario_id)
+        Ok(self
+            .app_service
+            .get_scenario_references(&use_case_id, &scenario_id)?)
     }
 
     /// Get all use cases that use a specific persona
@@ -1057,7 +1342,336 @@ impl UseCaseController {
         &self,
         persona_id: String,
     ) -> Result<Vec<(String, String, usize)>> {
-        self.app_service.get_use_cases_for_persona(&persona_id)
+        Ok(self.app_service.get_use_cases_for_persona(&persona_id)?)
+    }
+
+    /// Repair data files that fail to load
+    ///
+    /// Files that can be fixed are rewritten in place; the rest are moved to
+    /// the quarantine directory so the project loads cleanly.
+    ///
+    /// # Returns
+    /// DisplayResult with one line per repaired file
+    pub fn repair(&mut self) -> Result<DisplayResult> {
+        let actions = match self.app_service.repair() {
+            Ok(actions) => actions,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        if actions.is_empty() {
+            return Ok(DisplayResult::success(
+                "✅ All data files load; nothing to repair".to_string(),
+            ));
+        }
+
+        let recovered = actions
+            .iter()
+            .filter(|a| matches!(a.outcome, RepairOutcome::Recovered { .. }))
+            .count();
+        let mut message = format!(
+            "🔧 Repaired {} data file(s): {} recovered, {} quarantined\n",
+            actions.len(),
+            recovered,
+            actions.len() - recovered
+        );
+        for action in &actions {
+            match &action.outcome {
+                RepairOutcome::Recovered { use_case_id } => message.push_str(&format!(
+                    "\n  • {}: recovered {}",
+                    action.path.display(),
+                    use_case_id
+                )),
+                RepairOutcome::Quarantined { to, reason } => message.push_str(&format!(
+                    "\n  • {}: moved to {} ({})",
+                    action.path.display(),
+                    to.display(),
+                    reason
+                )),
+            }
+        }
+        Ok(DisplayResult::success(message))
+    }
+
+    /// Display the lines of use case data matching a pattern.
+    ///
+    /// # Arguments
+    /// * `pattern` - Regular expression, or literal text with `fixed_strings`
+    /// * `ignore_case` - Match case-insensitively
+    /// * `fixed_strings` - Treat the pattern as literal text
+    ///
+    /// # Errors
+    /// Returns error if the pattern is not a valid regular expression
+    pub fn grep(&self, pattern: String, ignore_case: bool, fixed_strings: bool) -> Result<()> {
+        let matches = self
+            .app_service
+            .grep(&pattern, ignore_case, fixed_strings)?;
+        UseCaseFormatter::display_grep_matches(&matches);
+        Ok(())
+    }
+
+    /// Plan a mutation without performing it
+    ///
+    /// # Arguments
+    /// * `command` - Command name recorded in the plan
+    /// * `mutation` - The mutation to plan
+    ///
+    /// # Returns
+    /// DisplayResult with the plan as JSON
+    pub fn plan(&self, command: String, mutation: PlannedMutation) -> Result<DisplayResult> {
+        Self::plan_result(self.app_service.plan(&command, &mutation))
+    }
+
+    /// Plan the creation of a use case without performing it
+    ///
+    /// Views are chosen like `create_use_case` does: `views`, else `methodology:normal`,
+    /// else the default methodology.
+    ///
+    /// # Returns
+    /// DisplayResult with the plan as JSON
+    pub fn plan_create(
+        &self,
+        category: String,
+        methodology: Option<String>,
+        views: Option<String>,
+    ) -> Result<DisplayResult> {
+        let views = views.unwrap_or_else(|| {
+            let methodology = methodology.unwrap_or_else(|| {
+                Config::load()
+                    .map(|c| c.templates.default_methodology.clone())
+                    .unwrap_or_else(|_| "feature".to_string())
+            });
+            format!("{}:normal", methodology)
+        });
+        Self::plan_result(self.app_service.plan_create("create", &category, &views))
+    }
+
+    fn plan_result(plan: crate::core::Result<ExecutionPlan>) -> Result<DisplayResult> {
+        match plan {
+            Ok(plan) => Ok(DisplayResult::success(serde_json::to_string_pretty(&plan)?)),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Explain where a use case, scenario, or generated file comes from
+    ///
+    /// # Arguments
+    /// * `target` - Use case ID, scenario ID, or file path
+    ///
+    /// # Returns
+    /// DisplayResult with the data file, views, templates, and recent changes
+    pub fn explain(&self, target: String) -> Result<DisplayResult> {
+        let explanation = match self.app_service.explain(&target) {
+            Ok(explanation) => explanation,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        let describe = |template: &TemplateFile| {
+            let origin = if template.project_override {
+                "project override"
+            } else {
+                "source template"
+            };
+            let missing = if template.path.exists() {
+                ""
+            } else {
+                ", missing"
+            };
+            format!("{} ({}{})", template.path.display(), origin, missing)
+        };
+
+        let message = match explanation {
+            Explanation::Overview(overview) => {
+                let kind = if overview.index {
+                    "overview index"
+                } else {
+                    "overview"
+                };
+                let template = overview
+                    .template
+                    .as_ref()
+                    .map(describe)
+                    .unwrap_or_else(|| "built-in default".to_string());
+                format!(
+                    "🔎 {} is the generated {}\n\n  Template: {}\n  Data: every use case",
+                    overview.file.display(),
+                    kind,
+                    template
+                )
+            }
+            Explanation::UseCase(use_case) => {
+                let mut message = format!(
+                    "🔎 {} - {} ({})\n",
+                    use_case.use_case_id, use_case.title, use_case.category
+                );
+                if let Some((id, title)) = &use_case.scenario {
+                    message.push_str(&format!("   Scenario {} - {}\n", id, title));
+                }
+                message.push_str(&format!("\n  Data: {}\n", use_case.data_file.display()));
+                for view in &use_case.views {
+                    message.push_str(&format!(
+                        "\n  View {}\n    Output:   {}\n    Template: {}\n",
+                        view.view.key(),
+                        view.output.display(),
+                        describe(&view.template)
+                    ));
+                    if let Some(partial) = &view.scenario_template {
+                        message.push_str(&format!("    Scenario: {}\n", describe(partial)));
+                    }
+                }
+                if let Some(test_file) = &use_case.test_file {
+                    message.push_str(&format!("\n  Tests: {}\n", test_file.display()));
+                }
+                message.push_str(&format!(
+                    "\n  Created {}, last updated {}",
+                    use_case.created_at.format("%Y-%m-%d %H:%M"),
+                    use_case.updated_at.format("%Y-%m-%d %H:%M")
+                ));
+                if use_case.changes.is_empty() {
+                    message.push_str("\n  No backups of the data file");
+                } else {
+                    message.push_str("\n  Changed by commands at (backups, newest first):");
+                    for snapshot in use_case.changes.iter().take(5) {
+                        message.push_str(&format!("\n    • {}", snapshot));
+                    }
+                }
+                message
+            }
+        };
+        Ok(DisplayResult::success(message))
+    }
+
+    /// Snapshot all data files now
+    ///
+    /// # Returns
+    /// DisplayResult with the snapshot name
+    pub fn backup_now(&self) -> Result<DisplayResult> {
+        match self.app_service.backup_now() {
+            Ok(snapshot) => Ok(DisplayResult::success(format!(
+                "💾 Backed up {} file(s) to {}",
+                snapshot.files,
+                snapshot.path.display()
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// List backup snapshots, newest first
+    ///
+    /// # Returns
+    /// DisplayResult with one line per snapshot
+    pub fn list_backups(&self) -> Result<DisplayResult> {
+        let snapshots = match self.app_service.list_backups() {
+            Ok(snapshots) => snapshots,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        if snapshots.is_empty() {
+            return Ok(DisplayResult::success("No backups yet".to_string()));
+        }
+        let mut message = format!("💾 {} backup(s), newest first:\n", snapshots.len());
+        for snapshot in &snapshots {
+            message.push_str(&format!(
+                "\n  • {} ({} file(s))",
+                snapshot.name, snapshot.files
+            ));
+        }
+        Ok(DisplayResult::success(message))
+    }
+
+    /// Restore data files from a backup snapshot
+    ///
+    /// # Arguments
+    /// * `snapshot` - Snapshot name
+    /// * `use_case_id` - Restore only this use case
+    ///
+    /// # Returns
+    /// DisplayResult with the restored files
+    pub fn restore_backup(
+        &mut self,
+        snapshot: String,
+        use_case_id: Option<String>,
+    ) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .restore_backup(&snapshot, use_case_id.as_deref())
+        {
+            Ok(restored) => {
+                let mut message = format!(
+                    "♻️  Restored {} file(s) from backup {}\n",
+                    restored.len(),
+                    snapshot
+                );
+                for path in &restored {
+                    message.push_str(&format!("\n  • {}", path.display()));
+                }
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// List the recorded test names of scenarios
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Only this use case's scenarios (all if None)
+    ///
+    /// # Returns
+    /// DisplayResult with one line per scenario
+    pub fn list_test_names(&self, use_case_id: Option<String>) -> Result<DisplayResult> {
+        let names = match self.app_service.test_names(use_case_id.as_deref()) {
+            Ok(names) => names,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+        if names.is_empty() {
+            return Ok(DisplayResult::success("No scenarios found".to_string()));
+        }
+        let mut message = format!("🧪 Test names of {} scenario(s):\n", names.len());
+        for (scenario_id, name) in &names {
+            match name {
+                Some(name) if name.previous.is_empty() => {
+                    message.push_str(&format!("\n  • {} → {}", scenario_id, name.symbol))
+                }
+                Some(name) => message.push_str(&format!(
+                    "\n  • {} → {} (formerly {})",
+                    scenario_id,
+                    name.symbol,
+                    name.previous.join(", ")
+                )),
+                None => {
+                    message.push_str(&format!("\n  • {} → (no test generated yet)", scenario_id))
+                }
+            }
+        }
+        Ok(DisplayResult::success(message))
+    }
+
+    /// Rename the generated test of a scenario
+    ///
+    /// # Arguments
+    /// * `scenario_id` - Scenario whose test is renamed
+    /// * `name` - New test name
+    ///
+    /// # Returns
+    /// DisplayResult describing the rename
+    pub fn rename_test(&mut self, scenario_id: String, name: String) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .rename_scenario_test(&scenario_id, name.clone())
+        {
+            Ok((old, test_file, true)) => Ok(DisplayResult::success(format!(
+                "✅ Renamed test of {} from {} to {}; regenerated {} with a deprecation shim for the old name",
+                scenario_id,
+                old,
+                name,
+                test_file.display()
+            ))),
+            Ok((old, test_file, false)) => Ok(DisplayResult::success(format!(
+                "✅ Renamed test of {} from {} to {} in the mapping; {} was kept, rename the function there \
+                 (or enable generation.overwrite_test_documentation and regenerate)",
+                scenario_id,
+                old,
+                name,
+                test_file.display()
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
     }
 
     /// Clean up orphaned methodology fields from use cases
@@ -1130,9 +1744,942 @@ impl UseCaseController {
         }
     }
 
-    // ========== Update Operations ==========
-
-    /// Update basic use case information
+    /// Regenerate the JSON manifest (`mucm-manifest.json`)
+    ///
+    /// # Returns
+    /// DisplayResult with the path of the written manifest
+    pub fn generate_manifest(&mut self) -> Result<DisplayResult> {
+        match self.app_service.generate_manifest() {
+            Ok(path) => Ok(DisplayResult::success(format!(
+                "Generated manifest at: {}",
+                path.display()
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Export the documentation in another format
+    ///
+    /// # Arguments
+    /// * `format` - Output format: "html" or "pdf"
+    /// * `output` - Where to write, relative to the project root: the directory
+    ///   of the pages ("site" by default) or the PDF file ("use-cases.pdf", or
+    ///   "<ID>.pdf" for a single use case, by default)
+    /// * `use_case_id` - Use case to put in the PDF; all use cases when None
+    /// * `tag` - Only export the scenarios with this tag and their use cases
+    ///
+    /// # Returns
+    /// DisplayResult with what was written and where
+    pub fn export_docs(
+        &mut self,
+        format: String,
+        output: Option<String>,
+        use_case_id: Option<String>,
+        tag: Option<String>,
+    ) -> Result<DisplayResult> {
+        let format = format.to_lowercase();
+        if tag.is_some() && matches!(format.as_str(), "html" | "mdbook") {
+            return Ok(DisplayResult::error(format!(
+                "The {} export renders the whole documentation; --tag is supported with pdf, json, csv, xlsx and reqif",
+                format
+            )));
+        }
+        let tag = tag.as_deref();
+        match format.as_str() {
+            "html" => {
+                if use_case_id.is_some() {
+                    return Ok(DisplayResult::error(
+                        "The HTML export covers all use cases; a use case ID is only supported with --format pdf"
+                            .to_string(),
+                    ));
+                }
+                let output = output.unwrap_or_else(|| "site".to_string());
+                match self.app_service.export_html(&output) {
+                    Ok((dir, pages)) => Ok(DisplayResult::success(format!(
+                        "✅ Exported {} HTML page(s) to {} (start at {})",
+                        pages,
+                        dir.display(),
+                        dir.join("index.html").display()
+                    ))),
+                    Err(e) => Ok(DisplayResult::error(e.to_string())),
+                }
+            }
+            "pdf" => {
+                let output = output.unwrap_or_else(|| match &use_case_id {
+                    Some(id) => format!("{}.pdf", id),
+                    None => "use-cases.pdf".to_string(),
+                });
+                match self.app_service.export_pdf(use_case_id.as_deref(), &output, tag) {
+                    Ok((path, count)) => Ok(DisplayResult::success(format!(
+                        "✅ Exported {} use case(s) to {}",
+                        count,
+                        path.display()
+                    ))),
+                    Err(e) => Ok(DisplayResult::error(e.to_string())),
+                }
+            }
+            "csv" => {
+                if use_case_id.is_some() {
+                    return Ok(DisplayResult::error(
+                        "The CSV matrix covers all use cases; a use case ID is only supported with --format pdf"
+                            .to_string(),
+                    ));
+                }
+                let output = output.unwrap_or_else(|| "use-cases.csv".to_string());
+                match self.app_service.export_csv(&output, tag) {
+                    Ok((path, count)) => Ok(DisplayResult::success(format!(
+                        "✅ Exported {} use case(s) to {}",
+                        count,
+                        path.display()
+                    ))),
+                    Err(e) => Ok(DisplayResult::error(e.to_string())),
+                }
+            }
+            "xlsx" => {
+                if use_case_id.is_some() {
+                    return Ok(DisplayResult::error(
+                        "The workbook covers all use cases; a use case ID is only supported with --format pdf"
+                            .to_string(),
+                    ));
+                }
+                let output = output.unwrap_or_else(|| "use-cases.xlsx".to_string());
+                match self.app_service.export_xlsx(&output, tag) {
+                    Ok((path, count)) => Ok(DisplayResult::success(format!(
+                        "✅ Exported {} use case(s) to {}",
+                        count,
+                        path.display()
+                    ))),
+                    Err(e) => Ok(DisplayResult::error(e.to_string())),
+                }
+            }
+            "json" => {
+                if use_case_id.is_some() {
+                    return Ok(DisplayResult::error(
+                        "The JSON export covers the whole model; a use case ID is only supported with --format pdf"
+                            .to_string(),
+                    ));
+                }
+                let output = output.unwrap_or_else(|| "use-cases.json".to_string());
+                match self.app_service.export_json(&output, tag) {
+                    Ok((path, count)) => Ok(DisplayResult::success(format!(
+                        "✅ Exported {} use case(s) to {}",
+                        count,
+                        path.display()
+                    ))),
+                    Err(e) => Ok(DisplayResult::error(e.to_string())),
+                }
+            }
+            "mdbook" => {
+                if use_case_id.is_some() {
+                    return Ok(DisplayResult::error(
+                        "The book covers all use cases; a use case ID is only supported with --format pdf"
+                            .to_string(),
+                    ));
+                }
+                match self.app_service.export_mdbook(output.as_deref()) {
+                    Ok((dir, count, created)) => {
+                        let mut message = format!(
+                            "✅ Wrote the mdBook summary of {} use case(s); serve it with `mdbook serve {}`",
+                            count,
+                            dir.display()
+                        );
+                        if created {
+                            message.push_str(&format!(
+                                "\n   Created {}",
+                                dir.join("book.toml").display()
+                            ));
+                        }
+                        Ok(DisplayResult::success(message))
+                    }
+                    Err(e) => Ok(DisplayResult::error(e.to_string())),
+                }
+            }
+            "reqif" => {
+                if use_case_id.is_some() {
+                    return Ok(DisplayResult::error(
+                        "The ReqIF export covers all use cases; a use case ID is only supported with --format pdf"
+                            .to_string(),
+                    ));
+                }
+                let output = output.unwrap_or_else(|| "use-cases.reqif".to_string());
+                match self.app_service.export_reqif(&output, tag) {
+                    Ok((path, count)) => Ok(DisplayResult::success(format!(
+                        "✅ Exported {} use case(s) to {}",
+                        count,
+                        path.display()
+                    ))),
+                    Err(e) => Ok(DisplayResult::error(e.to_string())),
+                }
+            }
+            _ => Ok(DisplayResult::error(format!(
+                "Unsupported export format '{}'. Supported formats: html, pdf, json, csv, xlsx, reqif, mdbook",
+                format
+            ))),
+        }
+    }
+
+    /// Publish the documentation to Confluence
+    ///
+    /// # Returns
+    /// DisplayResult with the number of pages created and updated
+    pub fn publish_confluence(&mut self) -> Result<DisplayResult> {
+        match self.app_service.publish_confluence() {
+            Ok((created, updated)) => Ok(DisplayResult::success(format!(
+                "✅ Published to Confluence: {} page(s) created, {} updated",
+                created, updated
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Synchronize the use cases with Jira issues
+    ///
+    /// # Returns
+    /// DisplayResult listing created issues, pulled statuses and new links
+    pub fn sync_jira(&mut self) -> Result<DisplayResult> {
+        match self.app_service.sync_jira() {
+            Ok(report) => {
+                let mut message = format!(
+                    "✅ Synced with Jira: {} issue(s) created, {} updated, {} status change(s), {} link(s) added",
+                    report.created.len(),
+                    report.updated.len(),
+                    report.status_changes.len(),
+                    report.linked.len()
+                );
+                for (id, key) in &report.created {
+                    message.push_str(&format!("\n  • Created {} for {}", key, id));
+                }
+                for (id, from, to) in &report.status_changes {
+                    message.push_str(&format!(
+                        "\n  • {}: {} → {}",
+                        id,
+                        from.display_name(),
+                        to.display_name()
+                    ));
+                }
+                for (from, to) in &report.linked {
+                    message.push_str(&format!("\n  • Linked {} to {}", from, to));
+                }
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Synchronize the use cases with GitHub issues
+    ///
+    /// # Arguments
+    /// * `pull_status` - If true, updates the scenario statuses from the issue states
+    ///
+    /// # Returns
+    /// DisplayResult listing created issues and pulled statuses
+    pub fn sync_github(&mut self, pull_status: bool) -> Result<DisplayResult> {
+        match self.app_service.sync_github(pull_status) {
+            Ok(report) => {
+                let mut message = format!(
+                    "✅ Synced with GitHub: {} issue(s) created, {} updated, {} status change(s)",
+                    report.created.len(),
+                    report.updated.len(),
+                    report.status_changes.len()
+                );
+                for (id, number) in &report.created {
+                    message.push_str(&format!("\n  • Created #{} for {}", number, id));
+                }
+                for (id, from, to) in &report.status_changes {
+                    message.push_str(&format!(
+                        "\n  • {}: {} → {}",
+                        id,
+                        from.display_name(),
+                        to.display_name()
+                    ));
+                }
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Reconcile generated test files with use case scenarios
+    ///
+    /// Reports scenarios without tests and tests without scenarios, optionally
+    /// scaffolding stub scenarios for unmatched tests.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Optional specific use case to check. If None, checks all use cases.
+    /// * `create_missing_scenarios` - If true, creates stub scenarios for unmatched tests
+    ///
+    /// # Returns
+    /// DisplayResult with the reconciliation report
+    pub fn reconcile_tests(
+        &mut self,
+        use_case_id: Option<String>,
+        create_missing_scenarios: bool,
+    ) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .reconcile_tests(use_case_id.as_deref(), create_missing_scenarios)
+        {
+            Ok((report, created)) => {
+                let out_of_sync: Vec<_> = report.iter().filter(|r| !r.is_in_sync()).collect();
+                let mut message = format!(
+                    "🔗 Reconciled {} use case(s): {} in sync, {} with differences\n",
+                    report.len(),
+                    report.len() - out_of_sync.len(),
+                    out_of_sync.len()
+                );
+
+                for entry in out_of_sync {
+                    message.push_str(&format!(
+                        "\n{} ({})\n",
+                        entry.use_case_id,
+                        entry.test_file.display()
+                    ));
+                    if !entry.file_exists {
+                        message.push_str("  ⚠️  Test file not found\n");
+                    }
+                    for scenario_id in &entry.scenarios_without_tests {
+                        message.push_str(&format!("  • Scenario without test: {}\n", scenario_id));
+                    }
+                    for test_name in &entry.tests_without_scenarios {
+                        message.push_str(&format!("  • Test without scenario: {}\n", test_name));
+                    }
+                }
+
+                if !created.is_empty() {
+                    message.push_str(&format!(
+                        "\n✨ Created {} stub scenario(s): {}",
+                        created.len(),
+                        created.join(", ")
+                    ));
+                } else if !create_missing_scenarios
+                    && report.iter().any(|r| !r.tests_without_scenarios.is_empty())
+                {
+                    message.push_str(
+                        "\nRun with --create-missing-scenarios to scaffold scenarios for untracked tests.",
+                    );
+                }
+
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Generate test files, optionally only where scenarios lack tests
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Optional specific use case. If None, covers all use cases.
+    /// * `only_missing` - Only generate files missing tests for some scenarios
+    /// * `force` - Also overwrite files with manual modifications
+    /// * `tag` - Generate the suite of this scenario tag instead
+    ///
+    /// # Returns
+    /// DisplayResult with a summary per use case
+    pub fn generate_tests(
+        &mut self,
+        use_case_id: Option<String>,
+        only_missing: bool,
+        force: bool,
+        tag: Option<String>,
+    ) -> Result<DisplayResult> {
+        let results = match self.app_service.generate_tests(
+            use_case_id.as_deref(),
+            only_missing,
+            force,
+            tag.as_deref(),
+        ) {
+            Ok(results) => results,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+
+        let count = |action: TestFileAction| results.iter().filter(|r| r.action == action).count();
+        let mut message = format!(
+            "🧪 Test files: {} created, {} updated, {} skipped (modified), {} up to date\n",
+            count(TestFileAction::Created),
+            count(TestFileAction::Updated),
+            count(TestFileAction::SkippedModified),
+            count(TestFileAction::UpToDate)
+        );
+        for result in &results {
+            let label = match result.action {
+                TestFileAction::Created => "created",
+                TestFileAction::Updated => "updated",
+                TestFileAction::SkippedModified => "skipped, has manual modifications",
+                TestFileAction::UpToDate => continue,
+            };
+            message.push_str(&format!(
+                "\n{} ({}): {}\n",
+                result.use_case_id,
+                result.test_file.display(),
+                label
+            ));
+            if result.action != TestFileAction::Created {
+                for scenario_id in &result.new_scenarios {
+                    message.push_str(&format!("  • Scenario without test: {}\n", scenario_id));
+                }
+            }
+        }
+        if count(TestFileAction::SkippedModified) > 0 {
+            message.push_str("\nRun with --force to overwrite files with manual modifications.");
+        }
+
+        Ok(DisplayResult::success(message))
+    }
+
+    // ========== Import Operations ==========
+
+    /// Import use cases from TOML data files.
+    ///
+    /// # Arguments
+    /// * `path` - Data file or directory to import from
+    /// * `resolve` - Called for every incoming ID that already exists
+    ///
+    /// # Returns
+    /// DisplayResult summarizing what was imported and how conflicts were resolved
+    pub fn import_use_cases<F>(&mut self, path: &str, mut resolve: F) -> Result<DisplayResult>
+    where
+        F: FnMut(&UseCase, &UseCase) -> Result<ConflictResolution>,
+    {
+        let result = self
+            .app_service
+            .import_use_cases(std::path::Path::new(path), |existing, incoming| {
+                resolve(existing, incoming).map_err(prompt_error)
+            });
+        Ok(import_result(result, path))
+    }
+
+    /// Import use cases from a JSON export document.
+    ///
+    /// # Arguments
+    /// * `path` - Document written by `mucm export --format json`
+    /// * `resolve` - Called for every incoming ID that already exists
+    ///
+    /// # Returns
+    /// DisplayResult summarizing what was imported and how conflicts were resolved
+    pub fn import_json_use_cases<F>(&mut self, path: &str, mut resolve: F) -> Result<DisplayResult>
+    where
+        F: FnMut(&UseCase, &UseCase) -> Result<ConflictResolution>,
+    {
+        let result = self
+            .app_service
+            .import_json_use_cases(std::path::Path::new(path), |existing, incoming| {
+                resolve(existing, incoming).map_err(prompt_error)
+            });
+        Ok(import_result(result, path))
+    }
+
+    /// Apply the create, update and delete operations of a batch file
+    ///
+    /// # Arguments
+    /// * `path` - TOML or JSON batch file
+    ///
+    /// # Returns
+    /// DisplayResult listing what each operation did; an error if any failed
+    pub fn run_batch(&mut self, path: &str) -> Result<DisplayResult> {
+        let operations = match BatchOperation::load(std::path::Path::new(path)) {
+            Ok(operations) => operations,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+
+        let progress = Progress::new(operations.len() as u64, "Applying");
+        let result = self
+            .app_service
+            .execute_batch(&operations, |_| progress.inc());
+        progress.finish();
+        let report = match result {
+            Ok(report) => report,
+            Err(e) => return Ok(DisplayResult::error(e.to_string())),
+        };
+
+        let mut message = format!(
+            "📦 Applied {} of {} operation(s) from {}",
+            report.applied(),
+            operations.len(),
+            path
+        );
+        for id in &report.created {
+            message.push_str(&format!("\n  • Created {}", id));
+        }
+        for id in &report.updated {
+            message.push_str(&format!("\n  • Updated {}", id));
+        }
+        for id in &report.deleted {
+            message.push_str(&format!("\n  • Deleted {}", id));
+        }
+        for failure in &report.failed {
+            message.push_str(&format!(
+                "\n  • Operation {} ({}) failed: {}",
+                failure.index, failure.operation, failure.error
+            ));
+        }
+        if report.failed.is_empty() {
+            Ok(DisplayResult::success(message))
+        } else {
+            Ok(DisplayResult::error(message))
+        }
+    }
+
+    /// Import Gherkin feature files, updating use cases imported before
+    ///
+    /// # Arguments
+    /// * `path` - Feature file or directory of feature files
+    /// * `category` - Optional category for new use cases
+    ///
+    /// # Returns
+    /// DisplayResult summarizing created, updated and unchanged use cases
+    pub fn import_gherkin(&mut self, path: &str, category: Option<&str>) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .import_gherkin(std::path::Path::new(path), category)
+        {
+            Ok(report) => {
+                let mut message = format!(
+                    "📥 Imported {} feature(s) from {}: {} created, {} updated, {} unchanged",
+                    report.created.len() + report.updated.len() + report.unchanged.len(),
+                    path,
+                    report.created.len(),
+                    report.updated.len(),
+                    report.unchanged.len()
+                );
+                for id in &report.created {
+                    message.push_str(&format!("\n  • Created {}", id));
+                }
+                for (id, added, updated) in &report.updated {
+                    message.push_str(&format!(
+                        "\n  • Updated {} ({} scenario(s) added, {} updated)",
+                        id, added, updated
+                    ));
+                }
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Import hand-written use case markdown, skipping files imported before
+    ///
+    /// # Arguments
+    /// * `path` - Markdown file or directory of markdown files
+    /// * `category` - Optional category for new use cases
+    ///
+    /// # Returns
+    /// DisplayResult listing the created use cases and their source
+    pub fn import_markdown(&mut self, path: &str, category: Option<&str>) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .import_markdown(std::path::Path::new(path), category)
+        {
+            Ok(report) => {
+                let mut message = format!(
+                    "📥 Imported {} use case(s) from {}: {} created, {} skipped (imported before)",
+                    report.created.len() + report.skipped.len(),
+                    path,
+                    report.created.len(),
+                    report.skipped.len()
+                );
+                for (id, source) in &report.created {
+                    message.push_str(&format!("\n  • Created {} from {}", id, source));
+                }
+                for (source, reason) in &report.unreadable {
+                    message.push_str(&format!(
+                        "\n  ⚠️  Skipped {}: could not be read ({})",
+                        source, reason
+                    ));
+                }
+                if !report.created.is_empty() {
+                    message.push_str(
+                        "\n💡 Review the imported use cases: steps and sections are recognized on a best-effort basis",
+                    );
+                }
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Import the rows of a requirement traceability matrix, skipping rows imported before
+    ///
+    /// # Arguments
+    /// * `path` - Spreadsheet holding the matrix
+    /// * `mapping` - TOML file mapping its columns to use case fields
+    ///
+    /// # Returns
+    /// DisplayResult listing the created use cases and their row
+    pub fn import_rtm(&mut self, path: &str, mapping: &str) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .import_rtm(std::path::Path::new(path), std::path::Path::new(mapping))
+        {
+            Ok(report) => {
+                let mut message = format!(
+                    "📥 Imported {} row(s) from {}: {} created, {} skipped (imported before)",
+                    report.created.len() + report.skipped.len(),
+                    path,
+                    report.created.len(),
+                    report.skipped.len()
+                );
+                for (id, row) in &report.created {
+                    message.push_str(&format!("\n  • Created {} from row {}", id, row));
+                }
+                for (id, reference) in &report.unresolved {
+                    message.push_str(&format!(
+                        "\n  ⚠️  {}: reference '{}' matches no row or use case",
+                        id, reference
+                    ));
+                }
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    // ========== Project Operations ==========
+
+    /// Rename the project and optionally re-prefix every use case ID.
+    ///
+    /// # Arguments
+    /// * `name` - Optional new project name
+    /// * `description` - Optional new project description
+    /// * `id_prefix` - Optional new ID prefix
+    ///
+    /// # Returns
+    /// DisplayResult listing the renamed use cases
+    pub fn rename_project(
+        &mut self,
+        name: Option<String>,
+        description: Option<String>,
+        id_prefix: Option<String>,
+    ) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .rename_project(name.clone(), description, id_prefix)
+        {
+            Ok(renamed) => {
+                let mut message = match name {
+                    Some(name) => format!("✅ Renamed project to \"{}\"", name),
+                    None => "✅ Updated project settings".to_string(),
+                };
+                if !renamed.is_empty() {
+                    message.push_str(&format!("\n{} use case(s) re-prefixed:", renamed.len()));
+                    for (old_id, new_id) in &renamed {
+                        message.push_str(&format!("\n  • {} → {}", old_id, new_id));
+                    }
+                }
+                message.push_str("\n📝 Regenerated all documentation");
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Point a directory setting at a new location, moving the existing files along.
+    ///
+    /// # Arguments
+    /// * `key` - Directory setting, e.g. "directories.use_case_dir"
+    /// * `value` - New directory, relative to the project root
+    ///
+    /// # Returns
+    /// DisplayResult describing the move
+    pub fn move_directory(&mut self, key: String, value: String) -> Result<DisplayResult> {
+        match self.app_service.move_directory(&key, &value) {
+            Ok(Some((old_dir, new_dir))) => Ok(DisplayResult::success(format!(
+                "📦 Moved {} → {} ({})\n📝 Regenerated all documentation",
+                old_dir, new_dir, key
+            ))),
+            Ok(None) => Ok(DisplayResult::success(format!(
+                "{} is already {}",
+                key, value
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Report ID sequences per category, with gaps and duplicates.
+    ///
+    /// # Returns
+    /// DisplayResult listing every sequence; an error result when IDs are duplicated
+    pub fn id_report(&self) -> Result<DisplayResult> {
+        let report = self.app_service.id_report();
+        if report.sequences.is_empty() && report.unnumbered.is_empty() {
+            return Ok(DisplayResult::success("No use cases found".to_string()));
+        }
+
+        let mut message = String::from("🔢 Use case ID sequences");
+        for sequence in &report.sequences {
+            let highest = sequence.numbers.last().copied().unwrap_or(0);
+            message.push_str(&format!(
+                "\n  {} ({}): {} ID(s), highest {:03}{}",
+                sequence.key,
+                sequence.categories.join(", "),
+                sequence.numbers.len(),
+                highest,
+                if sequence.is_compact() {
+                    ", no gaps"
+                } else {
+                    ""
+                }
+            ));
+            if !sequence.gaps.is_empty() {
+                let gaps: Vec<String> = sequence.gaps.iter().map(|n| format!("{:03}", n)).collect();
+                message.push_str(&format!("\n    ⚠️  Gaps: {}", gaps.join(", ")));
+            }
+            if !sequence.duplicates.is_empty() {
+                message.push_str(&format!(
+                    "\n    ❌ Duplicates: {}",
+                    sequence.duplicates.join(", ")
+                ));
+            }
+        }
+        if !report.unnumbered.is_empty() {
+            message.push_str(&format!(
+                "\n⚠️  IDs without a number: {}",
+                report.unnumbered.join(", ")
+            ));
+        }
+        if !report.duplicate_scenarios.is_empty() {
+            message.push_str(&format!(
+                "\n❌ Duplicate scenario IDs: {}",
+                report.duplicate_scenarios.join(", ")
+            ));
+        }
+
+        if report.has_duplicates() {
+            message.push_str("\n\nDuplicated IDs usually come from copied data files; give the copies new IDs (e.g. with `mucm import toml --on-conflict re-id`)");
+            return Ok(DisplayResult::error(message));
+        }
+        if report.sequences.iter().any(|s| !s.is_compact()) {
+            message.push_str("\n\n💡 Run `mucm report ids --renumber` to close the gaps");
+        }
+        Ok(DisplayResult::success(message))
+    }
+
+    /// Compact every ID sequence to 1..n, rewriting references.
+    ///
+    /// # Returns
+    /// DisplayResult listing the renumbered use cases
+    pub fn renumber_use_cases(&mut self) -> Result<DisplayResult> {
+        match self.app_service.renumber_use_cases() {
+            Ok(renamed) if renamed.is_empty() => Ok(DisplayResult::success(
+                "✅ All ID sequences are already compact".to_string(),
+            )),
+            Ok(renamed) => {
+                let mut message = format!("✅ Renumbered {} use case(s):", renamed.len());
+                for (old_id, new_id) in &renamed {
+                    message.push_str(&format!("\n  • {} → {}", old_id, new_id));
+                }
+                message.push_str("\n📝 References rewritten and documentation regenerated");
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    // ========== Split and Merge Operations ==========
+
+    /// Move scenarios out of a use case into a new use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to split
+    /// * `scenario_ids` - Scenarios to move
+    /// * `title` - Title of the new use case
+    /// * `category` - Optional category of the new use case
+    ///
+    /// # Returns
+    /// DisplayResult with the new use case ID
+    pub fn split_use_case(
+        &mut self,
+        use_case_id: String,
+        scenario_ids: Vec<String>,
+        title: String,
+        category: Option<String>,
+    ) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .split_use_case(&use_case_id, &scenario_ids, title, category)
+        {
+            Ok(new_id) => Ok(DisplayResult::success(format!(
+                "✂️  Moved {} scenario(s) from {} into {} (extends {})",
+                scenario_ids.len(),
+                use_case_id,
+                new_id,
+                use_case_id
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Merge one use case into another.
+    ///
+    /// # Arguments
+    /// * `target_id` - The use case that is kept
+    /// * `source_id` - The use case that is merged in and removed
+    /// * `resolve` - Decides each conflicting value
+    ///
+    /// # Returns
+    /// DisplayResult summarizing the merge
+    pub fn merge_use_cases<F>(
+        &mut self,
+        target_id: String,
+        source_id: String,
+        mut resolve: F,
+    ) -> Result<DisplayResult>
+    where
+        F: FnMut(&MergeConflict) -> Result<MergeChoice>,
+    {
+        match self
+            .app_service
+            .merge_use_cases(&target_id, &source_id, |conflict| {
+                resolve(conflict).map_err(prompt_error)
+            }) {
+            Ok(scenario_count) => Ok(DisplayResult::success(format!(
+                "🔗 Merged {} into {} ({} scenario(s)); references to {} now point to {}",
+                source_id, target_id, scenario_count, source_id, target_id
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Summarize use case changes since a git revision as release notes.
+    ///
+    /// # Arguments
+    /// * `since` - Git revision of the baseline, e.g. a release tag
+    /// * `output` - Optional markdown file to write
+    ///
+    /// # Returns
+    /// DisplayResult with the release notes or a summary of the written file
+    pub fn release_notes(&self, since: String, output: Option<String>) -> Result<DisplayResult> {
+        let notes = match self.app_service.release_notes(&since) {
+            Ok(notes) => notes,
+            Err(e) => return Ok(DisplayResult::error(format!("{:#}", e))),
+        };
+
+        let markdown = notes.to_markdown();
+        match output {
+            Some(path) => {
+                std::fs::write(&path, markdown)?;
+                Ok(DisplayResult::success(format!(
+                    "📰 Wrote release notes since {} ({} new, {} changed, {} removed) to {}",
+                    since,
+                    notes.count(ReleaseNoteKind::Added),
+                    notes.count(ReleaseNoteKind::Changed),
+                    notes.count(ReleaseNoteKind::Removed),
+                    path
+                )))
+            }
+            None => Ok(DisplayResult::success(markdown)),
+        }
+    }
+
+    /// Write acceptance checklists.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Use case to write the checklist of; all use cases if None
+    ///
+    /// # Returns
+    /// DisplayResult summarizing the written checklists
+    pub fn generate_checklists(&mut self, use_case_id: Option<String>) -> Result<DisplayResult> {
+        match self.app_service.generate_checklists(use_case_id.as_deref()) {
+            Ok(ids) => Ok(DisplayResult::success(match ids.as_slice() {
+                [id] => format!("☑️  Wrote {}-checklist.md", id),
+                _ => format!("☑️  Wrote {} checklist(s)", ids.len()),
+            })),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Tick a scenario in the acceptance checklist, marking it tested.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case holding the scenario
+    /// * `scenario` - Full scenario ID or short form (e.g. `S02`)
+    ///
+    /// # Returns
+    /// DisplayResult confirming the tick
+    pub fn tick_checklist(
+        &mut self,
+        use_case_id: String,
+        scenario: String,
+    ) -> Result<DisplayResult> {
+        match self.app_service.tick_checklist(&use_case_id, &scenario) {
+            Ok(scenario_id) => Ok(DisplayResult::success(format!(
+                "☑️  Ticked {} (status: TESTED) in {}-checklist.md",
+                scenario_id, use_case_id
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Estimate the scenarios of a use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to estimate
+    /// * `ask` - Returns the estimate for a scenario field, or None to keep the current one
+    ///
+    /// # Returns
+    /// DisplayResult with the aggregated estimates
+    pub fn estimate_use_case<F>(&mut self, use_case_id: String, mut ask: F) -> Result<DisplayResult>
+    where
+        F: FnMut(&EstimateRequest) -> Result<Option<f64>>,
+    {
+        match self
+            .app_service
+            .estimate_use_case(&use_case_id, |request| ask(request).map_err(prompt_error))
+        {
+            Ok(report) => {
+                let mut message = format!(
+                    "📏 Estimated {} ({} scenario(s))",
+                    report.use_case_id, report.scenario_count
+                );
+                for total in &report.totals {
+                    let value = match total.value {
+                        Some(value) => format!("{} ({})", value, total.aggregate),
+                        None => "not estimated".to_string(),
+                    };
+                    message.push_str(&format!("\n   {}: {}", total.label, value));
+                }
+                Ok(DisplayResult::success(message))
+            }
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Copy a use case under a new ID.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to copy
+    /// * `title` - Title of the new use case
+    /// * `category` - Optional category of the new use case
+    /// * `include_scenarios` - Copy scenarios
+    /// * `include_conditions` - Copy preconditions and postconditions
+    ///
+    /// # Returns
+    /// DisplayResult with the new use case ID
+    pub fn clone_use_case(
+        &mut self,
+        use_case_id: String,
+        title: String,
+        category: Option<String>,
+        include_scenarios: bool,
+        include_conditions: bool,
+    ) -> Result<DisplayResult> {
+        match self.app_service.clone_use_case(
+            &use_case_id,
+            title,
+            category,
+            include_scenarios,
+            include_conditions,
+        ) {
+            Ok(new_id) => Ok(DisplayResult::success(format!(
+                "✅ Cloned {} as {}",
+                use_case_id, new_id
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    // ========== Update Operations ==========
+
+    /// Update basic use case information
     ///
     /// Updates the title, category, description, and/or priority of an existing use case.
     /// Only updates fields that are provided (Some). None values leave the field unchanged.
@@ -1275,6 +2822,83 @@ impl UseCaseController {
         }
     }
 
+    /// Designate the primary view of a use case
+    ///
+    /// The primary view is also generated under the canonical `<ID>.md`
+    /// filename.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The ID of the use case
+    /// * `methodology` - The methodology of the view to make primary
+    ///
+    /// # Returns
+    /// DisplayResult with success message
+    pub fn set_primary_view(
+        &mut self,
+        use_case_id: String,
+        methodology: String,
+    ) -> Result<DisplayResult> {
+        match self
+            .app_service
+            .set_primary_view(&use_case_id, &methodology)
+        {
+            Ok(_) => Ok(DisplayResult::success(format!(
+                "✅ {} view is now primary for use case: {} ({}.md)",
+                methodology, use_case_id, use_case_id
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
+    /// Get the use case template of a methodology level for customization
+    ///
+    /// # Arguments
+    /// * `methodology` - Methodology of the template
+    /// * `level` - Documentation level of the template (e.g. "normal")
+    ///
+    /// # Returns
+    /// The current template source and the path of the project version
+    pub fn template_draft(&self, methodology: &str, level: &str) -> Result<TemplateDraft> {
+        Ok(self.app_service.template_draft(methodology, level)?)
+    }
+
+    /// Render a sample use case with a candidate template
+    ///
+    /// # Returns
+    /// The rendered sample and the variables available to the template
+    ///
+    /// # Errors
+    /// Returns the template error when the candidate does not compile or render
+    pub fn preview_template(
+        &self,
+        methodology: &str,
+        level: &str,
+        source: &str,
+    ) -> Result<TemplatePreview> {
+        Ok(self
+            .app_service
+            .preview_template(methodology, level, source)?)
+    }
+
+    /// Save a template as the project version once it renders
+    ///
+    /// # Returns
+    /// DisplayResult naming the saved file, or the template error
+    pub fn save_template(
+        &self,
+        methodology: &str,
+        level: &str,
+        source: &str,
+    ) -> Result<DisplayResult> {
+        match self.app_service.save_template(methodology, level, source) {
+            Ok(path) => Ok(DisplayResult::success(format!(
+                "✅ Saved {}\n   Run `mucm regenerate` to apply it to the documentation",
+                path.display()
+            ))),
+            Err(e) => Ok(DisplayResult::error(e.to_string())),
+        }
+    }
+
     /// Get use case by ID for display/editing
     ///
     /// Retrieves a use case by its ID, useful for displaying current values
@@ -1309,3 +2933,54 @@ impl UseCaseController {
         Ok(self.app_service.get_all_use_cases().to_vec())
     }
 }
+
+/// Parse a date (end of that day, UTC) or an RFC 3339 timestamp
+fn parse_cutoff(date: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+    if let Ok(timestamp) = chrono::DateTime::parse_from_rfc3339(date) {
+        return Ok(timestamp.with_timezone(&chrono::Utc));
+    }
+    let day = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+        anyhow::anyhow!(
+            "Invalid date '{}': expected YYYY-MM-DD or an RFC 3339 timestamp",
+            date
+        )
+    })?;
+    Ok(day
+        .and_hms_nano_opt(23, 59, 59, 999_999_999)
+        .expect("valid time of day")
+        .and_utc())
+}
+
+/// Errors of interactive callbacks (prompts) handed to the coordinator
+fn prompt_error(error: anyhow::Error) -> crate::core::MucmError {
+    crate::core::MucmError::Io(std::io::Error::other(error))
+}
+
+/// Summary of an import run: what was imported and how conflicts were resolved
+fn import_result(result: crate::core::Result<ImportReport>, path: &str) -> DisplayResult {
+    let report = match result {
+        Ok(report) => report,
+        Err(e) => return DisplayResult::error(e.to_string()),
+    };
+    let mut message = format!(
+        "📥 Imported {} new use case(s) from {}",
+        report.imported.len(),
+        path
+    );
+    for id in &report.overwritten {
+        message.push_str(&format!("\n  • Overwrote {}", id));
+    }
+    for (old_id, new_id) in &report.renamed {
+        message.push_str(&format!("\n  • Imported {} as {}", old_id, new_id));
+    }
+    for (id, added) in &report.merged {
+        message.push_str(&format!(
+            "\n  • Merged {} new scenario(s) into {}",
+            added, id
+        ));
+    }
+    for id in &report.skipped {
+        message.push_str(&format!("\n  • Skipped {} (already exists)", id));
+    }
+    DisplayResult::success(message)
+}
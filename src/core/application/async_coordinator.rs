@@ -0,0 +1,216 @@
+// Async front end of the coordinator, for servers and remote storage
+//
+// Every UseCaseCoordinator operation runs against an in-memory working copy
+// of the project; what it changed is then awaited into the async repository.
+// The repository is never waited on synchronously, so a remote backend does
+// not block the runtime's threads.
+use crate::config::ProjectContext;
+use crate::core::file_operations::FileOperations;
+use crate::core::Result;
+use crate::core::{
+    to_snake_case, AsyncUseCaseRepository, InMemoryActorRepository, InMemoryUseCaseRepository,
+    LoadFailure, RepositoryFactory, RepositoryTransaction, UseCase, UseCaseCoordinator,
+    UseCaseRepository,
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Coordinator over an [`AsyncUseCaseRepository`], with an async API
+///
+/// Operations are the ones of [`UseCaseCoordinator`], passed to [`run`]:
+/// they work on a local copy of the use cases, and the use cases and
+/// markdown they changed are persisted with awaited repository calls.
+/// Overviews, manifests and test files are written under the context's root,
+/// as for [`UseCaseCoordinator::in_memory`].
+///
+/// [`run`]: AsyncUseCaseCoordinator::run
+pub struct AsyncUseCaseCoordinator {
+    repository: Box<dyn AsyncUseCaseRepository>,
+    coordinator: UseCaseCoordinator,
+    working_copy: InMemoryUseCaseRepository,
+    /// Use cases as last persisted, serialized for comparison
+    persisted: HashMap<String, Value>,
+    /// Markdown as last persisted, by `<category>/<filename>`
+    persisted_markdown: BTreeMap<String, String>,
+    load_failures: Vec<LoadFailure>,
+}
+
+impl AsyncUseCaseCoordinator {
+    /// Open the project described by `context`, loading its use cases from `repository`
+    pub async fn open(
+        context: ProjectContext,
+        repository: Box<dyn AsyncUseCaseRepository>,
+    ) -> Result<Self> {
+        let report = repository.load_all_with_report().await?;
+        // Actors are optional: a project without an actor directory has none
+        let actors = RepositoryFactory::create_actor_repository(&context.resolved_config())
+            .and_then(|actors| actors.load_all_actors())
+            .unwrap_or_default();
+
+        let persisted = report
+            .use_cases
+            .iter()
+            .map(|use_case| Ok((use_case.id.clone(), serde_json::to_value(use_case)?)))
+            .collect::<Result<_>>()?;
+        let working_copy = InMemoryUseCaseRepository::with_use_cases(report.use_cases);
+        let coordinator = UseCaseCoordinator::in_memory(
+            context,
+            working_copy.clone(),
+            &InMemoryActorRepository::with_actors(actors),
+        )?;
+        Ok(Self {
+            repository,
+            coordinator,
+            working_copy,
+            persisted,
+            persisted_markdown: BTreeMap::new(),
+            load_failures: report.failures,
+        })
+    }
+
+    /// The coordinator, for queries; changes go through [`run`](Self::run)
+    pub fn coordinator(&self) -> &UseCaseCoordinator {
+        &self.coordinator
+    }
+
+    /// Data that failed to load when the project was opened
+    pub fn load_failures(&self) -> &[LoadFailure] {
+        &self.load_failures
+    }
+
+    /// Run a coordinator operation, then persist what it changed
+    ///
+    /// Changes are persisted even when the operation fails part way, as the
+    /// synchronous coordinator would have saved them already.
+    ///
+    /// # Errors
+    /// Returns the operation's error, or the repository's if persisting fails.
+    pub async fn run<T>(
+        &mut self,
+        operation: impl FnOnce(&mut UseCaseCoordinator) -> Result<T>,
+    ) -> Result<T> {
+        let result = operation(&mut self.coordinator);
+        self.persist().await?;
+        result
+    }
+
+    /// Save changed use cases and delete removed ones as one transaction, then
+    /// save the markdown generated for them
+    async fn persist(&mut self) -> Result<()> {
+        let use_cases = self.working_copy.load_all()?;
+        let mut transaction = RepositoryTransaction::new();
+        let mut current = HashMap::new();
+        for use_case in &use_cases {
+            let value = serde_json::to_value(use_case)?;
+            let before = self.persisted.get(&use_case.id);
+            if before != Some(&value) {
+                // Files live under their category; deleting first removes the old ones
+                if before.is_some_and(|before| before["category"] != value["category"]) {
+                    transaction.delete(use_case.id.as_str());
+                }
+                transaction.save(use_case.clone());
+            }
+            current.insert(use_case.id.clone(), value);
+        }
+        for id in self.persisted.keys() {
+            if !current.contains_key(id) {
+                transaction.delete(id.as_str());
+            }
+        }
+        if !transaction.is_empty() {
+            self.repository.commit(transaction).await?;
+        }
+        self.persisted = current;
+
+        let paths = self.working_copy.markdown_paths();
+        self.persisted_markdown
+            .retain(|path, _| paths.contains(path));
+        for path in paths {
+            let Some(content) = self.working_copy.markdown(&path) else {
+                continue;
+            };
+            if self.persisted_markdown.get(&path) == Some(&content) {
+                continue;
+            }
+            if let Some((use_case, filename)) = markdown_owner(&use_cases, &path) {
+                self.repository
+                    .save_markdown_with_filename(use_case, filename, &content)
+                    .await?;
+            }
+            self.persisted_markdown.insert(path, content);
+        }
+        Ok(())
+    }
+}
+
+/// The use case a `<category>/<filename>` markdown path was generated for, and the filename
+fn markdown_owner<'a>(use_cases: &'a [UseCase], path: &'a str) -> Option<(&'a UseCase, &'a str)> {
+    let (category, filename) = path.split_once('/')?;
+    use_cases
+        .iter()
+        .find(|use_case| {
+            to_snake_case(&use_case.category) == category
+                && FileOperations::is_generated_document(filename, &use_case.id)
+        })
+        .map(|use_case| (use_case, filename))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::core::{block_on, SyncRepositoryAdapter};
+    use tempfile::TempDir;
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn test_run_persists_changes_to_the_async_repository() -> Result<()> {
+        let project = TempDir::new()?;
+        let remote = InMemoryUseCaseRepository::new();
+        let mut coordinator = block_on(AsyncUseCaseCoordinator::open(
+            ProjectContext::new(project.path(), Config::default()),
+            Box::new(SyncRepositoryAdapter::new(remote.clone())),
+        ))?;
+
+        let create = coordinator.run(|coordinator| {
+            coordinator.create_use_case_with_views(
+                "Login".to_string(),
+                "Auth".to_string(),
+                None,
+                "feature:normal",
+            )
+        });
+        assert_send(&create);
+        let id = block_on(create)?;
+        assert_eq!(remote.load_all()?[0].title, "Login");
+        assert!(remote
+            .markdown(&format!("auth/{}-feature-normal.md", id))
+            .is_some());
+
+        // Moving to another category deletes the use case and saves it again
+        block_on(coordinator.run(|coordinator| {
+            coordinator.update_use_case(&id, Some("Sign in"), Some("Access"), None, None)
+        }))?;
+        let saved = remote.load_all()?;
+        assert_eq!(saved.len(), 1);
+        assert_eq!(
+            (saved[0].title.as_str(), saved[0].category.as_str()),
+            ("Sign in", "Access")
+        );
+        assert!(remote
+            .markdown(&format!("access/{}-feature-normal.md", id))
+            .is_some());
+        assert!(remote
+            .markdown(&format!("auth/{}-feature-normal.md", id))
+            .is_none());
+
+        // A failed operation leaves the repository as it was
+        let error = block_on(coordinator.run(|coordinator| {
+            coordinator.update_use_case("UC-NOP-001", Some("Nothing"), None, None, None)
+        }));
+        assert!(error.is_err());
+        assert_eq!(remote.load_all()?.len(), 1);
+        Ok(())
+    }
+}
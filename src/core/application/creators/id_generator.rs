@@ -0,0 +1,316 @@
+//! Strategies for assigning IDs to new use cases
+//!
+//! The strategy is chosen per project with `project.id_strategy`. Sequential
+//! IDs read well in documents; ULIDs and short UUIDs never collide, which
+//! matters to teams creating use cases on several branches or syncing them to
+//! external trackers.
+
+use crate::config::{Config, IdStrategy};
+use crate::core::domain::UseCaseService;
+use crate::core::{MucmError, Result, UseCase};
+use chrono::{Datelike, Utc};
+use regex::Regex;
+
+/// Pattern equivalent to the sequential strategy
+pub const DEFAULT_ID_PATTERN: &str = "{prefix}-{category}-{seq:03}";
+
+/// Assigns IDs to new use cases
+pub trait IdGenerator: Send + Sync {
+    /// ID for a new use case in `category`, different from all `existing` IDs
+    fn next_id(&self, category: &str, existing: &[UseCase]) -> Result<String>;
+}
+
+/// The generator configured for the project
+pub fn id_generator(config: &Config) -> Box<dyn IdGenerator> {
+    let prefix = &config.project.id_prefix;
+    match config.project.id_strategy {
+        IdStrategy::Sequential => Box::new(SequentialIdGenerator::new(
+            prefix,
+            &config.directories.use_case_dir,
+        )),
+        IdStrategy::Ulid => Box::new(UlidIdGenerator::new(prefix)),
+        IdStrategy::UuidShort => Box::new(ShortUuidIdGenerator::new(prefix)),
+        IdStrategy::Pattern => Box::new(PatternIdGenerator::new(
+            prefix,
+            config
+                .project
+                .id_pattern
+                .as_deref()
+                .unwrap_or(DEFAULT_ID_PATTERN),
+        )),
+    }
+}
+
+/// UC-AUT-001, UC-AUT-002, ... numbered per category
+///
+/// Also counts markdown files in the category directory, so IDs of deleted
+/// use cases whose documentation is still around are not reused.
+pub struct SequentialIdGenerator {
+    service: UseCaseService,
+    use_case_dir: String,
+}
+
+impl SequentialIdGenerator {
+    pub fn new(prefix: &str, use_case_dir: &str) -> Self {
+        Self {
+            service: UseCaseService::with_prefix(prefix),
+            use_case_dir: use_case_dir.to_string(),
+        }
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&self, category: &str, existing: &[UseCase]) -> Result<String> {
+        Ok(self
+            .service
+            .generate_unique_use_case_id(category, existing, &self.use_case_dir))
+    }
+}
+
+/// Prefix and a ULID: UC-01JA2Z6V4KQ3M8XG5T7B9CDE0F
+pub struct UlidIdGenerator {
+    prefix: String,
+}
+
+impl UlidIdGenerator {
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+        }
+    }
+}
+
+impl IdGenerator for UlidIdGenerator {
+    fn next_id(&self, _category: &str, existing: &[UseCase]) -> Result<String> {
+        Ok(unused(existing, || {
+            format!("{}-{}", self.prefix, ulid::Ulid::new())
+        }))
+    }
+}
+
+/// Prefix and the first 8 hex digits of a random UUID: UC-3f9a6c1e
+pub struct ShortUuidIdGenerator {
+    prefix: String,
+}
+
+impl ShortUuidIdGenerator {
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+        }
+    }
+}
+
+impl IdGenerator for ShortUuidIdGenerator {
+    fn next_id(&self, _category: &str, existing: &[UseCase]) -> Result<String> {
+        Ok(unused(existing, || {
+            let uuid = uuid::Uuid::new_v4().simple().to_string();
+            format!("{}-{}", self.prefix, &uuid[..8])
+        }))
+    }
+}
+
+/// IDs built from a pattern such as "{prefix}-{category}-{year}-{seq:04}"
+///
+/// Placeholders: `{prefix}` (project.id_prefix), `{category}` (category code,
+/// e.g. AUT), `{year}` and `{seq}` or `{seq:<width>}`, one more than the
+/// highest number among existing IDs that match the rest of the pattern.
+/// Patterns start with `{prefix}-`, like every use case ID.
+pub struct PatternIdGenerator {
+    prefix: String,
+    pattern: String,
+}
+
+impl PatternIdGenerator {
+    pub fn new(prefix: &str, pattern: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            pattern: pattern.to_string(),
+        }
+    }
+
+    /// Check that `pattern` only uses known placeholders and contains `{seq}`
+    pub fn validate(pattern: &str) -> Result<()> {
+        parse(pattern).map(|_| ())
+    }
+}
+
+impl IdGenerator for PatternIdGenerator {
+    fn next_id(&self, category: &str, existing: &[UseCase]) -> Result<String> {
+        let parts = parse(&self.pattern)?;
+        let category_code = UseCaseService::category_code(category);
+        let year = Utc::now().year().to_string();
+        let literal = |part: &Part| match part {
+            Part::Text(text) => Some(text.clone()),
+            Part::Prefix => Some(self.prefix.clone()),
+            Part::Category => Some(category_code.clone()),
+            Part::Year => Some(year.clone()),
+            Part::Seq(_) => None,
+        };
+
+        let mut matcher = String::from("^");
+        for part in &parts {
+            match literal(part) {
+                Some(text) => matcher.push_str(&regex::escape(&text)),
+                None => matcher.push_str(r"(\d+)"),
+            }
+        }
+        matcher.push('$');
+        let matcher = Regex::new(&matcher)?;
+        let seq = existing
+            .iter()
+            .filter_map(|uc| matcher.captures(&uc.id))
+            .filter_map(|captures| captures[1].parse::<usize>().ok())
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        Ok(parts
+            .iter()
+            .map(|part| match (literal(part), part) {
+                (Some(text), _) => text,
+                (None, Part::Seq(width)) => format!("{:0width$}", seq, width = *width),
+                (None, _) => unreachable!("only {{seq}} has no literal value"),
+            })
+            .collect())
+    }
+}
+
+/// A piece of an ID pattern
+enum Part {
+    Text(String),
+    Prefix,
+    Category,
+    Year,
+    /// Sequence number, zero-padded to the width
+    Seq(usize),
+}
+
+fn parse(pattern: &str) -> Result<Vec<Part>> {
+    let invalid = |problem: String| {
+        MucmError::Validation(format!("Invalid ID pattern '{}': {}", pattern, problem))
+    };
+
+    // The TOML backend only loads data files named after the prefix
+    if !pattern.starts_with("{prefix}-") {
+        return Err(invalid("it must start with {prefix}-".to_string()));
+    }
+
+    let mut parts = Vec::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        if start > 0 {
+            parts.push(Part::Text(rest[..start].to_string()));
+        }
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| invalid("unclosed '{'".to_string()))?
+            + start;
+        let placeholder = &rest[start + 1..end];
+        parts.push(match placeholder.split_once(':') {
+            None if placeholder == "prefix" => Part::Prefix,
+            None if placeholder == "category" => Part::Category,
+            None if placeholder == "year" => Part::Year,
+            None if placeholder == "seq" => Part::Seq(1),
+            Some(("seq", width)) => Part::Seq(
+                width
+                    .parse()
+                    .map_err(|_| invalid(format!("'{}' is not a width", width)))?,
+            ),
+            _ => {
+                return Err(invalid(format!(
+                    "unknown placeholder {{{}}} (use prefix, category, year or seq)",
+                    placeholder
+                )))
+            }
+        });
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(Part::Text(rest.to_string()));
+    }
+
+    match parts
+        .iter()
+        .filter(|part| matches!(part, Part::Seq(_)))
+        .count()
+    {
+        1 => Ok(parts),
+        0 => Err(invalid("it needs a {seq} placeholder".to_string())),
+        _ => Err(invalid("{seq} may only appear once".to_string())),
+    }
+}
+
+/// First generated ID not taken by an existing use case
+fn unused(existing: &[UseCase], generate: impl Fn() -> String) -> String {
+    loop {
+        let id = generate();
+        if !existing.iter().any(|uc| uc.id == id) {
+            return id;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn use_case(id: &str, category: &str) -> UseCase {
+        UseCase::builder(id, "Title")
+            .category(category)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_pattern_continues_the_highest_sequence() {
+        let generator = PatternIdGenerator::new("PAY", "{prefix}-{category}-{seq:04}");
+        let existing = vec![
+            use_case("PAY-AUT-0007", "Auth"),
+            use_case("PAY-AUT-0002", "Auth"),
+            use_case("PAY-API-0042", "API"),
+            use_case("UC-AUT-0099", "Auth"),
+        ];
+
+        assert_eq!(
+            generator.next_id("Auth", &existing).unwrap(),
+            "PAY-AUT-0008"
+        );
+        assert_eq!(
+            generator.next_id("Billing", &existing).unwrap(),
+            "PAY-BIL-0001"
+        );
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        assert!(PatternIdGenerator::validate("{prefix}-{seq:3}").is_ok());
+        for pattern in [
+            "{category}-{seq}",
+            "{prefix}-{category}",
+            "{prefix}-{seq}-{seq}",
+            "{id}-{seq}",
+            "{seq:x}",
+            "{seq",
+        ] {
+            assert!(
+                PatternIdGenerator::validate(pattern).is_err(),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_ids() {
+        let ulid = UlidIdGenerator::new("UC").next_id("Auth", &[]).unwrap();
+        assert!(ulid.starts_with("UC-"));
+        assert_eq!(ulid.len(), 3 + 26);
+
+        let short = ShortUuidIdGenerator::new("UC")
+            .next_id("Auth", &[])
+            .unwrap();
+        assert_eq!(short.len(), 3 + 8);
+        assert!(short[3..].chars().all(|c| c.is_ascii_hexdigit()));
+    }
+}
@@ -3,8 +3,13 @@
 //! This module contains components responsible for creating new use cases
 //! and scenarios with proper validation, unique ID generation, and methodology support.
 
+mod id_generator;
 mod scenario_creator;
 mod use_case_creator;
 
+pub use id_generator::{
+    id_generator, IdGenerator, PatternIdGenerator, SequentialIdGenerator, ShortUuidIdGenerator,
+    UlidIdGenerator, DEFAULT_ID_PATTERN,
+};
 pub use scenario_creator::ScenarioCreator;
 pub use use_case_creator::UseCaseCreator;
@@ -9,6 +9,7 @@ impl ScenarioCreator {
     }
 
     /// Create a new scenario for a use case
+    #[allow(clippy::too_many_arguments)]
     pub fn create_scenario(
         &self,
         use_case: &UseCase,
@@ -1,26 +1,120 @@
 use crate::config::Config;
+use crate::core::application::creators::{id_generator, IdGenerator};
 use crate::core::application::MethodologyFieldCollector;
-use crate::core::domain::UseCaseService;
-use crate::core::{MethodologyView, UseCase, UseCaseRepository};
-use anyhow::Result;
+use crate::core::MucmError;
+use crate::core::Result;
+use crate::core::{
+    LifecycleConfig, MethodologyDefinition, MethodologyView, Scenario, UseCase, UseCaseRepository,
+};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Handles use case creation with methodology support
 pub struct UseCaseCreator {
     config: Config,
-    use_case_service: UseCaseService,
+    id_generator: Box<dyn IdGenerator>,
+    /// Installed templates the methodology fields and lifecycles are read from
+    templates_dir: PathBuf,
 }
 
 impl UseCaseCreator {
     pub fn new(config: Config) -> Self {
         Self {
-            use_case_service: UseCaseService::new(),
+            id_generator: id_generator(&config),
             config,
+            templates_dir: Path::new(Config::CONFIG_DIR).join(Config::TEMPLATES_DIR),
         }
     }
 
+    /// Read methodologies from `templates_dir` instead of the working directory
+    pub fn with_templates_dir(mut self, templates_dir: PathBuf) -> Self {
+        self.templates_dir = templates_dir;
+        self
+    }
+
+    /// Set the first review date when the project has a review interval
+    fn schedule_review(&self, use_case: &mut UseCase) {
+        if let Some(days) = self.config.metadata.review_interval_days {
+            use_case.metadata.schedule_review(days);
+        }
+    }
+
+    /// Lifecycle declared by `methodology` in the project's templates, if it loads
+    fn lifecycle(&self, methodology: &str) -> Option<LifecycleConfig> {
+        let methodology_dir = self.templates_dir.join("methodologies").join(methodology);
+        MethodologyDefinition::from_toml(methodology_dir)
+            .ok()
+            .map(|definition| definition.lifecycle().clone())
+    }
+
+    /// `views` followed by the views their methodology lifecycles add for their level
+    pub fn with_lifecycle_views(&self, views: Vec<MethodologyView>) -> Vec<MethodologyView> {
+        let mut all_views = views.clone();
+        for view in &views {
+            let Some(lifecycle) = self.lifecycle(&view.methodology) else {
+                continue;
+            };
+            for added in lifecycle.views.get(&view.level).into_iter().flatten() {
+                let (methodology, level) = added
+                    .split_once(':')
+                    .unwrap_or((view.methodology.as_str(), added.as_str()));
+                let added =
+                    MethodologyView::new(methodology.trim().to_string(), level.trim().to_string());
+                if !all_views.iter().any(|v| v.key() == added.key()) {
+                    all_views.push(added);
+                }
+            }
+        }
+        all_views
+    }
+
+    /// Add the scenarios declared by the lifecycles of the use case's methodologies
+    ///
+    /// Scenarios whose title the use case already has are skipped.
+    fn add_lifecycle_scenarios(&self, use_case: &mut UseCase) {
+        let mut methodologies: Vec<String> = Vec::new();
+        for view in &use_case.views {
+            if !methodologies.contains(&view.methodology) {
+                methodologies.push(view.methodology.clone());
+            }
+        }
+
+        for methodology in methodologies {
+            let Some(lifecycle) = self.lifecycle(&methodology) else {
+                continue;
+            };
+            for added in &lifecycle.scenarios {
+                if use_case.scenarios.iter().any(|s| s.title == added.title) {
+                    continue;
+                }
+                let mut scenario = Scenario::new(
+                    use_case.next_scenario_id(),
+                    added.title.clone(),
+                    added.description.clone(),
+                    added.scenario_type,
+                );
+                if let Some(status) = lifecycle.initial_status {
+                    scenario.status = status;
+                }
+                use_case.add_scenario(scenario);
+            }
+        }
+    }
+
+    /// ID for the next use case created in `category`, from the project's ID strategy
+    ///
+    /// Random strategies give a different ID on every call.
+    pub fn next_use_case_id(
+        &self,
+        category: &str,
+        existing_use_cases: &[UseCase],
+    ) -> Result<String> {
+        self.id_generator.next_id(category, existing_use_cases)
+    }
+
     /// Create a use case with methodology-specific custom fields
+    #[allow(clippy::too_many_arguments)]
     pub fn create_use_case_with_methodology(
         &self,
         title: String,
@@ -31,17 +125,13 @@ impl UseCaseCreator {
         existing_use_cases: &[UseCase],
         repository: &dyn UseCaseRepository,
     ) -> Result<UseCase> {
-        let use_case_id = self.use_case_service.generate_unique_use_case_id(
-            &category,
-            existing_use_cases,
-            &self.config.directories.use_case_dir,
-        );
+        let use_case_id = self.next_use_case_id(&category, existing_use_cases)?;
         let description = description.unwrap_or_default();
 
         // Create base use case
         let mut use_case =
             UseCase::new(use_case_id.clone(), title, category, description, priority)
-                .map_err(|e: String| anyhow::anyhow!(e))?;
+                .map_err(MucmError::Validation)?;
 
         // Add default view (methodology:normal)
         use_case.add_view(MethodologyView::new(
@@ -50,7 +140,7 @@ impl UseCaseCreator {
         ));
 
         // Collect and store methodology fields for this view
-        let collector = MethodologyFieldCollector::new()?;
+        let collector = MethodologyFieldCollector::for_templates_dir(&self.templates_dir);
         let collection = collector
             .collect_fields_for_views(&[(methodology.to_string(), "normal".to_string())])?;
 
@@ -60,7 +150,7 @@ impl UseCaseCreator {
             for (field_name, field_config) in collection.fields {
                 let value = field_config
                     .default
-                    .map(|d| serde_json::Value::String(d))
+                    .map(serde_json::Value::String)
                     .unwrap_or(serde_json::Value::Null);
                 methodology_fields.insert(field_name, value);
             }
@@ -70,16 +160,18 @@ impl UseCaseCreator {
         }
 
         // Step 1: Save TOML first (source of truth)
+        self.schedule_review(&mut use_case);
         repository.save(&use_case)?;
 
         // Step 2: Load from TOML to ensure we're working with persisted data
-        let use_case_from_toml = repository
-            .load_by_id(&use_case.id)?
-            .ok_or_else(|| anyhow::anyhow!("Failed to load newly created use case from TOML"))?;
+        let use_case_from_toml = repository.load_by_id(&use_case.id)?.ok_or_else(|| {
+            MucmError::Storage("Failed to load newly created use case from TOML".to_string())
+        })?;
 
         Ok(use_case_from_toml)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_use_case_with_custom_fields(
         &self,
         title: String,
@@ -91,17 +183,13 @@ impl UseCaseCreator {
         existing_use_cases: &[UseCase],
         repository: &dyn UseCaseRepository,
     ) -> Result<UseCase> {
-        let use_case_id = self.use_case_service.generate_unique_use_case_id(
-            &category,
-            existing_use_cases,
-            &self.config.directories.use_case_dir,
-        );
+        let use_case_id = self.next_use_case_id(&category, existing_use_cases)?;
         let description = description.unwrap_or_default();
 
         // Create base use case
         let mut use_case =
             UseCase::new(use_case_id.clone(), title, category, description, priority)
-                .map_err(|e: String| anyhow::anyhow!(e))?;
+                .map_err(MucmError::Validation)?;
 
         // Add default view (methodology:normal)
         use_case.add_view(MethodologyView::new(
@@ -110,7 +198,7 @@ impl UseCaseCreator {
         ));
 
         // Collect methodology fields
-        let collector = MethodologyFieldCollector::new()?;
+        let collector = MethodologyFieldCollector::for_templates_dir(&self.templates_dir);
         let collection = collector
             .collect_fields_for_views(&[(methodology.to_string(), "normal".to_string())])?;
 
@@ -130,9 +218,9 @@ impl UseCaseCreator {
 
         // Add any user fields that weren't in the methodology definition
         for (key, value) in user_fields {
-            if !methodology_fields.contains_key(&key) {
-                methodology_fields.insert(key, serde_json::Value::String(value));
-            }
+            methodology_fields
+                .entry(key)
+                .or_insert(serde_json::Value::String(value));
         }
 
         if !methodology_fields.is_empty() {
@@ -142,12 +230,13 @@ impl UseCaseCreator {
         }
 
         // Step 1: Save TOML first (source of truth)
+        self.schedule_review(&mut use_case);
         repository.save(&use_case)?;
 
         // Step 2: Load from TOML to ensure we're working with persisted data
-        let use_case_from_toml = repository
-            .load_by_id(&use_case.id)?
-            .ok_or_else(|| anyhow::anyhow!("Failed to load newly created use case from TOML"))?;
+        let use_case_from_toml = repository.load_by_id(&use_case.id)?.ok_or_else(|| {
+            MucmError::Storage("Failed to load newly created use case from TOML".to_string())
+        })?;
 
         Ok(use_case_from_toml)
     }
@@ -156,6 +245,7 @@ impl UseCaseCreator {
     ///
     /// This method properly uses MethodologyFieldCollector to gather fields from all views,
     /// stores them in methodology_fields structure, and handles user value overrides.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_use_case_with_views(
         &self,
         title: String,
@@ -167,16 +257,49 @@ impl UseCaseCreator {
         existing_use_cases: &[UseCase],
         repository: &dyn UseCaseRepository,
     ) -> Result<UseCase> {
-        let use_case_id = self.use_case_service.generate_unique_use_case_id(
-            &category,
+        let mut use_case = self.build_use_case_with_views(
+            title,
+            category,
+            description,
+            priority,
+            views,
+            user_fields,
             existing_use_cases,
-            &self.config.directories.use_case_dir,
-        );
+        )?;
+
+        // Save and reload from TOML
+        self.schedule_review(&mut use_case);
+        repository.save(&use_case)?;
+        let use_case_from_toml = repository.load_by_id(&use_case.id)?.ok_or_else(|| {
+            MucmError::Storage("Failed to load newly created use case from TOML".to_string())
+        })?;
+
+        Ok(use_case_from_toml)
+    }
+
+    /// Build a use case with multiple views without saving it
+    ///
+    /// Used where creations are staged and committed together, e.g. batches.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_use_case_with_views(
+        &self,
+        title: String,
+        category: String,
+        description: Option<String>,
+        priority: String,
+        views: Vec<MethodologyView>,
+        user_fields: HashMap<String, String>,
+        existing_use_cases: &[UseCase],
+    ) -> Result<UseCase> {
+        let use_case_id = self.next_use_case_id(&category, existing_use_cases)?;
         let description = description.unwrap_or_default();
 
+        // Views added by the methodology lifecycles get their fields collected too
+        let views = self.with_lifecycle_views(views);
+
         // Collect fields from all methodology views using the collector
         // If collector fails (e.g., in test environment without methodologies), use empty fields
-        let collector = MethodologyFieldCollector::new()?;
+        let collector = MethodologyFieldCollector::for_templates_dir(&self.templates_dir);
         let view_pairs: Vec<(String, String)> = views
             .iter()
             .map(|v| (v.methodology.clone(), v.level.clone()))
@@ -208,7 +331,7 @@ impl UseCaseCreator {
         for view in &views {
             methodology_fields
                 .entry(view.methodology.clone())
-                .or_insert_with(HashMap::new);
+                .or_default();
         }
 
         // Populate with actual field values
@@ -218,7 +341,7 @@ impl UseCaseCreator {
                 for methodology in &collected_field.methodologies {
                     methodology_fields
                         .entry(methodology.clone())
-                        .or_insert_with(HashMap::new)
+                        .or_default()
                         .insert(field_name.clone(), field_value.clone());
                 }
             }
@@ -227,7 +350,7 @@ impl UseCaseCreator {
         // Create the use case with empty extra fields (methodology fields go in methodology_fields)
         let mut use_case =
             UseCase::new(use_case_id.clone(), title, category, description, priority)
-                .map_err(|e| anyhow::anyhow!(e))?;
+                .map_err(MucmError::Validation)?;
 
         // Set methodology fields
         use_case.methodology_fields = methodology_fields;
@@ -237,12 +360,8 @@ impl UseCaseCreator {
             use_case.add_view(view);
         }
 
-        // Save and reload from TOML
-        repository.save(&use_case)?;
-        let use_case_from_toml = repository
-            .load_by_id(&use_case.id)?
-            .ok_or_else(|| anyhow::anyhow!("Failed to load newly created use case from TOML"))?;
+        self.add_lifecycle_scenarios(&mut use_case);
 
-        Ok(use_case_from_toml)
+        Ok(use_case)
     }
 }
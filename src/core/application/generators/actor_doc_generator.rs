@@ -0,0 +1,212 @@
+//! Actor pages: one markdown file per persona or system actor in `actor_dir`.
+//!
+//! Besides the actor's own fields, each page gets its place in the actor
+//! hierarchy (`parent`, `children`) and the use cases it is involved in,
+//! directly (`use_case_count`) and over its whole subtree (`rollup_count`).
+
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use crate::config::{Config, ProjectContext};
+use crate::core::domain::ActorRollup;
+use crate::core::file_operations::FileOperations;
+use crate::core::utils::{link_path, normalize_line_endings, relative_link};
+use crate::core::{to_snake_case, ActorEntity, ActorHierarchy, Result, TemplateEngine, UseCase};
+
+use super::front_matter::page_front_matter;
+use super::OutputManager;
+
+/// Generator for actor documentation pages
+pub struct ActorDocGenerator {
+    config: Config,
+    template_engine: TemplateEngine,
+}
+
+impl ActorDocGenerator {
+    /// Creates an actor page generator for the project described by `context`.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        Self {
+            config: context.resolved_config(),
+            template_engine: TemplateEngine::for_project(context),
+        }
+    }
+
+    /// Writes the page of every actor, returning the number of pages written
+    pub fn generate(&self, actors: &[ActorEntity], use_cases: &[UseCase]) -> Result<usize> {
+        if actors.is_empty() {
+            return Ok(0);
+        }
+        let hierarchy = ActorHierarchy::new(actors);
+        let rollups = hierarchy.rollups(use_cases);
+        let use_cases: HashMap<&str, &UseCase> =
+            use_cases.iter().map(|uc| (uc.id.as_str(), uc)).collect();
+
+        let actor_dir = Path::new(&self.config.directories.actor_dir);
+        std::fs::create_dir_all(actor_dir)?;
+        for actor in actors {
+            let data = self.actor_data(actor, &hierarchy, &rollups, &use_cases)?;
+            let front_matter =
+                page_front_matter(&self.config.generation.front_matter, &actor.name)?;
+            FileOperations::write_streamed(&actor_dir.join(page(&actor.id)), |writer| {
+                if let Some(front_matter) = &front_matter {
+                    let eol = self.config.generation.line_endings.as_str();
+                    writer.write_all(normalize_line_endings(front_matter, eol).as_bytes())?;
+                }
+                self.template_engine.render_actor_to(&data, writer)
+            })?;
+        }
+        Ok(actors.len())
+    }
+
+    /// Template data of an actor page
+    ///
+    /// The actor's extra fields are passed both at the top level and as
+    /// `extra`, as `actor.hbs` expects.
+    fn actor_data(
+        &self,
+        actor: &ActorEntity,
+        hierarchy: &ActorHierarchy,
+        rollups: &BTreeMap<&str, ActorRollup>,
+        use_cases: &HashMap<&str, &UseCase>,
+    ) -> Result<HashMap<String, Value>> {
+        let mut data: HashMap<String, Value> =
+            serde_json::from_value(serde_json::to_value(actor)?)?;
+        let mut extra = actor.extra.clone();
+        extra.remove(ActorEntity::PARENT_FIELD);
+        data.insert("extra".to_string(), json!(extra));
+        data.insert(
+            "actor_type".to_string(),
+            json!(actor.actor_type.to_string()),
+        );
+
+        let rollup = rollups.get(actor.id.as_str()).cloned().unwrap_or_default();
+        let parent = hierarchy.parent(&actor.id).map(|parent| {
+            json!({
+                "id": parent.id,
+                "name": parent.name,
+                "emoji": parent.emoji,
+                "page": page(&parent.id),
+            })
+        });
+        data.insert("parent".to_string(), json!(parent));
+        let children: Vec<Value> = hierarchy
+            .children(&actor.id)
+            .into_iter()
+            .map(|child| {
+                json!({
+                    "id": child.id,
+                    "name": child.name,
+                    "emoji": child.emoji,
+                    "page": page(&child.id),
+                    "rollup_count": rollups.get(child.id.as_str()).map_or(0, |r| r.total.len()),
+                })
+            })
+            .collect();
+        data.insert("children".to_string(), json!(children));
+
+        let listed: Vec<Value> = rollup
+            .total
+            .iter()
+            .filter_map(|id| use_cases.get(id.as_str()))
+            .map(|uc| {
+                json!({
+                    "id": uc.id,
+                    "title": uc.title,
+                    "page": self.use_case_page(uc),
+                    "direct": rollup.direct.contains(&uc.id),
+                })
+            })
+            .collect();
+        data.insert("use_cases".to_string(), json!(listed));
+        data.insert("use_case_count".to_string(), json!(rollup.direct.len()));
+        data.insert("rollup_count".to_string(), json!(rollup.total.len()));
+        Ok(data)
+    }
+
+    /// Link from the actor directory to the first markdown file of a use case
+    fn use_case_page(&self, use_case: &UseCase) -> String {
+        let filename =
+            OutputManager::generate_all_filenames(use_case, self.config.generation.output_format)
+                .into_iter()
+                .next()
+                .map(|(filename, _)| filename)
+                .unwrap_or_else(|| {
+                    OutputManager::canonical_filename(
+                        use_case,
+                        self.config.generation.output_format,
+                    )
+                });
+        relative_link(
+            &self.config.directories.actor_dir,
+            &link_path(&[
+                self.config.directories.use_case_dir.clone(),
+                to_snake_case(&use_case.category),
+                filename,
+            ]),
+        )
+    }
+}
+
+/// File name of an actor page, relative to `actor_dir`
+fn page(actor_id: &str) -> String {
+    format!("{}.md", actor_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Scenario, ScenarioType};
+
+    #[test]
+    fn test_actor_data_includes_rollups_and_hierarchy() {
+        let generator = ActorDocGenerator::for_project(&ProjectContext::current(Config::default()));
+        let mut department = ActorEntity::persona("sales".to_string(), "Sales".to_string());
+        department
+            .extra
+            .insert("function".to_string(), json!("Department"));
+        let mut role = ActorEntity::persona("sales-rep".to_string(), "Sales Rep".to_string());
+        role.set_parent(Some("sales".to_string()));
+        let actors = vec![department, role];
+
+        let mut use_case = UseCase::new(
+            "UC-SAL-001".to_string(),
+            "Close deal".to_string(),
+            "Sales".to_string(),
+            String::new(),
+            "low".to_string(),
+        )
+        .unwrap();
+        let mut scenario = Scenario::new(
+            "UC-SAL-001-S01".to_string(),
+            "Main".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        );
+        scenario.persona = Some("sales-rep".to_string());
+        use_case.add_scenario(scenario);
+        let use_cases = vec![use_case];
+
+        let hierarchy = ActorHierarchy::new(&actors);
+        let rollups = hierarchy.rollups(&use_cases);
+        let by_id: HashMap<&str, &UseCase> =
+            use_cases.iter().map(|uc| (uc.id.as_str(), uc)).collect();
+
+        let data = generator
+            .actor_data(&actors[0], &hierarchy, &rollups, &by_id)
+            .unwrap();
+        assert_eq!(data["actor_type"], "Persona");
+        assert_eq!(data["function"], "Department");
+        assert_eq!(data["use_case_count"], 0);
+        assert_eq!(data["rollup_count"], 1);
+        assert_eq!(data["children"][0]["page"], "sales-rep.md");
+        assert_eq!(data["use_cases"][0]["direct"], false);
+
+        let data = generator
+            .actor_data(&actors[1], &hierarchy, &rollups, &by_id)
+            .unwrap();
+        assert_eq!(data["parent"]["id"], "sales");
+        assert!(data["extra"].get("parent").is_none());
+        assert_eq!(data["use_case_count"], 1);
+    }
+}
@@ -0,0 +1,436 @@
+//! AsciiDoc output: the markdown rendered by the methodology templates,
+//! converted for `generation.output_format = "asciidoc"`.
+//!
+//! Headings, lists, tables, code blocks, quotes, links, images and footnotes
+//! map to their AsciiDoc counterparts. Links to other AsciiDoc documents
+//! become cross references (`xref:`), Mermaid and PlantUML code blocks become
+//! diagram blocks for asciidoctor-diagram, and raw HTML is passed through.
+
+use pulldown_cmark::{
+    Alignment, BlockQuoteKind, CodeBlockKind, Event, HeadingLevel, LinkType, Options, Parser, Tag,
+    TagEnd,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Converts a markdown document to AsciiDoc
+pub fn markdown_to_asciidoc(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+    let mut converter = Converter {
+        footnotes: footnote_texts(Parser::new_ext(markdown, options)),
+        ..Converter::default()
+    };
+    for event in Parser::new_ext(markdown, options) {
+        converter.event(event);
+    }
+    let mut out = converter.out.trim_end().to_string();
+    out.push('\n');
+    out
+}
+
+/// Text of every footnote definition, by label
+fn footnote_texts<'a>(events: impl Iterator<Item = Event<'a>>) -> HashMap<String, String> {
+    let mut texts = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+    for event in events {
+        match event {
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                current = Some((label.to_string(), String::new()));
+            }
+            Event::End(TagEnd::FootnoteDefinition) => {
+                if let Some((label, text)) = current.take() {
+                    texts.insert(label, text.trim().to_string());
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, current)) = &mut current {
+                    current.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some((_, current)) = &mut current {
+                    current.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+    texts
+}
+
+#[derive(Default)]
+struct Converter {
+    out: String,
+    /// Nothing written since the last block opened (e.g. `____`)
+    fresh: bool,
+    /// Open lists: whether each is ordered
+    lists: Vec<bool>,
+    /// Open list items: whether each has content yet
+    items: Vec<bool>,
+    /// Delimiter of the open code block
+    code_block: Option<&'static str>,
+    /// Start offsets of link texts, image alt texts and table cells
+    captures: Vec<usize>,
+    /// Destination and title of the open links and images
+    targets: Vec<(String, String, LinkType)>,
+    table_cell: bool,
+    /// Depth inside footnote definitions, which are inlined at their reference
+    skipping: usize,
+    footnotes: HashMap<String, String>,
+    used_footnotes: HashSet<String>,
+}
+
+impl Converter {
+    fn write(&mut self, text: &str) {
+        self.out.push_str(text);
+        self.fresh = false;
+        if let Some(has_content) = self.items.last_mut() {
+            *has_content = true;
+        }
+    }
+
+    fn newline(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    /// Separates a new block from the previous one
+    fn block(&mut self) {
+        if let Some(has_content) = self.items.last().copied() {
+            // Blocks after the first one of a list item are attached with `+`
+            if has_content {
+                self.newline();
+                self.write("+\n");
+            }
+            return;
+        }
+        if self.out.is_empty() || self.fresh {
+            self.newline();
+        } else if !self.out.ends_with("\n\n") {
+            self.newline();
+            self.out.push('\n');
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        if self.skipping > 0 {
+            match event {
+                Event::Start(Tag::FootnoteDefinition(_)) => self.skipping += 1,
+                Event::End(TagEnd::FootnoteDefinition) => self.skipping -= 1,
+                _ => {}
+            }
+            return;
+        }
+
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if self.table_cell {
+                    self.write(&text.replace('|', "\\|"));
+                } else {
+                    self.write(&text);
+                }
+            }
+            Event::Code(code) => self.write(&format!("`+{}+`", code)),
+            Event::InlineMath(math) => self.write(&format!("stem:[{}]", math)),
+            Event::DisplayMath(math) => {
+                self.block();
+                self.write(&format!("[stem]\n++++\n{}\n++++\n", math));
+            }
+            Event::Html(html) => self.write(&html),
+            Event::InlineHtml(html) => {
+                let tag = html.trim().to_lowercase();
+                if matches!(tag.as_str(), "<br>" | "<br/>" | "<br />") {
+                    self.write(" +\n");
+                } else {
+                    self.write(&format!("+++{}+++", html));
+                }
+            }
+            Event::FootnoteReference(label) => {
+                let footnote = match self.footnotes.get(label.as_ref()) {
+                    Some(_) if self.used_footnotes.contains(label.as_ref()) => {
+                        format!("footnote:{}[]", label)
+                    }
+                    Some(text) => format!("footnote:{}[{}]", label, text.replace(']', "\\]")),
+                    None => format!("footnote:[{}]", label),
+                };
+                self.used_footnotes.insert(label.to_string());
+                self.write(&footnote);
+            }
+            Event::SoftBreak => self.write(if self.table_cell { " " } else { "\n" }),
+            Event::HardBreak => self.write(" +\n"),
+            Event::Rule => {
+                self.block();
+                self.write("'''\n");
+            }
+            Event::TaskListMarker(checked) => self.write(if checked { "[x] " } else { "[ ] " }),
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.block(),
+            Tag::Heading { level, .. } => {
+                self.block();
+                self.write(&format!("{} ", "=".repeat(heading_depth(level))));
+            }
+            Tag::BlockQuote(kind) => {
+                self.block();
+                if let Some(kind) = kind {
+                    self.write(&format!("[{}]\n", admonition(kind)));
+                }
+                self.write("____\n");
+                self.fresh = true;
+            }
+            Tag::CodeBlock(kind) => {
+                self.block();
+                let language = match &kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().unwrap_or("").to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                let delimiter = match language.as_str() {
+                    "mermaid" | "plantuml" => {
+                        self.write(&format!("[{}]\n", language));
+                        "...."
+                    }
+                    "" => "----",
+                    _ => {
+                        self.write(&format!("[source,{}]\n", language));
+                        "----"
+                    }
+                };
+                self.write(&format!("{}\n", delimiter));
+                self.code_block = Some(delimiter);
+            }
+            Tag::HtmlBlock => {
+                self.block();
+                self.write("++++\n");
+            }
+            Tag::List(start) => {
+                if self.items.is_empty() {
+                    self.block();
+                } else {
+                    self.newline();
+                }
+                self.lists.push(start.is_some());
+            }
+            Tag::Item => {
+                self.newline();
+                let ordered = self.lists.last().copied().unwrap_or(false);
+                let marker = if ordered { "." } else { "*" };
+                self.write(&format!("{} ", marker.repeat(self.lists.len())));
+                self.items.push(false);
+            }
+            Tag::FootnoteDefinition(_) => self.skipping = 1,
+            Tag::Table(alignments) => {
+                self.block();
+                let cols: Vec<&str> = alignments.iter().map(column_spec).collect();
+                self.write(&format!(
+                    "[cols=\"{}\", options=\"header\"]\n|===\n",
+                    cols.join(",")
+                ));
+            }
+            Tag::TableHead | Tag::TableRow => {}
+            Tag::TableCell => {
+                self.write("| ");
+                self.table_cell = true;
+                self.captures.push(self.out.len());
+            }
+            Tag::Emphasis => self.write("__"),
+            Tag::Strong => self.write("**"),
+            Tag::Strikethrough => self.write("[.line-through]#"),
+            Tag::Superscript => self.write("^"),
+            Tag::Subscript => self.write("~"),
+            Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                ..
+            }
+            | Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                ..
+            } => {
+                self.targets
+                    .push((dest_url.to_string(), title.to_string(), link_type));
+                self.captures.push(self.out.len());
+            }
+            Tag::DefinitionList
+            | Tag::DefinitionListTitle
+            | Tag::DefinitionListDefinition
+            | Tag::MetadataBlock(_) => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::Heading(_) => self.newline(),
+            TagEnd::BlockQuote(_) => {
+                self.newline();
+                self.write("____\n");
+            }
+            TagEnd::CodeBlock => {
+                self.newline();
+                let delimiter = self.code_block.take().unwrap_or("----");
+                self.write(&format!("{}\n", delimiter));
+            }
+            TagEnd::HtmlBlock => {
+                self.newline();
+                self.write("++++\n");
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+                self.newline();
+            }
+            TagEnd::Item => {
+                self.items.pop();
+                self.newline();
+            }
+            TagEnd::FootnoteDefinition => {}
+            TagEnd::Table => {
+                self.newline();
+                self.write("|===\n");
+            }
+            TagEnd::TableHead | TagEnd::TableRow => self.write("\n"),
+            TagEnd::TableCell => {
+                self.table_cell = false;
+                let start = self.captures.pop().unwrap_or(self.out.len());
+                let cell = self.out.split_off(start);
+                self.write(&format!("{} ", cell.trim()));
+            }
+            TagEnd::Emphasis => self.write("__"),
+            TagEnd::Strong => self.write("**"),
+            TagEnd::Strikethrough => self.write("#"),
+            TagEnd::Superscript => self.write("^"),
+            TagEnd::Subscript => self.write("~"),
+            TagEnd::Link => {
+                let text = self.take_capture();
+                if let Some((dest, title, link_type)) = self.targets.pop() {
+                    let link = link_macro(&dest, &title, link_type, &text);
+                    self.write(&link);
+                }
+            }
+            TagEnd::Image => {
+                let alt = self.take_capture();
+                if let Some((dest, _, _)) = self.targets.pop() {
+                    self.write(&format!("image:{}[{}]", dest, alt.replace(']', "\\]")));
+                }
+            }
+            TagEnd::DefinitionList
+            | TagEnd::DefinitionListTitle
+            | TagEnd::DefinitionListDefinition
+            | TagEnd::MetadataBlock(_) => {}
+        }
+    }
+
+    /// Removes and returns the text written since the last capture started
+    fn take_capture(&mut self) -> String {
+        let start = self.captures.pop().unwrap_or(self.out.len());
+        self.out.split_off(start)
+    }
+}
+
+fn heading_depth(level: HeadingLevel) -> usize {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+fn admonition(kind: BlockQuoteKind) -> &'static str {
+    match kind {
+        BlockQuoteKind::Note => "NOTE",
+        BlockQuoteKind::Tip => "TIP",
+        BlockQuoteKind::Important => "IMPORTANT",
+        BlockQuoteKind::Warning => "WARNING",
+        BlockQuoteKind::Caution => "CAUTION",
+    }
+}
+
+fn column_spec(alignment: &Alignment) -> &'static str {
+    match alignment {
+        Alignment::None | Alignment::Left => "<",
+        Alignment::Center => "^",
+        Alignment::Right => ">",
+    }
+}
+
+/// Link to `dest`: a cross reference for AsciiDoc documents, a link otherwise
+fn link_macro(dest: &str, title: &str, link_type: LinkType, text: &str) -> String {
+    if matches!(link_type, LinkType::Autolink | LinkType::Email) {
+        return dest.to_string();
+    }
+    let text = text.replace(']', "\\]");
+    let path = dest.split('#').next().unwrap_or(dest);
+    let external = dest.contains("://") || dest.starts_with("mailto:");
+    if !external && path.ends_with(".adoc") {
+        return format!("xref:{}[{}]", dest, text);
+    }
+    if title.is_empty() {
+        format!("link:{}[{}]", dest, text)
+    } else {
+        format!(
+            "link:{}[\"{}\",title=\"{}\"]",
+            dest,
+            text.replace('"', "\\\""),
+            title.replace('"', "\\\"")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_to_asciidoc_blocks() {
+        let markdown = "# Login\n\n**Status:** _planned_ with `mucm`\n\n\
+                        ## Steps\n\n1. Open the page\n2. Sign in\n   - with SSO\n\n\
+                        ```rust\nfn main() {}\n```\n\n\
+                        ```mermaid\nflowchart TD\n```\n\n\
+                        | Role | Goal |\n|:--|--:|\n| Admin | a \\| b |\n\n> Quote\n";
+        assert_eq!(
+            markdown_to_asciidoc(markdown),
+            "= Login\n\n\
+             **Status:** __planned__ with `+mucm+`\n\n\
+             == Steps\n\n\
+             . Open the page\n\
+             . Sign in\n\
+             ** with SSO\n\n\
+             [source,rust]\n----\nfn main() {}\n----\n\n\
+             [mermaid]\n....\nflowchart TD\n....\n\n\
+             [cols=\"<,>\", options=\"header\"]\n|===\n\
+             | Role | Goal \n\
+             | Admin | a \\| b \n\
+             |===\n\n\
+             ____\nQuote\n____\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_asciidoc_links_and_footnotes() {
+        let markdown = "See [UC-AUT-002](UC-AUT-002.adoc#main), \
+                        [Jack](../../actors/jack.md \"Teacher\")[^jack], \
+                        [site](https://example.com) and ![Status](badge.svg).\n\n\
+                        [^jack]: Jack, primary school teacher\n";
+        assert_eq!(
+            markdown_to_asciidoc(markdown),
+            "See xref:UC-AUT-002.adoc#main[UC-AUT-002], \
+             link:../../actors/jack.md[\"Jack\",title=\"Teacher\"]\
+             footnote:jack[Jack, primary school teacher], \
+             link:https://example.com[site] and image:badge.svg[Status].\n"
+        );
+    }
+}
@@ -0,0 +1,391 @@
+//! Confluence publishing: use cases pushed to Confluence pages.
+//!
+//! Each use case becomes a page titled "<ID>: <title>" under a page of its
+//! category ("<category> use cases"), itself under `[confluence] parent_page_id`
+//! or the space root. Page bodies are the generated markdown converted to
+//! Confluence storage format (XHTML). The ID of each page is handed back so it
+//! can be kept in the use case metadata: later publishes update that page, even
+//! after the use case was renamed or moved to another category.
+//!
+//! Requests to the REST API go through `curl`, with the API token read from
+//! the environment variable named by `[confluence] token_env`.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::config::{Config, ConfluenceConfig, OutputFormat, ProjectContext};
+use crate::core::infrastructure::http::{error_message, query_escape, RestClient};
+use crate::core::{MucmError, Result, UseCase};
+
+use super::front_matter::strip_front_matter;
+use super::html_exporter::{markdown_to_html, Pages};
+use super::OutputManager;
+
+/// Page operations of the Confluence REST API used for publishing
+pub trait ConfluenceApi {
+    /// ID of the page titled `title` in the space, if there is one
+    fn find_page(&self, title: &str) -> Result<Option<String>>;
+
+    /// Current version number of page `id`, or None when it no longer exists
+    fn page_version(&self, id: &str) -> Result<Option<u64>>;
+
+    /// Creates a page and returns its ID
+    fn create_page(&self, title: &str, parent_id: Option<&str>, body: &str) -> Result<String>;
+
+    /// Replaces the title, parent and body of page `id`, as version `version`
+    fn update_page(
+        &self,
+        id: &str,
+        version: u64,
+        title: &str,
+        parent_id: Option<&str>,
+        body: &str,
+    ) -> Result<()>;
+}
+
+/// A use case page written by [`ConfluencePublisher::publish`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedPage {
+    pub use_case_id: String,
+    pub page_id: String,
+    /// Whether the page was created rather than updated
+    pub created: bool,
+}
+
+/// Publisher of the generated documentation to Confluence
+pub struct ConfluencePublisher<'a> {
+    config: Config,
+    api: &'a dyn ConfluenceApi,
+}
+
+impl<'a> ConfluencePublisher<'a> {
+    /// Creates a publisher for the project described by `context`.
+    pub fn for_project(context: &ProjectContext, api: &'a dyn ConfluenceApi) -> Self {
+        Self {
+            config: context.resolved_config(),
+            api,
+        }
+    }
+
+    /// Creates or updates the pages of `use_cases` and of their categories
+    ///
+    /// The markdown must be up to date. Pages are found by the ID stored in
+    /// the use case metadata, then by title.
+    pub fn publish(&self, use_cases: &[UseCase]) -> Result<Vec<PublishedPage>> {
+        let mut use_cases: Vec<&UseCase> = use_cases.iter().collect();
+        use_cases.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let root = Some(self.config.confluence.parent_page_id.trim()).filter(|id| !id.is_empty());
+        let mut category_pages: HashMap<&str, String> = HashMap::new();
+        let mut published = Vec::new();
+        for use_case in use_cases {
+            let category_page = match category_pages.get(use_case.category.as_str()) {
+                Some(id) => id.clone(),
+                None => {
+                    let title = format!("{} use cases", use_case.category);
+                    let body = "<p><ac:structured-macro ac:name=\"children\" /></p>";
+                    let id = self.write_page(None, &title, root, body)?.0;
+                    category_pages.insert(use_case.category.as_str(), id.clone());
+                    id
+                }
+            };
+
+            let markdown = std::fs::read_to_string(self.markdown_path(use_case)).map_err(|e| {
+                MucmError::Io(e).context(format!("Failed to read the markdown of {}", use_case.id))
+            })?;
+            let title = format!("{}: {}", use_case.id, use_case.title);
+            let (page_id, created) = self.write_page(
+                use_case.metadata.confluence_page_id.as_deref(),
+                &title,
+                Some(&category_page),
+                &storage_format(strip_front_matter(&markdown)),
+            )?;
+            published.push(PublishedPage {
+                use_case_id: use_case.id.clone(),
+                page_id,
+                created,
+            });
+        }
+        Ok(published)
+    }
+
+    /// Updates the page `known_id`, or else the page titled `title`, or
+    /// creates it; returns its ID and whether it was created
+    fn write_page(
+        &self,
+        known_id: Option<&str>,
+        title: &str,
+        parent_id: Option<&str>,
+        body: &str,
+    ) -> Result<(String, bool)> {
+        let existing = match known_id {
+            Some(id) => self
+                .api
+                .page_version(id)?
+                .map(|version| (id.to_string(), version)),
+            None => None,
+        };
+        let existing = match existing {
+            Some(existing) => Some(existing),
+            None => match self.api.find_page(title)? {
+                Some(id) => self.api.page_version(&id)?.map(|version| (id, version)),
+                None => None,
+            },
+        };
+
+        match existing {
+            Some((id, version)) => {
+                self.api
+                    .update_page(&id, version + 1, title, parent_id, body)?;
+                Ok((id, false))
+            }
+            None => Ok((self.api.create_page(title, parent_id, body)?, true)),
+        }
+    }
+
+    /// Generated markdown file of a use case (its primary view)
+    fn markdown_path(&self, use_case: &UseCase) -> PathBuf {
+        let filename = OutputManager::primary_filename(use_case, OutputFormat::Markdown);
+        OutputManager::document_path(&self.config, use_case, &filename)
+    }
+}
+
+/// Confluence storage format of a generated markdown document
+///
+/// The leading level-one heading is dropped, as Confluence shows the page title.
+fn storage_format(markdown: &str) -> String {
+    let html = markdown_to_html(markdown, "", &Pages::new());
+    let html = match html.strip_prefix("<h1>") {
+        Some(rest) => rest
+            .split_once("</h1>")
+            .map_or(html.as_str(), |(_, rest)| rest.trim_start()),
+        None => html.as_str(),
+    };
+    // Storage format is XML: void elements written by templates must be closed
+    html.replace("<br>", "<br />").replace("<hr>", "<hr />")
+}
+
+/// [`ConfluenceApi`] over the REST API, calling `curl`
+pub struct CurlConfluenceApi {
+    client: RestClient,
+    space_key: String,
+}
+
+impl CurlConfluenceApi {
+    /// Creates a client from the `[confluence]` settings
+    ///
+    /// # Errors
+    /// Returns an error when a setting is missing or the token variable is not set.
+    pub fn new(config: &ConfluenceConfig) -> Result<Self> {
+        for (key, value) in [
+            ("base_url", &config.base_url),
+            ("space_key", &config.space_key),
+            ("user", &config.user),
+        ] {
+            if value.trim().is_empty() {
+                return Err(MucmError::Validation(format!(
+                    "Set confluence.{} in the configuration to publish to Confluence",
+                    key
+                )));
+            }
+        }
+        Ok(Self {
+            client: RestClient::from_env(
+                "Confluence",
+                &config.base_url,
+                &config.user,
+                &config.token_env,
+            )?,
+            space_key: config.space_key.trim().to_string(),
+        })
+    }
+
+    /// Request body of a page
+    fn page_body(&self, title: &str, parent_id: Option<&str>, body: &str) -> Value {
+        let mut page = json!({
+            "type": "page",
+            "title": title,
+            "space": { "key": self.space_key },
+            "body": { "storage": { "value": body, "representation": "storage" } },
+        });
+        if let Some(parent_id) = parent_id {
+            page["ancestors"] = json!([{ "id": parent_id }]);
+        }
+        page
+    }
+}
+
+impl ConfluenceApi for CurlConfluenceApi {
+    fn find_page(&self, title: &str) -> Result<Option<String>> {
+        let path = format!(
+            "/rest/api/content?type=page&spaceKey={}&title={}",
+            query_escape(&self.space_key),
+            query_escape(title)
+        );
+        let response = self.client.expect("GET", &path, None)?;
+        Ok(response["results"][0]["id"].as_str().map(str::to_string))
+    }
+
+    fn page_version(&self, id: &str) -> Result<Option<u64>> {
+        let path = format!("/rest/api/content/{}?expand=version", query_escape(id));
+        let (status, response) = self.client.request("GET", &path, None)?;
+        if status == 404 {
+            return Ok(None);
+        }
+        if !(200..300).contains(&status) {
+            return Err(MucmError::Validation(format!(
+                "Confluence answered {} for page {}: {}",
+                status,
+                id,
+                error_message(&response)
+            )));
+        }
+        Ok(response["version"]["number"].as_u64())
+    }
+
+    fn create_page(&self, title: &str, parent_id: Option<&str>, body: &str) -> Result<String> {
+        let page = self.page_body(title, parent_id, body);
+        let response = self
+            .client
+            .expect("POST", "/rest/api/content", Some(&page))?;
+        response["id"].as_str().map(str::to_string).ok_or_else(|| {
+            MucmError::Validation(format!("Confluence did not return the ID of '{}'", title))
+        })
+    }
+
+    fn update_page(
+        &self,
+        id: &str,
+        version: u64,
+        title: &str,
+        parent_id: Option<&str>,
+        body: &str,
+    ) -> Result<()> {
+        let mut page = self.page_body(title, parent_id, body);
+        page["id"] = json!(id);
+        page["version"] = json!({ "number": version });
+        let path = format!("/rest/api/content/{}", query_escape(id));
+        self.client.expect("PUT", &path, Some(&page))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::path::Path;
+
+    /// A page as (ID, title, parent, body, version)
+    type FakePage = (String, String, Option<String>, String, u64);
+
+    /// In-memory Confluence
+    #[derive(Default)]
+    struct FakeConfluence {
+        pages: RefCell<Vec<FakePage>>,
+    }
+
+    impl ConfluenceApi for FakeConfluence {
+        fn find_page(&self, title: &str) -> Result<Option<String>> {
+            let pages = self.pages.borrow();
+            Ok(pages.iter().find(|p| p.1 == title).map(|p| p.0.clone()))
+        }
+
+        fn page_version(&self, id: &str) -> Result<Option<u64>> {
+            let pages = self.pages.borrow();
+            Ok(pages.iter().find(|p| p.0 == id).map(|p| p.4))
+        }
+
+        fn create_page(&self, title: &str, parent_id: Option<&str>, body: &str) -> Result<String> {
+            let mut pages = self.pages.borrow_mut();
+            let id = (pages.len() + 100).to_string();
+            pages.push((
+                id.clone(),
+                title.to_string(),
+                parent_id.map(str::to_string),
+                body.to_string(),
+                1,
+            ));
+            Ok(id)
+        }
+
+        fn update_page(
+            &self,
+            id: &str,
+            version: u64,
+            title: &str,
+            parent_id: Option<&str>,
+            body: &str,
+        ) -> Result<()> {
+            let mut pages = self.pages.borrow_mut();
+            let page = pages.iter_mut().find(|p| p.0 == id).unwrap();
+            assert_eq!(version, page.4 + 1);
+            *page = (
+                id.to_string(),
+                title.to_string(),
+                parent_id.map(str::to_string),
+                body.to_string(),
+                version,
+            );
+            Ok(())
+        }
+    }
+
+    fn publisher<'a>(dir: &Path, api: &'a FakeConfluence) -> ConfluencePublisher<'a> {
+        let mut config = Config::default();
+        config.directories.use_case_dir = dir.to_string_lossy().to_string();
+        config.confluence.parent_page_id = "1".to_string();
+        ConfluencePublisher { config, api }
+    }
+
+    fn use_case(dir: &Path, id: &str, title: &str) -> UseCase {
+        let use_case = UseCase::builder(id, title)
+            .category("Auth")
+            .build()
+            .unwrap();
+        let filename = OutputManager::canonical_filename(&use_case, OutputFormat::Markdown);
+        std::fs::create_dir_all(dir.join("auth")).unwrap();
+        std::fs::write(
+            dir.join("auth").join(filename),
+            format!("# {}\n\nLine one<br>line two\n", title),
+        )
+        .unwrap();
+        use_case
+    }
+
+    #[test]
+    fn test_publish_creates_then_updates_pages() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let api = FakeConfluence::default();
+        let publisher = publisher(dir.path(), &api);
+        let mut use_cases = vec![
+            use_case(dir.path(), "UC-AUT-001", "Login"),
+            use_case(dir.path(), "UC-AUT-002", "Logout"),
+        ];
+
+        let published = publisher.publish(&use_cases).unwrap();
+        assert_eq!(published.len(), 2);
+        assert!(published.iter().all(|page| page.created));
+        {
+            let pages = api.pages.borrow();
+            assert_eq!(pages.len(), 3);
+            assert_eq!(pages[0].1, "Auth use cases");
+            assert_eq!(pages[0].2.as_deref(), Some("1"));
+            assert_eq!(pages[1].1, "UC-AUT-001: Login");
+            assert_eq!(pages[1].2.as_deref(), Some(pages[0].0.as_str()));
+            assert_eq!(pages[1].3, "<p>Line one<br />line two</p>\n");
+        }
+
+        // Renamed use cases keep their page through the stored ID
+        use_cases[0].metadata.confluence_page_id = Some(published[0].page_id.clone());
+        use_cases[0].title = "Sign in".to_string();
+        let republished = publisher.publish(&use_cases).unwrap();
+        assert!(republished.iter().all(|page| !page.created));
+        assert_eq!(republished[0].page_id, published[0].page_id);
+        let pages = api.pages.borrow();
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[1].1, "UC-AUT-001: Sign in");
+        assert_eq!(pages[1].4, 2);
+    }
+}
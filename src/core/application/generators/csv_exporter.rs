@@ -0,0 +1,124 @@
+//! CSV export: one row per use case, for spreadsheets and dashboards.
+//!
+//! Columns: ID, title, category, priority, status, scenario count, and the
+//! enabled views as `methodology:level` separated by `; `. Fields are quoted
+//! following RFC 4180 when they contain commas, quotes, or line breaks.
+
+use crate::core::Result;
+use std::path::Path;
+
+use crate::core::UseCase;
+
+/// Header row of the matrix
+const HEADER: [&str; 7] = [
+    "ID",
+    "Title",
+    "Category",
+    "Priority",
+    "Status",
+    "Scenarios",
+    "Views",
+];
+
+/// Exporter of the use case matrix to CSV
+pub struct CsvExporter;
+
+impl CsvExporter {
+    /// Writes the matrix of `use_cases` to `output`
+    pub fn export(use_cases: &[UseCase], output: &Path) -> Result<()> {
+        if let Some(dir) = output.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(output, Self::matrix(use_cases))?;
+        Ok(())
+    }
+
+    /// The CSV document, with use cases sorted by ID
+    pub fn matrix(use_cases: &[UseCase]) -> String {
+        let mut sorted: Vec<&UseCase> = use_cases.iter().collect();
+        sorted.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut csv = row(HEADER.iter().map(|column| column.to_string()));
+        for use_case in sorted {
+            csv.push_str(&row([
+                use_case.id.clone(),
+                use_case.title.clone(),
+                use_case.category.clone(),
+                use_case.priority.to_string(),
+                use_case.status().display_name().to_string(),
+                use_case.scenarios.len().to_string(),
+                view_list(use_case),
+            ]));
+        }
+        csv
+    }
+}
+
+/// Enabled views as `methodology:level`, separated by `; `
+pub(super) fn view_list(use_case: &UseCase) -> String {
+    use_case
+        .enabled_views()
+        .map(|view| format!("{}:{}", view.methodology, view.level))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// One CSV line
+fn row(fields: impl IntoIterator<Item = String>) -> String {
+    let fields: Vec<String> = fields.into_iter().map(|field| quote(&field)).collect();
+    format!("{}\n", fields.join(","))
+}
+
+/// A field, quoted when it holds a separator, a quote, or a line break
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MethodologyView, Scenario, ScenarioType};
+
+    #[test]
+    fn test_matrix_has_one_quoted_row_per_use_case() {
+        let mut login = UseCase::new(
+            "UC-AUT-002".to_string(),
+            "Log in, \"quickly\"".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "high".to_string(),
+        )
+        .unwrap();
+        login.add_view(MethodologyView::new("feature", "normal"));
+        login.add_view(MethodologyView::new("business", "advanced"));
+        login.add_scenario(Scenario::new(
+            login.next_scenario_id(),
+            "Valid credentials".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        ));
+        let logout = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Log out".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "low".to_string(),
+        )
+        .unwrap();
+
+        let csv = CsvExporter::matrix(&[login, logout]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "ID,Title,Category,Priority,Status,Scenarios,Views",
+                "UC-AUT-001,Log out,Auth,LOW,PLANNED,0,",
+                "UC-AUT-002,\"Log in, \"\"quickly\"\"\",Auth,HIGH,PLANNED,1,feature:normal; business:advanced",
+            ]
+        );
+    }
+}
@@ -0,0 +1,366 @@
+//! Diagrams for use case documentation.
+//!
+//! Actor, flow and sequence diagrams are built once into a format-neutral
+//! model ([`Diagram`]) and then rendered as Mermaid or PlantUML code blocks,
+//! depending on `generation.diagram_format`.
+
+use crate::config::DiagramFormat;
+use crate::core::{Actor, Scenario, UseCase};
+
+/// Someone or something taking part in a diagram
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Participant {
+    pub name: String,
+    /// Humans are drawn as stick figures, systems as boxes
+    pub human: bool,
+}
+
+/// A message between two participants, by index into the participant list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub from: usize,
+    pub to: usize,
+    pub text: String,
+}
+
+/// A branch of the flow diagram: one scenario of the use case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Branch {
+    pub title: String,
+    /// Scenario type, e.g. "happy path"
+    pub label: String,
+}
+
+/// Format-neutral diagram model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagram {
+    /// Actors taking part in a use case
+    Actors {
+        use_case: String,
+        actors: Vec<Participant>,
+    },
+    /// A use case branching into its scenarios
+    Flow {
+        use_case: String,
+        branches: Vec<Branch>,
+    },
+    /// The steps of one scenario as messages between its actors
+    Sequence {
+        participants: Vec<Participant>,
+        messages: Vec<Message>,
+    },
+}
+
+impl Diagram {
+    /// Actor diagram of every actor appearing in the use case's steps
+    pub fn actors(use_case: &UseCase) -> Self {
+        let mut actors = Vec::new();
+        for step in use_case.scenarios.iter().flat_map(|s| &s.steps) {
+            for actor in std::iter::once(&step.actor).chain(step.receiver.as_ref()) {
+                participant_index(&mut actors, actor);
+            }
+        }
+        Diagram::Actors {
+            use_case: use_case.title.clone(),
+            actors,
+        }
+    }
+
+    /// Flow diagram of the use case and its scenarios, in the given order
+    pub fn flow(use_case: &UseCase) -> Self {
+        Diagram::Flow {
+            use_case: use_case.title.clone(),
+            branches: use_case
+                .scenarios
+                .iter()
+                .map(|scenario| Branch {
+                    title: scenario.title.clone(),
+                    label: scenario.scenario_type.to_string().replace('_', " "),
+                })
+                .collect(),
+        }
+    }
+
+    /// Sequence diagram of a scenario's steps
+    ///
+    /// Steps without a receiver are sent to the System, or to the actor itself
+    /// when the System performs them.
+    pub fn sequence(scenario: &Scenario) -> Self {
+        let mut participants = Vec::new();
+        let messages = scenario
+            .steps
+            .iter()
+            .map(|step| {
+                let from = participant_index(&mut participants, &step.actor);
+                let receiver = step.receiver.clone().unwrap_or(Actor::System);
+                let to = participant_index(&mut participants, &receiver);
+                let text = format!("{} {}", step.action, step.description);
+                Message {
+                    from,
+                    to,
+                    text: text.trim().to_string(),
+                }
+            })
+            .collect();
+        Diagram::Sequence {
+            participants,
+            messages,
+        }
+    }
+
+    /// Whether the diagram has nothing to show
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Diagram::Actors { actors, .. } => actors.is_empty(),
+            Diagram::Flow { branches, .. } => branches.is_empty(),
+            Diagram::Sequence { messages, .. } => messages.is_empty(),
+        }
+    }
+
+    /// Render as a fenced code block; None for `DiagramFormat::None` or an empty diagram
+    pub fn render(&self, format: DiagramFormat) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        match format {
+            DiagramFormat::None => None,
+            DiagramFormat::Mermaid => Some(format!("```mermaid\n{}```", self.to_mermaid())),
+            DiagramFormat::Plantuml => Some(format!(
+                "```plantuml\n@startuml\n{}@enduml\n```",
+                self.to_plantuml()
+            )),
+        }
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut out = String::new();
+        match self {
+            Diagram::Actors { use_case, actors } => {
+                out.push_str("flowchart LR\n");
+                for (index, actor) in actors.iter().enumerate() {
+                    let name = mermaid_text(&actor.name);
+                    if actor.human {
+                        out.push_str(&format!("    A{}((\"{}\"))\n", index + 1, name));
+                    } else {
+                        out.push_str(&format!("    A{}[\"{}\"]\n", index + 1, name));
+                    }
+                }
+                out.push_str(&format!("    UC([\"{}\"])\n", mermaid_text(use_case)));
+                for index in 0..actors.len() {
+                    out.push_str(&format!("    A{} --- UC\n", index + 1));
+                }
+            }
+            Diagram::Flow { use_case, branches } => {
+                out.push_str("flowchart TD\n");
+                out.push_str(&format!("    UC([\"{}\"])\n", mermaid_text(use_case)));
+                for (index, branch) in branches.iter().enumerate() {
+                    out.push_str(&format!(
+                        "    UC -->|{}| S{}[\"{}\"]\n",
+                        mermaid_text(&branch.label),
+                        index + 1,
+                        mermaid_text(&branch.title)
+                    ));
+                }
+            }
+            Diagram::Sequence {
+                participants,
+                messages,
+            } => {
+                out.push_str("sequenceDiagram\n");
+                for (index, participant) in participants.iter().enumerate() {
+                    let kind = if participant.human {
+                        "actor"
+                    } else {
+                        "participant"
+                    };
+                    out.push_str(&format!(
+                        "    {} P{} as {}\n",
+                        kind,
+                        index + 1,
+                        mermaid_text(&participant.name)
+                    ));
+                }
+                for message in messages {
+                    out.push_str(&format!(
+                        "    P{}->>P{}: {}\n",
+                        message.from + 1,
+                        message.to + 1,
+                        mermaid_text(&message.text)
+                    ));
+                }
+            }
+        }
+        out
+    }
+
+    fn to_plantuml(&self) -> String {
+        let mut out = String::new();
+        match self {
+            Diagram::Actors { use_case, actors } => {
+                out.push_str("left to right direction\n");
+                for (index, actor) in actors.iter().enumerate() {
+                    let kind = if actor.human { "actor" } else { "rectangle" };
+                    out.push_str(&format!(
+                        "{} \"{}\" as A{}\n",
+                        kind,
+                        plantuml_text(&actor.name),
+                        index + 1
+                    ));
+                }
+                out.push_str(&format!("usecase \"{}\" as UC\n", plantuml_text(use_case)));
+                for index in 0..actors.len() {
+                    out.push_str(&format!("A{} -- UC\n", index + 1));
+                }
+            }
+            Diagram::Flow { use_case, branches } => {
+                out.push_str(&format!("usecase \"{}\" as UC\n", plantuml_text(use_case)));
+                for (index, branch) in branches.iter().enumerate() {
+                    out.push_str(&format!(
+                        "rectangle \"{}\" as S{}\nUC --> S{} : {}\n",
+                        plantuml_text(&branch.title),
+                        index + 1,
+                        index + 1,
+                        plantuml_text(&branch.label)
+                    ));
+                }
+            }
+            Diagram::Sequence {
+                participants,
+                messages,
+            } => {
+                for (index, participant) in participants.iter().enumerate() {
+                    let kind = if participant.human {
+                        "actor"
+                    } else {
+                        "participant"
+                    };
+                    out.push_str(&format!(
+                        "{} \"{}\" as P{}\n",
+                        kind,
+                        plantuml_text(&participant.name),
+                        index + 1
+                    ));
+                }
+                for message in messages {
+                    out.push_str(&format!(
+                        "P{} -> P{} : {}\n",
+                        message.from + 1,
+                        message.to + 1,
+                        plantuml_text(&message.text)
+                    ));
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Index of an actor in the participant list, adding it on first appearance
+fn participant_index(participants: &mut Vec<Participant>, actor: &Actor) -> usize {
+    if let Some(index) = participants.iter().position(|p| p.name == actor.name()) {
+        return index;
+    }
+    participants.push(Participant {
+        name: actor.name().to_string(),
+        human: !actor.is_system(),
+    });
+    participants.len() - 1
+}
+
+/// Escapes characters that end a Mermaid label or statement
+fn mermaid_text(text: &str) -> String {
+    // `;` first, as the entity codes end in one
+    text.replace(['\n', '\r'], " ")
+        .replace(';', "#59;")
+        .replace('"', "#quot;")
+}
+
+/// Keeps a PlantUML label on one line and inside its quotes
+fn plantuml_text(text: &str) -> String {
+    text.replace(['\n', '\r'], " ").replace('"', "'")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::ScenarioStep;
+    use crate::core::ScenarioType;
+
+    fn login_scenario() -> Scenario {
+        let mut scenario = Scenario::new(
+            "UC-AUT-001-S01".to_string(),
+            "Successful login".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        );
+        scenario.add_step(ScenarioStep::new(
+            1,
+            Actor::User,
+            "enters".to_string(),
+            "email; password".to_string(),
+        ));
+        scenario.add_step(ScenarioStep::with_receiver(
+            2,
+            Actor::System,
+            Actor::Database,
+            "loads".to_string(),
+            "the \"account\"".to_string(),
+        ));
+        scenario
+    }
+
+    #[test]
+    fn test_sequence_renders_in_both_formats() {
+        let diagram = Diagram::sequence(&login_scenario());
+
+        let mermaid = diagram.render(DiagramFormat::Mermaid).unwrap();
+        assert!(mermaid.starts_with("```mermaid\nsequenceDiagram\n"));
+        assert!(mermaid.contains("    actor P1 as User\n"));
+        assert!(mermaid.contains("    participant P3 as Database\n"));
+        assert!(mermaid.contains("    P1->>P2: enters email#59; password\n"));
+        assert!(mermaid.contains("    P2->>P3: loads the #quot;account#quot;\n"));
+
+        let plantuml = diagram.render(DiagramFormat::Plantuml).unwrap();
+        assert!(plantuml.starts_with("```plantuml\n@startuml\n"));
+        assert!(plantuml.contains("actor \"User\" as P1\n"));
+        assert!(plantuml.contains("P2 -> P3 : loads the 'account'\n"));
+        assert!(plantuml.ends_with("@enduml\n```"));
+
+        assert_eq!(diagram.render(DiagramFormat::None), None);
+    }
+
+    #[test]
+    fn test_actor_and_flow_diagrams_share_the_model() {
+        let mut use_case = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        assert!(Diagram::actors(&use_case).is_empty());
+        use_case.add_scenario(login_scenario());
+
+        let actors = Diagram::actors(&use_case);
+        let Diagram::Actors { actors: list, .. } = &actors else {
+            panic!("expected an actor diagram");
+        };
+        let names: Vec<&str> = list.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["User", "System", "Database"]);
+        assert!(actors
+            .render(DiagramFormat::Plantuml)
+            .unwrap()
+            .contains("A1 -- UC\n"));
+
+        let flow = Diagram::flow(&use_case);
+        assert!(flow
+            .render(DiagramFormat::Mermaid)
+            .unwrap()
+            .contains("UC -->|happy path| S1[\"Successful login\"]"));
+        assert!(flow
+            .render(DiagramFormat::Plantuml)
+            .unwrap()
+            .contains("UC --> S1 : happy path"));
+    }
+}
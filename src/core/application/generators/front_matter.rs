@@ -0,0 +1,189 @@
+//! YAML front matter of generated markdown, for Docusaurus and Hugo sites.
+//!
+//! With `generation.front_matter.enabled`, every generated markdown document
+//! starts with a `---` delimited YAML block. Use case documents get a title,
+//! sidebar position, slug, tags and extra keys from patterns with `{id}`,
+//! `{title}`, `{category}`, `{category_slug}`, `{priority}`, `{status}`,
+//! `{methodology}`, `{level}` and `{view}` placeholders; overview and actor
+//! pages get a title only. The exporters that read the generated markdown back
+//! strip the block with [`strip_front_matter`].
+
+use crate::core::Result;
+use serde_yaml::{Mapping, Value};
+
+use crate::config::FrontMatterConfig;
+use crate::core::{to_snake_case, MethodologyView, UseCase};
+
+/// Front matter of a use case document, or `None` when disabled
+///
+/// `methodology` and `view` are the ones the document is rendered with.
+pub fn use_case_front_matter(
+    config: &FrontMatterConfig,
+    use_case: &UseCase,
+    methodology: &str,
+    view: Option<&MethodologyView>,
+) -> Result<Option<String>> {
+    if !config.enabled {
+        return Ok(None);
+    }
+    let level = view.map_or("normal", |v| v.level.as_str());
+    let status = serde_json::to_value(use_case.status())?;
+    let placeholders = [
+        ("{id}", use_case.id.clone()),
+        ("{title}", use_case.title.clone()),
+        ("{category}", use_case.category.clone()),
+        ("{category_slug}", to_snake_case(&use_case.category)),
+        ("{priority}", use_case.priority.to_string().to_lowercase()),
+        ("{status}", status.as_str().unwrap_or_default().to_string()),
+        ("{methodology}", methodology.to_string()),
+        ("{level}", level.to_string()),
+        ("{view}", format!("{}-{}", methodology, level)),
+    ];
+    let fill = |pattern: &str| {
+        placeholders
+            .iter()
+            .fold(pattern.to_string(), |text, (placeholder, value)| {
+                text.replace(placeholder, value)
+            })
+    };
+
+    let mut fields = Mapping::new();
+    fields.insert("title".into(), fill(&config.title).into());
+    if config.sidebar_position {
+        if let Some(position) = id_number(&use_case.id) {
+            fields.insert("sidebar_position".into(), position.into());
+        }
+    }
+    if let Some(slug) = &config.slug {
+        fields.insert("slug".into(), fill(slug).into());
+    }
+    let tags: Vec<Value> = config
+        .tags
+        .iter()
+        .map(|tag| fill(tag))
+        .filter(|tag| !tag.trim().is_empty())
+        .map(Value::from)
+        .collect();
+    if !tags.is_empty() {
+        fields.insert("tags".into(), Value::Sequence(tags));
+    }
+    for (key, pattern) in &config.extra {
+        fields.insert(key.as_str().into(), scalar(fill(pattern)));
+    }
+    block(&fields).map(Some)
+}
+
+/// Front matter of an overview or actor page titled `title`, or `None` when disabled
+pub fn page_front_matter(config: &FrontMatterConfig, title: &str) -> Result<Option<String>> {
+    if !config.enabled {
+        return Ok(None);
+    }
+    let mut fields = Mapping::new();
+    fields.insert("title".into(), title.into());
+    block(&fields).map(Some)
+}
+
+/// `markdown` without its leading front matter block, if it has one
+pub fn strip_front_matter(markdown: &str) -> &str {
+    let Some(rest) = markdown
+        .strip_prefix("---\n")
+        .or_else(|| markdown.strip_prefix("---\r\n"))
+    else {
+        return markdown;
+    };
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        offset += line.len();
+        if line.trim_end() == "---" {
+            return rest[offset..].trim_start_matches(['\r', '\n']);
+        }
+    }
+    markdown
+}
+
+/// `---` delimited YAML of `fields`, followed by a blank line
+fn block(fields: &Mapping) -> Result<String> {
+    Ok(format!("---\n{}---\n\n", serde_yaml::to_string(fields)?))
+}
+
+/// Number at the end of a use case ID (`UC-AUT-012` is 12)
+fn id_number(id: &str) -> Option<u64> {
+    let digits = id.len() - id.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    id[id.len() - digits..].parse().ok()
+}
+
+/// Extra value as YAML: integers and booleans typed, anything else a string
+fn scalar(value: String) -> Value {
+    if let Ok(number) = value.parse::<i64>() {
+        return number.into();
+    }
+    match value.as_str() {
+        "true" => true.into(),
+        "false" => false.into(),
+        _ => value.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Priority;
+    use std::collections::BTreeMap;
+
+    fn use_case() -> UseCase {
+        UseCase::builder("UC-AUT-012", "Log in: fast")
+            .category("User Auth")
+            .priority(Priority::High)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_use_case_front_matter_fills_patterns() {
+        let config = FrontMatterConfig {
+            enabled: true,
+            sidebar_position: true,
+            slug: Some("/use-cases/{category_slug}/{id}".to_string()),
+            tags: vec!["{category}".to_string(), "{priority}".to_string()],
+            extra: BTreeMap::from([
+                ("draft".to_string(), "false".to_string()),
+                ("weight".to_string(), "30".to_string()),
+            ]),
+            ..FrontMatterConfig::default()
+        };
+        let view = MethodologyView::new("feature", "simple");
+
+        let front_matter = use_case_front_matter(&config, &use_case(), "feature", Some(&view))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            front_matter,
+            "---\n\
+             title: 'UC-AUT-012: Log in: fast'\n\
+             sidebar_position: 12\n\
+             slug: /use-cases/user_auth/UC-AUT-012\n\
+             tags:\n\
+             - User Auth\n\
+             - high\n\
+             draft: false\n\
+             weight: 30\n\
+             ---\n\n"
+        );
+        assert_eq!(
+            strip_front_matter(&format!("{}# Log in\n", front_matter)),
+            "# Log in\n"
+        );
+    }
+
+    #[test]
+    fn test_front_matter_disabled_by_default() {
+        let config = FrontMatterConfig::default();
+
+        assert!(use_case_front_matter(&config, &use_case(), "feature", None)
+            .unwrap()
+            .is_none());
+        assert!(page_front_matter(&config, "Overview").unwrap().is_none());
+        assert_eq!(strip_front_matter("# Title\n---\n"), "# Title\n---\n");
+    }
+}
@@ -0,0 +1,252 @@
+//! Gherkin feature files generated from use cases (`[generation.gherkin]`).
+//!
+//! Each use case becomes `<directory>/<category>/<ID>.feature`: the use case is
+//! the `Feature`, its preconditions the `Background`, and each scenario a
+//! `Scenario` tagged with its type. Steps keep the Given/When/Then keyword they
+//! were imported with; other steps get `When` for people and `Then` for the
+//! system, repeated keywords becoming `And`. The layout is the one
+//! `mucm import gherkin` reads, so a generated feature imports back into the
+//! same use case.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, GherkinConfig, ProjectContext};
+use crate::core::domain::ScenarioStep;
+use crate::core::file_operations::FileOperations;
+use crate::core::utils::normalize_line_endings;
+use crate::core::{Result, Scenario, ScenarioType, UseCase};
+
+use super::with_scenario_order;
+
+/// Generator of Gherkin feature files
+pub struct GherkinGenerator {
+    config: Config,
+    directory: PathBuf,
+}
+
+impl GherkinGenerator {
+    /// Creates a feature file generator for the project described by `context`.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        let config = context.config().clone();
+        let directory = context.resolve(&config.generation.gherkin.directory);
+        Self { config, directory }
+    }
+
+    /// Settings of the generator
+    pub fn settings(&self) -> &GherkinConfig {
+        &self.config.generation.gherkin
+    }
+
+    /// Path of the feature file of `use_case`
+    pub fn file_path(&self, use_case: &UseCase) -> PathBuf {
+        self.directory
+            .join(&use_case.category)
+            .join(format!("{}.feature", use_case.id))
+    }
+
+    /// Writes the feature file of `use_case`, returning its path
+    pub fn generate(&self, use_case: &UseCase) -> Result<PathBuf> {
+        let path = self.file_path(use_case);
+        std::fs::create_dir_all(path.parent().unwrap_or(Path::new(".")))?;
+        let eol = self.config.generation.line_endings.as_str();
+        let feature = normalize_line_endings(&self.feature(use_case), eol);
+        FileOperations::write_streamed(&path, |writer| {
+            writer.write_all(feature.as_bytes())?;
+            Ok(())
+        })?;
+        Ok(path)
+    }
+
+    /// The feature file content of `use_case`
+    pub fn feature(&self, use_case: &UseCase) -> String {
+        let use_case = with_scenario_order(use_case, self.config.generation.scenario_order);
+        let mut out = String::new();
+        let _ = writeln!(out, "@{}", use_case.id);
+        let _ = writeln!(out, "Feature: {}", use_case.title);
+        write_text(&mut out, &use_case.description, "  ");
+
+        if !use_case.preconditions.is_empty() {
+            out.push_str("\n  Background:\n");
+            for (index, condition) in use_case.preconditions.iter().enumerate() {
+                let keyword = if index == 0 { "Given" } else { "And" };
+                let _ = writeln!(out, "    {} {}", keyword, condition.text);
+            }
+        }
+
+        for scenario in &use_case.scenarios {
+            out.push('\n');
+            write_scenario(&mut out, scenario);
+        }
+        out
+    }
+}
+
+fn write_scenario(out: &mut String, scenario: &Scenario) {
+    let mut tags = vec![format!("@{}", type_tag(&scenario.scenario_type))];
+    tags.extend(scenario.tags.iter().map(|tag| format!("@{}", tag)));
+    let _ = writeln!(out, "  {}", tags.join(" "));
+    let _ = writeln!(out, "  Scenario: {}", scenario.title);
+    write_text(out, &scenario.description, "    ");
+
+    let mut previous: Option<&str> = None;
+    for step in &scenario.steps {
+        let keyword = step_keyword(step);
+        let written = match (previous, keyword) {
+            (Some(previous), "Given" | "When" | "Then") if previous == keyword => "And",
+            _ => keyword,
+        };
+        if matches!(keyword, "Given" | "When" | "Then") {
+            previous = Some(keyword);
+        }
+        let _ = writeln!(out, "    {} {}", written, step_text(step));
+        if let Some(notes) = step.notes.as_deref().filter(|notes| !notes.is_empty()) {
+            write_argument(out, notes);
+        }
+    }
+}
+
+/// Tag of a scenario type, as understood by the Gherkin import
+fn type_tag(scenario_type: &ScenarioType) -> &'static str {
+    match scenario_type {
+        ScenarioType::HappyPath => "main",
+        ScenarioType::AlternativeFlow => "alternative",
+        ScenarioType::ExceptionFlow => "exception",
+        ScenarioType::Extension => "extension",
+    }
+}
+
+/// The step's own keyword, or `Then` for the system and `When` for anyone else
+fn step_keyword(step: &ScenarioStep) -> &str {
+    match step.keyword.as_deref() {
+        Some(keyword) => keyword,
+        None if step.actor.is_system() => "Then",
+        None => "When",
+    }
+}
+
+/// Imported steps hold their whole text in the action; others name their actor
+fn step_text(step: &ScenarioStep) -> String {
+    let text = if step.keyword.is_some() {
+        format!("{} {}", step.action, step.description)
+    } else {
+        format!("{} {} {}", step.actor, step.action, step.description)
+    };
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Step notes as a data table when every line is a table row, else a doc string
+fn write_argument(out: &mut String, notes: &str) {
+    if notes.lines().all(|line| line.trim_start().starts_with('|')) {
+        for line in notes.lines() {
+            let _ = writeln!(out, "      {}", line.trim());
+        }
+    } else {
+        out.push_str("      \"\"\"\n");
+        for line in notes.lines() {
+            let _ = writeln!(out, "      {}", line);
+        }
+        out.push_str("      \"\"\"\n");
+    }
+}
+
+/// Free text below a title, one indented line per non-empty line
+fn write_text(out: &mut String, text: &str, indent: &str) {
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let _ = writeln!(out, "{}{}", indent, line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::application::services::{apply_feature, load_features};
+    use crate::core::{Actor, Condition};
+
+    fn login() -> UseCase {
+        let mut use_case = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Log in".to_string(),
+            "Auth".to_string(),
+            "A registered user signs in".to_string(),
+            "high".to_string(),
+        )
+        .unwrap();
+        use_case.preconditions = vec![Condition::new("The user has an account".to_string())];
+        let mut scenario = Scenario::new(
+            use_case.next_scenario_id(),
+            "Valid credentials".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        );
+        scenario.tags = vec!["smoke".to_string()];
+        scenario.steps = vec![
+            ScenarioStep::new(1, Actor::User, "enters".to_string(), "email".to_string()),
+            ScenarioStep::new(
+                2,
+                Actor::User,
+                "submits".to_string(),
+                "the form".to_string(),
+            ),
+            ScenarioStep::new(
+                3,
+                Actor::System,
+                "shows".to_string(),
+                "the dashboard".to_string(),
+            ),
+        ];
+        scenario.steps[1].notes = Some("| email | a@b.c |".to_string());
+        use_case.add_scenario(scenario);
+        use_case
+    }
+
+    #[test]
+    fn test_feature_maps_steps_to_keywords() {
+        let generator = GherkinGenerator {
+            config: Config::default(),
+            directory: PathBuf::from("features"),
+        };
+        let use_case = login();
+
+        assert_eq!(
+            generator.feature(&use_case),
+            "@UC-AUT-001
+Feature: Log in
+  A registered user signs in
+
+  Background:
+    Given The user has an account
+
+  @main @smoke
+  Scenario: Valid credentials
+    When User enters email
+    And User submits the form
+      | email | a@b.c |
+    Then System shows the dashboard
+"
+        );
+        assert_eq!(
+            generator.file_path(&use_case),
+            PathBuf::from("features/Auth/UC-AUT-001.feature")
+        );
+    }
+
+    #[test]
+    fn test_generated_feature_imports_back_unchanged() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let generator = GherkinGenerator {
+            config: Config::default(),
+            directory: dir.path().to_path_buf(),
+        };
+        let use_case = login();
+        generator.generate(&use_case).unwrap();
+
+        let features = load_features(dir.path()).unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].category, "Auth");
+        let mut imported = use_case.clone();
+        apply_feature(&mut imported, &features[0]);
+        assert_eq!(generator.feature(&imported), generator.feature(&use_case));
+        assert_eq!(imported.preconditions, use_case.preconditions);
+    }
+}
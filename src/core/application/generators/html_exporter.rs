@@ -0,0 +1,328 @@
+//! HTML export: the generated markdown documentation as standalone HTML pages.
+//!
+//! The use case pages, the overview and the actor pages are converted to HTML
+//! and wrapped in the `html-page.hbs` layout, whose navigation links the
+//! overview, every category and the actors. Pages keep their place relative to
+//! each other, so links between them still work once `.md` becomes `.html`
+//! (`README.md` becomes `index.html`). Categories without an overview page of
+//! their own, and the actor directory, get a generated index page.
+
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::config::{Config, OutputFormat, ProjectContext};
+use crate::core::file_operations::FileOperations;
+use crate::core::utils::{link_path, portable_path, relative_link, start_progress};
+use crate::core::{to_snake_case, ActorEntity, ActorHierarchy, Result, TemplateEngine, UseCase};
+
+use super::front_matter::strip_front_matter;
+use super::OutputManager;
+
+/// Markdown of every exported page, by path relative to the export root
+pub(super) type Pages = BTreeMap<String, String>;
+
+/// Exporter of the generated documentation to HTML
+pub struct HtmlExporter {
+    config: Config,
+    template_engine: TemplateEngine,
+}
+
+impl HtmlExporter {
+    /// Creates an HTML exporter for the project described by `context`.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        Self {
+            config: context.resolved_config(),
+            template_engine: TemplateEngine::for_project(context),
+        }
+    }
+
+    /// Converts the generated markdown into HTML pages under `output`
+    ///
+    /// The markdown must be up to date; returns the number of pages written.
+    pub fn export(
+        &self,
+        use_cases: &[UseCase],
+        actors: &[ActorEntity],
+        output: &Path,
+    ) -> Result<usize> {
+        let use_case_dir = Path::new(&self.config.directories.use_case_dir);
+        let actor_dir = Path::new(&self.config.directories.actor_dir);
+        let root = common_dir(use_case_dir, actor_dir);
+        let use_case_dir = relative_dir(use_case_dir, &root);
+        let actor_dir = relative_dir(actor_dir, &root);
+
+        let mut pages = Pages::new();
+        read_pages(&root, &use_case_dir, None, &mut pages)?;
+        read_pages(&root, &actor_dir, Some(1), &mut pages)?;
+
+        // Navigation: overview, categories, actors
+        let mut nav = vec![(
+            "Overview".to_string(),
+            link_path(&[use_case_dir.as_str(), "README.md"]),
+        )];
+        let mut categories: BTreeMap<&str, Vec<&UseCase>> = BTreeMap::new();
+        for use_case in use_cases {
+            categories
+                .entry(use_case.category.as_str())
+                .or_default()
+                .push(use_case);
+        }
+        for (category, category_use_cases) in &categories {
+            let page = link_path(&[use_case_dir.as_str(), &to_snake_case(category), "README.md"]);
+            pages
+                .entry(page.clone())
+                .or_insert_with(|| category_index(category, category_use_cases));
+            nav.push((category.to_string(), page));
+        }
+        if !actors.is_empty() {
+            let page = link_path(&[actor_dir.as_str(), "README.md"]);
+            pages
+                .entry(page.clone())
+                .or_insert_with(|| actor_index(actors));
+            nav.push(("Actors".to_string(), page));
+        }
+        nav.retain(|(_, page)| pages.contains_key(page));
+
+        let progress = start_progress(pages.len() as u64, "Exporting");
+        for (page, markdown) in &pages {
+            let html_file = html_path(page);
+            let html_dir = parent_dir(&html_file);
+            let nav: Vec<Value> = nav
+                .iter()
+                .map(|(name, target)| {
+                    json!({
+                        "name": name,
+                        "href": relative_link(html_dir, &html_path(target)),
+                        "current": target == page,
+                    })
+                })
+                .collect();
+
+            let mut data: HashMap<String, Value> = HashMap::new();
+            data.insert("title".to_string(), json!(page_title(markdown, page)));
+            data.insert("project_name".to_string(), json!(self.config.project.name));
+            data.insert("nav".to_string(), json!(nav));
+            data.insert(
+                "content".to_string(),
+                json!(markdown_to_html(markdown, page, &pages)),
+            );
+
+            let path = output.join(&html_file);
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            FileOperations::write_streamed(&path, |writer| {
+                self.template_engine.render_html_page_to(&data, writer)
+            })?;
+            progress.inc();
+        }
+        progress.finish();
+        Ok(pages.len())
+    }
+}
+
+/// Reads the markdown files of `dir` (relative to `root`) into `pages`
+fn read_pages(root: &Path, dir: &str, max_depth: Option<usize>, pages: &mut Pages) -> Result<()> {
+    let dir_path = root.join(dir);
+    if !dir_path.is_dir() {
+        return Ok(());
+    }
+    let mut walk = WalkDir::new(&dir_path);
+    if let Some(depth) = max_depth {
+        walk = walk.max_depth(depth);
+    }
+    for entry in walk.into_iter().filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        if !entry.file_type().is_file() || path.extension().and_then(|e| e.to_str()) != Some("md") {
+            continue;
+        }
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        pages.insert(
+            normalize(&portable_path(relative)),
+            strip_front_matter(&std::fs::read_to_string(path)?).to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// Index page of a category without an overview page of its own
+fn category_index(category: &str, use_cases: &[&UseCase]) -> String {
+    let mut markdown = format!("# {}\n\n", category);
+    for use_case in use_cases {
+        let filename = OutputManager::generate_all_filenames(use_case, OutputFormat::Markdown)
+            .into_iter()
+            .next()
+            .map(|(filename, _)| filename)
+            .unwrap_or_else(|| OutputManager::canonical_filename(use_case, OutputFormat::Markdown));
+        markdown.push_str(&format!(
+            "- [{}]({}) {}\n",
+            use_case.id, filename, use_case.title
+        ));
+    }
+    markdown
+}
+
+/// Index page of the actor directory, following the actor hierarchy
+fn actor_index(actors: &[ActorEntity]) -> String {
+    let mut markdown = "# Actors\n\n".to_string();
+    for (actor, depth) in ActorHierarchy::new(actors).walk() {
+        markdown.push_str(&format!(
+            "{}- [{} {}]({}.md)\n",
+            "  ".repeat(depth),
+            actor.emoji,
+            actor.name,
+            actor.id
+        ));
+    }
+    markdown
+}
+
+/// Renders markdown to HTML, pointing links to exported pages at their HTML file
+pub(super) fn markdown_to_html(markdown: &str, page: &str, pages: &Pages) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+    let events = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: rewrite_link(dest_url, page, pages),
+            title,
+            id,
+        }),
+        event => event,
+    });
+    let mut out = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut out, events);
+    out
+}
+
+/// The link to the HTML file of an exported page, other links unchanged
+fn rewrite_link<'a>(dest: CowStr<'a>, page: &str, pages: &Pages) -> CowStr<'a> {
+    let (path, fragment) = match dest.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (&*dest, None),
+    };
+    // External, absolute and same-page links
+    if path.is_empty() || path.starts_with('/') || path.contains(':') {
+        return dest;
+    }
+    let target = normalize(&link_path(&[parent_dir(page), path]));
+    if !pages.contains_key(&target) {
+        return dest;
+    }
+    let mut link = relative_link(parent_dir(&html_path(page)), &html_path(&target));
+    if let Some(fragment) = fragment {
+        link.push('#');
+        link.push_str(fragment);
+    }
+    CowStr::from(link)
+}
+
+/// Path of the HTML file of a markdown page (`README.md` becomes `index.html`)
+fn html_path(page: &str) -> String {
+    let (dir, file) = page.rsplit_once('/').unwrap_or(("", page));
+    let file = if file == "README.md" {
+        "index.html".to_string()
+    } else {
+        format!("{}.html", file.strip_suffix(".md").unwrap_or(file))
+    };
+    link_path(&[dir, file.as_str()])
+}
+
+/// Title of a page: its first level-one heading, or its file name
+fn page_title(markdown: &str, page: &str) -> String {
+    markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .unwrap_or_else(|| {
+            Path::new(page)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default()
+        })
+}
+
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+/// Resolves `.` and `..` segments of a relative `/`-separated path
+fn normalize(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." if segments.last().is_some_and(|last| *last != "..") => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+/// Deepest directory containing both `a` and `b`
+fn common_dir(a: &Path, b: &Path) -> PathBuf {
+    a.components()
+        .zip(b.components())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a)
+        .collect()
+}
+
+/// `dir` relative to `root`, with `/` separators
+fn relative_dir(dir: &Path, root: &Path) -> String {
+    normalize(&portable_path(dir.strip_prefix(root).unwrap_or(dir)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_path_and_normalize() {
+        assert_eq!(
+            html_path("use-cases/auth/UC-AUT-001.md"),
+            "use-cases/auth/UC-AUT-001.html"
+        );
+        assert_eq!(html_path("use-cases/README.md"), "use-cases/index.html");
+        assert_eq!(
+            normalize("use-cases/auth/../../actors/./admin.md"),
+            "actors/admin.md"
+        );
+        assert_eq!(
+            common_dir(Path::new("docs/use-cases"), Path::new("docs/actors")),
+            PathBuf::from("docs")
+        );
+    }
+
+    #[test]
+    fn test_links_to_exported_pages_point_to_html() {
+        let mut pages = Pages::new();
+        pages.insert("use-cases/auth/UC-AUT-001.md".to_string(), String::new());
+        pages.insert("actors/admin.md".to_string(), String::new());
+        pages.insert("use-cases/README.md".to_string(), String::new());
+
+        let markdown = "[Login](UC-AUT-001.md#main) by [Admin](../../actors/admin.md), \
+                        see [overview](../README.md), [tests](../../tests/auth.rs) \
+                        and [site](https://example.com/a.md)";
+        let html = markdown_to_html(markdown, "use-cases/auth/UC-AUT-002.md", &pages);
+        assert!(html.contains(r#"href="UC-AUT-001.html#main""#));
+        assert!(html.contains(r#"href="../../actors/admin.html""#));
+        assert!(html.contains(r#"href="../index.html""#));
+        assert!(html.contains(r#"href="../../tests/auth.rs""#));
+        assert!(html.contains(r#"href="https://example.com/a.md""#));
+    }
+}
@@ -0,0 +1,412 @@
+//! Cross-reference links in use case documents.
+//!
+//! With `generation.autolink_ids` enabled, use case IDs mentioned in
+//! descriptions, steps, notes and conditions become relative links to the
+//! document of the referenced use case: its canonical `<ID>.md` (or `.adoc`)
+//! when a primary view is designated, otherwise the file of its first view.
+//!
+//! With `generation.autolink_actors` enabled, actor and persona names become
+//! links to their pages in the actor directory, with a short summary shown on
+//! hover and, with `generation.actor_footnotes`, in a footnote.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::core::Result;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use super::OutputManager;
+use crate::config::{Config, OutputFormat};
+use crate::core::domain::UseCaseService;
+use crate::core::utils::{link_path, relative_link};
+use crate::core::{to_snake_case, ActorEntity, RepositoryFactory, UseCase};
+
+/// Template data keys whose text is scanned for use case IDs and actor names
+const LINKED_KEYS: &[&str] = &["description", "action", "notes", "text"];
+
+/// An actor page that mentions can link to
+#[derive(Debug, Clone)]
+struct ActorTarget {
+    id: String,
+    name: String,
+    emoji: String,
+    /// Path of the page, relative to the project root
+    page: String,
+    /// Role or type, shown on hover and in footnotes
+    summary: String,
+}
+
+/// Link targets of a project: use case markdown files and actor pages
+#[derive(Debug, Clone)]
+pub struct IdLinks {
+    /// ID -> (category directory, filename)
+    targets: HashMap<String, (String, String)>,
+    /// Category code used in IDs -> category names
+    categories: BTreeMap<String, BTreeSet<String>>,
+    /// Candidate ID mentions; only known IDs are linked
+    mention: Regex,
+    /// Whether use case IDs are linked
+    link_ids: bool,
+    /// Format of the linked use case documents
+    format: OutputFormat,
+    actors: Vec<ActorTarget>,
+    /// Whole-word actor names, longest first
+    actor_mention: Option<Regex>,
+    use_case_dir: String,
+    footnotes: bool,
+}
+
+impl IdLinks {
+    /// Index the markdown files of `use_cases`
+    pub fn new(use_cases: &[UseCase]) -> Self {
+        Self::with_format(use_cases, OutputFormat::Markdown)
+    }
+
+    /// Index the documents of `use_cases` in the given output format
+    pub(crate) fn with_format(use_cases: &[UseCase], format: OutputFormat) -> Self {
+        let mut links = Self {
+            targets: HashMap::new(),
+            categories: BTreeMap::new(),
+            mention: Regex::new(r"\b[A-Za-z0-9]+(?:-[A-Za-z0-9]+)+\b").expect("valid id regex"),
+            link_ids: true,
+            format,
+            actors: Vec::new(),
+            actor_mention: None,
+            use_case_dir: String::new(),
+            footnotes: false,
+        };
+        for use_case in use_cases {
+            links.insert(use_case);
+        }
+        links
+    }
+
+    /// Link targets as selected by the project's `[generation]` settings
+    ///
+    /// Actors are only loaded when `generation.autolink_actors` is enabled.
+    pub fn for_project(config: &Config, use_cases: &[UseCase]) -> Result<Self> {
+        let mut links = Self::with_format(use_cases, config.generation.output_format);
+        links.link_ids = config.generation.autolink_ids;
+        if config.generation.autolink_actors {
+            let actors = RepositoryFactory::create_actor_repository(config)?.load_all_actors()?;
+            links = links.with_actors(
+                &actors,
+                &config.directories.actor_dir,
+                &config.directories.use_case_dir,
+                config.generation.actor_footnotes,
+            );
+        }
+        Ok(links)
+    }
+
+    /// Also link the names of `actors` to their pages in `actor_dir`
+    pub fn with_actors(
+        mut self,
+        actors: &[ActorEntity],
+        actor_dir: &str,
+        use_case_dir: &str,
+        footnotes: bool,
+    ) -> Self {
+        self.actors = actors
+            .iter()
+            .filter(|actor| !actor.name.trim().is_empty())
+            .map(|actor| ActorTarget {
+                id: actor.id.clone(),
+                name: actor.name.clone(),
+                emoji: actor.emoji.clone(),
+                page: link_path(&[actor_dir.to_string(), format!("{}.md", actor.id)]),
+                summary: actor_summary(actor),
+            })
+            .collect();
+        self.actors
+            .sort_by(|a, b| b.name.len().cmp(&a.name.len()).then(a.name.cmp(&b.name)));
+        let names: Vec<String> = self.actors.iter().map(|a| regex::escape(&a.name)).collect();
+        self.actor_mention = (!names.is_empty()).then(|| {
+            Regex::new(&format!(r"\b(?:{})\b", names.join("|"))).expect("escaped actor names")
+        });
+        self.use_case_dir = use_case_dir.to_string();
+        self.footnotes = footnotes;
+        self
+    }
+
+    /// Add or replace the target of one use case
+    pub fn insert(&mut self, use_case: &UseCase) {
+        let filename = OutputManager::primary_filename(use_case, self.format);
+        self.targets.insert(
+            use_case.id.clone(),
+            (to_snake_case(&use_case.category), filename),
+        );
+        self.categories
+            .entry(UseCaseService::category_code(&use_case.category))
+            .or_default()
+            .insert(use_case.category.clone());
+    }
+
+    /// Full category names by the code used in IDs, e.g. "AUT" -> "Authentication"
+    ///
+    /// Categories sharing a code are joined with " / ".
+    pub fn category_codes(&self) -> BTreeMap<String, String> {
+        self.categories
+            .iter()
+            .map(|(code, names)| {
+                let names: Vec<&str> = names.iter().map(String::as_str).collect();
+                (code.clone(), names.join(" / "))
+            })
+            .collect()
+    }
+
+    /// Relative path from a document of `from_category` to the use case `id`
+    pub fn path_from(&self, id: &str, from_category: &str) -> Option<String> {
+        let (category, filename) = self.targets.get(id)?;
+        if *category == to_snake_case(from_category) {
+            Some(filename.clone())
+        } else {
+            Some(link_path(&["..", category.as_str(), filename.as_str()]))
+        }
+    }
+
+    /// Replace mentions of known use case IDs and actor names in `text` with markdown links
+    ///
+    /// Mentions of `from` itself and mentions that are already part of a link
+    /// or path are left alone.
+    pub fn link_text(&self, text: &str, from: &UseCase) -> String {
+        self.link_mentions(text, from, &mut BTreeSet::new())
+    }
+
+    /// Link the mentions in every text field of template data
+    ///
+    /// Scenarios with a known persona get a `persona_link`, and with footnotes
+    /// enabled the document gets an `actor_footnotes` block.
+    pub(crate) fn link_data(&self, data: &mut HashMap<String, Value>, from: &UseCase) {
+        let mut linked_actors = BTreeSet::new();
+        for (key, value) in data.iter_mut() {
+            self.link_value(key, value, from, &mut linked_actors);
+        }
+        if self.footnotes && !linked_actors.is_empty() {
+            let block: Vec<String> = self
+                .actors
+                .iter()
+                .filter(|actor| linked_actors.contains(&actor.id))
+                .map(|actor| {
+                    format!(
+                        "[^{}]: {} **{}**: {}",
+                        actor.id, actor.emoji, actor.name, actor.summary
+                    )
+                })
+                .collect();
+            data.insert("actor_footnotes".to_string(), json!(block.join("\n")));
+        }
+    }
+
+    fn link_value(
+        &self,
+        key: &str,
+        value: &mut Value,
+        from: &UseCase,
+        linked_actors: &mut BTreeSet<String>,
+    ) {
+        match value {
+            Value::String(text) if LINKED_KEYS.contains(&key) => {
+                *text = self.link_mentions(text, from, linked_actors);
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.link_value(key, item, from, linked_actors);
+                }
+            }
+            Value::Object(fields) => {
+                for (key, value) in fields.iter_mut() {
+                    self.link_value(key, value, from, linked_actors);
+                }
+                let persona = fields.get("persona").and_then(Value::as_str);
+                if let Some(actor) = persona.and_then(|id| self.actors.iter().find(|a| a.id == id))
+                {
+                    let link = self.actor_link(actor, from, linked_actors);
+                    fields.insert("persona_link".to_string(), json!(link));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn link_mentions(
+        &self,
+        text: &str,
+        from: &UseCase,
+        linked_actors: &mut BTreeSet<String>,
+    ) -> String {
+        // (start, end, replacement) of every mention, in text order
+        let mut mentions: Vec<(usize, usize, String)> = Vec::new();
+        if self.link_ids {
+            for found in self.mention.find_iter(text) {
+                if found.as_str() == from.id || inside_link(text, found.start(), found.end()) {
+                    continue;
+                }
+                if let Some(path) = self.path_from(found.as_str(), &from.category) {
+                    let link = format!("[{}]({})", found.as_str(), path);
+                    mentions.push((found.start(), found.end(), link));
+                }
+            }
+        }
+        if let Some(actor_mention) = &self.actor_mention {
+            for found in actor_mention.find_iter(text) {
+                if inside_link(text, found.start(), found.end()) {
+                    continue;
+                }
+                if let Some(actor) = self.actors.iter().find(|a| a.name == found.as_str()) {
+                    let link = self.actor_link(actor, from, linked_actors);
+                    mentions.push((found.start(), found.end(), link));
+                }
+            }
+        }
+        mentions.sort_by_key(|(start, _, _)| *start);
+
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+        for (start, end, link) in mentions {
+            if start < last {
+                continue;
+            }
+            out.push_str(&text[last..start]);
+            out.push_str(&link);
+            last = end;
+        }
+        out.push_str(&text[last..]);
+        out
+    }
+
+    /// Link to an actor page, with its summary as the hover title
+    fn actor_link(
+        &self,
+        actor: &ActorTarget,
+        from: &UseCase,
+        linked_actors: &mut BTreeSet<String>,
+    ) -> String {
+        let from_dir = link_path(&[
+            self.use_case_dir.as_str(),
+            to_snake_case(&from.category).as_str(),
+        ]);
+        let mut link = format!(
+            "[{}]({} ({}))",
+            actor.name,
+            relative_link(&from_dir, &actor.page),
+            actor.summary
+        );
+        if self.footnotes {
+            link.push_str(&format!("[^{}]", actor.id));
+        }
+        linked_actors.insert(actor.id.clone());
+        link
+    }
+}
+
+/// Whether a mention is the text or target of an existing markdown link
+fn inside_link(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    matches!(before, Some('[' | '/')) || matches!(after, Some(']' | '/'))
+}
+
+/// Role of a persona, or the type of other actors, as one line without parentheses
+fn actor_summary(actor: &ActorEntity) -> String {
+    let summary = actor
+        .extra
+        .get("function")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|function| !function.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| actor.actor_type.to_string());
+    summary.replace(['(', ')'], "").replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MethodologyView, Persona};
+
+    fn use_case(id: &str, category: &str) -> UseCase {
+        UseCase::builder(id, "Title")
+            .category(category)
+            .view("feature", "simple")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_link_text_resolves_relative_paths() {
+        let login = use_case("UC-AUT-001", "Auth");
+        let logout = use_case("UC-AUT-002", "Auth");
+        let mut pay = use_case("UC-PAY-001", "Online Payments");
+        pay.add_view(MethodologyView::new("business", "normal"));
+        pay.set_primary_view("business").unwrap();
+        let links = IdLinks::new(&[login.clone(), logout, pay]);
+
+        assert_eq!(
+            links.link_text("After UC-AUT-002, see UC-PAY-001.", &login),
+            "After [UC-AUT-002](UC-AUT-002-feature-simple.md), \
+             see [UC-PAY-001](../online_payments/UC-PAY-001.md)."
+        );
+    }
+
+    #[test]
+    fn test_link_text_skips_self_unknown_and_existing_links() {
+        let login = use_case("UC-AUT-001", "Auth");
+        let links = IdLinks::new(&[login.clone(), use_case("UC-AUT-002", "Auth")]);
+
+        let text = "UC-AUT-001 then [UC-AUT-002](x.md), UC-XYZ-009 and UC-AUT-002-S01";
+        assert_eq!(links.link_text(text, &login), text);
+    }
+
+    #[test]
+    fn test_category_codes_join_shared_codes() {
+        let links = IdLinks::new(&[
+            use_case("UC-AUT-001", "Authentication"),
+            use_case("UC-AUT-002", "Authorization"),
+            use_case("UC-PAY-001", "Payments"),
+        ]);
+
+        let codes = links.category_codes();
+        assert_eq!(codes["AUT"], "Authentication / Authorization");
+        assert_eq!(codes["PAY"], "Payments");
+    }
+
+    #[test]
+    fn test_actor_names_link_to_pages_with_footnotes() {
+        let login = use_case("UC-AUT-001", "Auth");
+        let teacher = Persona::new(
+            "teacher".to_string(),
+            "Jack".to_string(),
+            "Primary school teacher (part-time)".to_string(),
+        )
+        .to_actor();
+        let links = IdLinks::new(&[]).with_actors(
+            &[teacher, ActorEntity::standard_actors().remove(0)],
+            "docs/actors",
+            "docs/use-cases",
+            true,
+        );
+
+        assert_eq!(
+            links.link_text("Jack saves to the Database", &login),
+            "[Jack](../../actors/teacher.md (Primary school teacher part-time))[^teacher] \
+             saves to the [Database](../../actors/database.md (Database))[^database]"
+        );
+
+        let mut data = HashMap::new();
+        data.insert(
+            "scenarios".to_string(),
+            json!([{ "persona": "teacher", "description": "Jacky" }]),
+        );
+        links.link_data(&mut data, &login);
+        assert_eq!(
+            data["scenarios"][0]["persona_link"],
+            "[Jack](../../actors/teacher.md (Primary school teacher part-time))[^teacher]"
+        );
+        assert_eq!(data["scenarios"][0]["description"], "Jacky");
+        assert_eq!(
+            data["actor_footnotes"],
+            "[^teacher]: 🙂 **Jack**: Primary school teacher part-time"
+        );
+    }
+}
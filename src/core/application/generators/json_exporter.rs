@@ -0,0 +1,135 @@
+//! JSON export of the whole use case model.
+//!
+//! Unlike the manifest, which summarizes the generated files, the export holds
+//! every use case with its scenarios, views and methodology fields, and every
+//! persona and actor, exactly as they are stored, for tooling that wants the
+//! data rather than the documentation.
+
+use crate::core::Result;
+use serde_json::{json, Value};
+use std::path::Path;
+
+use crate::config::{Config, ProjectContext};
+use crate::core::{ActorEntity, Persona, UseCase};
+
+/// Version of the export format, bumped on breaking changes
+pub(crate) const EXPORT_VERSION: u32 = 1;
+
+/// Exporter of the use case model to a single JSON document
+pub struct JsonExporter {
+    config: Config,
+}
+
+impl JsonExporter {
+    /// Creates a JSON exporter for the project described by `context`.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        Self {
+            config: context.config().clone(),
+        }
+    }
+
+    /// Writes the export document to `output`
+    pub fn export(
+        &self,
+        use_cases: &[UseCase],
+        personas: &[Persona],
+        actors: &[ActorEntity],
+        output: &Path,
+    ) -> Result<()> {
+        let document = self.build(use_cases, personas, actors)?;
+        if let Some(dir) = output.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(output, serde_json::to_string_pretty(&document)? + "\n")?;
+        Ok(())
+    }
+
+    /// Builds the export document, with every list sorted by ID
+    pub fn build(
+        &self,
+        use_cases: &[UseCase],
+        personas: &[Persona],
+        actors: &[ActorEntity],
+    ) -> Result<Value> {
+        let mut use_cases: Vec<&UseCase> = use_cases.iter().collect();
+        use_cases.sort_by(|a, b| a.id.cmp(&b.id));
+        let mut personas: Vec<&Persona> = personas.iter().collect();
+        personas.sort_by(|a, b| a.id.cmp(&b.id));
+        let mut actors: Vec<&ActorEntity> = actors.iter().collect();
+        actors.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(json!({
+            "version": EXPORT_VERSION,
+            "exported_at": chrono::Utc::now().to_rfc3339(),
+            "project": {
+                "name": self.config.project.name,
+                "description": self.config.project.description,
+                "id_prefix": self.config.project.id_prefix,
+            },
+            "use_cases": serde_json::to_value(use_cases)?,
+            "personas": serde_json::to_value(personas)?,
+            "actors": serde_json::to_value(actors)?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MethodologyView, Scenario, ScenarioType};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_export_holds_the_full_model() {
+        let mut login = UseCase::new(
+            "UC-AUT-002".to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            "User signs in".to_string(),
+            "high".to_string(),
+        )
+        .unwrap();
+        login.add_view(MethodologyView::new("business", "normal"));
+        login.methodology_fields.insert(
+            "business".to_string(),
+            HashMap::from([("business_value".to_string(), json!("Fewer support calls"))]),
+        );
+        login.add_scenario(Scenario::new(
+            login.next_scenario_id(),
+            "Valid credentials".to_string(),
+            "Signs in".to_string(),
+            ScenarioType::HappyPath,
+        ));
+        let logout = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Logout".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "low".to_string(),
+        )
+        .unwrap();
+        let persona = Persona::new(
+            "customer".to_string(),
+            "Customer".to_string(),
+            "Buyer".to_string(),
+        );
+        let actor = ActorEntity::persona("customer".to_string(), "Customer".to_string());
+
+        let document = JsonExporter {
+            config: Config::default(),
+        }
+        .build(&[login, logout], &[persona], &[actor])
+        .unwrap();
+
+        assert_eq!(document["version"], EXPORT_VERSION);
+        assert_eq!(document["use_cases"][0]["id"], "UC-AUT-001");
+        let login = &document["use_cases"][1];
+        assert_eq!(login["scenarios"][0]["title"], "Valid credentials");
+        assert_eq!(
+            login["methodology_fields"]["business"]["business_value"],
+            "Fewer support calls"
+        );
+        assert_eq!(document["personas"][0]["function"], "Buyer");
+        assert_eq!(document["actors"][0]["id"], "customer");
+    }
+}
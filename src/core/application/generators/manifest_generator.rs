@@ -0,0 +1,189 @@
+//! Manifest generator for machine-readable project state.
+//!
+//! Writes `mucm-manifest.json` next to the overview so editor extensions and
+//! dashboards can read IDs, titles, file paths, statuses, and relations in one go.
+
+use crate::core::Result;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, ProjectContext, StorageBackend};
+use crate::core::application::generators::OutputManager;
+use crate::core::file_operations::FileOperations;
+use crate::core::infrastructure::EVENT_LOG;
+use crate::core::utils::portable_path;
+use crate::core::{to_snake_case, UseCase};
+
+/// Version of the manifest format, bumped on breaking changes
+const MANIFEST_VERSION: u32 = 1;
+
+/// Generator for the project manifest file.
+pub struct ManifestGenerator {
+    config: Config,
+    file_operations: FileOperations,
+}
+
+impl ManifestGenerator {
+    /// Creates a manifest generator for the project described by `context`.
+    ///
+    /// Paths in the manifest stay relative to the project root; only the
+    /// manifest file itself is written under the root.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        Self {
+            config: context.config().clone(),
+            file_operations: FileOperations::new(context.resolved_config()),
+        }
+    }
+
+    /// Generates and saves `mucm-manifest.json`.
+    pub fn generate(&self, use_cases: &[UseCase]) -> Result<()> {
+        let manifest = self.build(use_cases);
+        self.file_operations
+            .save_manifest(&serde_json::to_string_pretty(&manifest)?)
+    }
+
+    /// Builds the manifest document without saving it.
+    pub fn build(&self, use_cases: &[UseCase]) -> Value {
+        let mut sorted: Vec<&UseCase> = use_cases.iter().collect();
+        sorted.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let entries: Vec<Value> = sorted.iter().map(|uc| self.use_case_entry(uc)).collect();
+
+        json!({
+            "version": MANIFEST_VERSION,
+            "project": {
+                "name": self.config.project.name,
+                "description": self.config.project.description,
+            },
+            "storage": self.config.storage.backend.to_string(),
+            "overview_file": portable_path(&Path::new(&self.config.directories.use_case_dir).join("README.md")),
+            "use_cases": entries,
+        })
+    }
+
+    fn use_case_entry(&self, use_case: &UseCase) -> Value {
+        let markdown_files: Vec<Value> =
+            OutputManager::generate_all_filenames(use_case, self.config.generation.output_format)
+                .into_iter()
+                .map(|(filename, view)| {
+                    json!({
+                        "view": view.key(),
+                        "path": portable_path(&OutputManager::document_path(
+                            &self.config,
+                            use_case,
+                            &filename,
+                        )),
+                    })
+                })
+                .collect();
+
+        let relations: Vec<Value> = use_case
+            .use_case_references
+            .iter()
+            .map(|r| {
+                json!({
+                    "source": use_case.id,
+                    "target": r.target_id,
+                    "relationship": r.relationship,
+                })
+            })
+            .chain(use_case.scenarios.iter().flat_map(|s| {
+                s.references.iter().map(move |r| {
+                    json!({
+                        "source": s.id,
+                        "target": r.target_id,
+                        "relationship": r.relationship,
+                    })
+                })
+            }))
+            .collect();
+
+        let scenarios: Vec<Value> = use_case
+            .scenarios
+            .iter()
+            .map(|s| {
+                json!({
+                    "id": s.id,
+                    "title": s.title,
+                    "type": s.scenario_type.to_string(),
+                    "status": s.status,
+                    "persona": s.persona,
+                })
+            })
+            .collect();
+
+        json!({
+            "id": use_case.id,
+            "title": use_case.title,
+            "category": use_case.category,
+            "status": use_case.status(),
+            "priority": use_case.priority.to_string(),
+            "data_file": portable_path(&self.data_path(use_case)),
+            "markdown_files": markdown_files,
+            "scenarios": scenarios,
+            "relations": relations,
+        })
+    }
+
+    /// Path of the source data for a use case (TOML file, SQLite database or event log)
+    fn data_path(&self, use_case: &UseCase) -> PathBuf {
+        let data_dir = Path::new(&self.config.directories.data_dir);
+        match self.config.storage.backend {
+            StorageBackend::Toml => data_dir
+                .join(to_snake_case(&use_case.category))
+                .join(format!("{}.toml", use_case.id)),
+            StorageBackend::Sqlite => data_dir.join("usecases.db"),
+            StorageBackend::Events => data_dir.join(EVENT_LOG),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::UseCaseReference;
+    use crate::core::MethodologyView;
+
+    #[test]
+    fn test_build_manifest() {
+        let mut config = Config::default();
+        config.directories.use_case_dir = "docs/use-cases".to_string();
+        config.directories.data_dir = "use-cases-data".to_string();
+        config.storage.backend = StorageBackend::Toml;
+
+        let mut use_case = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Login".to_string(),
+            "User Auth".to_string(),
+            String::new(),
+            "high".to_string(),
+        )
+        .unwrap();
+        use_case.add_view(MethodologyView::new(
+            "feature".to_string(),
+            "normal".to_string(),
+        ));
+        use_case.use_case_references.push(UseCaseReference::new(
+            "UC-AUT-002".to_string(),
+            "dependency".to_string(),
+        ));
+
+        let generator = ManifestGenerator::for_project(&ProjectContext::current(config));
+        let manifest = generator.build(std::slice::from_ref(&use_case));
+
+        // Unchanged use cases give an identical manifest
+        assert_eq!(manifest, generator.build(&[use_case]));
+        assert_eq!(manifest["version"], MANIFEST_VERSION);
+        let entry = &manifest["use_cases"][0];
+        assert_eq!(entry["id"], "UC-AUT-001");
+        assert_eq!(
+            entry["data_file"],
+            "use-cases-data/user_auth/UC-AUT-001.toml"
+        );
+        assert_eq!(
+            entry["markdown_files"][0]["path"],
+            "docs/use-cases/user_auth/UC-AUT-001-feature-normal.md"
+        );
+        assert_eq!(entry["relations"][0]["target"], "UC-AUT-002");
+    }
+}
@@ -2,16 +2,28 @@
 //!
 //! Handles generation of markdown documentation from use cases using templates.
 
-use anyhow::Result;
-use serde_json::Value;
+use crate::core::Result;
+use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::io::Write;
 
-use crate::config::Config;
-use crate::core::{MethodologyView, TemplateEngine, UseCase};
+use super::asciidoc::markdown_to_asciidoc;
+use super::front_matter::use_case_front_matter;
+use super::{Diagram, IdLinks};
+use crate::config::{
+    BadgeStyle, Config, DiagramFormat, DirectoryConfig, OutputFormat, ProjectContext,
+    StorageBackend,
+};
+use crate::core::domain::{Priority, UseCaseService};
+use crate::core::utils::{link_path, normalize_line_endings, relative_link};
+use crate::core::{to_snake_case, MethodologyView, Status, TemplateEngine, UseCase};
+use crate::presentation::{status_icon, with_icon};
 
 /// Generator for use case markdown documentation.
 pub struct MarkdownGenerator {
     config: Config,
+    /// Directories relative to the project root, for paths shown in documents
+    directories: DirectoryConfig,
     template_engine: TemplateEngine,
 }
 
@@ -20,11 +32,21 @@ impl MarkdownGenerator {
     pub fn new(config: Config) -> Self {
         let template_engine = TemplateEngine::with_config(Some(&config));
         Self {
+            directories: config.directories.clone(),
             config,
             template_engine,
         }
     }
 
+    /// Creates a markdown generator for the project described by `context`.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        Self {
+            config: context.resolved_config(),
+            directories: context.config().directories.clone(),
+            template_engine: TemplateEngine::for_project(context),
+        }
+    }
+
     /// Generates markdown for a use case with flexible rendering options.
     ///
     /// Converts UseCase to JSON and passes it to the template engine.
@@ -46,19 +68,48 @@ impl MarkdownGenerator {
         methodology: Option<&str>,
         view: Option<&MethodologyView>,
     ) -> Result<String> {
-        // Convert UseCase directly to JSON - templates can access any field from TOML
-        let use_case_json = serde_json::to_value(use_case)?;
+        let mut buffer = Vec::new();
+        self.generate_to(use_case, methodology, view, &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
 
-        // Convert to HashMap for template engine compatibility
-        let mut data: HashMap<String, Value> = serde_json::from_value(use_case_json)?;
+    /// Generates markdown for a use case straight into `writer`.
+    ///
+    /// Same options as [`MarkdownGenerator::generate`], but the document is
+    /// streamed instead of being built in memory first.
+    pub fn generate_to(
+        &self,
+        use_case: &UseCase,
+        methodology: Option<&str>,
+        view: Option<&MethodologyView>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.render_to(use_case, methodology, view, None, writer)
+    }
 
-        // Merge extra fields into top-level HashMap so templates can access them directly
-        if let Some(Value::Object(extra_map)) = data.remove("extra") {
-            for (key, value) in extra_map {
-                data.insert(key, value);
-            }
-        }
+    /// Generates markdown straight into `writer`, linking mentioned use case IDs.
+    ///
+    /// Same as [`MarkdownGenerator::generate_to`], but use case IDs and actor names
+    /// known to `links` become relative links to their markdown files.
+    pub fn generate_linked_to(
+        &self,
+        use_case: &UseCase,
+        methodology: Option<&str>,
+        view: Option<&MethodologyView>,
+        links: &IdLinks,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.render_to(use_case, methodology, view, Some(links), writer)
+    }
 
+    fn render_to(
+        &self,
+        use_case: &UseCase,
+        methodology: Option<&str>,
+        view: Option<&MethodologyView>,
+        links: Option<&IdLinks>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
         // Determine which methodology to use for field flattening
         let methodology_name = if let Some(v) = view {
             &v.methodology
@@ -67,37 +118,292 @@ impl MarkdownGenerator {
         } else {
             &self.config.templates.default_methodology
         };
+        let mut data = self.template_data(use_case, methodology_name, links)?;
+
+        // Output format, so shared partials can tell markdown and AsciiDoc apart
+        let format = self.config.generation.output_format;
+        data.insert("output_format".to_string(), json!(format));
+
+        // Render based on what parameters were provided
+        let level = view.map_or("normal", |v| v.level.as_str());
+        if format == OutputFormat::Markdown {
+            if let Some(front_matter) = use_case_front_matter(
+                &self.config.generation.front_matter,
+                use_case,
+                methodology_name,
+                view,
+            )? {
+                let eol = self.config.generation.line_endings.as_str();
+                writer.write_all(normalize_line_endings(&front_matter, eol).as_bytes())?;
+            }
+            return self
+                .template_engine
+                .render_use_case_with_methodology_and_level_to(
+                    &data,
+                    methodology_name,
+                    level,
+                    writer,
+                );
+        }
+
+        // Levels with a template of their own for the format render it directly,
+        // the others have their markdown converted
+        if self.template_engine.render_use_case_variant_to(
+            &data,
+            methodology_name,
+            level,
+            format.extension(),
+            writer,
+        )? {
+            return Ok(());
+        }
+        let mut markdown = Vec::new();
+        self.template_engine
+            .render_use_case_with_methodology_and_level_to(
+                &data,
+                methodology_name,
+                level,
+                &mut markdown,
+            )?;
+        let asciidoc = markdown_to_asciidoc(&String::from_utf8(markdown)?);
+        let eol = self.config.generation.line_endings.as_str();
+        writer.write_all(normalize_line_endings(&asciidoc, eol).as_bytes())?;
+        Ok(())
+    }
+
+    /// Renders `use_case` with the markdown template `source` in place of the template of `view`
+    ///
+    /// Returns the output and the data the template was given.
+    pub fn preview(
+        &self,
+        use_case: &UseCase,
+        view: &MethodologyView,
+        source: &str,
+    ) -> Result<(String, HashMap<String, Value>)> {
+        let mut data = self.template_data(use_case, &view.methodology, None)?;
+        data.insert("output_format".to_string(), json!(OutputFormat::Markdown));
+        let output = self.template_engine.render_use_case_source(
+            source,
+            &data,
+            &view.methodology,
+            &view.level,
+        )?;
+        Ok((output, data))
+    }
+
+    /// Data handed to the templates for `use_case` rendered with `methodology_name`
+    fn template_data(
+        &self,
+        use_case: &UseCase,
+        methodology_name: &str,
+        links: Option<&IdLinks>,
+    ) -> Result<HashMap<String, Value>> {
+        // Convert UseCase directly to JSON - templates can access any field from TOML
+        let ordered = super::with_scenario_order(use_case, self.config.generation.scenario_order);
+        let use_case_json = serde_json::to_value(&ordered)?;
+
+        // Convert to HashMap for template engine compatibility
+        let mut data: HashMap<String, Value> = serde_json::from_value(use_case_json)?;
+
+        // Merge extra fields into top-level HashMap so templates can access them directly
+        if let Some(Value::Object(extra_map)) = data.remove("extra") {
+            for (key, value) in extra_map {
+                data.insert(key, value);
+            }
+        }
 
         // Merge methodology_fields for the SPECIFIC methodology into top-level HashMap
         // This flattens methodology_fields.{current_methodology}.{field} -> {field}
         if let Some(Value::Object(methodology_fields_map)) = data.remove("methodology_fields") {
-            if let Some(fields) = methodology_fields_map.get(methodology_name) {
-                if let Value::Object(field_map) = fields {
-                    for (field_name, field_value) in field_map {
-                        // Only insert if not already present (standard fields take priority)
-                        data.entry(field_name.clone())
-                            .or_insert(field_value.clone());
-                    }
+            if let Some(Value::Object(field_map)) = methodology_fields_map.get(methodology_name) {
+                for (field_name, field_value) in field_map {
+                    // Only insert if not already present (standard fields take priority)
+                    data.entry(field_name.clone())
+                        .or_insert(field_value.clone());
                 }
             }
         }
 
-        // Render based on what parameters were provided
-        if let Some(v) = view {
-            self.template_engine
-                .render_use_case_with_methodology_and_level(&data, &v.methodology, &v.level)
-        } else {
-            self.template_engine
-                .render_use_case_with_methodology(&data, methodology_name)
+        // Computed status, so templates can pair {{status}} with {{status_icon status}}
+        data.entry("status".to_string())
+            .or_insert_with(|| json!(use_case.status()));
+
+        // Header badges, rendered by templates as {{{badges}}}
+        let badges = self.render_badges(use_case);
+        if !badges.is_empty() {
+            data.insert("badges".to_string(), Value::String(badges));
+        }
+
+        // Link to the data file, rendered by templates as {{{edit_link}}}
+        if let Some(edit_link) = self.edit_link(use_case) {
+            data.insert("edit_link".to_string(), Value::String(edit_link));
+        }
+
+        // Category code used in IDs, and the codes of the whole project for
+        // the {{category_name}} helper
+        let category_code = UseCaseService::category_code(&use_case.category);
+        let mut category_codes = links.map(IdLinks::category_codes).unwrap_or_default();
+        category_codes
+            .entry(category_code.clone())
+            .or_insert_with(|| use_case.category.clone());
+        data.insert("category_code".to_string(), json!(category_code));
+        data.insert("category_codes".to_string(), json!(category_codes));
+
+        // Scenario conditions, including the inherited use case ones
+        Self::insert_scenario_conditions(&ordered, &mut data);
+
+        // Cross-reference links to the use cases and actors mentioned in the text
+        if let Some(links) = links {
+            links.link_data(&mut data, use_case);
         }
+
+        // Diagrams, rendered by templates as {{{actor_diagram}}}, {{{flow_diagram}}}
+        // and {{{sequence_diagram}}} inside each scenario
+        self.insert_diagrams(&ordered, &mut data);
+
+        Ok(data)
+    }
+
+    /// Format of the generated use case documents (`generation.output_format`)
+    pub fn output_format(&self) -> OutputFormat {
+        self.config.generation.output_format
+    }
+
+    /// Replaces the conditions of each scenario by the ones that apply to it,
+    /// so templates render inherited use case conditions without duplicates.
+    ///
+    /// `use_case` must have its scenarios in the same order as `data["scenarios"]`.
+    fn insert_scenario_conditions(use_case: &UseCase, data: &mut HashMap<String, Value>) {
+        if let Some(Value::Array(scenarios)) = data.get_mut("scenarios") {
+            for (scenario, json) in use_case.scenarios.iter().zip(scenarios.iter_mut()) {
+                if let Value::Object(map) = json {
+                    map.insert(
+                        "preconditions".to_string(),
+                        json!(scenario.effective_preconditions(&use_case.preconditions)),
+                    );
+                    map.insert(
+                        "postconditions".to_string(),
+                        json!(scenario.effective_postconditions(&use_case.postconditions)),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Adds the diagrams selected by `generation.diagram_format` to the template data.
+    ///
+    /// `use_case` must have its scenarios in the same order as `data["scenarios"]`.
+    fn insert_diagrams(&self, use_case: &UseCase, data: &mut HashMap<String, Value>) {
+        let format = self.config.generation.diagram_format;
+        if format == DiagramFormat::None {
+            return;
+        }
+
+        for (key, diagram) in [
+            ("actor_diagram", Diagram::actors(use_case)),
+            ("flow_diagram", Diagram::flow(use_case)),
+        ] {
+            if let Some(rendered) = diagram.render(format) {
+                data.insert(key.to_string(), Value::String(rendered));
+            }
+        }
+
+        if let Some(Value::Array(scenarios)) = data.get_mut("scenarios") {
+            for (scenario, json) in use_case.scenarios.iter().zip(scenarios.iter_mut()) {
+                if let (Some(rendered), Value::Object(map)) =
+                    (Diagram::sequence(scenario).render(format), json)
+                {
+                    map.insert("sequence_diagram".to_string(), Value::String(rendered));
+                }
+            }
+        }
+    }
+
+    /// Renders status and priority badges according to `generation.badges`.
+    ///
+    /// Returns an empty string when badges are disabled.
+    fn render_badges(&self, use_case: &UseCase) -> String {
+        let status = use_case.status();
+        let priority = &use_case.priority;
+
+        match self.config.generation.badges {
+            BadgeStyle::None => String::new(),
+            BadgeStyle::Shields => format!(
+                "![Status](https://img.shields.io/badge/status-{}-{}) ![Priority](https://img.shields.io/badge/priority-{}-{})",
+                shields_escape(status.display_name()),
+                status_color(status),
+                shields_escape(&priority.to_string()),
+                priority_color(priority)
+            ),
+            BadgeStyle::Html => format!(
+                "<kbd>{}</kbd> <kbd>{}</kbd>",
+                with_icon(
+                    status_icon(status, self.config.presentation.icon_theme),
+                    status.display_name()
+                ),
+                priority
+            ),
+        }
+    }
+
+    /// The "Edit this use case" link of `generation.edit_links`.
+    ///
+    /// Points to `generation.edit_url` when set, otherwise relatively from the
+    /// document to the data file. Only TOML storage has a file per use case.
+    fn edit_link(&self, use_case: &UseCase) -> Option<String> {
+        if !self.config.generation.edit_links || self.config.storage.backend != StorageBackend::Toml
+        {
+            return None;
+        }
+        let category = to_snake_case(&use_case.category);
+        let data_file = link_path(&[
+            self.directories.data_dir.as_str(),
+            &category,
+            &format!("{}.toml", use_case.id),
+        ]);
+        let target = match &self.config.generation.edit_url {
+            Some(pattern) => pattern.replace("{path}", &data_file),
+            None => relative_link(
+                &link_path(&[self.directories.use_case_dir.as_str(), &category]),
+                &data_file,
+            ),
+        };
+        Some(format!("[Edit this use case]({})", target))
+    }
+}
+
+/// Escapes a shields.io badge segment (`-` -> `--`, `_` -> `__`, space -> `%20`).
+fn shields_escape(text: &str) -> String {
+    text.replace('-', "--")
+        .replace('_', "__")
+        .replace(' ', "%20")
+}
+
+fn status_color(status: Status) -> &'static str {
+    match status {
+        Status::Planned => "lightgrey",
+        Status::InProgress => "yellow",
+        Status::Implemented => "blue",
+        Status::Tested => "green",
+        Status::Deployed => "brightgreen",
+        Status::Deprecated => "red",
+    }
+}
+
+fn priority_color(priority: &Priority) -> &'static str {
+    match priority {
+        Priority::Low => "lightgrey",
+        Priority::Medium => "blue",
+        Priority::High => "orange",
+        Priority::Critical => "red",
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::IconTheme;
     use crate::core::domain::UseCase;
-    use serde_json::json;
 
     #[test]
     fn test_methodology_fields_flattening() {
@@ -205,4 +511,129 @@ mod tests {
         // Verify standard field takes priority
         assert_eq!(data["author"], json!("Standard Author"));
     }
+
+    #[test]
+    fn test_shields_escape() {
+        assert_eq!(shields_escape("IN_PROGRESS"), "IN__PROGRESS");
+        assert_eq!(shields_escape("multi-word text"), "multi--word%20text");
+    }
+
+    #[test]
+    fn test_render_badges_per_style() {
+        let use_case = UseCase::new(
+            "UC-TEST-001".to_string(),
+            "Test Use Case".to_string(),
+            "Test".to_string(),
+            "Test description".to_string(),
+            "High".to_string(),
+        )
+        .unwrap();
+        let mut config = Config::default();
+
+        config.generation.badges = BadgeStyle::None;
+        assert!(MarkdownGenerator::new(config.clone())
+            .render_badges(&use_case)
+            .is_empty());
+
+        config.generation.badges = BadgeStyle::Shields;
+        let badges = MarkdownGenerator::new(config.clone()).render_badges(&use_case);
+        assert!(badges.contains("badge/status-PLANNED-lightgrey"));
+        assert!(badges.contains("badge/priority-HIGH-orange"));
+
+        config.generation.badges = BadgeStyle::Html;
+        let badges = MarkdownGenerator::new(config.clone()).render_badges(&use_case);
+        assert!(badges.contains("<kbd>HIGH</kbd>"));
+
+        config.presentation.icon_theme = IconTheme::Ascii;
+        let badges = MarkdownGenerator::new(config).render_badges(&use_case);
+        assert!(badges.contains("<kbd>[ ] PLANNED</kbd>"));
+    }
+
+    #[test]
+    fn test_edit_link_points_to_the_data_file() {
+        let use_case = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Login".to_string(),
+            "User Auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        let mut config = Config::default();
+        config.directories.use_case_dir = "docs/use-cases".to_string();
+        config.directories.data_dir = "use-cases-data".to_string();
+        assert_eq!(
+            MarkdownGenerator::new(config.clone()).edit_link(&use_case),
+            None
+        );
+
+        config.generation.edit_links = true;
+        assert_eq!(
+            MarkdownGenerator::new(config.clone()).edit_link(&use_case),
+            Some(
+                "[Edit this use case](../../../use-cases-data/user_auth/UC-AUT-001.toml)"
+                    .to_string()
+            )
+        );
+
+        config.generation.edit_url =
+            Some("https://github.com/org/repo/edit/main/{path}".to_string());
+        assert_eq!(
+            MarkdownGenerator::new(config.clone()).edit_link(&use_case),
+            Some(
+                "[Edit this use case](https://github.com/org/repo/edit/main/use-cases-data/user_auth/UC-AUT-001.toml)"
+                    .to_string()
+            )
+        );
+
+        config.storage.backend = StorageBackend::Sqlite;
+        assert_eq!(MarkdownGenerator::new(config).edit_link(&use_case), None);
+    }
+
+    #[test]
+    fn test_scenario_conditions_include_inherited_ones() {
+        use crate::core::domain::{Condition, Scenario, ScenarioType};
+
+        let mut use_case = UseCase::new(
+            "UC-TEST-001".to_string(),
+            "Test Use Case".to_string(),
+            "Test".to_string(),
+            "Test description".to_string(),
+            "High".to_string(),
+        )
+        .unwrap();
+        use_case.add_precondition(Condition::new("System is online"));
+        use_case.add_precondition(Condition::new("User is logged in"));
+        for (id, inherit) in [("UC-TEST-001-S01", true), ("UC-TEST-001-S02", false)] {
+            let mut scenario = Scenario::new(
+                id.to_string(),
+                "Scenario".to_string(),
+                String::new(),
+                ScenarioType::HappyPath,
+            );
+            scenario.inherit_preconditions = inherit;
+            scenario.add_precondition(Condition::new("User is logged in"));
+            scenario.add_precondition(Condition::new("Cart is not empty"));
+            use_case.add_scenario(scenario);
+        }
+
+        let mut data: HashMap<String, Value> =
+            serde_json::from_value(serde_json::to_value(&use_case).unwrap()).unwrap();
+        MarkdownGenerator::insert_scenario_conditions(&use_case, &mut data);
+
+        let texts = |index: usize| -> Vec<String> {
+            data["scenarios"][index]["preconditions"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|c| c["text"].as_str().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(
+            texts(0),
+            vec!["System is online", "User is logged in", "Cart is not empty"]
+        );
+        assert_eq!(texts(1), vec!["User is logged in", "Cart is not empty"]);
+        assert_eq!(data["scenarios"][0]["inherit_preconditions"], true);
+    }
 }
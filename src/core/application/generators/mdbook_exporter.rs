@@ -0,0 +1,175 @@
+//! mdBook export: a `SUMMARY.md` and `book.toml` around the generated markdown.
+//!
+//! The use case directory becomes the source directory of the book, so
+//! `mdbook serve` renders the documentation as it is generated, without
+//! copying it. `SUMMARY.md` starts with the overview, then has a chapter per
+//! category (linking its overview page when there is one) with a chapter per
+//! use case, and the other views of a use case nested under it. `book.toml` is
+//! only written when missing, so the book settings can be edited.
+
+use crate::core::Result;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::OutputManager;
+use crate::config::{Config, OutputFormat, ProjectContext};
+use crate::core::utils::{link_path, to_snake_case};
+use crate::core::UseCase;
+
+/// Name of the table of contents mdBook reads from the source directory
+pub const SUMMARY_FILE: &str = "SUMMARY.md";
+
+/// Name of the mdBook configuration file
+pub const BOOK_FILE: &str = "book.toml";
+
+/// Exporter of the generated documentation as an mdBook
+pub struct MdBookExporter {
+    config: Config,
+}
+
+impl MdBookExporter {
+    /// Creates an mdBook exporter for the project described by `context`.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        Self {
+            config: context.config().clone(),
+        }
+    }
+
+    /// Writes `SUMMARY.md` into `src_dir`, and `book.toml` into `book_dir`
+    /// unless it exists
+    ///
+    /// `src` is the path of `src_dir` relative to `book_dir`. Returns whether
+    /// `book.toml` was written.
+    pub fn export(
+        &self,
+        use_cases: &[UseCase],
+        book_dir: &Path,
+        src_dir: &Path,
+        src: &str,
+    ) -> Result<bool> {
+        let summary = Self::summary(use_cases, |page| src_dir.join(page).is_file());
+        std::fs::create_dir_all(src_dir)?;
+        std::fs::write(src_dir.join(SUMMARY_FILE), summary)?;
+
+        let book_file = book_dir.join(BOOK_FILE);
+        if book_file.exists() {
+            return Ok(false);
+        }
+        std::fs::create_dir_all(book_dir)?;
+        std::fs::write(&book_file, self.book_toml(src))?;
+        Ok(true)
+    }
+
+    /// The table of contents; `exists` tells whether a page of the source
+    /// directory exists, to link the overview and the category pages
+    pub fn summary(use_cases: &[UseCase], exists: impl Fn(&str) -> bool) -> String {
+        let mut categories: BTreeMap<&str, Vec<&UseCase>> = BTreeMap::new();
+        for use_case in use_cases {
+            categories
+                .entry(use_case.category.as_str())
+                .or_default()
+                .push(use_case);
+        }
+
+        let mut summary = String::from("# Summary\n\n");
+        if exists("README.md") {
+            summary.push_str("[Overview](README.md)\n\n");
+        }
+        for (category, mut category_use_cases) in categories {
+            category_use_cases.sort_by(|a, b| a.id.cmp(&b.id));
+            let dir = to_snake_case(category);
+            let page = ["overview.md", "README.md"]
+                .iter()
+                .map(|file| link_path(&[dir.as_str(), file]))
+                .find(|page| exists(page))
+                .unwrap_or_default();
+            summary.push_str(&format!("- [{}]({})\n", link_text(category), page));
+
+            for use_case in category_use_cases {
+                let files = OutputManager::generate_all_filenames(use_case, OutputFormat::Markdown);
+                let Some((first, _)) = files.first() else {
+                    continue;
+                };
+                summary.push_str(&format!(
+                    "  - [{}: {}]({})\n",
+                    use_case.id,
+                    link_text(&use_case.title),
+                    link_path(&[dir.as_str(), first.as_str()])
+                ));
+                for (file, view) in files.iter().skip(1) {
+                    summary.push_str(&format!(
+                        "    - [{}:{}]({})\n",
+                        view.methodology,
+                        view.level,
+                        link_path(&[dir.as_str(), file.as_str()])
+                    ));
+                }
+            }
+        }
+        summary
+    }
+
+    /// The book configuration, titled after the project
+    fn book_toml(&self, src: &str) -> String {
+        let mut book = toml_edit::DocumentMut::new();
+        book["book"] = toml_edit::table();
+        book["book"]["title"] = toml_edit::value(self.config.project.name.as_str());
+        if !self.config.project.description.is_empty() {
+            book["book"]["description"] =
+                toml_edit::value(self.config.project.description.as_str());
+        }
+        book["book"]["src"] = toml_edit::value(src);
+        book.to_string()
+    }
+}
+
+/// Text of a summary link, with the brackets mdBook would misread escaped
+fn link_text(text: &str) -> String {
+    text.replace('[', "\\[").replace(']', "\\]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn use_case(id: &str, title: &str, category: &str, views: &[(&str, &str)]) -> UseCase {
+        views
+            .iter()
+            .fold(
+                UseCase::builder(id, title).category(category),
+                |builder, (methodology, level)| builder.view(*methodology, *level),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_summary_nests_use_cases_under_categories() {
+        let use_cases = [
+            use_case(
+                "UC-ORD-001",
+                "Check out [fast]",
+                "Order Handling",
+                &[("feature", "normal"), ("business", "normal")],
+            ),
+            use_case("UC-AUT-002", "Log out", "Auth", &[("feature", "normal")]),
+            use_case("UC-AUT-001", "Log in", "Auth", &[("feature", "normal")]),
+        ];
+
+        let summary = MdBookExporter::summary(&use_cases, |page| {
+            page == "README.md" || page == "auth/overview.md"
+        });
+
+        assert_eq!(
+            summary,
+            "# Summary\n\n\
+             [Overview](README.md)\n\n\
+             - [Auth](auth/overview.md)\n\
+             \x20 - [UC-AUT-001: Log in](auth/UC-AUT-001-feature-normal.md)\n\
+             \x20 - [UC-AUT-002: Log out](auth/UC-AUT-002-feature-normal.md)\n\
+             - [Order Handling]()\n\
+             \x20 - [UC-ORD-001: Check out \\[fast\\]](order_handling/UC-ORD-001-feature-normal.md)\n\
+             \x20   - [business:normal](order_handling/UC-ORD-001-business-normal.md)\n"
+        );
+    }
+}
@@ -3,19 +3,79 @@
 //! This module contains specialized generators for different types of content:
 //!
 //! - **MarkdownGenerator**: Generates use case markdown documentation
+//! - **AsciiDoc**: Converts the rendered markdown for the AsciiDoc output format
+//! - **ActorDocGenerator**: Generates actor pages with use case roll-ups per actor subtree
 //! - **TestGenerator**: Generates test documentation for use cases
+//! - **GherkinGenerator**: Generates Gherkin `.feature` files from the scenarios
+//! - **FrontMatter**: YAML front matter of generated markdown for Docusaurus and Hugo
 //! - **OverviewGenerator**: Generates project overview documentation
+//! - **HtmlExporter**: Converts the generated markdown into standalone HTML pages
+//! - **CsvExporter**: Flat matrix of the use cases for spreadsheets
+//! - **JsonExporter**: Serializes the whole use case model to one JSON document
+//! - **ReqifExporter**: ReqIF document of the use cases and their conditions for requirements tools
+//! - **MdBookExporter**: `SUMMARY.md` and `book.toml` to serve the use case directory with mdBook
+//! - **PdfExporter**: Compiles use cases into a printable PDF through an external converter
+//! - **ConfluencePublisher**: Pushes the generated documentation to Confluence pages
+//! - **XlsxExporter**: Excel workbook with a summary sheet and a sheet per category
+//! - **OverviewSections**: Data of the summary, progress, recent changes and risks sections
+//! - **Diagrams**: Actor, flow and sequence diagrams as Mermaid or PlantUML
+//! - **IdLinks**: Links use case IDs mentioned in text to their markdown files
+//! - **ManifestGenerator**: Generates the machine-readable `mucm-manifest.json`
 //! - **OutputManager**: Manages output filenames for single/multi-view use cases
+//! - **TestNameMap**: Keeps generated test names stable per scenario
+//! - **Snippets**: Project code injected at the named points of test templates
 //!
 //! These generators encapsulate the logic for creating various types of
 //! documentation, separating concerns from the main application service.
 
+pub mod actor_doc_generator;
+pub mod asciidoc;
+pub mod confluence_publisher;
+pub mod csv_exporter;
+pub mod diagrams;
+pub mod front_matter;
+pub mod gherkin_generator;
+pub mod html_exporter;
+pub mod id_links;
+pub mod json_exporter;
+pub mod manifest_generator;
 pub mod markdown_generator;
+pub mod mdbook_exporter;
 pub mod output_manager;
 pub mod overview_generator;
+pub(crate) mod overview_sections;
+pub mod pdf_exporter;
+pub mod reqif_exporter;
+pub mod snippets;
 pub mod test_generator;
+pub mod test_names;
+pub mod xlsx_exporter;
 
+pub use actor_doc_generator::ActorDocGenerator;
+pub use confluence_publisher::{ConfluencePublisher, CurlConfluenceApi};
+pub use csv_exporter::CsvExporter;
+pub use diagrams::Diagram;
+pub use gherkin_generator::GherkinGenerator;
+pub use html_exporter::HtmlExporter;
+pub use id_links::IdLinks;
+pub use json_exporter::JsonExporter;
+pub use manifest_generator::ManifestGenerator;
 pub use markdown_generator::MarkdownGenerator;
+pub use mdbook_exporter::MdBookExporter;
 pub use output_manager::OutputManager;
 pub use overview_generator::OverviewGenerator;
+pub use pdf_exporter::PdfExporter;
+pub use reqif_exporter::ReqifExporter;
 pub use test_generator::TestGenerator;
+pub use test_names::{TestName, TestNameMap};
+pub use xlsx_exporter::XlsxExporter;
+
+use crate::config::ScenarioOrder;
+use crate::core::UseCase;
+
+/// Copy of a use case with its scenarios in the configured rendering order.
+pub(crate) fn with_scenario_order(use_case: &UseCase, order: ScenarioOrder) -> UseCase {
+    let mut ordered = use_case.clone();
+    ordered.sort_scenarios(order == ScenarioOrder::ByType);
+    ordered
+}
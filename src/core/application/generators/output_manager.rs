@@ -3,8 +3,15 @@
 //! Handles filename generation logic for both single-view and multi-view use cases:
 //! - Single view: `UC-001.md` (no suffix)
 //! - Multiple views: `UC-001-feat-s.md`, `UC-001-bus-n.md` (with methodology-level suffix)
+//! - Designated primary view: also `UC-001.md`, a canonical filename that
+//!   stays the same when views are added or removed
+//!
+//! The extension follows `generation.output_format` (`.adoc` for AsciiDoc).
+
+use std::path::{Path, PathBuf};
 
-use crate::core::{MethodologyView, UseCase};
+use crate::config::{Config, OutputFormat, StorageBackend};
+use crate::core::{to_snake_case, MethodologyView, UseCase};
 
 /// Manages output filenames for use case documentation.
 pub struct OutputManager;
@@ -12,17 +19,55 @@ pub struct OutputManager;
 impl OutputManager {
     /// Generates all filenames for a use case based on its enabled views.
     ///
-    /// Returns a vector of (filename, view) tuples for each enabled view.
+    /// Returns a vector of (filename, view) tuples for each enabled view,
+    /// preceded by the canonical filename when a primary view is designated.
     /// Every use case must have at least one view.
-    pub fn generate_all_filenames(use_case: &UseCase) -> Vec<(String, MethodologyView)> {
-        use_case
-            .enabled_views()
-            .map(|view| {
-                let filename = format!("{}-{}.md", use_case.id, view.key());
+    pub fn generate_all_filenames(
+        use_case: &UseCase,
+        format: OutputFormat,
+    ) -> Vec<(String, MethodologyView)> {
+        let canonical = use_case
+            .designated_primary_view()
+            .map(|view| (Self::canonical_filename(use_case, format), view.clone()));
+        canonical
+            .into_iter()
+            .chain(use_case.enabled_views().map(|view| {
+                let filename = format!("{}-{}.{}", use_case.id, view.key(), format.extension());
                 (filename, view.clone())
-            })
+            }))
             .collect()
     }
+
+    /// Canonical filename of a use case, generated from its primary view
+    pub fn canonical_filename(use_case: &UseCase, format: OutputFormat) -> String {
+        format!("{}.{}", use_case.id, format.extension())
+    }
+
+    /// Filename of the document links and exports point to: the canonical
+    /// filename when there is one, otherwise the first view's
+    pub fn primary_filename(use_case: &UseCase, format: OutputFormat) -> String {
+        Self::generate_all_filenames(use_case, format)
+            .into_iter()
+            .next()
+            .map(|(filename, _)| filename)
+            .unwrap_or_else(|| Self::canonical_filename(use_case, format))
+    }
+
+    /// Path of the generated document `filename` of a use case, where the
+    /// storage backend writes it: the category directory of `use_case_dir`,
+    /// or `markdown/` next to the SQLite database
+    pub fn document_path(config: &Config, use_case: &UseCase, filename: &str) -> PathBuf {
+        match config.storage.backend {
+            StorageBackend::Toml | StorageBackend::Events => {
+                Path::new(&config.directories.use_case_dir)
+                    .join(to_snake_case(&use_case.category))
+                    .join(filename)
+            }
+            StorageBackend::Sqlite => Path::new(&config.directories.data_dir)
+                .join("markdown")
+                .join(filename),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -47,7 +92,7 @@ mod tests {
             "normal".to_string(),
         ));
 
-        let filenames = OutputManager::generate_all_filenames(&use_case);
+        let filenames = OutputManager::generate_all_filenames(&use_case, OutputFormat::Markdown);
 
         assert_eq!(filenames.len(), 1);
         assert_eq!(filenames[0].0, "UC-001-business-normal.md");
@@ -75,7 +120,7 @@ mod tests {
             "normal".to_string(),
         ));
 
-        let filenames = OutputManager::generate_all_filenames(&use_case);
+        let filenames = OutputManager::generate_all_filenames(&use_case, OutputFormat::Markdown);
 
         assert_eq!(filenames.len(), 2);
 
@@ -109,10 +154,36 @@ mod tests {
         disabled_view.enabled = false;
         use_case.add_view(disabled_view);
 
-        let filenames = OutputManager::generate_all_filenames(&use_case);
+        let filenames = OutputManager::generate_all_filenames(&use_case, OutputFormat::Markdown);
 
         // Should only include the enabled view
         assert_eq!(filenames.len(), 1);
         assert_eq!(filenames[0].0, "UC-001-feature-simple.md");
     }
+
+    #[test]
+    fn test_generate_all_filenames_with_primary_view() {
+        let mut use_case = UseCase::new(
+            "UC-001".to_string(),
+            "Test Use Case".to_string(),
+            "testing".to_string(),
+            "Description".to_string(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        use_case.add_view(MethodologyView::new("feature", "simple"));
+        use_case.add_view(MethodologyView::new("business", "normal"));
+        use_case.set_primary_view("business").unwrap();
+
+        let filenames = OutputManager::generate_all_filenames(&use_case, OutputFormat::Markdown);
+
+        assert_eq!(filenames.len(), 3);
+        assert_eq!(filenames[0].0, "UC-001.md");
+        assert_eq!(filenames[0].1.methodology, "business");
+        assert_eq!(filenames[2].0, "UC-001-business-normal.md");
+
+        let filenames = OutputManager::generate_all_filenames(&use_case, OutputFormat::Asciidoc);
+        assert_eq!(filenames[0].0, "UC-001.adoc");
+        assert_eq!(filenames[2].0, "UC-001-business-normal.adoc");
+    }
 }
@@ -1,20 +1,32 @@
 //! Overview generator for project documentation.
 //!
 //! Handles generation of project overview documentation that summarizes all use cases.
+//! The overview is made of sections (see [`OverviewSection`]) chosen and ordered
+//! by `generation.overview_sections` or the default methodology.
 
-use anyhow::Result;
+use crate::core::Result;
 use serde_json::{json, Value};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::PathBuf;
 
-use crate::config::Config;
+use super::front_matter::page_front_matter;
+use super::{overview_sections, IdLinks};
+use crate::config::{Config, OverviewSection, ProjectContext};
 use crate::core::file_operations::FileOperations;
-use crate::core::{TemplateEngine, UseCase};
+use crate::core::utils::{field_values, link_path, normalize_line_endings};
+use crate::core::{to_snake_case, ActorEntity, MethodologyDefinition, TemplateEngine, UseCase};
+
+/// Template data of one use case in the overview
+type UseCaseData = serde_json::Map<String, Value>;
 
 /// Generator for project overview documentation.
 pub struct OverviewGenerator {
     config: Config,
     file_operations: FileOperations,
     template_engine: TemplateEngine,
+    /// Sections of each overview page, in order
+    sections: Vec<OverviewSection>,
 }
 
 impl OverviewGenerator {
@@ -22,24 +34,296 @@ impl OverviewGenerator {
     pub fn new(config: Config) -> Self {
         let file_operations = FileOperations::new(config.clone());
         let template_engine = TemplateEngine::with_config(Some(&config));
+        let sections = config
+            .generation
+            .overview_sections
+            .clone()
+            .unwrap_or_else(|| OverviewSection::DEFAULT.to_vec());
         Self {
             config,
             file_operations,
             template_engine,
+            sections,
+        }
+    }
+
+    /// Creates an overview generator for the project described by `context`.
+    ///
+    /// Without `generation.overview_sections`, the sections declared in the
+    /// default methodology's `[overview]` section are used, if any.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        let config = context.resolved_config();
+        let sections = config
+            .generation
+            .overview_sections
+            .clone()
+            .or_else(|| {
+                let methodology_dir = context
+                    .templates_dir()
+                    .join("methodologies")
+                    .join(&config.templates.default_methodology);
+                MethodologyDefinition::from_toml(methodology_dir)
+                    .ok()?
+                    .overview_sections()
+                    .map(<[OverviewSection]>::to_vec)
+            })
+            .unwrap_or_else(|| OverviewSection::DEFAULT.to_vec());
+        Self {
+            file_operations: FileOperations::new(config.clone()),
+            template_engine: TemplateEngine::for_project(context),
+            config,
+            sections,
         }
     }
 
+    /// Sections of each overview page, in order
+    pub fn sections(&self) -> &[OverviewSection] {
+        &self.sections
+    }
+
     /// Generates and saves the project overview file.
     ///
-    /// Creates an overview document that includes:
-    /// - Project name and generation date
-    /// - Total use case count
-    /// - Use cases grouped by category with id, title, status, and priority
-    pub fn generate(&self, use_cases: &[UseCase]) -> Result<()> {
+    /// Creates an overview document with the project name, the generation
+    /// date and the configured sections:
+    /// - `summary`: use case, scenario and category counts
+    /// - `table`: use cases grouped by category (or by `generation.overview_group_by`)
+    ///   with id, title, status, priority and scenario count
+    /// - `progress`: use cases per status and the share implemented
+    /// - `recent_changes`: the most recently updated use cases
+    /// - `risks`: open high-priority use cases and overdue reviews
+    /// - `actors`: the actor hierarchy with use case counts per actor subtree
+    ///
+    /// Projects with more use cases than `generation.overview_shard_threshold`
+    /// get one overview page per category instead, next to that category's
+    /// use case files, and the top-level overview becomes an index linking them.
+    ///
+    /// With `generation.category_overviews`, each category directory also gets
+    /// an `overview.md` of its own use cases, so the directory reads on its own.
+    pub fn generate(&self, use_cases: &[UseCase], actors: &[ActorEntity]) -> Result<()> {
+        let shard = should_shard(
+            use_cases.len(),
+            self.config.generation.overview_shard_threshold,
+        );
+        let category_overviews = self.config.generation.category_overviews;
+        if !shard {
+            let all: Vec<&UseCase> = use_cases.iter().collect();
+            let data = self.page_data(&all, actors, self.group(all.iter().copied())?);
+            self.file_operations.write_overview(|writer| {
+                self.write_front_matter(&self.config.project.name, writer)?;
+                self.template_engine.render_overview_to(&data, writer)
+            })?;
+            if !category_overviews {
+                return Ok(());
+            }
+        }
+
+        // Category pages are grouped like a single-page overview
+        let mut categories_map: BTreeMap<&str, Vec<&UseCase>> = BTreeMap::new();
+        for uc in use_cases {
+            categories_map
+                .entry(uc.category.as_str())
+                .or_default()
+                .push(uc);
+        }
+        let links = IdLinks::with_format(use_cases, self.config.generation.output_format);
+
+        let mut index_categories = Vec::new();
+        for (category_name, category_use_cases) in categories_map {
+            let mut entry = serde_json::Map::new();
+            entry.insert("category_name".to_string(), json!(category_name));
+            entry.insert(
+                "use_case_count".to_string(),
+                json!(category_use_cases.len()),
+            );
+            entry.insert("page".to_string(), json!(category_page(category_name)));
+            index_categories.push(entry);
+
+            let data = self.category_data(category_name, &category_use_cases, actors, &links)?;
+            if shard {
+                self.file_operations.write_category_overview(
+                    category_name,
+                    "README.md",
+                    |writer| {
+                        self.write_front_matter(category_name, writer)?;
+                        self.template_engine.render_overview_to(&data, writer)
+                    },
+                )?;
+            }
+            if category_overviews {
+                self.file_operations.write_category_overview(
+                    category_name,
+                    CATEGORY_OVERVIEW_FILE,
+                    |writer| {
+                        self.write_front_matter(category_name, writer)?;
+                        self.template_engine.render_overview_to(&data, writer)
+                    },
+                )?;
+            }
+        }
+        if !shard {
+            return Ok(());
+        }
+
+        let mut data = self.base_data(use_cases.len());
+        data.insert("category_count".to_string(), json!(index_categories.len()));
+        data.insert("categories".to_string(), json!(index_categories));
+        self.file_operations.write_overview(|writer| {
+            self.write_front_matter(&self.config.project.name, writer)?;
+            self.template_engine.render_overview_index_to(&data, writer)
+        })
+    }
+
+    /// Generates the named overview `name` (`overviews/<name>.hbs`) into its own file.
+    ///
+    /// Named overviews are always a single page. Besides the data of the
+    /// regular overview, each use case gets its `description` and `scenarios`.
+    /// Returns the path of the written file.
+    pub fn generate_named(
+        &self,
+        use_cases: &[UseCase],
+        actors: &[ActorEntity],
+        name: &str,
+    ) -> Result<PathBuf> {
+        self.template_engine.check_overview_name(name)?;
+        let mut groups = self.group(use_cases.iter())?;
+        let by_id: HashMap<&str, &UseCase> =
+            use_cases.iter().map(|uc| (uc.id.as_str(), uc)).collect();
+        for (_, group) in &mut groups {
+            for uc_data in group {
+                let Some(uc) = uc_data
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .and_then(|id| by_id.get(id))
+                else {
+                    continue;
+                };
+                let scenarios: Vec<Value> = uc
+                    .scenarios
+                    .iter()
+                    .map(|scenario| {
+                        json!({
+                            "id": scenario.id,
+                            "title": scenario.title,
+                            "scenario_type": scenario.scenario_type.to_string(),
+                            "status": scenario.status.display_name(),
+                        })
+                    })
+                    .collect();
+                uc_data.insert("description".to_string(), json!(uc.description));
+                uc_data.insert("scenarios".to_string(), json!(scenarios));
+            }
+        }
+
+        let use_cases: Vec<&UseCase> = use_cases.iter().collect();
+        let mut data = self.page_data(&use_cases, actors, groups);
+        data.insert("overview_name".to_string(), json!(name));
+
+        self.file_operations.write_named_overview(name, |writer| {
+            self.write_front_matter(name, writer)?;
+            self.template_engine
+                .render_named_overview_to(name, &data, writer)
+        })
+    }
+
+    /// Names of the named overview templates of the project
+    pub fn overview_names(&self) -> &[String] {
+        self.template_engine.overview_names()
+    }
+
+    /// Group use cases by category, or by the `generation.overview_group_by` field
+    ///
+    /// Groups are sorted by name. With a custom field, use cases without a
+    /// value end up in a trailing "Ungrouped" group, and a list value (such
+    /// as tags) puts the use case in one group per item.
+    fn group<'u>(
+        &self,
+        use_cases: impl Iterator<Item = &'u UseCase>,
+    ) -> Result<Vec<(String, Vec<UseCaseData>)>> {
+        let group_by = self.config.generation.overview_group_by.as_deref();
+        let mut groups: BTreeMap<String, Vec<UseCaseData>> = BTreeMap::new();
+        let mut ungrouped = Vec::new();
+        for uc in use_cases {
+            let mut uc_data = serde_json::Map::new();
+            uc_data.insert("id".to_string(), json!(uc.id));
+            uc_data.insert("title".to_string(), json!(uc.title));
+            uc_data.insert(
+                "aggregated_status".to_string(),
+                json!(uc.status().display_name()),
+            );
+            uc_data.insert("priority".to_string(), json!(uc.priority.to_string()));
+            uc_data.insert("scenario_count".to_string(), json!(uc.scenarios.len()));
+
+            // Views of multi-view use cases, in `templates.view_order` precedence
+            let mut views: Vec<_> = uc.enabled_views().collect();
+            views.sort_by_key(|view| view.precedence(&self.config.templates.view_order));
+            let view_keys: Vec<String> = views.iter().map(|view| view.key()).collect();
+            uc_data.insert("multi_view".to_string(), json!(view_keys.len() > 1));
+            uc_data.insert("views".to_string(), json!(view_keys));
+
+            let keys = match group_by {
+                Some(path) => field_values(&serde_json::to_value(uc)?, path),
+                None => vec![uc.category.clone()],
+            };
+            if keys.is_empty() {
+                ungrouped.push(uc_data);
+                continue;
+            }
+            for key in keys {
+                groups.entry(key).or_default().push(uc_data.clone());
+            }
+        }
+
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        if !ungrouped.is_empty() {
+            groups.push((UNGROUPED.to_string(), ungrouped));
+        }
+        Ok(groups)
+    }
+
+    /// Template data of the overview page of one category
+    ///
+    /// Besides the regular page data, the page gets its `category` and each
+    /// use case a `link` to its document, relative to the category directory.
+    fn category_data(
+        &self,
+        category: &str,
+        use_cases: &[&UseCase],
+        actors: &[ActorEntity],
+        links: &IdLinks,
+    ) -> Result<HashMap<String, Value>> {
+        let mut groups = self.group(use_cases.iter().copied())?;
+        for (_, group) in &mut groups {
+            for uc_data in group {
+                let link = uc_data
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .and_then(|id| links.path_from(id, category));
+                if let Some(link) = link {
+                    uc_data.insert("link".to_string(), json!(link));
+                }
+            }
+        }
+        let mut data = self.page_data(use_cases, actors, groups);
+        data.insert("category".to_string(), json!(category));
+        Ok(data)
+    }
+
+    /// Writes the front matter of a page titled `title`, when enabled
+    fn write_front_matter(&self, title: &str, writer: &mut dyn Write) -> Result<()> {
+        if let Some(front_matter) = page_front_matter(&self.config.generation.front_matter, title)?
+        {
+            let eol = self.config.generation.line_endings.as_str();
+            writer.write_all(normalize_line_endings(&front_matter, eol).as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Template data shared by overview pages and the index
+    fn base_data(&self, total_use_cases: usize) -> HashMap<String, Value> {
         let mut data = HashMap::new();
 
         // Basic counts
-        data.insert("total_use_cases".to_string(), json!(use_cases.len()));
+        data.insert("total_use_cases".to_string(), json!(total_use_cases));
 
         // Project name and generated date
         data.insert("project_name".to_string(), json!(self.config.project.name));
@@ -47,43 +331,213 @@ impl OverviewGenerator {
             "generated_date".to_string(),
             json!(chrono::Utc::now().format("%Y-%m-%d").to_string()),
         );
+        data
+    }
 
-        // Group use cases by category
-        let mut categories_map: HashMap<String, Vec<serde_json::Map<String, Value>>> =
-            HashMap::new();
-        for uc in use_cases {
-            categories_map
-                .entry(uc.category.clone())
-                .or_default()
-                .push({
-                    let mut uc_data = serde_json::Map::new();
-                    uc_data.insert("id".to_string(), json!(uc.id));
-                    uc_data.insert("title".to_string(), json!(uc.title));
-                    uc_data.insert(
-                        "aggregated_status".to_string(),
-                        json!(uc.status().display_name()),
-                    );
-                    uc_data.insert("priority".to_string(), json!(uc.priority.to_string()));
-                    uc_data
-                });
-        }
+    /// Template data for an overview page of `use_cases`, listed in the given groups
+    ///
+    /// The configured sections are passed as `sections`, each with its `name`.
+    /// The data of the summary, progress and table sections is also passed at
+    /// the top level, for templates written before sections existed.
+    ///
+    /// Groups are passed as `categories` (with `category_name`) whatever they
+    /// are grouped by, so templates written for category grouping keep working;
+    /// `group_by` names the field when it is not the category.
+    fn page_data(
+        &self,
+        use_cases: &[&UseCase],
+        actors: &[ActorEntity],
+        groups: Vec<(String, Vec<UseCaseData>)>,
+    ) -> HashMap<String, Value> {
+        let mut data = self.base_data(use_cases.len());
+        data.insert(
+            "total_scenarios".to_string(),
+            json!(overview_sections::total_scenarios(use_cases)),
+        );
+        data.insert(
+            "status_counts".to_string(),
+            json!(overview_sections::status_counts(use_cases)),
+        );
 
         // Convert to array format expected by template
-        let categories: Vec<serde_json::Map<String, Value>> = categories_map
+        let categories: Vec<serde_json::Map<String, Value>> = groups
             .into_iter()
             .map(|(category_name, use_cases)| {
                 let mut cat = serde_json::Map::new();
+                cat.insert(
+                    "ungrouped".to_string(),
+                    json!(
+                        self.config.generation.overview_group_by.is_some()
+                            && category_name == UNGROUPED
+                    ),
+                );
                 cat.insert("category_name".to_string(), json!(category_name));
                 cat.insert("use_cases".to_string(), json!(use_cases));
                 cat
             })
             .collect();
 
-        data.insert("categories".to_string(), json!(categories));
+        let mut table = serde_json::Map::new();
+        table.insert("categories".to_string(), json!(categories));
+        if let Some(group_by) = &self.config.generation.overview_group_by {
+            table.insert("group_by".to_string(), json!(group_by));
+        }
 
-        let overview_content = self.template_engine.render_overview(&data)?;
-        self.file_operations.save_overview(&overview_content)?;
+        let today = chrono::Utc::now().date_naive();
+        let sections: Vec<Value> = self
+            .sections
+            .iter()
+            .map(|section| {
+                let mut section_data = match section {
+                    OverviewSection::Summary => overview_sections::summary(use_cases),
+                    OverviewSection::Table => table.clone(),
+                    OverviewSection::Progress => overview_sections::progress(use_cases),
+                    OverviewSection::RecentChanges => overview_sections::recent_changes(use_cases),
+                    OverviewSection::Risks => overview_sections::risks(use_cases, today),
+                    OverviewSection::Actors => overview_sections::actors(use_cases, actors),
+                };
+                section_data.insert("name".to_string(), json!(section.name()));
+                Value::Object(section_data)
+            })
+            .collect();
+        data.insert("sections".to_string(), json!(sections));
+        data.extend(table);
+        data
+    }
+}
 
-        Ok(())
+/// Name of the group of use cases without a value for `overview_group_by`
+const UNGROUPED: &str = "Ungrouped";
+
+/// File name of the overview written into each category directory with
+/// `generation.category_overviews`
+pub(crate) const CATEGORY_OVERVIEW_FILE: &str = "overview.md";
+
+/// Whether an overview of `count` use cases is split per category
+pub(crate) fn should_shard(count: usize, threshold: usize) -> bool {
+    threshold > 0 && count > threshold
+}
+
+/// Path of a category's overview page, relative to the top-level overview
+fn category_page(category: &str) -> String {
+    link_path(&[to_snake_case(category).as_str(), "README.md"])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::OutputFormat;
+
+    #[test]
+    fn test_should_shard_above_threshold_only() {
+        assert!(!should_shard(500, 500));
+        assert!(should_shard(501, 500));
+        assert!(!should_shard(10_000, 0));
+    }
+
+    #[test]
+    fn test_group_by_field_puts_missing_values_last() {
+        let mut config = Config::default();
+        config.generation.overview_group_by = Some("sprint".to_string());
+        let generator = OverviewGenerator::new(config);
+        let mut use_cases = Vec::new();
+        for (id, sprint) in [
+            ("UC-A-001", None),
+            ("UC-A-002", Some("S2")),
+            ("UC-A-003", Some("S1")),
+        ] {
+            let mut uc = UseCase::new(
+                id.to_string(),
+                "Title".to_string(),
+                "A".to_string(),
+                String::new(),
+                "medium".to_string(),
+            )
+            .unwrap();
+            if let Some(sprint) = sprint {
+                uc.extra.insert("sprint".to_string(), json!(sprint));
+            }
+            use_cases.push(uc);
+        }
+
+        let groups = generator.group(use_cases.iter()).unwrap();
+        let names: Vec<&str> = groups.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["S1", "S2", UNGROUPED]);
+        assert_eq!(groups[2].1[0]["id"], "UC-A-001");
+    }
+
+    #[test]
+    fn test_page_data_lists_sections_in_configured_order() {
+        let mut config = Config::default();
+        config.generation.overview_sections =
+            Some(vec![OverviewSection::Risks, OverviewSection::Table]);
+        let generator = OverviewGenerator::new(config);
+        let uc = UseCase::new(
+            "UC-A-001".to_string(),
+            "Title".to_string(),
+            "A".to_string(),
+            String::new(),
+            "high".to_string(),
+        )
+        .unwrap();
+
+        let groups = generator.group([&uc].into_iter()).unwrap();
+        let data = generator.page_data(&[&uc], &[], groups);
+        let sections = data["sections"].as_array().unwrap();
+        let names: Vec<&str> = sections
+            .iter()
+            .map(|s| s["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["risks", "table"]);
+        assert_eq!(sections[0]["use_cases"][0]["id"], "UC-A-001");
+        assert_eq!(sections[1]["categories"], data["categories"]);
+        assert_eq!(data["total_scenarios"], 0);
+    }
+
+    #[test]
+    fn test_generate_named_rejects_unknown_overview() {
+        let generator = OverviewGenerator::new(Config::default());
+        let err = generator
+            .generate_named(&[], &[], "no-such-overview")
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Overview template 'no-such-overview' not found"));
+    }
+
+    #[test]
+    fn test_category_data_links_use_cases_from_their_directory() {
+        let generator = OverviewGenerator::new(Config::default());
+        let mut use_cases = Vec::new();
+        for (id, category) in [("UC-USE-001", "User Management"), ("UC-BIL-001", "Billing")] {
+            let mut uc = UseCase::new(
+                id.to_string(),
+                "Title".to_string(),
+                category.to_string(),
+                String::new(),
+                "medium".to_string(),
+            )
+            .unwrap();
+            uc.add_view(crate::core::MethodologyView::new("feature", "normal"));
+            use_cases.push(uc);
+        }
+        let links = IdLinks::with_format(&use_cases, OutputFormat::Markdown);
+
+        let data = generator
+            .category_data("User Management", &[&use_cases[0]], &[], &links)
+            .unwrap();
+        assert_eq!(data["category"], "User Management");
+        assert_eq!(data["total_use_cases"], 1);
+        let listed = &data["categories"][0]["use_cases"][0];
+        assert_eq!(listed["id"], "UC-USE-001");
+        assert_eq!(listed["link"], "UC-USE-001-feature-normal.md");
+    }
+
+    #[test]
+    fn test_category_page_matches_use_case_directory() {
+        assert_eq!(
+            category_page("User Management"),
+            "user_management/README.md"
+        );
     }
 }
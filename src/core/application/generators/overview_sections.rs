@@ -0,0 +1,259 @@
+//! Data of the composable sections of the project overview.
+//!
+//! The overview passes its sections to the template as a `sections` list in
+//! the configured order, each entry carrying its `name` and its own data, so
+//! templates render them with `{{#each sections}}` and `{{#if (eq name "...")}}`.
+//! The table section is assembled by the overview generator, which owns the
+//! grouping of use cases.
+
+use chrono::NaiveDate;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+use crate::core::{ActorEntity, ActorHierarchy, Priority, Status, UseCase};
+
+/// Number of use cases listed under recent changes
+pub(crate) const RECENT_CHANGES_LIMIT: usize = 10;
+
+/// Use case, scenario and category counts
+pub(crate) fn summary(use_cases: &[&UseCase]) -> Map<String, Value> {
+    let mut categories: Vec<&str> = use_cases.iter().map(|uc| uc.category.as_str()).collect();
+    categories.sort_unstable();
+    categories.dedup();
+
+    let mut data = Map::new();
+    data.insert("total_use_cases".to_string(), json!(use_cases.len()));
+    data.insert(
+        "total_scenarios".to_string(),
+        json!(total_scenarios(use_cases)),
+    );
+    data.insert("category_count".to_string(), json!(categories.len()));
+    data
+}
+
+/// Use cases per status in lifecycle order, and the share that is implemented
+///
+/// Implemented, tested and deployed use cases count as implemented.
+pub(crate) fn progress(use_cases: &[&UseCase]) -> Map<String, Value> {
+    let total = use_cases.len();
+    let statuses: Vec<Value> = Status::ALL
+        .iter()
+        .filter_map(|status| {
+            let count = use_cases.iter().filter(|uc| uc.status() == *status).count();
+            (count > 0).then(|| {
+                json!({
+                    "status": status.display_name(),
+                    "count": count,
+                    "percent": percent(count, total),
+                })
+            })
+        })
+        .collect();
+    let implemented = use_cases
+        .iter()
+        .filter(|uc| is_implemented(uc.status()))
+        .count();
+
+    let mut data = Map::new();
+    data.insert("statuses".to_string(), json!(statuses));
+    data.insert("implemented".to_string(), json!(implemented));
+    data.insert(
+        "percent_implemented".to_string(),
+        json!(percent(implemented, total)),
+    );
+    data
+}
+
+/// The most recently updated use cases, newest first
+pub(crate) fn recent_changes(use_cases: &[&UseCase]) -> Map<String, Value> {
+    let mut recent = use_cases.to_vec();
+    recent.sort_by(|a, b| {
+        b.metadata
+            .updated_at
+            .cmp(&a.metadata.updated_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    let recent: Vec<Value> = recent
+        .into_iter()
+        .take(RECENT_CHANGES_LIMIT)
+        .map(|uc| {
+            json!({
+                "id": uc.id,
+                "title": uc.title,
+                "aggregated_status": uc.status().display_name(),
+                "updated": uc.metadata.updated_at.format("%Y-%m-%d").to_string(),
+            })
+        })
+        .collect();
+
+    let mut data = Map::new();
+    data.insert("use_cases".to_string(), json!(recent));
+    data
+}
+
+/// High and critical use cases that are not implemented, and overdue reviews
+///
+/// Each use case lists its `reasons`; critical ones come first.
+pub(crate) fn risks(use_cases: &[&UseCase], today: NaiveDate) -> Map<String, Value> {
+    let mut at_risk: Vec<(&UseCase, Vec<String>)> = use_cases
+        .iter()
+        .filter_map(|uc| {
+            let mut reasons = Vec::new();
+            let status = uc.status();
+            if matches!(uc.priority, Priority::High | Priority::Critical)
+                && !is_implemented(status)
+                && status != Status::Deprecated
+            {
+                reasons.push(format!(
+                    "{} priority, {}",
+                    uc.priority,
+                    status.display_name()
+                ));
+            }
+            if uc.metadata.is_review_due(today) {
+                if let Some(review_by) = uc.metadata.review_by {
+                    reasons.push(format!("review due since {}", review_by));
+                }
+            }
+            (!reasons.is_empty()).then_some((*uc, reasons))
+        })
+        .collect();
+    at_risk.sort_by(|(a, _), (b, _)| {
+        (a.priority != Priority::Critical)
+            .cmp(&(b.priority != Priority::Critical))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    let at_risk: Vec<Value> = at_risk
+        .into_iter()
+        .map(|(uc, reasons)| {
+            json!({
+                "id": uc.id,
+                "title": uc.title,
+                "priority": uc.priority.to_string(),
+                "aggregated_status": uc.status().display_name(),
+                "reasons": reasons,
+            })
+        })
+        .collect();
+
+    let mut data = Map::new();
+    data.insert("use_cases".to_string(), json!(at_risk));
+    data
+}
+
+/// Actors in hierarchy order, each with the use cases involving it directly
+/// and over its subtree
+///
+/// `indent` holds two spaces per level, for nested markdown lists.
+pub(crate) fn actors(use_cases: &[&UseCase], actors: &[ActorEntity]) -> Map<String, Value> {
+    let hierarchy = ActorHierarchy::new(actors);
+    let rollups = hierarchy.rollups(use_cases.iter().copied());
+    let actors: Vec<Value> = hierarchy
+        .walk()
+        .into_iter()
+        .map(|(actor, depth)| {
+            let rollup = rollups.get(actor.id.as_str());
+            json!({
+                "id": actor.id,
+                "name": actor.name,
+                "emoji": actor.emoji,
+                "depth": depth,
+                "indent": "  ".repeat(depth),
+                "use_case_count": rollup.map_or(0, |r| r.direct.len()),
+                "rollup_count": rollup.map_or(0, |r| r.total.len()),
+            })
+        })
+        .collect();
+
+    let mut data = Map::new();
+    data.insert("actors".to_string(), json!(actors));
+    data
+}
+
+/// Number of use cases per status display name
+pub(crate) fn status_counts(use_cases: &[&UseCase]) -> BTreeMap<&'static str, usize> {
+    let mut counts = BTreeMap::new();
+    for uc in use_cases {
+        *counts.entry(uc.status().display_name()).or_default() += 1;
+    }
+    counts
+}
+
+pub(crate) fn total_scenarios(use_cases: &[&UseCase]) -> usize {
+    use_cases.iter().map(|uc| uc.scenarios.len()).sum()
+}
+
+fn is_implemented(status: Status) -> bool {
+    matches!(
+        status,
+        Status::Implemented | Status::Tested | Status::Deployed
+    )
+}
+
+/// `count` as a whole percentage of `total`
+fn percent(count: usize, total: usize) -> usize {
+    (count * 100).checked_div(total).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Scenario;
+
+    fn use_case(id: &str, priority: &str, status: Option<Status>) -> UseCase {
+        let mut builder = UseCase::builder(id, "Title")
+            .category("Auth")
+            .priority(priority.parse().unwrap());
+        if let Some(status) = status {
+            builder = builder.scenario(Scenario::builder("Main").status(status));
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_progress_counts_statuses_in_lifecycle_order() {
+        let use_cases = [
+            use_case("UC-AUT-001", "low", Some(Status::Tested)),
+            use_case("UC-AUT-002", "low", None),
+            use_case("UC-AUT-003", "low", Some(Status::InProgress)),
+            use_case("UC-AUT-004", "low", None),
+        ];
+        let refs: Vec<&UseCase> = use_cases.iter().collect();
+
+        let data = progress(&refs);
+        let statuses: Vec<&str> = data["statuses"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["status"].as_str().unwrap())
+            .collect();
+        assert_eq!(statuses, vec!["PLANNED", "IN_PROGRESS", "TESTED"]);
+        assert_eq!(data["statuses"][0]["percent"], 50);
+        assert_eq!(data["implemented"], 1);
+        assert_eq!(data["percent_implemented"], 25);
+    }
+
+    #[test]
+    fn test_risks_lists_open_high_priority_and_overdue_reviews() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let mut overdue = use_case("UC-AUT-004", "low", Some(Status::Deployed));
+        overdue.metadata.review_by = NaiveDate::from_ymd_opt(2026, 2, 1);
+        let use_cases = [
+            use_case("UC-AUT-001", "high", None),
+            use_case("UC-AUT-002", "critical", Some(Status::InProgress)),
+            use_case("UC-AUT-003", "critical", Some(Status::Implemented)),
+            overdue,
+        ];
+        let refs: Vec<&UseCase> = use_cases.iter().collect();
+
+        let data = risks(&refs, today);
+        let at_risk = data["use_cases"].as_array().unwrap();
+        let ids: Vec<&str> = at_risk
+            .iter()
+            .map(|uc| uc["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["UC-AUT-002", "UC-AUT-001", "UC-AUT-004"]);
+        assert_eq!(at_risk[0]["reasons"][0], "CRITICAL priority, IN_PROGRESS");
+        assert_eq!(at_risk[2]["reasons"][0], "review due since 2026-02-01");
+    }
+}
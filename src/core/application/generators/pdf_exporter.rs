@@ -0,0 +1,207 @@
+//! PDF export: use cases compiled into one printable document.
+//!
+//! The generated markdown of each use case is converted to HTML and wrapped in
+//! the `pdf-document.hbs` layout (cover page with a sign-off table, one use
+//! case per page). The HTML is then handed to the external converter of
+//! `[export] pdf_command`, so any HTML-to-PDF tool (wkhtmltopdf, WeasyPrint, a
+//! headless browser) can produce the PDF.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::config::{Config, OutputFormat, ProjectContext};
+use crate::core::file_operations::FileOperations;
+use crate::core::utils::start_progress;
+use crate::core::{MucmError, Result, TemplateEngine, UseCase};
+
+use super::front_matter::strip_front_matter;
+use super::html_exporter::{markdown_to_html, Pages};
+use super::OutputManager;
+
+/// Exporter of use cases to a PDF document
+pub struct PdfExporter {
+    config: Config,
+    template_engine: TemplateEngine,
+}
+
+impl PdfExporter {
+    /// Creates a PDF exporter for the project described by `context`.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        Self {
+            config: context.resolved_config(),
+            template_engine: TemplateEngine::for_project(context),
+        }
+    }
+
+    /// Compiles `use_cases` into the PDF file `output`
+    ///
+    /// The markdown must be up to date. The intermediate HTML document is
+    /// written next to `output` and removed once the PDF is written.
+    ///
+    /// # Errors
+    /// Returns an error when the converter cannot be started, fails, or does
+    /// not write the PDF; the HTML document is kept for inspection.
+    pub fn export(&self, use_cases: &[UseCase], output: &Path) -> Result<()> {
+        let mut use_cases: Vec<&UseCase> = use_cases.iter().collect();
+        use_cases.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let progress = start_progress(use_cases.len() as u64, "Exporting");
+        let mut sections = Vec::new();
+        for use_case in &use_cases {
+            let markdown = std::fs::read_to_string(self.markdown_path(use_case)).map_err(|e| {
+                MucmError::Io(e).context(format!("Failed to read the markdown of {}", use_case.id))
+            })?;
+            sections.push(json!({
+                "id": use_case.id,
+                "title": use_case.title,
+                "content": markdown_to_html(strip_front_matter(&markdown), "", &Pages::new()),
+            }));
+            progress.inc();
+        }
+        progress.finish();
+
+        let title = match use_cases.as_slice() {
+            [use_case] => format!("{}: {}", use_case.id, use_case.title),
+            _ => "Use Case Specification".to_string(),
+        };
+        let mut data: HashMap<String, Value> = HashMap::new();
+        data.insert("title".to_string(), json!(title));
+        data.insert("project_name".to_string(), json!(self.config.project.name));
+        data.insert(
+            "date".to_string(),
+            json!(chrono::Local::now().format("%Y-%m-%d").to_string()),
+        );
+        data.insert("use_case_count".to_string(), json!(sections.len()));
+        data.insert("use_cases".to_string(), json!(sections));
+
+        if let Some(dir) = output.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let html = output.with_extension("html");
+        FileOperations::write_streamed(&html, |writer| {
+            self.template_engine.render_pdf_document_to(&data, writer)
+        })?;
+
+        run_converter(&self.config.export.pdf_command, &html, output)?;
+        std::fs::remove_file(&html)?;
+        Ok(())
+    }
+
+    /// Generated markdown file of a use case (its primary view)
+    fn markdown_path(&self, use_case: &UseCase) -> PathBuf {
+        let filename = OutputManager::primary_filename(use_case, OutputFormat::Markdown);
+        OutputManager::document_path(&self.config, use_case, &filename)
+    }
+}
+
+/// Arguments of the converter command, with `{input}` and `{output}` replaced
+fn converter_args(command: &str, input: &Path, output: &Path) -> Vec<String> {
+    let input = input.to_string_lossy();
+    let output = output.to_string_lossy();
+    command
+        .split_whitespace()
+        .map(|arg| arg.replace("{input}", &input).replace("{output}", &output))
+        .collect()
+}
+
+/// Runs the converter of `export.pdf_command` on the HTML document
+fn run_converter(command: &str, input: &Path, output: &Path) -> Result<()> {
+    if !command.contains("{input}") || !command.contains("{output}") {
+        return Err(MucmError::Validation(format!(
+            "export.pdf_command '{}' must contain {{input}} and {{output}}",
+            command
+        )));
+    }
+    let args = converter_args(command, input, output);
+    let (program, args) = args
+        .split_first()
+        .ok_or_else(|| MucmError::Validation("export.pdf_command must not be empty".to_string()))?;
+
+    // A stale PDF must not pass for the converter's output
+    if output.exists() {
+        std::fs::remove_file(output)?;
+    }
+    let result = Command::new(program).args(args).output();
+    let converted = match result {
+        Ok(result) if result.status.success() => output.is_file(),
+        Ok(result) => {
+            return Err(MucmError::Validation(format!(
+                "PDF converter '{}' failed ({}): {}\nThe HTML document is kept at {}",
+                program,
+                result.status,
+                String::from_utf8_lossy(&result.stderr).trim(),
+                input.display()
+            )))
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            return Err(MucmError::NotFound(format!(
+                "PDF converter '{}' not found. Install it, or set [export] pdf_command \
+                 in the configuration to another HTML-to-PDF converter \
+                 (e.g. \"weasyprint {{input}} {{output}}\").\nThe HTML document is kept at {}",
+                program,
+                input.display()
+            )))
+        }
+        Err(e) => return Err(MucmError::Io(e).context(format!("Failed to run '{}'", program))),
+    };
+    if !converted {
+        return Err(MucmError::Validation(format!(
+            "PDF converter '{}' did not write {}. The HTML document is kept at {}",
+            program,
+            output.display(),
+            input.display()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converter_args_replace_placeholders() {
+        let args = converter_args(
+            "wkhtmltopdf --quiet {input} {output}",
+            Path::new("out/specs.html"),
+            Path::new("out/specs.pdf"),
+        );
+        assert_eq!(
+            args,
+            vec!["wkhtmltopdf", "--quiet", "out/specs.html", "out/specs.pdf"]
+        );
+    }
+
+    #[test]
+    fn test_run_converter_reports_missing_placeholders_and_programs() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("doc.html");
+        let output = dir.path().join("doc.pdf");
+
+        assert!(matches!(
+            run_converter("wkhtmltopdf {input}", &input, &output),
+            Err(MucmError::Validation(_))
+        ));
+        assert!(matches!(
+            run_converter("mucm-no-such-converter {input} {output}", &input, &output),
+            Err(MucmError::NotFound(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_converter_runs_the_configured_command() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("doc.html");
+        let output = dir.path().join("doc.pdf");
+        std::fs::write(&input, "<p>Hello</p>").unwrap();
+
+        run_converter("cp {input} {output}", &input, &output).unwrap();
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "<p>Hello</p>");
+
+        assert!(run_converter("false {input} {output}", &input, &output).is_err());
+    }
+}
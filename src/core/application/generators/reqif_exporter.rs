@@ -0,0 +1,573 @@
+//! ReqIF export, for requirements management tools such as DOORS or Polarion.
+//!
+//! Every use case is a spec object of the type "Use Case" (ID, title,
+//! description, category, priority, status), and every pre- and postcondition
+//! a spec object of the type "Condition" related to its use case by a
+//! `precondition` or `postcondition` relation. References between use cases
+//! become relations typed after their relationship (`dependency`,
+//! `extension`, ...), as do conditions that point to a use case or one of its
+//! scenarios. References to use cases missing from the export and GitHub
+//! issue links are left out. One specification lists the use cases by ID,
+//! each with its conditions as children.
+
+use crate::core::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::core::{Condition, ReferenceType, UseCase};
+
+/// Relation from a use case to its preconditions
+const PRECONDITION: &str = "precondition";
+/// Relation from a use case to its postconditions
+const POSTCONDITION: &str = "postcondition";
+/// Relation of a condition pointing to a use case without a relationship
+const CONDITION_TARGET: &str = "references";
+
+/// Attributes of the "Use Case" type, as (identifier, name)
+const USE_CASE_ATTRIBUTES: [(&str, &str); 6] = [
+    ("AD-UC-ID", "ReqIF.ForeignID"),
+    ("AD-UC-TITLE", "ReqIF.Name"),
+    ("AD-UC-DESCRIPTION", "ReqIF.Text"),
+    ("AD-UC-CATEGORY", "ReqIF.Category"),
+    ("AD-UC-PRIORITY", "Priority"),
+    ("AD-UC-STATUS", "Status"),
+];
+
+/// Attributes of the "Condition" type, as (identifier, name)
+const CONDITION_ATTRIBUTES: [(&str, &str); 2] = [
+    ("AD-CONDITION-TEXT", "ReqIF.Text"),
+    ("AD-CONDITION-KIND", "Kind"),
+];
+
+/// Exporter of the use cases to a ReqIF document
+pub struct ReqifExporter;
+
+impl ReqifExporter {
+    /// Writes the ReqIF document of `use_cases`, titled `title`, to `output`
+    pub fn export(use_cases: &[UseCase], title: &str, output: &Path) -> Result<()> {
+        if let Some(dir) = output.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(output, Self::document(use_cases, title, Utc::now()))?;
+        Ok(())
+    }
+
+    /// The ReqIF document, created at `created`
+    pub fn document(use_cases: &[UseCase], title: &str, created: DateTime<Utc>) -> String {
+        let mut sorted: Vec<&UseCase> = use_cases.iter().collect();
+        sorted.sort_by(|a, b| a.id.cmp(&b.id));
+        let relations = relations(&sorted);
+        let created = timestamp(created);
+
+        let mut xml = Xml::default();
+        xml.line("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+        xml.open(
+            "REQ-IF",
+            &[("xmlns", "http://www.omg.org/spec/ReqIF/20110401/reqif.xsd")],
+        );
+        xml.open("THE-HEADER", &[]);
+        xml.open("REQ-IF-HEADER", &[("IDENTIFIER", "mucm-header")]);
+        xml.text("CREATION-TIME", &created);
+        xml.text("REQ-IF-TOOL-ID", "mucm");
+        xml.text("REQ-IF-VERSION", "1.0");
+        xml.text(
+            "SOURCE-TOOL-ID",
+            &format!("markdown-use-case-manager {}", env!("CARGO_PKG_VERSION")),
+        );
+        xml.text("TITLE", title);
+        xml.close("REQ-IF-HEADER");
+        xml.close("THE-HEADER");
+
+        xml.open("CORE-CONTENT", &[]);
+        xml.open("REQ-IF-CONTENT", &[]);
+
+        xml.open("DATATYPES", &[]);
+        xml.empty(
+            "DATATYPE-DEFINITION-STRING",
+            &[
+                ("IDENTIFIER", "DT-STRING"),
+                ("LAST-CHANGE", &created),
+                ("LONG-NAME", "String"),
+                ("MAX-LENGTH", "65535"),
+            ],
+        );
+        xml.close("DATATYPES");
+
+        xml.open("SPEC-TYPES", &[]);
+        spec_object_type(
+            &mut xml,
+            "SOT-USE-CASE",
+            "Use Case",
+            &USE_CASE_ATTRIBUTES,
+            &created,
+        );
+        spec_object_type(
+            &mut xml,
+            "SOT-CONDITION",
+            "Condition",
+            &CONDITION_ATTRIBUTES,
+            &created,
+        );
+        let relation_types: BTreeSet<&str> = relations
+            .iter()
+            .map(|relation| relation.relationship.as_str())
+            .collect();
+        for relationship in relation_types {
+            xml.empty(
+                "SPEC-RELATION-TYPE",
+                &[
+                    ("IDENTIFIER", &relation_type_id(relationship)),
+                    ("LAST-CHANGE", &created),
+                    ("LONG-NAME", relationship),
+                ],
+            );
+        }
+        xml.empty(
+            "SPECIFICATION-TYPE",
+            &[
+                ("IDENTIFIER", "ST-USE-CASES"),
+                ("LAST-CHANGE", &created),
+                ("LONG-NAME", "Use Cases"),
+            ],
+        );
+        xml.close("SPEC-TYPES");
+
+        xml.open("SPEC-OBJECTS", &[]);
+        for use_case in &sorted {
+            let changed = timestamp(use_case.metadata.updated_at);
+            let status = use_case.status();
+            spec_object(
+                &mut xml,
+                &identifier(&use_case.id),
+                "SOT-USE-CASE",
+                &changed,
+                &[
+                    ("AD-UC-ID", &use_case.id),
+                    ("AD-UC-TITLE", &use_case.title),
+                    ("AD-UC-DESCRIPTION", &use_case.description),
+                    ("AD-UC-CATEGORY", &use_case.category),
+                    ("AD-UC-PRIORITY", &use_case.priority.to_string()),
+                    ("AD-UC-STATUS", status.display_name()),
+                ],
+            );
+            for (kind, index, condition) in conditions(use_case) {
+                spec_object(
+                    &mut xml,
+                    &condition_id(&use_case.id, kind, index),
+                    "SOT-CONDITION",
+                    &changed,
+                    &[
+                        ("AD-CONDITION-TEXT", &condition.text),
+                        ("AD-CONDITION-KIND", kind),
+                    ],
+                );
+            }
+        }
+        xml.close("SPEC-OBJECTS");
+
+        xml.open("SPEC-RELATIONS", &[]);
+        for relation in &relations {
+            let mut attributes = vec![
+                ("IDENTIFIER", relation.identifier.clone()),
+                ("LAST-CHANGE", relation.changed.clone()),
+            ];
+            if let Some(description) = &relation.description {
+                attributes.push(("DESC", description.clone()));
+            }
+            let attributes: Vec<(&str, &str)> = attributes
+                .iter()
+                .map(|(name, value)| (*name, value.as_str()))
+                .collect();
+            xml.open("SPEC-RELATION", &attributes);
+            xml.open("TARGET", &[]);
+            xml.text("SPEC-OBJECT-REF", &relation.target);
+            xml.close("TARGET");
+            xml.open("SOURCE", &[]);
+            xml.text("SPEC-OBJECT-REF", &relation.source);
+            xml.close("SOURCE");
+            xml.open("TYPE", &[]);
+            xml.text(
+                "SPEC-RELATION-TYPE-REF",
+                &relation_type_id(&relation.relationship),
+            );
+            xml.close("TYPE");
+            xml.close("SPEC-RELATION");
+        }
+        xml.close("SPEC-RELATIONS");
+
+        xml.open("SPECIFICATIONS", &[]);
+        xml.open(
+            "SPECIFICATION",
+            &[
+                ("IDENTIFIER", "SPEC-USE-CASES"),
+                ("LAST-CHANGE", &created),
+                ("LONG-NAME", title),
+            ],
+        );
+        xml.open("TYPE", &[]);
+        xml.text("SPECIFICATION-TYPE-REF", "ST-USE-CASES");
+        xml.close("TYPE");
+        if !sorted.is_empty() {
+            xml.open("CHILDREN", &[]);
+            for use_case in &sorted {
+                let changed = timestamp(use_case.metadata.updated_at);
+                let object = identifier(&use_case.id);
+                let children: Vec<String> = conditions(use_case)
+                    .into_iter()
+                    .map(|(kind, index, _)| condition_id(&use_case.id, kind, index))
+                    .collect();
+                hierarchy(&mut xml, &object, &changed, &children);
+            }
+            xml.close("CHILDREN");
+        }
+        xml.close("SPECIFICATION");
+        xml.close("SPECIFICATIONS");
+
+        xml.close("REQ-IF-CONTENT");
+        xml.close("CORE-CONTENT");
+        xml.close("REQ-IF");
+        xml.out
+    }
+}
+
+/// A relation between two spec objects
+struct Relation {
+    identifier: String,
+    source: String,
+    target: String,
+    relationship: String,
+    description: Option<String>,
+    changed: String,
+}
+
+/// Relations of the use cases to their conditions, of the conditions to the
+/// use cases they point to, and between use cases
+fn relations(use_cases: &[&UseCase]) -> Vec<Relation> {
+    let owner = |target: &str| -> Option<&UseCase> {
+        use_cases.iter().copied().find(|use_case| {
+            use_case.id == target || use_case.scenarios.iter().any(|s| s.id == target)
+        })
+    };
+
+    let mut relations = Vec::new();
+    for use_case in use_cases {
+        let changed = timestamp(use_case.metadata.updated_at);
+        let source = identifier(&use_case.id);
+        for (kind, index, condition) in conditions(use_case) {
+            let condition_object = condition_id(&use_case.id, kind, index);
+            relations.push(Relation {
+                identifier: format!("{}-REL", condition_object),
+                source: source.clone(),
+                target: condition_object.clone(),
+                relationship: kind.to_string(),
+                description: None,
+                changed: changed.clone(),
+            });
+            let Some(target_id) = condition.target_id.as_deref() else {
+                continue;
+            };
+            let Some(target) = owner(target_id) else {
+                continue;
+            };
+            relations.push(Relation {
+                identifier: format!("{}-TARGET", condition_object),
+                source: condition_object,
+                target: identifier(&target.id),
+                relationship: condition
+                    .relationship
+                    .clone()
+                    .unwrap_or_else(|| CONDITION_TARGET.to_string()),
+                description: (condition.target_type == Some(ReferenceType::Scenario))
+                    .then(|| format!("Scenario {}", target_id)),
+                changed: changed.clone(),
+            });
+        }
+        for reference in &use_case.use_case_references {
+            if reference.is_github_issue()
+                || !use_cases.iter().any(|uc| uc.id == reference.target_id)
+            {
+                continue;
+            }
+            relations.push(Relation {
+                identifier: identifier(&format!(
+                    "{}-{}-{}",
+                    use_case.id, reference.relationship, reference.target_id
+                )),
+                source: source.clone(),
+                target: identifier(&reference.target_id),
+                relationship: reference.relationship.clone(),
+                description: reference.description.clone(),
+                changed: changed.clone(),
+            });
+        }
+    }
+    relations
+}
+
+/// Pre- then postconditions of a use case as (kind, 1-based index, condition)
+fn conditions(use_case: &UseCase) -> Vec<(&'static str, usize, &Condition)> {
+    use_case
+        .preconditions
+        .iter()
+        .enumerate()
+        .map(|(index, condition)| (PRECONDITION, index + 1, condition))
+        .chain(
+            use_case
+                .postconditions
+                .iter()
+                .enumerate()
+                .map(|(index, condition)| (POSTCONDITION, index + 1, condition)),
+        )
+        .collect()
+}
+
+/// Identifier of the `index`th condition of a kind of a use case
+fn condition_id(use_case_id: &str, kind: &str, index: usize) -> String {
+    let suffix = if kind == PRECONDITION { "PRE" } else { "POST" };
+    identifier(&format!("{}-{}-{}", use_case_id, suffix, index))
+}
+
+/// Identifier of the relation type of a relationship
+fn relation_type_id(relationship: &str) -> String {
+    identifier(&format!("SRT-{}", relationship))
+}
+
+/// A valid XML ID: characters other than letters, digits, `-`, `_` and `.`
+/// replaced by `_`, with a leading `_` unless it starts with a letter
+fn identifier(text: &str) -> String {
+    let id: String = text
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if id.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        id
+    } else {
+        format!("_{}", id)
+    }
+}
+
+/// A date as xsd:dateTime
+fn timestamp(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// A spec object type with string attributes
+fn spec_object_type(
+    xml: &mut Xml,
+    id: &str,
+    name: &str,
+    attributes: &[(&str, &str)],
+    created: &str,
+) {
+    xml.open(
+        "SPEC-OBJECT-TYPE",
+        &[
+            ("IDENTIFIER", id),
+            ("LAST-CHANGE", created),
+            ("LONG-NAME", name),
+        ],
+    );
+    xml.open("SPEC-ATTRIBUTES", &[]);
+    for (attribute_id, attribute_name) in attributes {
+        xml.open(
+            "ATTRIBUTE-DEFINITION-STRING",
+            &[
+                ("IDENTIFIER", attribute_id),
+                ("LAST-CHANGE", created),
+                ("LONG-NAME", attribute_name),
+            ],
+        );
+        xml.open("TYPE", &[]);
+        xml.text("DATATYPE-DEFINITION-STRING-REF", "DT-STRING");
+        xml.close("TYPE");
+        xml.close("ATTRIBUTE-DEFINITION-STRING");
+    }
+    xml.close("SPEC-ATTRIBUTES");
+    xml.close("SPEC-OBJECT-TYPE");
+}
+
+/// A spec object with its string values, as (attribute identifier, value)
+fn spec_object(xml: &mut Xml, id: &str, object_type: &str, changed: &str, values: &[(&str, &str)]) {
+    xml.open(
+        "SPEC-OBJECT",
+        &[("IDENTIFIER", id), ("LAST-CHANGE", changed)],
+    );
+    xml.open("VALUES", &[]);
+    for (attribute, value) in values {
+        xml.open("ATTRIBUTE-VALUE-STRING", &[("THE-VALUE", value)]);
+        xml.open("DEFINITION", &[]);
+        xml.text("ATTRIBUTE-DEFINITION-STRING-REF", attribute);
+        xml.close("DEFINITION");
+        xml.close("ATTRIBUTE-VALUE-STRING");
+    }
+    xml.close("VALUES");
+    xml.open("TYPE", &[]);
+    xml.text("SPEC-OBJECT-TYPE-REF", object_type);
+    xml.close("TYPE");
+    xml.close("SPEC-OBJECT");
+}
+
+/// A node of the specification for `object`, with `children` as leaves
+fn hierarchy(xml: &mut Xml, object: &str, changed: &str, children: &[String]) {
+    xml.open(
+        "SPEC-HIERARCHY",
+        &[
+            ("IDENTIFIER", &format!("H-{}", object)),
+            ("LAST-CHANGE", changed),
+        ],
+    );
+    xml.open("OBJECT", &[]);
+    xml.text("SPEC-OBJECT-REF", object);
+    xml.close("OBJECT");
+    if !children.is_empty() {
+        xml.open("CHILDREN", &[]);
+        for child in children {
+            hierarchy(xml, child, changed, &[]);
+        }
+        xml.close("CHILDREN");
+    }
+    xml.close("SPEC-HIERARCHY");
+}
+
+/// Indented XML writer
+#[derive(Default)]
+struct Xml {
+    out: String,
+    depth: usize,
+}
+
+impl Xml {
+    fn line(&mut self, line: &str) {
+        self.out.push_str(&"  ".repeat(self.depth));
+        self.out.push_str(line);
+        self.out.push('\n');
+    }
+
+    fn open(&mut self, tag: &str, attributes: &[(&str, &str)]) {
+        self.line(&format!("<{}{}>", tag, attribute_list(attributes)));
+        self.depth += 1;
+    }
+
+    fn close(&mut self, tag: &str) {
+        self.depth -= 1;
+        self.line(&format!("</{}>", tag));
+    }
+
+    fn empty(&mut self, tag: &str, attributes: &[(&str, &str)]) {
+        self.line(&format!("<{}{}/>", tag, attribute_list(attributes)));
+    }
+
+    fn text(&mut self, tag: &str, text: &str) {
+        self.line(&format!("<{}>{}</{}>", tag, escape(text), tag));
+    }
+}
+
+fn attribute_list(attributes: &[(&str, &str)]) -> String {
+    attributes
+        .iter()
+        .map(|(name, value)| format!(" {}=\"{}\"", name, escape(value)))
+        .collect()
+}
+
+/// Text escaped for XML content and attribute values, keeping line breaks
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\t' => escaped.push_str("&#9;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::UseCaseReference;
+    use crate::core::{Priority, Scenario, ScenarioType};
+
+    fn use_case(id: &str, title: &str) -> UseCase {
+        UseCase::builder(id, title)
+            .category("Auth")
+            .description("Line one\nline <two>")
+            .priority(Priority::High)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_document_maps_conditions_and_references_to_relations() {
+        let mut login = use_case("UC-AUT-001", "Log in & stay");
+        login.add_scenario(Scenario::new(
+            login.next_scenario_id(),
+            "Valid credentials".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        ));
+        let mut logout = use_case("UC-AUT-002", "Log out");
+        logout.preconditions = vec![Condition::with_scenario(
+            "User is logged in",
+            login.scenarios[0].id.clone(),
+            None,
+        )];
+        logout.postconditions = vec![Condition::new("Session is closed")];
+        logout.use_case_references = vec![
+            UseCaseReference::new("UC-AUT-001".to_string(), "dependency".to_string()),
+            UseCaseReference::new("UC-MIS-009".to_string(), "extension".to_string()),
+        ];
+        let created = DateTime::parse_from_rfc3339("2026-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let document = ReqifExporter::document(&[logout, login], "Shop", created);
+
+        assert!(document.contains("<CREATION-TIME>2026-01-02T03:04:05Z</CREATION-TIME>"));
+        assert!(document.contains("<TITLE>Shop</TITLE>"));
+        assert!(document.contains("THE-VALUE=\"Log in &amp; stay\""));
+        assert!(document.contains("THE-VALUE=\"Line one&#10;line &lt;two&gt;\""));
+        assert!(
+            document.find("IDENTIFIER=\"UC-AUT-001\"").unwrap()
+                < document.find("IDENTIFIER=\"UC-AUT-002\"").unwrap()
+        );
+        for relation_type in [
+            "SRT-dependency",
+            "SRT-postcondition",
+            "SRT-precondition",
+            "SRT-references",
+        ] {
+            assert!(
+                document.contains(&format!("IDENTIFIER=\"{}\"", relation_type)),
+                "missing {}",
+                relation_type
+            );
+        }
+        assert!(!document.contains("SRT-extension"));
+        assert!(document
+            .contains("<SPEC-RELATION IDENTIFIER=\"UC-AUT-002-PRE-1-TARGET\" LAST-CHANGE=\""));
+        assert!(document.contains("DESC=\"Scenario UC-AUT-001-S01\""));
+        assert!(document.contains(
+            "<TARGET>\n            <SPEC-OBJECT-REF>UC-AUT-002-POST-1</SPEC-OBJECT-REF>"
+        ));
+        assert_eq!(document.matches("<SPEC-HIERARCHY ").count(), 4);
+    }
+
+    #[test]
+    fn test_identifier_is_a_valid_xml_id() {
+        assert_eq!(identifier("UC-AUT-001"), "UC-AUT-001");
+        assert_eq!(identifier("42 cases/x"), "_42_cases_x");
+    }
+}
@@ -0,0 +1,118 @@
+//! Snippets injected into generated test files.
+//!
+//! Language templates expose named injection points (`{{{snippets.imports}}}`
+//! and so on) so a project can make generated tests compile within its own
+//! harness without forking the template. A point is filled from
+//! `generation.snippets`, then from `<snippet_dir>/<point>.<ext>`, then from
+//! `<snippet_dir>/<category>/<point>.<ext>`; everything found is appended in
+//! that order.
+
+use crate::core::{Context, MucmError, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::core::to_snake_case;
+
+/// Injection points every language template provides
+pub const INJECTION_POINTS: &[&str] = &["imports", "fixtures", "helpers"];
+
+/// Snippet text per injection point for a use case of `category`
+///
+/// Every point is present in the result (empty when nothing fills it), so
+/// templates can test them with `{{#if snippets.<point>}}`.
+pub fn load_snippets(
+    config: &Config,
+    category: &str,
+    extension: &str,
+) -> Result<BTreeMap<String, String>> {
+    if let Some(unknown) = config
+        .generation
+        .snippets
+        .keys()
+        .find(|point| !INJECTION_POINTS.contains(&point.as_str()))
+    {
+        return Err(MucmError::Validation(format!(
+            "Unknown snippet injection point '{}' in generation.snippets (expected one of: {})",
+            unknown,
+            INJECTION_POINTS.join(", ")
+        )));
+    }
+
+    let snippet_dir = Path::new(&config.generation.snippet_dir);
+    let category_dir = snippet_dir.join(to_snake_case(category));
+    let mut snippets = BTreeMap::new();
+    for point in INJECTION_POINTS {
+        let mut parts: Vec<String> = config
+            .generation
+            .snippets
+            .get(*point)
+            .into_iter()
+            .cloned()
+            .collect();
+        for dir in [snippet_dir, category_dir.as_path()] {
+            let path = dir.join(format!("{}.{}", point, extension));
+            if path.is_file() {
+                let content = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read snippet {}", path.display()))?;
+                parts.push(content);
+            }
+        }
+        let text = parts
+            .iter()
+            .map(|part| part.trim_end_matches(['\r', '\n']))
+            .filter(|part| !part.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        snippets.insert(point.to_string(), text);
+    }
+    Ok(snippets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn config(dir: &Path) -> Config {
+        let mut config = Config::default();
+        config.generation.snippet_dir = dir.to_string_lossy().into_owned();
+        config
+    }
+
+    #[test]
+    fn test_snippets_append_config_then_project_then_category() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("imports.py"), "import app\n").unwrap();
+        fs::create_dir_all(dir.path().join("auth")).unwrap();
+        fs::write(dir.path().join("auth/imports.py"), "import auth\n").unwrap();
+        fs::write(dir.path().join("helpers.rs"), "fn helper() {}\n").unwrap();
+        let mut config = config(dir.path());
+        config
+            .generation
+            .snippets
+            .insert("imports".to_string(), "import os".to_string());
+
+        let auth = load_snippets(&config, "Auth", "py").unwrap();
+        assert_eq!(auth["imports"], "import os\nimport app\nimport auth");
+        assert_eq!(auth["helpers"], "");
+        assert_eq!(auth["fixtures"], "");
+
+        let billing = load_snippets(&config, "Billing", "py").unwrap();
+        assert_eq!(billing["imports"], "import os\nimport app");
+    }
+
+    #[test]
+    fn test_unknown_injection_point_fails() {
+        let dir = TempDir::new().unwrap();
+        let mut config = config(dir.path());
+        config
+            .generation
+            .snippets
+            .insert("teardown".to_string(), "pass".to_string());
+
+        let error = load_snippets(&config, "Auth", "py").unwrap_err();
+        assert!(error.to_string().contains("'teardown'"));
+    }
+}
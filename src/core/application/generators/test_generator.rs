@@ -2,13 +2,20 @@
 //!
 //! Handles generation of test files from use cases using language-specific templates.
 
-use anyhow::Result;
+use crate::core::MucmError;
+use crate::core::Result;
+use regex::Regex;
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
 
-use crate::config::Config;
+use super::snippets::load_snippets;
+use super::test_names::{validate_symbol, TestNameMap, TEST_NAMES_FILE};
+
+use crate::config::{Config, ProjectContext};
 use crate::core::file_operations::FileOperations;
-use crate::core::{to_snake_case, TemplateEngine, UseCase};
+use crate::core::{to_snake_case, Scenario, TemplateEngine, UseCase};
 use crate::presentation::UseCaseFormatter;
 
 /// Generator for use case test documentation.
@@ -19,14 +26,13 @@ pub struct TestGenerator {
 }
 
 impl TestGenerator {
-    /// Creates a new test generator with the given configuration.
-    pub fn new(config: Config) -> Self {
-        let file_operations = FileOperations::new(config.clone());
-        let template_engine = TemplateEngine::with_config(Some(&config));
+    /// Creates a test generator for the project described by `context`.
+    pub fn for_project(context: &ProjectContext) -> Self {
+        let config = context.resolved_config();
         Self {
+            file_operations: FileOperations::new(config.clone()),
+            template_engine: TemplateEngine::for_project(context),
             config,
-            file_operations,
-            template_engine,
         }
     }
 
@@ -51,15 +57,7 @@ impl TestGenerator {
             return Ok(());
         }
 
-        // Generate test content using template
-        let test_content = self.generate_content(use_case)?;
-
-        // Save the test file
-        self.file_operations
-            .save_test_file(use_case, &test_content, &file_extension)?;
-
-        // Get the test file path for display
-        let test_file_path = self.get_file_path(use_case)?;
+        let test_file_path = self.write(use_case)?;
 
         // Use the formatter to display the generated message
         UseCaseFormatter::display_test_generated(
@@ -70,10 +68,88 @@ impl TestGenerator {
         Ok(())
     }
 
+    /// Renders and saves the test file of a use case, replacing any existing file.
+    ///
+    /// Returns the path of the written file.
+    pub fn write(&self, use_case: &UseCase) -> Result<PathBuf> {
+        // Generate test content using template, with the recorded test names
+        let mut test_names = self.load_test_names()?;
+        let before = test_names.clone();
+        let test_content = self.generate_content(use_case, &mut test_names, None)?;
+        if test_names != before {
+            test_names.save(&self.test_names_path())?;
+        }
+
+        self.file_operations
+            .save_test_file(use_case, &test_content, &self.get_file_extension())?;
+        self.get_file_path(use_case)
+    }
+
+    /// Renders and saves a test file holding only the scenarios tagged `tag`,
+    /// under `tags/<tag>/` in the test directory.
+    ///
+    /// Returns the path of the written file, or None (writing nothing) when no
+    /// scenario of the use case has the tag.
+    pub fn write_tagged(&self, use_case: &UseCase, tag: &str) -> Result<Option<PathBuf>> {
+        let mut tagged = use_case.clone();
+        tagged.scenarios.retain(|scenario| scenario.has_tag(tag));
+        if tagged.scenarios.is_empty() {
+            return Ok(None);
+        }
+
+        let mut test_names = self.load_test_names()?;
+        let before = test_names.clone();
+        let test_content = self.generate_content(&tagged, &mut test_names, None)?;
+        if test_names != before {
+            test_names.save(&self.test_names_path())?;
+        }
+
+        let path = self.get_tagged_file_path(use_case, tag);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, test_content)?;
+        Ok(Some(path))
+    }
+
+    /// Whether a use case's test file is still exactly as generated.
+    ///
+    /// The file is rendered again for the scenarios in `scenario_ids` (those it
+    /// has tests for), with the timestamp recorded in the file. Any difference,
+    /// including scenario text edited since generation, counts as a manual
+    /// modification.
+    pub fn is_unmodified(&self, use_case: &UseCase, scenario_ids: &[String]) -> Result<bool> {
+        let path = self.get_file_path(use_case)?;
+        let existing = fs::read_to_string(&path)?.replace("\r\n", "\n");
+        let generated_at_re = Regex::new(r"Generated at: ([^\n]+)").expect("valid timestamp regex");
+        let Some(generated_at) = generated_at_re
+            .captures(&existing)
+            .map(|caps| caps[1].trim_end().to_string())
+        else {
+            return Ok(false);
+        };
+
+        let mut generated = use_case.clone();
+        generated
+            .scenarios
+            .retain(|scenario| scenario_ids.contains(&scenario.id));
+        let mut test_names = self.load_test_names()?;
+        let rendered = self.generate_content(&generated, &mut test_names, Some(generated_at))?;
+        Ok(rendered.replace("\r\n", "\n") == existing)
+    }
+
     /// Generates test content for a use case without saving to file.
-    fn generate_content(&self, use_case: &UseCase) -> Result<String> {
+    ///
+    /// `generated_at` defaults to the current time.
+    fn generate_content(
+        &self,
+        use_case: &UseCase,
+        test_names: &mut TestNameMap,
+        generated_at: Option<String>,
+    ) -> Result<String> {
         // Convert UseCase to JSON for template engine
-        let use_case_json = serde_json::to_value(use_case)?;
+        let ordered = super::with_scenario_order(use_case, self.config.generation.scenario_order);
+        let use_case_json = serde_json::to_value(&ordered)?;
         let mut data: HashMap<String, Value> = serde_json::from_value(use_case_json)?;
 
         // Merge extra fields into top-level HashMap
@@ -84,23 +160,128 @@ impl TestGenerator {
         }
 
         // Add generated timestamp
-        data.insert(
-            "generated_at".to_string(),
-            json!(chrono::Utc::now()
+        let generated_at = generated_at.unwrap_or_else(|| {
+            chrono::Utc::now()
                 .format("%Y-%m-%d %H:%M:%S UTC")
-                .to_string()),
-        );
+                .to_string()
+        });
+        data.insert("generated_at".to_string(), json!(generated_at));
 
         // Add snake_case version of title for class names
         if let Some(Value::String(title)) = data.get("title") {
             data.insert("title_snake_case".to_string(), json!(to_snake_case(title)));
         }
 
+        // Add stable test symbols so test files can be reconciled with scenarios
+        data.insert(
+            "test_module_name".to_string(),
+            json!(format!("test_{}", to_snake_case(&use_case.id))),
+        );
+        let language = self.config.generation.test_language.as_str();
+        let mut has_deprecated_tests = false;
+        if let Some(Value::Array(scenarios)) = data.get_mut("scenarios") {
+            for scenario in scenarios.iter_mut() {
+                if let Some(obj) = scenario.as_object_mut() {
+                    let id = obj
+                        .get("id")
+                        .and_then(|id| id.as_str())
+                        .unwrap_or_default()
+                        .to_string();
+                    let title = obj
+                        .get("title")
+                        .and_then(|title| title.as_str())
+                        .unwrap_or_default()
+                        .to_string();
+                    let name = test_names.symbol_for(language, &id, || {
+                        Self::default_test_symbol(language, &id, &title)
+                    });
+                    has_deprecated_tests |= !name.previous.is_empty();
+                    obj.insert("test_symbol".to_string(), json!(name.symbol));
+                    obj.insert("deprecated_symbols".to_string(), json!(name.previous));
+                    obj.insert("snake_case_id".to_string(), json!(to_snake_case(&id)));
+                }
+            }
+        }
+        data.insert(
+            "has_deprecated_tests".to_string(),
+            json!(has_deprecated_tests),
+        );
+
+        // Add project snippets for the template injection points
+        let snippets = load_snippets(&self.config, &use_case.category, &self.get_file_extension())?;
+        data.insert("snippets".to_string(), json!(snippets));
+
         // Render using test template for the configured language
         self.template_engine
             .render_test(&self.config.generation.test_language, &data)
     }
 
+    /// Test symbol a scenario gets the first time its test is generated
+    ///
+    /// JavaScript tests are named after the scenario title; the other languages
+    /// use the scenario ID.
+    fn default_test_symbol(language: &str, scenario_id: &str, title: &str) -> String {
+        match language {
+            "javascript" => title.to_string(),
+            _ => format!("test_{}", to_snake_case(scenario_id)),
+        }
+    }
+
+    /// Configured test language
+    pub(crate) fn test_language(&self) -> &str {
+        &self.config.generation.test_language
+    }
+
+    /// Path of the scenario-to-test-name mapping file
+    pub(crate) fn test_names_path(&self) -> PathBuf {
+        std::path::Path::new(&self.config.directories.test_dir).join(TEST_NAMES_FILE)
+    }
+
+    /// Recorded test names of all scenarios
+    pub fn load_test_names(&self) -> Result<TestNameMap> {
+        TestNameMap::load(&self.test_names_path())
+    }
+
+    /// Give a scenario's test a new name; the old one is kept for a deprecation shim
+    ///
+    /// Returns the old name.
+    pub fn rename_test(
+        &self,
+        use_case: &UseCase,
+        scenario_id: &str,
+        symbol: String,
+    ) -> Result<String> {
+        let language = self.config.generation.test_language.as_str();
+        validate_symbol(language, &symbol)?;
+        let scenario = use_case
+            .scenarios
+            .iter()
+            .find(|s| s.id == scenario_id)
+            .ok_or_else(|| {
+                MucmError::NotFound(format!(
+                    "Scenario {} not found in {}",
+                    scenario_id, use_case.id
+                ))
+            })?;
+
+        let mut test_names = self.load_test_names()?;
+        test_names.symbol_for(language, scenario_id, || {
+            Self::default_test_symbol(language, scenario_id, &scenario.title)
+        });
+        let old = test_names.rename(language, scenario_id, symbol)?;
+        test_names.save(&self.test_names_path())?;
+        Ok(old)
+    }
+
+    /// Carry recorded test names over to renamed scenario IDs
+    pub fn rekey_test_names(&self, renames: &HashMap<String, String>) -> Result<()> {
+        let mut test_names = self.load_test_names()?;
+        if test_names.rekey(renames) {
+            test_names.save(&self.test_names_path())?;
+        }
+        Ok(())
+    }
+
     /// Gets the file extension for test files based on the configured language.
     fn get_file_extension(&self) -> String {
         match self.config.generation.test_language.as_str() {
@@ -113,11 +294,38 @@ impl TestGenerator {
     }
 
     /// Gets the full file path for a use case's test file.
-    fn get_file_path(&self, use_case: &UseCase) -> Result<std::path::PathBuf> {
+    pub(crate) fn get_file_path(&self, use_case: &UseCase) -> Result<std::path::PathBuf> {
+        let test_dir = std::path::Path::new(&self.config.directories.test_dir);
+        Ok(self.file_path_in(test_dir, use_case))
+    }
+
+    /// Gets the path of a use case's test file in the suite of a scenario tag.
+    pub(crate) fn get_tagged_file_path(&self, use_case: &UseCase, tag: &str) -> PathBuf {
+        let suite_dir = std::path::Path::new(&self.config.directories.test_dir)
+            .join("tags")
+            .join(to_snake_case(&Scenario::normalize_tag(tag)));
+        self.file_path_in(&suite_dir, use_case)
+    }
+
+    /// Gets the path the test file of a use case has in a language with `file_extension`.
+    pub(crate) fn file_path_with_extension(
+        &self,
+        use_case: &UseCase,
+        file_extension: &str,
+    ) -> PathBuf {
         let test_dir = std::path::Path::new(&self.config.directories.test_dir);
+        let category_dir = test_dir.join(to_snake_case(&use_case.category));
+        category_dir.join(format!(
+            "{}.{}",
+            to_snake_case(&use_case.id),
+            file_extension
+        ))
+    }
+
+    fn file_path_in(&self, test_dir: &std::path::Path, use_case: &UseCase) -> PathBuf {
         let category_dir = test_dir.join(to_snake_case(&use_case.category));
         let file_extension = self.get_file_extension();
         let file_name = format!("{}.{}", to_snake_case(&use_case.id), file_extension);
-        Ok(category_dir.join(file_name))
+        category_dir.join(file_name)
     }
 }
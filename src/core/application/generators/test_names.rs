@@ -0,0 +1,196 @@
+//! Stable test names for scenarios.
+//!
+//! Generated test functions are named after scenario IDs (or titles, for
+//! JavaScript), so a changed title or ID would silently rename the test and
+//! break its history. The mapping file records the test symbol each scenario
+//! was first generated with; the test generator reuses it from then on.
+//! Renamed symbols keep their previous names so the language templates can
+//! emit deprecation shims.
+
+use crate::core::{Context, MucmError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// Name of the mapping file inside the test directory
+pub const TEST_NAMES_FILE: &str = ".mucm-test-names.toml";
+
+/// Test symbol of one scenario
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestName {
+    pub symbol: String,
+    /// Earlier symbols, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous: Vec<String>,
+}
+
+/// Scenario ID to test symbol, per test language
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TestNameMap {
+    languages: BTreeMap<String, BTreeMap<String, TestName>>,
+}
+
+impl TestNameMap {
+    /// Load the mapping file; a missing file is an empty mapping
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let header = "# Test symbol of every scenario, maintained by mucm.\n\
+                      # Keeps generated test names stable when titles or IDs change.\n\n";
+        fs::write(path, format!("{}{}", header, toml::to_string(self)?))?;
+        Ok(())
+    }
+
+    pub fn get(&self, language: &str, scenario_id: &str) -> Option<&TestName> {
+        self.languages.get(language)?.get(scenario_id)
+    }
+
+    /// Test symbol of a scenario, recording `default` if it has none yet
+    pub fn symbol_for(
+        &mut self,
+        language: &str,
+        scenario_id: &str,
+        default: impl FnOnce() -> String,
+    ) -> &TestName {
+        self.languages
+            .entry(language.to_string())
+            .or_default()
+            .entry(scenario_id.to_string())
+            .or_insert_with(|| TestName {
+                symbol: default(),
+                previous: Vec::new(),
+            })
+    }
+
+    /// Give a scenario a new test symbol, keeping the old one as previous
+    ///
+    /// Returns the old symbol.
+    pub fn rename(&mut self, language: &str, scenario_id: &str, symbol: String) -> Result<String> {
+        let Some(entry) = self
+            .languages
+            .get_mut(language)
+            .and_then(|scenarios| scenarios.get_mut(scenario_id))
+        else {
+            return Err(MucmError::NotFound(format!(
+                "No {} test recorded for scenario {}",
+                language, scenario_id
+            )));
+        };
+        if entry.symbol == symbol {
+            return Err(MucmError::Conflict(format!(
+                "{} is already named {}",
+                scenario_id, symbol
+            )));
+        }
+        let old = std::mem::replace(&mut entry.symbol, symbol);
+        entry.previous.retain(|previous| *previous != entry.symbol);
+        entry.previous.push(old.clone());
+        Ok(old)
+    }
+
+    /// Move entries to new scenario IDs so renamed scenarios keep their test names
+    ///
+    /// Returns whether anything changed.
+    pub fn rekey(&mut self, renames: &HashMap<String, String>) -> bool {
+        let mut changed = false;
+        for scenarios in self.languages.values_mut() {
+            for (old_id, new_id) in renames {
+                if let Some(entry) = scenarios.remove(old_id) {
+                    scenarios.insert(new_id.clone(), entry);
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+}
+
+/// Whether `symbol` is a valid test name in `language`
+pub fn validate_symbol(language: &str, symbol: &str) -> Result<()> {
+    let valid = match language {
+        "javascript" => !symbol.trim().is_empty() && !symbol.contains(['\'', '\n']),
+        _ => {
+            let mut chars = symbol.chars();
+            chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+    };
+    if !valid {
+        return Err(MucmError::Validation(format!(
+            "'{}' is not a valid {} test name",
+            symbol, language
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_symbol_is_recorded_once() {
+        let mut map = TestNameMap::default();
+        map.symbol_for("python", "UC-AUT-001-S01", || "test_first".to_string());
+        let name = map.symbol_for("python", "UC-AUT-001-S01", || "test_second".to_string());
+        assert_eq!(name.symbol, "test_first");
+    }
+
+    #[test]
+    fn test_rename_keeps_previous_and_round_trips() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(TEST_NAMES_FILE);
+        let mut map = TestNameMap::default();
+        map.symbol_for("rust", "UC-AUT-001-S01", || "test_old".to_string());
+
+        assert_eq!(
+            map.rename("rust", "UC-AUT-001-S01", "test_new".to_string())
+                .unwrap(),
+            "test_old"
+        );
+        map.save(&path).unwrap();
+
+        let loaded = TestNameMap::load(&path).unwrap();
+        let name = loaded.get("rust", "UC-AUT-001-S01").unwrap();
+        assert_eq!(name.symbol, "test_new");
+        assert_eq!(name.previous, vec!["test_old"]);
+        assert!(loaded.get("python", "UC-AUT-001-S01").is_none());
+    }
+
+    #[test]
+    fn test_rekey_moves_entries() {
+        let mut map = TestNameMap::default();
+        map.symbol_for("rust", "UC-AUT-001-S02", || {
+            "test_uc_aut_001_s02".to_string()
+        });
+        let renames = HashMap::from([("UC-AUT-001-S02".to_string(), "UC-AUT-002-S01".to_string())]);
+
+        assert!(map.rekey(&renames));
+        assert_eq!(
+            map.get("rust", "UC-AUT-002-S01").unwrap().symbol,
+            "test_uc_aut_001_s02"
+        );
+    }
+
+    #[test]
+    fn test_validate_symbol() {
+        assert!(validate_symbol("python", "test_login_2").is_ok());
+        assert!(validate_symbol("rust", "2fast").is_err());
+        assert!(validate_symbol("javascript", "User logs in").is_ok());
+        assert!(validate_symbol("javascript", "it's").is_err());
+    }
+}
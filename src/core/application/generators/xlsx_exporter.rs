@@ -0,0 +1,232 @@
+//! Excel export: a workbook with a summary sheet and one sheet per category.
+//!
+//! The summary counts the use cases and scenarios of each category by status;
+//! the category sheets list their use cases with the columns of the CSV matrix
+//! plus the description and dates. Every sheet has a bold, frozen header row
+//! with filters, so the workbook can be sorted and filtered as it is.
+
+use crate::core::Result;
+use rust_xlsxwriter::{Format, Workbook, Worksheet};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use super::csv_exporter::view_list;
+use crate::core::{Status, UseCase};
+
+/// Columns of the category sheets
+const USE_CASE_COLUMNS: [&str; 9] = [
+    "ID",
+    "Title",
+    "Priority",
+    "Status",
+    "Scenarios",
+    "Views",
+    "Description",
+    "Created",
+    "Updated",
+];
+
+/// Longest sheet name Excel accepts
+const MAX_SHEET_NAME: usize = 31;
+
+/// Exporter of the use cases to an Excel workbook
+pub struct XlsxExporter;
+
+impl XlsxExporter {
+    /// Writes the workbook of `use_cases` to `output`
+    pub fn export(use_cases: &[UseCase], output: &Path) -> Result<()> {
+        if let Some(dir) = output.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        Self::workbook(use_cases)?.save(output)?;
+        Ok(())
+    }
+
+    /// The workbook: the summary, then the categories in alphabetical order
+    pub fn workbook(use_cases: &[UseCase]) -> Result<Workbook> {
+        let mut categories: BTreeMap<&str, Vec<&UseCase>> = BTreeMap::new();
+        for use_case in use_cases {
+            categories
+                .entry(use_case.category.as_str())
+                .or_default()
+                .push(use_case);
+        }
+        for category_use_cases in categories.values_mut() {
+            category_use_cases.sort_by(|a, b| a.id.cmp(&b.id));
+        }
+
+        let header = Format::new().set_bold();
+        let mut workbook = Workbook::new();
+        let mut used_names = HashSet::from(["summary".to_string()]);
+
+        let summary = workbook.add_worksheet();
+        summary.set_name("Summary")?;
+        write_summary(summary, &categories, &header)?;
+
+        for (category, category_use_cases) in &categories {
+            let sheet = workbook.add_worksheet();
+            sheet.set_name(sheet_name(category, &mut used_names))?;
+            write_use_cases(sheet, category_use_cases, &header)?;
+        }
+        Ok(workbook)
+    }
+}
+
+/// Use case and scenario counts of each category, by status
+fn write_summary(
+    sheet: &mut Worksheet,
+    categories: &BTreeMap<&str, Vec<&UseCase>>,
+    header: &Format,
+) -> Result<()> {
+    let mut columns = vec!["Category", "Use Cases", "Scenarios"];
+    columns.extend(Status::ALL.iter().map(|status| status.display_name()));
+    write_header(sheet, &columns, header)?;
+
+    let mut row = 0;
+    let mut totals = vec![0usize; columns.len() - 1];
+    for (category, use_cases) in categories {
+        row += 1;
+        let mut counts = vec![
+            use_cases.len(),
+            use_cases.iter().map(|uc| uc.scenarios.len()).sum(),
+        ];
+        counts.extend(
+            Status::ALL
+                .iter()
+                .map(|status| use_cases.iter().filter(|uc| uc.status() == *status).count()),
+        );
+        sheet.write_string(row, 0, *category)?;
+        for (col, count) in counts.iter().enumerate() {
+            sheet.write_number(row, col as u16 + 1, *count as f64)?;
+            totals[col] += count;
+        }
+    }
+    sheet.autofilter(0, 0, row, columns.len() as u16 - 1)?;
+
+    row += 1;
+    sheet.write_string_with_format(row, 0, "Total", header)?;
+    for (col, total) in totals.iter().enumerate() {
+        sheet.write_number_with_format(row, col as u16 + 1, *total as f64, header)?;
+    }
+    sheet.autofit();
+    Ok(())
+}
+
+/// One row per use case of a category
+fn write_use_cases(sheet: &mut Worksheet, use_cases: &[&UseCase], header: &Format) -> Result<()> {
+    write_header(sheet, &USE_CASE_COLUMNS, header)?;
+    for (index, use_case) in use_cases.iter().enumerate() {
+        let row = index as u32 + 1;
+        sheet.write_string(row, 0, &use_case.id)?;
+        sheet.write_string(row, 1, &use_case.title)?;
+        sheet.write_string(row, 2, use_case.priority.to_string())?;
+        sheet.write_string(row, 3, use_case.status().display_name())?;
+        sheet.write_number(row, 4, use_case.scenarios.len() as f64)?;
+        sheet.write_string(row, 5, view_list(use_case))?;
+        sheet.write_string(row, 6, &use_case.description)?;
+        sheet.write_string(row, 7, date(&use_case.metadata.created_at))?;
+        sheet.write_string(row, 8, date(&use_case.metadata.updated_at))?;
+    }
+    sheet.autofilter(
+        0,
+        0,
+        use_cases.len() as u32,
+        USE_CASE_COLUMNS.len() as u16 - 1,
+    )?;
+    sheet.autofit();
+    // Descriptions can be long; keep the column readable
+    sheet.set_column_width(6, 60)?;
+    Ok(())
+}
+
+fn write_header(sheet: &mut Worksheet, columns: &[&str], header: &Format) -> Result<()> {
+    for (col, column) in columns.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, *column, header)?;
+    }
+    sheet.set_freeze_panes(1, 0)?;
+    Ok(())
+}
+
+fn date(timestamp: &chrono::DateTime<chrono::Utc>) -> String {
+    timestamp.format("%Y-%m-%d").to_string()
+}
+
+/// A sheet name Excel accepts for `category`, unique among `used` (lowercased)
+fn sheet_name(category: &str, used: &mut HashSet<String>) -> String {
+    let cleaned: String = category
+        .chars()
+        .map(|c| match c {
+            '[' | ']' | ':' | '*' | '?' | '/' | '\\' => '_',
+            c => c,
+        })
+        .collect();
+    let cleaned = cleaned.trim_matches('\'').trim();
+    let base = if cleaned.is_empty() || cleaned.eq_ignore_ascii_case("history") {
+        format!("{}_", cleaned)
+    } else {
+        cleaned.to_string()
+    };
+
+    let mut suffix = 1;
+    loop {
+        let tail = if suffix == 1 {
+            String::new()
+        } else {
+            format!(" ({})", suffix)
+        };
+        let keep = MAX_SHEET_NAME - tail.chars().count();
+        let name: String = base.chars().take(keep).chain(tail.chars()).collect();
+        if used.insert(name.to_lowercase()) {
+            return name;
+        }
+        suffix += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sheet_names_are_valid_and_unique() {
+        let mut used = HashSet::from(["summary".to_string()]);
+        assert_eq!(
+            sheet_name("Auth/Login: [v2]", &mut used),
+            "Auth_Login_ _v2_"
+        );
+        assert_eq!(sheet_name("Summary", &mut used), "Summary (2)");
+        assert_eq!(sheet_name("History", &mut used), "History_");
+        let long = "A very long category name that Excel would reject";
+        assert_eq!(sheet_name(long, &mut used).chars().count(), MAX_SHEET_NAME);
+        assert_eq!(
+            sheet_name(long, &mut used),
+            "A very long category name t (2)"
+        );
+    }
+
+    #[test]
+    fn test_workbook_has_a_summary_and_a_sheet_per_category() {
+        let use_cases: Vec<UseCase> = [("UC-AUT-001", "Auth"), ("UC-BIL-001", "Billing")]
+            .into_iter()
+            .map(|(id, category)| {
+                UseCase::new(
+                    id.to_string(),
+                    format!("Use case {}", id),
+                    category.to_string(),
+                    String::new(),
+                    "medium".to_string(),
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let mut workbook = XlsxExporter::workbook(&use_cases).unwrap();
+        let names: Vec<String> = workbook
+            .worksheets()
+            .iter()
+            .map(|sheet| sheet.name())
+            .collect();
+        assert_eq!(names, ["Summary", "Auth", "Billing"]);
+        assert!(workbook.save_to_buffer().unwrap().starts_with(b"PK"));
+    }
+}
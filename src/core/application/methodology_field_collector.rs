@@ -6,7 +6,8 @@
 /// - Collision detection between methodologies
 /// - Conflict warnings for standard field overlaps
 /// - Validation of duplicate fields within same methodology inheritance chain
-use anyhow::{Context, Result};
+use crate::core::MucmError;
+use crate::core::{Context, Result};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -18,7 +19,7 @@ use crate::core::{CustomFieldConfig, FieldResolver, MethodologyDefinition};
 pub struct CollectedField {
     /// Field name
     pub name: String,
-    /// Field type (string, array, number, boolean)
+    /// Field type (string, array, number, boolean, text, table)
     pub field_type: String,
     /// Display label for prompts
     pub label: String,
@@ -28,6 +29,8 @@ pub struct CollectedField {
     pub default: Option<String>,
     /// Description/help text
     pub description: Option<String>,
+    /// Column names of a table field
+    pub columns: Vec<String>,
     /// Which methodologies define this field
     pub methodologies: Vec<String>,
     /// Which level this field comes from (simple, normal, detailed)
@@ -55,6 +58,17 @@ impl MethodologyFieldCollector {
         Ok(Self { templates_dir })
     }
 
+    /// Create a field collector for the templates installed in `templates_dir`
+    /// (a project's `.config/.mucm/template-assets`)
+    pub fn for_templates_dir(templates_dir: &Path) -> Self {
+        Self {
+            templates_dir: templates_dir
+                .join("methodologies")
+                .to_string_lossy()
+                .into_owned(),
+        }
+    }
+
     /// Collect all custom fields for given methodology views
     ///
     /// # Arguments
@@ -71,7 +85,7 @@ impl MethodologyFieldCollector {
         let mut collection = FieldCollection::default();
 
         // Standard extra field names (these have priority over methodology fields)
-        let standard_fields = vec!["author", "reviewer", "description"];
+        let standard_fields = ["author", "reviewer", "description"];
 
         // Collect fields from each view
         for (methodology, level) in views {
@@ -91,7 +105,7 @@ impl MethodologyFieldCollector {
                 if let Some(existing_field) = collection.fields.get(&field_name) {
                     if !existing_field.methodologies.contains(methodology) {
                         // This is a collision between different methodologies
-                        return Err(anyhow::anyhow!(
+                        return Err(MucmError::Conflict(format!(
                             "Field name collision detected!\n\n\
                              Field '{}' is defined by multiple methodologies:\n\
                              - {} ({}): \"{}\"\n\
@@ -106,7 +120,7 @@ impl MethodologyFieldCollector {
                             methodology,
                             field_config.field_type,
                             field_config.label.as_deref().unwrap_or(&field_name)
-                        ));
+                        )));
                     }
                 } else {
                     // New field - add it
@@ -122,6 +136,7 @@ impl MethodologyFieldCollector {
                             required: field_config.required,
                             default: field_config.default.clone(),
                             description: field_config.description.clone(),
+                            columns: field_config.columns.clone(),
                             methodologies: vec![methodology.clone()],
                             level: level.clone(),
                         },
@@ -151,11 +166,10 @@ impl MethodologyFieldCollector {
         let methodology_dir = Path::new(&self.templates_dir).join(methodology);
 
         if !methodology_dir.exists() || !methodology_dir.join("methodology.toml").exists() {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::NotFound(format!(
                 "Methodology '{}' not found in {}",
-                methodology,
-                self.templates_dir
-            ));
+                methodology, self.templates_dir
+            )));
         }
 
         let methodology_def = MethodologyDefinition::from_toml(&methodology_dir)
@@ -212,7 +226,7 @@ impl MethodologyFieldCollector {
             } else {
                 // No user value and no default - use empty value based on type
                 let json_value = match field.field_type.as_str() {
-                    "array" => serde_json::Value::Array(vec![]),
+                    "array" | "table" => serde_json::Value::Array(vec![]),
                     "number" => serde_json::Value::Number(serde_json::Number::from(0)),
                     "boolean" => serde_json::Value::Bool(false),
                     _ => serde_json::Value::String(String::new()),
@@ -250,6 +264,13 @@ impl MethodologyFieldCollector {
                 let val = matches!(value.to_lowercase().as_str(), "true" | "yes" | "1");
                 serde_json::Value::Bool(val)
             }
+            "table" => {
+                // Rows are given as a JSON array of objects
+                match serde_json::from_str::<serde_json::Value>(value) {
+                    Ok(rows @ serde_json::Value::Array(_)) => rows,
+                    _ => serde_json::Value::Array(vec![]),
+                }
+            }
             _ => serde_json::Value::String(value.to_string()),
         }
     }
@@ -302,6 +323,22 @@ mod tests {
         assert_eq!(value, serde_json::Value::Bool(false));
     }
 
+    #[test]
+    fn test_convert_to_json_type_table() {
+        let collector = MethodologyFieldCollector::default();
+        let value = collector.convert_to_json_type(
+            r#"[{"Stakeholder": "Support", "Interest": "Fewer tickets"}]"#,
+            "table",
+        );
+        assert_eq!(
+            value,
+            serde_json::json!([{ "Stakeholder": "Support", "Interest": "Fewer tickets" }])
+        );
+
+        let value = collector.convert_to_json_type("not a table", "table");
+        assert_eq!(value, serde_json::json!([]));
+    }
+
     #[test]
     fn test_apply_user_values_all_fields_present_when_empty() {
         let collector = MethodologyFieldCollector::default();
@@ -319,6 +356,7 @@ mod tests {
                 required: true,
                 default: None,
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -334,6 +372,7 @@ mod tests {
                 required: false,
                 default: None,
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -349,6 +388,7 @@ mod tests {
                 required: false,
                 default: None,
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -364,6 +404,7 @@ mod tests {
                 required: false,
                 default: None,
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -379,6 +420,7 @@ mod tests {
                 required: false,
                 default: None,
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -439,6 +481,7 @@ mod tests {
                 required: false,
                 default: Some("default value".to_string()),
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -471,6 +514,7 @@ mod tests {
                 required: false,
                 default: Some("default value".to_string()),
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -504,6 +548,7 @@ mod tests {
                 required: false,
                 default: None,
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -518,6 +563,7 @@ mod tests {
                 required: false,
                 default: None,
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
@@ -532,6 +578,7 @@ mod tests {
                 required: false,
                 default: None,
                 description: None,
+                columns: Vec::new(),
                 methodologies: vec!["test".to_string()],
                 level: "normal".to_string(),
             },
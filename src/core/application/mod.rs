@@ -1,10 +1,12 @@
 // Application layer - orchestrates use cases and business logic
+mod async_coordinator;
 pub mod creators;
 pub mod generators;
 pub mod methodology_field_collector;
 pub mod services;
 mod use_case_coordinator;
 
+pub use async_coordinator::AsyncUseCaseCoordinator;
 pub use methodology_field_collector::MethodologyFieldCollector;
 pub use use_case_coordinator::UseCaseCoordinator;
 
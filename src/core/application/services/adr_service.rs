@@ -0,0 +1,80 @@
+//! Links from use cases to architecture decision records (ADRs).
+//!
+//! ADRs are markdown files kept in the repository (e.g. `docs/adr/0007.md`).
+//! Links are stored as paths relative to the project root with forward
+//! slashes, so they stay valid on every platform and in generated docs.
+
+use crate::core::MucmError;
+use crate::core::Result;
+use std::path::{Component, Path};
+
+use crate::core::UseCase;
+
+/// Turn a user-supplied ADR path into the stored form, relative to `root`
+///
+/// Absolute paths must lie inside the project.
+pub(crate) fn normalize_adr_path(root: &Path, path: &str) -> Result<String> {
+    let trimmed = path.trim();
+    if trimmed.is_empty() {
+        return Err(MucmError::Validation(
+            "ADR path cannot be empty".to_string(),
+        ));
+    }
+    let path = Path::new(trimmed);
+    let relative = if path.is_absolute() {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        path.strip_prefix(&root)
+            .map_err(|_| {
+                MucmError::Validation(format!("ADR {} is outside the project", path.display()))
+            })?
+            .to_path_buf()
+    } else {
+        path.to_path_buf()
+    };
+
+    let mut parts = Vec::new();
+    for component in relative.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::CurDir => {}
+            _ => {
+                return Err(MucmError::Validation(format!(
+                    "ADR {} must be a path inside the project",
+                    trimmed
+                )))
+            }
+        }
+    }
+    Ok(parts.join("/"))
+}
+
+/// ADR links of a use case whose file does not exist
+pub(crate) fn missing_adrs<'a>(use_case: &'a UseCase, root: &Path) -> Vec<&'a str> {
+    use_case
+        .adrs
+        .iter()
+        .filter(|adr| !root.join(adr.as_str()).is_file())
+        .map(|adr| adr.as_str())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_adr_path() {
+        let root = Path::new("/project");
+        assert_eq!(
+            normalize_adr_path(root, "./docs/adr/0007.md").unwrap(),
+            "docs/adr/0007.md"
+        );
+        assert_eq!(
+            normalize_adr_path(root, "/project/docs/adr/0007.md").unwrap(),
+            "docs/adr/0007.md"
+        );
+        assert!(normalize_adr_path(root, "../other/0001.md").is_err());
+        assert!(normalize_adr_path(root, "/elsewhere/0001.md").is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! Read-only aggregation of several MUCM projects into one report.
+//!
+//! Each project is loaded from its own config and data directory without
+//! touching the current project. The report combines status counts and lists
+//! every use case in a cross-project index. A saved filter, looked up in each
+//! project's own config, narrows every project to the matching use cases.
+
+use crate::core::{Context, Result};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::config::{ProjectContext, StorageBackend};
+use crate::core::{RepositoryFactory, Status, UseCase};
+
+use super::{find_saved_filter, UseCaseQueryService};
+
+/// Use cases loaded from one project
+#[derive(Debug, Clone)]
+pub struct ProjectSnapshot {
+    /// Project name from its config
+    pub name: String,
+    /// Project root as given on the command line
+    pub root: PathBuf,
+    /// All use cases of the project
+    pub use_cases: Vec<UseCase>,
+}
+
+impl ProjectSnapshot {
+    /// Number of use cases per computed status
+    pub fn status_counts(&self) -> BTreeMap<Status, usize> {
+        let mut counts = BTreeMap::new();
+        for use_case in &self.use_cases {
+            *counts.entry(use_case.status()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Total number of scenarios across all use cases
+    pub fn scenario_count(&self) -> usize {
+        self.use_cases.iter().map(|uc| uc.scenarios.len()).sum()
+    }
+}
+
+/// Combined view over several projects
+#[derive(Debug, Clone, Default)]
+pub struct AggregateReport {
+    pub projects: Vec<ProjectSnapshot>,
+    /// Saved filter the projects were narrowed with
+    pub filter: Option<String>,
+}
+
+impl AggregateReport {
+    /// Load every project under `roots`, keeping only use cases matching the
+    /// saved filter `filter` of each project when given.
+    pub fn load(roots: &[PathBuf], filter: Option<&str>) -> Result<Self> {
+        let projects = roots
+            .iter()
+            .map(|root| load_project_snapshot(root, filter))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            projects,
+            filter: filter.map(str::to_string),
+        })
+    }
+
+    /// Number of use cases per status across all projects
+    pub fn status_counts(&self) -> BTreeMap<Status, usize> {
+        let mut counts = BTreeMap::new();
+        for project in &self.projects {
+            for (status, count) in project.status_counts() {
+                *counts.entry(status).or_insert(0) += count;
+            }
+        }
+        counts
+    }
+
+    /// Total number of use cases across all projects
+    pub fn use_case_count(&self) -> usize {
+        self.projects.iter().map(|p| p.use_cases.len()).sum()
+    }
+
+    /// Total number of scenarios across all projects
+    pub fn scenario_count(&self) -> usize {
+        self.projects.iter().map(|p| p.scenario_count()).sum()
+    }
+
+    /// Render the report as markdown: a stats table followed by a per-project index.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Aggregate Use Case Report\n\n");
+        out.push_str(&format!(
+            "{} project(s), {} use case(s), {} scenario(s).\n\n",
+            self.projects.len(),
+            self.use_case_count(),
+            self.scenario_count()
+        ));
+        if let Some(filter) = &self.filter {
+            out.push_str(&format!("Filtered by `{}`.\n\n", filter));
+        }
+
+        out.push_str("## Summary\n\n| Project | Use Cases | Scenarios |");
+        for status in Status::ALL {
+            out.push_str(&format!(" {} |", status.display_name()));
+        }
+        out.push_str("\n|---|---|---|");
+        for _ in Status::ALL {
+            out.push_str("---|");
+        }
+        out.push('\n');
+
+        for project in &self.projects {
+            out.push_str(&stats_row(
+                &project.name,
+                project.use_cases.len(),
+                project.scenario_count(),
+                &project.status_counts(),
+            ));
+        }
+        out.push_str(&stats_row(
+            "**Total**",
+            self.use_case_count(),
+            self.scenario_count(),
+            &self.status_counts(),
+        ));
+
+        out.push_str("\n## Index\n");
+        for project in &self.projects {
+            out.push_str(&format!(
+                "\n### {} (`{}`)\n\n",
+                project.name,
+                project.root.display()
+            ));
+            if project.use_cases.is_empty() {
+                out.push_str("_No use cases._\n");
+                continue;
+            }
+            out.push_str("| ID | Title | Category | Status | Priority |\n");
+            out.push_str("|---|---|---|---|---|\n");
+            let mut use_cases: Vec<&UseCase> = project.use_cases.iter().collect();
+            use_cases.sort_by(|a, b| a.id.cmp(&b.id));
+            for use_case in use_cases {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    use_case.id,
+                    use_case.title.replace('|', "\\|"),
+                    use_case.category,
+                    use_case.status().display_name(),
+                    use_case.priority
+                ));
+            }
+        }
+        out
+    }
+}
+
+fn stats_row(
+    label: &str,
+    use_cases: usize,
+    scenarios: usize,
+    counts: &BTreeMap<Status, usize>,
+) -> String {
+    let mut row = format!("| {} | {} | {} |", label, use_cases, scenarios);
+    for status in Status::ALL {
+        row.push_str(&format!(" {} |", counts.get(&status).copied().unwrap_or(0)));
+    }
+    row.push('\n');
+    row
+}
+
+/// Load a project's use cases without modifying it.
+///
+/// Directory settings are resolved against `root`. A SQLite project without a
+/// database yet has no use cases; no database is created for it. With
+/// `filter`, only use cases matching that saved filter of the project are kept.
+pub fn load_project_snapshot(root: &Path, filter: Option<&str>) -> Result<ProjectSnapshot> {
+    let config = ProjectContext::load(root)?.resolved_config();
+
+    let has_database = Path::new(&config.directories.data_dir)
+        .join("usecases.db")
+        .exists();
+    let mut use_cases = if config.storage.backend == StorageBackend::Sqlite && !has_database {
+        Vec::new()
+    } else {
+        RepositoryFactory::create(&config)?
+            .load_all()
+            .with_context(|| format!("Failed to load use cases from {}", root.display()))?
+    };
+
+    if let Some(name) = filter {
+        let saved = find_saved_filter(&config.filters, name)
+            .with_context(|| format!("In project {}", root.display()))?;
+        let matching: HashSet<String> = UseCaseQueryService::new(&use_cases)
+            .filter(saved, chrono::Utc::now().date_naive())?
+            .into_iter()
+            .map(|uc| uc.id.clone())
+            .collect();
+        use_cases.retain(|uc| matching.contains(&uc.id));
+    }
+
+    Ok(ProjectSnapshot {
+        name: config.project.name,
+        root: root.to_path_buf(),
+        use_cases,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Priority;
+
+    fn use_case(id: &str, title: &str) -> UseCase {
+        UseCase::builder(id, title)
+            .category("Auth")
+            .priority(Priority::High)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_aggregate_markdown_totals_and_index() {
+        let report = AggregateReport {
+            projects: vec![
+                ProjectSnapshot {
+                    name: "Web".to_string(),
+                    root: PathBuf::from("./web"),
+                    use_cases: vec![
+                        use_case("UC-AUT-002", "Logout"),
+                        use_case("UC-AUT-001", "Login"),
+                    ],
+                },
+                ProjectSnapshot {
+                    name: "Mobile".to_string(),
+                    root: PathBuf::from("./mobile"),
+                    use_cases: Vec::new(),
+                },
+            ],
+            filter: None,
+        };
+
+        assert_eq!(report.use_case_count(), 2);
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("| Web | 2 | 0 | 2 | 0 | 0 | 0 | 0 | 0 |"));
+        assert!(markdown.contains("| **Total** | 2 | 0 | 2 |"));
+        assert!(markdown.contains("### Mobile (`./mobile`)\n\n_No use cases._"));
+        let login = markdown.find("UC-AUT-001").unwrap();
+        let logout = markdown.find("UC-AUT-002").unwrap();
+        assert!(login < logout);
+    }
+
+    #[test]
+    fn test_load_project_snapshot_requires_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert!(load_project_snapshot(temp_dir.path(), None).is_err());
+    }
+}
@@ -0,0 +1,355 @@
+//! Batches of create/update/delete operations applied with one load and one save.
+//!
+//! Operations run in order against a working copy of the project; a failing
+//! operation is reported and skipped, the others still apply. The changes are
+//! then staged in a single transaction, so every use case is written and
+//! rendered once however many operations touched it.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::core::application::creators::UseCaseCreator;
+use crate::core::application::UseCaseCoordinator;
+use crate::core::{MucmError, Priority, RepositoryTransaction, Result, UseCase};
+
+/// One change to apply in a batch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BatchOperation {
+    Create {
+        title: String,
+        category: String,
+        #[serde(default)]
+        description: Option<String>,
+        /// Defaults to medium
+        #[serde(default)]
+        priority: Option<String>,
+        /// Comma-separated methodology:level pairs; defaults to the default methodology
+        #[serde(default)]
+        views: Option<String>,
+    },
+    Update {
+        use_case_id: String,
+        #[serde(default)]
+        title: Option<String>,
+        #[serde(default)]
+        category: Option<String>,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(default)]
+        priority: Option<String>,
+    },
+    Delete {
+        use_case_id: String,
+    },
+}
+
+/// A batch file: `[[operations]]` tables in TOML, or `{"operations": [...]}` in JSON
+#[derive(Debug, Deserialize)]
+struct BatchFile {
+    operations: Vec<BatchOperation>,
+}
+
+impl BatchOperation {
+    /// Read operations from a TOML or JSON (by extension) batch file
+    pub fn load(path: &Path) -> Result<Vec<BatchOperation>> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            MucmError::from(e).context(format!("Failed to read {}", path.display()))
+        })?;
+        let file: BatchFile = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&content)?
+        } else {
+            toml::from_str(&content)?
+        };
+        Ok(file.operations)
+    }
+
+    /// Short description for reports, e.g. "update UC-AUT-001"
+    pub fn summary(&self) -> String {
+        match self {
+            BatchOperation::Create {
+                title, category, ..
+            } => format!("create '{}' in {}", title, category),
+            BatchOperation::Update { use_case_id, .. } => format!("update {}", use_case_id),
+            BatchOperation::Delete { use_case_id } => format!("delete {}", use_case_id),
+        }
+    }
+}
+
+/// Passed to the progress callback after each operation
+#[derive(Debug)]
+pub struct BatchProgress<'a> {
+    /// Operations done so far, including this one
+    pub completed: usize,
+    pub total: usize,
+    pub operation: &'a BatchOperation,
+    pub succeeded: bool,
+}
+
+/// An operation that was skipped because it failed
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchFailure {
+    /// Position of the operation in the batch, starting at 1
+    pub index: usize,
+    pub operation: String,
+    pub error: String,
+}
+
+/// Outcome of a batch
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BatchReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub deleted: Vec<String>,
+    pub failed: Vec<BatchFailure>,
+}
+
+impl BatchReport {
+    /// Number of operations that applied
+    pub fn applied(&self) -> usize {
+        self.created.len() + self.updated.len() + self.deleted.len()
+    }
+}
+
+/// Applies batch operations to a working copy of the project's use cases
+pub(crate) struct BatchExecutor<'a> {
+    creator: &'a UseCaseCreator,
+    default_methodology: &'a str,
+}
+
+impl<'a> BatchExecutor<'a> {
+    pub fn new(creator: &'a UseCaseCreator, default_methodology: &'a str) -> Self {
+        Self {
+            creator,
+            default_methodology,
+        }
+    }
+
+    /// Run `operations` against `use_cases`, returning the resulting use cases
+    /// and the transaction that persists them
+    pub fn run(
+        &self,
+        use_cases: &[UseCase],
+        operations: &[BatchOperation],
+        mut on_progress: impl FnMut(&BatchProgress),
+    ) -> (Vec<UseCase>, RepositoryTransaction, BatchReport) {
+        let mut working = use_cases.to_vec();
+        let mut touched = HashSet::new();
+        let mut report = BatchReport::default();
+
+        for (index, operation) in operations.iter().enumerate() {
+            let result = self.apply(&mut working, operation);
+            match &result {
+                Ok(id) => {
+                    let ids = match operation {
+                        BatchOperation::Create { .. } => &mut report.created,
+                        BatchOperation::Update { .. } => &mut report.updated,
+                        BatchOperation::Delete { .. } => &mut report.deleted,
+                    };
+                    ids.push(id.clone());
+                    touched.insert(id.clone());
+                }
+                Err(e) => report.failed.push(BatchFailure {
+                    index: index + 1,
+                    operation: operation.summary(),
+                    error: e.to_string(),
+                }),
+            }
+            on_progress(&BatchProgress {
+                completed: index + 1,
+                total: operations.len(),
+                operation,
+                succeeded: result.is_ok(),
+            });
+        }
+
+        // Stage the net effect, so a use case created and deleted in the same
+        // batch is never written
+        let mut transaction = RepositoryTransaction::new();
+        for before in use_cases {
+            match working.iter().find(|uc| uc.id == before.id) {
+                None => transaction.delete(before.id.as_str()),
+                // Remove first so a changed category doesn't leave the old files behind
+                Some(after) if after.category != before.category => {
+                    transaction.delete(before.id.as_str())
+                }
+                Some(_) => {}
+            }
+        }
+        for use_case in working.iter().filter(|uc| touched.contains(&uc.id)) {
+            transaction.save(use_case.clone());
+        }
+        (working, transaction, report)
+    }
+
+    /// Apply one operation, returning the ID of the use case it concerns
+    fn apply(&self, use_cases: &mut Vec<UseCase>, operation: &BatchOperation) -> Result<String> {
+        match operation {
+            BatchOperation::Create {
+                title,
+                category,
+                description,
+                priority,
+                views,
+            } => {
+                let views = match views {
+                    Some(views) => UseCaseCoordinator::parse_views(views)?,
+                    None => UseCaseCoordinator::parse_views(&format!(
+                        "{}:normal",
+                        self.default_methodology
+                    ))?,
+                };
+                let use_case = self.creator.build_use_case_with_views(
+                    title.clone(),
+                    category.clone(),
+                    description.clone(),
+                    priority.clone().unwrap_or_else(|| "medium".to_string()),
+                    views,
+                    HashMap::new(),
+                    use_cases,
+                )?;
+                let id = use_case.id.clone();
+                use_cases.push(use_case);
+                Ok(id)
+            }
+            BatchOperation::Update {
+                use_case_id,
+                title,
+                category,
+                description,
+                priority,
+            } => {
+                let use_case = find(use_cases, use_case_id)?;
+                if let Some(priority) = priority {
+                    use_case.priority = priority
+                        .parse::<Priority>()
+                        .map_err(MucmError::Validation)?;
+                }
+                if let Some(title) = title {
+                    use_case.title = title.clone();
+                }
+                if let Some(category) = category {
+                    use_case.category = category.clone();
+                }
+                if let Some(description) = description {
+                    use_case.description = description.clone();
+                }
+                use_case.metadata.touch();
+                Ok(use_case_id.clone())
+            }
+            BatchOperation::Delete { use_case_id } => {
+                find(use_cases, use_case_id)?;
+                use_cases.retain(|uc| &uc.id != use_case_id);
+                Ok(use_case_id.clone())
+            }
+        }
+    }
+}
+
+fn find<'u>(use_cases: &'u mut [UseCase], use_case_id: &str) -> Result<&'u mut UseCase> {
+    use_cases
+        .iter_mut()
+        .find(|uc| uc.id == use_case_id)
+        .ok_or_else(|| MucmError::NotFound(format!("Use case {} not found", use_case_id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn use_case(id: &str, category: &str) -> UseCase {
+        UseCase::builder(id, "Title")
+            .category(category)
+            .priority(Priority::Low)
+            .build()
+            .unwrap()
+    }
+
+    fn update(use_case_id: &str, category: Option<&str>) -> BatchOperation {
+        BatchOperation::Update {
+            use_case_id: use_case_id.to_string(),
+            title: Some("Renamed".to_string()),
+            category: category.map(str::to_string),
+            description: None,
+            priority: Some("high".to_string()),
+        }
+    }
+
+    fn delete(use_case_id: &str) -> BatchOperation {
+        BatchOperation::Delete {
+            use_case_id: use_case_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_run_stages_the_net_effect() {
+        let creator = UseCaseCreator::new(Config::default());
+        let existing = vec![
+            use_case("UC-AUT-001", "Auth"),
+            use_case("UC-AUT-002", "Auth"),
+            use_case("UC-AUT-003", "Auth"),
+        ];
+        let operations = vec![
+            update("UC-AUT-001", None),
+            update("UC-AUT-001", None),
+            update("UC-AUT-002", Some("Billing")),
+            delete("UC-AUT-003"),
+            delete("UC-AUT-404"),
+            update("UC-AUT-003", None),
+        ];
+
+        let mut completed = Vec::new();
+        let (use_cases, transaction, report) =
+            BatchExecutor::new(&creator, "feature").run(&existing, &operations, |progress| {
+                completed.push((progress.completed, progress.succeeded))
+            });
+
+        assert_eq!(
+            report.updated,
+            vec!["UC-AUT-001", "UC-AUT-001", "UC-AUT-002"]
+        );
+        assert_eq!(report.deleted, vec!["UC-AUT-003"]);
+        let failed: Vec<usize> = report.failed.iter().map(|f| f.index).collect();
+        assert_eq!(failed, vec![5, 6]);
+        assert_eq!(completed.len(), 6);
+        assert!(!completed[4].1);
+
+        assert_eq!(use_cases.len(), 2);
+        assert_eq!(use_cases[0].priority, Priority::High);
+        let saved: Vec<&str> = transaction
+            .saves()
+            .iter()
+            .map(|uc| uc.id.as_str())
+            .collect();
+        assert_eq!(saved, vec!["UC-AUT-001", "UC-AUT-002"]);
+        assert_eq!(transaction.deletes(), ["UC-AUT-002", "UC-AUT-003"]);
+    }
+
+    #[test]
+    fn test_load_toml_batch_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("batch.toml");
+        fs::write(
+            &path,
+            r#"
+[[operations]]
+op = "create"
+title = "Login"
+category = "Auth"
+
+[[operations]]
+op = "delete"
+use_case_id = "UC-AUT-009"
+"#,
+        )
+        .unwrap();
+
+        let operations = BatchOperation::load(&path).unwrap();
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations[0].summary(), "create 'Login' in Auth");
+        assert_eq!(operations[1], delete("UC-AUT-009"));
+    }
+}
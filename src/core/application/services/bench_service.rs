@@ -0,0 +1,145 @@
+//! Synthetic projects for measuring generation performance.
+//!
+//! Used by the criterion benchmarks and the hidden `mucm bench` command. A
+//! project of generated use cases is saved, loaded and rendered through the
+//! real repository and generators, timing each phase.
+
+use crate::core::Result;
+use std::fs;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+use crate::core::application::generators::{MarkdownGenerator, OutputManager, OverviewGenerator};
+use crate::core::{Condition, MethodologyView, RepositoryFactory, Scenario, ScenarioType, UseCase};
+
+/// Number of categories synthetic use cases are spread over
+const SYNTHETIC_CATEGORIES: usize = 10;
+
+/// Generate `count` use cases resembling a real project.
+///
+/// Each use case has a view of `methodology`, pre- and postconditions and one
+/// scenario of each main type, so templates exercise their full structure.
+pub fn synthetic_use_cases(count: usize, id_prefix: &str, methodology: &str) -> Vec<UseCase> {
+    const PRIORITIES: [&str; 4] = ["low", "medium", "high", "critical"];
+    const SCENARIO_TYPES: [(ScenarioType, &str); 3] = [
+        (ScenarioType::HappyPath, "Main success"),
+        (ScenarioType::AlternativeFlow, "Alternative path"),
+        (ScenarioType::ExceptionFlow, "Failure handling"),
+    ];
+
+    (0..count)
+        .map(|i| {
+            let category = format!("Category {}", i % SYNTHETIC_CATEGORIES + 1);
+            let mut use_case = UseCase::new(
+                format!("{}-BENCH-{:04}", id_prefix, i + 1),
+                format!("Synthetic Use Case {}", i + 1),
+                category,
+                format!(
+                    "Generated use case {} for benchmarking. It describes a typical \
+                     interaction between a user and the system.",
+                    i + 1
+                ),
+                PRIORITIES[i % PRIORITIES.len()].to_string(),
+            )
+            .expect("synthetic priorities are valid");
+
+            use_case.add_view(MethodologyView::new(methodology, "normal"));
+            use_case.add_precondition(Condition::new("User is authenticated"));
+            use_case.add_postcondition(Condition::new("Changes are persisted"));
+
+            for (scenario_type, title) in SCENARIO_TYPES {
+                let mut scenario = Scenario::new(
+                    use_case.next_scenario_id(),
+                    title.to_string(),
+                    format!("{} of {}", title, use_case.title),
+                    scenario_type,
+                );
+                scenario.add_precondition(Condition::new("System is available"));
+                use_case.add_scenario(scenario);
+            }
+            use_case
+        })
+        .collect()
+}
+
+/// Timings of one benchmark run over a synthetic project
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub use_case_count: usize,
+    /// Phase name and wall-clock duration, in execution order
+    pub timings: Vec<(String, Duration)>,
+}
+
+impl BenchReport {
+    /// Save, load and render `count` synthetic use cases with `config`.
+    ///
+    /// The config's directories should point at a scratch location; files are
+    /// written there and left for the caller to clean up.
+    pub fn run(config: &Config, count: usize) -> Result<Self> {
+        fs::create_dir_all(&config.directories.use_case_dir)?;
+        fs::create_dir_all(&config.directories.data_dir)?;
+
+        let use_cases = synthetic_use_cases(
+            count,
+            &config.project.id_prefix,
+            &config.templates.default_methodology,
+        );
+        let repository = RepositoryFactory::create(config)?;
+        let mut timings = Vec::new();
+
+        let started = Instant::now();
+        for use_case in &use_cases {
+            repository.save(use_case)?;
+        }
+        timings.push(("save".to_string(), started.elapsed()));
+
+        let started = Instant::now();
+        let loaded = repository.load_all()?;
+        timings.push(("load_all".to_string(), started.elapsed()));
+
+        let generator = MarkdownGenerator::new(config.clone());
+        let started = Instant::now();
+        for use_case in &loaded {
+            for (_, view) in
+                OutputManager::generate_all_filenames(use_case, config.generation.output_format)
+            {
+                black_box(generator.generate(use_case, None, Some(&view))?);
+            }
+        }
+        timings.push(("render".to_string(), started.elapsed()));
+
+        let overview = OverviewGenerator::new(config.clone());
+        let started = Instant::now();
+        overview.generate(&loaded, &[])?;
+        timings.push(("overview".to_string(), started.elapsed()));
+
+        Ok(Self {
+            use_case_count: loaded.len(),
+            timings,
+        })
+    }
+
+    /// Total time over all phases
+    pub fn total(&self) -> Duration {
+        self.timings.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_use_cases() {
+        let use_cases = synthetic_use_cases(25, "UC", "feature");
+
+        assert_eq!(use_cases.len(), 25);
+        assert_eq!(use_cases[0].id, "UC-BENCH-0001");
+        assert_eq!(use_cases[0].scenarios.len(), 3);
+        assert_eq!(use_cases[0].views[0].methodology, "feature");
+        let categories: std::collections::HashSet<_> =
+            use_cases.iter().map(|uc| &uc.category).collect();
+        assert_eq!(categories.len(), SYNTHETIC_CATEGORIES);
+    }
+}
@@ -0,0 +1,202 @@
+//! Acceptance checklists.
+//!
+//! A checklist (`<ID>-checklist.md`, next to the use case's markdown) lists the
+//! use case's conditions and scenarios as `- [ ]` items. Scenario items are
+//! ticked from the scenario status, so ticking a scenario is a status change
+//! (to tested). Condition items have no status; ticks made by hand are kept when
+//! the checklist is regenerated. Used by `mucm checklist`.
+
+use crate::core::MucmError;
+use crate::core::Result;
+use std::collections::HashSet;
+
+use crate::core::utils::suggest_alternatives;
+use crate::core::{Condition, Scenario, Status, UseCase};
+
+/// File name of a use case's checklist
+pub(crate) fn checklist_filename(use_case_id: &str) -> String {
+    format!("{}-checklist.md", use_case_id)
+}
+
+/// Whether a scenario counts as accepted (ticked) in the checklist
+pub(crate) fn is_accepted(status: Status) -> bool {
+    matches!(status, Status::Tested | Status::Deployed)
+}
+
+/// Render the checklist of a use case
+///
+/// `existing` is the current checklist, whose ticked condition items stay ticked.
+pub(crate) fn render_checklist(use_case: &UseCase, existing: Option<&str>) -> String {
+    let ticked = existing.map(ticked_items).unwrap_or_default();
+    let scenarios: Vec<&Scenario> = use_case
+        .scenarios
+        .iter()
+        .filter(|s| s.status != Status::Deprecated)
+        .collect();
+    let accepted = scenarios.iter().filter(|s| is_accepted(s.status)).count();
+
+    let mut out = format!(
+        "# {} Acceptance Checklist: {}\n\n",
+        use_case.id, use_case.title
+    );
+    out.push_str(&format!(
+        "{} of {} scenario(s) accepted. Tick scenarios with `mucm checklist tick {} <SCENARIO>`; \
+         their items follow the scenario status.\n",
+        accepted,
+        scenarios.len(),
+        use_case.id
+    ));
+
+    push_conditions(&mut out, "Preconditions", &use_case.preconditions, &ticked);
+
+    out.push_str("\n## Scenarios\n\n");
+    if scenarios.is_empty() {
+        out.push_str("_No scenarios yet._\n");
+    }
+    for scenario in &scenarios {
+        out.push_str(&format!(
+            "- [{}] {} {} ({})\n",
+            mark(is_accepted(scenario.status)),
+            scenario.id,
+            scenario.title,
+            scenario.scenario_type
+        ));
+        let conditions = scenario
+            .preconditions
+            .iter()
+            .map(|c| ("Given", c))
+            .chain(scenario.postconditions.iter().map(|c| ("Then", c)));
+        for (keyword, condition) in conditions {
+            let text = format!("{} {}", keyword, condition.text);
+            out.push_str(&format!(
+                "  - [{}] {}\n",
+                mark(ticked.contains(&text)),
+                text
+            ));
+        }
+    }
+
+    push_conditions(
+        &mut out,
+        "Postconditions",
+        &use_case.postconditions,
+        &ticked,
+    );
+    out
+}
+
+/// Find a scenario by full ID (`UC-AUTH-001-S02`) or short form (`S02`, `S-002`, `2`)
+pub(crate) fn resolve_scenario_id(use_case: &UseCase, reference: &str) -> Result<String> {
+    if let Some(scenario) = use_case.scenarios.iter().find(|s| s.id == reference) {
+        return Ok(scenario.id.clone());
+    }
+    let number = scenario_number(reference);
+    let found = number.and_then(|number| {
+        use_case
+            .scenarios
+            .iter()
+            .find(|s| s.id.rsplit('-').next().and_then(scenario_number) == Some(number))
+    });
+    match found {
+        Some(scenario) => Ok(scenario.id.clone()),
+        None => {
+            let ids: Vec<String> = use_case.scenarios.iter().map(|s| s.id.clone()).collect();
+            Err(MucmError::NotFound(suggest_alternatives(
+                reference, &ids, "Scenario",
+            )))
+        }
+    }
+}
+
+/// Parse the number of `S02`, `S-002`, or `2`
+fn scenario_number(reference: &str) -> Option<u32> {
+    let reference = reference.trim();
+    let digits = reference
+        .strip_prefix(['S', 's'])
+        .map(|rest| rest.trim_start_matches('-'))
+        .unwrap_or(reference);
+    digits.parse().ok()
+}
+
+fn push_conditions(
+    out: &mut String,
+    heading: &str,
+    conditions: &[Condition],
+    ticked: &HashSet<String>,
+) {
+    if conditions.is_empty() {
+        return;
+    }
+    out.push_str(&format!("\n## {}\n\n", heading));
+    for condition in conditions {
+        out.push_str(&format!(
+            "- [{}] {}\n",
+            mark(ticked.contains(&condition.text)),
+            condition.text
+        ));
+    }
+}
+
+fn mark(checked: bool) -> char {
+    if checked {
+        'x'
+    } else {
+        ' '
+    }
+}
+
+/// Text of the ticked items of a checklist
+fn ticked_items(content: &str) -> HashSet<String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_start().strip_prefix("- ")?;
+            line.strip_prefix("[x] ")
+                .or_else(|| line.strip_prefix("[X] "))
+                .map(|text| text.trim_end().to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn use_case() -> UseCase {
+        UseCase::builder("UC-PAY-001", "Checkout")
+            .category("Payments")
+            .precondition("Cart is not empty")
+            .scenario(Scenario::builder("Pay by card"))
+            .scenario(Scenario::builder("Card declined"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_checklist_ticks_accepted_scenarios_and_keeps_condition_ticks() {
+        let mut use_case = use_case();
+        use_case.scenarios[1].status = Status::Tested;
+
+        let first = render_checklist(&use_case, None);
+        assert!(first.contains("- [ ] Cart is not empty\n"));
+        assert!(first.contains("- [ ] UC-PAY-001-S01 Pay by card"));
+        assert!(first.contains("- [x] UC-PAY-001-S02 Card declined"));
+        assert!(first.contains("1 of 2 scenario(s) accepted"));
+
+        let edited = first.replace("- [ ] Cart is not empty", "- [x] Cart is not empty");
+        let second = render_checklist(&use_case, Some(&edited));
+        assert!(second.contains("- [x] Cart is not empty\n"));
+    }
+
+    #[test]
+    fn test_resolve_scenario_id_accepts_short_forms() {
+        let use_case = use_case();
+        for reference in ["UC-PAY-001-S02", "S02", "S-002", "s2", "2"] {
+            assert_eq!(
+                resolve_scenario_id(&use_case, reference).unwrap(),
+                "UC-PAY-001-S02"
+            );
+        }
+        assert!(resolve_scenario_id(&use_case, "S09").is_err());
+    }
+}
@@ -0,0 +1,540 @@
+//! Example project for new users (`mucm demo`)
+//!
+//! Creates a small online bookshop in an empty directory: several categories,
+//! personas, use cases with views of every methodology, scenarios with steps,
+//! tags and statuses, references between use cases and scenarios, and the
+//! generated Python tests. Everything goes through the coordinator, so the
+//! output is exactly what the same commands would produce by hand.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, ProjectContext, TemplateManager};
+use crate::core::application::UseCaseCoordinator;
+use crate::core::{
+    MucmError, Persona, ReferenceType, RepositoryFactory, Result, ScenarioReference, ScenarioType,
+    Status,
+};
+
+use super::PersonaService;
+
+/// Language of the generated tests
+const DEMO_LANGUAGE: &str = "python";
+
+struct DemoPersona {
+    id: &'static str,
+    name: &'static str,
+    function: &'static str,
+}
+
+struct DemoScenario {
+    title: &'static str,
+    scenario_type: ScenarioType,
+    description: &'static str,
+    persona: Option<&'static str>,
+    tags: &'static [&'static str],
+    status: Status,
+    /// (actor, action, description); actors as accepted by `scenario step add`
+    steps: &'static [(&'static str, &'static str, &'static str)],
+    /// (use case index, scenario index, relationship) of scenarios this one refers to
+    references: &'static [(usize, usize, &'static str)],
+}
+
+struct DemoUseCase {
+    title: &'static str,
+    category: &'static str,
+    description: &'static str,
+    priority: &'static str,
+    views: &'static str,
+    preconditions: &'static [&'static str],
+    postconditions: &'static [&'static str],
+    scenarios: &'static [DemoScenario],
+    /// (use case index, relationship) of use cases this one refers to
+    references: &'static [(usize, &'static str)],
+}
+
+const PERSONAS: &[DemoPersona] = &[
+    DemoPersona {
+        id: "shopper",
+        name: "Sam the Shopper",
+        function: "Buys books online, mostly from a phone",
+    },
+    DemoPersona {
+        id: "store-manager",
+        name: "Morgan the Store Manager",
+        function: "Keeps the catalog up to date and handles orders",
+    },
+];
+
+const USE_CASES: &[DemoUseCase] = &[
+    DemoUseCase {
+        title: "Register an account",
+        category: "Accounts",
+        description: "A visitor creates an account to order books and follow their orders.",
+        priority: "high",
+        views: "feature:normal,tester:normal",
+        preconditions: &["The visitor has no account"],
+        postconditions: &["The account exists and the shopper is signed in"],
+        scenarios: &[
+            DemoScenario {
+                title: "Register with an email address",
+                scenario_type: ScenarioType::HappyPath,
+                description: "The visitor fills in the registration form.",
+                persona: Some("shopper"),
+                tags: &["smoke"],
+                status: Status::Tested,
+                steps: &[
+                    ("User", "opens", "the registration form"),
+                    ("User", "enters", "an email address and a password"),
+                    ("System", "creates", "the account and sends a welcome email"),
+                ],
+                references: &[],
+            },
+            DemoScenario {
+                title: "Email address already registered",
+                scenario_type: ScenarioType::ExceptionFlow,
+                description: "Someone registers with an address that has an account.",
+                persona: Some("shopper"),
+                tags: &[],
+                status: Status::Implemented,
+                steps: &[
+                    (
+                        "User",
+                        "submits",
+                        "an email address that is already registered",
+                    ),
+                    (
+                        "System",
+                        "refuses",
+                        "the registration and links to the sign in page",
+                    ),
+                ],
+                references: &[(1, 0, "extends")],
+            },
+        ],
+        references: &[],
+    },
+    DemoUseCase {
+        title: "Sign in",
+        category: "Accounts",
+        description: "A registered shopper signs in to reach their basket and orders.",
+        priority: "high",
+        views: "feature:normal,developer:normal",
+        preconditions: &["The shopper has an account"],
+        postconditions: &["The shopper is signed in"],
+        scenarios: &[
+            DemoScenario {
+                title: "Valid credentials",
+                scenario_type: ScenarioType::HappyPath,
+                description: "The shopper signs in with the right password.",
+                persona: Some("shopper"),
+                tags: &["smoke"],
+                status: Status::Deployed,
+                steps: &[
+                    ("User", "enters", "their email address and password"),
+                    (
+                        "System",
+                        "signs",
+                        "the shopper in and restores the basket of the last visit",
+                    ),
+                ],
+                references: &[],
+            },
+            DemoScenario {
+                title: "Wrong password",
+                scenario_type: ScenarioType::AlternativeFlow,
+                description: "The password does not match the account.",
+                persona: Some("shopper"),
+                tags: &[],
+                status: Status::Tested,
+                steps: &[
+                    ("User", "enters", "a wrong password"),
+                    (
+                        "System",
+                        "rejects",
+                        "the attempt and offers to reset the password",
+                    ),
+                ],
+                references: &[],
+            },
+        ],
+        references: &[(0, "dependency")],
+    },
+    DemoUseCase {
+        title: "Search the catalog",
+        category: "Catalog",
+        description: "Anyone can find books by title, author or ISBN.",
+        priority: "medium",
+        views: "feature:normal,business:normal",
+        preconditions: &[],
+        postconditions: &[],
+        scenarios: &[
+            DemoScenario {
+                title: "Search by title",
+                scenario_type: ScenarioType::HappyPath,
+                description: "The shopper types part of a title.",
+                persona: Some("shopper"),
+                tags: &["smoke", "search"],
+                status: Status::Implemented,
+                steps: &[
+                    ("User", "types", "a few words of the title"),
+                    (
+                        "System",
+                        "lists",
+                        "the matching books, those in stock first",
+                    ),
+                ],
+                references: &[],
+            },
+            DemoScenario {
+                title: "No matching book",
+                scenario_type: ScenarioType::AlternativeFlow,
+                description: "Nothing in the catalog matches the search.",
+                persona: None,
+                tags: &["search"],
+                status: Status::InProgress,
+                steps: &[
+                    ("User", "searches", "for a book the shop does not sell"),
+                    (
+                        "System",
+                        "shows",
+                        "similar titles and offers to notify the shopper when the book arrives",
+                    ),
+                ],
+                references: &[],
+            },
+        ],
+        references: &[],
+    },
+    DemoUseCase {
+        title: "Manage book listings",
+        category: "Catalog",
+        description:
+            "The store manager adds books to the catalog and keeps prices and stock right.",
+        priority: "medium",
+        views: "business:advanced,developer:normal",
+        preconditions: &["The store manager is signed in"],
+        postconditions: &["Shoppers see the updated listing"],
+        scenarios: &[DemoScenario {
+            title: "Add a new book",
+            scenario_type: ScenarioType::HappyPath,
+            description: "A book arrives that the shop did not sell before.",
+            persona: Some("store-manager"),
+            tags: &[],
+            status: Status::Planned,
+            steps: &[
+                ("@store-manager", "enters", "the ISBN of the book"),
+                (
+                    "System",
+                    "fills",
+                    "in the title, author and cover from the ISBN database",
+                ),
+                ("@store-manager", "sets", "the price and the stock"),
+            ],
+            references: &[],
+        }],
+        references: &[],
+    },
+    DemoUseCase {
+        title: "Check out a basket",
+        category: "Orders",
+        description:
+            "The shopper pays for the books in their basket and gets an order confirmation.",
+        priority: "critical",
+        views: "feature:advanced,business:normal,tester:advanced",
+        preconditions: &["The basket holds at least one book"],
+        postconditions: &["An order is recorded and the stock is reserved"],
+        scenarios: &[
+            DemoScenario {
+                title: "Pay by card",
+                scenario_type: ScenarioType::HappyPath,
+                description: "A signed in shopper pays with a saved card.",
+                persona: Some("shopper"),
+                tags: &["smoke", "payment"],
+                status: Status::InProgress,
+                steps: &[
+                    ("User", "opens", "the basket and chooses to check out"),
+                    ("User", "confirms", "the delivery address and the card"),
+                    ("System", "charges", "the card and shows the order number"),
+                ],
+                references: &[],
+            },
+            DemoScenario {
+                title: "Sign in during checkout",
+                scenario_type: ScenarioType::AlternativeFlow,
+                description: "The shopper was not signed in when checking out.",
+                persona: Some("shopper"),
+                tags: &[],
+                status: Status::Planned,
+                steps: &[
+                    ("System", "asks", "the shopper to sign in"),
+                    (
+                        "User",
+                        "signs",
+                        "in and continues the checkout with the same basket",
+                    ),
+                ],
+                references: &[(1, 0, "includes")],
+            },
+            DemoScenario {
+                title: "Card declined",
+                scenario_type: ScenarioType::ExceptionFlow,
+                description: "The payment provider refuses the card.",
+                persona: Some("shopper"),
+                tags: &["payment"],
+                status: Status::Planned,
+                steps: &[
+                    ("System", "reports", "that the card was declined"),
+                    ("User", "chooses", "another card"),
+                ],
+                references: &[],
+            },
+        ],
+        references: &[(1, "dependency"), (2, "dependency")],
+    },
+    DemoUseCase {
+        title: "Track an order",
+        category: "Orders",
+        description: "The shopper follows the delivery of an order.",
+        priority: "low",
+        views: "feature:normal",
+        preconditions: &["The shopper has placed an order"],
+        postconditions: &[],
+        scenarios: &[DemoScenario {
+            title: "Follow the delivery",
+            scenario_type: ScenarioType::HappyPath,
+            description: "The order has left the warehouse.",
+            persona: Some("shopper"),
+            tags: &[],
+            status: Status::Planned,
+            steps: &[
+                ("User", "opens", "the order from their account"),
+                (
+                    "System",
+                    "shows",
+                    "the delivery status with the tracking link of the carrier",
+                ),
+            ],
+            references: &[],
+        }],
+        references: &[(4, "extends")],
+    },
+];
+
+/// An example project created by `mucm demo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DemoProject {
+    pub root: PathBuf,
+    pub categories: Vec<String>,
+    pub use_cases: usize,
+    pub scenarios: usize,
+    pub personas: usize,
+    pub test_files: usize,
+}
+
+impl DemoProject {
+    /// Create the example project in `root`, which must not exist or be empty
+    pub fn create(root: &Path) -> Result<Self> {
+        if root.exists() && fs::read_dir(root)?.next().is_some() {
+            return Err(MucmError::Conflict(format!(
+                "{} is not empty; choose a new directory for the demo project",
+                root.display()
+            )));
+        }
+
+        let mut config = Config::for_template_with_methodologies_and_storage(
+            Some(DEMO_LANGUAGE.to_string()),
+            ["feature", "business", "developer", "tester"]
+                .iter()
+                .map(|methodology| methodology.to_string())
+                .collect(),
+            Some("feature".to_string()),
+            "toml".to_string(),
+        );
+        config.project.name = "Bookshop Demo".to_string();
+        config.project.description =
+            "Example project created by mucm demo: an online bookshop".to_string();
+
+        fs::create_dir_all(root.join(Config::CONFIG_DIR))?;
+        TemplateManager::create_config_from_template_in(&config, root)?;
+        TemplateManager::copy_templates_to_config(&root.to_string_lossy())?;
+
+        let context = ProjectContext::load(root)?;
+        let resolved = context.resolved_config();
+        for dir in [
+            &resolved.directories.use_case_dir,
+            &resolved.directories.test_dir,
+            &resolved.directories.actor_dir,
+            &resolved.directories.data_dir,
+        ] {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Personas first, so the coordinator knows them when scenarios refer to them
+        let personas = RepositoryFactory::create_persona_repository(&resolved)?;
+        let fields = PersonaService::new(&resolved.actor.persona_fields);
+        for persona in PERSONAS {
+            personas.save(&Persona::with_fields(
+                persona.id.to_string(),
+                persona.name.to_string(),
+                persona.function.to_string(),
+                fields.initial_fields(&HashMap::new())?,
+            ))?;
+        }
+
+        let mut coordinator = UseCaseCoordinator::for_project(context)?;
+        let scenarios = populate(&mut coordinator)?;
+        let test_files = coordinator.generate_tests(None, false, false, None)?.len();
+        coordinator.regenerate_all_markdown()?;
+
+        let mut categories: Vec<String> = USE_CASES
+            .iter()
+            .map(|use_case| use_case.category.to_string())
+            .collect();
+        categories.dedup();
+        Ok(Self {
+            root: root.to_path_buf(),
+            categories,
+            use_cases: USE_CASES.len(),
+            scenarios,
+            personas: PERSONAS.len(),
+            test_files,
+        })
+    }
+}
+
+/// Create the use cases and scenarios, returning the number of scenarios
+fn populate(coordinator: &mut UseCaseCoordinator) -> Result<usize> {
+    let mut use_case_ids = Vec::new();
+    let mut scenario_ids: Vec<Vec<String>> = Vec::new();
+
+    for use_case in USE_CASES {
+        let id = coordinator.create_use_case_with_views(
+            use_case.title.to_string(),
+            use_case.category.to_string(),
+            Some(use_case.description.to_string()),
+            use_case.views,
+        )?;
+        coordinator.update_use_case(&id, None, None, None, Some(use_case.priority))?;
+        for condition in use_case.preconditions {
+            coordinator.add_precondition(&id, condition.to_string())?;
+        }
+        for condition in use_case.postconditions {
+            coordinator.add_postcondition(&id, condition.to_string())?;
+        }
+
+        let mut ids = Vec::new();
+        for scenario in use_case.scenarios {
+            let scenario_id = coordinator.add_scenario(
+                &id,
+                scenario.title.to_string(),
+                scenario.scenario_type,
+                Some(scenario.description.to_string()),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                &[],
+            )?;
+            for (order, (actor, action, description)) in scenario.steps.iter().enumerate() {
+                coordinator.add_scenario_step(
+                    &id,
+                    &scenario_id,
+                    order as u32 + 1,
+                    actor.to_string(),
+                    None,
+                    action.to_string(),
+                    Some(description.to_string()),
+                )?;
+            }
+            if let Some(persona) = scenario.persona {
+                coordinator.assign_persona_to_scenario(&id, &scenario_id, persona)?;
+            }
+            if !scenario.tags.is_empty() {
+                let tags: Vec<String> = scenario.tags.iter().map(|tag| tag.to_string()).collect();
+                coordinator.add_scenario_tags(&id, &scenario_id, &tags)?;
+            }
+            coordinator.update_scenario_status(&id, &scenario_id, scenario.status)?;
+            ids.push(scenario_id);
+        }
+        use_case_ids.push(id);
+        scenario_ids.push(ids);
+    }
+
+    // References last: their targets all exist by now
+    for (index, use_case) in USE_CASES.iter().enumerate() {
+        let id = &use_case_ids[index];
+        for (target, relationship) in use_case.references {
+            coordinator.add_reference(
+                id,
+                use_case_ids[*target].clone(),
+                relationship.to_string(),
+                None,
+            )?;
+        }
+        for (scenario_index, scenario) in use_case.scenarios.iter().enumerate() {
+            for (target_use_case, target_scenario, relationship) in scenario.references {
+                coordinator.add_scenario_reference(
+                    id,
+                    &scenario_ids[index][scenario_index],
+                    ScenarioReference::new(
+                        ReferenceType::Scenario,
+                        scenario_ids[*target_use_case][*target_scenario].clone(),
+                        relationship.to_string(),
+                    ),
+                )?;
+            }
+        }
+    }
+
+    Ok(scenario_ids.iter().map(Vec::len).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_refuses_a_directory_that_is_not_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join("notes.txt"), "keep me").unwrap();
+
+        let error = DemoProject::create(dir.path()).unwrap_err();
+        assert!(error.to_string().contains("is not empty"));
+        assert!(!dir.path().join(Config::CONFIG_DIR).exists());
+    }
+
+    #[test]
+    fn test_demo_creates_a_valid_project() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path().join("demo");
+
+        let demo = DemoProject::create(&root).unwrap();
+        assert_eq!(demo.categories, ["Accounts", "Catalog", "Orders"]);
+        assert_eq!(demo.test_files, USE_CASES.len());
+
+        let coordinator =
+            UseCaseCoordinator::for_project(ProjectContext::load(&root).unwrap()).unwrap();
+        let use_cases = coordinator.get_all_use_cases();
+        assert_eq!(use_cases.len(), demo.use_cases);
+        assert_eq!(
+            use_cases.iter().map(|uc| uc.scenarios.len()).sum::<usize>(),
+            demo.scenarios
+        );
+        assert!(coordinator.validate().is_empty());
+    }
+
+    #[test]
+    fn test_demo_references_point_at_existing_entries() {
+        for (index, use_case) in USE_CASES.iter().enumerate() {
+            for (target, _) in use_case.references {
+                assert!(*target < USE_CASES.len() && *target != index);
+            }
+            for scenario in use_case.scenarios {
+                for (target, target_scenario, _) in scenario.references {
+                    assert!(*target_scenario < USE_CASES[*target].scenarios.len());
+                }
+            }
+        }
+    }
+}
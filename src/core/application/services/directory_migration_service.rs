@@ -0,0 +1,122 @@
+//! Moving project directories to a new location (`mucm config set directories.* --migrate`)
+
+use crate::core::MucmError;
+use crate::core::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Move the contents of `from` to `to`; returns false when `from` does not exist.
+///
+/// `to` may be inside `from` (docs → docs/uc) or contain it (docs/uc → docs).
+/// When `to` already has content, entries are moved one by one and an entry
+/// that exists on both sides stops the move before anything is changed.
+pub(crate) fn move_directory(from: &Path, to: &Path) -> Result<bool> {
+    if !from.is_dir() {
+        return Ok(false);
+    }
+    if to.exists() && !to.is_dir() {
+        return Err(MucmError::Validation(format!(
+            "{} exists and is not a directory",
+            to.display()
+        )));
+    }
+
+    if to.starts_with(from) {
+        // Moving a directory into itself: park it next to its old place first
+        let parked = from.with_file_name(format!(
+            "{}.mucm-move",
+            from.file_name().and_then(|n| n.to_str()).unwrap_or("dir")
+        ));
+        fs::rename(from, &parked).with_context(|| format!("Failed to move {}", from.display()))?;
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(&parked, to)
+            .with_context(|| format!("Failed to move {} to {}", from.display(), to.display()))?;
+        return Ok(true);
+    }
+
+    if !to.exists() {
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(from, to)
+            .with_context(|| format!("Failed to move {} to {}", from.display(), to.display()))?;
+        return Ok(true);
+    }
+
+    let entries: Vec<_> = fs::read_dir(from)?.collect::<std::io::Result<_>>()?;
+    for entry in &entries {
+        let target = to.join(entry.file_name());
+        if target.exists() {
+            return Err(MucmError::Conflict(format!(
+                "Cannot move {}: {} already exists",
+                entry.path().display(),
+                target.display()
+            )));
+        }
+    }
+    for entry in entries {
+        let target = to.join(entry.file_name());
+        fs::rename(entry.path(), &target)
+            .with_context(|| format!("Failed to move {}", entry.path().display()))?;
+    }
+
+    fs::remove_dir(from).with_context(|| format!("Failed to remove {}", from.display()))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, relative: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, relative).unwrap();
+    }
+
+    #[test]
+    fn test_move_to_new_location() {
+        let root = TempDir::new().unwrap();
+        write(root.path(), "docs/use-cases/auth/UC-AUT-001.md");
+
+        assert!(
+            move_directory(&root.path().join("docs/use-cases"), &root.path().join("uc")).unwrap()
+        );
+        assert!(root.path().join("uc/auth/UC-AUT-001.md").exists());
+        assert!(!root.path().join("docs/use-cases").exists());
+    }
+
+    #[test]
+    fn test_move_into_own_subdirectory_and_back() {
+        let root = TempDir::new().unwrap();
+        write(root.path(), "docs/README.md");
+
+        move_directory(&root.path().join("docs"), &root.path().join("docs/uc")).unwrap();
+        assert!(root.path().join("docs/uc/README.md").exists());
+
+        move_directory(&root.path().join("docs/uc"), &root.path().join("docs")).unwrap();
+        assert!(root.path().join("docs/README.md").exists());
+        assert!(!root.path().join("docs/uc").exists());
+    }
+
+    #[test]
+    fn test_conflict_leaves_everything_in_place() {
+        let root = TempDir::new().unwrap();
+        write(root.path(), "old/a.md");
+        write(root.path(), "old/b.md");
+        write(root.path(), "new/b.md");
+
+        let err = move_directory(&root.path().join("old"), &root.path().join("new")).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        assert!(root.path().join("old/a.md").exists());
+    }
+
+    #[test]
+    fn test_missing_source_moves_nothing() {
+        let root = TempDir::new().unwrap();
+        assert!(!move_directory(&root.path().join("old"), &root.path().join("new")).unwrap());
+    }
+}
@@ -0,0 +1,285 @@
+//! Effort and complexity estimation.
+//!
+//! Methodologies declare estimation fields in `[estimation]`. Each scenario
+//! stores its own estimates under `estimates`, and the use case stores the
+//! aggregated values under the same key so templates and reports can use both.
+//! Used by `mucm estimate`.
+
+use crate::core::MucmError;
+use crate::core::Result;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+use crate::core::{EstimateAggregate, EstimationFieldConfig, Scenario, UseCase};
+
+/// Key under which estimates are stored on scenarios and use cases
+pub(crate) const ESTIMATES_KEY: &str = "estimates";
+
+/// One estimate to ask for
+#[derive(Debug, Clone)]
+pub struct EstimateRequest {
+    pub scenario_id: String,
+    pub scenario_title: String,
+    /// Field name, e.g. "effort"
+    pub field: String,
+    pub config: EstimationFieldConfig,
+    /// Estimate already stored for this scenario, if any
+    pub current: Option<f64>,
+}
+
+/// Aggregated value of one estimation field
+#[derive(Debug, Clone, PartialEq)]
+pub struct EstimateTotal {
+    pub field: String,
+    pub label: String,
+    pub aggregate: EstimateAggregate,
+    /// None when no scenario has an estimate for the field
+    pub value: Option<f64>,
+    /// Number of scenarios with an estimate for the field
+    pub estimated: usize,
+}
+
+/// Result of estimating a use case
+#[derive(Debug, Clone)]
+pub struct EstimateReport {
+    pub use_case_id: String,
+    pub scenario_count: usize,
+    pub totals: Vec<EstimateTotal>,
+}
+
+/// Ask for every field of every scenario and store the answers
+///
+/// `ask` returns the new estimate, or None to keep the current one. The
+/// aggregated estimates are written onto the use case afterwards.
+pub(crate) fn estimate_use_case<F>(
+    use_case: &mut UseCase,
+    fields: &BTreeMap<String, EstimationFieldConfig>,
+    mut ask: F,
+) -> Result<EstimateReport>
+where
+    F: FnMut(&EstimateRequest) -> Result<Option<f64>>,
+{
+    if fields.is_empty() {
+        return Err(MucmError::Validation(format!(
+            "No methodology of {} declares estimation fields. \
+             Add an [estimation] section to methodology.toml",
+            use_case.id
+        )));
+    }
+    if use_case.scenarios.is_empty() {
+        return Err(MucmError::Validation(format!(
+            "{} has no scenarios to estimate",
+            use_case.id
+        )));
+    }
+
+    for scenario in &mut use_case.scenarios {
+        for (field, config) in fields {
+            let request = EstimateRequest {
+                scenario_id: scenario.id.clone(),
+                scenario_title: scenario.title.clone(),
+                field: field.clone(),
+                config: config.clone(),
+                current: scenario_estimate(scenario, field),
+            };
+            if let Some(value) = ask(&request)? {
+                validate_estimate(&request, value)?;
+                set_scenario_estimate(scenario, field, value);
+            }
+        }
+    }
+
+    let totals = aggregate_estimates(use_case, fields);
+    let aggregated: Map<String, Value> = totals
+        .iter()
+        .filter_map(|total| Some((total.field.clone(), number(total.value?))))
+        .collect();
+    if aggregated.is_empty() {
+        use_case.extra.remove(ESTIMATES_KEY);
+    } else {
+        use_case
+            .extra
+            .insert(ESTIMATES_KEY.to_string(), Value::Object(aggregated));
+    }
+
+    Ok(EstimateReport {
+        use_case_id: use_case.id.clone(),
+        scenario_count: use_case.scenarios.len(),
+        totals,
+    })
+}
+
+/// Combine the scenario estimates of a use case per field
+fn aggregate_estimates(
+    use_case: &UseCase,
+    fields: &BTreeMap<String, EstimationFieldConfig>,
+) -> Vec<EstimateTotal> {
+    fields
+        .iter()
+        .map(|(field, config)| {
+            let values: Vec<f64> = use_case
+                .scenarios
+                .iter()
+                .filter_map(|scenario| scenario_estimate(scenario, field))
+                .collect();
+            let value = if values.is_empty() {
+                None
+            } else {
+                Some(match config.aggregate {
+                    EstimateAggregate::Sum => values.iter().sum(),
+                    EstimateAggregate::Max => values.iter().copied().fold(f64::MIN, f64::max),
+                    EstimateAggregate::Average => values.iter().sum::<f64>() / values.len() as f64,
+                })
+            };
+            EstimateTotal {
+                field: field.clone(),
+                label: config.label_or(field).to_string(),
+                aggregate: config.aggregate,
+                value,
+                estimated: values.len(),
+            }
+        })
+        .collect()
+}
+
+fn scenario_estimate(scenario: &Scenario, field: &str) -> Option<f64> {
+    scenario.extra.get(ESTIMATES_KEY)?.get(field)?.as_f64()
+}
+
+fn set_scenario_estimate(scenario: &mut Scenario, field: &str, value: f64) {
+    let estimates = scenario
+        .extra
+        .entry(ESTIMATES_KEY.to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+    if !estimates.is_object() {
+        *estimates = Value::Object(Map::new());
+    }
+    if let Value::Object(estimates) = estimates {
+        estimates.insert(field.to_string(), number(value));
+    }
+}
+
+fn validate_estimate(request: &EstimateRequest, value: f64) -> Result<()> {
+    let label = request.config.label_or(&request.field);
+    if !value.is_finite() || value < 0.0 {
+        return Err(MucmError::Validation(format!(
+            "{} must be a non-negative number, got {}",
+            label, value
+        )));
+    }
+    let scale = &request.config.scale;
+    if !scale.is_empty() && !scale.contains(&value) {
+        return Err(MucmError::Validation(format!(
+            "{} must be one of {}, got {}",
+            label,
+            format_scale(scale),
+            value
+        )));
+    }
+    Ok(())
+}
+
+/// Format a scale for errors, e.g. "1, 2, 3, 5"
+fn format_scale(scale: &[f64]) -> String {
+    scale
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Store whole numbers as integers so data files read `effort = 3`
+fn number(value: f64) -> Value {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        Value::from(value as i64)
+    } else {
+        Value::from(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ScenarioType;
+
+    fn field(aggregate: EstimateAggregate, scale: Vec<f64>) -> EstimationFieldConfig {
+        EstimationFieldConfig {
+            label: None,
+            description: None,
+            scale,
+            aggregate,
+        }
+    }
+
+    fn use_case_with_scenarios(count: usize) -> UseCase {
+        let mut use_case = UseCase::new(
+            "UC-PAY-001".to_string(),
+            "Checkout".to_string(),
+            "Payments".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        for index in 1..=count {
+            use_case.scenarios.push(Scenario::new(
+                format!("UC-PAY-001-S{:02}", index),
+                format!("Scenario {}", index),
+                String::new(),
+                ScenarioType::HappyPath,
+            ));
+        }
+        use_case
+    }
+
+    #[test]
+    fn test_estimates_are_stored_per_scenario_and_aggregated() {
+        let mut use_case = use_case_with_scenarios(3);
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "complexity".to_string(),
+            field(EstimateAggregate::Max, vec![]),
+        );
+        fields.insert("effort".to_string(), field(EstimateAggregate::Sum, vec![]));
+
+        let answers = [2.0, 3.0, 4.0, 5.0, 1.0, 0.5];
+        let mut next = answers.iter();
+        let report =
+            estimate_use_case(&mut use_case, &fields, |_| Ok(next.next().copied())).unwrap();
+
+        let totals: Vec<_> = report.totals.iter().map(|t| t.value).collect();
+        assert_eq!(totals, vec![Some(4.0), Some(8.5)]);
+        assert_eq!(use_case.extra[ESTIMATES_KEY]["complexity"], 4);
+        assert_eq!(use_case.extra[ESTIMATES_KEY]["effort"], 8.5);
+        assert_eq!(use_case.scenarios[1].extra[ESTIMATES_KEY]["effort"], 5);
+
+        // Skipping keeps the stored estimates
+        let report = estimate_use_case(&mut use_case, &fields, |request| {
+            assert!(request.current.is_some());
+            Ok(None)
+        })
+        .unwrap();
+        assert_eq!(report.totals[1].value, Some(8.5));
+    }
+
+    #[test]
+    fn test_estimates_outside_the_scale_are_rejected() {
+        let mut use_case = use_case_with_scenarios(1);
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "effort".to_string(),
+            field(EstimateAggregate::Sum, vec![1.0, 2.0, 3.0, 5.0]),
+        );
+
+        let error = estimate_use_case(&mut use_case, &fields, |_| Ok(Some(4.0))).unwrap_err();
+        assert!(error.to_string().contains("one of 1, 2, 3, 5"));
+        assert!(!use_case.extra.contains_key(ESTIMATES_KEY));
+    }
+
+    #[test]
+    fn test_estimating_without_fields_fails() {
+        let mut use_case = use_case_with_scenarios(1);
+        let error =
+            estimate_use_case(&mut use_case, &BTreeMap::new(), |_| Ok(Some(1.0))).unwrap_err();
+        assert!(error.to_string().contains("[estimation]"));
+    }
+}
@@ -0,0 +1,304 @@
+//! Provenance of use cases and generated files.
+//!
+//! Given an ID or a generated file path, works out where the data lives,
+//! which views and template files produce the documentation, and which
+//! backup snapshots hold earlier versions of the data (one per command that
+//! changed it). Used by `mucm explain` when debugging template customization.
+
+use crate::core::{MucmError, Result};
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, StorageBackend};
+use crate::core::application::generators::overview_generator::{
+    should_shard, CATEGORY_OVERVIEW_FILE,
+};
+use crate::core::application::generators::{OutputManager, TestGenerator};
+use crate::core::infrastructure::EVENT_LOG;
+use crate::core::utils::suggest_alternatives;
+use crate::core::{
+    to_snake_case, BackupStore, MethodologyView, TemplateEngine, TemplateFile, UseCase,
+};
+
+/// What an ID or path refers to and how it is produced
+#[derive(Debug, Clone)]
+pub enum Explanation {
+    UseCase(UseCaseExplanation),
+    Overview(OverviewExplanation),
+}
+
+/// Provenance of a use case (or one of its scenarios)
+#[derive(Debug, Clone)]
+pub struct UseCaseExplanation {
+    pub use_case_id: String,
+    pub title: String,
+    pub category: String,
+    /// Scenario ID and title when a scenario was asked for
+    pub scenario: Option<(String, String)>,
+    /// TOML data file, or the database for the SQLite backend
+    pub data_file: PathBuf,
+    /// Generated documentation per view
+    pub views: Vec<ViewProvenance>,
+    /// Generated test file, if test generation is enabled
+    pub test_file: Option<PathBuf>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Backup snapshots holding an earlier version of the data file, newest first
+    pub changes: Vec<String>,
+}
+
+/// A generated documentation file and the templates that render it
+#[derive(Debug, Clone)]
+pub struct ViewProvenance {
+    pub view: MethodologyView,
+    pub output: PathBuf,
+    pub template: TemplateFile,
+    /// Scenario partial, when the methodology definition can be read
+    pub scenario_template: Option<TemplateFile>,
+}
+
+/// Provenance of an overview page
+#[derive(Debug, Clone)]
+pub struct OverviewExplanation {
+    pub file: PathBuf,
+    /// Template file; None when the built-in default is used
+    pub template: Option<TemplateFile>,
+    /// Whether the page is the index of a sharded overview
+    pub index: bool,
+}
+
+/// Service that explains where use cases and generated files come from
+pub struct ExplainService<'a> {
+    use_cases: &'a [UseCase],
+    config: &'a Config,
+    template_engine: &'a TemplateEngine,
+    test_generator: &'a TestGenerator,
+}
+
+impl<'a> ExplainService<'a> {
+    pub fn new(
+        use_cases: &'a [UseCase],
+        config: &'a Config,
+        template_engine: &'a TemplateEngine,
+        test_generator: &'a TestGenerator,
+    ) -> Self {
+        Self {
+            use_cases,
+            config,
+            template_engine,
+            test_generator,
+        }
+    }
+
+    /// Explain a use case ID, scenario ID, or generated/data file path
+    pub fn explain(&self, query: &str) -> Result<Explanation> {
+        if let Some(use_case) = self.use_cases.iter().find(|uc| uc.id == query) {
+            return self.explain_use_case(use_case, None, None);
+        }
+        for use_case in self.use_cases {
+            if let Some(scenario) = use_case.scenarios.iter().find(|s| s.id == query) {
+                let scenario = (scenario.id.clone(), scenario.title.clone());
+                return self.explain_use_case(use_case, Some(scenario), None);
+            }
+        }
+
+        let path = Path::new(query);
+        if path
+            .file_name()
+            .is_some_and(|name| name == "README.md" || name == CATEGORY_OVERVIEW_FILE)
+        {
+            return Ok(self.explain_overview(path));
+        }
+        if let Some(stem) = path.file_stem().map(|stem| stem.to_string_lossy()) {
+            if let Some((use_case, suffix)) = self.use_case_for_stem(&stem) {
+                return self.explain_use_case(use_case, None, suffix);
+            }
+        }
+
+        let ids: Vec<String> = self.use_cases.iter().map(|uc| uc.id.clone()).collect();
+        Err(MucmError::NotFound(suggest_alternatives(
+            query, &ids, "use case",
+        )))
+    }
+
+    /// Use case whose ID (or snake_case ID, for test files) a file stem starts with
+    ///
+    /// Returns the remainder after `<ID>-`, which names the view of a markdown file.
+    fn use_case_for_stem<'s>(&self, stem: &'s str) -> Option<(&'a UseCase, Option<&'s str>)> {
+        self.use_cases
+            .iter()
+            .filter_map(|uc| {
+                if stem == uc.id || stem == to_snake_case(&uc.id) {
+                    Some((uc, None))
+                } else {
+                    stem.strip_prefix(uc.id.as_str())
+                        .and_then(|rest| rest.strip_prefix('-'))
+                        .map(|view| (uc, Some(view)))
+                }
+            })
+            .max_by_key(|(uc, _)| uc.id.len())
+    }
+
+    fn explain_use_case(
+        &self,
+        use_case: &UseCase,
+        scenario: Option<(String, String)>,
+        view_key: Option<&str>,
+    ) -> Result<Explanation> {
+        let data_dir = Path::new(&self.config.directories.data_dir);
+        let data_file = data_file_path(self.config, use_case);
+
+        let output_dir = Path::new(&self.config.directories.use_case_dir)
+            .join(to_snake_case(&use_case.category));
+        let views =
+            OutputManager::generate_all_filenames(use_case, self.config.generation.output_format)
+                .into_iter()
+                .filter(|(_, view)| view_key.is_none() || view_key == Some(view.key().as_str()))
+                .map(|(filename, view)| ViewProvenance {
+                    output: output_dir.join(filename),
+                    template: self
+                        .template_engine
+                        .use_case_template_file(&view.methodology, &view.level),
+                    scenario_template: self
+                        .template_engine
+                        .scenario_template_file(&view.methodology, &view.level),
+                    view,
+                })
+                .collect();
+
+        let test_file = if self.config.generation.test_language == "none" {
+            None
+        } else {
+            Some(self.test_generator.get_file_path(use_case)?)
+        };
+
+        let relative = data_file.strip_prefix(data_dir).unwrap_or(&data_file);
+        let changes = BackupStore::new(self.config)
+            .list()?
+            .into_iter()
+            .filter(|snapshot| snapshot.path.join(relative).exists())
+            .map(|snapshot| snapshot.name)
+            .collect();
+
+        Ok(Explanation::UseCase(UseCaseExplanation {
+            use_case_id: use_case.id.clone(),
+            title: use_case.title.clone(),
+            category: use_case.category.clone(),
+            scenario,
+            data_file,
+            views,
+            test_file,
+            created_at: use_case.metadata.created_at,
+            updated_at: use_case.metadata.updated_at,
+            changes,
+        }))
+    }
+
+    fn explain_overview(&self, path: &Path) -> Explanation {
+        let top_level = path.parent() == Some(Path::new(&self.config.directories.use_case_dir));
+        let index = top_level
+            && should_shard(
+                self.use_cases.len(),
+                self.config.generation.overview_shard_threshold,
+            );
+        Explanation::Overview(OverviewExplanation {
+            file: path.to_path_buf(),
+            template: self.template_engine.overview_template_file(index),
+            index,
+        })
+    }
+}
+
+/// Data file of a use case (the database for SQLite, the log for the event backend)
+pub(crate) fn data_file_path(config: &Config, use_case: &UseCase) -> PathBuf {
+    let data_dir = Path::new(&config.directories.data_dir);
+    match config.storage.backend {
+        StorageBackend::Toml => data_dir
+            .join(to_snake_case(&use_case.category))
+            .join(format!("{}.toml", use_case.id)),
+        StorageBackend::Sqlite => data_dir.join("usecases.db"),
+        StorageBackend::Events => data_dir.join(EVENT_LOG),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Scenario, ScenarioType};
+
+    fn create_use_cases() -> Vec<UseCase> {
+        let mut login = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        login.views = vec![
+            MethodologyView::new("feature", "normal"),
+            MethodologyView::new("business", "simple"),
+        ];
+        login.add_scenario(Scenario::new(
+            "UC-AUT-001-S01".to_string(),
+            "Valid password".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        ));
+        vec![login]
+    }
+
+    fn explain(query: &str) -> Result<Explanation> {
+        let use_cases = create_use_cases();
+        let config = Config::default();
+        let engine = TemplateEngine::with_config(Some(&config));
+        let tests =
+            TestGenerator::for_project(&crate::config::ProjectContext::current(config.clone()));
+        ExplainService::new(&use_cases, &config, &engine, &tests).explain(query)
+    }
+
+    fn use_case(explanation: Explanation) -> UseCaseExplanation {
+        match explanation {
+            Explanation::UseCase(explanation) => explanation,
+            Explanation::Overview(_) => panic!("expected a use case explanation"),
+        }
+    }
+
+    #[test]
+    fn test_explain_scenario_id() {
+        let explanation = use_case(explain("UC-AUT-001-S01").unwrap());
+        assert_eq!(explanation.use_case_id, "UC-AUT-001");
+        assert_eq!(
+            explanation.scenario,
+            Some(("UC-AUT-001-S01".to_string(), "Valid password".to_string()))
+        );
+        assert_eq!(explanation.views.len(), 2);
+        assert!(explanation.data_file.ends_with("auth/UC-AUT-001.toml"));
+    }
+
+    #[test]
+    fn test_explain_markdown_path_selects_view() {
+        let explanation =
+            use_case(explain("docs/use-cases/auth/UC-AUT-001-business-simple.md").unwrap());
+        assert_eq!(explanation.views.len(), 1);
+        let view = &explanation.views[0];
+        assert_eq!(view.view.key(), "business-simple");
+        assert!(view
+            .template
+            .path
+            .ends_with("methodologies/business/uc_simple.hbs"));
+    }
+
+    #[test]
+    fn test_explain_overview_and_unknown() {
+        assert!(matches!(
+            explain(&format!(
+                "{}/README.md",
+                Config::default().directories.use_case_dir
+            ))
+            .unwrap(),
+            Explanation::Overview(OverviewExplanation { index: false, .. })
+        ));
+        assert!(explain("UC-XYZ-999").is_err());
+    }
+}
@@ -0,0 +1,617 @@
+//! Import of Gherkin feature files for teams migrating from Cucumber.
+//!
+//! Each `Feature` becomes a use case, each `Scenario` (or `Scenario Outline`)
+//! a scenario, and each step a scenario step that keeps its Given/When/Then
+//! keyword. `Background` steps become use case preconditions.
+//!
+//! Re-importing is idempotent: a feature is matched to the use case it created
+//! through the `gherkin_source` extra field (falling back to the title), and
+//! scenarios are matched by title, so changed features update their use case
+//! instead of creating duplicates. Nothing is ever deleted by an import.
+//!
+//! Only the English Gherkin keywords are understood. Scenario types are taken
+//! from `@main`, `@alternative`, `@exception` and `@extension` tags; other
+//! scenario tags (e.g. `@smoke`) become scenario tags.
+
+use crate::core::{Context, MucmError, Result};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::core::domain::ScenarioStep;
+use crate::core::{Actor, Scenario, ScenarioType, UseCase};
+
+/// Extra field linking a use case to the feature file it was imported from
+pub const GHERKIN_SOURCE_FIELD: &str = "gherkin_source";
+
+/// Category of features found directly in the import root
+pub const DEFAULT_FEATURE_CATEGORY: &str = "Features";
+
+/// A parsed `Feature`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GherkinFeature {
+    /// Feature file path relative to the import root, used to match re-imports
+    pub source: String,
+    /// Category derived from the directory holding the file
+    pub category: String,
+    pub title: String,
+    pub description: String,
+    /// Background step texts, imported as preconditions
+    pub background: Vec<String>,
+    pub scenarios: Vec<GherkinScenario>,
+}
+
+/// A parsed `Scenario` or `Scenario Outline`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GherkinScenario {
+    pub title: String,
+    /// Free text below the title, plus any `Examples` tables
+    pub description: String,
+    pub scenario_type: ScenarioType,
+    /// Tags other than the scenario type tag, without the leading '@'
+    pub tags: Vec<String>,
+    pub steps: Vec<GherkinStep>,
+}
+
+/// A parsed step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GherkinStep {
+    /// Keyword as written (Given, When, Then, And, But or *)
+    pub keyword: String,
+    pub text: String,
+    /// Doc string or data table attached to the step
+    pub notes: Option<String>,
+}
+
+/// Outcome of a Gherkin import run
+#[derive(Debug, Default)]
+pub struct GherkinImportReport {
+    /// IDs of use cases created from new features
+    pub created: Vec<String>,
+    /// (ID, scenarios added, scenarios updated) of re-imported features that changed
+    pub updated: Vec<(String, usize, usize)>,
+    /// IDs of re-imported features without changes
+    pub unchanged: Vec<String>,
+}
+
+/// What applying a feature to an existing use case changed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureSync {
+    pub added: usize,
+    pub updated: usize,
+    /// Whether anything at all changed (including description and preconditions)
+    pub changed: bool,
+}
+
+const STEP_KEYWORDS: [&str; 5] = ["Given", "When", "Then", "And", "But"];
+const SCENARIO_KEYWORDS: [&str; 4] = [
+    "Scenario Outline:",
+    "Scenario Template:",
+    "Scenario:",
+    "Example:",
+];
+
+/// Where free text and table rows currently go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Feature,
+    Background,
+    Scenario,
+    Examples,
+    Ignored,
+}
+
+/// Parse the content of one `.feature` file
+pub fn parse_feature(content: &str) -> Result<GherkinFeature> {
+    let mut feature: Option<GherkinFeature> = None;
+    let mut section = Section::Feature;
+    let mut tags: Vec<String> = Vec::new();
+    let mut lines = content.lines().enumerate();
+
+    while let Some((index, raw)) = lines.next() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line_number = index + 1;
+
+        if line.starts_with('@') {
+            tags.extend(
+                line.split_whitespace()
+                    .map(|tag| tag.trim_start_matches('@').to_string()),
+            );
+            continue;
+        }
+
+        if let Some(title) = line.strip_prefix("Feature:") {
+            if feature.is_some() {
+                return Err(MucmError::Validation(format!(
+                    "Line {}: only one Feature is allowed per file",
+                    line_number
+                )));
+            }
+            feature = Some(GherkinFeature {
+                title: title.trim().to_string(),
+                ..Default::default()
+            });
+            section = Section::Feature;
+            tags.clear();
+            continue;
+        }
+
+        let Some(current) = feature.as_mut() else {
+            return Err(MucmError::Validation(format!(
+                "Line {}: expected 'Feature:', found '{}'",
+                line_number, line
+            )));
+        };
+
+        if line.starts_with("Background:") {
+            section = Section::Background;
+            tags.clear();
+            continue;
+        }
+        if line.starts_with("Rule:") {
+            // Rules only group scenarios; their own text is not imported
+            section = Section::Ignored;
+            tags.clear();
+            continue;
+        }
+        if line.starts_with("Examples:") || line.starts_with("Scenarios:") {
+            section = Section::Examples;
+            tags.clear();
+            if let Some(scenario) = current.scenarios.last_mut() {
+                push_line(&mut scenario.description, line);
+            }
+            continue;
+        }
+        if let Some(title) = SCENARIO_KEYWORDS
+            .iter()
+            .find_map(|keyword| line.strip_prefix(keyword))
+        {
+            let scenario_type = tags
+                .iter()
+                .find_map(|tag| ScenarioType::from_str(tag).ok())
+                .unwrap_or_default();
+            let scenario_tags = tags
+                .iter()
+                .filter(|tag| ScenarioType::from_str(tag).is_err())
+                .cloned()
+                .collect();
+            current.scenarios.push(GherkinScenario {
+                title: title.trim().to_string(),
+                description: String::new(),
+                scenario_type,
+                tags: scenario_tags,
+                steps: Vec::new(),
+            });
+            section = Section::Scenario;
+            tags.clear();
+            continue;
+        }
+
+        if line.starts_with("\"\"\"") || line.starts_with("```") {
+            let delimiter = &line[..3];
+            let indent = raw.len() - raw.trim_start().len();
+            let mut doc = Vec::new();
+            loop {
+                let Some((_, doc_line)) = lines.next() else {
+                    return Err(MucmError::Validation(format!(
+                        "Line {}: unterminated doc string",
+                        line_number
+                    )));
+                };
+                if doc_line.trim() == delimiter {
+                    break;
+                }
+                let strip = indent.min(doc_line.len() - doc_line.trim_start().len());
+                doc.push(&doc_line[strip..]);
+            }
+            attach_to_last_step(current, section, &doc.join("\n"), line_number)?;
+            continue;
+        }
+
+        if line.starts_with('|') {
+            match section {
+                Section::Examples => {
+                    if let Some(scenario) = current.scenarios.last_mut() {
+                        let in_table = scenario
+                            .description
+                            .lines()
+                            .last()
+                            .is_some_and(|last| last.starts_with('|'));
+                        push_line(&mut scenario.description, line);
+                        if !in_table {
+                            // Gherkin tables have no separator row; markdown needs one
+                            push_line(&mut scenario.description, &separator_row(line));
+                        }
+                    }
+                }
+                Section::Ignored => {}
+                _ => attach_to_last_step(current, section, line, line_number)?,
+            }
+            continue;
+        }
+
+        if let Some((keyword, text)) = split_step(line) {
+            match section {
+                Section::Background => current.background.push(text.to_string()),
+                Section::Scenario => {
+                    let Some(scenario) = current.scenarios.last_mut() else {
+                        return Err(MucmError::Validation(format!(
+                            "Line {}: step outside of a scenario",
+                            line_number
+                        )));
+                    };
+                    scenario.steps.push(GherkinStep {
+                        keyword: keyword.to_string(),
+                        text: text.to_string(),
+                        notes: None,
+                    });
+                }
+                _ => {
+                    return Err(MucmError::Validation(format!(
+                        "Line {}: step outside of a scenario",
+                        line_number
+                    )))
+                }
+            }
+            continue;
+        }
+
+        // Free text describes whatever was opened last
+        match section {
+            Section::Feature => push_line(&mut current.description, line),
+            Section::Scenario | Section::Examples => {
+                if let Some(scenario) = current.scenarios.last_mut() {
+                    push_line(&mut scenario.description, line);
+                }
+            }
+            Section::Background | Section::Ignored => {}
+        }
+    }
+
+    match feature {
+        Some(feature) if !feature.title.is_empty() => Ok(feature),
+        Some(_) => Err(MucmError::Validation("Feature title is empty".to_string())),
+        None => Err(MucmError::Validation("No 'Feature:' found".to_string())),
+    }
+}
+
+fn push_line(text: &mut String, line: &str) {
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text.push_str(line);
+}
+
+/// The markdown separator row for a table whose header row is `header`
+fn separator_row(header: &str) -> String {
+    let mut columns = 0;
+    let mut escaped = false;
+    for c in header.trim().trim_start_matches('|').chars() {
+        if c == '|' && !escaped {
+            columns += 1;
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    format!("|{}", " --- |".repeat(columns.max(1)))
+}
+
+fn split_step(line: &str) -> Option<(&str, &str)> {
+    if let Some(text) = line.strip_prefix("* ") {
+        return Some(("*", text.trim()));
+    }
+    STEP_KEYWORDS.iter().find_map(|keyword| {
+        let text = line.strip_prefix(keyword)?;
+        text.starts_with(' ').then_some((*keyword, text.trim()))
+    })
+}
+
+fn attach_to_last_step(
+    feature: &mut GherkinFeature,
+    section: Section,
+    text: &str,
+    line_number: usize,
+) -> Result<()> {
+    let step = match section {
+        Section::Scenario => feature
+            .scenarios
+            .last_mut()
+            .and_then(|scenario| scenario.steps.last_mut()),
+        // Background arguments have nowhere to go; keep them in the precondition
+        Section::Background => {
+            if let Some(condition) = feature.background.last_mut() {
+                push_line(condition, text);
+                return Ok(());
+            }
+            None
+        }
+        _ => None,
+    };
+    let Some(step) = step else {
+        return Err(MucmError::Validation(format!(
+            "Line {}: doc string or table without a preceding step",
+            line_number
+        )));
+    };
+    match &mut step.notes {
+        Some(notes) => push_line(notes, text),
+        None => step.notes = Some(text.to_string()),
+    }
+    Ok(())
+}
+
+/// Read the `.feature` files at a path (a file or a directory searched recursively)
+///
+/// Each feature's category is the name of the directory holding it, or
+/// [`DEFAULT_FEATURE_CATEGORY`] for files directly in the import root.
+pub fn load_features(path: &Path) -> Result<Vec<GherkinFeature>> {
+    if !path.exists() {
+        return Err(MucmError::NotFound(format!(
+            "Import path not found: {}",
+            path.display()
+        )));
+    }
+
+    let (root, files) = if path.is_file() {
+        let root = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        (root, vec![path.to_path_buf()])
+    } else {
+        let mut files = Vec::new();
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if entry.file_type().is_file()
+                && entry.path().extension().is_some_and(|ext| ext == "feature")
+            {
+                files.push(entry.into_path());
+            }
+        }
+        files.sort();
+        (path.to_path_buf(), files)
+    };
+
+    files
+        .iter()
+        .map(|file| {
+            let content = fs::read_to_string(file)?;
+            let mut feature = parse_feature(&content)
+                .with_context(|| format!("{} is not a valid feature file", file.display()))?;
+            let relative = file.strip_prefix(&root).unwrap_or(file);
+            feature.source = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            feature.category = relative
+                .parent()
+                .and_then(|dir| dir.file_name())
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| DEFAULT_FEATURE_CATEGORY.to_string());
+            Ok(feature)
+        })
+        .collect()
+}
+
+/// Index of the use case a feature was imported into before, if any
+pub fn find_feature_use_case(use_cases: &[UseCase], feature: &GherkinFeature) -> Option<usize> {
+    use_cases
+        .iter()
+        .position(|uc| {
+            uc.extra.get(GHERKIN_SOURCE_FIELD).and_then(|v| v.as_str())
+                == Some(feature.source.as_str())
+        })
+        .or_else(|| {
+            use_cases
+                .iter()
+                .position(|uc| uc.title.eq_ignore_ascii_case(&feature.title))
+        })
+}
+
+/// Convert Gherkin steps to scenario steps
+///
+/// `Then` steps are performed by the system, `Given` and `When` steps by the
+/// user; `And`, `But` and `*` keep the actor of the step before them.
+fn to_scenario_steps(steps: &[GherkinStep]) -> Vec<ScenarioStep> {
+    let mut actor = Actor::User;
+    steps
+        .iter()
+        .enumerate()
+        .map(|(index, step)| {
+            match step.keyword.as_str() {
+                "Then" => actor = Actor::System,
+                "Given" | "When" => actor = Actor::User,
+                _ => {}
+            }
+            let mut scenario_step =
+                ScenarioStep::new(index + 1, actor.clone(), step.text.clone(), String::new());
+            scenario_step.keyword = Some(step.keyword.clone());
+            scenario_step.notes = step.notes.clone();
+            scenario_step
+        })
+        .collect()
+}
+
+/// Bring a use case in line with a feature
+///
+/// Scenarios are matched by title (case-insensitive). Matched scenarios get the
+/// feature's description, type and steps; new ones are appended with fresh IDs.
+/// Scenarios missing from the feature are kept.
+pub fn apply_feature(use_case: &mut UseCase, feature: &GherkinFeature) -> FeatureSync {
+    let mut sync = FeatureSync::default();
+
+    let source = serde_json::Value::String(feature.source.clone());
+    if use_case.extra.get(GHERKIN_SOURCE_FIELD) != Some(&source) {
+        use_case
+            .extra
+            .insert(GHERKIN_SOURCE_FIELD.to_string(), source);
+        sync.changed = true;
+    }
+    if !feature.description.is_empty() && use_case.description != feature.description {
+        use_case.description = feature.description.clone();
+        sync.changed = true;
+    }
+    for condition in &feature.background {
+        if !use_case.preconditions.iter().any(|c| c.text == *condition) {
+            use_case.add_precondition(condition.clone().into());
+            sync.changed = true;
+        }
+    }
+
+    for incoming in &feature.scenarios {
+        let steps = to_scenario_steps(&incoming.steps);
+        match use_case
+            .scenarios
+            .iter_mut()
+            .find(|s| s.title.eq_ignore_ascii_case(&incoming.title))
+        {
+            Some(existing) => {
+                let mut updated = existing.steps != steps
+                    || existing.description != incoming.description
+                    || existing.scenario_type != incoming.scenario_type;
+                if updated {
+                    existing.steps = steps;
+                    existing.description = incoming.description.clone();
+                    existing.scenario_type = incoming.scenario_type;
+                }
+                for tag in &incoming.tags {
+                    updated |= existing.add_tag(tag);
+                }
+                if updated {
+                    sync.updated += 1;
+                }
+            }
+            None => {
+                let mut scenario = Scenario::new(
+                    use_case.next_scenario_id(),
+                    incoming.title.clone(),
+                    incoming.description.clone(),
+                    incoming.scenario_type,
+                );
+                scenario.steps = steps;
+                for tag in &incoming.tags {
+                    scenario.add_tag(tag);
+                }
+                use_case.add_scenario(scenario);
+                sync.added += 1;
+            }
+        }
+    }
+
+    if sync.added > 0 || sync.updated > 0 {
+        sync.changed = true;
+        use_case.metadata.touch();
+    }
+    sync
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOGIN_FEATURE: &str = r#"
+@auth
+Feature: User login
+  As a registered user
+  I want to sign in
+
+  Background:
+    Given the user has an account
+
+  Scenario: Successful login
+    When the user enters valid credentials
+    And submits the form
+    Then the dashboard is shown
+    But no warning is displayed
+
+  @exception @regression
+  Scenario Outline: Wrong password
+    When the user enters "<password>"
+      """
+      hidden in the logs
+      """
+    Then an error is shown
+
+    Examples:
+      | password |
+      | wrong    |
+"#;
+
+    #[test]
+    fn test_parse_feature() {
+        let feature = parse_feature(LOGIN_FEATURE).unwrap();
+        assert_eq!(feature.title, "User login");
+        assert_eq!(
+            feature.description,
+            "As a registered user\nI want to sign in"
+        );
+        assert_eq!(feature.background, vec!["the user has an account"]);
+        assert_eq!(feature.scenarios.len(), 2);
+
+        let main = &feature.scenarios[0];
+        assert_eq!(main.scenario_type, ScenarioType::HappyPath);
+        let keywords: Vec<&str> = main.steps.iter().map(|s| s.keyword.as_str()).collect();
+        assert_eq!(keywords, vec!["When", "And", "Then", "But"]);
+
+        let outline = &feature.scenarios[1];
+        assert_eq!(outline.title, "Wrong password");
+        assert_eq!(outline.scenario_type, ScenarioType::ExceptionFlow);
+        assert_eq!(outline.tags, vec!["regression"]);
+        assert_eq!(
+            outline.steps[0].notes.as_deref(),
+            Some("hidden in the logs")
+        );
+        assert_eq!(
+            outline.description,
+            "Examples:\n| password |\n| --- |\n| wrong    |"
+        );
+        assert_eq!(separator_row(r"| a \| b | c |"), "| --- | --- |");
+
+        assert!(parse_feature("Scenario: no feature").is_err());
+        assert!(parse_feature("Feature: x\n  Given a step").is_err());
+    }
+
+    #[test]
+    fn test_apply_feature_is_idempotent() {
+        let mut feature = parse_feature(LOGIN_FEATURE).unwrap();
+        feature.source = "auth/login.feature".to_string();
+        let mut use_case = UseCase::new(
+            "UC-AUT-001".to_string(),
+            feature.title.clone(),
+            "auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+
+        let sync = apply_feature(&mut use_case, &feature);
+        assert_eq!(sync.added, 2);
+        assert_eq!(use_case.preconditions.len(), 1);
+        let steps = &use_case.scenarios[0].steps;
+        assert_eq!(steps[1].actor, Actor::User);
+        assert_eq!(steps[3].actor, Actor::System);
+        assert_eq!(steps[3].keyword.as_deref(), Some("But"));
+
+        let sync = apply_feature(&mut use_case, &feature);
+        assert_eq!(sync, FeatureSync::default());
+        assert_eq!(
+            find_feature_use_case(std::slice::from_ref(&use_case), &feature),
+            Some(0)
+        );
+
+        feature.scenarios[0].steps.pop();
+        feature.scenarios.push(GherkinScenario {
+            title: "Remember me".to_string(),
+            description: String::new(),
+            scenario_type: ScenarioType::AlternativeFlow,
+            tags: vec!["smoke".to_string()],
+            steps: Vec::new(),
+        });
+        let sync = apply_feature(&mut use_case, &feature);
+        assert_eq!((sync.added, sync.updated), (1, 1));
+        assert_eq!(use_case.scenarios.len(), 3);
+        assert_eq!(use_case.scenarios[2].id, "UC-AUT-001-S03");
+        assert_eq!(use_case.scenarios[2].tags, vec!["smoke"]);
+        assert_eq!(use_case.scenarios[0].steps.len(), 3);
+    }
+}
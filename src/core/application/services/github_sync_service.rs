@@ -0,0 +1,462 @@
+//! GitHub synchronization: one GitHub issue per use case.
+//!
+//! Each use case is pushed to an issue of `[github] repository` titled
+//! "<ID>: <title>" whose body lists the scenarios as a task list. The issue
+//! is recorded in the use case references as a `github_issue` reference to
+//! `owner/repo#number`, described by the issue URL; an issue whose reference
+//! was lost is found again through the use case ID, which every issue carries
+//! as a label.
+//!
+//! With `--pull-status`, the state of the issue is pulled back into the
+//! scenarios: a closed issue moves use cases that are not done yet to
+//! implemented (deprecated when closed as not planned), and a reopened issue
+//! moves done use cases back to in progress. Use cases without scenarios
+//! always count as planned, so their status is left alone.
+//!
+//! Requests to the REST API go through `curl`, with the access token read
+//! from the environment variable named by `[github] token_env`.
+
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+
+use crate::config::GitHubConfig;
+use crate::core::domain::{UseCaseReference, GITHUB_ISSUE_RELATIONSHIP};
+use crate::core::infrastructure::http::{error_message, query_escape, RestClient};
+use crate::core::{MucmError, Result, Status, UseCase};
+
+/// Issue operations of the GitHub REST API used for synchronization
+pub trait GitHubApi {
+    /// The issue `number`, or None when it does not exist
+    fn issue(&self, number: u64) -> Result<Option<GitHubIssue>>;
+
+    /// The issue of the repository labelled `label`, if there is one
+    fn find_issue(&self, label: &str) -> Result<Option<GitHubIssue>>;
+
+    /// Creates an issue from `issue` (title, body, labels)
+    fn create_issue(&self, issue: &Value) -> Result<GitHubIssue>;
+
+    /// Replaces the title and body of the issue `number`
+    fn update_issue(&self, number: u64, issue: &Value) -> Result<GitHubIssue>;
+}
+
+/// State of a GitHub issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHubIssue {
+    pub number: u64,
+    /// "open" or "closed"
+    pub state: String,
+    /// Why a closed issue was closed: "completed" or "not_planned"
+    pub state_reason: Option<String>,
+    /// Web page of the issue
+    pub url: String,
+}
+
+/// Outcome of a GitHub synchronization
+#[derive(Debug, Default)]
+pub struct GitHubSyncReport {
+    /// (use case ID, issue number) of the issues created
+    pub created: Vec<(String, u64)>,
+    /// (use case ID, issue number) of the issues updated
+    pub updated: Vec<(String, u64)>,
+    /// (use case ID, old status, new status) pulled from the issue states
+    pub status_changes: Vec<(String, Status, Status)>,
+}
+
+/// Number of the issue of `repository` referenced by `use_case`
+pub fn issue_number(use_case: &UseCase, repository: &str) -> Option<u64> {
+    use_case
+        .use_case_references
+        .iter()
+        .filter(|reference| reference.is_github_issue())
+        .find_map(|reference| {
+            let (repo, number) = reference.target_id.rsplit_once('#')?;
+            if repo.eq_ignore_ascii_case(repository) {
+                number.parse().ok()
+            } else {
+                None
+            }
+        })
+}
+
+/// Push `use_cases` to GitHub issues, pulling the issue states back when `pull_status`
+///
+/// Returns the report and the indices of the use cases that changed (new
+/// issue reference or scenario statuses) and need to be saved.
+pub(crate) fn sync_github(
+    use_cases: &mut [UseCase],
+    config: &GitHubConfig,
+    pull_status: bool,
+    api: &dyn GitHubApi,
+) -> Result<(GitHubSyncReport, Vec<usize>)> {
+    let repository = config.repository.trim();
+    let mut order: Vec<usize> = (0..use_cases.len()).collect();
+    order.sort_by(|a, b| use_cases[*a].id.cmp(&use_cases[*b].id));
+
+    let mut report = GitHubSyncReport::default();
+    let mut changed = Vec::new();
+    for &index in &order {
+        let use_case = &mut use_cases[index];
+        let known = match issue_number(use_case, repository) {
+            Some(number) => api.issue(number)?,
+            None => None,
+        };
+        let known = match known {
+            Some(issue) => Some(issue),
+            None => api.find_issue(&use_case.id)?,
+        };
+
+        let issue = match known {
+            Some(issue) => {
+                let issue = api.update_issue(issue.number, &issue_content(use_case, None))?;
+                report.updated.push((use_case.id.clone(), issue.number));
+                issue
+            }
+            None => {
+                let issue = api.create_issue(&issue_content(use_case, Some(config)))?;
+                report.created.push((use_case.id.clone(), issue.number));
+                issue
+            }
+        };
+
+        let mut use_case_changed = store_issue(use_case, repository, &issue);
+        if let Some(status) = pulled_status(&issue, use_case.status()) {
+            if pull_status && !use_case.scenarios.is_empty() {
+                report
+                    .status_changes
+                    .push((use_case.id.clone(), use_case.status(), status));
+                for scenario in &mut use_case.scenarios {
+                    scenario.set_status(status);
+                }
+                use_case.metadata.touch();
+                use_case_changed = true;
+            }
+        }
+        if use_case_changed {
+            changed.push(index);
+        }
+    }
+
+    changed.sort_unstable();
+    Ok((report, changed))
+}
+
+/// Record `issue` as the GitHub issue reference of `use_case`; returns whether it changed
+fn store_issue(use_case: &mut UseCase, repository: &str, issue: &GitHubIssue) -> bool {
+    let reference = UseCaseReference::new(
+        format!("{}#{}", repository, issue.number),
+        GITHUB_ISSUE_RELATIONSHIP.to_string(),
+    )
+    .with_description(issue.url.clone());
+    if use_case.use_case_references.contains(&reference) {
+        return false;
+    }
+    let prefix = format!("{}#", repository.to_lowercase());
+    use_case.use_case_references.retain(|existing| {
+        !(existing.is_github_issue() && existing.target_id.to_lowercase().starts_with(&prefix))
+    });
+    use_case.add_reference(reference);
+    true
+}
+
+/// Status the use case takes from its issue, or None when it stays as it is
+fn pulled_status(issue: &GitHubIssue, current: Status) -> Option<Status> {
+    let done = matches!(
+        current,
+        Status::Implemented | Status::Tested | Status::Deployed
+    );
+    if issue.state != "closed" {
+        return (done || current == Status::Deprecated).then_some(Status::InProgress);
+    }
+    if issue.state_reason.as_deref() == Some("not_planned") {
+        return (current != Status::Deprecated).then_some(Status::Deprecated);
+    }
+    (!done).then_some(Status::Implemented)
+}
+
+/// Title and body of the issue of a use case; the labels only when creating
+fn issue_content(use_case: &UseCase, create: Option<&GitHubConfig>) -> Value {
+    let mut issue = json!({
+        "title": format!("{}: {}", use_case.id, use_case.title),
+        "body": issue_body(use_case),
+    });
+    if let Some(config) = create {
+        let mut labels = config.labels.clone();
+        labels.push(use_case.id.clone());
+        issue["labels"] = json!(labels);
+    }
+    issue
+}
+
+/// Issue body in GitHub Markdown, the scenarios as a task list
+fn issue_body(use_case: &UseCase) -> String {
+    let mut text = String::new();
+    if !use_case.description.trim().is_empty() {
+        let _ = writeln!(text, "{}\n", use_case.description.trim());
+    }
+    let _ = writeln!(
+        text,
+        "**Category:** {} · **Priority:** {}",
+        use_case.category, use_case.priority
+    );
+    if !use_case.scenarios.is_empty() {
+        text.push_str("\n### Scenarios\n\n");
+        for scenario in &use_case.scenarios {
+            let checked = matches!(
+                scenario.status,
+                Status::Implemented | Status::Tested | Status::Deployed
+            );
+            let _ = writeln!(
+                text,
+                "- [{}] {} ({}, {})",
+                if checked { "x" } else { " " },
+                scenario.title,
+                scenario.scenario_type,
+                scenario.status.display_name()
+            );
+        }
+    }
+    text.push_str("\n_Maintained by mucm; edits to the title and body are overwritten._");
+    text
+}
+
+/// [`GitHubApi`] over the REST API, calling `curl`
+pub struct CurlGitHubApi {
+    client: RestClient,
+    repository: String,
+}
+
+impl CurlGitHubApi {
+    /// Creates a client from the `[github]` settings
+    ///
+    /// # Errors
+    /// Returns an error when the repository is not set or the token variable is not set.
+    pub fn new(config: &GitHubConfig) -> Result<Self> {
+        let repository = config.repository.trim();
+        if repository
+            .split('/')
+            .filter(|part| !part.is_empty())
+            .count()
+            != 2
+        {
+            return Err(MucmError::Validation(
+                "Set github.repository to \"owner/repo\" in the configuration to sync with GitHub"
+                    .to_string(),
+            ));
+        }
+        Ok(Self {
+            // GitHub ignores the user name of token credentials
+            client: RestClient::from_env("GitHub", &config.api_url, "mucm", &config.token_env)?,
+            repository: repository.to_string(),
+        })
+    }
+}
+
+impl GitHubApi for CurlGitHubApi {
+    fn issue(&self, number: u64) -> Result<Option<GitHubIssue>> {
+        let path = format!("/repos/{}/issues/{}", self.repository, number);
+        let (status, response) = self.client.request("GET", &path, None)?;
+        // Deleted and transferred issues answer 404 and 410
+        if status == 404 || status == 410 {
+            return Ok(None);
+        }
+        if !(200..300).contains(&status) {
+            return Err(MucmError::Validation(format!(
+                "GitHub answered {} for issue #{}: {}",
+                status,
+                number,
+                error_message(&response)
+            )));
+        }
+        Ok(parse_issue(&response))
+    }
+
+    fn find_issue(&self, label: &str) -> Result<Option<GitHubIssue>> {
+        let path = format!(
+            "/repos/{}/issues?state=all&per_page=1&labels={}",
+            self.repository,
+            query_escape(label)
+        );
+        let response = self.client.expect("GET", &path, None)?;
+        Ok(parse_issue(&response[0]))
+    }
+
+    fn create_issue(&self, issue: &Value) -> Result<GitHubIssue> {
+        let path = format!("/repos/{}/issues", self.repository);
+        let response = self.client.expect("POST", &path, Some(issue))?;
+        parse_issue(&response).ok_or_else(|| {
+            MucmError::Validation(format!(
+                "GitHub did not return the number of '{}'",
+                issue["title"].as_str().unwrap_or_default()
+            ))
+        })
+    }
+
+    fn update_issue(&self, number: u64, issue: &Value) -> Result<GitHubIssue> {
+        let path = format!("/repos/{}/issues/{}", self.repository, number);
+        let response = self.client.expect("PATCH", &path, Some(issue))?;
+        parse_issue(&response).ok_or_else(|| {
+            MucmError::Validation(format!("GitHub did not return issue #{}", number))
+        })
+    }
+}
+
+/// Issue of a REST API response, None when it has no number
+fn parse_issue(response: &Value) -> Option<GitHubIssue> {
+    Some(GitHubIssue {
+        number: response["number"].as_u64()?,
+        state: response["state"].as_str().unwrap_or("open").to_string(),
+        state_reason: response["state_reason"].as_str().map(str::to_string),
+        url: response["html_url"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Priority, Scenario};
+    use std::cell::RefCell;
+
+    /// In-memory GitHub: issues as (content, state, state reason)
+    #[derive(Default)]
+    struct FakeGitHub {
+        issues: RefCell<Vec<(Value, String, Option<String>)>>,
+    }
+
+    impl FakeGitHub {
+        fn state(&self, number: u64) -> GitHubIssue {
+            let issues = self.issues.borrow();
+            let issue = &issues[number as usize - 1];
+            GitHubIssue {
+                number,
+                state: issue.1.clone(),
+                state_reason: issue.2.clone(),
+                url: format!("https://github.com/acme/shop/issues/{}", number),
+            }
+        }
+    }
+
+    impl GitHubApi for FakeGitHub {
+        fn issue(&self, number: u64) -> Result<Option<GitHubIssue>> {
+            let exists = (1..=self.issues.borrow().len() as u64).contains(&number);
+            Ok(exists.then(|| self.state(number)))
+        }
+
+        fn find_issue(&self, label: &str) -> Result<Option<GitHubIssue>> {
+            let position = self
+                .issues
+                .borrow()
+                .iter()
+                .position(|i| i.0["labels"].as_array().unwrap().contains(&json!(label)));
+            Ok(position.map(|position| self.state(position as u64 + 1)))
+        }
+
+        fn create_issue(&self, issue: &Value) -> Result<GitHubIssue> {
+            self.issues
+                .borrow_mut()
+                .push((issue.clone(), "open".to_string(), None));
+            Ok(self.state(self.issues.borrow().len() as u64))
+        }
+
+        fn update_issue(&self, number: u64, issue: &Value) -> Result<GitHubIssue> {
+            assert!(issue.get("labels").is_none());
+            let mut issues = self.issues.borrow_mut();
+            let stored = &mut issues[number as usize - 1].0;
+            stored["title"] = issue["title"].clone();
+            stored["body"] = issue["body"].clone();
+            drop(issues);
+            Ok(self.state(number))
+        }
+    }
+
+    fn use_case(id: &str, title: &str) -> UseCase {
+        UseCase::builder(id, title)
+            .category("Orders")
+            .priority(Priority::High)
+            .scenario(Scenario::builder("Happy path"))
+            .build()
+            .unwrap()
+    }
+
+    fn config() -> GitHubConfig {
+        GitHubConfig {
+            repository: "acme/shop".to_string(),
+            ..GitHubConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_sync_records_issue_references_then_pulls_status() {
+        let api = FakeGitHub::default();
+        let mut use_cases = vec![
+            use_case("UC-ORD-002", "Pay"),
+            use_case("UC-ORD-001", "Check out"),
+        ];
+
+        let (report, changed) = sync_github(&mut use_cases, &config(), true, &api).unwrap();
+        assert_eq!(
+            report.created,
+            [("UC-ORD-001".to_string(), 1), ("UC-ORD-002".to_string(), 2)]
+        );
+        assert!(report.status_changes.is_empty());
+        assert_eq!(changed, [0, 1]);
+        assert_eq!(issue_number(&use_cases[1], "acme/shop"), Some(1));
+        let reference = &use_cases[1].use_case_references[0];
+        assert_eq!(reference.target_id, "acme/shop#1");
+        assert!(reference.is_github_issue());
+        assert_eq!(
+            reference.description.as_deref(),
+            Some("https://github.com/acme/shop/issues/1")
+        );
+        let issues = api.issues.borrow().clone();
+        assert_eq!(issues[0].0["title"], "UC-ORD-001: Check out");
+        assert_eq!(issues[0].0["labels"], json!(["use-case", "UC-ORD-001"]));
+
+        // Issues are closed; a lost reference is found again through the label
+        {
+            let mut issues = api.issues.borrow_mut();
+            issues[0].1 = "closed".to_string();
+            issues[0].2 = Some("completed".to_string());
+            issues[1].1 = "closed".to_string();
+            issues[1].2 = Some("not_planned".to_string());
+        }
+        use_cases[0].use_case_references.clear();
+
+        let (report, changed) = sync_github(&mut use_cases, &config(), false, &api).unwrap();
+        assert!(report.created.is_empty());
+        assert_eq!(report.updated.len(), 2);
+        assert!(report.status_changes.is_empty());
+        assert_eq!(changed, [0]);
+        assert_eq!(issue_number(&use_cases[0], "acme/shop"), Some(2));
+
+        let (report, changed) = sync_github(&mut use_cases, &config(), true, &api).unwrap();
+        assert_eq!(
+            report.status_changes,
+            [
+                (
+                    "UC-ORD-001".to_string(),
+                    Status::Planned,
+                    Status::Implemented
+                ),
+                (
+                    "UC-ORD-002".to_string(),
+                    Status::Planned,
+                    Status::Deprecated
+                ),
+            ]
+        );
+        assert_eq!(changed, [0, 1]);
+
+        // A reopened issue moves done use cases back to in progress
+        api.issues.borrow_mut()[0].1 = "open".to_string();
+        use_cases[1].scenarios[0].set_status(Status::Tested);
+        let (report, _) = sync_github(&mut use_cases, &config(), true, &api).unwrap();
+        assert_eq!(
+            report.status_changes[0],
+            ("UC-ORD-001".to_string(), Status::Tested, Status::InProgress)
+        );
+        assert_eq!(use_cases[1].use_case_references.len(), 1);
+    }
+}
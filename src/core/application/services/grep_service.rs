@@ -0,0 +1,119 @@
+//! Search over use case data.
+//!
+//! Searches the data of every use case (not the generated documentation), so
+//! each match is reported once, with the use case ID and the path of the field
+//! it was found in (e.g. `scenarios[1].steps[0].action`). Used by `mucm grep`.
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::core::UseCase;
+
+/// One matching line of a data field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrepMatch {
+    pub use_case_id: String,
+    /// Field path in the data file, e.g. "scenarios[0].description"
+    pub field: String,
+    /// Line number within the field, for multi-line text
+    pub line_number: Option<usize>,
+    /// The matching line
+    pub line: String,
+    /// Byte ranges of the matches within `line`
+    pub ranges: Vec<(usize, usize)>,
+}
+
+/// Find the lines of use case data matching `pattern`, in use case order
+pub(crate) fn grep(use_cases: &[UseCase], pattern: &Regex) -> Vec<GrepMatch> {
+    let mut matches = Vec::new();
+    for use_case in use_cases {
+        // Fields are searched as stored, so the serialized form is walked
+        let Ok(data) = serde_json::to_value(use_case) else {
+            continue;
+        };
+        search_value(&use_case.id, "", &data, pattern, &mut matches);
+    }
+    matches
+}
+
+fn search_value(
+    use_case_id: &str,
+    path: &str,
+    value: &Value,
+    pattern: &Regex,
+    matches: &mut Vec<GrepMatch>,
+) {
+    match value {
+        Value::String(text) => {
+            let multi_line = text.contains('\n');
+            for (index, line) in text.lines().enumerate() {
+                let ranges: Vec<(usize, usize)> = pattern
+                    .find_iter(line)
+                    .filter(|m| !m.is_empty())
+                    .map(|m| (m.start(), m.end()))
+                    .collect();
+                if !ranges.is_empty() {
+                    matches.push(GrepMatch {
+                        use_case_id: use_case_id.to_string(),
+                        field: path.to_string(),
+                        line_number: multi_line.then_some(index + 1),
+                        line: line.to_string(),
+                        ranges,
+                    });
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let path = format!("{}[{}]", path, index);
+                search_value(use_case_id, &path, item, pattern, matches);
+            }
+        }
+        Value::Object(fields) => {
+            for (key, item) in fields {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                search_value(use_case_id, &path, item, pattern, matches);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Scenario, ScenarioType};
+
+    #[test]
+    fn test_grep_reports_field_paths_and_ranges() {
+        let mut use_case = UseCase::new(
+            "UC-PAY-001".to_string(),
+            "Checkout".to_string(),
+            "Payments".to_string(),
+            "Pay for an order\nRefunds are handled by support, see refund policy".to_string(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        use_case.scenarios.push(Scenario::new(
+            "UC-PAY-001-S01".to_string(),
+            "Request a refund".to_string(),
+            String::new(),
+            ScenarioType::AlternativeFlow,
+        ));
+
+        let pattern = Regex::new("(?i)refund").unwrap();
+        let matches = grep(&[use_case], &pattern);
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].field, "description");
+        assert_eq!(matches[0].line_number, Some(2));
+        assert_eq!(matches[0].ranges, vec![(0, 6), (36, 42)]);
+        assert_eq!(matches[1].field, "scenarios[0].title");
+        assert_eq!(matches[1].line_number, None);
+        assert_eq!(matches[1].line, "Request a refund");
+    }
+}
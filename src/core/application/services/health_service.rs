@@ -0,0 +1,201 @@
+//! Project health score.
+//!
+//! Condenses the state of the project into one number from 0 to 100 that
+//! leads can track over time. Each component is the share of use cases that
+//! pass one check: no validation issues, not stale, enough scenarios, and a
+//! test file in sync with the scenarios. The score is the average of the
+//! components weighted by `[health]` in the configuration. Used by
+//! `mucm status`.
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::config::HealthConfig;
+use crate::core::UseCase;
+
+use super::{TestReconciliation, ValidationIssue};
+
+/// One check of the health score
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthComponent {
+    pub name: &'static str,
+    pub weight: u32,
+    /// Percentage of use cases passing the check, `None` when it does not apply
+    pub score: Option<u32>,
+    pub passing: usize,
+    pub total: usize,
+    /// Use cases failing the check
+    pub failing: Vec<String>,
+}
+
+/// The health score of a project and how it was computed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    pub score: u32,
+    pub components: Vec<HealthComponent>,
+}
+
+/// Compute the health of `use_cases`
+///
+/// `drift` is the test reconciliation of every use case, or `None` when test
+/// generation is disabled, in which case the drift component does not count.
+pub(crate) fn health_report(
+    use_cases: &[UseCase],
+    issues: &[ValidationIssue],
+    drift: Option<&[TestReconciliation]>,
+    config: &HealthConfig,
+    today: NaiveDate,
+) -> HealthReport {
+    let ids = |failing: &dyn Fn(&UseCase) -> bool| -> Vec<String> {
+        use_cases
+            .iter()
+            .filter(|uc| failing(uc))
+            .map(|uc| uc.id.clone())
+            .collect()
+    };
+
+    let mut components = vec![
+        component(
+            "validation",
+            config.validation_weight,
+            use_cases.len(),
+            ids(&|uc| issues.iter().any(|issue| issue.use_case_id == uc.id)),
+        ),
+        component(
+            "staleness",
+            config.staleness_weight,
+            use_cases.len(),
+            ids(&|uc| is_stale(uc, config.stale_after_days, today)),
+        ),
+        component(
+            "coverage",
+            config.coverage_weight,
+            use_cases.len(),
+            ids(&|uc| uc.scenarios.len() < config.min_scenarios),
+        ),
+    ];
+    components.push(match drift {
+        Some(report) => component(
+            "drift",
+            config.drift_weight,
+            report.len(),
+            report
+                .iter()
+                .filter(|entry| !entry.is_in_sync())
+                .map(|entry| entry.use_case_id.clone())
+                .collect(),
+        ),
+        None => HealthComponent {
+            name: "drift",
+            weight: config.drift_weight,
+            score: None,
+            passing: 0,
+            total: 0,
+            failing: Vec::new(),
+        },
+    });
+
+    let weighted: Vec<(u32, u32)> = components
+        .iter()
+        .filter_map(|c| c.score.map(|score| (c.weight, score)))
+        .collect();
+    let total_weight: u32 = weighted.iter().map(|(weight, _)| weight).sum();
+    let score = if total_weight == 0 {
+        100
+    } else {
+        let sum: u32 = weighted.iter().map(|(weight, score)| weight * score).sum();
+        (sum as f64 / total_weight as f64).round() as u32
+    };
+
+    HealthReport { score, components }
+}
+
+fn component(
+    name: &'static str,
+    weight: u32,
+    total: usize,
+    failing: Vec<String>,
+) -> HealthComponent {
+    let passing = total - failing.len();
+    let score = if total == 0 {
+        100
+    } else {
+        (passing as f64 * 100.0 / total as f64).round() as u32
+    };
+    HealthComponent {
+        name,
+        weight,
+        score: Some(score),
+        passing,
+        total,
+        failing,
+    }
+}
+
+/// Past its review date, or not updated for `stale_after_days` (0 = never)
+fn is_stale(use_case: &UseCase, stale_after_days: u32, today: NaiveDate) -> bool {
+    let untouched = (today - use_case.metadata.updated_at.date_naive()).num_days();
+    use_case.metadata.is_review_due(today)
+        || (stale_after_days > 0 && untouched > i64::from(stale_after_days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Scenario;
+    use std::path::PathBuf;
+
+    fn use_case(id: &str, scenarios: usize) -> UseCase {
+        (0..scenarios)
+            .fold(
+                UseCase::builder(id, format!("Use case {}", id)).category("Auth"),
+                |builder, _| builder.scenario(Scenario::builder("Scenario")),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_score_weights_the_share_of_passing_use_cases() {
+        let today = chrono::Utc::now().date_naive();
+        let mut stale = use_case("UC-AUT-003", 1);
+        stale.metadata.review_by = Some(today);
+        let use_cases = vec![use_case("UC-AUT-001", 2), use_case("UC-AUT-002", 0), stale];
+        let issues = vec![ValidationIssue {
+            use_case_id: "UC-AUT-001".to_string(),
+            message: "ADR docs/adr/0001.md does not exist".to_string(),
+            hint: None,
+        }];
+        let config = HealthConfig::default();
+
+        let report = health_report(&use_cases, &issues, None, &config, today);
+        let scores: Vec<(&str, Option<u32>)> = report
+            .components
+            .iter()
+            .map(|c| (c.name, c.score))
+            .collect();
+        assert_eq!(
+            scores,
+            [
+                ("validation", Some(67)),
+                ("staleness", Some(67)),
+                ("coverage", Some(67)),
+                ("drift", None),
+            ]
+        );
+        assert_eq!(report.components[2].failing, ["UC-AUT-002"]);
+        // Without drift, only the other weights count
+        assert_eq!(report.score, 67);
+
+        let drift = vec![TestReconciliation {
+            use_case_id: "UC-AUT-001".to_string(),
+            test_file: PathBuf::from("tests/uc_aut_001.rs"),
+            file_exists: false,
+            scenarios_without_tests: vec!["UC-AUT-001-S01".to_string()],
+            tests_without_scenarios: Vec::new(),
+        }];
+        let report = health_report(&use_cases, &issues, Some(&drift), &config, today);
+        assert_eq!(report.components[3].score, Some(0));
+        assert_eq!(report.score, 54);
+    }
+}
@@ -0,0 +1,203 @@
+//! Use case numbering report: ID sequences, gaps and duplicates.
+//!
+//! Use case IDs are `<sequence>-<number>` (e.g. `UC-AUT-007` is number 7 of
+//! the `UC-AUT` sequence). Deleting use cases leaves gaps, and copying data
+//! files by hand can leave two use cases with the same ID. This service reports
+//! both and computes the renames that compact every sequence to 1..n.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::core::UseCase;
+
+/// One ID sequence, e.g. every `UC-AUT-NNN` use case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdSequence {
+    /// Everything before the number, e.g. "UC-AUT"
+    pub key: String,
+    /// Categories of the use cases in this sequence
+    pub categories: Vec<String>,
+    /// Numbers in use, ascending, with repeats for duplicates
+    pub numbers: Vec<usize>,
+    /// Unused numbers below the highest one
+    pub gaps: Vec<usize>,
+    /// IDs used by more than one use case
+    pub duplicates: Vec<String>,
+}
+
+impl IdSequence {
+    /// Whether the sequence runs 1..n without gaps or duplicates
+    pub fn is_compact(&self) -> bool {
+        self.gaps.is_empty() && self.duplicates.is_empty()
+    }
+}
+
+/// Numbering report of a whole project
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdReport {
+    /// Sequences ordered by key
+    pub sequences: Vec<IdSequence>,
+    /// Use case IDs that do not end in a number
+    pub unnumbered: Vec<String>,
+    /// Scenario IDs used by more than one scenario
+    pub duplicate_scenarios: Vec<String>,
+}
+
+impl IdReport {
+    /// Whether any use case or scenario ID is used twice
+    pub fn has_duplicates(&self) -> bool {
+        !self.duplicate_scenarios.is_empty()
+            || self.sequences.iter().any(|s| !s.duplicates.is_empty())
+    }
+}
+
+/// Split an ID into its sequence key and number
+fn split_id(id: &str) -> Option<(&str, usize)> {
+    let (key, number) = id.rsplit_once('-')?;
+    if key.is_empty() || number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((key, number.parse().ok()?))
+}
+
+/// Build the numbering report of a set of use cases
+pub fn id_report(use_cases: &[UseCase]) -> IdReport {
+    let mut report = IdReport::default();
+    let mut sequences: BTreeMap<&str, Vec<&UseCase>> = BTreeMap::new();
+    for use_case in use_cases {
+        match split_id(&use_case.id) {
+            Some((key, _)) => sequences.entry(key).or_default().push(use_case),
+            None => report.unnumbered.push(use_case.id.clone()),
+        }
+    }
+
+    for (key, members) in sequences {
+        let mut numbers: Vec<usize> = members
+            .iter()
+            .filter_map(|uc| split_id(&uc.id).map(|(_, number)| number))
+            .collect();
+        numbers.sort_unstable();
+
+        let mut categories: Vec<String> = members.iter().map(|uc| uc.category.clone()).collect();
+        categories.sort();
+        categories.dedup();
+
+        let highest = numbers.last().copied().unwrap_or(0);
+        let gaps = (1..highest)
+            .filter(|n| numbers.binary_search(n).is_err())
+            .collect();
+
+        let mut duplicates: Vec<String> = members
+            .iter()
+            .filter(|uc| members.iter().filter(|other| other.id == uc.id).count() > 1)
+            .map(|uc| uc.id.clone())
+            .collect();
+        duplicates.sort();
+        duplicates.dedup();
+
+        report.sequences.push(IdSequence {
+            key: key.to_string(),
+            categories,
+            numbers,
+            gaps,
+            duplicates,
+        });
+    }
+
+    let mut scenario_counts: HashMap<&str, usize> = HashMap::new();
+    for scenario in use_cases.iter().flat_map(|uc| &uc.scenarios) {
+        *scenario_counts.entry(scenario.id.as_str()).or_default() += 1;
+    }
+    report.duplicate_scenarios = scenario_counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(id, _)| id.to_string())
+        .collect();
+    report.duplicate_scenarios.sort();
+    report.unnumbered.sort();
+    report
+}
+
+/// Renames that compact every sequence to 1..n, keeping the current order
+///
+/// Scenario IDs follow their use case. Unchanged IDs are not included.
+/// Callers must make sure there are no duplicate IDs first.
+pub fn renumber_renames(use_cases: &[UseCase]) -> HashMap<String, String> {
+    let mut sequences: BTreeMap<&str, Vec<(usize, &UseCase)>> = BTreeMap::new();
+    for use_case in use_cases {
+        if let Some((key, number)) = split_id(&use_case.id) {
+            sequences.entry(key).or_default().push((number, use_case));
+        }
+    }
+
+    let mut renames = HashMap::new();
+    for (key, mut members) in sequences {
+        members.sort_by_key(|(number, _)| *number);
+        for (index, (number, use_case)) in members.into_iter().enumerate() {
+            if number == index + 1 {
+                continue;
+            }
+            let new_id = format!("{}-{:03}", key, index + 1);
+            let old_prefix = format!("{}-", use_case.id);
+            for scenario in &use_case.scenarios {
+                if let Some(suffix) = scenario.id.strip_prefix(&old_prefix) {
+                    renames.insert(scenario.id.clone(), format!("{}-{}", new_id, suffix));
+                }
+            }
+            renames.insert(use_case.id.clone(), new_id);
+        }
+    }
+    renames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Scenario;
+
+    fn use_case(id: &str, category: &str) -> UseCase {
+        UseCase::builder(id, "Title")
+            .category(category)
+            .scenario(Scenario::builder("Main"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_id_report_finds_gaps_and_duplicates() {
+        let use_cases = vec![
+            use_case("UC-AUT-001", "Auth"),
+            use_case("UC-AUT-004", "Auth"),
+            use_case("UC-AUT-004", "Auth"),
+            use_case("UC-PAY-001", "Payments"),
+            use_case("LEGACY", "Misc"),
+        ];
+
+        let report = id_report(&use_cases);
+        assert_eq!(report.sequences.len(), 2);
+        let auth = &report.sequences[0];
+        assert_eq!(auth.key, "UC-AUT");
+        assert_eq!(auth.numbers, vec![1, 4, 4]);
+        assert_eq!(auth.gaps, vec![2, 3]);
+        assert_eq!(auth.duplicates, vec!["UC-AUT-004"]);
+        assert!(report.sequences[1].is_compact());
+        assert_eq!(report.unnumbered, vec!["LEGACY"]);
+        assert_eq!(report.duplicate_scenarios, vec!["UC-AUT-004-S01"]);
+        assert!(report.has_duplicates());
+    }
+
+    #[test]
+    fn test_renumber_renames_compacts_sequences() {
+        let use_cases = vec![
+            use_case("UC-AUT-007", "Auth"),
+            use_case("UC-AUT-002", "Auth"),
+            use_case("UC-PAY-001", "Payments"),
+        ];
+
+        let renames = renumber_renames(&use_cases);
+        assert_eq!(renames.len(), 4);
+        assert_eq!(renames["UC-AUT-002"], "UC-AUT-001");
+        assert_eq!(renames["UC-AUT-002-S01"], "UC-AUT-001-S01");
+        assert_eq!(renames["UC-AUT-007"], "UC-AUT-002");
+        assert!(!renames.contains_key("UC-PAY-001"));
+    }
+}
@@ -0,0 +1,371 @@
+//! Import of use case data files and resolution of ID conflicts.
+//!
+//! Incoming use cases are read from MUCM TOML data files (for example another
+//! project's `use-cases-data/` directory) or from a `mucm export --format json`
+//! document. When an incoming ID is already taken, the caller picks a
+//! [`ConflictResolution`] and the helpers here apply it.
+
+use crate::core::application::generators::json_exporter::EXPORT_VERSION;
+use crate::core::MucmError;
+use crate::core::{Context, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::core::{TomlUseCaseRepository, UseCase};
+
+/// What to do when an incoming use case ID already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Keep the existing use case and drop the incoming one
+    Skip,
+    /// Replace the existing use case with the incoming one
+    Overwrite,
+    /// Import the incoming use case under a freshly generated ID
+    ReId,
+    /// Keep the existing use case and append incoming scenarios it lacks
+    MergeScenarios,
+}
+
+impl ConflictResolution {
+    /// All resolutions, in the order they are offered to the user
+    pub const ALL: [ConflictResolution; 4] = [
+        ConflictResolution::Skip,
+        ConflictResolution::Overwrite,
+        ConflictResolution::ReId,
+        ConflictResolution::MergeScenarios,
+    ];
+}
+
+impl FromStr for ConflictResolution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('_', "-").as_str() {
+            "skip" => Ok(ConflictResolution::Skip),
+            "overwrite" => Ok(ConflictResolution::Overwrite),
+            "re-id" | "reid" => Ok(ConflictResolution::ReId),
+            "merge" | "merge-scenarios" => Ok(ConflictResolution::MergeScenarios),
+            _ => Err(format!(
+                "Invalid conflict policy: {}. Valid options: ask, skip, overwrite, re-id, merge",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ConflictResolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConflictResolution::Skip => write!(f, "Skip (keep existing)"),
+            ConflictResolution::Overwrite => write!(f, "Overwrite existing"),
+            ConflictResolution::ReId => write!(f, "Import under a new ID"),
+            ConflictResolution::MergeScenarios => write!(f, "Merge scenarios into existing"),
+        }
+    }
+}
+
+/// Outcome of an import run
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// IDs imported without conflict
+    pub imported: Vec<String>,
+    /// IDs whose existing use case was replaced
+    pub overwritten: Vec<String>,
+    /// (incoming ID, new ID) pairs for re-ID'd use cases
+    pub renamed: Vec<(String, String)>,
+    /// (ID, number of scenarios added) pairs for merged use cases
+    pub merged: Vec<(String, usize)>,
+    /// IDs left untouched because of a conflict
+    pub skipped: Vec<String>,
+}
+
+/// Read use cases from a TOML data file or a directory of them.
+///
+/// Directories are searched recursively for `<id_prefix>-*.toml` files,
+/// matching the layout of a MUCM data directory.
+pub fn load_use_cases_from_path(path: &Path, id_prefix: &str) -> Result<Vec<UseCase>> {
+    if !path.exists() {
+        return Err(MucmError::NotFound(format!(
+            "Import path not found: {}",
+            path.display()
+        )));
+    }
+
+    let file_prefix = format!("{}-", id_prefix);
+    let mut files = Vec::new();
+    if path.is_file() {
+        files.push(path.to_path_buf());
+    } else {
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if entry.file_type().is_file()
+                && entry.path().extension().is_some_and(|ext| ext == "toml")
+                && entry
+                    .path()
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with(&file_prefix))
+            {
+                files.push(entry.into_path());
+            }
+        }
+    }
+    files.sort();
+
+    files
+        .iter()
+        .map(|file| {
+            let content = fs::read_to_string(file)?;
+            // Older data files are migrated the same way the repository does on load
+            let (use_case, _) = TomlUseCaseRepository::parse(&content)
+                .with_context(|| format!("{} is not a valid use case", file.display()))?;
+            Ok(use_case)
+        })
+        .collect()
+}
+
+/// Read the use cases of a JSON export document.
+///
+/// The document is checked against the domain model before anything is
+/// imported: every use case must deserialize, have an ID and a title, and IDs
+/// must be unique among the use cases and among the scenarios of each. All
+/// problems are reported at once. Personas and actors in the document are
+/// not read.
+pub fn load_use_cases_from_json(path: &Path) -> Result<Vec<UseCase>> {
+    if !path.is_file() {
+        return Err(MucmError::NotFound(format!(
+            "Import file not found: {}",
+            path.display()
+        )));
+    }
+    let content = fs::read_to_string(path)?;
+    let document: Value = serde_json::from_str(&content)
+        .with_context(|| format!("{} is not valid JSON", path.display()))?;
+
+    if let Some(version) = document.get("version") {
+        match version.as_u64() {
+            Some(version) if version <= u64::from(EXPORT_VERSION) => {}
+            _ => {
+                return Err(MucmError::Validation(format!(
+                    "{} has export version {}, but this mucm reads versions up to {}",
+                    path.display(),
+                    version,
+                    EXPORT_VERSION
+                )))
+            }
+        }
+    }
+    let Some(entries) = document.get("use_cases").and_then(Value::as_array) else {
+        return Err(MucmError::Validation(format!(
+            "{} has no \"use_cases\" list; expected a document written by mucm export --format json",
+            path.display()
+        )));
+    };
+
+    let mut problems = Vec::new();
+    let mut use_cases = Vec::new();
+    let mut ids = HashSet::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let use_case: UseCase = match serde_json::from_value(entry.clone()) {
+            Ok(use_case) => use_case,
+            Err(e) => {
+                problems.push(format!("use_cases[{}]: {}", index, e));
+                continue;
+            }
+        };
+        let at = format!("use_cases[{}] ({})", index, use_case.id);
+        if use_case.id.trim().is_empty() {
+            problems.push(format!("use_cases[{}]: empty ID", index));
+        } else if !ids.insert(use_case.id.clone()) {
+            problems.push(format!("{}: duplicate use case ID", at));
+        }
+        if use_case.title.trim().is_empty() {
+            problems.push(format!("{}: empty title", at));
+        }
+        let mut scenario_ids = HashSet::new();
+        for scenario in &use_case.scenarios {
+            if !scenario_ids.insert(scenario.id.as_str()) {
+                problems.push(format!("{}: duplicate scenario ID {}", at, scenario.id));
+            }
+        }
+        use_cases.push(use_case);
+    }
+
+    if !problems.is_empty() {
+        return Err(MucmError::Validation(format!(
+            "{} does not match the use case model:\n  - {}",
+            path.display(),
+            problems.join("\n  - ")
+        )));
+    }
+    Ok(use_cases)
+}
+
+/// Copy a use case under a new ID, renaming its scenarios to match.
+///
+/// Scenario references that pointed into the use case itself are rewritten too.
+pub fn re_id(use_case: &UseCase, new_id: &str) -> UseCase {
+    let old_prefix = format!("{}-", use_case.id);
+    let rename = |id: &str| -> String {
+        if id == use_case.id {
+            new_id.to_string()
+        } else if let Some(suffix) = id.strip_prefix(&old_prefix) {
+            format!("{}-{}", new_id, suffix)
+        } else {
+            id.to_string()
+        }
+    };
+
+    let mut renamed = use_case.clone();
+    renamed.id = new_id.to_string();
+    for scenario in &mut renamed.scenarios {
+        scenario.id = rename(&scenario.id);
+        for reference in &mut scenario.references {
+            reference.target_id = rename(&reference.target_id);
+        }
+    }
+    renamed
+}
+
+/// Append incoming scenarios whose titles the existing use case does not have.
+///
+/// Added scenarios get fresh IDs in the existing use case. Returns how many were added.
+pub fn merge_scenarios(existing: &mut UseCase, incoming: &UseCase) -> usize {
+    let mut added = 0;
+    for scenario in &incoming.scenarios {
+        let already_present = existing
+            .scenarios
+            .iter()
+            .any(|s| s.title.eq_ignore_ascii_case(&scenario.title));
+        if already_present {
+            continue;
+        }
+
+        let mut scenario = scenario.clone();
+        scenario.id = existing.next_scenario_id();
+        existing.add_scenario(scenario);
+        added += 1;
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Scenario, ScenarioType};
+
+    fn use_case_with_scenarios(id: &str, titles: &[&str]) -> UseCase {
+        let mut use_case = UseCase::new(
+            id.to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        for title in titles {
+            let scenario = Scenario::new(
+                use_case.next_scenario_id(),
+                title.to_string(),
+                String::new(),
+                ScenarioType::HappyPath,
+            );
+            use_case.add_scenario(scenario);
+        }
+        use_case
+    }
+
+    #[test]
+    fn test_conflict_resolution_from_str() {
+        assert_eq!(
+            "re-id".parse::<ConflictResolution>(),
+            Ok(ConflictResolution::ReId)
+        );
+        assert_eq!(
+            "MERGE".parse::<ConflictResolution>(),
+            Ok(ConflictResolution::MergeScenarios)
+        );
+        assert!("ask".parse::<ConflictResolution>().is_err());
+    }
+
+    #[test]
+    fn test_re_id_renames_scenarios() {
+        let use_case = use_case_with_scenarios("UC-AUT-001", &["Sign in"]);
+        let renamed = re_id(&use_case, "UC-AUT-007");
+        assert_eq!(renamed.id, "UC-AUT-007");
+        assert_eq!(renamed.scenarios[0].id, "UC-AUT-007-S01");
+    }
+
+    #[test]
+    fn test_merge_scenarios_skips_existing_titles() {
+        let mut existing = use_case_with_scenarios("UC-AUT-001", &["Sign in"]);
+        let incoming = use_case_with_scenarios("UC-AUT-001", &["sign in", "Locked out"]);
+
+        assert_eq!(merge_scenarios(&mut existing, &incoming), 1);
+        assert_eq!(existing.scenarios.len(), 2);
+        assert_eq!(existing.scenarios[1].id, "UC-AUT-001-S02");
+        assert_eq!(existing.scenarios[1].title, "Locked out");
+    }
+
+    #[test]
+    fn test_load_use_cases_from_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let category_dir = temp_dir.path().join("auth");
+        fs::create_dir_all(&category_dir).unwrap();
+        let use_case = use_case_with_scenarios("UC-AUT-001", &["Sign in"]);
+        fs::write(
+            category_dir.join("UC-AUT-001.toml"),
+            toml::to_string_pretty(&use_case).unwrap(),
+        )
+        .unwrap();
+        fs::write(category_dir.join("notes.toml"), "ignored = true").unwrap();
+        let use_case = use_case_with_scenarios("PAY-AUT-002", &["Pay"]);
+        fs::write(
+            category_dir.join("PAY-AUT-002.toml"),
+            toml::to_string_pretty(&use_case).unwrap(),
+        )
+        .unwrap();
+
+        let loaded = load_use_cases_from_path(temp_dir.path(), "UC").unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].scenarios[0].title, "Sign in");
+        let loaded = load_use_cases_from_path(temp_dir.path(), "PAY").unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].id, "PAY-AUT-002");
+    }
+
+    #[test]
+    fn test_load_use_cases_from_json_export() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("export.json");
+        let login = use_case_with_scenarios("UC-AUT-001", &["Sign in"]);
+        let document = serde_json::json!({ "version": 1, "use_cases": [login], "personas": [] });
+        fs::write(&path, document.to_string()).unwrap();
+
+        let loaded = load_use_cases_from_json(&path).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].scenarios[0].id, "UC-AUT-001-S01");
+
+        let mut untitled = use_case_with_scenarios("UC-AUT-002", &[]);
+        untitled.title = String::new();
+        let document = serde_json::json!({
+            "version": 1,
+            "use_cases": [login, login, untitled, { "id": "UC-AUT-003" }],
+        });
+        fs::write(&path, document.to_string()).unwrap();
+
+        let error = load_use_cases_from_json(&path).unwrap_err().to_string();
+        assert!(error.contains("use_cases[1] (UC-AUT-001): duplicate use case ID"));
+        assert!(error.contains("use_cases[2] (UC-AUT-002): empty title"));
+        assert!(error.contains("use_cases[3]: missing field"));
+
+        fs::write(&path, r#"{ "version": 99, "use_cases": [] }"#).unwrap();
+        assert!(load_use_cases_from_json(&path)
+            .unwrap_err()
+            .to_string()
+            .contains("export version 99"));
+    }
+}
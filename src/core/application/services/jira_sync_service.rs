@@ -0,0 +1,544 @@
+//! Jira synchronization: one Jira issue per use case.
+//!
+//! Each use case is pushed to an issue of `[jira] project_key` whose summary
+//! is "<ID>: <title>" and whose description lists the scenarios. The issue
+//! key is kept in the `jira` group of the use case's methodology fields; an
+//! issue whose key was lost is found again through the use case ID, which
+//! every issue carries as a label. References between use cases become issue
+//! links of `[jira] link_type`.
+//!
+//! The status of the issue is pulled back into the scenarios: a status named
+//! in `[jira] status_map` sets that status, any other moves use cases whose
+//! status does not match the issue's category (to do, in progress, done) to
+//! planned, in progress, or implemented. Use cases without scenarios always
+//! count as planned, so their status is left alone.
+//!
+//! Requests to the REST API go through `curl`, with the API token read from
+//! the environment variable named by `[jira] token_env`.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use crate::config::JiraConfig;
+use crate::core::infrastructure::http::{error_message, query_escape, RestClient};
+use crate::core::{MucmError, Result, Status, UseCase};
+
+/// Methodology field group holding the Jira issue of a use case
+pub const JIRA_FIELDS: &str = "jira";
+
+/// Issue operations of the Jira REST API used for synchronization
+pub trait JiraApi {
+    /// The issue `key`, or None when it no longer exists
+    fn issue(&self, key: &str) -> Result<Option<JiraIssue>>;
+
+    /// Key of the issue of the project labelled `label`, if there is one
+    fn find_issue(&self, label: &str) -> Result<Option<String>>;
+
+    /// Creates an issue with `fields` and returns its key
+    fn create_issue(&self, fields: &Value) -> Result<String>;
+
+    /// Replaces `fields` of the issue `key`
+    fn update_issue(&self, key: &str, fields: &Value) -> Result<()>;
+
+    /// Links the issue `from` to the issue `to` with the link type named `link_type`
+    fn link_issues(&self, link_type: &str, from: &str, to: &str) -> Result<()>;
+}
+
+/// State of a Jira issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraIssue {
+    pub key: String,
+    /// Status name, e.g. "In Review"
+    pub status: String,
+    /// Key of the status category: "new", "indeterminate" or "done"
+    pub status_category: String,
+    /// Keys of the issues linked to this one, in either direction
+    pub linked: Vec<String>,
+}
+
+/// Outcome of a Jira synchronization
+#[derive(Debug, Default)]
+pub struct JiraSyncReport {
+    /// (use case ID, issue key) of the issues created
+    pub created: Vec<(String, String)>,
+    /// (use case ID, issue key) of the issues updated
+    pub updated: Vec<(String, String)>,
+    /// (use case ID, old status, new status) pulled from Jira
+    pub status_changes: Vec<(String, Status, Status)>,
+    /// (from, to) issue keys linked for use case references
+    pub linked: Vec<(String, String)>,
+}
+
+/// Issue key stored in the methodology fields of `use_case`
+pub fn issue_key(use_case: &UseCase) -> Option<&str> {
+    use_case
+        .methodology_fields
+        .get(JIRA_FIELDS)?
+        .get("issue_key")?
+        .as_str()
+        .filter(|key| !key.is_empty())
+}
+
+/// Push `use_cases` to Jira and pull the issue statuses back
+///
+/// Returns the report and the indices of the use cases that changed (new
+/// issue key, issue status, or scenario statuses) and need to be saved.
+pub(crate) fn sync_jira(
+    use_cases: &mut [UseCase],
+    config: &JiraConfig,
+    api: &dyn JiraApi,
+) -> Result<(JiraSyncReport, Vec<usize>)> {
+    let mut order: Vec<usize> = (0..use_cases.len()).collect();
+    order.sort_by(|a, b| use_cases[*a].id.cmp(&use_cases[*b].id));
+
+    let mut report = JiraSyncReport::default();
+    let mut changed = Vec::new();
+    let mut issues: HashMap<String, JiraIssue> = HashMap::new();
+    for &index in &order {
+        let use_case = &mut use_cases[index];
+        let known = match issue_key(use_case) {
+            Some(key) => api.issue(key)?.map(|issue| issue.key),
+            None => None,
+        };
+        let known = match known {
+            Some(key) => Some(key),
+            None => api.find_issue(&use_case.id)?,
+        };
+
+        let key = match known {
+            Some(key) => {
+                api.update_issue(&key, &issue_fields(use_case, None))?;
+                report.updated.push((use_case.id.clone(), key.clone()));
+                key
+            }
+            None => {
+                let key = api.create_issue(&issue_fields(use_case, Some(config)))?;
+                report.created.push((use_case.id.clone(), key.clone()));
+                key
+            }
+        };
+        let issue = api.issue(&key)?.ok_or_else(|| {
+            MucmError::NotFound(format!(
+                "Jira issue {} of {} disappeared during the sync",
+                key, use_case.id
+            ))
+        })?;
+
+        let mut use_case_changed = store_issue(use_case, &issue);
+        if let Some(status) = pulled_status(&issue, use_case.status(), config)? {
+            if !use_case.scenarios.is_empty() {
+                report
+                    .status_changes
+                    .push((use_case.id.clone(), use_case.status(), status));
+                for scenario in &mut use_case.scenarios {
+                    scenario.set_status(status);
+                }
+                use_case.metadata.touch();
+                use_case_changed = true;
+            }
+        }
+        if use_case_changed {
+            changed.push(index);
+        }
+        issues.insert(use_case.id.clone(), issue);
+    }
+
+    for &index in &order {
+        let use_case = &use_cases[index];
+        for reference in &use_case.use_case_references {
+            let (Some(from), Some(to)) = (
+                issues.get(&use_case.id).map(|issue| issue.key.clone()),
+                issues
+                    .get(&reference.target_id)
+                    .map(|issue| issue.key.clone()),
+            ) else {
+                continue;
+            };
+            let already_linked = from == to
+                || issues.values().any(|issue| {
+                    (issue.key == from && issue.linked.contains(&to))
+                        || (issue.key == to && issue.linked.contains(&from))
+                });
+            if already_linked {
+                continue;
+            }
+            api.link_issues(&config.link_type, &from, &to)?;
+            if let Some(issue) = issues.get_mut(&use_case.id) {
+                issue.linked.push(to.clone());
+            }
+            report.linked.push((from, to));
+        }
+    }
+
+    changed.sort_unstable();
+    Ok((report, changed))
+}
+
+/// Keep the key and status of `issue` in the Jira fields; returns whether they changed
+fn store_issue(use_case: &mut UseCase, issue: &JiraIssue) -> bool {
+    let fields = use_case
+        .methodology_fields
+        .entry(JIRA_FIELDS.to_string())
+        .or_default();
+    let mut changed = false;
+    for (name, value) in [("issue_key", &issue.key), ("status", &issue.status)] {
+        if fields.get(name).and_then(Value::as_str) != Some(value.as_str()) {
+            fields.insert(name.to_string(), json!(value));
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Status the use case takes from its issue, or None when it stays as it is
+fn pulled_status(
+    issue: &JiraIssue,
+    current: Status,
+    config: &JiraConfig,
+) -> Result<Option<Status>> {
+    let mapped = config
+        .status_map
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(&issue.status));
+    if let Some((name, status)) = mapped {
+        let status = Status::from_str(status).map_err(|e| {
+            MucmError::Validation(format!("jira.status_map entry '{}': {}", name, e))
+        })?;
+        return Ok((status != current).then_some(status));
+    }
+
+    let category = match current {
+        Status::Planned => "new",
+        Status::InProgress => "indeterminate",
+        Status::Implemented | Status::Tested | Status::Deployed | Status::Deprecated => "done",
+    };
+    if issue.status_category == category {
+        return Ok(None);
+    }
+    Ok(match issue.status_category.as_str() {
+        "new" => Some(Status::Planned),
+        "indeterminate" => Some(Status::InProgress),
+        "done" => Some(Status::Implemented),
+        _ => None,
+    })
+}
+
+/// Issue fields of a use case; the project and issue type only when creating
+fn issue_fields(use_case: &UseCase, create: Option<&JiraConfig>) -> Value {
+    let mut fields = json!({
+        "summary": format!("{}: {}", use_case.id, use_case.title),
+        "description": issue_description(use_case),
+        "labels": ["mucm", use_case.id],
+    });
+    if let Some(config) = create {
+        fields["project"] = json!({ "key": config.project_key.trim() });
+        fields["issuetype"] = json!({ "name": config.issue_type });
+    }
+    fields
+}
+
+/// Issue description in Jira wiki markup
+fn issue_description(use_case: &UseCase) -> String {
+    let mut text = String::new();
+    if !use_case.description.trim().is_empty() {
+        let _ = writeln!(text, "{}\n", use_case.description.trim());
+    }
+    let _ = writeln!(
+        text,
+        "*Category:* {} | *Priority:* {}",
+        use_case.category, use_case.priority
+    );
+    if !use_case.scenarios.is_empty() {
+        text.push_str("\nh3. Scenarios\n");
+        for scenario in &use_case.scenarios {
+            let _ = writeln!(
+                text,
+                "# {} ({}, {})",
+                scenario.title,
+                scenario.scenario_type,
+                scenario.status.display_name()
+            );
+        }
+    }
+    text.push_str("\n_Maintained by mucm; edits to the summary and description are overwritten._");
+    text
+}
+
+/// [`JiraApi`] over the REST API, calling `curl`
+pub struct CurlJiraApi {
+    client: RestClient,
+    project_key: String,
+}
+
+impl CurlJiraApi {
+    /// Creates a client from the `[jira]` settings
+    ///
+    /// # Errors
+    /// Returns an error when a setting is missing or the token variable is not set.
+    pub fn new(config: &JiraConfig) -> Result<Self> {
+        for (key, value) in [
+            ("base_url", &config.base_url),
+            ("project_key", &config.project_key),
+            ("user", &config.user),
+        ] {
+            if value.trim().is_empty() {
+                return Err(MucmError::Validation(format!(
+                    "Set jira.{} in the configuration to sync with Jira",
+                    key
+                )));
+            }
+        }
+        Ok(Self {
+            client: RestClient::from_env(
+                "Jira",
+                &config.base_url,
+                &config.user,
+                &config.token_env,
+            )?,
+            project_key: config.project_key.trim().to_string(),
+        })
+    }
+}
+
+impl JiraApi for CurlJiraApi {
+    fn issue(&self, key: &str) -> Result<Option<JiraIssue>> {
+        let path = format!(
+            "/rest/api/2/issue/{}?fields=status,issuelinks",
+            query_escape(key)
+        );
+        let (status, response) = self.client.request("GET", &path, None)?;
+        if status == 404 {
+            return Ok(None);
+        }
+        if !(200..300).contains(&status) {
+            return Err(MucmError::Validation(format!(
+                "Jira answered {} for issue {}: {}",
+                status,
+                key,
+                error_message(&response)
+            )));
+        }
+        let fields = &response["fields"];
+        let linked = fields["issuelinks"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|link| {
+                link["outwardIssue"]["key"]
+                    .as_str()
+                    .or_else(|| link["inwardIssue"]["key"].as_str())
+            })
+            .map(str::to_string)
+            .collect();
+        Ok(Some(JiraIssue {
+            key: response["key"].as_str().unwrap_or(key).to_string(),
+            status: fields["status"]["name"].as_str().unwrap_or("").to_string(),
+            status_category: fields["status"]["statusCategory"]["key"]
+                .as_str()
+                .unwrap_or("")
+                .to_string(),
+            linked,
+        }))
+    }
+
+    fn find_issue(&self, label: &str) -> Result<Option<String>> {
+        let jql = format!(
+            "project = \"{}\" AND labels = \"{}\"",
+            self.project_key.replace('"', "\\\""),
+            label.replace('"', "\\\"")
+        );
+        let path = format!(
+            "/rest/api/2/search?jql={}&fields=key&maxResults=1",
+            query_escape(&jql)
+        );
+        let response = self.client.expect("GET", &path, None)?;
+        Ok(response["issues"][0]["key"].as_str().map(str::to_string))
+    }
+
+    fn create_issue(&self, fields: &Value) -> Result<String> {
+        let response = self.client.expect(
+            "POST",
+            "/rest/api/2/issue",
+            Some(&json!({ "fields": fields })),
+        )?;
+        response["key"].as_str().map(str::to_string).ok_or_else(|| {
+            MucmError::Validation(format!(
+                "Jira did not return the key of '{}'",
+                fields["summary"].as_str().unwrap_or_default()
+            ))
+        })
+    }
+
+    fn update_issue(&self, key: &str, fields: &Value) -> Result<()> {
+        let path = format!("/rest/api/2/issue/{}", query_escape(key));
+        self.client
+            .expect("PUT", &path, Some(&json!({ "fields": fields })))?;
+        Ok(())
+    }
+
+    fn link_issues(&self, link_type: &str, from: &str, to: &str) -> Result<()> {
+        let link = json!({
+            "type": { "name": link_type },
+            "outwardIssue": { "key": from },
+            "inwardIssue": { "key": to },
+        });
+        self.client
+            .expect("POST", "/rest/api/2/issueLink", Some(&link))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::UseCaseReference;
+    use crate::core::{Priority, Scenario};
+    use std::cell::RefCell;
+
+    /// An issue as (key, fields, status, status category, linked keys)
+    type FakeIssue = (String, Value, String, String, Vec<String>);
+
+    /// In-memory Jira
+    #[derive(Default)]
+    struct FakeJira {
+        issues: RefCell<Vec<FakeIssue>>,
+    }
+
+    impl JiraApi for FakeJira {
+        fn issue(&self, key: &str) -> Result<Option<JiraIssue>> {
+            let issues = self.issues.borrow();
+            Ok(issues.iter().find(|i| i.0 == key).map(|i| JiraIssue {
+                key: i.0.clone(),
+                status: i.2.clone(),
+                status_category: i.3.clone(),
+                linked: i.4.clone(),
+            }))
+        }
+
+        fn find_issue(&self, label: &str) -> Result<Option<String>> {
+            let issues = self.issues.borrow();
+            Ok(issues
+                .iter()
+                .find(|i| i.1["labels"].as_array().unwrap().contains(&json!(label)))
+                .map(|i| i.0.clone()))
+        }
+
+        fn create_issue(&self, fields: &Value) -> Result<String> {
+            assert_eq!(fields["project"]["key"], "SHOP");
+            let mut issues = self.issues.borrow_mut();
+            let key = format!("SHOP-{}", issues.len() + 1);
+            issues.push((
+                key.clone(),
+                fields.clone(),
+                "To Do".to_string(),
+                "new".to_string(),
+                Vec::new(),
+            ));
+            Ok(key)
+        }
+
+        fn update_issue(&self, key: &str, fields: &Value) -> Result<()> {
+            assert!(fields.get("project").is_none());
+            let mut issues = self.issues.borrow_mut();
+            let issue = issues.iter_mut().find(|i| i.0 == key).unwrap();
+            issue.1 = fields.clone();
+            Ok(())
+        }
+
+        fn link_issues(&self, _link_type: &str, from: &str, to: &str) -> Result<()> {
+            let mut issues = self.issues.borrow_mut();
+            for (key, other) in [(from, to), (to, from)] {
+                let issue = issues.iter_mut().find(|i| i.0 == key).unwrap();
+                issue.4.push(other.to_string());
+            }
+            Ok(())
+        }
+    }
+
+    fn use_case(id: &str, title: &str) -> UseCase {
+        UseCase::builder(id, title)
+            .category("Orders")
+            .priority(Priority::High)
+            .scenario(Scenario::builder("Happy path"))
+            .build()
+            .unwrap()
+    }
+
+    fn config() -> JiraConfig {
+        JiraConfig {
+            project_key: "SHOP".to_string(),
+            ..JiraConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_sync_creates_links_then_pulls_status() {
+        let api = FakeJira::default();
+        let mut checkout = use_case("UC-ORD-001", "Check out");
+        checkout.use_case_references.push(UseCaseReference::new(
+            "UC-ORD-002".to_string(),
+            "includes".to_string(),
+        ));
+        let mut use_cases = vec![use_case("UC-ORD-002", "Pay"), checkout];
+
+        let (report, changed) = sync_jira(&mut use_cases, &config(), &api).unwrap();
+        assert_eq!(
+            report.created,
+            [
+                ("UC-ORD-001".to_string(), "SHOP-1".to_string()),
+                ("UC-ORD-002".to_string(), "SHOP-2".to_string()),
+            ]
+        );
+        assert_eq!(
+            report.linked,
+            [("SHOP-1".to_string(), "SHOP-2".to_string())]
+        );
+        assert!(report.status_changes.is_empty());
+        assert_eq!(changed, [0, 1]);
+        assert_eq!(issue_key(&use_cases[1]), Some("SHOP-1"));
+        assert_eq!(api.issues.borrow()[0].1["summary"], "UC-ORD-001: Check out");
+
+        // Work moves on in Jira; a lost key is found again through the label
+        {
+            let mut issues = api.issues.borrow_mut();
+            issues[0].2 = "In Review".to_string();
+            issues[0].3 = "indeterminate".to_string();
+            issues[1].2 = "Done".to_string();
+            issues[1].3 = "done".to_string();
+        }
+        use_cases[1].methodology_fields.remove(JIRA_FIELDS);
+        let mut config = config();
+        config
+            .status_map
+            .insert("in review".to_string(), "implemented".to_string());
+
+        let (report, changed) = sync_jira(&mut use_cases, &config, &api).unwrap();
+        assert!(report.created.is_empty());
+        assert_eq!(report.updated.len(), 2);
+        assert!(report.linked.is_empty());
+        assert_eq!(
+            report.status_changes,
+            [
+                (
+                    "UC-ORD-001".to_string(),
+                    Status::Planned,
+                    Status::Implemented
+                ),
+                (
+                    "UC-ORD-002".to_string(),
+                    Status::Planned,
+                    Status::Implemented
+                ),
+            ]
+        );
+        assert_eq!(changed, [0, 1]);
+        assert_eq!(use_cases[1].scenarios[0].status, Status::Implemented);
+        assert_eq!(issue_key(&use_cases[1]), Some("SHOP-1"));
+
+        // A done issue leaves tested use cases alone
+        use_cases[0].scenarios[0].set_status(Status::Tested);
+        let (report, _) = sync_jira(&mut use_cases, &config, &api).unwrap();
+        assert!(report.status_changes.is_empty());
+    }
+}
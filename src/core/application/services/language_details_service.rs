@@ -0,0 +1,161 @@
+//! Details of the test languages shown by `mucm languages --details`.
+//!
+//! Each language lists its aliases, file extension and framework variants,
+//! the source template it is read from and, inside a project, the local copy
+//! under `template-assets/languages/<name>/` with whether any of its files
+//! differ from the source ones, and the use cases whose test file in that
+//! language exists.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::core::{LanguageRegistry, Result};
+
+/// What `mucm languages --details` shows for one language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageDetails {
+    pub name: String,
+    pub aliases: Vec<String>,
+    /// Extension of the generated test files, without the dot
+    pub file_extension: String,
+    /// Framework variants as (name, description)
+    pub variants: Vec<(String, String)>,
+    /// Test template the language is read from
+    pub template: PathBuf,
+    /// Copy of the template in the project, when there is one
+    pub local_template: Option<PathBuf>,
+    /// Whether a file of the local copy differs from the source one
+    pub customized: bool,
+    /// IDs of the use cases with a generated test file in this language
+    pub tested_use_cases: Vec<String>,
+}
+
+/// Details of the languages of `templates_dir`, ordered by name
+///
+/// `local_templates_dir` is the `template-assets` directory of the project,
+/// if any, and `tested_use_cases` lists the IDs of the use cases with a test
+/// file of the given extension.
+pub fn language_details(
+    templates_dir: &Path,
+    local_templates_dir: Option<&Path>,
+    tested_use_cases: impl Fn(&str) -> Vec<String>,
+) -> Result<Vec<LanguageDetails>> {
+    let registry = LanguageRegistry::new_dynamic(templates_dir)?;
+    let mut names = registry.available_languages();
+    names.sort();
+
+    let mut details = Vec::new();
+    for name in names {
+        let Some(language) = registry.get(&name) else {
+            continue;
+        };
+        let source_dir = templates_dir.join("languages").join(language.name());
+        let local_dir = local_templates_dir
+            .map(|dir| dir.join("languages").join(language.name()))
+            .filter(|dir| dir.is_dir());
+        let mut tested = tested_use_cases(language.file_extension());
+        tested.sort();
+        details.push(LanguageDetails {
+            name: language.name().to_string(),
+            aliases: language.aliases().iter().map(|a| a.to_string()).collect(),
+            file_extension: language.file_extension().to_string(),
+            variants: language
+                .variants()
+                .into_iter()
+                .map(|(variant, description)| (variant.to_string(), description.to_string()))
+                .collect(),
+            template: source_dir.join(language.template_file()),
+            local_template: local_dir
+                .as_ref()
+                .map(|dir| dir.join(language.template_file())),
+            customized: local_dir
+                .as_ref()
+                .is_some_and(|dir| differs(&source_dir, dir)),
+            tested_use_cases: tested,
+        });
+    }
+    Ok(details)
+}
+
+/// Whether a file of `local` is missing from `source` or has other content
+fn differs(source: &Path, local: &Path) -> bool {
+    let read = |path: &Path| fs::read_to_string(path).map(|text| text.replace("\r\n", "\n"));
+    WalkDir::new(local)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .any(|entry| {
+            let Ok(relative) = entry.path().strip_prefix(local) else {
+                return false;
+            };
+            match (read(entry.path()), read(&source.join(relative))) {
+                (Ok(local), Ok(source)) => local != source,
+                _ => true,
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_language_details_report_local_customizations() {
+        let source = TempDir::new().unwrap();
+        for (name, alias, extension) in [("rust", "rs", "rs"), ("python", "py", "py")] {
+            write(
+                &source.path().join("languages").join(name).join("info.toml"),
+                &format!(
+                    "name = \"{}\"\naliases = [\"{}\"]\nfile_extension = \"{}\"\ntemplate_file = \"test.hbs\"\n",
+                    name, alias, extension
+                ),
+            );
+            write(
+                &source.path().join("languages").join(name).join("test.hbs"),
+                "// {{title}}\n",
+            );
+        }
+        let local = TempDir::new().unwrap();
+        write(
+            &local.path().join("languages/rust/test.hbs"),
+            "// {{title}}\r\n",
+        );
+        write(
+            &local.path().join("languages/python/test.hbs"),
+            "# {{title}}\n",
+        );
+
+        let details = language_details(source.path(), Some(local.path()), |extension| {
+            if extension == "py" {
+                vec!["UC-AUT-001".to_string()]
+            } else {
+                Vec::new()
+            }
+        })
+        .unwrap();
+
+        assert_eq!(details.len(), 2);
+        let (python, rust) = (&details[0], &details[1]);
+        assert_eq!(python.name, "python");
+        assert_eq!(python.aliases, ["py"]);
+        assert!(python.customized);
+        assert_eq!(python.tested_use_cases, ["UC-AUT-001"]);
+        assert_eq!(rust.template, source.path().join("languages/rust/test.hbs"));
+        assert_eq!(
+            rust.local_template,
+            Some(local.path().join("languages/rust/test.hbs"))
+        );
+        assert!(!rust.customized);
+        assert!(rust.tested_use_cases.is_empty());
+
+        let details = language_details(source.path(), None, |_| Vec::new()).unwrap();
+        assert_eq!(details[0].local_template, None);
+    }
+}
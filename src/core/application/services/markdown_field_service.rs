@@ -0,0 +1,129 @@
+//! Markdown checks of the free-text fields of a use case.
+//!
+//! Descriptions and text fields are rendered straight into the generated
+//! documents, so an unclosed code fence or a malformed table in one field
+//! breaks everything after it. Fields are normalized when a use case is saved,
+//! and every problem is reported with the field it was found in.
+
+use serde_json::Value;
+
+use crate::core::utils::{normalize_markdown, MarkdownIssue};
+use crate::core::UseCase;
+
+/// A markdown problem in one field of a use case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownFieldWarning {
+    pub use_case_id: String,
+    /// Dotted path of the field, e.g. `methodology_fields.business.business_value`
+    pub field: String,
+    pub issue: MarkdownIssue,
+}
+
+impl std::fmt::Display for MarkdownFieldWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} (line {}): {}",
+            self.use_case_id, self.field, self.issue.line, self.issue.message
+        )
+    }
+}
+
+/// Normalize the markdown of the text fields of `use_case` in place
+///
+/// Covers the description, the string values of methodology and extra fields,
+/// and the descriptions of the scenarios.
+pub(crate) fn normalize_markdown_fields(use_case: &mut UseCase) -> Vec<MarkdownFieldWarning> {
+    let mut warnings = Vec::new();
+    let id = use_case.id.clone();
+    let mut check = |field: String, text: &mut String| {
+        let (normalized, issues) = normalize_markdown(text);
+        *text = normalized;
+        warnings.extend(issues.into_iter().map(|issue| MarkdownFieldWarning {
+            use_case_id: id.clone(),
+            field: field.clone(),
+            issue,
+        }));
+    };
+
+    check("description".to_string(), &mut use_case.description);
+
+    let mut methodologies: Vec<_> = use_case.methodology_fields.iter_mut().collect();
+    methodologies.sort_by(|a, b| a.0.cmp(b.0));
+    for (methodology, fields) in methodologies {
+        let mut fields: Vec<_> = fields.iter_mut().collect();
+        fields.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, value) in fields {
+            if let Value::String(text) = value {
+                check(format!("methodology_fields.{}.{}", methodology, name), text);
+            }
+        }
+    }
+
+    let mut extra: Vec<_> = use_case.extra.iter_mut().collect();
+    extra.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, value) in extra {
+        if let Value::String(text) = value {
+            check(name.clone(), text);
+        }
+    }
+
+    for scenario in &mut use_case.scenarios {
+        check(
+            format!("scenarios.{}.description", scenario.id),
+            &mut scenario.description,
+        );
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_normalize_markdown_fields_reports_the_field() {
+        let mut use_case = UseCase::new(
+            "UC-PAY-001".to_string(),
+            "Checkout".to_string(),
+            "Payments".to_string(),
+            "Pay with a card".to_string(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        use_case.methodology_fields.insert(
+            "business".to_string(),
+            HashMap::from([
+                (
+                    "business_value".to_string(),
+                    json!("| Metric | Target |\n| Tickets | -30% |"),
+                ),
+                ("stakeholders".to_string(), json!([])),
+            ]),
+        );
+        use_case
+            .extra
+            .insert("notes".to_string(), json!("```\nmucm list"));
+
+        let warnings = normalize_markdown_fields(&mut use_case);
+
+        let fields: Vec<&str> = warnings.iter().map(|w| w.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["methodology_fields.business.business_value", "notes"]
+        );
+        assert_eq!(
+            warnings[1].to_string(),
+            "UC-PAY-001 notes (line 1): code fence is never closed; closed it at the end"
+        );
+        assert_eq!(use_case.description, "Pay with a card");
+        assert_eq!(
+            use_case.methodology_fields["business"]["business_value"],
+            json!("| Metric | Target |\n| --- | --- |\n| Tickets | -30% |")
+        );
+        assert_eq!(use_case.extra["notes"], json!("```\nmucm list\n```"));
+    }
+}
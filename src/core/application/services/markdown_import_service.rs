@@ -0,0 +1,813 @@
+//! Best-effort import of hand-written use case markdown.
+//!
+//! Teams adopting MUCM often have use cases written by hand already. Each `#`
+//! heading starts a use case and the text below it becomes the description.
+//! Deeper sections are recognized by their heading: preconditions,
+//! postconditions (or guarantees), priority, description, and scenarios or
+//! flows, whose heading also decides the scenario type (alternative,
+//! exception, extension, otherwise main). A scenario heading with only deeper
+//! headings below it groups the scenarios under it.
+//!
+//! Numbered or bulleted items and table rows become steps and conditions; a
+//! step starting with a known actor (`System validates...`, `**Admin**
+//! approves...`) is performed by that actor, any other by the user.
+//! `Priority` and `Category` table rows or `**Priority:** High` lines set those
+//! fields. Sections that are not recognized are kept in the description, so
+//! nothing written is lost.
+//!
+//! A file without a `#` heading is one use case titled after the file. Each
+//! imported use case remembers where it came from in the `markdown_source`
+//! extra field, and importing the same source again skips it.
+
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::core::domain::ScenarioStep;
+use crate::core::{Actor, Priority, Scenario, ScenarioType, UseCase};
+use crate::core::{MucmError, Result};
+
+/// Extra field linking a use case to the markdown it was imported from
+pub const MARKDOWN_SOURCE_FIELD: &str = "markdown_source";
+
+/// Category of use cases found directly in the import root
+pub const DEFAULT_MARKDOWN_CATEGORY: &str = "Imported";
+
+/// A use case read from markdown
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkdownUseCase {
+    /// File path relative to the import root, with `#<n>` when the file holds
+    /// several use cases; used to skip re-imports
+    pub source: String,
+    /// Category from a `Category` field, else the directory holding the file
+    pub category: String,
+    pub title: String,
+    pub description: String,
+    pub priority: Option<Priority>,
+    pub preconditions: Vec<String>,
+    pub postconditions: Vec<String>,
+    pub scenarios: Vec<MarkdownScenario>,
+}
+
+/// A scenario read from a markdown section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownScenario {
+    pub title: String,
+    pub scenario_type: ScenarioType,
+    pub description: String,
+    pub steps: Vec<ScenarioStep>,
+}
+
+/// Outcome of a markdown import run
+#[derive(Debug, Default)]
+pub struct MarkdownImportReport {
+    /// (ID, source) of the use cases created
+    pub created: Vec<(String, String)>,
+    /// Sources imported before, left alone
+    pub skipped: Vec<String>,
+    /// (source, reason) of files that could not be read, left out
+    pub unreadable: Vec<(String, String)>,
+}
+
+/// Use cases read from markdown files
+#[derive(Debug, Default)]
+pub struct LoadedMarkdown {
+    pub use_cases: Vec<MarkdownUseCase>,
+    /// (source, reason) of files that could not be read
+    pub unreadable: Vec<(String, String)>,
+}
+
+/// What the lines of the current section describe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Description,
+    Preconditions,
+    Postconditions,
+    Priority,
+    Scenario,
+    /// An unrecognized section, kept in the description
+    Other,
+}
+
+/// Parser state for one markdown document
+struct Parser {
+    fallback_title: String,
+    use_cases: Vec<MarkdownUseCase>,
+    current: Option<MarkdownUseCase>,
+    section: Section,
+    /// Level and type of the scenario heading the current headings sit under
+    group: Option<(usize, ScenarioType)>,
+    table: Vec<Vec<String>>,
+    list_item: Regex,
+}
+
+/// Parse the use cases of a markdown document
+///
+/// `fallback_title` names the use case of text before the first `#` heading.
+pub fn parse_markdown(content: &str, fallback_title: &str) -> Vec<MarkdownUseCase> {
+    let mut parser = Parser {
+        fallback_title: fallback_title.to_string(),
+        use_cases: Vec::new(),
+        current: None,
+        section: Section::Description,
+        group: None,
+        table: Vec::new(),
+        list_item: Regex::new(r"^(?:[-*+]|\d+[.)])\s+(?:\[[ xX]\]\s+)?(.*)$")
+            .expect("valid list item regex"),
+    };
+    let mut fence: Option<&str> = None;
+
+    for raw in content.lines() {
+        let line = raw.trim();
+        if let Some(delimiter) = fence {
+            parser.text(raw.trim_end());
+            if line.starts_with(delimiter) {
+                fence = None;
+            }
+            continue;
+        }
+        if line.starts_with("```") || line.starts_with("~~~") {
+            parser.flush_table();
+            fence = Some(&line[..3]);
+            parser.text(raw.trim_end());
+            continue;
+        }
+        if line.starts_with('|') {
+            let cells = table_cells(line);
+            if !cells
+                .iter()
+                .all(|cell| !cell.is_empty() && cell.chars().all(|c| matches!(c, '-' | ':')))
+            {
+                parser.table.push(cells);
+            }
+            continue;
+        }
+        parser.flush_table();
+
+        let level = line.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&level) && line[level..].starts_with(' ') {
+            parser.heading(level, line[level..].trim().trim_end_matches('#').trim());
+        } else if let Some(item) = parser.list_item.captures(line).map(|c| c[1].to_string()) {
+            parser.item(&item, raw.trim_end());
+        } else {
+            parser.text(line);
+        }
+    }
+    parser.flush_table();
+    parser.finish()
+}
+
+impl Parser {
+    fn use_case(&mut self) -> &mut MarkdownUseCase {
+        let fallback_title = &self.fallback_title;
+        self.current.get_or_insert_with(|| MarkdownUseCase {
+            title: fallback_title.clone(),
+            ..Default::default()
+        })
+    }
+
+    fn heading(&mut self, level: usize, text: &str) {
+        if level == 1 {
+            if let Some(done) = self.current.take() {
+                self.use_cases.push(done);
+            }
+            self.current = Some(MarkdownUseCase {
+                title: clean_title(text),
+                ..Default::default()
+            });
+            self.section = Section::Description;
+            self.group = None;
+            return;
+        }
+
+        if self
+            .group
+            .is_some_and(|(group_level, _)| level <= group_level)
+        {
+            self.group = None;
+        }
+        let lower = text.to_lowercase();
+        let section = classify(&lower);
+        let scenario_type = match (section, self.group) {
+            (Section::Scenario, _) => Some(scenario_type(&lower).unwrap_or_default()),
+            (Section::Other, Some((_, group_type))) => {
+                Some(scenario_type(&lower).unwrap_or(group_type))
+            }
+            _ => None,
+        };
+
+        match scenario_type {
+            Some(scenario_type) => {
+                if self.group.is_none() {
+                    self.group = Some((level, scenario_type));
+                }
+                self.section = Section::Scenario;
+                self.use_case().scenarios.push(MarkdownScenario {
+                    title: clean_scenario_title(text),
+                    scenario_type,
+                    description: String::new(),
+                    steps: Vec::new(),
+                });
+            }
+            None => {
+                self.group = None;
+                self.section = section;
+                if section == Section::Other {
+                    let description = &mut self.use_case().description;
+                    push_line(description, "");
+                    push_line(description, &format!("**{}**", text));
+                }
+            }
+        }
+    }
+
+    fn item(&mut self, item: &str, raw: &str) {
+        match self.section {
+            Section::Preconditions => self.use_case().preconditions.push(item.to_string()),
+            Section::Postconditions => self.use_case().postconditions.push(item.to_string()),
+            Section::Priority => self.priority(item),
+            Section::Scenario => self.step(None, item),
+            Section::Description | Section::Other => {
+                if !self.field(item) {
+                    push_line(&mut self.use_case().description, raw);
+                }
+            }
+        }
+    }
+
+    fn text(&mut self, line: &str) {
+        let section = self.section;
+        match section {
+            Section::Preconditions if !line.is_empty() => {
+                self.use_case().preconditions.push(line.to_string())
+            }
+            Section::Postconditions if !line.is_empty() => {
+                self.use_case().postconditions.push(line.to_string())
+            }
+            Section::Priority if !line.is_empty() => self.priority(line),
+            Section::Scenario => {
+                if let Some(scenario) = self.use_case().scenarios.last_mut() {
+                    push_line(&mut scenario.description, line);
+                }
+            }
+            Section::Description | Section::Other if !self.field(line) => {
+                push_line(&mut self.use_case().description, line)
+            }
+            _ => {}
+        }
+    }
+
+    /// Take a `Priority: High` or `**Category:** Auth` line, if it is one
+    fn field(&mut self, line: &str) -> bool {
+        let Some((key, value)) = line.split_once(':') else {
+            return false;
+        };
+        let key = key.trim().trim_matches('*').trim().to_lowercase();
+        let value = value.trim().trim_matches('*').trim();
+        self.key_value(&key, value)
+    }
+
+    fn key_value(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            "priority" => {
+                self.priority(value);
+                true
+            }
+            "category" if !value.is_empty() => {
+                self.use_case().category = value.to_string();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn priority(&mut self, text: &str) {
+        let priority = text
+            .split(|c: char| !c.is_alphanumeric())
+            .find_map(|word| Priority::from_str(word).ok());
+        let use_case = self.use_case();
+        if use_case.priority.is_none() {
+            use_case.priority = priority;
+        }
+    }
+
+    /// Add a step to the current scenario, with `actor` or the one the text starts with
+    fn step(&mut self, actor: Option<Actor>, text: &str) {
+        let (actor, action) = match actor {
+            Some(actor) => (actor, text.to_string()),
+            None => split_actor(text),
+        };
+        if let Some(scenario) = self.use_case().scenarios.last_mut() {
+            let order = scenario.steps.len() + 1;
+            scenario
+                .steps
+                .push(ScenarioStep::new(order, actor, action, String::new()));
+        }
+    }
+
+    fn flush_table(&mut self) {
+        let rows = std::mem::take(&mut self.table);
+        if rows.is_empty() {
+            return;
+        }
+        match self.section {
+            Section::Scenario => {
+                let header: Vec<String> = rows[0].iter().map(|c| c.to_lowercase()).collect();
+                let column = |names: &[&str]| {
+                    header
+                        .iter()
+                        .position(|cell| names.iter().any(|name| cell.contains(name)))
+                };
+                let actor_column = column(&["actor", "who"]);
+                match column(&["action", "step", "description", "what"]) {
+                    Some(action_column) => {
+                        for row in &rows[1..] {
+                            let action = row.get(action_column).cloned().unwrap_or_default();
+                            let actor = actor_column
+                                .and_then(|index| row.get(index))
+                                .filter(|actor| !actor.is_empty())
+                                .map(|actor| Actor::from_str(actor).unwrap_or(Actor::User));
+                            if !action.is_empty() {
+                                self.step(actor, &action);
+                            }
+                        }
+                    }
+                    None => {
+                        for row in &rows[1..] {
+                            let text = row_text(row);
+                            if !text.is_empty() {
+                                self.step(None, &text);
+                            }
+                        }
+                    }
+                }
+            }
+            Section::Preconditions | Section::Postconditions => {
+                let conditions: Vec<String> = rows[1..]
+                    .iter()
+                    .map(|row| row_text(row))
+                    .filter(|text| !text.is_empty())
+                    .collect();
+                let preconditions = self.section == Section::Preconditions;
+                let use_case = self.use_case();
+                if preconditions {
+                    use_case.preconditions.extend(conditions);
+                } else {
+                    use_case.postconditions.extend(conditions);
+                }
+            }
+            Section::Priority => {
+                if let Some(value) = rows.iter().flatten().last().cloned() {
+                    self.priority(&value);
+                }
+            }
+            Section::Description | Section::Other => {
+                let mut kept: Vec<&Vec<String>> = Vec::new();
+                for row in &rows {
+                    let taken = row.len() == 2
+                        && self.key_value(&row[0].trim_matches('*').to_lowercase(), &row[1]);
+                    if !taken {
+                        kept.push(row);
+                    }
+                }
+                let description = &mut self.use_case().description;
+                for (index, row) in kept.iter().enumerate() {
+                    push_line(description, &format!("| {} |", row.join(" | ")));
+                    if index == 0 {
+                        let separator = vec!["---"; row.len()].join(" | ");
+                        push_line(description, &format!("| {} |", separator));
+                    }
+                }
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<MarkdownUseCase> {
+        if let Some(done) = self.current.take() {
+            self.use_cases.push(done);
+        }
+        for use_case in &mut self.use_cases {
+            use_case.description = collapse_blank_lines(&use_case.description);
+            if use_case.title.is_empty() {
+                use_case.title = self.fallback_title.clone();
+            }
+            // Headings that only grouped other scenarios hold nothing themselves
+            use_case
+                .scenarios
+                .retain(|s| !s.steps.is_empty() || !s.description.trim().is_empty());
+            for scenario in &mut use_case.scenarios {
+                scenario.description = collapse_blank_lines(&scenario.description);
+            }
+        }
+        self.use_cases
+    }
+}
+
+/// Section a `##` heading (lowercased) opens
+fn classify(heading: &str) -> Section {
+    let has = |words: &[&str]| words.iter().any(|word| heading.contains(word));
+    if has(&["precondition", "pre-condition", "prerequisite"]) {
+        Section::Preconditions
+    } else if has(&["postcondition", "post-condition", "guarantee"]) {
+        Section::Postconditions
+    } else if has(&[
+        "scenario",
+        "flow",
+        "path",
+        "extension",
+        "exception",
+        "alternat",
+    ]) {
+        Section::Scenario
+    } else if heading.starts_with("priority") {
+        Section::Priority
+    } else if has(&["description", "summary", "overview", "goal", "purpose"]) {
+        Section::Description
+    } else {
+        Section::Other
+    }
+}
+
+/// Scenario type named by a heading (lowercased), if any
+fn scenario_type(heading: &str) -> Option<ScenarioType> {
+    let has = |words: &[&str]| words.iter().any(|word| heading.contains(word));
+    if has(&["alternat"]) {
+        Some(ScenarioType::AlternativeFlow)
+    } else if has(&["exception", "error", "failure"]) {
+        Some(ScenarioType::ExceptionFlow)
+    } else if has(&["extension"]) {
+        Some(ScenarioType::Extension)
+    } else if has(&["main", "basic", "happy", "primary", "success"]) {
+        Some(ScenarioType::HappyPath)
+    } else {
+        None
+    }
+}
+
+/// A use case title without a leading ID or `Use Case:` label
+fn clean_title(text: &str) -> String {
+    let prefix = Regex::new(
+        r"^(?:(?i:use[ -]?case)\s*\d*\s*[:.\-–—]\s*|[A-Z]{1,5}(?:-[A-Z0-9]+)*-\d+\s*[:.\-–—]?\s*)",
+    )
+    .expect("valid title prefix regex");
+    prefix.replace(text, "").trim().to_string()
+}
+
+/// A scenario title without leading numbering such as `3a.` or `A1:`
+fn clean_scenario_title(text: &str) -> String {
+    let prefix =
+        Regex::new(r"^(?:\d+[a-z]?|[A-Z]\d+)[.):]\s+").expect("valid scenario prefix regex");
+    prefix.replace(text, "").trim().to_string()
+}
+
+/// The actor a step starts with (`System ...`, `The database ...`, `**Admin** ...`),
+/// else the user, and the rest of the text
+fn split_actor(text: &str) -> (Actor, String) {
+    if let Some(rest) = text.strip_prefix("**") {
+        if let Some((actor, action)) = rest.split_once("**") {
+            let actor = actor.trim().trim_end_matches(':');
+            let action = action.trim().trim_start_matches(':').trim();
+            if !actor.is_empty() && !action.is_empty() {
+                return (
+                    Actor::from_str(actor).unwrap_or(Actor::User),
+                    action.to_string(),
+                );
+            }
+        }
+    }
+
+    let without_article = text
+        .strip_prefix("The ")
+        .or_else(|| text.strip_prefix("the "))
+        .unwrap_or(text);
+    if let Some((word, action)) = without_article.split_once(' ') {
+        let actor = match word.trim_end_matches(':').to_lowercase().as_str() {
+            "user" => Some(Actor::User),
+            "system" => Some(Actor::System),
+            "server" => Some(Actor::Server),
+            "database" => Some(Actor::Database),
+            _ => None,
+        };
+        if let Some(actor) = actor {
+            return (actor, action.trim().to_string());
+        }
+    }
+    (Actor::User, text.to_string())
+}
+
+fn table_cells(line: &str) -> Vec<String> {
+    let line = line.trim().trim_start_matches('|').trim_end_matches('|');
+    line.split('|')
+        .map(|cell| cell.trim().to_string())
+        .collect()
+}
+
+/// Cells of a table row as one text, without a leading step number
+fn row_text(row: &[String]) -> String {
+    let cells: Vec<&str> = row
+        .iter()
+        .map(String::as_str)
+        .filter(|cell| !cell.is_empty())
+        .collect();
+    let cells = match cells.first() {
+        Some(first)
+            if first
+                .trim_end_matches('.')
+                .chars()
+                .all(|c| c.is_ascii_digit()) =>
+        {
+            &cells[1..]
+        }
+        _ => &cells[..],
+    };
+    cells.join(" ")
+}
+
+fn push_line(text: &mut String, line: &str) {
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text.push_str(line);
+}
+
+/// Text without leading, trailing, or repeated blank lines
+fn collapse_blank_lines(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank = false;
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            blank = !out.is_empty();
+            continue;
+        }
+        if blank {
+            out.push('\n');
+            blank = false;
+        }
+        push_line(&mut out, line);
+    }
+    out
+}
+
+/// Read the use cases of the markdown files at a path (a file or a directory
+/// searched recursively for `*.md` and `*.markdown`)
+///
+/// Use cases without a `Category` field take the name of the directory
+/// holding their file, or [`DEFAULT_MARKDOWN_CATEGORY`] in the import root.
+/// Files that cannot be read, such as ones that are not UTF-8 text, are
+/// reported next to the use cases instead of failing the import.
+pub fn load_markdown(path: &Path) -> Result<LoadedMarkdown> {
+    if !path.exists() {
+        return Err(MucmError::NotFound(format!(
+            "Import path not found: {}",
+            path.display()
+        )));
+    }
+    let (root, files) = if path.is_file() {
+        let root = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        (root, vec![path.to_path_buf()])
+    } else {
+        let mut files = Vec::new();
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext == "md" || ext == "markdown")
+            {
+                files.push(entry.into_path());
+            }
+        }
+        files.sort();
+        (path.to_path_buf(), files)
+    };
+
+    let mut use_cases = Vec::new();
+    let mut unreadable = Vec::new();
+    for file in &files {
+        let relative = file.strip_prefix(&root).unwrap_or(file);
+        let source = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let content = match fs::read_to_string(file) {
+            Ok(content) => content,
+            Err(e) => {
+                unreadable.push((source, e.to_string()));
+                continue;
+            }
+        };
+        let directory = relative
+            .parent()
+            .and_then(|dir| dir.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| DEFAULT_MARKDOWN_CATEGORY.to_string());
+        let fallback_title = file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().replace(['-', '_'], " "))
+            .unwrap_or_default();
+
+        let parsed = parse_markdown(&content, &fallback_title);
+        let several = parsed.len() > 1;
+        for (index, mut use_case) in parsed.into_iter().enumerate() {
+            use_case.source = if several {
+                format!("{}#{}", source, index + 1)
+            } else {
+                source.clone()
+            };
+            if use_case.category.is_empty() {
+                use_case.category = directory.clone();
+            }
+            use_cases.push(use_case);
+        }
+    }
+    Ok(LoadedMarkdown {
+        use_cases,
+        unreadable,
+    })
+}
+
+/// Whether a use case was imported from `source` before
+pub fn is_imported_from(use_case: &UseCase, source: &str) -> bool {
+    use_case
+        .extra
+        .get(MARKDOWN_SOURCE_FIELD)
+        .and_then(|value| value.as_str())
+        == Some(source)
+}
+
+/// Fill a new use case with what was read from markdown
+pub fn apply_markdown(use_case: &mut UseCase, parsed: &MarkdownUseCase) {
+    use_case.extra.insert(
+        MARKDOWN_SOURCE_FIELD.to_string(),
+        serde_json::Value::String(parsed.source.clone()),
+    );
+    use_case.description = parsed.description.clone();
+    if let Some(priority) = &parsed.priority {
+        use_case.priority = priority.clone();
+    }
+    for condition in &parsed.preconditions {
+        use_case.add_precondition(condition.clone().into());
+    }
+    for condition in &parsed.postconditions {
+        use_case.add_postcondition(condition.clone().into());
+    }
+    for incoming in &parsed.scenarios {
+        let mut scenario = Scenario::new(
+            use_case.next_scenario_id(),
+            incoming.title.clone(),
+            incoming.description.clone(),
+            incoming.scenario_type,
+        );
+        scenario.steps = incoming.steps.clone();
+        use_case.add_scenario(scenario);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECKOUT: &str = "\
+# UC-12: Checkout
+
+Customer pays for the items in the cart.
+
+| Field | Value |
+|-------|-------|
+| Priority | High |
+| Primary Actor | Customer |
+
+## Preconditions
+- The cart is not empty
+- The customer is signed in
+
+## Main Success Scenario
+1. Customer opens the cart
+2. The system shows the total
+3. **Payment Gateway** charges the card
+
+## Alternative Flows
+
+### 3a. Card declined
+| # | Actor | Action |
+|---|-------|--------|
+| 1 | System | shows the decline reason |
+| 2 | Customer | picks another card |
+
+### Session expired
+- System asks the customer to sign in again
+
+## Notes
+Totals include VAT.
+
+## Postconditions
+The order is recorded.
+";
+
+    #[test]
+    fn test_parse_markdown_reads_sections_tables_and_lists() {
+        let parsed = parse_markdown(CHECKOUT, "checkout");
+        assert_eq!(parsed.len(), 1);
+        let use_case = &parsed[0];
+
+        assert_eq!(use_case.title, "Checkout");
+        assert_eq!(use_case.priority, Some(Priority::High));
+        assert_eq!(
+            use_case.description,
+            "Customer pays for the items in the cart.\n\n\
+             | Field | Value |\n| --- | --- |\n| Primary Actor | Customer |\n\n\
+             **Notes**\nTotals include VAT."
+        );
+        assert_eq!(
+            use_case.preconditions,
+            ["The cart is not empty", "The customer is signed in"]
+        );
+        assert_eq!(use_case.postconditions, ["The order is recorded."]);
+
+        let scenarios: Vec<(&str, ScenarioType, usize)> = use_case
+            .scenarios
+            .iter()
+            .map(|s| (s.title.as_str(), s.scenario_type, s.steps.len()))
+            .collect();
+        assert_eq!(
+            scenarios,
+            [
+                ("Main Success Scenario", ScenarioType::HappyPath, 3),
+                ("Card declined", ScenarioType::AlternativeFlow, 2),
+                ("Session expired", ScenarioType::AlternativeFlow, 1),
+            ]
+        );
+
+        let main = &use_case.scenarios[0].steps;
+        assert_eq!(main[0].actor, Actor::User);
+        assert_eq!(main[0].action, "Customer opens the cart");
+        assert_eq!(main[1].actor, Actor::System);
+        assert_eq!(main[1].action, "shows the total");
+        assert_eq!(main[2].actor, Actor::custom("Payment Gateway"));
+        assert_eq!(main[2].action, "charges the card");
+        let declined = &use_case.scenarios[1].steps;
+        assert_eq!(declined[0].actor, Actor::System);
+        assert_eq!(declined[1].actor, Actor::custom("Customer"));
+        assert_eq!(declined[1].order, 2);
+    }
+
+    #[test]
+    fn test_parse_markdown_without_heading_uses_fallback_title() {
+        let parsed = parse_markdown(
+            "**Category:** Billing\n\nSend invoices monthly.",
+            "invoicing",
+        );
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].title, "invoicing");
+        assert_eq!(parsed[0].category, "Billing");
+        assert_eq!(parsed[0].description, "Send invoices monthly.");
+
+        let several = parse_markdown("# Use Case 1: Log in\n\n# Log out\n", "auth");
+        let titles: Vec<&str> = several.iter().map(|uc| uc.title.as_str()).collect();
+        assert_eq!(titles, ["Log in", "Log out"]);
+    }
+
+    #[test]
+    fn test_load_markdown_sets_sources_and_categories() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("orders")).unwrap();
+        fs::write(temp_dir.path().join("orders/checkout.md"), CHECKOUT).unwrap();
+        fs::write(temp_dir.path().join("auth.md"), "# Log in\n\n# Log out\n").unwrap();
+        fs::write(temp_dir.path().join("orders/bin.md"), [0xff, 0xfe, 0x00]).unwrap();
+
+        let LoadedMarkdown {
+            use_cases: loaded,
+            unreadable,
+        } = load_markdown(temp_dir.path()).unwrap();
+        assert_eq!(unreadable.len(), 1);
+        assert_eq!(unreadable[0].0, "orders/bin.md");
+        let found: Vec<(&str, &str)> = loaded
+            .iter()
+            .map(|uc| (uc.source.as_str(), uc.category.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("auth.md#1", "Imported"),
+                ("auth.md#2", "Imported"),
+                ("orders/checkout.md", "orders"),
+            ]
+        );
+
+        let mut use_case = UseCase::new(
+            "UC-ORD-001".to_string(),
+            loaded[2].title.clone(),
+            "orders".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        apply_markdown(&mut use_case, &loaded[2]);
+        assert!(is_imported_from(&use_case, "orders/checkout.md"));
+        assert_eq!(use_case.scenarios[1].id, "UC-ORD-001-S02");
+        assert_eq!(use_case.preconditions.len(), 2);
+    }
+}
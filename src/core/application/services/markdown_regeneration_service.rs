@@ -1,31 +1,35 @@
-use crate::core::application::generators::MarkdownGenerator;
+use crate::core::application::generators::{IdLinks, MarkdownGenerator, OutputManager};
 use crate::core::utils::suggest_alternatives;
-use crate::core::{TemplateEngine, UseCase, UseCaseRepository};
-use anyhow::Result;
+use crate::core::MucmError;
+use crate::core::Result;
+use crate::core::{MethodologyView, TemplateEngine, UseCase, UseCaseRepository};
 
 /// Service for regenerating markdown documentation
 ///
 /// This service handles regeneration of markdown files from TOML source data.
-/// It generates markdown for individual use cases.
+/// It generates markdown for individual use cases, or for a single view of one.
 pub struct MarkdownRegenerationService<'a> {
-    repository: &'a Box<dyn UseCaseRepository>,
+    repository: &'a dyn UseCaseRepository,
     use_cases: &'a [UseCase],
     markdown_generator: &'a MarkdownGenerator,
     template_engine: &'a TemplateEngine,
+    links: &'a IdLinks,
 }
 
 impl<'a> MarkdownRegenerationService<'a> {
     pub fn new(
-        repository: &'a Box<dyn UseCaseRepository>,
+        repository: &'a dyn UseCaseRepository,
         use_cases: &'a [UseCase],
         markdown_generator: &'a MarkdownGenerator,
         template_engine: &'a TemplateEngine,
+        links: &'a IdLinks,
     ) -> Self {
         Self {
             repository,
             use_cases,
             markdown_generator,
             template_engine,
+            links,
         }
     }
 
@@ -43,28 +47,34 @@ impl<'a> MarkdownRegenerationService<'a> {
                 let available_ids: Vec<String> =
                     self.use_cases.iter().map(|uc| uc.id.clone()).collect();
                 let error_msg = suggest_alternatives(use_case_id, &available_ids, "Use case");
-                return Err(anyhow::anyhow!("{}", error_msg));
+                return Err(MucmError::NotFound(error_msg));
             }
         };
 
         // Validate methodology exists
         let available_methodologies = self.template_engine.available_methodologies();
         if !available_methodologies.contains(&methodology.to_string()) {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Unknown methodology '{}'. Available: {:?}",
-                methodology,
-                available_methodologies
-            ));
+                methodology, available_methodologies
+            )));
         }
 
         // Regenerate markdown for all enabled views
-        for view in use_case.enabled_views() {
-            let markdown_content =
-                self.markdown_generator
-                    .generate(&use_case, None, Some(&view))?;
-            let filename = format!("{}-{}-{}.md", use_case.id, view.methodology, view.level);
+        for (filename, view) in OutputManager::generate_all_filenames(
+            &use_case,
+            self.markdown_generator.output_format(),
+        ) {
             self.repository
-                .save_markdown_with_filename(&use_case, &filename, &markdown_content)?;
+                .write_markdown_with_filename(&use_case, &filename, &mut |writer| {
+                    self.markdown_generator.generate_linked_to(
+                        &use_case,
+                        None,
+                        Some(&view),
+                        self.links,
+                        writer,
+                    )
+                })?;
         }
 
         Ok(())
@@ -72,28 +82,79 @@ impl<'a> MarkdownRegenerationService<'a> {
 
     /// Regenerate markdown for a single use case
     pub fn regenerate_markdown(&self, use_case_id: &str) -> Result<()> {
-        // Load use case from TOML (source of truth)
-        let use_case = match self.repository.load_by_id(use_case_id)? {
-            Some(uc) => uc,
+        let use_case = self.load_use_case(use_case_id)?;
+
+        // Generate markdown for each enabled view
+        for (filename, view) in OutputManager::generate_all_filenames(
+            &use_case,
+            self.markdown_generator.output_format(),
+        ) {
+            self.repository
+                .write_markdown_with_filename(&use_case, &filename, &mut |writer| {
+                    self.markdown_generator.generate_linked_to(
+                        &use_case,
+                        None,
+                        Some(&view),
+                        self.links,
+                        writer,
+                    )
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Regenerate the file of one view of a use case, returning its filename
+    ///
+    /// Only `<ID>-<methodology>-<level>` is written: the other views, the
+    /// canonical file of a primary view and the overview are left as they are.
+    pub fn regenerate_view(&self, use_case_id: &str, view: &MethodologyView) -> Result<String> {
+        let use_case = self.load_use_case(use_case_id)?;
+        let format = self.markdown_generator.output_format();
+        let filename = format!("{}-{}.{}", use_case.id, view.key(), format.extension());
+
+        let Some((_, view)) = OutputManager::generate_all_filenames(&use_case, format)
+            .into_iter()
+            .find(|(name, _)| *name == filename)
+        else {
+            let enabled: Vec<String> = use_case
+                .enabled_views()
+                .map(|view| format!("{}:{}", view.methodology, view.level))
+                .collect();
+            return Err(MucmError::NotFound(format!(
+                "View '{}:{}' is not enabled for {}. Enabled views: {}",
+                view.methodology,
+                view.level,
+                use_case.id,
+                enabled.join(", ")
+            )));
+        };
+
+        self.repository
+            .write_markdown_with_filename(&use_case, &filename, &mut |writer| {
+                self.markdown_generator.generate_linked_to(
+                    &use_case,
+                    None,
+                    Some(&view),
+                    self.links,
+                    writer,
+                )
+            })?;
+
+        Ok(filename)
+    }
+
+    /// Load a use case from TOML (source of truth)
+    fn load_use_case(&self, use_case_id: &str) -> Result<UseCase> {
+        match self.repository.load_by_id(use_case_id)? {
+            Some(uc) => Ok(uc),
             None => {
                 // Get available use case IDs for suggestions
                 let available_ids: Vec<String> =
                     self.use_cases.iter().map(|uc| uc.id.clone()).collect();
                 let error_msg = suggest_alternatives(use_case_id, &available_ids, "Use case");
-                return Err(anyhow::anyhow!("{}", error_msg));
+                Err(MucmError::NotFound(error_msg))
             }
-        };
-
-        // Generate markdown for each enabled view
-        for view in use_case.enabled_views() {
-            let markdown_content =
-                self.markdown_generator
-                    .generate(&use_case, None, Some(&view))?;
-            let filename = format!("{}-{}-{}.md", use_case.id, view.methodology, view.level);
-            self.repository
-                .save_markdown_with_filename(&use_case, &filename, &markdown_content)?;
         }
-
-        Ok(())
     }
 }
@@ -1,18 +1,21 @@
+use super::JIRA_FIELDS;
+use crate::core::MucmError;
+use crate::core::Result;
 use crate::core::{UseCase, UseCaseRepository};
-use anyhow::Result;
 use std::collections::HashSet;
 
+/// Result of a cleanup: (cleaned_count, total_checked, details), where details
+/// lists the removed methodologies of each cleaned use case
+pub type CleanupSummary = (usize, usize, Vec<(String, Vec<String>)>);
+
 /// Service for cleaning up orphaned methodology fields
 pub struct MethodologyFieldCleanupService<'a> {
-    repository: &'a Box<dyn UseCaseRepository>,
+    repository: &'a dyn UseCaseRepository,
     use_cases: &'a mut Vec<UseCase>,
 }
 
 impl<'a> MethodologyFieldCleanupService<'a> {
-    pub fn new(
-        repository: &'a Box<dyn UseCaseRepository>,
-        use_cases: &'a mut Vec<UseCase>,
-    ) -> Self {
+    pub fn new(repository: &'a dyn UseCaseRepository, use_cases: &'a mut Vec<UseCase>) -> Self {
         Self {
             repository,
             use_cases,
@@ -22,19 +25,20 @@ impl<'a> MethodologyFieldCleanupService<'a> {
     /// Clean up orphaned methodology fields from use cases
     ///
     /// Scans methodology_fields HashMap in each use case and removes entries for
-    /// methodologies that are not currently used by any enabled view.
+    /// methodologies that are not currently used by any enabled view. The
+    /// `jira` group holds the linked issue, not methodology fields, and is kept.
     pub fn cleanup_methodology_fields(
         &mut self,
         use_case_id: Option<String>,
         dry_run: bool,
-    ) -> Result<(usize, usize, Vec<(String, Vec<String>)>)> {
+    ) -> Result<CleanupSummary> {
         let mut cleaned_count = 0;
         let mut total_checked = 0;
         let mut details = Vec::new();
 
         let use_case_ids: Vec<String> = if let Some(id) = use_case_id {
             if !self.use_cases.iter().any(|uc| uc.id == id) {
-                anyhow::bail!("Use case '{}' not found", id);
+                return Err(MucmError::NotFound(format!("Use case '{}' not found", id)));
             }
             vec![id]
         } else {
@@ -54,7 +58,7 @@ impl<'a> MethodologyFieldCleanupService<'a> {
             let orphaned: Vec<String> = use_case
                 .methodology_fields
                 .keys()
-                .filter(|m| !active_methodologies.contains(*m))
+                .filter(|m| !active_methodologies.contains(*m) && *m != JIRA_FIELDS)
                 .cloned()
                 .collect();
 
@@ -79,6 +83,6 @@ impl<'a> MethodologyFieldCleanupService<'a> {
         self.use_cases
             .iter()
             .position(|uc| uc.id == use_case_id)
-            .ok_or_else(|| anyhow::anyhow!("Use case '{}' not found", use_case_id))
+            .ok_or_else(|| MucmError::NotFound(format!("Use case '{}' not found", use_case_id)))
     }
 }
@@ -1,13 +1,100 @@
+mod adr_service;
+mod aggregate_report_service;
+mod batch_service;
+mod bench_service;
+mod checklist_service;
+mod demo_service;
+mod directory_migration_service;
+mod estimation_service;
+mod explain_service;
+mod gherkin_import_service;
+mod github_sync_service;
+mod grep_service;
+mod health_service;
+mod id_report_service;
+mod import_service;
+mod jira_sync_service;
+mod language_details_service;
+mod markdown_field_service;
+mod markdown_import_service;
 mod markdown_regeneration_service;
 mod methodology_field_cleanup_service;
+mod persona_service;
+mod plan_service;
 mod precondition_postcondition_service;
 mod reference_management_service;
+mod release_notes_service;
+mod restructure_service;
+mod rtm_import_service;
+mod sandbox_service;
 mod scenario_management_service;
+mod template_customization_service;
+mod test_reconciliation_service;
 mod use_case_query_service;
+mod validation_service;
 
+pub(crate) use adr_service::normalize_adr_path;
+pub use aggregate_report_service::{AggregateReport, ProjectSnapshot};
+pub(crate) use batch_service::BatchExecutor;
+pub use batch_service::{BatchFailure, BatchOperation, BatchProgress, BatchReport};
+pub use bench_service::{synthetic_use_cases, BenchReport};
+pub(crate) use checklist_service::{checklist_filename, render_checklist, resolve_scenario_id};
+pub use demo_service::DemoProject;
+pub(crate) use directory_migration_service::move_directory;
+pub(crate) use estimation_service::estimate_use_case;
+pub use estimation_service::{EstimateReport, EstimateRequest, EstimateTotal};
+pub(crate) use explain_service::ExplainService;
+pub use explain_service::{Explanation, OverviewExplanation, UseCaseExplanation, ViewProvenance};
+pub use gherkin_import_service::GherkinImportReport;
+pub(crate) use gherkin_import_service::{apply_feature, find_feature_use_case, load_features};
+pub use github_sync_service::GitHubSyncReport;
+pub(crate) use github_sync_service::{sync_github, CurlGitHubApi};
+pub(crate) use grep_service::grep;
+pub use grep_service::GrepMatch;
+pub(crate) use health_service::health_report;
+pub use health_service::{HealthComponent, HealthReport};
+pub(crate) use id_report_service::{id_report, renumber_renames};
+pub use id_report_service::{IdReport, IdSequence};
+pub(crate) use import_service::{
+    load_use_cases_from_json, load_use_cases_from_path, merge_scenarios, re_id,
+};
+pub use import_service::{ConflictResolution, ImportReport};
+pub use jira_sync_service::JiraSyncReport;
+pub(crate) use jira_sync_service::{sync_jira, CurlJiraApi, JIRA_FIELDS};
+pub use language_details_service::{language_details, LanguageDetails};
+pub(crate) use markdown_field_service::normalize_markdown_fields;
+pub use markdown_import_service::MarkdownImportReport;
+pub(crate) use markdown_import_service::{apply_markdown, is_imported_from, load_markdown};
 pub(crate) use markdown_regeneration_service::MarkdownRegenerationService;
-pub(crate) use methodology_field_cleanup_service::MethodologyFieldCleanupService;
+pub(crate) use methodology_field_cleanup_service::{
+    CleanupSummary, MethodologyFieldCleanupService,
+};
+pub use persona_service::PersonaService;
+pub(crate) use plan_service::PlanService;
+pub use plan_service::{ExecutionPlan, PlanAction, PlannedMutation, PlannedOperation};
 pub(crate) use precondition_postcondition_service::PreconditionPostconditionService;
 pub(crate) use reference_management_service::ReferenceManagementService;
+pub(crate) use release_notes_service::{
+    is_materially_changed, load_use_cases_at_revision, release_notes,
+};
+pub use release_notes_service::{ReleaseNote, ReleaseNoteKind, ReleaseNotes};
+pub(crate) use restructure_service::{
+    clone_use_case, merge_use_cases, reprefix_renames, rewrite_id_mentions, rewrite_references,
+    split_scenarios, CloneOptions,
+};
+pub use restructure_service::{MergeChoice, MergeConflict};
+pub use rtm_import_service::RtmImportReport;
+pub(crate) use rtm_import_service::{
+    apply_rtm_row, is_imported_from_rtm, link_rtm_references, load_rtm, RtmMapping,
+};
+pub use sandbox_service::{Sandbox, SandboxChange, SandboxChangeKind};
 pub(crate) use scenario_management_service::ScenarioManagementService;
-pub(crate) use use_case_query_service::UseCaseQueryService;
+pub(crate) use template_customization_service::{sample_use_case, template_variables};
+pub use template_customization_service::{TemplateDraft, TemplatePreview};
+pub(crate) use test_reconciliation_service::{
+    scenario_title_from_test_name, TestReconciliationService,
+};
+pub use test_reconciliation_service::{GeneratedTestFile, TestFileAction, TestReconciliation};
+pub(crate) use use_case_query_service::{find_saved_filter, UseCaseQueryService};
+pub(crate) use validation_service::validate;
+pub use validation_service::ValidationIssue;
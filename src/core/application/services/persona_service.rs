@@ -0,0 +1,236 @@
+//! Persona custom fields checked against the `[actor.persona_fields]` schema.
+//!
+//! Values arrive as text (from the command line or prompts) and are stored
+//! typed: numbers and booleans as JSON numbers and booleans, arrays as lists.
+//! Array input is one item per line, or comma-separated on a single line.
+
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::config::{PersonaFieldConfig, PersonaFieldType};
+use crate::core::utils::find_closest_matches;
+use crate::core::{MucmError, Result};
+
+/// Parses and checks persona field values against the configured schema
+pub struct PersonaService<'a> {
+    fields: &'a HashMap<String, PersonaFieldConfig>,
+}
+
+impl<'a> PersonaService<'a> {
+    pub fn new(fields: &'a HashMap<String, PersonaFieldConfig>) -> Self {
+        Self { fields }
+    }
+
+    /// Custom fields of a new persona: `values` parsed by type, defaults or
+    /// empty values for the other fields
+    ///
+    /// # Errors
+    /// Returns an error for an unknown field, an invalid value, or a required
+    /// field without a value or default
+    pub fn initial_fields(
+        &self,
+        values: &HashMap<String, String>,
+    ) -> Result<HashMap<String, Value>> {
+        let mut fields = HashMap::new();
+        for (name, input) in values {
+            fields.insert(name.clone(), self.parse_value(name, input)?);
+        }
+        for (name, config) in self.fields {
+            if fields.contains_key(name) {
+                continue;
+            }
+            let value = match &config.default {
+                Some(default) => self.parse_value(name, default)?,
+                None => empty_value(config.field_type),
+            };
+            fields.insert(name.clone(), value);
+        }
+        self.check_required(&fields)?;
+        Ok(fields)
+    }
+
+    /// Typed value of `field` from text input
+    ///
+    /// # Errors
+    /// Returns an error for an unknown field, a value that is not of the
+    /// field's type, or a value outside the field's options
+    pub fn parse_value(&self, field: &str, input: &str) -> Result<Value> {
+        let config = self.fields.get(field).ok_or_else(|| {
+            let names: Vec<String> = self.fields.keys().cloned().collect();
+            let hint = match find_closest_matches(field, &names, 3).first() {
+                Some(suggestion) => format!(" (did you mean '{}'?)", suggestion),
+                None if names.is_empty() => " (no persona fields are configured)".to_string(),
+                None => String::new(),
+            };
+            MucmError::NotFound(format!("Unknown persona field '{}'{}", field, hint))
+        })?;
+        let input = input.trim();
+        let invalid = |expected: &str| {
+            MucmError::Validation(format!(
+                "Persona field '{}' expects {}, got '{}'",
+                field, expected, input
+            ))
+        };
+
+        let value = match config.field_type {
+            PersonaFieldType::String | PersonaFieldType::Text => json!(input),
+            PersonaFieldType::Number if input.is_empty() => empty_value(config.field_type),
+            PersonaFieldType::Number => {
+                json!(input.parse::<f64>().map_err(|_| invalid("a number"))?)
+            }
+            PersonaFieldType::Boolean => match input.to_lowercase().as_str() {
+                "" | "false" | "no" => json!(false),
+                "true" | "yes" => json!(true),
+                _ => return Err(invalid("true or false")),
+            },
+            PersonaFieldType::Array => {
+                let separator = if input.contains('\n') { '\n' } else { ',' };
+                let items: Vec<&str> = input
+                    .split(separator)
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .collect();
+                json!(items)
+            }
+        };
+
+        if !config.options.is_empty() {
+            let chosen: Vec<&str> = match &value {
+                Value::Array(items) => items.iter().filter_map(Value::as_str).collect(),
+                Value::String(text) if !text.is_empty() => vec![text.as_str()],
+                _ => Vec::new(),
+            };
+            if let Some(other) = chosen
+                .iter()
+                .find(|item| !config.options.iter().any(|option| option == *item))
+            {
+                return Err(MucmError::Validation(format!(
+                    "Persona field '{}' must be one of {}, got '{}'",
+                    field,
+                    config.options.join(", "),
+                    other
+                )));
+            }
+        }
+        Ok(value)
+    }
+
+    /// Check that every required field of `fields` has a non-empty value
+    ///
+    /// # Errors
+    /// Returns an error naming the required fields without a value
+    pub fn check_required(&self, fields: &HashMap<String, Value>) -> Result<()> {
+        let missing: Vec<&str> = self
+            .fields()
+            .into_iter()
+            .filter(|(name, config)| config.required && fields.get(*name).is_none_or(is_empty))
+            .map(|(name, _)| name)
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(MucmError::Validation(format!(
+                "Persona field(s) {} are required",
+                missing.join(", ")
+            )))
+        }
+    }
+
+    /// The schema, ordered by field name
+    pub fn fields(&self) -> BTreeMap<&str, &PersonaFieldConfig> {
+        self.fields
+            .iter()
+            .map(|(name, config)| (name.as_str(), config))
+            .collect()
+    }
+}
+
+/// Value of a field nobody filled in
+fn empty_value(field_type: PersonaFieldType) -> Value {
+    match field_type {
+        PersonaFieldType::String | PersonaFieldType::Text => json!(""),
+        PersonaFieldType::Number => json!(0),
+        PersonaFieldType::Boolean => json!(false),
+        PersonaFieldType::Array => json!([]),
+    }
+}
+
+/// Whether a required field counts as missing
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> HashMap<String, PersonaFieldConfig> {
+        let field = |field_type, required, options: &[&str]| PersonaFieldConfig {
+            label: None,
+            field_type,
+            required,
+            default: None,
+            description: None,
+            example: None,
+            options: options.iter().map(|o| o.to_string()).collect(),
+        };
+        HashMap::from([
+            (
+                "seniority".to_string(),
+                field(PersonaFieldType::String, true, &["junior", "senior"]),
+            ),
+            (
+                "devices".to_string(),
+                field(PersonaFieldType::Array, false, &["desktop", "phone"]),
+            ),
+            (
+                "age".to_string(),
+                field(PersonaFieldType::Number, false, &[]),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_parse_value_by_type() {
+        let fields = schema();
+        let service = PersonaService::new(&fields);
+
+        assert_eq!(service.parse_value("age", "42").unwrap(), json!(42.0));
+        assert_eq!(
+            service.parse_value("devices", "desktop, phone").unwrap(),
+            json!(["desktop", "phone"])
+        );
+        assert!(matches!(
+            service.parse_value("age", "old"),
+            Err(MucmError::Validation(_))
+        ));
+        assert!(matches!(
+            service.parse_value("devices", "desktop\ntv"),
+            Err(MucmError::Validation(_))
+        ));
+        assert!(matches!(
+            service.parse_value("agee", "1"),
+            Err(MucmError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_initial_fields_require_required_fields() {
+        let fields = schema();
+        let service = PersonaService::new(&fields);
+
+        let error = service.initial_fields(&HashMap::new()).unwrap_err();
+        assert!(error.to_string().contains("seniority"));
+
+        let values = HashMap::from([("seniority".to_string(), "senior".to_string())]);
+        let initial = service.initial_fields(&values).unwrap();
+        assert_eq!(initial["seniority"], json!("senior"));
+        assert_eq!(initial["devices"], json!([]));
+        assert_eq!(initial["age"], json!(0));
+    }
+}
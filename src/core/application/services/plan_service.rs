@@ -0,0 +1,335 @@
+//! Planned effects of mutating commands (`--explain`)
+//!
+//! A plan lists, in execution order, the files a command reads, writes or
+//! deletes and the services it invokes, without running the command.
+
+use crate::core::MucmError;
+use crate::core::Result;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::core::application::generators::overview_generator::{
+    should_shard, CATEGORY_OVERVIEW_FILE,
+};
+use crate::core::application::generators::{OutputManager, TestGenerator};
+use crate::core::utils::suggest_alternatives;
+use crate::core::{to_snake_case, MethodologyView, UseCase};
+
+use super::explain_service::data_file_path;
+
+/// What a planned operation does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanAction {
+    Read,
+    Write,
+    Delete,
+    Invoke,
+}
+
+/// One step of a plan: a file read, written or deleted, or a service invoked
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedOperation {
+    pub action: PlanAction,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// The ordered operations of one command
+#[derive(Debug, Clone, Serialize)]
+pub struct ExecutionPlan {
+    pub command: String,
+    pub operations: Vec<PlannedOperation>,
+}
+
+/// A mutation whose effects can be planned
+#[derive(Debug, Clone)]
+pub enum PlannedMutation {
+    /// A new use case with the given ID, category and views
+    Create {
+        use_case_id: String,
+        category: String,
+        views: Vec<MethodologyView>,
+    },
+    /// An edit of an existing use case (fields, scenarios, references, views)
+    Update { use_case_id: String },
+    /// Regeneration of one use case's markdown, or of all of them
+    Regenerate { use_case_id: Option<String> },
+}
+
+/// Builds execution plans from the project's current state
+pub struct PlanService<'a> {
+    use_cases: &'a [UseCase],
+    config: &'a Config,
+    test_generator: &'a TestGenerator,
+    operations: Vec<PlannedOperation>,
+}
+
+impl<'a> PlanService<'a> {
+    pub fn new(
+        use_cases: &'a [UseCase],
+        config: &'a Config,
+        test_generator: &'a TestGenerator,
+    ) -> Self {
+        Self {
+            use_cases,
+            config,
+            test_generator,
+            operations: Vec::new(),
+        }
+    }
+
+    /// Plan the operations of `mutation`
+    pub fn plan(mut self, command: &str, mutation: &PlannedMutation) -> Result<ExecutionPlan> {
+        match mutation {
+            PlannedMutation::Create {
+                use_case_id,
+                category,
+                views,
+            } => {
+                self.push(
+                    PlanAction::Read,
+                    &self.config.directories.use_case_dir,
+                    Some(format!("assign the next ID in category '{}'", category)),
+                );
+                let mut draft = UseCase::new(
+                    use_case_id.clone(),
+                    String::new(),
+                    category.clone(),
+                    String::new(),
+                    "medium".to_string(),
+                )
+                .map_err(MucmError::Validation)?;
+                for view in views {
+                    draft.add_view(view.clone());
+                }
+                self.save(&draft, false)?;
+                self.overview(self.use_cases.len() + 1);
+            }
+            PlannedMutation::Update { use_case_id } => {
+                let use_case = self.find(use_case_id)?;
+                self.save(use_case, true)?;
+                self.overview(self.use_cases.len());
+            }
+            PlannedMutation::Regenerate { use_case_id } => {
+                let use_cases: Vec<&UseCase> = match use_case_id {
+                    Some(id) => vec![self.find(id)?],
+                    None => self.use_cases.iter().collect(),
+                };
+                for use_case in use_cases {
+                    self.push(
+                        PlanAction::Read,
+                        data_file_path(self.config, use_case),
+                        None,
+                    );
+                    self.render(use_case);
+                }
+                if use_case_id.is_none() {
+                    self.overview(self.use_cases.len());
+                }
+            }
+        }
+
+        Ok(ExecutionPlan {
+            command: command.to_string(),
+            operations: self.operations,
+        })
+    }
+
+    fn find(&self, use_case_id: &str) -> Result<&'a UseCase> {
+        self.use_cases
+            .iter()
+            .find(|uc| uc.id == use_case_id)
+            .ok_or_else(|| {
+                let ids: Vec<String> = self.use_cases.iter().map(|uc| uc.id.clone()).collect();
+                MucmError::NotFound(suggest_alternatives(use_case_id, &ids, "Use case"))
+            })
+    }
+
+    /// Persist a use case, then render its views and test file
+    fn save(&mut self, use_case: &UseCase, existing: bool) -> Result<()> {
+        let data_file = data_file_path(self.config, use_case);
+        if existing {
+            self.push(PlanAction::Read, &data_file, None);
+            if self.config.backup.enabled {
+                self.push(
+                    PlanAction::Invoke,
+                    "BackupStore",
+                    Some(format!(
+                        "copy {} into {}",
+                        data_file.display(),
+                        self.config.backup.directory
+                    )),
+                );
+            }
+        }
+        self.push(PlanAction::Write, &data_file, None);
+        self.render(use_case);
+        if self.config.generation.auto_generate_tests
+            && self.config.generation.test_language != "none"
+        {
+            let test_file = self.test_generator.get_file_path(use_case)?;
+            self.push(
+                PlanAction::Invoke,
+                "TestGenerator",
+                Some(self.config.generation.test_language.clone()),
+            );
+            self.push(PlanAction::Write, test_file, None);
+        }
+        Ok(())
+    }
+
+    /// Render every view of a use case into its markdown file
+    fn render(&mut self, use_case: &UseCase) {
+        let output_dir = Path::new(&self.config.directories.use_case_dir)
+            .join(to_snake_case(&use_case.category));
+        for (filename, view) in
+            OutputManager::generate_all_filenames(use_case, self.config.generation.output_format)
+        {
+            self.push(
+                PlanAction::Invoke,
+                "MarkdownGenerator",
+                Some(format!("{} view of {}", view.key(), use_case.id)),
+            );
+            self.push(PlanAction::Write, output_dir.join(filename), None);
+        }
+    }
+
+    /// Regenerate the overview (sharded past the threshold, with category
+    /// overviews when enabled) and the manifest
+    fn overview(&mut self, use_case_count: usize) {
+        let use_case_dir = Path::new(&self.config.directories.use_case_dir);
+        self.push(PlanAction::Invoke, "OverviewGenerator", None);
+        self.push(PlanAction::Write, use_case_dir.join("README.md"), None);
+        let mut pages = Vec::new();
+        if should_shard(
+            use_case_count,
+            self.config.generation.overview_shard_threshold,
+        ) {
+            pages.push("README.md");
+        }
+        if self.config.generation.category_overviews {
+            pages.push(CATEGORY_OVERVIEW_FILE);
+        }
+        if !pages.is_empty() {
+            let mut categories: Vec<&str> = self
+                .use_cases
+                .iter()
+                .map(|uc| uc.category.as_str())
+                .collect();
+            categories.sort_unstable();
+            categories.dedup();
+            for category in categories {
+                for page in &pages {
+                    let path = use_case_dir.join(to_snake_case(category)).join(page);
+                    self.push(PlanAction::Write, path, None);
+                }
+            }
+        }
+        self.push(PlanAction::Invoke, "ManifestGenerator", None);
+        self.push(
+            PlanAction::Write,
+            use_case_dir.join("mucm-manifest.json"),
+            None,
+        );
+    }
+
+    fn push(&mut self, action: PlanAction, target: impl AsRef<Path>, detail: Option<String>) {
+        self.operations.push(PlannedOperation {
+            action,
+            target: target.as_ref().display().to_string(),
+            detail,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ProjectContext;
+
+    fn use_cases() -> Vec<UseCase> {
+        let mut login = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        login.views = vec![MethodologyView::new("feature", "normal")];
+        vec![login]
+    }
+
+    fn plan(mutation: PlannedMutation) -> Result<ExecutionPlan> {
+        let use_cases = use_cases();
+        let config = Config::default();
+        let tests = TestGenerator::for_project(&ProjectContext::current(config.clone()));
+        PlanService::new(&use_cases, &config, &tests).plan("test", &mutation)
+    }
+
+    fn targets(plan: &ExecutionPlan, action: PlanAction) -> Vec<&str> {
+        plan.operations
+            .iter()
+            .filter(|op| op.action == action)
+            .map(|op| op.target.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_update_reads_then_writes_data_before_markdown() {
+        let plan = plan(PlannedMutation::Update {
+            use_case_id: "UC-AUT-001".to_string(),
+        })
+        .unwrap();
+
+        assert!(plan.operations[0].action == PlanAction::Read);
+        assert!(plan.operations[0].target.ends_with("auth/UC-AUT-001.toml"));
+        let writes = targets(&plan, PlanAction::Write);
+        assert!(writes[0].ends_with("auth/UC-AUT-001.toml"));
+        assert!(writes[1].ends_with("auth/UC-AUT-001-feature-normal.md"));
+        assert!(writes.last().unwrap().ends_with("mucm-manifest.json"));
+    }
+
+    #[test]
+    fn test_create_writes_new_files_only() {
+        let plan = plan(PlannedMutation::Create {
+            use_case_id: "UC-AUT-002".to_string(),
+            category: "Auth".to_string(),
+            views: vec![
+                MethodologyView::new("feature", "normal"),
+                MethodologyView::new("business", "simple"),
+            ],
+        })
+        .unwrap();
+
+        let writes = targets(&plan, PlanAction::Write);
+        assert!(writes
+            .iter()
+            .any(|w| w.ends_with("UC-AUT-002-business-simple.md")));
+        assert!(!plan
+            .operations
+            .iter()
+            .any(|op| op.target.contains("UC-AUT-001")));
+    }
+
+    #[test]
+    fn test_unknown_use_case_is_an_error() {
+        let err = plan(PlannedMutation::Regenerate {
+            use_case_id: Some("UC-AUT-009".to_string()),
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("UC-AUT-009"));
+    }
+
+    #[test]
+    fn test_plan_serializes_actions_in_snake_case() {
+        let plan = plan(PlannedMutation::Regenerate { use_case_id: None }).unwrap();
+        let json = serde_json::to_value(&plan).unwrap();
+        assert_eq!(json["command"], "test");
+        assert_eq!(json["operations"][0]["action"], "read");
+        assert!(json["operations"][0].get("detail").is_none());
+    }
+}
@@ -1,17 +1,15 @@
+use crate::core::MucmError;
+use crate::core::Result;
 use crate::core::{UseCase, UseCaseRepository};
-use anyhow::Result;
 
 /// Service for managing preconditions and postconditions on use cases
 pub struct PreconditionPostconditionService<'a> {
-    repository: &'a Box<dyn UseCaseRepository>,
+    repository: &'a dyn UseCaseRepository,
     use_cases: &'a mut Vec<UseCase>,
 }
 
 impl<'a> PreconditionPostconditionService<'a> {
-    pub fn new(
-        repository: &'a Box<dyn UseCaseRepository>,
-        use_cases: &'a mut Vec<UseCase>,
-    ) -> Self {
+    pub fn new(repository: &'a dyn UseCaseRepository, use_cases: &'a mut Vec<UseCase>) -> Self {
         Self {
             repository,
             use_cases,
@@ -36,10 +34,10 @@ impl<'a> PreconditionPostconditionService<'a> {
         // Convert 1-based index to 0-based
         let zero_based_index = index.saturating_sub(1);
         if zero_based_index >= use_case.preconditions.len() {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Precondition index {} is out of bounds",
                 index
-            ));
+            )));
         }
 
         use_case.preconditions.remove(zero_based_index);
@@ -66,10 +64,10 @@ impl<'a> PreconditionPostconditionService<'a> {
         // Convert 1-based index to 0-based
         let zero_based_index = index.saturating_sub(1);
         if zero_based_index >= use_case.postconditions.len() {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Postcondition index {} is out of bounds",
                 index
-            ));
+            )));
         }
 
         use_case.postconditions.remove(zero_based_index);
@@ -91,10 +89,10 @@ impl<'a> PreconditionPostconditionService<'a> {
         // Convert 1-based index to 0-based
         let zero_based_index = index.saturating_sub(1);
         if zero_based_index >= use_case.preconditions.len() {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Precondition index {} is out of bounds",
                 index
-            ));
+            )));
         }
 
         use_case.preconditions[zero_based_index] = new_text.into();
@@ -116,10 +114,10 @@ impl<'a> PreconditionPostconditionService<'a> {
         // Convert 1-based index to 0-based
         let zero_based_index = index.saturating_sub(1);
         if zero_based_index >= use_case.postconditions.len() {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Postcondition index {} is out of bounds",
                 index
-            ));
+            )));
         }
 
         use_case.postconditions[zero_based_index] = new_text.into();
@@ -143,7 +141,7 @@ impl<'a> PreconditionPostconditionService<'a> {
         let to_idx = to_index.saturating_sub(1);
 
         if from_idx >= use_case.preconditions.len() || to_idx >= use_case.preconditions.len() {
-            return Err(anyhow::anyhow!("Index out of bounds"));
+            return Err(MucmError::Validation("Index out of bounds".to_string()));
         }
 
         // Remove item from old position
@@ -171,7 +169,7 @@ impl<'a> PreconditionPostconditionService<'a> {
         let to_idx = to_index.saturating_sub(1);
 
         if from_idx >= use_case.postconditions.len() || to_idx >= use_case.postconditions.len() {
-            return Err(anyhow::anyhow!("Index out of bounds"));
+            return Err(MucmError::Validation("Index out of bounds".to_string()));
         }
 
         // Remove item from old position
@@ -211,6 +209,6 @@ impl<'a> PreconditionPostconditionService<'a> {
         self.use_cases
             .iter()
             .position(|uc| uc.id == use_case_id)
-            .ok_or_else(|| anyhow::anyhow!("Use case '{}' not found", use_case_id))
+            .ok_or_else(|| MucmError::NotFound(format!("Use case '{}' not found", use_case_id)))
     }
 }
@@ -1,18 +1,16 @@
 use crate::core::domain::UseCaseReference;
+use crate::core::MucmError;
+use crate::core::Result;
 use crate::core::{UseCase, UseCaseRepository};
-use anyhow::Result;
 
 /// Service for managing references between use cases
 pub struct ReferenceManagementService<'a> {
-    repository: &'a Box<dyn UseCaseRepository>,
+    repository: &'a dyn UseCaseRepository,
     use_cases: &'a mut Vec<UseCase>,
 }
 
 impl<'a> ReferenceManagementService<'a> {
-    pub fn new(
-        repository: &'a Box<dyn UseCaseRepository>,
-        use_cases: &'a mut Vec<UseCase>,
-    ) -> Self {
+    pub fn new(repository: &'a dyn UseCaseRepository, use_cases: &'a mut Vec<UseCase>) -> Self {
         Self {
             repository,
             use_cases,
@@ -58,6 +56,6 @@ impl<'a> ReferenceManagementService<'a> {
         self.use_cases
             .iter()
             .position(|uc| uc.id == use_case_id)
-            .ok_or_else(|| anyhow::anyhow!("Use case '{}' not found", use_case_id))
+            .ok_or_else(|| MucmError::NotFound(format!("Use case '{}' not found", use_case_id)))
     }
 }
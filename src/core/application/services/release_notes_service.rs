@@ -0,0 +1,394 @@
+//! Release notes from use case changes.
+//!
+//! Compares the use cases of the project with their data files at an earlier
+//! git revision (e.g. a release tag) and summarizes what was added, removed,
+//! or materially changed, grouped by category. Material changes are changes to
+//! the title, description, priority, status, conditions, and scenarios;
+//! timestamps, views, tags, and custom fields are left out. Used by
+//! `mucm report release-notes`.
+
+use crate::core::MucmError;
+use crate::core::{Context, Result};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::process::Command;
+
+use crate::core::{Condition, Scenario, TomlUseCaseRepository, UseCase};
+
+/// How a use case changed since the baseline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReleaseNoteKind {
+    Added,
+    Changed,
+    Removed,
+}
+
+impl ReleaseNoteKind {
+    fn label(self) -> &'static str {
+        match self {
+            ReleaseNoteKind::Added => "New",
+            ReleaseNoteKind::Changed => "Changed",
+            ReleaseNoteKind::Removed => "Removed",
+        }
+    }
+}
+
+/// One use case in the release notes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseNote {
+    pub kind: ReleaseNoteKind,
+    pub use_case_id: String,
+    pub title: String,
+    /// What changed, e.g. `scenario added: "Card declined"`
+    pub changes: Vec<String>,
+}
+
+/// Release notes since a revision, grouped by category
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseNotes {
+    pub since: String,
+    /// Category name to notes, new use cases first
+    pub categories: BTreeMap<String, Vec<ReleaseNote>>,
+}
+
+impl ReleaseNotes {
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
+
+    /// Number of notes of a kind
+    pub fn count(&self, kind: ReleaseNoteKind) -> usize {
+        self.categories
+            .values()
+            .flatten()
+            .filter(|note| note.kind == kind)
+            .count()
+    }
+
+    /// Markdown suitable for a release announcement
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# Release Notes (since {})\n", self.since);
+        if self.is_empty() {
+            out.push_str("\nNo use case changes.\n");
+            return out;
+        }
+        for (category, notes) in &self.categories {
+            out.push_str(&format!("\n## {}\n\n", category));
+            for note in notes {
+                out.push_str(&format!(
+                    "- **{}:** {} {}",
+                    note.kind.label(),
+                    note.use_case_id,
+                    note.title
+                ));
+                if !note.changes.is_empty() {
+                    out.push_str(&format!(" ({})", note.changes.join("; ")));
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+/// Compare the use cases at the baseline with the current ones
+pub(crate) fn release_notes(since: &str, before: &[UseCase], after: &[UseCase]) -> ReleaseNotes {
+    let before_by_id: HashMap<&str, &UseCase> =
+        before.iter().map(|uc| (uc.id.as_str(), uc)).collect();
+    let after_ids: Vec<&str> = after.iter().map(|uc| uc.id.as_str()).collect();
+
+    let mut categories: BTreeMap<String, Vec<ReleaseNote>> = BTreeMap::new();
+    for use_case in after {
+        let note = match before_by_id.get(use_case.id.as_str()) {
+            None => ReleaseNote {
+                kind: ReleaseNoteKind::Added,
+                use_case_id: use_case.id.clone(),
+                title: use_case.title.clone(),
+                changes: match use_case.scenarios.len() {
+                    0 => Vec::new(),
+                    count => vec![format!("{} scenario(s)", count)],
+                },
+            },
+            Some(previous) => {
+                let changes = material_changes(previous, use_case);
+                if changes.is_empty() {
+                    continue;
+                }
+                ReleaseNote {
+                    kind: ReleaseNoteKind::Changed,
+                    use_case_id: use_case.id.clone(),
+                    title: use_case.title.clone(),
+                    changes,
+                }
+            }
+        };
+        categories
+            .entry(use_case.category.clone())
+            .or_default()
+            .push(note);
+    }
+    for use_case in before {
+        if !after_ids.contains(&use_case.id.as_str()) {
+            categories
+                .entry(use_case.category.clone())
+                .or_default()
+                .push(ReleaseNote {
+                    kind: ReleaseNoteKind::Removed,
+                    use_case_id: use_case.id.clone(),
+                    title: use_case.title.clone(),
+                    changes: Vec::new(),
+                });
+        }
+    }
+    for notes in categories.values_mut() {
+        notes.sort_by(|a, b| (a.kind, &a.use_case_id).cmp(&(b.kind, &b.use_case_id)));
+    }
+
+    ReleaseNotes {
+        since: since.to_string(),
+        categories,
+    }
+}
+
+/// Whether a release note would list a change between `before` and `after`
+pub(crate) fn is_materially_changed(before: &UseCase, after: &UseCase) -> bool {
+    !material_changes(before, after).is_empty()
+}
+
+fn material_changes(before: &UseCase, after: &UseCase) -> Vec<String> {
+    let mut changes = Vec::new();
+    if before.title != after.title {
+        changes.push(format!("renamed from \"{}\"", before.title));
+    }
+    if before.description != after.description {
+        changes.push("description updated".to_string());
+    }
+    if before.priority != after.priority {
+        changes.push(format!(
+            "priority {} → {}",
+            before.priority.to_string().to_lowercase(),
+            after.priority.to_string().to_lowercase()
+        ));
+    }
+    if before.status() != after.status() {
+        changes.push(format!(
+            "status {} → {}",
+            before.status().display_name().to_lowercase(),
+            after.status().display_name().to_lowercase()
+        ));
+    }
+    push_condition_changes(
+        &mut changes,
+        "precondition",
+        &before.preconditions,
+        &after.preconditions,
+    );
+    push_condition_changes(
+        &mut changes,
+        "postcondition",
+        &before.postconditions,
+        &after.postconditions,
+    );
+
+    for scenario in &after.scenarios {
+        match before.scenarios.iter().find(|s| s.id == scenario.id) {
+            None => changes.push(format!("scenario added: \"{}\"", scenario.title)),
+            Some(previous) if scenario_changed(previous, scenario) => {
+                changes.push(format!("scenario updated: \"{}\"", scenario.title))
+            }
+            Some(_) => {}
+        }
+    }
+    for scenario in &before.scenarios {
+        if !after.scenarios.iter().any(|s| s.id == scenario.id) {
+            changes.push(format!("scenario removed: \"{}\"", scenario.title));
+        }
+    }
+    changes
+}
+
+fn scenario_changed(before: &Scenario, after: &Scenario) -> bool {
+    before.title != after.title
+        || before.description != after.description
+        || before.scenario_type != after.scenario_type
+        || before.status != after.status
+        || before.steps != after.steps
+        || before.preconditions != after.preconditions
+        || before.postconditions != after.postconditions
+}
+
+fn push_condition_changes(
+    changes: &mut Vec<String>,
+    name: &str,
+    before: &[Condition],
+    after: &[Condition],
+) {
+    let added = after.iter().filter(|c| !before.contains(c)).count();
+    let removed = before.iter().filter(|c| !after.contains(c)).count();
+    if added > 0 {
+        changes.push(format!("{} {}(s) added", added, name));
+    }
+    if removed > 0 {
+        changes.push(format!("{} {}(s) removed", removed, name));
+    }
+}
+
+/// Load the use case data files of `data_dir` as they were at a git revision
+///
+/// Data files are the `.toml` files named after a use case ID with `id_prefix`,
+/// as the TOML repository reads them. Returns no use cases when the data
+/// directory did not exist at the revision.
+pub(crate) fn load_use_cases_at_revision(
+    data_dir: &Path,
+    revision: &str,
+    id_prefix: &str,
+) -> Result<Vec<UseCase>> {
+    git(
+        data_dir,
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", revision)],
+    )
+    .with_context(|| format!("'{}' is not a git revision", revision))?;
+
+    // Paths are listed relative to the data directory (the working directory)
+    let listing = git(
+        data_dir,
+        &["ls-tree", "-r", "--name-only", revision, "--", "."],
+    )?;
+    let file_prefix = format!("{}-", id_prefix);
+    let mut use_cases = Vec::new();
+    for path in listing.lines() {
+        let is_data_file = Path::new(path)
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with(&file_prefix))
+            && path.ends_with(".toml");
+        if !is_data_file {
+            continue;
+        }
+        let content = git(data_dir, &["show", &format!("{}:./{}", revision, path)])?;
+        let (use_case, _) = TomlUseCaseRepository::parse(&content)
+            .with_context(|| format!("{} at {} is not a valid use case", path, revision))?;
+        use_cases.push(use_case);
+    }
+    Ok(use_cases)
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        return Err(MucmError::Storage(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::core::{ScenarioType, Status, UseCaseRepository};
+    use tempfile::TempDir;
+
+    fn use_case(id: &str, title: &str, category: &str) -> UseCase {
+        UseCase::builder(id, title)
+            .category(category)
+            .build()
+            .unwrap()
+    }
+
+    fn scenario(id: &str, title: &str) -> Scenario {
+        Scenario::new(
+            id.to_string(),
+            title.to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        )
+    }
+
+    #[test]
+    fn test_release_notes_group_added_changed_and_removed_use_cases() {
+        let mut checkout = use_case("UC-PAY-001", "Checkout", "Payments");
+        checkout
+            .scenarios
+            .push(scenario("UC-PAY-001-S01", "Pay by card"));
+        let login = use_case("UC-AUT-001", "Login", "Auth");
+        let legacy = use_case("UC-PAY-002", "Invoice by mail", "Payments");
+        let before = vec![checkout.clone(), login.clone(), legacy];
+
+        let mut after_checkout = checkout;
+        after_checkout.scenarios[0].status = Status::Tested;
+        after_checkout
+            .scenarios
+            .push(scenario("UC-PAY-001-S02", "Card declined"));
+        let mut refunds = use_case("UC-PAY-003", "Refunds", "Payments");
+        refunds
+            .scenarios
+            .push(scenario("UC-PAY-003-S01", "Full refund"));
+        let after = vec![after_checkout, login, refunds];
+
+        let notes = release_notes("v1.1", &before, &after);
+
+        // Unchanged Auth is left out
+        assert_eq!(
+            notes.categories.keys().collect::<Vec<_>>(),
+            vec!["Payments"]
+        );
+        let payments = &notes.categories["Payments"];
+        assert_eq!(payments.len(), 3);
+        assert_eq!(payments[0].kind, ReleaseNoteKind::Added);
+        assert_eq!(payments[0].use_case_id, "UC-PAY-003");
+        assert_eq!(payments[1].kind, ReleaseNoteKind::Changed);
+        assert_eq!(
+            payments[1].changes,
+            vec![
+                "scenario updated: \"Pay by card\"",
+                "scenario added: \"Card declined\""
+            ]
+        );
+        assert_eq!(payments[2].kind, ReleaseNoteKind::Removed);
+
+        let markdown = notes.to_markdown();
+        assert!(markdown.starts_with("# Release Notes (since v1.1)\n"));
+        assert!(markdown.contains("- **New:** UC-PAY-003 Refunds (1 scenario(s))\n"));
+        assert!(markdown.contains("- **Removed:** UC-PAY-002 Invoice by mail\n"));
+    }
+
+    #[test]
+    fn test_baseline_uses_the_configured_id_prefix() {
+        let dir = TempDir::new().unwrap();
+        let data_dir = dir.path().join("data");
+        let mut config = Config::default();
+        config.project.id_prefix = "PAY".to_string();
+        config.directories.data_dir = data_dir.to_string_lossy().into_owned();
+        TomlUseCaseRepository::new(config)
+            .save(&use_case("PAY-CHK-001", "Checkout", "Checkout"))
+            .unwrap();
+        for args in [
+            &["init", "-q"][..],
+            &["add", "."],
+            &[
+                "-c",
+                "user.name=t",
+                "-c",
+                "user.email=t@t",
+                "commit",
+                "-qm",
+                "v1",
+            ],
+        ] {
+            git(dir.path(), args).unwrap();
+        }
+
+        let before = load_use_cases_at_revision(&data_dir, "HEAD", "PAY").unwrap();
+        assert_eq!(before.len(), 1);
+        assert_eq!(before[0].id, "PAY-CHK-001");
+        assert!(load_use_cases_at_revision(&data_dir, "HEAD", "UC")
+            .unwrap()
+            .is_empty());
+    }
+}
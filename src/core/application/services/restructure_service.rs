@@ -0,0 +1,632 @@
+//! Splitting one use case into two, merging two use cases into one, and
+//! cloning a use case as a starting point for a new one.
+//!
+//! Both operations change scenario (and, for merges, use case) IDs. The helpers
+//! here return the old → new ID mapping so the caller can rewrite references in
+//! every other use case with [`rewrite_references`].
+
+use crate::core::MucmError;
+use crate::core::Result;
+use regex::{Captures, Regex};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use super::re_id;
+use crate::core::domain::Metadata;
+use crate::core::{Scenario, Status, UseCase};
+
+/// A field or scenario that differs between the two use cases being merged
+#[derive(Debug, Clone)]
+pub struct MergeConflict {
+    /// What clashes, e.g. `description` or `scenario "Sign in"`
+    pub subject: String,
+    /// Value in the use case that is kept
+    pub target_value: String,
+    /// Value in the use case that is merged in and removed
+    pub source_value: String,
+    /// Whether both values can be kept (only for scenarios)
+    pub can_keep_both: bool,
+}
+
+/// How to resolve a [`MergeConflict`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeChoice {
+    /// Keep the value of the use case that is kept
+    KeepTarget,
+    /// Take the value of the use case that is merged in
+    UseSource,
+    /// Keep both scenarios side by side
+    KeepBoth,
+}
+
+impl FromStr for MergeChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('_', "-").as_str() {
+            "keep-target" | "target" => Ok(MergeChoice::KeepTarget),
+            "use-source" | "source" => Ok(MergeChoice::UseSource),
+            "keep-both" | "both" => Ok(MergeChoice::KeepBoth),
+            _ => Err(format!(
+                "Invalid conflict policy: {}. Valid options: ask, keep-target, use-source, keep-both",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for MergeChoice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MergeChoice::KeepTarget => write!(f, "Keep the target's version"),
+            MergeChoice::UseSource => write!(f, "Use the merged-in version"),
+            MergeChoice::KeepBoth => write!(f, "Keep both"),
+        }
+    }
+}
+
+/// Move the given scenarios from `source` to `target`, renumbering them in `target`.
+///
+/// Returns the old → new scenario ID mapping.
+pub fn split_scenarios(
+    source: &mut UseCase,
+    scenario_ids: &[String],
+    target: &mut UseCase,
+) -> Result<HashMap<String, String>> {
+    if scenario_ids.is_empty() {
+        return Err(MucmError::Validation(
+            "No scenarios given to split off".to_string(),
+        ));
+    }
+    for id in scenario_ids {
+        if !source.scenarios.iter().any(|s| &s.id == id) {
+            return Err(MucmError::NotFound(format!(
+                "Scenario with ID '{}' not found in {}",
+                id, source.id
+            )));
+        }
+    }
+
+    let (moved, kept): (Vec<Scenario>, Vec<Scenario>) = source
+        .scenarios
+        .drain(..)
+        .partition(|s| scenario_ids.contains(&s.id));
+    source.scenarios = kept;
+    source.metadata.touch();
+
+    let mut renames = HashMap::new();
+    for mut scenario in moved {
+        let new_id = target.next_scenario_id();
+        renames.insert(scenario.id.clone(), new_id.clone());
+        scenario.id = new_id;
+        scenario.order = None;
+        target.add_scenario(scenario);
+    }
+    Ok(renames)
+}
+
+/// Merge `source` into `target`, asking `resolve` about every conflicting value.
+///
+/// Scenarios with the same title are conflicts; all other scenarios are appended
+/// with new IDs. Conditions, references, views, and custom fields are combined,
+/// with `target` winning where both define the same field.
+///
+/// Returns the old → new ID mapping, including `source.id` → `target.id`.
+pub fn merge_use_cases<F>(
+    target: &mut UseCase,
+    source: &UseCase,
+    mut resolve: F,
+) -> Result<HashMap<String, String>>
+where
+    F: FnMut(&MergeConflict) -> Result<MergeChoice>,
+{
+    let mut renames = HashMap::new();
+    renames.insert(source.id.clone(), target.id.clone());
+
+    if !source.description.is_empty() && source.description != target.description {
+        let use_source = target.description.is_empty()
+            || resolve(&MergeConflict {
+                subject: "description".to_string(),
+                target_value: target.description.clone(),
+                source_value: source.description.clone(),
+                can_keep_both: false,
+            })? == MergeChoice::UseSource;
+        if use_source {
+            target.description = source.description.clone();
+        }
+    }
+
+    if source.priority != target.priority
+        && resolve(&MergeConflict {
+            subject: "priority".to_string(),
+            target_value: target.priority.to_string(),
+            source_value: source.priority.to_string(),
+            can_keep_both: false,
+        })? == MergeChoice::UseSource
+    {
+        target.priority = source.priority.clone();
+    }
+
+    for scenario in &source.scenarios {
+        let existing = target
+            .scenarios
+            .iter()
+            .position(|s| s.title.eq_ignore_ascii_case(&scenario.title));
+
+        let choice = match existing {
+            Some(index) => resolve(&MergeConflict {
+                subject: format!("scenario \"{}\"", scenario.title),
+                target_value: target.scenarios[index].id.clone(),
+                source_value: scenario.id.clone(),
+                can_keep_both: true,
+            })?,
+            None => MergeChoice::KeepBoth,
+        };
+
+        match (choice, existing) {
+            (MergeChoice::KeepBoth, _) | (_, None) => {
+                let mut scenario = scenario.clone();
+                let new_id = target.next_scenario_id();
+                renames.insert(scenario.id.clone(), new_id.clone());
+                scenario.id = new_id;
+                scenario.order = None;
+                target.add_scenario(scenario);
+            }
+            (MergeChoice::UseSource, Some(index)) => {
+                let kept_id = target.scenarios[index].id.clone();
+                let kept_order = target.scenarios[index].order;
+                renames.insert(scenario.id.clone(), kept_id.clone());
+                target.scenarios[index] = Scenario {
+                    id: kept_id,
+                    order: kept_order,
+                    ..scenario.clone()
+                };
+            }
+            (MergeChoice::KeepTarget, Some(index)) => {
+                renames.insert(scenario.id.clone(), target.scenarios[index].id.clone());
+            }
+        }
+    }
+
+    for condition in &source.preconditions {
+        if !target.preconditions.contains(condition) {
+            target.preconditions.push(condition.clone());
+        }
+    }
+    for condition in &source.postconditions {
+        if !target.postconditions.contains(condition) {
+            target.postconditions.push(condition.clone());
+        }
+    }
+
+    // References between the two use cases become self-references, so drop them
+    target
+        .use_case_references
+        .retain(|r| r.target_id != source.id);
+    for reference in &source.use_case_references {
+        if reference.target_id != target.id {
+            target.add_reference(reference.clone());
+        }
+    }
+
+    for view in source.views() {
+        target.add_view(view.clone());
+    }
+    for (methodology, fields) in &source.methodology_fields {
+        let target_fields = target
+            .methodology_fields
+            .entry(methodology.clone())
+            .or_default();
+        for (name, value) in fields {
+            target_fields
+                .entry(name.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
+    for (key, value) in &source.extra {
+        target
+            .extra
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
+
+    target.metadata.touch();
+    Ok(renames)
+}
+
+/// What to carry over when cloning a use case
+#[derive(Debug, Clone, Copy)]
+pub struct CloneOptions {
+    /// Copy scenarios (with their statuses reset to planned)
+    pub scenarios: bool,
+    /// Copy preconditions and postconditions
+    pub conditions: bool,
+}
+
+/// Deep-copy a use case under a new ID, title, and category.
+///
+/// Views, custom fields, and references are kept. Copied scenarios are
+/// renumbered for the new ID and start over as planned.
+pub fn clone_use_case(
+    source: &UseCase,
+    new_id: &str,
+    title: String,
+    category: String,
+    options: CloneOptions,
+) -> UseCase {
+    let mut clone = re_id(source, new_id);
+    clone.title = title;
+    clone.category = category;
+    clone.metadata = Metadata::new();
+
+    if !options.scenarios {
+        clone.scenarios.clear();
+    }
+    for scenario in &mut clone.scenarios {
+        scenario.status = Status::Planned;
+        if !options.conditions {
+            scenario.preconditions.clear();
+            scenario.postconditions.clear();
+        }
+    }
+    if !options.conditions {
+        clone.preconditions.clear();
+        clone.postconditions.clear();
+    }
+    clone
+}
+
+/// Map every use case and scenario ID starting with `old_prefix` to `new_prefix`.
+///
+/// IDs with another prefix are left alone.
+pub fn reprefix_renames(
+    use_cases: &[UseCase],
+    old_prefix: &str,
+    new_prefix: &str,
+) -> HashMap<String, String> {
+    let old = format!("{}-", old_prefix);
+    let reprefix = |id: &str| {
+        id.strip_prefix(&old)
+            .map(|rest| format!("{}-{}", new_prefix, rest))
+    };
+
+    let mut renames = HashMap::new();
+    for use_case in use_cases {
+        if let Some(new_id) = reprefix(&use_case.id) {
+            renames.insert(use_case.id.clone(), new_id);
+        }
+        for scenario in &use_case.scenarios {
+            if let Some(new_id) = reprefix(&scenario.id) {
+                renames.insert(scenario.id.clone(), new_id);
+            }
+        }
+    }
+    renames
+}
+
+/// Point every reference in `use_case` at renamed IDs.
+///
+/// Returns `true` when anything changed.
+pub fn rewrite_references(use_case: &mut UseCase, renames: &HashMap<String, String>) -> bool {
+    let mut changed = false;
+    let mut rename = |id: &mut String| {
+        if let Some(new_id) = renames.get(id.as_str()) {
+            *id = new_id.clone();
+            changed = true;
+        }
+    };
+
+    for reference in &mut use_case.use_case_references {
+        rename(&mut reference.target_id);
+    }
+    for condition in use_case
+        .preconditions
+        .iter_mut()
+        .chain(use_case.postconditions.iter_mut())
+    {
+        if let Some(target_id) = condition.target_id.as_mut() {
+            rename(target_id);
+        }
+    }
+    for scenario in &mut use_case.scenarios {
+        for reference in &mut scenario.references {
+            rename(&mut reference.target_id);
+        }
+        for condition in scenario
+            .preconditions
+            .iter_mut()
+            .chain(scenario.postconditions.iter_mut())
+        {
+            if let Some(target_id) = condition.target_id.as_mut() {
+                rename(target_id);
+            }
+        }
+    }
+
+    if changed {
+        use_case.metadata.touch();
+    }
+    changed
+}
+
+/// Rewrite renamed IDs mentioned in the free-text fields of `use_case`.
+///
+/// Covers titles, descriptions, conditions, steps, reference descriptions and
+/// the string values of methodology and extra fields. Only whole IDs are
+/// replaced, all at once, so chained renames (004 → 003, 005 → 004) do not
+/// cascade. Returns `true` when anything changed.
+pub fn rewrite_id_mentions(use_case: &mut UseCase, renames: &HashMap<String, String>) -> bool {
+    if renames.is_empty() {
+        return false;
+    }
+    // Longest first, so a scenario ID wins over the use case ID it starts with
+    let mut ids: Vec<&String> = renames.keys().collect();
+    ids.sort_by_key(|id| std::cmp::Reverse(id.len()));
+    let pattern = ids
+        .iter()
+        .map(|id| regex::escape(id))
+        .collect::<Vec<_>>()
+        .join("|");
+    let Ok(mentions) = Regex::new(&format!(r"\b(?:{})\b", pattern)) else {
+        return false;
+    };
+
+    let mut changed = false;
+    let mut rewrite = |text: &mut String| {
+        if let Cow::Owned(rewritten) =
+            mentions.replace_all(text, |caps: &Captures| renames[&caps[0]].clone())
+        {
+            *text = rewritten;
+            changed = true;
+        }
+    };
+    let rewrite_values = |values: &mut HashMap<String, Value>,
+                          rewrite: &mut dyn FnMut(&mut String)| {
+        for value in values.values_mut() {
+            if let Value::String(text) = value {
+                rewrite(text);
+            }
+        }
+    };
+
+    rewrite(&mut use_case.title);
+    rewrite(&mut use_case.description);
+    for condition in use_case
+        .preconditions
+        .iter_mut()
+        .chain(use_case.postconditions.iter_mut())
+    {
+        rewrite(&mut condition.text);
+    }
+    for reference in &mut use_case.use_case_references {
+        if let Some(description) = reference.description.as_mut() {
+            rewrite(description);
+        }
+    }
+    for fields in use_case.methodology_fields.values_mut() {
+        rewrite_values(fields, &mut rewrite);
+    }
+    rewrite_values(&mut use_case.extra, &mut rewrite);
+    for scenario in &mut use_case.scenarios {
+        rewrite(&mut scenario.title);
+        rewrite(&mut scenario.description);
+        for step in &mut scenario.steps {
+            rewrite(&mut step.action);
+            rewrite(&mut step.description);
+            if let Some(notes) = step.notes.as_mut() {
+                rewrite(notes);
+            }
+        }
+        for condition in scenario
+            .preconditions
+            .iter_mut()
+            .chain(scenario.postconditions.iter_mut())
+        {
+            rewrite(&mut condition.text);
+        }
+        for reference in &mut scenario.references {
+            if let Some(description) = reference.description.as_mut() {
+                rewrite(description);
+            }
+        }
+        rewrite_values(&mut scenario.extra, &mut rewrite);
+    }
+
+    if changed {
+        use_case.metadata.touch();
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::UseCaseReference;
+    use crate::core::{ReferenceType, ScenarioReference, ScenarioType};
+
+    fn use_case_with_scenarios(id: &str, titles: &[&str]) -> UseCase {
+        let mut use_case = UseCase::new(
+            id.to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        for title in titles {
+            let scenario = Scenario::new(
+                use_case.next_scenario_id(),
+                title.to_string(),
+                String::new(),
+                ScenarioType::HappyPath,
+            );
+            use_case.add_scenario(scenario);
+        }
+        use_case
+    }
+
+    #[test]
+    fn test_split_scenarios_renumbers_in_target() {
+        let mut source = use_case_with_scenarios("UC-AUT-001", &["Sign in", "SSO", "Reset"]);
+        let mut target = use_case_with_scenarios("UC-AUT-002", &[]);
+
+        let renames = split_scenarios(
+            &mut source,
+            &["UC-AUT-001-S02".to_string(), "UC-AUT-001-S03".to_string()],
+            &mut target,
+        )
+        .unwrap();
+
+        assert_eq!(source.scenarios.len(), 1);
+        assert_eq!(target.scenarios[0].id, "UC-AUT-002-S01");
+        assert_eq!(target.scenarios[1].title, "Reset");
+        assert_eq!(renames["UC-AUT-001-S03"], "UC-AUT-002-S02");
+        assert_eq!(source.next_scenario_id(), "UC-AUT-001-S02");
+    }
+
+    #[test]
+    fn test_split_scenarios_rejects_unknown_ids() {
+        let mut source = use_case_with_scenarios("UC-AUT-001", &["Sign in"]);
+        let mut target = use_case_with_scenarios("UC-AUT-002", &[]);
+
+        assert!(
+            split_scenarios(&mut source, &["UC-AUT-001-S09".to_string()], &mut target).is_err()
+        );
+        assert!(split_scenarios(&mut source, &[], &mut target).is_err());
+        assert_eq!(source.scenarios.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_use_cases_resolves_scenario_conflicts() {
+        let mut target = use_case_with_scenarios("UC-AUT-001", &["Sign in"]);
+        let mut source = use_case_with_scenarios("UC-AUT-002", &["sign in", "Locked out"]);
+        source.description = "Merged".to_string();
+        source.add_reference(UseCaseReference::new(
+            "UC-AUT-001".to_string(),
+            "extends".to_string(),
+        ));
+
+        let mut asked = Vec::new();
+        let renames = merge_use_cases(&mut target, &source, |conflict| {
+            asked.push(conflict.subject.clone());
+            Ok(MergeChoice::KeepTarget)
+        })
+        .unwrap();
+
+        assert_eq!(asked, ["scenario \"sign in\""]);
+        assert_eq!(target.description, "Merged");
+        assert_eq!(target.scenarios.len(), 2);
+        assert_eq!(renames["UC-AUT-002"], "UC-AUT-001");
+        assert_eq!(renames["UC-AUT-002-S01"], "UC-AUT-001-S01");
+        assert_eq!(renames["UC-AUT-002-S02"], "UC-AUT-001-S02");
+        assert!(target.use_case_references.is_empty());
+    }
+
+    #[test]
+    fn test_rewrite_id_mentions_replaces_whole_ids_at_once() {
+        let mut use_case = use_case_with_scenarios("UC-AUT-003", &["Sign in"]);
+        use_case.description = "See UC-AUT-004 and UC-AUT-005-S01, not UC-AUT-0041".to_string();
+        use_case.scenarios[0]
+            .steps
+            .push(crate::core::domain::ScenarioStep::new(
+                1,
+                crate::core::domain::Actor::User,
+                "follows UC-AUT-005".to_string(),
+                String::new(),
+            ));
+        let renames: HashMap<String, String> = [
+            ("UC-AUT-004", "UC-AUT-003"),
+            ("UC-AUT-005", "UC-AUT-004"),
+            ("UC-AUT-005-S01", "UC-AUT-004-S01"),
+        ]
+        .into_iter()
+        .map(|(old, new)| (old.to_string(), new.to_string()))
+        .collect();
+
+        assert!(rewrite_id_mentions(&mut use_case, &renames));
+        assert_eq!(
+            use_case.description,
+            "See UC-AUT-003 and UC-AUT-004-S01, not UC-AUT-0041"
+        );
+        assert_eq!(use_case.scenarios[0].steps[0].action, "follows UC-AUT-004");
+        assert!(!rewrite_id_mentions(&mut use_case, &HashMap::new()));
+    }
+
+    #[test]
+    fn test_clone_use_case_resets_scenarios() {
+        let mut source = use_case_with_scenarios("UC-AUT-001", &["Sign in"]);
+        source.scenarios[0].status = Status::Tested;
+        source.add_precondition(crate::core::Condition::new("User has an account"));
+
+        let options = CloneOptions {
+            scenarios: true,
+            conditions: false,
+        };
+        let clone = clone_use_case(
+            &source,
+            "UC-AUT-002",
+            "SSO Login".to_string(),
+            "Auth".to_string(),
+            options,
+        );
+        assert_eq!(clone.title, "SSO Login");
+        assert_eq!(clone.scenarios[0].id, "UC-AUT-002-S01");
+        assert_eq!(clone.scenarios[0].status, Status::Planned);
+        assert!(clone.preconditions.is_empty());
+
+        let options = CloneOptions {
+            scenarios: false,
+            conditions: true,
+        };
+        let clone = clone_use_case(
+            &source,
+            "UC-AUT-003",
+            "Magic Link".to_string(),
+            "Auth".to_string(),
+            options,
+        );
+        assert!(clone.scenarios.is_empty());
+        assert_eq!(clone.preconditions.len(), 1);
+    }
+
+    #[test]
+    fn test_reprefix_renames() {
+        let mut foreign = use_case_with_scenarios("LEGACY-AUT-001", &[]);
+        foreign.title = "Legacy".to_string();
+        let use_cases = vec![use_case_with_scenarios("UC-AUT-001", &["Sign in"]), foreign];
+
+        let renames = reprefix_renames(&use_cases, "UC", "PAY");
+        assert_eq!(renames.len(), 2);
+        assert_eq!(renames["UC-AUT-001"], "PAY-AUT-001");
+        assert_eq!(renames["UC-AUT-001-S01"], "PAY-AUT-001-S01");
+    }
+
+    #[test]
+    fn test_rewrite_references() {
+        let mut use_case = use_case_with_scenarios("UC-AUT-003", &["Checkout"]);
+        use_case.scenarios[0].add_reference(ScenarioReference::new(
+            ReferenceType::Scenario,
+            "UC-AUT-001-S02".to_string(),
+            "includes".to_string(),
+        ));
+        use_case.add_reference(UseCaseReference::new(
+            "UC-AUT-002".to_string(),
+            "depends_on".to_string(),
+        ));
+
+        let renames = HashMap::from([
+            ("UC-AUT-001-S02".to_string(), "UC-AUT-004-S01".to_string()),
+            ("UC-AUT-002".to_string(), "UC-AUT-001".to_string()),
+        ]);
+        assert!(rewrite_references(&mut use_case, &renames));
+        assert_eq!(
+            use_case.scenarios[0].references[0].target_id,
+            "UC-AUT-004-S01"
+        );
+        assert_eq!(use_case.use_case_references[0].target_id, "UC-AUT-001");
+        assert!(!rewrite_references(&mut use_case, &renames));
+    }
+}
@@ -0,0 +1,562 @@
+//! Import of requirement traceability matrices (RTMs) kept in spreadsheets.
+//!
+//! Each row of a sheet becomes a use case. Which column feeds which field is
+//! set by a TOML mapping file, since every team lays out its matrix its own
+//! way:
+//!
+//! ```toml
+//! sheet = "Requirements"    # default: the first sheet
+//! header_row = 2            # row holding the column names (default: 1)
+//! list_separator = ";"      # between items of list fields, besides line breaks
+//! views = "feature:normal"  # views of the use cases (default: the default methodology's normal level)
+//!
+//! [columns]                 # field = column name, or column letter
+//! id = "Req ID"
+//! title = "Requirement"
+//! priority = "MoSCoW"
+//! scenarios = "Test Cases"
+//! business_value = "Value"  # any other name is a methodology field
+//!
+//! [values.priority]         # cell value = field value
+//! Must = "high"
+//!
+//! [defaults]
+//! category = "Legacy"
+//! ```
+//!
+//! Known fields are `id` (the row's ID in the matrix), `title` (required),
+//! `description`, `category`, `priority`, `preconditions`, `postconditions`,
+//! `scenarios` (one scenario per item) and `references` (matrix or use case
+//! IDs the row depends on). Other fields are stored as methodology fields of
+//! the first view the use cases are created with. Rows without a title are
+//! skipped. Imported use cases remember their matrix ID in the `rtm_id` extra
+//! field, so rows imported before are skipped when the matrix is imported
+//! again.
+
+use calamine::{open_workbook_auto, Data, Reader};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::core::domain::UseCaseReference;
+use crate::core::{Context, MucmError, Result};
+use crate::core::{Priority, Scenario, ScenarioType, UseCase};
+
+/// Extra field linking a use case to the ID of the matrix row it came from
+pub const RTM_ID_FIELD: &str = "rtm_id";
+
+/// Category of imported use cases when neither a column nor a default sets one
+pub const DEFAULT_RTM_CATEGORY: &str = "Imported";
+
+/// Fields of the use case a column can feed, besides methodology fields
+const KNOWN_FIELDS: [&str; 9] = [
+    "id",
+    "title",
+    "description",
+    "category",
+    "priority",
+    "preconditions",
+    "postconditions",
+    "scenarios",
+    "references",
+];
+
+/// Relationship of the references read from the `references` column
+const REFERENCE_RELATIONSHIP: &str = "dependency";
+
+/// Column-to-field mapping of a matrix, read from TOML
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RtmMapping {
+    /// Sheet to read; the first one when unset
+    #[serde(default)]
+    pub sheet: Option<String>,
+    /// 1-based row holding the column names; the rows below it are imported
+    #[serde(default = "default_header_row")]
+    pub header_row: usize,
+    /// Separator of the items of list fields, besides line breaks
+    #[serde(default = "default_list_separator")]
+    pub list_separator: String,
+    /// Views of the imported use cases as comma-separated `methodology:level`
+    #[serde(default)]
+    pub views: Option<String>,
+    /// Field name → column name (case-insensitive) or letter
+    pub columns: HashMap<String, String>,
+    /// Field name → cell value → field value
+    #[serde(default)]
+    pub values: HashMap<String, HashMap<String, String>>,
+    /// Field name → value used when the column is missing or the cell empty
+    #[serde(default)]
+    pub defaults: HashMap<String, String>,
+}
+
+fn default_header_row() -> usize {
+    1
+}
+
+fn default_list_separator() -> String {
+    ";".to_string()
+}
+
+impl RtmMapping {
+    /// Read and check a mapping file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read mapping {}", path.display()))?;
+        let mapping: RtmMapping = toml::from_str(&content).map_err(|e| {
+            MucmError::Validation(format!("Invalid mapping {}: {}", path.display(), e))
+        })?;
+        if !mapping.columns.contains_key("title") {
+            return Err(MucmError::Validation(format!(
+                "Mapping {} has no column for `title` under [columns]",
+                path.display()
+            )));
+        }
+        if mapping.header_row == 0 {
+            return Err(MucmError::Validation(
+                "header_row is 1-based; use 1 for the first row".to_string(),
+            ));
+        }
+        Ok(mapping)
+    }
+}
+
+/// A use case read from a row of the matrix
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RtmRow {
+    /// 1-based row number in the sheet
+    pub row: usize,
+    /// ID of the row in the matrix
+    pub id: Option<String>,
+    pub title: String,
+    pub description: String,
+    pub category: String,
+    pub priority: Option<Priority>,
+    pub preconditions: Vec<String>,
+    pub postconditions: Vec<String>,
+    pub scenarios: Vec<String>,
+    /// Matrix or use case IDs the row depends on
+    pub references: Vec<String>,
+    /// Methodology fields as (name, value), in mapping order
+    pub fields: Vec<(String, String)>,
+}
+
+/// Outcome of an RTM import run
+#[derive(Debug, Default)]
+pub struct RtmImportReport {
+    /// (ID, sheet row) of the use cases created
+    pub created: Vec<(String, usize)>,
+    /// Matrix IDs imported before, left alone
+    pub skipped: Vec<String>,
+    /// (use case ID, reference) of references matching no row or use case
+    pub unresolved: Vec<(String, String)>,
+}
+
+/// Read the rows of the matrix at `path` (xlsx, xlsm, xlsb, xls or ods)
+pub fn load_rtm(path: &Path, mapping: &RtmMapping) -> Result<Vec<RtmRow>> {
+    let mut workbook = open_workbook_auto(path)
+        .map_err(|e| MucmError::Validation(format!("Could not open {}: {}", path.display(), e)))?;
+    let sheet =
+        match &mapping.sheet {
+            Some(sheet) => sheet.clone(),
+            None => workbook.sheet_names().first().cloned().ok_or_else(|| {
+                MucmError::Validation(format!("{} has no sheets", path.display()))
+            })?,
+        };
+    let range = workbook.worksheet_range(&sheet).map_err(|e| {
+        MucmError::NotFound(format!("Sheet '{}' of {}: {}", sheet, path.display(), e))
+    })?;
+
+    // The range starts at the first used cell; pad it so rows and columns
+    // keep their sheet positions
+    let (first_row, first_column) = range.start().unwrap_or((0, 0));
+    let mut table: Vec<Vec<String>> = vec![Vec::new(); first_row as usize];
+    for cells in range.rows() {
+        let mut row = vec![String::new(); first_column as usize];
+        row.extend(cells.iter().map(cell_text));
+        table.push(row);
+    }
+    map_rows(&table, mapping)
+}
+
+/// Text of a cell, without the `.0` of whole numbers
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        Data::Float(value) if value.fract() == 0.0 && value.abs() < 1e15 => {
+            format!("{}", *value as i64)
+        }
+        other => other.to_string().trim().to_string(),
+    }
+}
+
+/// Map the rows below the header row of `table` to use cases
+pub fn map_rows(table: &[Vec<String>], mapping: &RtmMapping) -> Result<Vec<RtmRow>> {
+    let header = table.get(mapping.header_row - 1).ok_or_else(|| {
+        MucmError::Validation(format!(
+            "The sheet has no row {} to read the column names from",
+            mapping.header_row
+        ))
+    })?;
+
+    let mut columns: Vec<(String, usize)> = Vec::new();
+    let mut names: Vec<&String> = mapping.columns.keys().collect();
+    names.sort_by_key(|name| {
+        (
+            KNOWN_FIELDS
+                .iter()
+                .position(|known| known == name)
+                .unwrap_or(KNOWN_FIELDS.len()),
+            name.as_str(),
+        )
+    });
+    for field in names {
+        let column = &mapping.columns[field];
+        let index = column_index(header, column).ok_or_else(|| {
+            MucmError::Validation(format!(
+                "Column '{}' mapped to `{}` is not in row {} ({})",
+                column,
+                field,
+                mapping.header_row,
+                header
+                    .iter()
+                    .filter(|name| !name.is_empty())
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        })?;
+        columns.push((field.clone(), index));
+    }
+
+    let mut rows = Vec::new();
+    for (offset, cells) in table.iter().enumerate().skip(mapping.header_row) {
+        let row_number = offset + 1;
+        let value = |field: &str| -> Option<String> {
+            let cell = columns
+                .iter()
+                .find(|(name, _)| name == field)
+                .and_then(|(_, index)| cells.get(*index))
+                .map(|cell| cell.trim())
+                .filter(|cell| !cell.is_empty());
+            let text = match cell {
+                Some(cell) => mapping
+                    .values
+                    .get(field)
+                    .and_then(|values| values.get(cell))
+                    .cloned()
+                    .unwrap_or_else(|| cell.to_string()),
+                None => mapping.defaults.get(field)?.clone(),
+            };
+            Some(text)
+        };
+        let list = |field: &str| -> Vec<String> {
+            value(field)
+                .map(|text| split_list(&text, &mapping.list_separator))
+                .unwrap_or_default()
+        };
+
+        let Some(title) = value("title") else {
+            continue;
+        };
+        let priority = match value("priority") {
+            Some(text) => Some(Priority::from_str(&text).map_err(|_| {
+                MucmError::Validation(format!(
+                    "Row {}: unknown priority '{}'; map it to low, medium, high or critical under [values.priority]",
+                    row_number, text
+                ))
+            })?),
+            None => None,
+        };
+        rows.push(RtmRow {
+            row: row_number,
+            id: value("id"),
+            title,
+            description: value("description").unwrap_or_default(),
+            category: value("category").unwrap_or_else(|| DEFAULT_RTM_CATEGORY.to_string()),
+            priority,
+            preconditions: list("preconditions"),
+            postconditions: list("postconditions"),
+            scenarios: list("scenarios"),
+            references: list("references"),
+            fields: columns
+                .iter()
+                .filter(|(name, _)| !KNOWN_FIELDS.contains(&name.as_str()))
+                .filter_map(|(name, _)| value(name).map(|text| (name.clone(), text)))
+                .collect(),
+        });
+    }
+    Ok(rows)
+}
+
+/// Index of a column given by name (case-insensitive) or by letter (`A`, `AB`)
+fn column_index(header: &[String], column: &str) -> Option<usize> {
+    let column = column.trim();
+    if let Some(index) = header
+        .iter()
+        .position(|name| name.trim().eq_ignore_ascii_case(column))
+    {
+        return Some(index);
+    }
+    if column.is_empty() || column.len() > 3 || !column.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    Some(
+        column
+            .chars()
+            .fold(0, |index, c| index * 26 + (c as usize - 'A' as usize + 1))
+            - 1,
+    )
+}
+
+/// Items of a list cell: one per line or separator, blanks dropped
+fn split_list(text: &str, separator: &str) -> Vec<String> {
+    text.lines()
+        .flat_map(|line| {
+            if separator.is_empty() {
+                vec![line]
+            } else {
+                line.split(separator).collect()
+            }
+        })
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Whether a use case was imported from the matrix row `rtm_id` before
+pub fn is_imported_from_rtm(use_case: &UseCase, rtm_id: &str) -> bool {
+    use_case
+        .extra
+        .get(RTM_ID_FIELD)
+        .and_then(|value| value.as_str())
+        == Some(rtm_id)
+}
+
+/// Fill a new use case with a row of the matrix
+///
+/// Methodology fields are stored under `methodology`; references are added
+/// by [`link_rtm_references`] once every row has a use case.
+pub fn apply_rtm_row(use_case: &mut UseCase, row: &RtmRow, methodology: &str) {
+    if let Some(id) = &row.id {
+        use_case.extra.insert(
+            RTM_ID_FIELD.to_string(),
+            serde_json::Value::String(id.clone()),
+        );
+    }
+    use_case.description = row.description.clone();
+    if let Some(priority) = &row.priority {
+        use_case.priority = priority.clone();
+    }
+    for condition in &row.preconditions {
+        use_case.add_precondition(condition.clone().into());
+    }
+    for condition in &row.postconditions {
+        use_case.add_postcondition(condition.clone().into());
+    }
+    for title in &row.scenarios {
+        use_case.add_scenario(Scenario::new(
+            use_case.next_scenario_id(),
+            title.clone(),
+            String::new(),
+            ScenarioType::HappyPath,
+        ));
+    }
+    if !row.fields.is_empty() {
+        let fields = use_case
+            .methodology_fields
+            .entry(methodology.to_string())
+            .or_default();
+        for (name, value) in &row.fields {
+            fields.insert(name.clone(), serde_json::Value::String(value.clone()));
+        }
+    }
+}
+
+/// Add the references of a row to its use case
+///
+/// A reference is the matrix ID of a row (imported now or before) or a use
+/// case ID. Returns the references that match neither.
+pub fn link_rtm_references(
+    use_case_id: &str,
+    row: &RtmRow,
+    use_cases: &mut [UseCase],
+) -> Vec<String> {
+    let mut unresolved = Vec::new();
+    for reference in &row.references {
+        let target = use_cases
+            .iter()
+            .find(|uc| is_imported_from_rtm(uc, reference))
+            .or_else(|| use_cases.iter().find(|uc| uc.id == *reference))
+            .map(|uc| uc.id.clone());
+        let Some(target) = target else {
+            unresolved.push(reference.clone());
+            continue;
+        };
+        if let Some(use_case) = use_cases.iter_mut().find(|uc| uc.id == use_case_id) {
+            if !use_case
+                .use_case_references
+                .iter()
+                .any(|existing| existing.target_id == target)
+            {
+                use_case.add_reference(UseCaseReference::new(
+                    target,
+                    REFERENCE_RELATIONSHIP.to_string(),
+                ));
+            }
+        }
+    }
+    unresolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_xlsxwriter::Workbook;
+    use tempfile::TempDir;
+
+    fn mapping(toml: &str) -> RtmMapping {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("mapping.toml");
+        std::fs::write(&path, toml).unwrap();
+        RtmMapping::load(&path).unwrap()
+    }
+
+    fn table(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_map_rows_applies_columns_values_and_defaults() {
+        let mapping = mapping(
+            r#"
+header_row = 2
+
+[columns]
+id = "Req ID"
+title = "requirement"
+priority = "MoSCoW"
+scenarios = "D"
+business_value = "Value"
+
+[values.priority]
+Must = "high"
+
+[defaults]
+category = "Legacy"
+"#,
+        );
+        let rows = map_rows(
+            &table(&[
+                &["Traceability matrix"],
+                &["Req ID", "Requirement", "MoSCoW", "Tests", "Value"],
+                &[
+                    "R-1",
+                    "Log in",
+                    "Must",
+                    "Valid login; Wrong password\nLocked",
+                    "High",
+                ],
+                &["R-2", "", "low", "", ""],
+                &["R-3", "Log out", "", "", ""],
+            ]),
+            &mapping,
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].row, 3);
+        assert_eq!(rows[0].id.as_deref(), Some("R-1"));
+        assert_eq!(rows[0].category, "Legacy");
+        assert_eq!(rows[0].priority, Some(Priority::High));
+        assert_eq!(
+            rows[0].scenarios,
+            ["Valid login", "Wrong password", "Locked"]
+        );
+        assert_eq!(
+            rows[0].fields,
+            [("business_value".to_string(), "High".to_string())]
+        );
+        assert_eq!(rows[1].title, "Log out");
+        assert_eq!(rows[1].priority, None);
+        assert!(rows[1].fields.is_empty());
+    }
+
+    #[test]
+    fn test_map_rows_reports_unknown_columns_and_priorities() {
+        let missing = mapping("[columns]\ntitle = \"Name\"\n");
+        let error = map_rows(&table(&[&["Title"], &["Log in"]]), &missing).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Column 'Name' mapped to `title` is not in row 1 (Title)"
+        );
+
+        let priority = mapping("[columns]\ntitle = \"Title\"\npriority = \"Priority\"\n");
+        let error = map_rows(
+            &table(&[&["Title", "Priority"], &["Log in", "Must"]]),
+            &priority,
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Row 2: unknown priority 'Must'"));
+    }
+
+    #[test]
+    fn test_link_rtm_references_resolves_matrix_and_use_case_ids() {
+        let row = |id: &str| RtmRow {
+            id: Some(id.to_string()),
+            title: id.to_string(),
+            ..Default::default()
+        };
+        let mut login = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Log in".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        apply_rtm_row(&mut login, &row("R-1"), "feature");
+        let mut logout = login.clone();
+        logout.id = "UC-AUT-002".to_string();
+        logout.extra.clear();
+        let mut use_cases = vec![login, logout];
+
+        let mut referencing = row("R-2");
+        referencing.references = vec!["R-1".to_string(), "R-9".to_string()];
+        let unresolved = link_rtm_references("UC-AUT-002", &referencing, &mut use_cases);
+
+        assert_eq!(unresolved, ["R-9"]);
+        assert_eq!(use_cases[1].use_case_references.len(), 1);
+        assert_eq!(use_cases[1].use_case_references[0].target_id, "UC-AUT-001");
+        assert_eq!(
+            use_cases[1].use_case_references[0].relationship,
+            "dependency"
+        );
+    }
+
+    #[test]
+    fn test_load_rtm_reads_a_workbook() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("matrix.xlsx");
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.set_name("Requirements").unwrap();
+        sheet.write(1, 1, "ID").unwrap();
+        sheet.write(1, 2, "Title").unwrap();
+        sheet.write(2, 1, 7).unwrap();
+        sheet.write(2, 2, "Search books").unwrap();
+        workbook.save(&path).unwrap();
+
+        let mapping = mapping("header_row = 2\n[columns]\nid = \"ID\"\ntitle = \"C\"\n");
+        let rows = load_rtm(&path, &mapping).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id.as_deref(), Some("7"));
+        assert_eq!(rows[0].title, "Search books");
+        assert_eq!(rows[0].row, 3);
+    }
+}
@@ -0,0 +1,411 @@
+//! Throwaway copies of a project for experiments (`mucm sandbox`)
+//!
+//! A sandbox is a copy of the project's config and managed directories in a
+//! temp directory, marked by `.config/.mucm/sandbox.toml` which records the
+//! project it came from. Commands run inside the sandbox change only the copy;
+//! `diff` compares it to the project and `apply` copies the changes back.
+
+use crate::core::{Context, MucmError, Result};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::config::{Config, ProjectContext};
+use crate::core::{BackupStore, ProjectLock};
+
+/// Marker file in the sandbox's config directory
+const SANDBOX_FILE: &str = "sandbox.toml";
+
+/// Directories under `.config/.mucm` that belong to one checkout, not the project
+const LOCAL_DIRS: &[&str] = &["backups", "sessions"];
+
+/// How a file differs between a sandbox and its project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SandboxChangeKind {
+    Added,
+    Modified,
+    Removed,
+}
+
+impl SandboxChangeKind {
+    /// One-letter marker used in diff listings
+    pub fn marker(self) -> char {
+        match self {
+            Self::Added => 'A',
+            Self::Modified => 'M',
+            Self::Removed => 'D',
+        }
+    }
+}
+
+/// A file changed in the sandbox, relative to the project root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SandboxChange {
+    pub kind: SandboxChangeKind,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SandboxMarker {
+    origin: PathBuf,
+    created: String,
+}
+
+/// A sandbox copy of a project
+#[derive(Debug, Clone)]
+pub struct Sandbox {
+    root: PathBuf,
+    origin: PathBuf,
+}
+
+impl Sandbox {
+    /// Copy the project at `origin` into a new directory under the system temp dir
+    pub fn create(origin: &Path) -> Result<Self> {
+        let stamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
+        let base = std::env::temp_dir();
+        let mut root = base.join(format!("mucm-sandbox-{}", stamp));
+        let mut suffix = 1;
+        while root.exists() {
+            suffix += 1;
+            root = base.join(format!("mucm-sandbox-{}-{}", stamp, suffix));
+        }
+        Self::create_at(origin, &root)
+    }
+
+    /// Copy the project at `origin` into `root`, which must not exist yet
+    pub fn create_at(origin: &Path, root: &Path) -> Result<Self> {
+        let origin = origin
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {}", origin.display()))?;
+        if Self::open(&origin).is_ok() {
+            return Err(MucmError::Conflict(format!(
+                "{} is already a sandbox",
+                origin.display()
+            )));
+        }
+        if root.exists() {
+            return Err(MucmError::Conflict(format!(
+                "{} already exists",
+                root.display()
+            )));
+        }
+
+        for relative in project_files(&origin)? {
+            copy_file(&origin.join(&relative), &root.join(&relative))?;
+        }
+
+        let marker = SandboxMarker {
+            origin: origin.clone(),
+            created: Local::now().to_rfc3339(),
+        };
+        let marker_path = root.join(Config::CONFIG_DIR).join(SANDBOX_FILE);
+        fs::create_dir_all(marker_path.parent().unwrap_or(root))?;
+        fs::write(&marker_path, toml::to_string(&marker)?)
+            .with_context(|| format!("Failed to write {}", marker_path.display()))?;
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            origin,
+        })
+    }
+
+    /// Open the sandbox at `root`
+    pub fn open(root: &Path) -> Result<Self> {
+        let marker_path = root.join(Config::CONFIG_DIR).join(SANDBOX_FILE);
+        if !marker_path.is_file() {
+            return Err(MucmError::Validation(format!(
+                "{} is not a sandbox. Run 'mucm sandbox create' in a project first",
+                root.display()
+            )));
+        }
+        let content = fs::read_to_string(&marker_path)
+            .with_context(|| format!("Failed to read {}", marker_path.display()))?;
+        let marker: SandboxMarker = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", marker_path.display()))?;
+        Ok(Self {
+            root: root.canonicalize()?,
+            origin: marker.origin,
+        })
+    }
+
+    /// Directory holding the copy
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Project the sandbox was copied from
+    pub fn origin(&self) -> &Path {
+        &self.origin
+    }
+
+    /// Files that differ between the sandbox and its project, sorted by path
+    pub fn diff(&self) -> Result<Vec<SandboxChange>> {
+        let ours = project_files(&self.root)?;
+        let theirs = project_files(&self.origin)?;
+
+        let mut changes: Vec<SandboxChange> = ours
+            .union(&theirs)
+            .filter_map(|relative| {
+                let kind = match (ours.contains(relative), theirs.contains(relative)) {
+                    (true, false) => SandboxChangeKind::Added,
+                    (false, true) => SandboxChangeKind::Removed,
+                    _ => {
+                        let ours = fs::read(self.root.join(relative)).ok();
+                        let theirs = fs::read(self.origin.join(relative)).ok();
+                        if ours == theirs {
+                            return None;
+                        }
+                        SandboxChangeKind::Modified
+                    }
+                };
+                Some(SandboxChange {
+                    kind,
+                    path: relative.clone(),
+                })
+            })
+            .collect();
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(changes)
+    }
+
+    /// Copy the sandbox's changes back into its project.
+    ///
+    /// Data files that are replaced or removed are backed up first, like any
+    /// other command that overwrites them. Returns the applied changes.
+    pub fn apply(&self) -> Result<Vec<SandboxChange>> {
+        let changes = self.diff()?;
+        if changes.is_empty() {
+            return Ok(changes);
+        }
+
+        let config = ProjectContext::load(&self.origin)?.resolved_config();
+        let data_dir = PathBuf::from(&config.directories.data_dir);
+        let backups = BackupStore::new(&config);
+
+        for change in &changes {
+            let target = self.origin.join(&change.path);
+            if change.kind != SandboxChangeKind::Added && target.starts_with(&data_dir) {
+                backups.backup_file(&target)?;
+            }
+            match change.kind {
+                SandboxChangeKind::Added | SandboxChangeKind::Modified => {
+                    copy_file(&self.root.join(&change.path), &target)?;
+                }
+                SandboxChangeKind::Removed => {
+                    fs::remove_file(&target)
+                        .with_context(|| format!("Failed to remove {}", target.display()))?;
+                }
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Delete the sandbox directory
+    pub fn discard(self) -> Result<()> {
+        fs::remove_dir_all(&self.root)
+            .with_context(|| format!("Failed to remove {}", self.root.display()))
+    }
+}
+
+/// Relative paths of the files a sandbox copies: the config directory and
+/// every project-relative directory setting, without backups, session logs,
+/// the project lock and the revision counter
+fn project_files(root: &Path) -> Result<BTreeSet<PathBuf>> {
+    let config = Config::load_from_dir(root)
+        .with_context(|| format!("{} is not a MUCM project", root.display()))?;
+
+    let config_dir = PathBuf::from(Config::CONFIG_DIR);
+    let mut excluded: Vec<PathBuf> = LOCAL_DIRS.iter().map(|d| config_dir.join(d)).collect();
+    excluded.push(config_dir.join(SANDBOX_FILE));
+    excluded.extend(ProjectLock::LOCAL_FILES.iter().map(|f| config_dir.join(f)));
+    excluded.push(normalize(Path::new(&config.backup.directory)));
+
+    let mut files = BTreeSet::new();
+    for dir in [
+        Config::CONFIG_DIR,
+        &config.directories.use_case_dir,
+        &config.directories.test_dir,
+        &config.directories.actor_dir,
+        &config.directories.data_dir,
+        &config.generation.snippet_dir,
+    ] {
+        let dir = normalize(Path::new(dir));
+        if dir.as_os_str().is_empty() || dir.is_absolute() || !root.join(&dir).is_dir() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(root.join(&dir)) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = entry.path().strip_prefix(root)?.to_path_buf();
+            if !excluded.iter().any(|ex| relative.starts_with(ex)) {
+                files.insert(relative);
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Drop `.` components so "./docs" and "docs" compare equal
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .collect()
+}
+
+fn copy_file(from: &Path, to: &Path) -> Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::copy(from, to)
+        .with_context(|| format!("Failed to copy {} to {}", from.display(), to.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{BlockingRepository, RepositoryFactory, UseCase, UseCaseRepository};
+    use tempfile::TempDir;
+
+    fn project() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let config = Config::default();
+        let config_path = dir
+            .path()
+            .join(Config::CONFIG_DIR)
+            .join(Config::CONFIG_FILE);
+        fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+        fs::write(&config_path, toml::to_string(&config).unwrap()).unwrap();
+        write(
+            dir.path(),
+            &config.directories.use_case_dir,
+            "auth/UC-AUT-001.md",
+        );
+        write(
+            dir.path(),
+            &config.directories.data_dir,
+            "auth/UC-AUT-001.toml",
+        );
+        write(dir.path(), Config::CONFIG_DIR, "sessions/today.log");
+        dir
+    }
+
+    fn write(root: &Path, dir: &str, relative: &str) {
+        let path = root.join(dir).join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, relative).unwrap();
+    }
+
+    #[test]
+    fn test_create_copies_project_without_local_state() {
+        let origin = project();
+        let scratch = TempDir::new().unwrap();
+        let sandbox = Sandbox::create_at(origin.path(), &scratch.path().join("box")).unwrap();
+
+        let config = Config::default();
+        assert!(sandbox
+            .root()
+            .join(&config.directories.data_dir)
+            .join("auth/UC-AUT-001.toml")
+            .exists());
+        assert!(!sandbox
+            .root()
+            .join(Config::CONFIG_DIR)
+            .join("sessions")
+            .exists());
+        assert!(sandbox.diff().unwrap().is_empty());
+
+        let reopened = Sandbox::open(sandbox.root()).unwrap();
+        assert_eq!(reopened.origin(), origin.path().canonicalize().unwrap());
+    }
+
+    #[test]
+    fn test_diff_and_apply() {
+        let origin = project();
+        let scratch = TempDir::new().unwrap();
+        let sandbox = Sandbox::create_at(origin.path(), &scratch.path().join("box")).unwrap();
+        let config = Config::default();
+
+        let data_dir = sandbox.root().join(&config.directories.data_dir);
+        fs::write(data_dir.join("auth/UC-AUT-001.toml"), "changed").unwrap();
+        write(
+            sandbox.root(),
+            &config.directories.data_dir,
+            "auth/UC-AUT-002.toml",
+        );
+        fs::remove_file(
+            sandbox
+                .root()
+                .join(&config.directories.use_case_dir)
+                .join("auth/UC-AUT-001.md"),
+        )
+        .unwrap();
+
+        let mut kinds: Vec<_> = sandbox.diff().unwrap().iter().map(|c| c.kind).collect();
+        kinds.sort();
+        assert_eq!(
+            kinds,
+            vec![
+                SandboxChangeKind::Added,
+                SandboxChangeKind::Modified,
+                SandboxChangeKind::Removed
+            ]
+        );
+
+        assert_eq!(sandbox.apply().unwrap().len(), 3);
+        assert!(sandbox.diff().unwrap().is_empty());
+        let applied = origin
+            .path()
+            .join(&config.directories.data_dir)
+            .join("auth/UC-AUT-001.toml");
+        assert_eq!(fs::read_to_string(applied).unwrap(), "changed");
+    }
+
+    #[test]
+    fn test_commands_run_in_a_sandbox_created_under_the_lock() {
+        let origin = project();
+        let scratch = TempDir::new().unwrap();
+        let lock = ProjectLock::acquire(origin.path()).unwrap();
+        lock.bump_revision().unwrap();
+        let sandbox = Sandbox::create_at(origin.path(), &scratch.path().join("box")).unwrap();
+        drop(lock);
+
+        let config_dir = sandbox.root().join(Config::CONFIG_DIR);
+        for file in ProjectLock::LOCAL_FILES {
+            assert!(!config_dir.join(file).exists(), "{} was copied", file);
+        }
+
+        // What a command does: hold the lock, load the project, save a change
+        let _lock = ProjectLock::acquire(sandbox.root()).unwrap();
+        let context = ProjectContext::load(sandbox.root()).unwrap();
+        let repository = BlockingRepository::new(
+            RepositoryFactory::create_async(&context.resolved_config()).unwrap(),
+        )
+        .with_project_lock(sandbox.root());
+        let use_case = UseCase::new(
+            "UC-AUT-002".to_string(),
+            "Log out".to_string(),
+            "auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        repository.save(&use_case).unwrap();
+
+        assert_eq!(ProjectLock::revision(sandbox.root()), 1);
+        let changes = sandbox.diff().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, SandboxChangeKind::Added);
+    }
+
+    #[test]
+    fn test_open_rejects_plain_project() {
+        let origin = project();
+        let err = Sandbox::open(origin.path()).unwrap_err();
+        assert!(err.to_string().contains("not a sandbox"));
+    }
+}
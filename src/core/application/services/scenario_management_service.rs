@@ -1,24 +1,25 @@
 use crate::core::application::creators::ScenarioCreator;
 use crate::core::utils::suggest_alternatives;
+use crate::core::MucmError;
+use crate::core::Result;
 use crate::core::{
-    domain::{ScenarioReference, ScenarioType},
+    domain::{Scenario, ScenarioReference, ScenarioType},
     ReferenceType, ScenarioReferenceValidator, Status, UseCase, UseCaseRepository,
 };
-use anyhow::Result;
 
 /// Service for managing scenarios within use cases
 ///
 /// This service handles CRUD operations for scenarios, scenario steps,
 /// and scenario references.
 pub struct ScenarioManagementService<'a> {
-    repository: &'a Box<dyn UseCaseRepository>,
+    repository: &'a dyn UseCaseRepository,
     use_cases: &'a mut Vec<UseCase>,
     scenario_creator: &'a ScenarioCreator,
 }
 
 impl<'a> ScenarioManagementService<'a> {
     pub fn new(
-        repository: &'a Box<dyn UseCaseRepository>,
+        repository: &'a dyn UseCaseRepository,
         use_cases: &'a mut Vec<UseCase>,
         scenario_creator: &'a ScenarioCreator,
     ) -> Self {
@@ -29,7 +30,8 @@ impl<'a> ScenarioManagementService<'a> {
         }
     }
 
-    /// Add a scenario to a use case
+    /// Add a scenario to a use case, with its tags, in one save
+    #[allow(clippy::too_many_arguments)]
     pub fn add_scenario(
         &mut self,
         use_case_id: &str,
@@ -39,11 +41,12 @@ impl<'a> ScenarioManagementService<'a> {
         preconditions: Vec<String>,
         postconditions: Vec<String>,
         actors: Vec<String>,
+        tags: &[String],
     ) -> Result<String> {
         let index = self.find_use_case_index(use_case_id)?;
         let use_case = &self.use_cases[index];
 
-        let scenario = self.scenario_creator.create_scenario(
+        let mut scenario = self.scenario_creator.create_scenario(
             use_case,
             title,
             scenario_type,
@@ -52,6 +55,9 @@ impl<'a> ScenarioManagementService<'a> {
             postconditions,
             actors,
         );
+        for tag in tags {
+            scenario.add_tag(tag);
+        }
 
         let mut updated_use_case = self.use_cases[index].clone();
         updated_use_case.add_scenario(scenario.clone());
@@ -79,6 +85,7 @@ impl<'a> ScenarioManagementService<'a> {
     }
 
     /// Add a step to an existing scenario
+    #[allow(clippy::too_many_arguments)]
     pub fn add_scenario_step(
         &mut self,
         use_case_id: &str,
@@ -141,7 +148,9 @@ impl<'a> ScenarioManagementService<'a> {
             .scenarios
             .iter()
             .position(|s| s.id == scenario_id)
-            .ok_or_else(|| anyhow::anyhow!("Scenario with ID '{}' not found", scenario_id))?;
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
 
         // Update fields if provided
         if let Some(new_title) = title {
@@ -176,10 +185,10 @@ impl<'a> ScenarioManagementService<'a> {
             .any(|s| s.id != scenario_id && s.references_scenario(scenario_id));
 
         if has_references {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Cannot delete scenario '{}': it is referenced by other scenarios",
                 scenario_id
-            ));
+            )));
         }
 
         use_case.scenarios.retain(|s| s.id != scenario_id);
@@ -191,6 +200,25 @@ impl<'a> ScenarioManagementService<'a> {
         Ok(())
     }
 
+    /// Move a scenario directly before or after another scenario
+    pub fn move_scenario(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        target_id: &str,
+        before: bool,
+    ) -> Result<()> {
+        let index = self.find_use_case_index(use_case_id)?;
+        let mut use_case = self.use_cases[index].clone();
+
+        use_case.move_scenario(scenario_id, target_id, before)?;
+
+        self.repository.save(&use_case)?;
+        self.use_cases[index] = use_case;
+
+        Ok(())
+    }
+
     /// Edit a scenario step
     pub fn edit_scenario_step(
         &mut self,
@@ -206,14 +234,19 @@ impl<'a> ScenarioManagementService<'a> {
             .scenarios
             .iter()
             .position(|s| s.id == scenario_id)
-            .ok_or_else(|| anyhow::anyhow!("Scenario with ID '{}' not found", scenario_id))?;
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
 
         let step = use_case.scenarios[scenario_index]
             .steps
             .iter_mut()
             .find(|s| s.order == step_order as usize)
             .ok_or_else(|| {
-                anyhow::anyhow!("Step {} not found in scenario {}", step_order, scenario_id)
+                MucmError::NotFound(format!(
+                    "Step {} not found in scenario {}",
+                    step_order, scenario_id
+                ))
             })?;
 
         step.action = new_description;
@@ -239,7 +272,9 @@ impl<'a> ScenarioManagementService<'a> {
             .scenarios
             .iter()
             .position(|s| s.id == scenario_id)
-            .ok_or_else(|| anyhow::anyhow!("Scenario with ID '{}' not found", scenario_id))?;
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
 
         // Apply reorderings
         for step in &mut use_case.scenarios[scenario_index].steps {
@@ -274,7 +309,9 @@ impl<'a> ScenarioManagementService<'a> {
             .scenarios
             .iter()
             .position(|s| s.id == scenario_id)
-            .ok_or_else(|| anyhow::anyhow!("Scenario with ID '{}' not found", scenario_id))?;
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
 
         use_case.scenarios[scenario_index].persona = Some(persona_id.to_string());
         use_case.metadata.touch(); // Update use case metadata when scenario changes
@@ -298,7 +335,9 @@ impl<'a> ScenarioManagementService<'a> {
             .scenarios
             .iter()
             .position(|s| s.id == scenario_id)
-            .ok_or_else(|| anyhow::anyhow!("Scenario with ID '{}' not found", scenario_id))?;
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
 
         use_case.scenarios[scenario_index].persona = None;
         use_case.metadata.touch(); // Update use case metadata when scenario changes
@@ -309,6 +348,88 @@ impl<'a> ScenarioManagementService<'a> {
         Ok(())
     }
 
+    /// Add tags to a scenario, returning its tags
+    pub fn add_scenario_tags(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        tags: &[String],
+    ) -> Result<Vec<String>> {
+        self.update_scenario_tags(use_case_id, scenario_id, tags, true)
+    }
+
+    /// Remove tags from a scenario, returning its remaining tags
+    pub fn remove_scenario_tags(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        tags: &[String],
+    ) -> Result<Vec<String>> {
+        self.update_scenario_tags(use_case_id, scenario_id, tags, false)
+    }
+
+    fn update_scenario_tags(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        tags: &[String],
+        add: bool,
+    ) -> Result<Vec<String>> {
+        let index = self.find_use_case_index(use_case_id)?;
+        let mut use_case = self.use_cases[index].clone();
+
+        let scenario = use_case
+            .scenarios
+            .iter_mut()
+            .find(|s| s.id == scenario_id)
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
+
+        let mut changed = false;
+        for tag in tags {
+            changed |= if add {
+                scenario.add_tag(tag)
+            } else {
+                scenario.remove_tag(tag)
+            };
+        }
+        let result = scenario.tags.clone();
+
+        if changed {
+            use_case.metadata.touch(); // Update use case metadata when scenario changes
+            self.repository.save(&use_case)?;
+            self.use_cases[index] = use_case;
+        }
+
+        Ok(result)
+    }
+
+    /// Change the conditions of a scenario, or whether it inherits the use case ones
+    pub fn update_scenario_conditions(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        update: impl FnOnce(&mut Scenario),
+    ) -> Result<()> {
+        let index = self.find_use_case_index(use_case_id)?;
+        let mut use_case = self.use_cases[index].clone();
+
+        let scenario = use_case
+            .scenarios
+            .iter_mut()
+            .find(|s| s.id == scenario_id)
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
+        update(scenario);
+
+        use_case.metadata.touch(); // Update use case metadata when scenario changes
+        self.repository.save(&use_case)?;
+        self.use_cases[index] = use_case;
+        Ok(())
+    }
+
     /// Add a reference to a scenario
     pub fn add_scenario_reference(
         &mut self,
@@ -323,7 +444,9 @@ impl<'a> ScenarioManagementService<'a> {
             .scenarios
             .iter()
             .position(|s| s.id == scenario_id)
-            .ok_or_else(|| anyhow::anyhow!("Scenario with ID '{}' not found", scenario_id))?;
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
 
         // Validate no circular reference for scenario-to-scenario references
         if matches!(reference.ref_type, ReferenceType::Scenario) {
@@ -358,7 +481,9 @@ impl<'a> ScenarioManagementService<'a> {
             .scenarios
             .iter()
             .position(|s| s.id == scenario_id)
-            .ok_or_else(|| anyhow::anyhow!("Scenario with ID '{}' not found", scenario_id))?;
+            .ok_or_else(|| {
+                MucmError::NotFound(format!("Scenario with ID '{}' not found", scenario_id))
+            })?;
 
         use_case.scenarios[scenario_index].remove_reference(target_id, relationship);
         use_case.metadata.touch();
@@ -377,7 +502,7 @@ impl<'a> ScenarioManagementService<'a> {
                 let available_ids: Vec<String> =
                     self.use_cases.iter().map(|uc| uc.id.clone()).collect();
                 let error_msg = suggest_alternatives(use_case_id, &available_ids, "Use case");
-                anyhow::anyhow!("{}", error_msg)
+                MucmError::NotFound(error_msg)
             })
     }
 }
@@ -0,0 +1,151 @@
+//! Customization of the use case templates of a methodology level.
+//!
+//! A template is copied from the installed templates to the project override
+//! location (`template-assets/methodologies/<methodology>/uc_<level>.hbs`),
+//! edited, and tried on a sample use case before it is saved: the project
+//! use case with that view when there is one, else a synthetic one. The
+//! variables the sample hands to the template are listed as paths, with
+//! `[]` for the items of a list (e.g. `scenarios[].title`).
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::core::application::services::synthetic_use_cases;
+use crate::core::domain::MethodologyView;
+use crate::core::UseCase;
+
+/// A template of a methodology level, ready to be edited
+#[derive(Debug, Clone)]
+pub struct TemplateDraft {
+    pub methodology: String,
+    pub level: String,
+    /// Where the project version of the template is saved
+    pub path: PathBuf,
+    /// Current content: the project version if there is one, else the installed one
+    pub source: String,
+    /// Whether the project already has its own version
+    pub overridden: bool,
+}
+
+/// A template rendered on a sample use case
+#[derive(Debug, Clone)]
+pub struct TemplatePreview {
+    /// ID of the use case the template was rendered with
+    pub sample_id: String,
+    pub output: String,
+    /// (path, kind) of the variables available to the template
+    pub variables: Vec<(String, String)>,
+}
+
+/// The use case a template of `view` is previewed with
+///
+/// The first use case (by ID) with that view enabled, else the first use
+/// case, else a synthetic one with a view of the methodology.
+pub(crate) fn sample_use_case(use_cases: &[UseCase], view: &MethodologyView) -> UseCase {
+    let mut sorted: Vec<&UseCase> = use_cases.iter().collect();
+    sorted.sort_by(|a, b| a.id.cmp(&b.id));
+    sorted
+        .iter()
+        .find(|uc| {
+            uc.enabled_views()
+                .any(|v| v.methodology == view.methodology && v.level == view.level)
+        })
+        .or_else(|| sorted.first())
+        .map(|uc| (*uc).clone())
+        .unwrap_or_else(|| {
+            synthetic_use_cases(1, "UC", &view.methodology)
+                .into_iter()
+                .next()
+                .expect("one synthetic use case")
+        })
+}
+
+/// Variables of template data as (path, kind), ordered by path
+pub(crate) fn template_variables(data: &HashMap<String, Value>) -> Vec<(String, String)> {
+    let mut variables = Vec::new();
+    for (name, value) in data {
+        collect_variables(name.clone(), value, 0, &mut variables);
+    }
+    variables.sort();
+    variables
+}
+
+fn collect_variables(path: String, value: &Value, depth: usize, out: &mut Vec<(String, String)>) {
+    let kind = match value {
+        Value::Null => "empty",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "text",
+        Value::Array(_) => "list",
+        Value::Object(_) => "object",
+    };
+    out.push((path.clone(), kind.to_string()));
+    if depth >= 2 {
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                collect_variables(format!("{}.{}", path, key), child, depth + 1, out);
+            }
+        }
+        Value::Array(items) => {
+            if let Some(Value::Object(map)) = items.first() {
+                for (key, child) in map {
+                    collect_variables(format!("{}[].{}", path, key), child, depth + 1, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_template_variables_list_nested_paths() {
+        let data: HashMap<String, Value> = serde_json::from_value(json!({
+            "title": "Log in",
+            "scenarios": [{ "title": "Happy path", "steps": [] }],
+            "views": [],
+            "metadata": { "version": 2 },
+        }))
+        .unwrap();
+
+        let variables = template_variables(&data);
+        let paths: Vec<(&str, &str)> = variables
+            .iter()
+            .map(|(path, kind)| (path.as_str(), kind.as_str()))
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                ("metadata", "object"),
+                ("metadata.version", "number"),
+                ("scenarios", "list"),
+                ("scenarios[].steps", "list"),
+                ("scenarios[].title", "text"),
+                ("title", "text"),
+                ("views", "list"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sample_use_case_prefers_the_view() {
+        let view = MethodologyView::new("feature", "normal");
+        let mut with_view = synthetic_use_cases(1, "UC-B", "business").remove(0);
+        with_view.views = vec![view.clone()];
+        let without_view = synthetic_use_cases(1, "UC-A", "business").remove(0);
+
+        let sample = sample_use_case(&[without_view.clone(), with_view.clone()], &view);
+        assert_eq!(sample.id, with_view.id);
+        let sample = sample_use_case(std::slice::from_ref(&without_view), &view);
+        assert_eq!(sample.id, without_view.id);
+        assert!(!sample_use_case(&[], &view).id.is_empty());
+    }
+}
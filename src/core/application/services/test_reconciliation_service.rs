@@ -0,0 +1,445 @@
+use crate::core::application::generators::{TestGenerator, TestNameMap};
+use crate::core::utils::suggest_alternatives;
+use crate::core::MucmError;
+use crate::core::Result;
+use crate::core::{to_snake_case, Scenario, UseCase};
+use regex::Regex;
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+/// Result of comparing a use case's scenarios against its generated test file
+#[derive(Debug, Clone)]
+pub struct TestReconciliation {
+    pub use_case_id: String,
+    pub test_file: PathBuf,
+    pub file_exists: bool,
+    /// Scenario IDs that have no matching test function
+    pub scenarios_without_tests: Vec<String>,
+    /// Test function names that do not match any scenario
+    pub tests_without_scenarios: Vec<String>,
+}
+
+impl TestReconciliation {
+    /// Whether the test file and the scenarios are in sync
+    pub fn is_in_sync(&self) -> bool {
+        self.scenarios_without_tests.is_empty() && self.tests_without_scenarios.is_empty()
+    }
+}
+
+/// What test generation did with a use case's test file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestFileAction {
+    /// The file did not exist and was generated
+    Created,
+    /// The file was regenerated
+    Updated,
+    /// The file was left alone because it has manual modifications
+    SkippedModified,
+    /// Every scenario already has a test
+    UpToDate,
+}
+
+/// Outcome of generating one use case's test file
+#[derive(Debug, Clone)]
+pub struct GeneratedTestFile {
+    pub use_case_id: String,
+    pub test_file: PathBuf,
+    pub action: TestFileAction,
+    /// Scenarios that had no test before
+    pub new_scenarios: Vec<String>,
+}
+
+/// Service for reconciling generated test files with use case scenarios
+///
+/// Generated tests are named `test_<snake_case scenario id>` (or use the scenario
+/// title for JavaScript `test('...')` blocks) unless the test name mapping records
+/// another name. This service parses the test file of each use case and reports
+/// scenarios without tests and tests without scenarios.
+pub struct TestReconciliationService<'a> {
+    use_cases: &'a [UseCase],
+    test_generator: &'a TestGenerator,
+}
+
+impl<'a> TestReconciliationService<'a> {
+    pub fn new(use_cases: &'a [UseCase], test_generator: &'a TestGenerator) -> Self {
+        Self {
+            use_cases,
+            test_generator,
+        }
+    }
+
+    /// Reconcile one use case (or all when `use_case_id` is None)
+    pub fn reconcile(&self, use_case_id: Option<&str>) -> Result<Vec<TestReconciliation>> {
+        let targets: Vec<&UseCase> = match use_case_id {
+            Some(id) => vec![self.find_use_case_by_id(id)?],
+            None => self.use_cases.iter().collect(),
+        };
+
+        let recorded = self.test_generator.load_test_names()?;
+        let language = self.test_generator.test_language();
+        let mut results = Vec::new();
+        for use_case in targets {
+            let test_file = self.test_generator.get_file_path(use_case)?;
+            let file_exists = test_file.exists();
+            let test_names = if file_exists {
+                extract_test_names(&fs::read_to_string(&test_file)?)
+            } else {
+                Vec::new()
+            };
+            let (scenarios_without_tests, tests_without_scenarios) =
+                compare(use_case, &test_names, &recorded, language);
+
+            results.push(TestReconciliation {
+                use_case_id: use_case.id.clone(),
+                test_file,
+                file_exists,
+                scenarios_without_tests,
+                tests_without_scenarios,
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Generate test files for one use case (or all when `use_case_id` is None)
+    ///
+    /// With `only_missing`, files whose scenarios all have tests are left alone.
+    /// Existing files are regenerated only while they are exactly as generated,
+    /// unless `force` is set.
+    pub fn generate(
+        &self,
+        use_case_id: Option<&str>,
+        only_missing: bool,
+        force: bool,
+    ) -> Result<Vec<GeneratedTestFile>> {
+        let mut results = Vec::new();
+        for entry in self.reconcile(use_case_id)? {
+            let use_case = self.find_use_case_by_id(&entry.use_case_id)?;
+            let action = if !entry.file_exists {
+                self.test_generator.write(use_case)?;
+                TestFileAction::Created
+            } else if only_missing && entry.scenarios_without_tests.is_empty() {
+                TestFileAction::UpToDate
+            } else {
+                let tested: Vec<String> = use_case
+                    .scenarios
+                    .iter()
+                    .map(|s| s.id.clone())
+                    .filter(|id| !entry.scenarios_without_tests.contains(id))
+                    .collect();
+                if force || self.test_generator.is_unmodified(use_case, &tested)? {
+                    self.test_generator.write(use_case)?;
+                    TestFileAction::Updated
+                } else {
+                    TestFileAction::SkippedModified
+                }
+            };
+
+            results.push(GeneratedTestFile {
+                use_case_id: entry.use_case_id,
+                test_file: entry.test_file,
+                action,
+                new_scenarios: entry.scenarios_without_tests,
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Generate the test suite of a scenario tag for one use case (or all)
+    ///
+    /// Each file holds only the scenarios carrying `tag` and is written under
+    /// `tags/<tag>/` in the test directory. Suites are derived from the data,
+    /// so existing files are always regenerated; use cases without tagged
+    /// scenarios are left out.
+    pub fn generate_tagged(
+        &self,
+        use_case_id: Option<&str>,
+        tag: &str,
+    ) -> Result<Vec<GeneratedTestFile>> {
+        let use_cases: Vec<&UseCase> = match use_case_id {
+            Some(id) => vec![self.find_use_case_by_id(id)?],
+            None => self.use_cases.iter().collect(),
+        };
+
+        let mut results = Vec::new();
+        for use_case in use_cases {
+            let existed = self
+                .test_generator
+                .get_tagged_file_path(use_case, tag)
+                .exists();
+            if let Some(test_file) = self.test_generator.write_tagged(use_case, tag)? {
+                results.push(GeneratedTestFile {
+                    use_case_id: use_case.id.clone(),
+                    test_file,
+                    action: if existed {
+                        TestFileAction::Updated
+                    } else {
+                        TestFileAction::Created
+                    },
+                    new_scenarios: Vec::new(),
+                });
+            }
+        }
+
+        if results.is_empty() {
+            return Err(MucmError::NotFound(format!(
+                "No scenarios are tagged @{}",
+                Scenario::normalize_tag(tag)
+            )));
+        }
+        Ok(results)
+    }
+
+    fn find_use_case_by_id(&self, use_case_id: &str) -> Result<&'a UseCase> {
+        self.use_cases
+            .iter()
+            .find(|uc| uc.id == use_case_id)
+            .ok_or_else(|| {
+                let available_ids: Vec<String> =
+                    self.use_cases.iter().map(|uc| uc.id.clone()).collect();
+                let error_msg = suggest_alternatives(use_case_id, &available_ids, "Use case");
+                MucmError::NotFound(error_msg)
+            })
+    }
+}
+
+/// Expected test symbol for a scenario (matches the language templates)
+pub fn expected_test_name(scenario_id: &str) -> String {
+    format!("test_{}", to_snake_case(scenario_id))
+}
+
+/// Build a scenario title from a test function name, e.g. `test_refund_denied` -> `Refund denied`
+pub fn scenario_title_from_test_name(test_name: &str) -> String {
+    let words = test_name
+        .trim_start_matches("test_")
+        .replace('_', " ")
+        .trim()
+        .to_string();
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => test_name.to_string(),
+    }
+}
+
+/// Extract test names from a generated test file (Rust, Python, or JavaScript)
+fn extract_test_names(content: &str) -> Vec<String> {
+    let function_re =
+        Regex::new(r"(?m)^\s*(?:pub\s+)?(?:async\s+)?(?:fn|def|function)\s+(test_\w+)")
+            .expect("valid test function regex");
+    let js_block_re =
+        Regex::new(r#"(?m)^\s*(?:test|it)\(\s*['"`](.+?)['"`]"#).expect("valid test block regex");
+
+    let mut names = Vec::new();
+    for caps in function_re
+        .captures_iter(content)
+        .chain(js_block_re.captures_iter(content))
+    {
+        let name = caps[1].to_string();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Compare scenarios with test names, returning (scenarios without tests, tests without scenarios)
+///
+/// A scenario with a recorded test name must have a test of that name; its
+/// previous names (deprecation shims) count as belonging to it.
+fn compare(
+    use_case: &UseCase,
+    test_names: &[String],
+    recorded: &TestNameMap,
+    language: &str,
+) -> (Vec<String>, Vec<String>) {
+    let names: HashSet<&str> = test_names.iter().map(|s| s.as_str()).collect();
+    let mut matched: HashSet<&str> = HashSet::new();
+    let mut scenarios_without_tests = Vec::new();
+
+    for scenario in &use_case.scenarios {
+        let found = match recorded.get(language, &scenario.id) {
+            Some(name) => {
+                for previous in &name.previous {
+                    if let Some(shim) = names.get(previous.as_str()) {
+                        matched.insert(*shim);
+                    }
+                }
+                names.get(name.symbol.as_str())
+            }
+            None => {
+                let expected = expected_test_name(&scenario.id);
+                names
+                    .get(expected.as_str())
+                    .or_else(|| names.get(scenario.title.as_str()))
+            }
+        };
+        match found {
+            Some(name) => {
+                matched.insert(*name);
+            }
+            None => scenarios_without_tests.push(scenario.id.clone()),
+        }
+    }
+
+    let tests_without_scenarios = test_names
+        .iter()
+        .filter(|name| !matched.contains(name.as_str()))
+        .cloned()
+        .collect();
+
+    (scenarios_without_tests, tests_without_scenarios)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Scenario, ScenarioType};
+
+    fn create_use_case_with_scenarios(titles: &[&str]) -> UseCase {
+        let mut use_case = UseCase::new(
+            "UC-AUTH-001".to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            "Test description".to_string(),
+            "Medium".to_string(),
+        )
+        .unwrap();
+        for (i, title) in titles.iter().enumerate() {
+            use_case.add_scenario(Scenario::new(
+                format!("UC-AUTH-001-S{:02}", i + 1),
+                title.to_string(),
+                String::new(),
+                ScenarioType::HappyPath,
+            ));
+        }
+        use_case
+    }
+
+    #[test]
+    fn test_extract_rust_and_python_names() {
+        let content = r#"
+    #[test]
+    fn test_uc_auth_001_s01() {}
+    def test_uc_auth_001_s02(self):
+    def setUp(self):
+    fn helper() {}
+"#;
+        assert_eq!(
+            extract_test_names(content),
+            vec!["test_uc_auth_001_s01", "test_uc_auth_001_s02"]
+        );
+    }
+
+    #[test]
+    fn test_extract_javascript_names() {
+        let content = "    test('User logs in', () => {\n    it(\"rejects bad password\", () => {";
+        assert_eq!(
+            extract_test_names(content),
+            vec!["User logs in", "rejects bad password"]
+        );
+    }
+
+    #[test]
+    fn test_compare_reports_both_directions() {
+        let use_case = create_use_case_with_scenarios(&["Happy login", "Bad password"]);
+        let names = vec![
+            "test_uc_auth_001_s01".to_string(),
+            "test_legacy_lockout".to_string(),
+        ];
+
+        let (missing_tests, orphan_tests) =
+            compare(&use_case, &names, &TestNameMap::default(), "rust");
+        assert_eq!(missing_tests, vec!["UC-AUTH-001-S02"]);
+        assert_eq!(orphan_tests, vec!["test_legacy_lockout"]);
+    }
+
+    #[test]
+    fn test_compare_matches_javascript_titles() {
+        let use_case = create_use_case_with_scenarios(&["Happy login"]);
+        let (missing_tests, orphan_tests) = compare(
+            &use_case,
+            &["Happy login".to_string()],
+            &TestNameMap::default(),
+            "javascript",
+        );
+        assert!(missing_tests.is_empty());
+        assert!(orphan_tests.is_empty());
+    }
+
+    #[test]
+    fn test_compare_uses_recorded_names() {
+        let use_case = create_use_case_with_scenarios(&["Happy login"]);
+        let mut recorded = TestNameMap::default();
+        recorded.symbol_for("python", "UC-AUTH-001-S01", || "test_old_name".to_string());
+        recorded
+            .rename("python", "UC-AUTH-001-S01", "test_login".to_string())
+            .unwrap();
+        let names = vec!["test_login".to_string(), "test_old_name".to_string()];
+
+        let (missing_tests, orphan_tests) = compare(&use_case, &names, &recorded, "python");
+        assert!(missing_tests.is_empty());
+        assert!(orphan_tests.is_empty());
+    }
+
+    #[test]
+    fn test_generate_only_missing_skips_modified_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = crate::config::Config::default();
+        config.generation.test_language = "python".to_string();
+        config.directories.test_dir = dir.path().to_string_lossy().into_owned();
+        let generator = TestGenerator::for_project(&crate::config::ProjectContext::current(config));
+
+        let mut use_cases = vec![create_use_case_with_scenarios(&["Happy login"])];
+        let generate = |use_cases: &[UseCase], force| {
+            TestReconciliationService::new(use_cases, &generator)
+                .generate(None, true, force)
+                .unwrap()
+                .remove(0)
+        };
+        assert_eq!(generate(&use_cases, false).action, TestFileAction::Created);
+        assert_eq!(generate(&use_cases, false).action, TestFileAction::UpToDate);
+
+        use_cases[0].add_scenario(Scenario::new(
+            "UC-AUTH-001-S02".to_string(),
+            "Bad password".to_string(),
+            String::new(),
+            ScenarioType::AlternativeFlow,
+        ));
+        let updated = generate(&use_cases, false);
+        assert_eq!(updated.action, TestFileAction::Updated);
+        assert_eq!(updated.new_scenarios, vec!["UC-AUTH-001-S02"]);
+
+        use_cases[0].add_scenario(Scenario::new(
+            "UC-AUTH-001-S03".to_string(),
+            "Locked out".to_string(),
+            String::new(),
+            ScenarioType::AlternativeFlow,
+        ));
+        let content = fs::read_to_string(&updated.test_file).unwrap();
+        fs::write(
+            &updated.test_file,
+            content.replace("pass", "self.client = None"),
+        )
+        .unwrap();
+        assert_eq!(
+            generate(&use_cases, false).action,
+            TestFileAction::SkippedModified
+        );
+        assert_eq!(generate(&use_cases, true).action, TestFileAction::Updated);
+    }
+
+    #[test]
+    fn test_scenario_title_from_test_name() {
+        assert_eq!(
+            scenario_title_from_test_name("test_legacy_lockout"),
+            "Legacy lockout"
+        );
+        assert_eq!(
+            scenario_title_from_test_name("User logs in"),
+            "User logs in"
+        );
+    }
+}
@@ -1,6 +1,33 @@
-use crate::core::utils::suggest_alternatives;
-use crate::core::UseCase;
-use anyhow::Result;
+use crate::config::SavedFilter;
+use crate::core::domain::Priority;
+use crate::core::utils::{field_values, suggest_alternatives};
+use crate::core::MucmError;
+use crate::core::Result;
+use crate::core::{Status, UseCase};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+
+/// Look up a saved filter (`[filters.<name>]`) by name
+pub fn find_saved_filter<'f>(
+    filters: &'f BTreeMap<String, SavedFilter>,
+    name: &str,
+) -> Result<&'f SavedFilter> {
+    filters.get(name).ok_or_else(|| {
+        if filters.is_empty() {
+            MucmError::NotFound(format!(
+                "Filter '{}' not found. Define it under [filters.{}] in mucm.toml.",
+                name, name
+            ))
+        } else {
+            let names: Vec<&str> = filters.keys().map(String::as_str).collect();
+            MucmError::NotFound(format!(
+                "Filter '{}' not found. Available filters: {}",
+                name,
+                names.join(", ")
+            ))
+        }
+    })
+}
 
 /// Service for querying use case data
 ///
@@ -29,42 +56,13 @@ impl<'a> UseCaseQueryService<'a> {
             .find(|s| s.title == scenario_title)
             .map(|s| s.id.clone())
             .ok_or_else(|| {
-                anyhow::anyhow!(
+                MucmError::NotFound(format!(
                     "Scenario with title '{}' not found in use case '{}'",
-                    scenario_title,
-                    use_case_id
-                )
+                    scenario_title, use_case_id
+                ))
             })
     }
 
-    /// Get all use case info that uses a specific persona
-    /// Returns a list of tuples (use_case_id, title, scenario_count) where at least one scenario uses the given persona
-    pub fn get_use_cases_for_persona(
-        &self,
-        persona_id: &str,
-    ) -> Result<Vec<(String, String, usize)>> {
-        let mut matching_use_cases = Vec::new();
-
-        // Scan all loaded use cases for scenarios that use this persona
-        for use_case in self.use_cases {
-            let scenario_count = use_case
-                .scenarios
-                .iter()
-                .filter(|scenario| scenario.persona.as_deref() == Some(persona_id))
-                .count();
-
-            if scenario_count > 0 {
-                matching_use_cases.push((
-                    use_case.id.clone(),
-                    use_case.title.clone(),
-                    scenario_count,
-                ));
-            }
-        }
-
-        Ok(matching_use_cases)
-    }
-
     /// Helper to find a use case index by ID
     pub fn find_use_case_index(&self, use_case_id: &str) -> Result<usize> {
         self.use_cases
@@ -74,10 +72,75 @@ impl<'a> UseCaseQueryService<'a> {
                 let available_ids: Vec<String> =
                     self.use_cases.iter().map(|uc| uc.id.clone()).collect();
                 let error_msg = suggest_alternatives(use_case_id, &available_ids, "Use case");
-                anyhow::anyhow!("{}", error_msg)
+                MucmError::NotFound(error_msg)
             })
     }
 
+    /// Use cases matching every criterion of a saved filter
+    ///
+    /// `today` decides whether a review is due.
+    pub fn filter(&self, filter: &SavedFilter, today: NaiveDate) -> Result<Vec<&'a UseCase>> {
+        let status = filter
+            .status
+            .as_deref()
+            .map(|status| Status::from_str(&status.replace('-', "_")))
+            .transpose()
+            .map_err(MucmError::Validation)?;
+        let priority = filter
+            .priority
+            .as_deref()
+            .map(str::parse::<Priority>)
+            .transpose()
+            .map_err(MucmError::Validation)?;
+        let text = filter.text.as_deref().map(str::to_lowercase);
+
+        let mut matching = Vec::new();
+        for use_case in self.use_cases {
+            if let Some(category) = &filter.category {
+                if !category.eq_ignore_ascii_case(&use_case.category) {
+                    continue;
+                }
+            }
+            if status.is_some_and(|status| use_case.status() != status)
+                || priority
+                    .as_ref()
+                    .is_some_and(|priority| use_case.priority != *priority)
+                || (filter.needs_review && !use_case.metadata.is_review_due(today))
+            {
+                continue;
+            }
+            if let Some(methodology) = &filter.methodology {
+                if !use_case
+                    .views
+                    .iter()
+                    .any(|view| view.methodology.eq_ignore_ascii_case(methodology))
+                {
+                    continue;
+                }
+            }
+            if let Some(text) = &text {
+                if !use_case.title.to_lowercase().contains(text)
+                    && !use_case.description.to_lowercase().contains(text)
+                {
+                    continue;
+                }
+            }
+            if !filter.fields.is_empty() {
+                let data = serde_json::to_value(use_case)?;
+                let fields_match = filter.fields.iter().all(|(path, expected)| {
+                    field_values(&data, path)
+                        .iter()
+                        .any(|value| value.eq_ignore_ascii_case(expected))
+                });
+                if !fields_match {
+                    continue;
+                }
+            }
+            matching.push(use_case);
+        }
+        Ok(matching)
+    }
+
     /// Helper to find a use case by ID (immutable)
     pub fn find_use_case_by_id(&self, use_case_id: &str) -> Result<&UseCase> {
         let index = self.find_use_case_index(use_case_id)?;
@@ -103,6 +166,7 @@ mod tests {
             postconditions: vec![],
             methodology_fields: std::collections::HashMap::new(),
             use_case_references: vec![],
+            adrs: vec![],
             scenarios: vec![],
             extra: std::collections::HashMap::new(),
         }
@@ -121,6 +185,43 @@ mod tests {
         assert!(service.find_use_case_index("UC-999").is_err());
     }
 
+    #[test]
+    fn test_filter_combines_criteria() {
+        let mut billing = create_test_use_case("UC-001", "Pay invoice");
+        billing.category = "Billing".to_string();
+        billing
+            .extra
+            .insert("tags".to_string(), serde_json::json!(["finance", "urgent"]));
+        let mut other = create_test_use_case("UC-002", "Refund invoice");
+        other.category = "Billing".to_string();
+        let use_cases = vec![billing, other, create_test_use_case("UC-003", "Login")];
+        let service = UseCaseQueryService::new(&use_cases);
+        let today = chrono::Utc::now().date_naive();
+
+        let mut filter = SavedFilter {
+            category: Some("billing".to_string()),
+            status: Some("planned".to_string()),
+            ..SavedFilter::default()
+        };
+        let ids: Vec<&str> = service
+            .filter(&filter, today)
+            .unwrap()
+            .iter()
+            .map(|uc| uc.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["UC-001", "UC-002"]);
+
+        filter
+            .fields
+            .insert("tags".to_string(), "Urgent".to_string());
+        assert_eq!(service.filter(&filter, today).unwrap().len(), 1);
+
+        filter.status = Some("in-progress".to_string());
+        assert!(service.filter(&filter, today).unwrap().is_empty());
+        filter.status = Some("unknown".to_string());
+        assert!(service.filter(&filter, today).is_err());
+    }
+
     #[test]
     fn test_find_use_case_by_id() {
         let use_cases = vec![
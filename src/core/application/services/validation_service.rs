@@ -0,0 +1,251 @@
+//! Project validation.
+//!
+//! Checks the loaded use cases for links that point nowhere: references to
+//! use cases that do not exist, ADR files that are missing, views whose
+//! methodology template is not installed, and methodology fields of
+//! methodologies that are not installed. Used by `mucm validate`, which exits
+//! with an error when any issue is found.
+
+use std::path::Path;
+
+use crate::core::utils::suggest_alternatives;
+use crate::core::UseCase;
+
+use super::adr_service::missing_adrs;
+use super::JIRA_FIELDS;
+
+/// A problem found during validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub use_case_id: String,
+    pub message: String,
+    /// How to fix it, e.g. a command to run
+    pub hint: Option<String>,
+}
+
+/// Validate the use cases of the project rooted at `root`, whose installed
+/// templates are in `templates_dir`
+pub(crate) fn validate(
+    use_cases: &[UseCase],
+    root: &Path,
+    templates_dir: &Path,
+) -> Vec<ValidationIssue> {
+    let known_ids: Vec<String> = use_cases.iter().map(|uc| uc.id.clone()).collect();
+    let mut issues = Vec::new();
+
+    for use_case in use_cases {
+        for reference in &use_case.use_case_references {
+            if !reference.is_github_issue() && !known_ids.contains(&reference.target_id) {
+                issues.push(ValidationIssue {
+                    use_case_id: use_case.id.clone(),
+                    message: suggest_alternatives(&reference.target_id, &known_ids, "Use case"),
+                    hint: Some(format!(
+                        "mucm reference remove {} {}",
+                        use_case.id, reference.target_id
+                    )),
+                });
+            }
+        }
+
+        for adr in missing_adrs(use_case, root) {
+            issues.push(ValidationIssue {
+                use_case_id: use_case.id.clone(),
+                message: format!("ADR {} does not exist", adr),
+                hint: Some(format!("mucm reference adr remove {} {}", use_case.id, adr)),
+            });
+        }
+
+        issues.extend(methodology_issues(use_case, root, templates_dir));
+    }
+    issues
+}
+
+/// Views without a template and fields of methodologies that are not installed
+///
+/// Skipped when no methodology templates are installed at all, as before
+/// `mucm init --finalize`.
+fn methodology_issues(
+    use_case: &UseCase,
+    root: &Path,
+    templates_dir: &Path,
+) -> Vec<ValidationIssue> {
+    let methodologies_dir = templates_dir.join("methodologies");
+    if !methodologies_dir.is_dir() {
+        return Vec::new();
+    }
+    let installed = |methodology: &str| {
+        methodologies_dir
+            .join(methodology)
+            .join("methodology.toml")
+            .is_file()
+    };
+    let install_hint = |methodology: &str| {
+        format!(
+            "Install '{}' with mucm interactive (Settings → Add methodologies)",
+            methodology
+        )
+    };
+    let mut issues = Vec::new();
+
+    for view in use_case.enabled_views() {
+        let template = methodologies_dir
+            .join(&view.methodology)
+            .join(format!("uc_{}.hbs", view.level));
+        if template.is_file() {
+            continue;
+        }
+        let (message, hint) = if installed(&view.methodology) {
+            (
+                format!(
+                    "View {}:{} has no template ({} is missing)",
+                    view.methodology,
+                    view.level,
+                    template.strip_prefix(root).unwrap_or(&template).display()
+                ),
+                format!(
+                    "Add the template, or switch to a level listed by mucm methodology-info {}",
+                    view.methodology
+                ),
+            )
+        } else {
+            (
+                format!(
+                    "View {}:{} uses methodology '{}', which is not installed",
+                    view.methodology, view.level, view.methodology
+                ),
+                install_hint(&view.methodology),
+            )
+        };
+        issues.push(ValidationIssue {
+            use_case_id: use_case.id.clone(),
+            message,
+            hint: Some(hint),
+        });
+    }
+
+    let mut methodologies: Vec<&String> = use_case.methodology_fields.keys().collect();
+    methodologies.sort();
+    for methodology in methodologies {
+        if methodology == JIRA_FIELDS || installed(methodology) {
+            continue;
+        }
+        // Cleanup keeps the fields of enabled views, so those need the methodology back
+        let in_use = use_case
+            .enabled_views()
+            .any(|view| view.methodology == *methodology);
+        issues.push(ValidationIssue {
+            use_case_id: use_case.id.clone(),
+            message: format!(
+                "Has fields for methodology '{}', which is not installed",
+                methodology
+            ),
+            hint: Some(if in_use {
+                install_hint(methodology)
+            } else {
+                format!("mucm cleanup {}", use_case.id)
+            }),
+        });
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::UseCaseReference;
+    use crate::core::MethodologyView;
+    use std::collections::HashMap;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_validate_reports_missing_adrs_and_unknown_references() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("docs/adr")).unwrap();
+        fs::write(temp_dir.path().join("docs/adr/0001.md"), "# Use TOML").unwrap();
+
+        let mut use_case = UseCase::new(
+            "UC-PAY-001".to_string(),
+            "Checkout".to_string(),
+            "Payments".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        use_case.add_adr("docs/adr/0001.md");
+        use_case.add_adr("docs/adr/0002.md");
+        use_case.add_reference(UseCaseReference::new(
+            "UC-PAY-009".to_string(),
+            "dependency".to_string(),
+        ));
+
+        let issues = validate(
+            &[use_case],
+            temp_dir.path(),
+            &temp_dir.path().join("templates"),
+        );
+
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("UC-PAY-009"));
+        assert_eq!(issues[1].message, "ADR docs/adr/0002.md does not exist");
+        assert_eq!(
+            issues[1].hint.as_deref(),
+            Some("mucm reference adr remove UC-PAY-001 docs/adr/0002.md")
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_views_and_fields_of_missing_methodologies() {
+        let temp_dir = TempDir::new().unwrap();
+        let templates_dir = temp_dir.path().join(".config/.mucm/template-assets");
+        let feature_dir = templates_dir.join("methodologies/feature");
+        fs::create_dir_all(&feature_dir).unwrap();
+        fs::write(feature_dir.join("methodology.toml"), "").unwrap();
+        fs::write(feature_dir.join("uc_normal.hbs"), "# {{title}}").unwrap();
+
+        let mut use_case = UseCase::new(
+            "UC-PAY-001".to_string(),
+            "Checkout".to_string(),
+            "Payments".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        use_case.add_view(MethodologyView::new("feature", "normal"));
+        use_case.add_view(MethodologyView::new("feature", "advanced"));
+        use_case.add_view(MethodologyView::new("tester", "normal"));
+        for methodology in ["feature", "tester", "business"] {
+            use_case
+                .methodology_fields
+                .insert(methodology.to_string(), HashMap::new());
+        }
+
+        let issues = validate(&[use_case], temp_dir.path(), &templates_dir);
+        let found: Vec<(&str, Option<&str>)> = issues
+            .iter()
+            .map(|issue| (issue.message.as_str(), issue.hint.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    "View feature:advanced has no template \
+                     (.config/.mucm/template-assets/methodologies/feature/uc_advanced.hbs is missing)",
+                    Some("Add the template, or switch to a level listed by mucm methodology-info feature"),
+                ),
+                (
+                    "View tester:normal uses methodology 'tester', which is not installed",
+                    Some("Install 'tester' with mucm interactive (Settings → Add methodologies)"),
+                ),
+                (
+                    "Has fields for methodology 'business', which is not installed",
+                    Some("mucm cleanup UC-PAY-001"),
+                ),
+                (
+                    "Has fields for methodology 'tester', which is not installed",
+                    Some("Install 'tester' with mucm interactive (Settings → Add methodologies)"),
+                ),
+            ]
+        );
+    }
+}
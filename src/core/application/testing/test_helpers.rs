@@ -2,7 +2,7 @@
 
 use crate::config::Config;
 use crate::core::LanguageRegistry;
-use anyhow::Result;
+use crate::core::Result;
 use std::fs;
 use std::path::Path;
 
@@ -35,7 +35,7 @@ use std::path::Path;
 pub fn init_test_project(language: Option<String>) -> Result<Config> {
     let config_dir = Path::new(".config/.mucm");
     if !config_dir.exists() {
-        fs::create_dir_all(&config_dir)?;
+        fs::create_dir_all(config_dir)?;
     }
 
     let mut config = Config::default();
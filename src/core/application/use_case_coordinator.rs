@@ -1,24 +1,44 @@
 // Coordinator for use case operations
 // This orchestrates domain services, manages state, and provides transaction boundaries
 // Controllers (presentation layer) call this coordinator, which delegates to domain services
-use crate::config::Config;
+use crate::config::{Config, ConfigFileManager, OutputFormat, ProjectContext, StorageBackend};
 use crate::core::application::creators::{ScenarioCreator, UseCaseCreator};
 use crate::core::application::generators::{
-    MarkdownGenerator, OutputManager, OverviewGenerator, TestGenerator,
+    ActorDocGenerator, ConfluencePublisher, CsvExporter, CurlConfluenceApi, GherkinGenerator,
+    HtmlExporter, IdLinks, JsonExporter, ManifestGenerator, MarkdownGenerator, MdBookExporter,
+    OutputManager, OverviewGenerator, PdfExporter, ReqifExporter, TestGenerator, TestName,
+    XlsxExporter,
 };
-use crate::core::application::services;
-use crate::core::utils::suggest_alternatives;
+use crate::core::application::services::{
+    self, BatchOperation, BatchProgress, BatchReport, ConflictResolution, EstimateReport,
+    EstimateRequest, ExecutionPlan, GherkinImportReport, GitHubSyncReport, GrepMatch, HealthReport,
+    IdReport, ImportReport, JiraSyncReport, LanguageDetails, MarkdownImportReport, MergeChoice,
+    MergeConflict, PlannedMutation, ReleaseNotes, RtmImportReport, TemplateDraft, TemplatePreview,
+    ValidationIssue,
+};
+use crate::core::utils::{relative_link, start_progress, suggest_alternatives};
+use crate::core::MucmError;
+use crate::core::Result;
 use crate::core::{
-    domain::{Priority, Scenario, ScenarioReference, ScenarioType, UseCaseReference},
-    MethodologyView, RepositoryFactory, TemplateEngine, UseCase, UseCaseRepository,
+    domain::{
+        ActorEntity, ActorRepository, ActorType, Priority, Scenario, ScenarioReference,
+        ScenarioType, UseCaseReference,
+    },
+    AsyncUseCaseRepository, BackupSnapshot, BackupStore, BlockingRepository, EstimationFieldConfig,
+    EventUseCaseRepository, InMemoryActorRepository, InMemoryUseCaseRepository, LoadFailure,
+    MethodologyDefinition, MethodologyView, Project, RepairAction, RepairOutcome,
+    RepositoryFactory, RepositoryTransaction, SyncRepositoryAdapter, TemplateEngine, UseCase,
+    UseCaseRepository,
 };
-use anyhow::Result;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 /// Coordinator that orchestrates use case operations and manages application state
 ///
 /// This coordinator provides a centralized point for:
-/// - State management (use_cases collection, repository, config)
+/// - State management (project aggregate, repository, config)
 /// - Service orchestration (delegates to domain services)
 /// - Transaction boundaries (coordinates multi-service operations)
 /// - Cross-cutting concerns (overview generation, test generation)
@@ -26,43 +46,130 @@ use std::collections::HashMap;
 /// Controllers (presentation layer) are thin adapters that convert CLI/HTTP parameters
 /// into domain types and format results for display.
 pub struct UseCaseCoordinator {
+    context: ProjectContext,
+    /// Configuration with directories resolved against the project root
     config: Config,
-    repository: Box<dyn UseCaseRepository>,
+    repository: Box<dyn UseCaseRepository + Send + Sync>,
     template_engine: TemplateEngine,
-    use_cases: Vec<UseCase>,
+    /// Loaded use cases and actors, with their derived indices
+    project: Project,
+    /// Data that failed to load; those use cases are missing from `project`
+    load_failures: Vec<LoadFailure>,
     use_case_creator: UseCaseCreator,
     scenario_creator: ScenarioCreator,
     markdown_generator: MarkdownGenerator,
     test_generator: TestGenerator,
+    gherkin_generator: GherkinGenerator,
     overview_generator: OverviewGenerator,
+    manifest_generator: ManifestGenerator,
+    actor_doc_generator: ActorDocGenerator,
+    /// Problems found while preparing use cases for saving, until taken
+    save_warnings: Mutex<Vec<String>>,
 }
 
 impl UseCaseCoordinator {
     // ========== Initialization ==========
 
     pub fn new(config: Config) -> Result<Self> {
-        let repository: Box<dyn UseCaseRepository> = RepositoryFactory::create(&config)?;
-        let template_engine = TemplateEngine::with_config(Some(&config));
+        Self::for_project(ProjectContext::current(config))
+    }
+
+    /// Create a coordinator for the project described by `context`.
+    ///
+    /// Data, templates and generated files are all resolved against the
+    /// context's root, so the working directory is never consulted.
+    pub fn for_project(context: ProjectContext) -> Result<Self> {
+        let repository =
+            BlockingRepository::new(RepositoryFactory::create_async(&context.resolved_config())?)
+                .with_project_lock(context.root());
+        Self::with_context_and_repository(context, repository)
+    }
+
+    /// Create a coordinator on top of any async repository (e.g. a remote backend).
+    ///
+    /// The coordinator itself is synchronous and blocks on each repository
+    /// call; [`AsyncUseCaseCoordinator`](crate::core::AsyncUseCaseCoordinator)
+    /// awaits them instead.
+    pub fn with_async_repository(
+        config: Config,
+        repository: Box<dyn AsyncUseCaseRepository>,
+    ) -> Result<Self> {
+        Self::with_context_and_repository(
+            ProjectContext::current(config),
+            BlockingRepository::new(repository),
+        )
+    }
+
+    /// Create a coordinator whose use cases and actors live in memory.
+    ///
+    /// Nothing is read from the project's data directory and generated
+    /// markdown stays in `repository`; overviews, manifests and test files are
+    /// still written under the context's root.
+    pub fn in_memory(
+        context: ProjectContext,
+        repository: InMemoryUseCaseRepository,
+        actors: &InMemoryActorRepository,
+    ) -> Result<Self> {
+        let actors = actors.load_all_actors()?;
+        Self::with_parts(
+            context,
+            BlockingRepository::new(Box::new(SyncRepositoryAdapter::new(repository))),
+            actors,
+        )
+    }
+
+    fn with_context_and_repository(
+        context: ProjectContext,
+        repository: BlockingRepository,
+    ) -> Result<Self> {
+        // Actors are optional: a project without an actor directory has none
+        let actors = RepositoryFactory::create_actor_repository(&context.resolved_config())
+            .and_then(|actors| actors.load_all_actors())
+            .unwrap_or_default();
+        Self::with_parts(context, repository, actors)
+    }
+
+    fn with_parts(
+        context: ProjectContext,
+        repository: BlockingRepository,
+        actors: Vec<ActorEntity>,
+    ) -> Result<Self> {
+        let repository: Box<dyn UseCaseRepository + Send + Sync> = Box::new(repository);
+        let config = context.resolved_config();
+        let template_engine = TemplateEngine::for_project(&context);
 
         // Initialize creator and generators
-        let use_case_creator = UseCaseCreator::new(config.clone());
+        let use_case_creator =
+            UseCaseCreator::new(config.clone()).with_templates_dir(context.templates_dir());
         let scenario_creator = ScenarioCreator::new();
-        let markdown_generator = MarkdownGenerator::new(config.clone());
-        let test_generator = TestGenerator::new(config.clone());
-        let overview_generator = OverviewGenerator::new(config.clone());
-
-        let use_cases = repository.load_all()?;
+        let markdown_generator = MarkdownGenerator::for_project(&context);
+        let test_generator = TestGenerator::for_project(&context);
+        let gherkin_generator = GherkinGenerator::for_project(&context);
+        let overview_generator = OverviewGenerator::for_project(&context);
+        let manifest_generator = ManifestGenerator::for_project(&context);
+        let actor_doc_generator = ActorDocGenerator::for_project(&context);
+
+        let mut report = repository.load_all_with_report()?;
+        for use_case in &mut report.use_cases {
+            use_case.sort_views(&config.templates.view_order);
+        }
 
         Ok(Self {
+            context,
             config,
             repository,
             template_engine,
-            use_cases,
+            project: Project::new(report.use_cases).with_actors(actors),
+            load_failures: report.failures,
             use_case_creator,
             scenario_creator,
             markdown_generator,
             test_generator,
+            gherkin_generator,
             overview_generator,
+            manifest_generator,
+            actor_doc_generator,
+            save_warnings: Mutex::new(Vec::new()),
         })
     }
 
@@ -75,7 +182,27 @@ impl UseCaseCoordinator {
 
     /// Get all use cases (for display)
     pub fn get_all_use_cases(&self) -> &[UseCase] {
-        &self.use_cases
+        self.project.use_cases()
+    }
+
+    /// The loaded project: use cases, actors, and the lookups derived from them
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+
+    /// The project configuration
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Data that failed to load when the project was opened
+    pub fn load_failures(&self) -> &[LoadFailure] {
+        &self.load_failures
+    }
+
+    /// Warnings collected while saving since the last call, oldest first
+    pub fn take_save_warnings(&self) -> Vec<String> {
+        std::mem::take(&mut *self.save_warnings.lock().unwrap_or_else(|e| e.into_inner()))
     }
 
     /// Find scenario ID by its title within a use case
@@ -84,7 +211,7 @@ impl UseCaseCoordinator {
         use_case_id: &str,
         scenario_title: &str,
     ) -> Result<String> {
-        let query_service = services::UseCaseQueryService::new(&self.use_cases);
+        let query_service = services::UseCaseQueryService::new(self.project.use_cases());
         query_service.find_scenario_id_by_title(use_case_id, scenario_title)
     }
 
@@ -94,8 +221,69 @@ impl UseCaseCoordinator {
         &self,
         persona_id: &str,
     ) -> Result<Vec<(String, String, usize)>> {
-        let query_service = services::UseCaseQueryService::new(&self.use_cases);
-        query_service.get_use_cases_for_persona(persona_id)
+        Ok(self
+            .project
+            .for_persona(persona_id)
+            .into_iter()
+            .map(|use_case| {
+                let scenario_count = use_case
+                    .scenarios
+                    .iter()
+                    .filter(|scenario| scenario.persona.as_deref() == Some(persona_id))
+                    .count();
+                (use_case.id.clone(), use_case.title.clone(), scenario_count)
+            })
+            .collect())
+    }
+
+    /// Use cases matching the saved filter `[filters.<name>]`
+    pub fn filter_use_cases(&self, name: &str) -> Result<Vec<&UseCase>> {
+        let filter = services::find_saved_filter(&self.config.filters, name)?;
+        services::UseCaseQueryService::new(self.project.use_cases())
+            .filter(filter, chrono::Utc::now().date_naive())
+    }
+
+    /// Use cases as they were at `cutoff`, replayed from the event log
+    pub fn use_cases_at(&self, cutoff: DateTime<Utc>) -> Result<Vec<UseCase>> {
+        if self.config.storage.backend != StorageBackend::Events {
+            return Err(MucmError::Validation(
+                "Time travel replays the event log and needs storage.backend = \"events\""
+                    .to_string(),
+            ));
+        }
+        let mut use_cases = EventUseCaseRepository::new(self.config.clone()).load_at(cutoff)?;
+        for use_case in &mut use_cases {
+            use_case.sort_views(&self.config.templates.view_order);
+        }
+        Ok(use_cases)
+    }
+
+    /// Undo the latest change recorded in the event log and regenerate the
+    /// documents of the use cases it touched
+    ///
+    /// Returns the IDs of those use cases, or `None` when nothing is left to undo.
+    pub fn undo(&mut self) -> Result<Option<Vec<String>>> {
+        if self.config.storage.backend != StorageBackend::Events {
+            return Err(MucmError::Validation(
+                "Undo appends to the event log and needs storage.backend = \"events\"".to_string(),
+            ));
+        }
+        let Some(ids) = EventUseCaseRepository::new(self.config.clone()).undo()? else {
+            return Ok(None);
+        };
+        let mut use_cases = self.repository.load_all()?;
+        for use_case in &mut use_cases {
+            use_case.sort_views(&self.config.templates.view_order);
+        }
+        self.project.set_use_cases(use_cases);
+        let restored: Vec<String> = ids
+            .iter()
+            .filter(|id| self.project.use_cases().iter().any(|uc| &uc.id == *id))
+            .cloned()
+            .collect();
+        self.render_use_cases(&restored)?;
+        self.generate_overview()?;
+        Ok(Some(ids))
     }
 
     // ========== Use Case Creation ==========
@@ -111,11 +299,10 @@ impl UseCaseCoordinator {
         // Validate methodology exists
         let available_methodologies = self.template_engine.available_methodologies();
         if !available_methodologies.contains(&methodology.to_string()) {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Unknown methodology '{}'. Available: {:?}",
-                methodology,
-                available_methodologies
-            ));
+                methodology, available_methodologies
+            )));
         }
 
         // Create use case with methodology fields
@@ -129,7 +316,7 @@ impl UseCaseCoordinator {
 
         // Save and generate markdown
         self.save_use_case_with_views(&use_case)?;
-        self.use_cases.push(use_case);
+        self.project.add_use_case(use_case);
         self.generate_overview()?;
 
         Ok(use_case_id)
@@ -152,29 +339,7 @@ impl UseCaseCoordinator {
         description: Option<String>,
         views: &str,
     ) -> Result<String> {
-        // Parse views string into MethodologyView objects
-        let view_list: Vec<MethodologyView> = views
-            .split(',')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .map(|view_str| {
-                let parts: Vec<&str> = view_str.split(':').collect();
-                if parts.len() != 2 {
-                    anyhow::bail!(
-                        "Invalid view format '{}'. Expected 'methodology:level'",
-                        view_str
-                    );
-                }
-                Ok(MethodologyView::new(
-                    parts[0].to_string(),
-                    parts[1].to_string(),
-                ))
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        if view_list.is_empty() {
-            return Err(anyhow::anyhow!("At least one view must be specified"));
-        }
+        let view_list = Self::parse_views(views)?;
 
         // Use the new create_use_case_with_views method with empty user fields
         let use_case = self.use_case_creator.create_use_case_with_views(
@@ -184,7 +349,7 @@ impl UseCaseCoordinator {
             "Medium".to_string(), // Default priority for create_use_case_with_views
             view_list,
             HashMap::new(), // No user fields provided
-            &self.use_cases,
+            self.project.use_cases(),
             self.repository.as_ref(),
         )?;
 
@@ -192,7 +357,7 @@ impl UseCaseCoordinator {
 
         // Save and generate markdown for all views (multi-view mode)
         self.save_use_case_with_views(&use_case)?;
-        self.use_cases.push(use_case);
+        self.project.add_use_case(use_case);
         self.generate_overview()?;
 
         Ok(use_case_id)
@@ -218,29 +383,7 @@ impl UseCaseCoordinator {
         views: &str,
         extra_fields: std::collections::HashMap<String, String>,
     ) -> Result<String> {
-        // Parse views string into MethodologyView objects
-        let view_list: Vec<MethodologyView> = views
-            .split(',')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .map(|view_str| {
-                let parts: Vec<&str> = view_str.split(':').collect();
-                if parts.len() != 2 {
-                    anyhow::bail!(
-                        "Invalid view format '{}'. Expected 'methodology:level'",
-                        view_str
-                    );
-                }
-                Ok(MethodologyView::new(
-                    parts[0].to_string(),
-                    parts[1].to_string(),
-                ))
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        if view_list.is_empty() {
-            return Err(anyhow::anyhow!("At least one view must be specified"));
-        }
+        let view_list = Self::parse_views(views)?;
 
         // Use the new create_use_case_with_views method that properly handles methodology_fields
         let use_case = self.use_case_creator.create_use_case_with_views(
@@ -250,7 +393,7 @@ impl UseCaseCoordinator {
             priority,
             view_list,
             extra_fields,
-            &self.use_cases,
+            self.project.use_cases(),
             self.repository.as_ref(),
         )?;
 
@@ -258,7 +401,7 @@ impl UseCaseCoordinator {
 
         // Save and generate markdown for all views (multi-view mode)
         self.save_use_case_with_views(&use_case)?;
-        self.use_cases.push(use_case);
+        self.project.add_use_case(use_case);
         self.generate_overview()?;
 
         Ok(use_case_id)
@@ -276,11 +419,10 @@ impl UseCaseCoordinator {
         // Validate methodology exists
         let available_methodologies = self.template_engine.available_methodologies();
         if !available_methodologies.contains(&methodology.to_string()) {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Unknown methodology '{}'. Available: {:?}",
-                methodology,
-                available_methodologies
-            ));
+                methodology, available_methodologies
+            )));
         }
 
         // Create use case with custom fields
@@ -295,12 +437,67 @@ impl UseCaseCoordinator {
 
         // Save and generate markdown
         self.save_use_case_with_views(&use_case)?;
-        self.use_cases.push(use_case);
+        self.project.add_use_case(use_case);
         self.generate_overview()?;
 
         Ok(use_case_id)
     }
 
+    /// Parse comma-separated methodology:level pairs (e.g. "feature:simple,business:normal")
+    pub(crate) fn parse_views(views: &str) -> Result<Vec<MethodologyView>> {
+        let view_list: Vec<MethodologyView> = views
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|view_str| {
+                let parts: Vec<&str> = view_str.split(':').collect();
+                if parts.len() != 2 {
+                    return Err(MucmError::Validation(format!(
+                        "Invalid view format '{}'. Expected 'methodology:level'",
+                        view_str
+                    )));
+                }
+                Ok(MethodologyView::new(
+                    parts[0].to_string(),
+                    parts[1].to_string(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if view_list.is_empty() {
+            return Err(MucmError::Validation(
+                "At least one view must be specified".to_string(),
+            ));
+        }
+        Ok(view_list)
+    }
+
+    // ========== Execution Plans ==========
+
+    /// Plan the effects of a mutation without performing it
+    ///
+    /// # Arguments
+    /// * `command` - Command name recorded in the plan
+    /// * `mutation` - The mutation to plan
+    pub fn plan(&self, command: &str, mutation: &PlannedMutation) -> Result<ExecutionPlan> {
+        services::PlanService::new(self.project.use_cases(), &self.config, &self.test_generator)
+            .plan(command, mutation)
+    }
+
+    /// Plan the creation of a use case in `category` with the given views
+    pub fn plan_create(&self, command: &str, category: &str, views: &str) -> Result<ExecutionPlan> {
+        let mutation = PlannedMutation::Create {
+            use_case_id: self
+                .use_case_creator
+                .next_use_case_id(category, self.project.use_cases())?,
+            category: category.to_string(),
+            views: self
+                .use_case_creator
+                .with_lifecycle_views(Self::parse_views(views)?),
+        };
+        self.plan(command, &mutation)
+    }
+
     // ========== Regeneration Operations ==========
 
     /// Regenerate use case with different methodology
@@ -309,56 +506,400 @@ impl UseCaseCoordinator {
         use_case_id: &str,
         methodology: &str,
     ) -> Result<()> {
+        let links = IdLinks::for_project(&self.config, self.project.use_cases())?;
         let regen_service = services::MarkdownRegenerationService::new(
-            &self.repository,
-            &self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases(),
             &self.markdown_generator,
             &self.template_engine,
+            &links,
         );
         regen_service.regenerate_use_case_with_methodology(use_case_id, methodology)
     }
 
     /// Regenerate markdown for a single use case
     pub fn regenerate_markdown(&self, use_case_id: &str) -> Result<()> {
+        let links = IdLinks::for_project(&self.config, self.project.use_cases())?;
         let regen_service = services::MarkdownRegenerationService::new(
-            &self.repository,
-            &self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases(),
             &self.markdown_generator,
             &self.template_engine,
+            &links,
         );
         regen_service.regenerate_markdown(use_case_id)
     }
 
+    /// Regenerate the file of one view (`methodology:level`) of a use case,
+    /// leaving its other views and the overview untouched
+    pub fn regenerate_view(&self, use_case_id: &str, view: &str) -> Result<String> {
+        let view = match Self::parse_views(view)?.as_slice() {
+            [view] => view.clone(),
+            _ => {
+                return Err(MucmError::Validation(format!(
+                    "Expected a single view 'methodology:level', got '{}'",
+                    view
+                )))
+            }
+        };
+        let links = IdLinks::for_project(&self.config, self.project.use_cases())?;
+        let regen_service = services::MarkdownRegenerationService::new(
+            self.repository.as_ref(),
+            self.project.use_cases(),
+            &self.markdown_generator,
+            &self.template_engine,
+            &links,
+        );
+        regen_service.regenerate_view(use_case_id, &view)
+    }
+
+    /// Generate the named overviews `names` (`overviews/<name>.hbs`), each into its own file
+    pub fn generate_named_overviews(&self, names: &[String]) -> Result<Vec<PathBuf>> {
+        names
+            .iter()
+            .map(|name| {
+                self.overview_generator.generate_named(
+                    self.project.use_cases(),
+                    self.project.actors(),
+                    name,
+                )
+            })
+            .collect()
+    }
+
     /// Regenerate markdown for all use cases
     pub fn regenerate_all_markdown(&self) -> Result<()> {
         // Load all use cases from TOML (source of truth)
         let use_cases = self.repository.load_all()?;
+        let links = IdLinks::for_project(&self.config, &use_cases)?;
+        let progress = start_progress(use_cases.len() as u64, "Regenerating");
 
         for use_case in &use_cases {
             // Generate markdown for each enabled view
-            for view in use_case.enabled_views() {
-                let markdown_content =
-                    self.markdown_generator
-                        .generate(use_case, None, Some(&view))?;
-                let filename = format!("{}-{}-{}.md", use_case.id, view.methodology, view.level);
-                self.repository.save_markdown_with_filename(
+            for (filename, view) in OutputManager::generate_all_filenames(
+                use_case,
+                self.config.generation.output_format,
+            ) {
+                self.repository.write_markdown_with_filename(
                     use_case,
                     &filename,
-                    &markdown_content,
+                    &mut |writer| {
+                        self.markdown_generator.generate_linked_to(
+                            use_case,
+                            None,
+                            Some(&view),
+                            &links,
+                            writer,
+                        )
+                    },
                 )?;
             }
+            if self.gherkin_generator.settings().enabled {
+                self.gherkin_generator.generate(use_case)?;
+            }
+            progress.inc();
         }
 
+        progress.finish();
         self.generate_overview()?;
+        self.actor_doc_generator
+            .generate(self.project.actors(), &use_cases)?;
+        Ok(())
+    }
+
+    /// Regenerate only the JSON manifest
+    ///
+    /// # Returns
+    /// The path of the written manifest file
+    pub fn generate_manifest(&self) -> Result<std::path::PathBuf> {
+        self.manifest_generator.generate(self.project.use_cases())?;
+        Ok(std::path::Path::new(&self.config.directories.use_case_dir).join("mucm-manifest.json"))
+    }
+
+    /// Exports convert the generated markdown, so they need markdown output
+    fn ensure_markdown_output(&self, export: &str) -> Result<()> {
+        if self.config.generation.output_format != OutputFormat::Markdown {
+            return Err(MucmError::Validation(format!(
+                "{} reads the generated markdown; set generation.output_format = \"markdown\"",
+                export
+            )));
+        }
         Ok(())
     }
 
+    /// The use cases with a scenario tagged `tag`, holding only those scenarios
+    ///
+    /// All use cases, unchanged, when `tag` is `None`.
+    fn select_tagged(use_cases: Vec<UseCase>, tag: Option<&str>) -> Result<Vec<UseCase>> {
+        let Some(tag) = tag else {
+            return Ok(use_cases);
+        };
+        let tagged: Vec<UseCase> = use_cases
+            .into_iter()
+            .filter_map(|mut use_case| {
+                use_case.scenarios.retain(|scenario| scenario.has_tag(tag));
+                (!use_case.scenarios.is_empty()).then_some(use_case)
+            })
+            .collect();
+        if tagged.is_empty() {
+            return Err(MucmError::NotFound(format!(
+                "No scenarios are tagged @{}",
+                Scenario::normalize_tag(tag)
+            )));
+        }
+        Ok(tagged)
+    }
+
+    /// Regenerate the markdown documentation and export it as HTML pages
+    ///
+    /// `output` is resolved against the project root.
+    ///
+    /// # Returns
+    /// The output directory and the number of pages written
+    pub fn export_html(&self, output: &str) -> Result<(PathBuf, usize)> {
+        self.ensure_markdown_output("HTML export")?;
+        self.regenerate_all_markdown()?;
+        let use_cases = self.repository.load_all()?;
+        let output = self.context.resolve(output);
+        let pages = HtmlExporter::for_project(&self.context).export(
+            &use_cases,
+            self.project.actors(),
+            &output,
+        )?;
+        Ok((output, pages))
+    }
+
+    /// Regenerate the markdown documentation and compile it into a PDF
+    ///
+    /// Covers one use case, or all of them when `use_case_id` is `None`; with
+    /// `tag`, only the use cases with a scenario carrying it (their documents
+    /// are included whole). `output` is resolved against the project root.
+    ///
+    /// # Returns
+    /// The path of the PDF and the number of use cases it contains
+    pub fn export_pdf(
+        &self,
+        use_case_id: Option<&str>,
+        output: &str,
+        tag: Option<&str>,
+    ) -> Result<(PathBuf, usize)> {
+        self.ensure_markdown_output("PDF export")?;
+        self.regenerate_all_markdown()?;
+        let mut use_cases = Self::select_tagged(self.repository.load_all()?, tag)?;
+        if let Some(id) = use_case_id {
+            use_cases.retain(|use_case| use_case.id == id);
+            if use_cases.is_empty() {
+                return Err(MucmError::NotFound(format!("Use case {} not found", id)));
+            }
+        } else if use_cases.is_empty() {
+            return Err(MucmError::NotFound(
+                "No use cases to export; create one with `mucm create`".to_string(),
+            ));
+        }
+        let output = self.context.resolve(output);
+        PdfExporter::for_project(&self.context).export(&use_cases, &output)?;
+        Ok((output, use_cases.len()))
+    }
+
+    /// Export the use cases as a CSV matrix, one row per use case
+    ///
+    /// With `tag`, only the scenarios carrying it and their use cases are
+    /// exported. `output` is resolved against the project root.
+    ///
+    /// # Returns
+    /// The path of the file and the number of use cases it contains
+    pub fn export_csv(&self, output: &str, tag: Option<&str>) -> Result<(PathBuf, usize)> {
+        let use_cases = Self::select_tagged(self.project.use_cases().to_vec(), tag)?;
+        let output = self.context.resolve(output);
+        CsvExporter::export(&use_cases, &output)?;
+        Ok((output, use_cases.len()))
+    }
+
+    /// Export the use cases to an Excel workbook
+    ///
+    /// The workbook has a summary sheet and one sheet per category. With
+    /// `tag`, only the scenarios carrying it and their use cases are exported.
+    /// `output` is resolved against the project root.
+    ///
+    /// # Returns
+    /// The path of the workbook and the number of use cases it contains
+    pub fn export_xlsx(&self, output: &str, tag: Option<&str>) -> Result<(PathBuf, usize)> {
+        let use_cases = Self::select_tagged(self.project.use_cases().to_vec(), tag)?;
+        let output = self.context.resolve(output);
+        XlsxExporter::export(&use_cases, &output)?;
+        Ok((output, use_cases.len()))
+    }
+
+    /// Regenerate the markdown documentation and make it an mdBook
+    ///
+    /// `SUMMARY.md` is written into the use case directory, the source of the
+    /// book, and `book.toml` into `output` (default: the parent of the use
+    /// case directory) unless it exists. `output` is resolved against the
+    /// project root and must contain the use case directory.
+    ///
+    /// # Returns
+    /// The book directory, the number of use cases, and whether `book.toml` was written
+    pub fn export_mdbook(&self, output: Option<&str>) -> Result<(PathBuf, usize, bool)> {
+        self.ensure_markdown_output("mdBook export")?;
+        let use_case_dir = self.config.directories.use_case_dir.clone();
+        let book = match output {
+            Some(output) => output.to_string(),
+            None => Path::new(&use_case_dir)
+                .parent()
+                .map(|parent| parent.to_string_lossy().to_string())
+                .unwrap_or_default(),
+        };
+        let src = relative_link(&book, &use_case_dir);
+        if src.is_empty() || src.starts_with("..") {
+            return Err(MucmError::Validation(format!(
+                "The book directory must contain the use case directory ({}); pick one of its parents",
+                use_case_dir
+            )));
+        }
+
+        self.regenerate_all_markdown()?;
+        self.generate_overview()?;
+        let book_dir = self.context.resolve(&book);
+        let created = MdBookExporter::for_project(&self.context).export(
+            self.project.use_cases(),
+            &book_dir,
+            &self.context.resolve(&use_case_dir),
+            &src,
+        )?;
+        Ok((book_dir, self.project.use_cases().len(), created))
+    }
+
+    /// Export the use cases to a ReqIF document for requirements tools
+    ///
+    /// With `tag`, only the scenarios carrying it and their use cases are
+    /// exported. `output` is resolved against the project root.
+    ///
+    /// # Returns
+    /// The path of the document and the number of use cases it contains
+    pub fn export_reqif(&self, output: &str, tag: Option<&str>) -> Result<(PathBuf, usize)> {
+        let use_cases = Self::select_tagged(self.project.use_cases().to_vec(), tag)?;
+        let output = self.context.resolve(output);
+        ReqifExporter::export(&use_cases, &self.config.project.name, &output)?;
+        Ok((output, use_cases.len()))
+    }
+
+    /// Export every use case, persona and actor to one JSON document
+    ///
+    /// With `tag`, only the scenarios carrying it and their use cases are
+    /// exported. `output` is resolved against the project root.
+    ///
+    /// # Returns
+    /// The path of the document and the number of use cases it contains
+    pub fn export_json(&self, output: &str, tag: Option<&str>) -> Result<(PathBuf, usize)> {
+        let use_cases = Self::select_tagged(self.project.use_cases().to_vec(), tag)?;
+        let personas = RepositoryFactory::create_persona_repository(&self.config)
+            .and_then(|personas| personas.load_all())
+            .unwrap_or_default();
+        let output = self.context.resolve(output);
+        JsonExporter::for_project(&self.context).export(
+            &use_cases,
+            &personas,
+            self.project.actors(),
+            &output,
+        )?;
+        Ok((output, use_cases.len()))
+    }
+
+    /// Regenerate the markdown documentation and publish it to Confluence
+    ///
+    /// The page ID of each use case is stored in its metadata, so the next
+    /// publish updates the same page.
+    ///
+    /// # Returns
+    /// The number of pages created and updated
+    pub fn publish_confluence(&mut self) -> Result<(usize, usize)> {
+        self.ensure_markdown_output("Confluence publishing")?;
+        let api = CurlConfluenceApi::new(&self.config.confluence)?;
+        self.regenerate_all_markdown()?;
+        let use_cases = self.repository.load_all()?;
+        if use_cases.is_empty() {
+            return Err(MucmError::NotFound(
+                "No use cases to publish; create one with `mucm create`".to_string(),
+            ));
+        }
+
+        let published =
+            ConfluencePublisher::for_project(&self.context, &api).publish(&use_cases)?;
+        let created = published.iter().filter(|page| page.created).count();
+        for page in published {
+            let index = self.find_use_case_index(&page.use_case_id)?;
+            let use_case = &mut self.project.use_cases_mut()[index];
+            if use_case.metadata.confluence_page_id.as_deref() != Some(page.page_id.as_str()) {
+                use_case.metadata.confluence_page_id = Some(page.page_id);
+                self.repository.save(use_case)?;
+            }
+        }
+        Ok((created, use_cases.len() - created))
+    }
+
+    /// Push every use case to its Jira issue and pull the issue statuses back
+    ///
+    /// Issues are created for use cases without one and linked for use case
+    /// references. Issue keys and statuses are stored in the `jira`
+    /// methodology fields; use cases whose status changed are regenerated.
+    pub fn sync_jira(&mut self) -> Result<JiraSyncReport> {
+        let api = services::CurlJiraApi::new(&self.config.jira)?;
+        let mut use_cases = self.project.use_cases().to_vec();
+        if use_cases.is_empty() {
+            return Err(MucmError::NotFound(
+                "No use cases to sync; create one with `mucm create`".to_string(),
+            ));
+        }
+
+        let (report, changed) = services::sync_jira(&mut use_cases, &self.config.jira, &api)?;
+        let mut transaction = RepositoryTransaction::new();
+        for index in changed {
+            transaction.save(use_cases[index].clone());
+        }
+        if !transaction.is_empty() {
+            self.commit_transaction(transaction)?;
+            self.project.set_use_cases(use_cases);
+            self.generate_overview()?;
+        }
+        Ok(report)
+    }
+
+    /// Push every use case to its GitHub issue, pulling the issue states back when `pull_status`
+    ///
+    /// Issues are created for use cases without one and recorded as
+    /// `github_issue` references; use cases whose reference or status changed
+    /// are regenerated.
+    pub fn sync_github(&mut self, pull_status: bool) -> Result<GitHubSyncReport> {
+        let api = services::CurlGitHubApi::new(&self.config.github)?;
+        let mut use_cases = self.project.use_cases().to_vec();
+        if use_cases.is_empty() {
+            return Err(MucmError::NotFound(
+                "No use cases to sync; create one with `mucm create`".to_string(),
+            ));
+        }
+
+        let (report, changed) =
+            services::sync_github(&mut use_cases, &self.config.github, pull_status, &api)?;
+        let mut transaction = RepositoryTransaction::new();
+        for index in changed {
+            transaction.save(use_cases[index].clone());
+        }
+        if !transaction.is_empty() {
+            self.commit_transaction(transaction)?;
+            self.project.set_use_cases(use_cases);
+            self.generate_overview()?;
+        }
+        Ok(report)
+    }
+
     // ========== Field Management Methods ==========
 
     /// Add a precondition to a use case
     pub fn add_precondition(&mut self, use_case_id: &str, precondition: String) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.add_precondition(use_case_id, precondition)
     }
 
@@ -374,15 +915,19 @@ impl UseCaseCoordinator {
 
     /// Remove a precondition from a use case
     pub fn remove_precondition(&mut self, use_case_id: &str, index: usize) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.remove_precondition(use_case_id, index)
     }
 
     /// Add a postcondition to a use case
     pub fn add_postcondition(&mut self, use_case_id: &str, postcondition: String) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.add_postcondition(use_case_id, postcondition)
     }
 
@@ -398,8 +943,10 @@ impl UseCaseCoordinator {
 
     /// Remove a postcondition from a use case
     pub fn remove_postcondition(&mut self, use_case_id: &str, index: usize) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.remove_postcondition(use_case_id, index)
     }
 
@@ -410,8 +957,10 @@ impl UseCaseCoordinator {
         index: usize,
         new_text: String,
     ) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.edit_precondition(use_case_id, index, new_text)
     }
 
@@ -422,8 +971,10 @@ impl UseCaseCoordinator {
         index: usize,
         new_text: String,
     ) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.edit_postcondition(use_case_id, index, new_text)
     }
 
@@ -434,8 +985,10 @@ impl UseCaseCoordinator {
         from_index: usize,
         to_index: usize,
     ) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.reorder_preconditions(use_case_id, from_index, to_index)
     }
 
@@ -446,22 +999,28 @@ impl UseCaseCoordinator {
         from_index: usize,
         to_index: usize,
     ) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.reorder_postconditions(use_case_id, from_index, to_index)
     }
 
     /// Clear all preconditions from a use case
     pub fn clear_preconditions(&mut self, use_case_id: &str) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.clear_preconditions(use_case_id)
     }
 
     /// Clear all postconditions from a use case
     pub fn clear_postconditions(&mut self, use_case_id: &str) -> Result<()> {
-        let mut service =
-            services::PreconditionPostconditionService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::PreconditionPostconditionService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.clear_postconditions(use_case_id)
     }
 
@@ -473,27 +1032,228 @@ impl UseCaseCoordinator {
         relationship: String,
         description: Option<String>,
     ) -> Result<()> {
-        let mut service =
-            services::ReferenceManagementService::new(&self.repository, &mut self.use_cases);
+        let mut service = services::ReferenceManagementService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
         service.add_reference(use_case_id, target_id, relationship, description)
     }
 
-    /// Get all references for a use case
-    pub fn get_references(&self, use_case_id: &str) -> Result<Vec<UseCaseReference>> {
-        let use_case = self.find_use_case_by_id(use_case_id)?;
-        Ok(use_case.use_case_references.clone())
+    /// Link an architecture decision record to a use case
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to link
+    /// * `path` - ADR file, relative to the project root or absolute
+    ///
+    /// # Returns
+    /// The stored (project-relative) path and whether the file exists
+    pub fn add_adr(&mut self, use_case_id: &str, path: &str) -> Result<(String, bool)> {
+        let index = self.find_use_case_index(use_case_id)?;
+        let path = services::normalize_adr_path(self.context.root(), path)?;
+        let mut use_case = self.project.use_cases()[index].clone();
+        if !use_case.add_adr(&path) {
+            return Err(MucmError::Conflict(format!(
+                "{} already links {}",
+                use_case_id, path
+            )));
+        }
+        self.save_use_case_with_views(&use_case)?;
+        self.project.use_cases_mut()[index] = use_case;
+        let exists = self.context.root().join(&path).is_file();
+        Ok((path, exists))
     }
 
-    /// Remove a reference from a use case
-    pub fn remove_reference(&mut self, use_case_id: &str, target_id: &str) -> Result<()> {
-        let mut service =
-            services::ReferenceManagementService::new(&self.repository, &mut self.use_cases);
-        service.remove_reference(use_case_id, target_id)
-    }
+    /// Unlink an architecture decision record from a use case
+    pub fn remove_adr(&mut self, use_case_id: &str, path: &str) -> Result<()> {
+        let index = self.find_use_case_index(use_case_id)?;
+        let path = services::normalize_adr_path(self.context.root(), path)?;
+        let mut use_case = self.project.use_cases()[index].clone();
+        if !use_case.remove_adr(&path) {
+            return Err(MucmError::NotFound(format!(
+                "{} does not link {}",
+                use_case_id, path
+            )));
+        }
+        self.save_use_case_with_views(&use_case)?;
+        self.project.use_cases_mut()[index] = use_case;
+        Ok(())
+    }
+
+    /// ADRs linked to a use case, with whether each file exists
+    pub fn get_adrs(&self, use_case_id: &str) -> Result<Vec<(String, bool)>> {
+        let use_case = self.find_use_case_by_id(use_case_id)?;
+        Ok(use_case
+            .adrs
+            .iter()
+            .map(|adr| (adr.clone(), self.context.root().join(adr).is_file()))
+            .collect())
+    }
+
+    /// Check the project for references and ADR links that point nowhere,
+    /// and for views and methodology fields of methodologies not installed
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        services::validate(
+            self.project.use_cases(),
+            self.context.root(),
+            &self.context.templates_dir(),
+        )
+    }
+
+    /// Validate one use case, e.g. the unsaved text of its data file, against
+    /// the rest of the project
+    pub fn validate_use_case(&self, use_case: &UseCase) -> Vec<ValidationIssue> {
+        let mut use_cases: Vec<UseCase> = self
+            .project
+            .use_cases()
+            .iter()
+            .filter(|uc| uc.id != use_case.id)
+            .cloned()
+            .collect();
+        use_cases.push(use_case.clone());
+        services::validate(
+            &use_cases,
+            self.context.root(),
+            &self.context.templates_dir(),
+        )
+        .into_iter()
+        .filter(|issue| issue.use_case_id == use_case.id)
+        .collect()
+    }
+
+    /// Details of the test languages, with the project's template copies and generated tests
+    pub fn language_details(&self) -> Result<Vec<LanguageDetails>> {
+        let templates_dir = Config::get_metadata_load_dir()?;
+        services::language_details(
+            &templates_dir,
+            Some(&self.context.templates_dir()),
+            |extension| {
+                self.project
+                    .use_cases()
+                    .iter()
+                    .filter(|uc| {
+                        self.test_generator
+                            .file_path_with_extension(uc, extension)
+                            .is_file()
+                    })
+                    .map(|uc| uc.id.clone())
+                    .collect()
+            },
+        )
+    }
+
+    /// The use case template of a methodology level, for customization
+    ///
+    /// The draft holds the project version of the template when there is one,
+    /// else the installed template, and the path the project version is saved to.
+    pub fn template_draft(&self, methodology: &str, level: &str) -> Result<TemplateDraft> {
+        let file_name = format!("uc_{}.hbs", level);
+        let path = self
+            .context
+            .templates_dir()
+            .join("methodologies")
+            .join(methodology)
+            .join(&file_name);
+        let overridden = path.is_file();
+        let source_path = if overridden {
+            path.clone()
+        } else {
+            Config::get_metadata_load_dir()?
+                .join("methodologies")
+                .join(methodology)
+                .join(&file_name)
+        };
+        let source = std::fs::read_to_string(&source_path).map_err(|_| {
+            MucmError::NotFound(format!(
+                "No template for view {}:{} ({} not found)",
+                methodology,
+                level,
+                source_path.display()
+            ))
+        })?;
+        Ok(TemplateDraft {
+            methodology: methodology.to_string(),
+            level: level.to_string(),
+            path,
+            source,
+            overridden,
+        })
+    }
+
+    /// Render a sample use case with the template `source` for a methodology level
+    ///
+    /// # Errors
+    /// Returns the template error when the source does not compile or render.
+    pub fn preview_template(
+        &self,
+        methodology: &str,
+        level: &str,
+        source: &str,
+    ) -> Result<TemplatePreview> {
+        let view = MethodologyView::new(methodology, level);
+        let sample = services::sample_use_case(self.project.use_cases(), &view);
+        let (output, data) = self.markdown_generator.preview(&sample, &view, source)?;
+        Ok(TemplatePreview {
+            sample_id: sample.id,
+            output,
+            variables: services::template_variables(&data),
+        })
+    }
+
+    /// Save `source` as the project template of a methodology level once it renders
+    ///
+    /// Returns the path of the saved template; the documentation is not
+    /// regenerated.
+    pub fn save_template(&self, methodology: &str, level: &str, source: &str) -> Result<PathBuf> {
+        self.preview_template(methodology, level, source)?;
+        let draft = self.template_draft(methodology, level)?;
+        if let Some(parent) = draft.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&draft.path, source)?;
+        Ok(draft.path)
+    }
+
+    /// Compute the project health score (`[health]` in the configuration)
+    ///
+    /// Test drift only counts when test generation is enabled.
+    pub fn health(&self) -> Result<HealthReport> {
+        let use_cases = self.project.use_cases();
+        let drift = if self.config.generation.test_language == "none" {
+            None
+        } else {
+            Some(
+                services::TestReconciliationService::new(use_cases, &self.test_generator)
+                    .reconcile(None)?,
+            )
+        };
+        Ok(services::health_report(
+            use_cases,
+            &self.validate(),
+            drift.as_deref(),
+            &self.config.health,
+            chrono::Utc::now().date_naive(),
+        ))
+    }
+
+    /// Get all references for a use case
+    pub fn get_references(&self, use_case_id: &str) -> Result<Vec<UseCaseReference>> {
+        let use_case = self.find_use_case_by_id(use_case_id)?;
+        Ok(use_case.use_case_references.clone())
+    }
+
+    /// Remove a reference from a use case
+    pub fn remove_reference(&mut self, use_case_id: &str, target_id: &str) -> Result<()> {
+        let mut service = services::ReferenceManagementService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
+        service.remove_reference(use_case_id, target_id)
+    }
 
     // ========== Scenario Management Methods ==========
 
-    /// Add a scenario to a use case
+    /// Add a scenario to a use case, with its tags, in one save
+    #[allow(clippy::too_many_arguments)]
     pub fn add_scenario(
         &mut self,
         use_case_id: &str,
@@ -503,10 +1263,11 @@ impl UseCaseCoordinator {
         preconditions: Vec<String>,
         postconditions: Vec<String>,
         actors: Vec<String>,
+        tags: &[String],
     ) -> Result<String> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.add_scenario(
@@ -517,10 +1278,12 @@ impl UseCaseCoordinator {
             preconditions,
             postconditions,
             actors,
+            tags,
         )
     }
 
     /// Add a step to an existing scenario
+    #[allow(clippy::too_many_arguments)]
     pub fn add_scenario_step(
         &mut self,
         use_case_id: &str,
@@ -532,8 +1295,8 @@ impl UseCaseCoordinator {
         expected_result: Option<String>,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.add_scenario_step(
@@ -555,8 +1318,8 @@ impl UseCaseCoordinator {
         new_status: crate::core::Status,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.update_scenario_status(use_case_id, scenario_id, new_status)
@@ -576,8 +1339,8 @@ impl UseCaseCoordinator {
         step_order: u32,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.remove_scenario_step(use_case_id, scenario_id, step_order)
@@ -591,8 +1354,8 @@ impl UseCaseCoordinator {
         reference: ScenarioReference,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.add_scenario_reference(use_case_id, scenario_id, reference)
@@ -607,8 +1370,8 @@ impl UseCaseCoordinator {
         relationship: &str,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.remove_scenario_reference(
@@ -634,7 +1397,7 @@ impl UseCaseCoordinator {
                 let available_ids: Vec<String> =
                     use_case.scenarios.iter().map(|s| s.id.clone()).collect();
                 let error_msg = suggest_alternatives(scenario_id, &available_ids, "Scenario");
-                anyhow::anyhow!("{}", error_msg)
+                MucmError::NotFound(error_msg)
             })?;
 
         Ok(scenario.references.clone())
@@ -651,8 +1414,8 @@ impl UseCaseCoordinator {
         status: Option<crate::core::Status>,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.edit_scenario(
@@ -668,13 +1431,29 @@ impl UseCaseCoordinator {
     /// Delete a scenario from a use case
     pub fn delete_scenario(&mut self, use_case_id: &str, scenario_id: &str) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.delete_scenario(use_case_id, scenario_id)
     }
 
+    /// Move a scenario directly before or after another scenario
+    pub fn move_scenario(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        target_id: &str,
+        before: bool,
+    ) -> Result<()> {
+        let mut scenario_service = services::ScenarioManagementService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+            &self.scenario_creator,
+        );
+        scenario_service.move_scenario(use_case_id, scenario_id, target_id, before)
+    }
+
     /// Edit a scenario step
     pub fn edit_scenario_step(
         &mut self,
@@ -684,8 +1463,8 @@ impl UseCaseCoordinator {
         new_description: String,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.edit_scenario_step(use_case_id, scenario_id, step_order, new_description)
@@ -699,8 +1478,8 @@ impl UseCaseCoordinator {
         reorderings: HashMap<u32, u32>,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.reorder_scenario_steps(use_case_id, scenario_id, reorderings)
@@ -714,8 +1493,8 @@ impl UseCaseCoordinator {
         persona_id: &str,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.assign_persona_to_scenario(use_case_id, scenario_id, persona_id)
@@ -728,32 +1507,141 @@ impl UseCaseCoordinator {
         scenario_id: &str,
     ) -> Result<()> {
         let mut scenario_service = services::ScenarioManagementService::new(
-            &self.repository,
-            &mut self.use_cases,
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
             &self.scenario_creator,
         );
         scenario_service.unassign_persona_from_scenario(use_case_id, scenario_id)
     }
 
+    /// Move every scenario assigned to persona `from` over to persona `to`
+    ///
+    /// Only use cases of `category` are touched when it is given. All changes
+    /// are saved in one transaction and only the affected use cases are
+    /// rendered again, followed by the overview.
+    ///
+    /// # Returns
+    /// The changed use cases with the number of scenarios reassigned in each
+    pub fn reassign_persona(
+        &mut self,
+        from: &str,
+        to: &str,
+        category: Option<&str>,
+    ) -> Result<Vec<(String, usize)>> {
+        if from == to {
+            return Err(MucmError::Validation(format!(
+                "Cannot reassign persona '{}' to itself",
+                from
+            )));
+        }
+        let personas: Vec<String> = self
+            .project
+            .actors()
+            .iter()
+            .filter(|actor| actor.actor_type == ActorType::Persona)
+            .map(|actor| actor.id.clone())
+            .collect();
+        if !personas.iter().any(|id| id == to) {
+            return Err(MucmError::NotFound(suggest_alternatives(
+                to, &personas, "Persona",
+            )));
+        }
+
+        let mut use_cases = self.project.use_cases().to_vec();
+        let mut transaction = RepositoryTransaction::new();
+        let mut changed = Vec::new();
+        for use_case in use_cases
+            .iter_mut()
+            .filter(|uc| category.is_none_or(|category| uc.category.eq_ignore_ascii_case(category)))
+        {
+            let mut count = 0;
+            for scenario in &mut use_case.scenarios {
+                if scenario.persona.as_deref() == Some(from) {
+                    scenario.persona = Some(to.to_string());
+                    count += 1;
+                }
+            }
+            if count > 0 {
+                use_case.metadata.touch();
+                transaction.save(use_case.clone());
+                changed.push((use_case.id.clone(), count));
+            }
+        }
+
+        changed.sort();
+        if !changed.is_empty() {
+            self.commit_transaction(transaction)?;
+            self.project.set_use_cases(use_cases);
+            self.generate_overview()?;
+        }
+        Ok(changed)
+    }
+
+    /// Add tags to a scenario, returning its tags
+    pub fn add_scenario_tags(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        tags: &[String],
+    ) -> Result<Vec<String>> {
+        let mut scenario_service = services::ScenarioManagementService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+            &self.scenario_creator,
+        );
+        scenario_service.add_scenario_tags(use_case_id, scenario_id, tags)
+    }
+
+    /// Remove tags from a scenario, returning its remaining tags
+    pub fn remove_scenario_tags(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        tags: &[String],
+    ) -> Result<Vec<String>> {
+        let mut scenario_service = services::ScenarioManagementService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+            &self.scenario_creator,
+        );
+        scenario_service.remove_scenario_tags(use_case_id, scenario_id, tags)
+    }
+
+    /// Change the conditions of a scenario, or whether it inherits the use case ones
+    pub fn update_scenario_conditions(
+        &mut self,
+        use_case_id: &str,
+        scenario_id: &str,
+        update: impl FnOnce(&mut Scenario),
+    ) -> Result<()> {
+        let mut scenario_service = services::ScenarioManagementService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+            &self.scenario_creator,
+        );
+        scenario_service.update_scenario_conditions(use_case_id, scenario_id, update)
+    }
+
     // ========== Private Helpers (Delegation) ==========
 
     /// Helper to find a use case index by ID
     fn find_use_case_index(&self, use_case_id: &str) -> Result<usize> {
-        self.use_cases
-            .iter()
-            .position(|uc| uc.id == use_case_id)
-            .ok_or_else(|| {
-                let available_ids: Vec<String> =
-                    self.use_cases.iter().map(|uc| uc.id.clone()).collect();
-                let error_msg = suggest_alternatives(use_case_id, &available_ids, "Use case");
-                anyhow::anyhow!("{}", error_msg)
-            })
+        self.project.position(use_case_id).ok_or_else(|| {
+            let available_ids: Vec<String> = self
+                .project
+                .use_cases()
+                .iter()
+                .map(|uc| uc.id.clone())
+                .collect();
+            let error_msg = suggest_alternatives(use_case_id, &available_ids, "Use case");
+            MucmError::NotFound(error_msg)
+        })
     }
 
     /// Helper to find a use case by ID (immutable)
     fn find_use_case_by_id(&self, use_case_id: &str) -> Result<&UseCase> {
         let index = self.find_use_case_index(use_case_id)?;
-        Ok(&self.use_cases[index])
+        Ok(&self.project.use_cases()[index])
     }
 
     // Deleted: create_use_case_internal() - never used (PR #13)
@@ -772,7 +1660,7 @@ impl UseCaseCoordinator {
             description,
             "Medium".to_string(), // Default priority for internal helper
             methodology,
-            &self.use_cases,
+            self.project.use_cases(),
             self.repository.as_ref(),
         )?;
 
@@ -799,87 +1687,1128 @@ impl UseCaseCoordinator {
             "Medium".to_string(), // Default priority for internal helper
             methodology,
             extra_fields,
-            &self.use_cases,
+            self.project.use_cases(),
             self.repository.as_ref(),
         )?;
 
-        // Generate markdown from TOML data
-        let markdown_content = self.markdown_generator.generate(&use_case, None, None)?;
-        self.repository
-            .save_markdown(&use_case.id, &markdown_content)?;
+        // Generate markdown from TOML data
+        let markdown_content = self.markdown_generator.generate(&use_case, None, None)?;
+        self.repository
+            .save_markdown(&use_case.id, &markdown_content)?;
+
+        Ok(use_case)
+    }
+
+    /// Save use case and generate markdown for all views
+    fn save_use_case_with_views(&self, use_case: &UseCase) -> Result<()> {
+        // Step 1: Save TOML first (source of truth)
+        let persisted = self.repository.load_by_id(&use_case.id)?;
+        // Files live under their category; a new category leaves the old ones behind
+        if persisted.as_ref().is_some_and(|before| {
+            crate::core::to_snake_case(&before.category)
+                != crate::core::to_snake_case(&use_case.category)
+        }) {
+            self.repository.delete(&use_case.id)?;
+        }
+        self.repository
+            .save(&self.prepared_for_save(use_case, persisted.as_ref()))?;
+
+        // Step 2: Generate markdown from the persisted data
+        self.render_use_case(&use_case.id)
+    }
+
+    /// Commit staged changes as one unit, then generate markdown for every saved use case
+    fn commit_transaction(&self, transaction: RepositoryTransaction) -> Result<()> {
+        let mut scheduled = RepositoryTransaction::new();
+        for id in transaction.deletes() {
+            scheduled.delete(id.as_str());
+        }
+        let persisted = if transaction.saves().is_empty() {
+            Vec::new()
+        } else {
+            self.repository.load_all()?
+        };
+        for use_case in transaction.saves() {
+            let before = persisted.iter().find(|uc| uc.id == use_case.id);
+            scheduled.save(self.prepared_for_save(use_case, before));
+        }
+        let saved_ids: Vec<String> = scheduled.saves().iter().map(|uc| uc.id.clone()).collect();
+
+        self.repository.commit(scheduled)?;
+        self.render_use_cases(&saved_ids)
+    }
+
+    /// Generate markdown for several saved use cases, loading and linking the project once
+    fn render_use_cases(&self, use_case_ids: &[String]) -> Result<()> {
+        if use_case_ids.is_empty() {
+            return Ok(());
+        }
+        let saved = self.repository.load_all()?;
+        let links = IdLinks::for_project(&self.config, &saved)?;
+        for use_case_id in use_case_ids {
+            let use_case = saved
+                .iter()
+                .find(|uc| &uc.id == use_case_id)
+                .ok_or_else(|| {
+                    MucmError::Storage("Failed to load use case from TOML".to_string())
+                })?;
+            self.write_use_case_files(use_case, &links)?;
+        }
+        Ok(())
+    }
+
+    /// Copy of a use case as it is persisted: views in the configured order,
+    /// the markdown of its text fields normalized (with a save warning per
+    /// problem), and the next review date applied, if reviews are configured
+    ///
+    /// `persisted` is the saved copy it replaces, `None` for a new use case.
+    fn prepared_for_save(&self, use_case: &UseCase, persisted: Option<&UseCase>) -> UseCase {
+        let mut use_case = use_case.clone();
+        use_case.sort_views(&self.config.templates.view_order);
+        let warnings = services::normalize_markdown_fields(&mut use_case);
+        self.save_warnings
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .extend(warnings.iter().map(ToString::to_string));
+        // Changing what a reviewer reads counts as reviewing it
+        if let Some(days) = self.config.metadata.review_interval_days {
+            if persisted.is_none_or(|before| services::is_materially_changed(before, &use_case)) {
+                use_case.metadata.schedule_review(days);
+            }
+        }
+        use_case
+    }
+
+    /// Generate markdown for all views (and the test file, if enabled) of a saved use case
+    fn render_use_case(&self, use_case_id: &str) -> Result<()> {
+        // Load from TOML to ensure we're working with persisted data
+        let use_case_from_toml = self
+            .repository
+            .load_by_id(use_case_id)?
+            .ok_or_else(|| MucmError::Storage("Failed to load use case from TOML".to_string()))?;
+
+        // Link targets of the other use cases, with this one as just saved
+        let mut links = IdLinks::for_project(&self.config, self.project.use_cases())?;
+        links.insert(&use_case_from_toml);
+
+        self.write_use_case_files(&use_case_from_toml, &links)
+    }
+
+    /// Write the markdown of every view (and the test and feature files, if enabled) of a use case
+    fn write_use_case_files(&self, use_case: &UseCase, links: &IdLinks) -> Result<()> {
+        // Generate markdown files based on views
+        // Always use OutputManager for consistent filename generation
+        let all_outputs =
+            OutputManager::generate_all_filenames(use_case, self.config.generation.output_format);
+        for (filename, view) in all_outputs {
+            // Stream each view straight into its file
+            self.repository
+                .write_markdown_with_filename(use_case, &filename, &mut |writer| {
+                    self.markdown_generator.generate_linked_to(
+                        use_case,
+                        None,
+                        Some(&view),
+                        links,
+                        writer,
+                    )
+                })?;
+        }
+
+        // Generate test file if enabled
+        if self.config.generation.auto_generate_tests {
+            self.generate_test_file(use_case)?;
+        }
+
+        if self.gherkin_generator.settings().enabled {
+            self.gherkin_generator.generate(use_case)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate test file for a use case
+    fn generate_test_file(&self, use_case: &UseCase) -> Result<()> {
+        self.test_generator.generate(use_case)
+    }
+
+    /// Generate overview file (and the manifest that accompanies it)
+    fn generate_overview(&self) -> Result<()> {
+        self.overview_generator
+            .generate(self.project.use_cases(), self.project.actors())?;
+        self.manifest_generator.generate(self.project.use_cases())
+    }
+
+    // ========== Cleanup Operations ==========
+
+    /// Clean up orphaned methodology fields from use cases
+    ///
+    /// Scans methodology_fields HashMap in each use case and removes entries for
+    /// methodologies that are not currently used by any enabled view.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - Optional specific use case to clean. If None, cleans all use cases.
+    /// * `dry_run` - If true, returns what would be cleaned without making changes
+    ///
+    /// # Returns
+    /// A tuple of (cleaned_count, total_checked, details) where:
+    /// - cleaned_count: number of use cases that had fields removed
+    /// - total_checked: number of use cases checked
+    /// - details: vector of (use_case_id, removed_methodologies) for each cleaned use case
+    pub fn cleanup_methodology_fields(
+        &mut self,
+        use_case_id: Option<String>,
+        dry_run: bool,
+    ) -> Result<services::CleanupSummary> {
+        let mut service = services::MethodologyFieldCleanupService::new(
+            self.repository.as_ref(),
+            self.project.use_cases_mut(),
+        );
+        service.cleanup_methodology_fields(use_case_id, dry_run)
+    }
+
+    // ========== Test Reconciliation ==========
+
+    /// Reconcile generated test files with use case scenarios
+    ///
+    /// Reports scenarios that have no test function and test functions that have no
+    /// scenario. When `create_missing_scenarios` is set, a stub scenario is scaffolded
+    /// for every unmatched test so it can be documented.
+    ///
+    /// # Returns
+    /// A tuple of (reconciliation results, IDs of scaffolded scenarios)
+    pub fn reconcile_tests(
+        &mut self,
+        use_case_id: Option<&str>,
+        create_missing_scenarios: bool,
+    ) -> Result<(Vec<services::TestReconciliation>, Vec<String>)> {
+        if self.config.generation.test_language == "none" {
+            return Err(MucmError::Validation(
+                "Test generation is disabled (generation.test_language = \"none\"). \
+                 Set a test language in mucm.toml to reconcile test files."
+                    .to_string(),
+            ));
+        }
+
+        let report = services::TestReconciliationService::new(
+            self.project.use_cases(),
+            &self.test_generator,
+        )
+        .reconcile(use_case_id)?;
+
+        let mut created = Vec::new();
+        if create_missing_scenarios {
+            for entry in &report {
+                if entry.tests_without_scenarios.is_empty() {
+                    continue;
+                }
+                for test_name in &entry.tests_without_scenarios {
+                    let scenario_id = self.add_scenario(
+                        &entry.use_case_id,
+                        services::scenario_title_from_test_name(test_name),
+                        ScenarioType::HappyPath,
+                        Some(format!(
+                            "Scaffolded from test `{}` in {}",
+                            test_name,
+                            entry.test_file.display()
+                        )),
+                        Vec::new(),
+                        Vec::new(),
+                        Vec::new(),
+                        &[],
+                    )?;
+                    created.push(scenario_id);
+                }
+                let use_case = self.find_use_case_by_id(&entry.use_case_id)?.clone();
+                self.save_use_case_with_views(&use_case)?;
+            }
+            if !created.is_empty() {
+                self.generate_overview()?;
+            }
+        }
+
+        Ok((report, created))
+    }
+
+    /// Generate test files for one use case (or all)
+    ///
+    /// With `only_missing`, only files that are missing or lack tests for some
+    /// scenarios are generated. Files with manual modifications are never
+    /// overwritten unless `force` is set.
+    ///
+    /// With `tag`, the suite of that scenario tag is generated instead (see
+    /// `TestReconciliationService::generate_tagged`).
+    pub fn generate_tests(
+        &self,
+        use_case_id: Option<&str>,
+        only_missing: bool,
+        force: bool,
+        tag: Option<&str>,
+    ) -> Result<Vec<services::GeneratedTestFile>> {
+        if self.config.generation.test_language == "none" {
+            return Err(MucmError::Validation(
+                "Test generation is disabled (generation.test_language = \"none\"). \
+                 Set a test language in mucm.toml to generate test files."
+                    .to_string(),
+            ));
+        }
+
+        let service = services::TestReconciliationService::new(
+            self.project.use_cases(),
+            &self.test_generator,
+        );
+        match tag {
+            Some(tag) => service.generate_tagged(use_case_id, tag),
+            None => service.generate(use_case_id, only_missing, force),
+        }
+    }
+
+    /// Recorded test names of the scenarios of one use case (or all)
+    ///
+    /// # Returns
+    /// (scenario ID, recorded name) pairs; the name is None until a test is generated
+    pub fn test_names(&self, use_case_id: Option<&str>) -> Result<Vec<(String, Option<TestName>)>> {
+        let recorded = self.test_generator.load_test_names()?;
+        let language = &self.config.generation.test_language;
+        let use_cases: Vec<&UseCase> = match use_case_id {
+            Some(id) => vec![self.find_use_case_by_id(id)?],
+            None => self.project.use_cases().iter().collect(),
+        };
+        Ok(use_cases
+            .into_iter()
+            .flat_map(|uc| &uc.scenarios)
+            .map(|s| (s.id.clone(), recorded.get(language, &s.id).cloned()))
+            .collect())
+    }
+
+    /// Rename the generated test of a scenario
+    ///
+    /// The old name is kept in the mapping so regenerated test files include a
+    /// deprecation shim for it. The test file is regenerated unless it exists and
+    /// `generation.overwrite_test_documentation` is off.
+    ///
+    /// # Returns
+    /// The old name, the test file, and whether the file was regenerated
+    pub fn rename_scenario_test(
+        &self,
+        scenario_id: &str,
+        symbol: String,
+    ) -> Result<(String, std::path::PathBuf, bool)> {
+        if self.config.generation.test_language == "none" {
+            return Err(MucmError::Validation(
+                "Test generation is disabled (generation.test_language = \"none\").".to_string(),
+            ));
+        }
+        let use_case = self
+            .project
+            .owner_of_scenario(scenario_id)
+            .ok_or_else(|| MucmError::NotFound(format!("Scenario {} not found", scenario_id)))?;
+
+        let old = self
+            .test_generator
+            .rename_test(use_case, scenario_id, symbol)?;
+        let test_file = self.test_generator.get_file_path(use_case)?;
+        let regenerate = self.config.generation.overwrite_test_documentation || !test_file.exists();
+        if regenerate {
+            self.test_generator.generate(use_case)?;
+        }
+        Ok((old, test_file, regenerate))
+    }
+
+    // ========== Import Operations ==========
+
+    /// Import use cases from MUCM TOML data files
+    ///
+    /// `path` may be a single data file or a directory searched recursively
+    /// for data files with the project's ID prefix. Whenever an incoming ID already exists, `resolve` is called with the
+    /// existing and the incoming use case and its answer is applied.
+    ///
+    /// # Returns
+    /// A report of imported, overwritten, re-ID'd, merged, and skipped use cases
+    pub fn import_use_cases<F>(&mut self, path: &Path, resolve: F) -> Result<ImportReport>
+    where
+        F: FnMut(&UseCase, &UseCase) -> Result<ConflictResolution>,
+    {
+        let incoming = services::load_use_cases_from_path(path, &self.config.project.id_prefix)?;
+        self.import_incoming(incoming, resolve)
+    }
+
+    /// Import the use cases of a `mucm export --format json` document
+    ///
+    /// The document is checked against the use case model first, so a
+    /// malformed export imports nothing. ID conflicts are resolved as for
+    /// [`Self::import_use_cases`].
+    ///
+    /// # Returns
+    /// A report of imported, overwritten, re-ID'd, merged, and skipped use cases
+    pub fn import_json_use_cases<F>(&mut self, path: &Path, resolve: F) -> Result<ImportReport>
+    where
+        F: FnMut(&UseCase, &UseCase) -> Result<ConflictResolution>,
+    {
+        let incoming = services::load_use_cases_from_json(path)?;
+        self.import_incoming(incoming, resolve)
+    }
+
+    /// Add `incoming` to the project, resolving ID conflicts with `resolve`
+    fn import_incoming<F>(&mut self, incoming: Vec<UseCase>, mut resolve: F) -> Result<ImportReport>
+    where
+        F: FnMut(&UseCase, &UseCase) -> Result<ConflictResolution>,
+    {
+        let mut report = ImportReport::default();
+        let progress = start_progress(incoming.len() as u64, "Importing");
+
+        // Stage everything and commit once, so an error leaves the project untouched
+        let mut use_cases = self.project.use_cases().to_vec();
+        let mut transaction = RepositoryTransaction::new();
+        for use_case in incoming {
+            let Some(index) = use_cases.iter().position(|uc| uc.id == use_case.id) else {
+                transaction.save(use_case.clone());
+                report.imported.push(use_case.id.clone());
+                use_cases.push(use_case);
+                progress.inc();
+                continue;
+            };
+
+            let resolution = progress.suspend_with(|| resolve(&use_cases[index], &use_case))?;
+            match resolution {
+                ConflictResolution::Skip => report.skipped.push(use_case.id),
+                ConflictResolution::Overwrite => {
+                    // Remove first so a changed category doesn't leave the old files behind
+                    transaction.delete(use_case.id.as_str());
+                    transaction.save(use_case.clone());
+                    report.overwritten.push(use_case.id.clone());
+                    use_cases[index] = use_case;
+                }
+                ConflictResolution::ReId => {
+                    let new_id = self
+                        .use_case_creator
+                        .next_use_case_id(&use_case.category, &use_cases)?;
+                    let renamed = services::re_id(&use_case, &new_id);
+                    transaction.save(renamed.clone());
+                    report.renamed.push((use_case.id, new_id));
+                    use_cases.push(renamed);
+                }
+                ConflictResolution::MergeScenarios => {
+                    let added = services::merge_scenarios(&mut use_cases[index], &use_case);
+                    if added > 0 {
+                        transaction.save(use_cases[index].clone());
+                    }
+                    report.merged.push((use_case.id, added));
+                }
+            }
+            progress.inc();
+        }
+
+        progress.finish();
+        self.commit_transaction(transaction)?;
+        self.project.set_use_cases(use_cases);
+        self.generate_overview()?;
+        Ok(report)
+    }
+
+    /// Apply a batch of create, update and delete operations
+    ///
+    /// The project is loaded once and saved in one transaction; each touched
+    /// use case is rendered once and the overview is regenerated at the end.
+    /// Failing operations are skipped and listed in the report.
+    ///
+    /// # Arguments
+    /// * `operations` - Operations to apply, in order
+    /// * `on_progress` - Called after each operation
+    pub fn execute_batch<F>(
+        &mut self,
+        operations: &[BatchOperation],
+        on_progress: F,
+    ) -> Result<BatchReport>
+    where
+        F: FnMut(&BatchProgress),
+    {
+        let executor = services::BatchExecutor::new(
+            &self.use_case_creator,
+            &self.config.templates.default_methodology,
+        );
+        let (use_cases, transaction, report) =
+            executor.run(self.project.use_cases(), operations, on_progress);
+
+        if !transaction.is_empty() {
+            self.commit_transaction(transaction)?;
+            self.project.set_use_cases(use_cases);
+            self.generate_overview()?;
+        }
+        Ok(report)
+    }
+
+    /// Import Gherkin feature files as use cases
+    ///
+    /// New features become use cases with the default methodology view;
+    /// features imported before update their use case in place, so running the
+    /// import again after editing the features never creates duplicates.
+    ///
+    /// # Arguments
+    /// * `path` - Feature file or directory searched recursively for `*.feature`
+    /// * `category` - Category for new use cases (defaults to the feature's directory name)
+    pub fn import_gherkin(
+        &mut self,
+        path: &Path,
+        category: Option<&str>,
+    ) -> Result<GherkinImportReport> {
+        let features = services::load_features(path)?;
+        let mut report = GherkinImportReport::default();
+
+        // Stage everything and commit once, so an error leaves the project untouched
+        let mut use_cases = self.project.use_cases().to_vec();
+        let mut transaction = RepositoryTransaction::new();
+        for feature in features {
+            if let Some(index) = services::find_feature_use_case(&use_cases, &feature) {
+                let sync = services::apply_feature(&mut use_cases[index], &feature);
+                let id = use_cases[index].id.clone();
+                if sync.changed {
+                    transaction.save(use_cases[index].clone());
+                    report.updated.push((id, sync.added, sync.updated));
+                } else {
+                    report.unchanged.push(id);
+                }
+                continue;
+            }
+
+            let category = category.unwrap_or(&feature.category).to_string();
+            let id = self
+                .use_case_creator
+                .next_use_case_id(&category, &use_cases)?;
+            let mut use_case = UseCase::new(
+                id.clone(),
+                feature.title.clone(),
+                category,
+                String::new(),
+                "medium".to_string(),
+            )
+            .map_err(MucmError::Validation)?;
+            use_case.add_view(MethodologyView::new(
+                self.config.templates.default_methodology.clone(),
+                "normal",
+            ));
+            services::apply_feature(&mut use_case, &feature);
+            transaction.save(use_case.clone());
+            report.created.push(id);
+            use_cases.push(use_case);
+        }
+
+        self.commit_transaction(transaction)?;
+        self.project.set_use_cases(use_cases);
+        self.generate_overview()?;
+        Ok(report)
+    }
+
+    /// Import hand-written use case markdown as use cases
+    ///
+    /// Parsing is best effort: recognized sections become preconditions,
+    /// postconditions and scenarios, and anything else is kept in the
+    /// description. Markdown imported before is skipped, so the import can be
+    /// run again after adding files.
+    ///
+    /// # Arguments
+    /// * `path` - Markdown file or directory searched recursively for `*.md`
+    /// * `category` - Category for new use cases (defaults to the `Category` field or directory name)
+    pub fn import_markdown(
+        &mut self,
+        path: &Path,
+        category: Option<&str>,
+    ) -> Result<MarkdownImportReport> {
+        let loaded = services::load_markdown(path)?;
+        let mut report = MarkdownImportReport {
+            unreadable: loaded.unreadable,
+            ..Default::default()
+        };
+
+        let mut use_cases = self.project.use_cases().to_vec();
+        let mut transaction = RepositoryTransaction::new();
+        for markdown in loaded.use_cases {
+            if use_cases
+                .iter()
+                .any(|uc| services::is_imported_from(uc, &markdown.source))
+            {
+                report.skipped.push(markdown.source);
+                continue;
+            }
+
+            let category = category.unwrap_or(&markdown.category).to_string();
+            let id = self
+                .use_case_creator
+                .next_use_case_id(&category, &use_cases)?;
+            let mut use_case = UseCase::new(
+                id.clone(),
+                markdown.title.clone(),
+                category,
+                String::new(),
+                "medium".to_string(),
+            )
+            .map_err(MucmError::Validation)?;
+            use_case.add_view(MethodologyView::new(
+                self.config.templates.default_methodology.clone(),
+                "normal",
+            ));
+            services::apply_markdown(&mut use_case, &markdown);
+            transaction.save(use_case.clone());
+            report.created.push((id, markdown.source));
+            use_cases.push(use_case);
+        }
+
+        if !transaction.is_empty() {
+            self.commit_transaction(transaction)?;
+            self.project.set_use_cases(use_cases);
+            self.generate_overview()?;
+        }
+        Ok(report)
+    }
+
+    /// Import the rows of a requirement traceability matrix as use cases
+    ///
+    /// The columns are mapped to use case fields by the TOML file at
+    /// `mapping`. Rows imported before (by matrix ID) are skipped, so the
+    /// import can be run again after the matrix grows. References are
+    /// resolved once all rows have a use case.
+    ///
+    /// # Arguments
+    /// * `path` - Spreadsheet holding the matrix
+    /// * `mapping` - Column-to-field mapping file
+    pub fn import_rtm(&mut self, path: &Path, mapping: &Path) -> Result<RtmImportReport> {
+        let mapping = services::RtmMapping::load(mapping)?;
+        let rows = services::load_rtm(path, &mapping)?;
+        let views = match &mapping.views {
+            Some(views) => Self::parse_views(views)?,
+            None => vec![MethodologyView::new(
+                self.config.templates.default_methodology.clone(),
+                "normal",
+            )],
+        };
+        let mut report = RtmImportReport::default();
+
+        let mut use_cases = self.project.use_cases().to_vec();
+        let mut created = Vec::new();
+        for row in &rows {
+            if let Some(rtm_id) = &row.id {
+                if use_cases
+                    .iter()
+                    .any(|uc| services::is_imported_from_rtm(uc, rtm_id))
+                {
+                    report.skipped.push(rtm_id.clone());
+                    continue;
+                }
+            }
+            let id = self
+                .use_case_creator
+                .next_use_case_id(&row.category, &use_cases)?;
+            let mut use_case = UseCase::new(
+                id.clone(),
+                row.title.clone(),
+                row.category.clone(),
+                String::new(),
+                "medium".to_string(),
+            )
+            .map_err(|e| MucmError::Validation(format!("Row {}: {}", row.row, e)))?;
+            for view in &views {
+                use_case.add_view(view.clone());
+            }
+            services::apply_rtm_row(&mut use_case, row, &views[0].methodology);
+            report.created.push((id.clone(), row.row));
+            created.push((id, row));
+            use_cases.push(use_case);
+        }
+        for (id, row) in &created {
+            for reference in services::link_rtm_references(id, row, &mut use_cases) {
+                report.unresolved.push((id.clone(), reference));
+            }
+        }
+
+        if !created.is_empty() {
+            let mut transaction = RepositoryTransaction::new();
+            for (id, _) in &created {
+                if let Some(use_case) = use_cases.iter().find(|uc| uc.id == *id) {
+                    transaction.save(use_case.clone());
+                }
+            }
+            self.commit_transaction(transaction)?;
+            self.project.set_use_cases(use_cases);
+            self.generate_overview()?;
+        }
+        Ok(report)
+    }
+
+    // ========== Project Operations ==========
+
+    /// Rename the project and optionally re-prefix every use case ID
+    ///
+    /// The new name and prefix are saved to the config file, references are
+    /// rewritten to the new IDs, and all markdown and the overview are
+    /// regenerated so headers and footers pick up the new name.
+    ///
+    /// # Arguments
+    /// * `name` - Optional new project name
+    /// * `description` - Optional new project description
+    /// * `id_prefix` - Optional new ID prefix (e.g., "PAY" turns UC-AUT-001 into PAY-AUT-001)
+    ///
+    /// # Returns
+    /// (old ID, new ID) pairs of the re-prefixed use cases
+    pub fn rename_project(
+        &mut self,
+        name: Option<String>,
+        description: Option<String>,
+        id_prefix: Option<String>,
+    ) -> Result<Vec<(String, String)>> {
+        let mut config = self.context.config().clone();
+        if let Some(name) = name {
+            config.project.name = name;
+        }
+        if let Some(description) = description {
+            config.project.description = description;
+        }
+
+        let use_cases = self.project.use_cases().to_vec();
+        let mut renamed = Vec::new();
+        if let Some(prefix) = id_prefix.filter(|p| *p != config.project.id_prefix) {
+            if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(MucmError::Validation(format!(
+                    "Invalid ID prefix '{}': use letters and digits only",
+                    prefix
+                )));
+            }
+
+            let renames =
+                services::reprefix_renames(&use_cases, &config.project.id_prefix, &prefix);
+            for use_case in &use_cases {
+                let Some(new_id) = renames.get(&use_case.id) else {
+                    continue;
+                };
+                if use_cases.iter().any(|uc| &uc.id == new_id) {
+                    return Err(MucmError::Conflict(format!(
+                        "Cannot rename {} to {}: that ID is already taken",
+                        use_case.id, new_id
+                    )));
+                }
+                renamed.push((use_case.id.clone(), new_id.clone()));
+            }
+
+            // Replace every use case in one transaction so a failure keeps the old IDs intact
+            let mut transaction = RepositoryTransaction::new();
+            for (old_id, _) in &renamed {
+                transaction.delete(old_id.as_str());
+            }
+            for use_case in &use_cases {
+                let mut use_case = match renames.get(&use_case.id) {
+                    Some(new_id) => services::re_id(use_case, new_id),
+                    None => use_case.clone(),
+                };
+                services::rewrite_references(&mut use_case, &renames);
+                transaction.save(use_case);
+            }
+            self.repository.commit(transaction)?;
+            self.test_generator.rekey_test_names(&renames)?;
+            config.project.id_prefix = prefix;
+        }
+
+        let root = self.context.root().to_path_buf();
+        config.save_in_dir(&root.to_string_lossy())?;
+        *self = Self::for_project(ProjectContext::new(root, config))?;
+        self.regenerate_all_markdown()?;
+
+        Ok(renamed)
+    }
+
+    /// Point the directory setting `key` (e.g. "directories.use_case_dir") at
+    /// `new_dir`, moving the existing files along.
+    ///
+    /// The config is only saved once the files are moved. All documentation is
+    /// regenerated afterwards so links between the directories follow the move.
+    /// Returns the old and new directory, or `None` when nothing changed.
+    pub fn move_directory(&mut self, key: &str, new_dir: &str) -> Result<Option<(String, String)>> {
+        let directories = &self.context.config().directories;
+        let old_dir = match key {
+            "directories.use_case_dir" => &directories.use_case_dir,
+            "directories.test_dir" => &directories.test_dir,
+            "directories.actor_dir" | "directories.persona_dir" => &directories.actor_dir,
+            "directories.data_dir" => &directories.data_dir,
+            _ => return Err(MucmError::Validation("--migrate only applies to directories.use_case_dir, test_dir, actor_dir and data_dir".to_string())),
+        }
+        .clone();
+
+        let root = self.context.root().to_path_buf();
+        let (content, config) = ConfigFileManager::with_value(&root, key, new_dir)?;
+        let new_dir = match key {
+            "directories.use_case_dir" => &config.directories.use_case_dir,
+            "directories.test_dir" => &config.directories.test_dir,
+            "directories.data_dir" => &config.directories.data_dir,
+            _ => &config.directories.actor_dir,
+        }
+        .clone();
+        if root
+            .join(&old_dir)
+            .components()
+            .eq(root.join(&new_dir).components())
+        {
+            return Ok(None);
+        }
+
+        // Other directories inside the moved one would silently move along
+        let directories = &self.context.config().directories;
+        for (other_key, other_dir) in [
+            ("directories.use_case_dir", &directories.use_case_dir),
+            ("directories.test_dir", &directories.test_dir),
+            ("directories.actor_dir", &directories.actor_dir),
+            ("directories.data_dir", &directories.data_dir),
+        ] {
+            if *other_dir != old_dir && root.join(other_dir).starts_with(root.join(&old_dir)) {
+                return Err(MucmError::Validation(format!(
+                    "Cannot move {}: it contains {} ({}); move that first",
+                    old_dir, other_key, other_dir
+                )));
+            }
+        }
+
+        services::move_directory(&root.join(&old_dir), &root.join(&new_dir))?;
+        ConfigFileManager::write_content(&root, &content)?;
+        *self = Self::for_project(ProjectContext::new(root, config))?;
+        self.regenerate_all_markdown()?;
+
+        Ok(Some((old_dir, new_dir)))
+    }
+
+    /// Report ID sequences per category with their gaps and duplicates
+    pub fn id_report(&self) -> IdReport {
+        services::id_report(self.project.use_cases())
+    }
+
+    /// Compact every ID sequence to 1..n, rewriting references and mentions
+    /// in text to the new IDs
+    ///
+    /// Only allowed when `project.allow_renumbering` is set, and refused while
+    /// any use case or scenario ID is duplicated, since references to a
+    /// duplicated ID cannot be told apart.
+    ///
+    /// # Returns
+    /// (old ID, new ID) pairs of the renumbered use cases
+    pub fn renumber_use_cases(&mut self) -> Result<Vec<(String, String)>> {
+        if !self.config.project.allow_renumbering {
+            return Err(MucmError::Validation(
+                "Renumbering is disabled for this project. IDs may be referenced outside \
+                 the project; set allow_renumbering = true under [project] to allow it"
+                    .to_string(),
+            ));
+        }
+        let report = services::id_report(self.project.use_cases());
+        if report.has_duplicates() {
+            let mut duplicates: Vec<String> = report
+                .sequences
+                .iter()
+                .flat_map(|s| s.duplicates.iter().cloned())
+                .collect();
+            duplicates.extend(report.duplicate_scenarios);
+            return Err(MucmError::Conflict(format!(
+                "Resolve duplicate IDs before renumbering: {}",
+                duplicates.join(", ")
+            )));
+        }
+
+        let use_cases = self.project.use_cases().to_vec();
+        let renames = services::renumber_renames(&use_cases);
+        let mut renamed: Vec<(String, String)> = use_cases
+            .iter()
+            .filter_map(|uc| Some((uc.id.clone(), renames.get(&uc.id)?.clone())))
+            .collect();
+        renamed.sort();
+        if renamed.is_empty() {
+            return Ok(renamed);
+        }
+
+        // Replace the use cases in one transaction so a failure keeps the old IDs intact
+        let mut transaction = RepositoryTransaction::new();
+        for (old_id, _) in &renamed {
+            transaction.delete(old_id.as_str());
+        }
+        for use_case in &use_cases {
+            let new_id = renames.get(&use_case.id);
+            let mut use_case = match new_id {
+                Some(new_id) => services::re_id(use_case, new_id),
+                None => use_case.clone(),
+            };
+            // Only renumbered use cases and those referencing them change
+            let rewritten = services::rewrite_references(&mut use_case, &renames);
+            let mentioned = services::rewrite_id_mentions(&mut use_case, &renames);
+            if new_id.is_some() || rewritten || mentioned {
+                transaction.save(use_case);
+            }
+        }
+        self.repository.commit(transaction)?;
+        self.test_generator.rekey_test_names(&renames)?;
+
+        self.project.set_use_cases(self.repository.load_all()?);
+        self.regenerate_all_markdown()?;
+        Ok(renamed)
+    }
+
+    /// Recover data that fails to load and quarantine what cannot be recovered
+    ///
+    /// Recovered use cases are loaded again and their documentation is regenerated.
+    ///
+    /// # Returns
+    /// One action per data file that failed to load
+    pub fn repair(&mut self) -> Result<Vec<RepairAction>> {
+        let actions = self.repository.repair()?;
+        if actions.is_empty() {
+            return Ok(actions);
+        }
+
+        let report = self.repository.load_all_with_report()?;
+        self.project.set_use_cases(report.use_cases);
+        self.load_failures = report.failures;
+        for action in &actions {
+            if let RepairOutcome::Recovered { use_case_id } = &action.outcome {
+                self.render_use_case(use_case_id)?;
+            }
+        }
+        self.generate_overview()?;
+        Ok(actions)
+    }
+
+    /// Explain where a use case, scenario, or generated file comes from
+    ///
+    /// # Arguments
+    /// * `query` - Use case ID, scenario ID, or data/generated file path
+    pub fn explain(&self, query: &str) -> Result<services::Explanation> {
+        services::ExplainService::new(
+            self.project.use_cases(),
+            &self.config,
+            &self.template_engine,
+            &self.test_generator,
+        )
+        .explain(query)
+    }
+
+    /// Search the data of every use case for a pattern
+    ///
+    /// # Arguments
+    /// * `pattern` - Regular expression, or literal text with `fixed_strings`
+    /// * `ignore_case` - Match case-insensitively
+    /// * `fixed_strings` - Treat the pattern as literal text
+    pub fn grep(
+        &self,
+        pattern: &str,
+        ignore_case: bool,
+        fixed_strings: bool,
+    ) -> Result<Vec<GrepMatch>> {
+        let pattern = if fixed_strings {
+            regex::escape(pattern)
+        } else {
+            pattern.to_string()
+        };
+        let regex = regex::RegexBuilder::new(&pattern)
+            .case_insensitive(ignore_case)
+            .build()
+            .map_err(|e| MucmError::Validation(format!("Invalid pattern: {}", e)))?;
+        Ok(services::grep(self.project.use_cases(), &regex))
+    }
+
+    // ========== Backups ==========
 
-        Ok(use_case)
+    /// Snapshot every data file now
+    pub fn backup_now(&self) -> Result<BackupSnapshot> {
+        BackupStore::new(&self.config).snapshot_all()
     }
 
-    /// Save use case and generate markdown for all views
-    fn save_use_case_with_views(&self, use_case: &UseCase) -> Result<()> {
-        // Step 1: Save TOML first (source of truth)
-        self.repository.save(use_case)?;
+    /// Backup snapshots, newest first
+    pub fn list_backups(&self) -> Result<Vec<BackupSnapshot>> {
+        BackupStore::new(&self.config).list()
+    }
 
-        // Step 2: Load from TOML to ensure we're working with persisted data
-        let use_case_from_toml = self
-            .repository
-            .load_by_id(&use_case.id)?
-            .ok_or_else(|| anyhow::anyhow!("Failed to load use case from TOML"))?;
+    /// Restore data files from a backup snapshot and regenerate documentation
+    ///
+    /// The files being replaced are backed up into a new snapshot first.
+    ///
+    /// # Arguments
+    /// * `snapshot` - Snapshot name as shown by `list_backups`
+    /// * `use_case_id` - Restore only this use case instead of the whole snapshot
+    ///
+    /// # Returns
+    /// The restored data files
+    pub fn restore_backup(
+        &mut self,
+        snapshot: &str,
+        use_case_id: Option<&str>,
+    ) -> Result<Vec<std::path::PathBuf>> {
+        let restored = BackupStore::new(&self.config).restore(snapshot, use_case_id)?;
+
+        let report = self.repository.load_all_with_report()?;
+        self.project.set_use_cases(report.use_cases);
+        self.load_failures = report.failures;
+        self.regenerate_all_markdown()?;
+        Ok(restored)
+    }
 
-        // Step 3: Generate markdown files based on views
-        // Always use OutputManager for consistent filename generation
-        let all_outputs = OutputManager::generate_all_filenames(&use_case_from_toml);
-        for (filename, view) in all_outputs {
-            // Generate with specific view
-            let content =
-                self.markdown_generator
-                    .generate(&use_case_from_toml, None, Some(&view))?;
-
-            self.repository.save_markdown_with_filename(
-                &use_case_from_toml,
-                &filename,
-                &content,
-            )?;
-        }
+    // ========== Split and Merge Operations ==========
 
-        // Generate test file if enabled
-        if self.config.generation.auto_generate_tests {
-            self.generate_test_file(&use_case_from_toml)?;
+    /// Move scenarios out of a use case into a new one
+    ///
+    /// The new use case gets the next free ID in its category, the source's views
+    /// and priority, and an `extends` reference back to the source. References to
+    /// the moved scenarios are rewritten everywhere.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to split
+    /// * `scenario_ids` - Scenarios to move into the new use case
+    /// * `title` - Title of the new use case
+    /// * `category` - Category of the new use case (defaults to the source's)
+    ///
+    /// # Returns
+    /// The ID of the new use case
+    pub fn split_use_case(
+        &mut self,
+        use_case_id: &str,
+        scenario_ids: &[String],
+        title: String,
+        category: Option<String>,
+    ) -> Result<String> {
+        let index = self.find_use_case_index(use_case_id)?;
+        let mut source = self.project.use_cases()[index].clone();
+
+        let category = category.unwrap_or_else(|| source.category.clone());
+        let new_id = self
+            .use_case_creator
+            .next_use_case_id(&category, self.project.use_cases())?;
+        let mut new_use_case = UseCase::new(
+            new_id.clone(),
+            title,
+            category,
+            String::new(),
+            source.priority.to_string(),
+        )
+        .map_err(MucmError::Validation)?;
+        for view in source.views() {
+            new_use_case.add_view(view.clone());
         }
+        new_use_case.add_reference(UseCaseReference::new(
+            source.id.clone(),
+            "extends".to_string(),
+        ));
 
-        Ok(())
-    }
+        let renames = services::split_scenarios(&mut source, scenario_ids, &mut new_use_case)?;
 
-    /// Generate test file for a use case
-    fn generate_test_file(&self, use_case: &UseCase) -> Result<()> {
-        self.test_generator.generate(use_case)
-    }
+        let mut transaction = RepositoryTransaction::new();
+        transaction.save(source.clone());
+        transaction.save(new_use_case.clone());
+        let mut use_cases = self.project.use_cases().to_vec();
+        use_cases[index] = source;
+        use_cases.push(new_use_case);
+        Self::stage_reference_rewrites(&mut use_cases, &renames, &mut transaction);
 
-    /// Generate overview file
-    fn generate_overview(&self) -> Result<()> {
-        self.overview_generator.generate(&self.use_cases)
+        self.commit_transaction(transaction)?;
+        self.project.set_use_cases(use_cases);
+        self.test_generator.rekey_test_names(&renames)?;
+
+        self.generate_overview()?;
+        Ok(new_id)
     }
 
-    // ========== Cleanup Operations ==========
+    /// Merge one use case into another and delete it
+    ///
+    /// `resolve` is called for every value that differs between the two use
+    /// cases. References to the merged use case and its scenarios are rewritten
+    /// everywhere.
+    ///
+    /// # Arguments
+    /// * `target_id` - The use case that is kept
+    /// * `source_id` - The use case that is merged in and removed
+    /// * `resolve` - Decides each conflict
+    ///
+    /// # Returns
+    /// The number of scenarios in the merged use case
+    pub fn merge_use_cases<F>(
+        &mut self,
+        target_id: &str,
+        source_id: &str,
+        resolve: F,
+    ) -> Result<usize>
+    where
+        F: FnMut(&MergeConflict) -> Result<MergeChoice>,
+    {
+        if target_id == source_id {
+            return Err(MucmError::Validation(format!(
+                "Cannot merge {} into itself",
+                target_id
+            )));
+        }
+        let target_index = self.find_use_case_index(target_id)?;
+        let source_index = self.find_use_case_index(source_id)?;
+
+        let mut target = self.project.use_cases()[target_index].clone();
+        let source = self.project.use_cases()[source_index].clone();
+        let renames = services::merge_use_cases(&mut target, &source, resolve)?;
+        services::rewrite_references(&mut target, &renames);
+
+        let scenario_count = target.scenarios.len();
+        let mut transaction = RepositoryTransaction::new();
+        transaction.save(target.clone());
+        transaction.delete(source.id.as_str());
+        let mut use_cases = self.project.use_cases().to_vec();
+        use_cases[target_index] = target;
+        use_cases.remove(source_index);
+        Self::stage_reference_rewrites(&mut use_cases, &renames, &mut transaction);
+
+        self.commit_transaction(transaction)?;
+        self.project.set_use_cases(use_cases);
+        self.test_generator.rekey_test_names(&renames)?;
 
-    /// Clean up orphaned methodology fields from use cases
+        self.generate_overview()?;
+        Ok(scenario_count)
+    }
+
+    /// Copy a use case under a new ID as a starting point for a new one
     ///
-    /// Scans methodology_fields HashMap in each use case and removes entries for
-    /// methodologies that are not currently used by any enabled view.
+    /// Views, custom fields, and references are copied; scenario statuses are
+    /// reset to planned.
     ///
     /// # Arguments
-    /// * `use_case_id` - Optional specific use case to clean. If None, cleans all use cases.
-    /// * `dry_run` - If true, returns what would be cleaned without making changes
+    /// * `use_case_id` - The use case to copy
+    /// * `title` - Title of the new use case
+    /// * `category` - Category of the new use case (defaults to the original's)
+    /// * `include_scenarios` - Copy scenarios
+    /// * `include_conditions` - Copy preconditions and postconditions
     ///
     /// # Returns
-    /// A tuple of (cleaned_count, total_checked, details) where:
-    /// - cleaned_count: number of use cases that had fields removed
-    /// - total_checked: number of use cases checked
-    /// - details: vector of (use_case_id, removed_methodologies) for each cleaned use case
-    pub fn cleanup_methodology_fields(
+    /// The ID of the new use case
+    pub fn clone_use_case(
         &mut self,
-        use_case_id: Option<String>,
-        dry_run: bool,
-    ) -> Result<(usize, usize, Vec<(String, Vec<String>)>)> {
-        let mut service =
-            services::MethodologyFieldCleanupService::new(&self.repository, &mut self.use_cases);
-        service.cleanup_methodology_fields(use_case_id, dry_run)
+        use_case_id: &str,
+        title: String,
+        category: Option<String>,
+        include_scenarios: bool,
+        include_conditions: bool,
+    ) -> Result<String> {
+        let source = self.find_use_case_by_id(use_case_id)?;
+        let category = category.unwrap_or_else(|| source.category.clone());
+        let new_id = self
+            .use_case_creator
+            .next_use_case_id(&category, self.project.use_cases())?;
+        let options = services::CloneOptions {
+            scenarios: include_scenarios,
+            conditions: include_conditions,
+        };
+        let clone = services::clone_use_case(source, &new_id, title, category, options);
+
+        self.save_use_case_with_views(&clone)?;
+        self.project.add_use_case(clone);
+        self.generate_overview()?;
+        Ok(new_id)
+    }
+
+    /// Apply ID renames to every use case, staging the ones that changed
+    fn stage_reference_rewrites(
+        use_cases: &mut [UseCase],
+        renames: &HashMap<String, String>,
+        transaction: &mut RepositoryTransaction,
+    ) {
+        for use_case in use_cases {
+            if services::rewrite_references(use_case, renames) {
+                transaction.save(use_case.clone());
+            }
+        }
     }
 
     // ========== Update Operations ==========
@@ -918,7 +2847,7 @@ impl UseCaseCoordinator {
         let mut use_case = self
             .repository
             .load_by_id(use_case_id)?
-            .ok_or_else(|| anyhow::anyhow!("Use case {} not found", use_case_id))?;
+            .ok_or_else(|| MucmError::NotFound(format!("Use case {} not found", use_case_id)))?;
 
         // Apply updates (only if Some)
         if let Some(t) = title {
@@ -937,7 +2866,7 @@ impl UseCaseCoordinator {
                 "medium" => Priority::Medium,
                 "high" => Priority::High,
                 "critical" => Priority::Critical,
-                _ => return Err(anyhow::anyhow!("Invalid priority: {}", p)),
+                _ => return Err(MucmError::Validation(format!("Invalid priority: {}", p))),
             };
         }
 
@@ -948,7 +2877,7 @@ impl UseCaseCoordinator {
         self.save_use_case_with_views(&use_case)?;
 
         // Reload use cases to refresh in-memory state
-        self.use_cases = self.repository.load_all()?;
+        self.project.set_use_cases(self.repository.load_all()?);
 
         Ok(())
     }
@@ -982,14 +2911,14 @@ impl UseCaseCoordinator {
         let mut use_case = self
             .repository
             .load_by_id(use_case_id)?
-            .ok_or_else(|| anyhow::anyhow!("Use case {} not found", use_case_id))?;
+            .ok_or_else(|| MucmError::NotFound(format!("Use case {} not found", use_case_id)))?;
 
         // Verify methodology exists in views
         if !use_case.views.iter().any(|v| v.methodology == methodology) {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::NotFound(format!(
                 "Methodology {} not found in use case views",
                 methodology
-            ));
+            )));
         }
 
         // Get existing methodology fields or create new entry
@@ -1018,7 +2947,7 @@ impl UseCaseCoordinator {
         self.save_use_case_with_views(&use_case)?;
 
         // Reload use cases
-        self.use_cases = self.repository.load_all()?;
+        self.project.set_use_cases(self.repository.load_all()?);
 
         Ok(())
     }
@@ -1047,14 +2976,14 @@ impl UseCaseCoordinator {
         let mut use_case = self
             .repository
             .load_by_id(use_case_id)?
-            .ok_or_else(|| anyhow::anyhow!("Use case {} not found", use_case_id))?;
+            .ok_or_else(|| MucmError::NotFound(format!("Use case {} not found", use_case_id)))?;
 
         // Check if view already exists
         if use_case.views.iter().any(|v| v.methodology == methodology) {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Conflict(format!(
                 "View for methodology {} already exists",
                 methodology
-            ));
+            )));
         }
 
         // Add new view
@@ -1080,7 +3009,7 @@ impl UseCaseCoordinator {
         self.generate_overview()?;
 
         // Reload use cases
-        self.use_cases = self.repository.load_all()?;
+        self.project.set_use_cases(self.repository.load_all()?);
 
         Ok(())
     }
@@ -1107,27 +3036,38 @@ impl UseCaseCoordinator {
         let mut use_case = self
             .repository
             .load_by_id(use_case_id)?
-            .ok_or_else(|| anyhow::anyhow!("Use case {} not found", use_case_id))?;
+            .ok_or_else(|| MucmError::NotFound(format!("Use case {} not found", use_case_id)))?;
 
         // Check if it's the last view
         if use_case.views.len() <= 1 {
-            return Err(anyhow::anyhow!(
-                "Cannot remove the last view from a use case"
+            return Err(MucmError::Validation(
+                "Cannot remove the last view from a use case".to_string(),
             ));
         }
 
         // Check if methodology exists in views
         let view_exists = use_case.views.iter().any(|v| v.methodology == methodology);
         if !view_exists {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::NotFound(format!(
                 "View {} not found in use case",
                 methodology
-            ));
+            )));
         }
 
         // Remove the view by retaining all except the one to remove
+        let was_primary = use_case
+            .views
+            .iter()
+            .any(|v| v.methodology == methodology && v.primary);
         use_case.views.retain(|v| v.methodology != methodology);
 
+        // Keep the canonical file by promoting the next enabled view
+        if was_primary {
+            if let Some(view) = use_case.views.iter_mut().find(|v| v.enabled) {
+                view.primary = true;
+            }
+        }
+
         // Clean up methodology fields for removed methodology
         use_case.methodology_fields.remove(methodology);
 
@@ -1141,10 +3081,158 @@ impl UseCaseCoordinator {
         self.generate_overview()?;
 
         // Reload use cases
-        self.use_cases = self.repository.load_all()?;
+        self.project.set_use_cases(self.repository.load_all()?);
+
+        Ok(())
+    }
+
+    /// Designate the primary view of a use case
+    ///
+    /// The primary view is also generated as `<ID>.md`, a filename that stays
+    /// valid when other views are added or removed.
+    ///
+    /// # Errors
+    /// Returns error if the use case is not found or has no enabled view of
+    /// the methodology
+    pub fn set_primary_view(&mut self, use_case_id: &str, methodology: &str) -> Result<()> {
+        let mut use_case = self
+            .repository
+            .load_by_id(use_case_id)?
+            .ok_or_else(|| MucmError::NotFound(format!("Use case {} not found", use_case_id)))?;
+
+        use_case.set_primary_view(methodology)?;
+
+        self.save_use_case_with_views(&use_case)?;
+        self.generate_overview()?;
+        self.project.set_use_cases(self.repository.load_all()?);
 
         Ok(())
     }
+
+    /// Estimate the scenarios of a use case
+    ///
+    /// Asks for every estimation field declared by the methodologies of the
+    /// use case's enabled views, stores the answers on the scenarios, and
+    /// stores the aggregated estimates on the use case.
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case to estimate
+    /// * `ask` - Returns the estimate for a scenario field, or None to keep the current one
+    pub fn estimate_use_case<F>(&mut self, use_case_id: &str, ask: F) -> Result<EstimateReport>
+    where
+        F: FnMut(&EstimateRequest) -> Result<Option<f64>>,
+    {
+        let index = self.find_use_case_index(use_case_id)?;
+        let mut use_case = self.project.use_cases()[index].clone();
+        let fields = self.estimation_fields(&use_case)?;
+
+        let report = services::estimate_use_case(&mut use_case, &fields, ask)?;
+
+        self.save_use_case_with_views(&use_case)?;
+        self.project.use_cases_mut()[index] = use_case;
+        self.generate_overview()?;
+        Ok(report)
+    }
+
+    /// Summarize the use cases added, removed, and materially changed since a git revision
+    ///
+    /// # Arguments
+    /// * `since` - Git revision of the baseline, e.g. a release tag
+    pub fn release_notes(&self, since: &str) -> Result<ReleaseNotes> {
+        if self.config.storage.backend != StorageBackend::Toml {
+            return Err(MucmError::Validation(
+                "Release notes compare data files in git history and need the TOML backend"
+                    .to_string(),
+            ));
+        }
+        let data_dir = Path::new(&self.config.directories.data_dir);
+        if !data_dir.is_dir() {
+            return Err(MucmError::NotFound(format!(
+                "Data directory {} does not exist",
+                data_dir.display()
+            )));
+        }
+        let before =
+            services::load_use_cases_at_revision(data_dir, since, &self.config.project.id_prefix)?;
+        Ok(services::release_notes(
+            since,
+            &before,
+            self.project.use_cases(),
+        ))
+    }
+
+    /// Write the acceptance checklist of one use case, or of all use cases
+    ///
+    /// # Returns
+    /// IDs of the use cases whose checklist was written
+    pub fn generate_checklists(&self, use_case_id: Option<&str>) -> Result<Vec<String>> {
+        let use_cases: Vec<&UseCase> = match use_case_id {
+            Some(id) => vec![self.find_use_case_by_id(id)?],
+            None => self.project.use_cases().iter().collect(),
+        };
+        for use_case in &use_cases {
+            self.write_checklist(use_case)?;
+        }
+        Ok(use_cases.iter().map(|uc| uc.id.clone()).collect())
+    }
+
+    /// Mark a scenario as accepted: set its status to tested and update the checklist
+    ///
+    /// # Arguments
+    /// * `use_case_id` - The use case holding the scenario
+    /// * `scenario` - Full scenario ID or short form (e.g. `S02`)
+    ///
+    /// # Returns
+    /// The full ID of the ticked scenario
+    pub fn tick_checklist(&mut self, use_case_id: &str, scenario: &str) -> Result<String> {
+        let index = self.find_use_case_index(use_case_id)?;
+        let mut use_case = self.project.use_cases()[index].clone();
+        let scenario_id = services::resolve_scenario_id(&use_case, scenario)?;
+
+        use_case.update_scenario_status(&scenario_id, crate::core::Status::Tested)?;
+        self.save_use_case_with_views(&use_case)?;
+        self.write_checklist(&use_case)?;
+        self.project.use_cases_mut()[index] = use_case;
+        self.generate_overview()?;
+        Ok(scenario_id)
+    }
+
+    /// Regenerate a use case's checklist, keeping hand-ticked condition items
+    fn write_checklist(&self, use_case: &UseCase) -> Result<()> {
+        let filename = services::checklist_filename(&use_case.id);
+        let path = Path::new(&self.config.directories.use_case_dir)
+            .join(crate::core::to_snake_case(&use_case.category))
+            .join(&filename);
+        let existing = std::fs::read_to_string(path).ok();
+        let content = services::render_checklist(use_case, existing.as_deref());
+        self.repository
+            .save_markdown_with_filename(use_case, &filename, &content)
+    }
+
+    /// Estimation fields of the methodologies of a use case's enabled views
+    ///
+    /// When methodologies declare the same field, the first view's declaration wins.
+    fn estimation_fields(
+        &self,
+        use_case: &UseCase,
+    ) -> Result<std::collections::BTreeMap<String, EstimationFieldConfig>> {
+        let methodologies_dir = self.context.templates_dir().join("methodologies");
+        let mut fields = std::collections::BTreeMap::new();
+        for view in use_case.enabled_views() {
+            let methodology =
+                MethodologyDefinition::from_toml(methodologies_dir.join(&view.methodology))
+                    .map_err(|e| {
+                        MucmError::Storage(format!(
+                            "Failed to load methodology '{}': {}",
+                            view.methodology, e
+                        ))
+                    })?;
+            for (name, config) in methodology.estimation_fields() {
+                fields.entry(name.clone()).or_insert_with(|| config.clone());
+            }
+        }
+        Ok(fields)
+    }
 }
 
 #[cfg(test)]
@@ -1177,7 +3265,8 @@ mod tests {
         assert_eq!(use_case_id, "UC-TES-001");
 
         let use_case_ids: Vec<String> = coordinator
-            .use_cases
+            .project
+            .use_cases()
             .iter()
             .map(|uc| uc.id.clone())
             .collect();
@@ -1185,7 +3274,8 @@ mod tests {
         assert!(use_case_ids.contains(&"UC-TES-001".to_string()));
 
         let final_use_case_ids: Vec<String> = coordinator
-            .use_cases
+            .project
+            .use_cases()
             .iter()
             .map(|uc| uc.id.clone())
             .collect();
@@ -1205,7 +3295,8 @@ mod tests {
         let default_methodology = coordinator.config.templates.default_methodology.clone();
 
         let mut categories: Vec<String> = coordinator
-            .use_cases
+            .project
+            .use_cases()
             .iter()
             .map(|uc| uc.category.clone())
             .collect();
@@ -1235,7 +3326,8 @@ mod tests {
         )?;
 
         let mut categories: Vec<String> = coordinator
-            .use_cases
+            .project
+            .use_cases()
             .iter()
             .map(|uc| uc.category.clone())
             .collect();
@@ -1246,7 +3338,8 @@ mod tests {
         assert_eq!(categories[1], "authentication");
 
         let use_case_ids: Vec<String> = coordinator
-            .use_cases
+            .project
+            .use_cases()
             .iter()
             .map(|uc| uc.id.clone())
             .collect();
@@ -1284,14 +3377,16 @@ mod tests {
         )?;
 
         let all_use_cases: Vec<String> = coordinator
-            .use_cases
+            .project
+            .use_cases()
             .iter()
             .map(|uc| uc.id.clone())
             .collect();
         assert_eq!(all_use_cases.len(), 2);
 
         let mut categories: Vec<String> = coordinator
-            .use_cases
+            .project
+            .use_cases()
             .iter()
             .map(|uc| uc.category.clone())
             .collect();
@@ -1326,15 +3421,18 @@ mod tests {
         // Verify templates were copied - if not, fail with clear message
         let templates_dir = Path::new(".config/.mucm/template-assets");
         if !templates_dir.exists() {
-            anyhow::bail!(
+            return Err(MucmError::NotFound(format!(
                 "Templates were not copied. Template dir {:?} doesn't exist",
                 templates_dir
-            );
+            )));
         }
 
         let feature_dir = templates_dir.join("methodologies/feature");
         if !feature_dir.exists() {
-            anyhow::bail!("Feature methodology not found at {:?}", feature_dir);
+            return Err(MucmError::NotFound(format!(
+                "Feature methodology not found at {:?}",
+                feature_dir
+            )));
         }
 
         // Use the existing "feature" methodology which has custom fields defined
@@ -1396,4 +3494,266 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[serial]
+    fn test_regenerate_view_writes_only_that_view() -> Result<()> {
+        if crate::config::TemplateManager::find_source_templates_dir().is_err() {
+            eprintln!(
+                "SKIPPING test_regenerate_view_writes_only_that_view: source templates not available"
+            );
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        env::set_current_dir(&temp_dir)?;
+        init_test_project(None)?;
+
+        let mut coordinator = UseCaseCoordinator::load()?;
+        coordinator.create_use_case_with_views(
+            "Views".to_string(),
+            "testing".to_string(),
+            None,
+            "feature:normal,business:normal",
+        )?;
+        let dir = Path::new(&coordinator.config.directories.use_case_dir).join("testing");
+        let feature = dir.join("UC-TES-001-feature-normal.md");
+        let business = dir.join("UC-TES-001-business-normal.md");
+        fs::remove_file(&feature)?;
+        fs::remove_file(&business)?;
+
+        let filename = coordinator.regenerate_view("UC-TES-001", "business:normal")?;
+        assert_eq!(filename, "UC-TES-001-business-normal.md");
+        assert!(business.exists());
+        assert!(!feature.exists());
+
+        let error = coordinator
+            .regenerate_view("UC-TES-001", "tester:normal")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "View 'tester:normal' is not enabled for UC-TES-001. Enabled views: feature:normal, business:normal"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_template_only_saves_a_template_that_renders() -> Result<()> {
+        if crate::config::TemplateManager::find_source_templates_dir().is_err() {
+            eprintln!(
+                "SKIPPING test_save_template_only_saves_a_template_that_renders: source templates not available"
+            );
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        env::set_current_dir(&temp_dir)?;
+        init_test_project(None)?;
+
+        let mut coordinator = UseCaseCoordinator::load()?;
+        coordinator.create_use_case_with_views(
+            "Sample".to_string(),
+            "testing".to_string(),
+            None,
+            "feature:normal",
+        )?;
+
+        let draft = coordinator.template_draft("feature", "normal")?;
+        assert!(draft.source.contains("{{"));
+
+        let preview = coordinator.preview_template("feature", "normal", "# {{id}}: {{title}}\n")?;
+        assert_eq!(preview.sample_id, "UC-TES-001");
+        assert_eq!(preview.output.trim(), "# UC-TES-001: Sample");
+        assert!(preview
+            .variables
+            .contains(&("title".to_string(), "text".to_string())));
+
+        assert!(coordinator
+            .save_template("feature", "normal", "{{#if title}}")
+            .is_err());
+        assert_eq!(fs::read_to_string(&draft.path)?, draft.source);
+
+        let path = coordinator.save_template("feature", "normal", "# {{title}}\n")?;
+        assert_eq!(fs::read_to_string(&path)?, "# {{title}}\n");
+        assert_eq!(path, draft.path);
+        assert!(coordinator.template_draft("feature", "normal")?.overridden);
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_reassign_persona_moves_scenarios_in_matching_use_cases() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        env::set_current_dir(&temp_dir)?;
+        init_test_project(None)?;
+
+        let config = Config::load()?;
+        let personas = RepositoryFactory::create_persona_repository(&config)?;
+        for id in ["shopper", "member"] {
+            personas.save(&crate::core::Persona::new(
+                id.to_string(),
+                id.to_string(),
+                "Buyer".to_string(),
+            ))?;
+        }
+
+        let mut coordinator = UseCaseCoordinator::load()?;
+        let views = format!("{}:normal", config.templates.default_methodology);
+        for (title, category) in [("Checkout", "orders"), ("Sign in", "accounts")] {
+            let id = coordinator.create_use_case_with_views(
+                title.to_string(),
+                category.to_string(),
+                None,
+                &views,
+            )?;
+            for scenario in ["Main", "Other"] {
+                let scenario_id = coordinator.add_scenario(
+                    &id,
+                    scenario.to_string(),
+                    ScenarioType::HappyPath,
+                    None,
+                    Vec::new(),
+                    Vec::new(),
+                    Vec::new(),
+                    &[],
+                )?;
+                coordinator.assign_persona_to_scenario(&id, &scenario_id, "shopper")?;
+            }
+        }
+
+        let changed = coordinator.reassign_persona("shopper", "member", Some("Orders"))?;
+        assert_eq!(changed, [("UC-ORD-001".to_string(), 2)]);
+        let assigned = |coordinator: &UseCaseCoordinator, id: &str| -> Vec<Option<String>> {
+            let use_case = coordinator.repository.load_by_id(id).unwrap().unwrap();
+            use_case
+                .scenarios
+                .iter()
+                .map(|s| s.persona.clone())
+                .collect()
+        };
+        assert_eq!(
+            assigned(&coordinator, "UC-ORD-001"),
+            [Some("member".to_string()), Some("member".to_string())]
+        );
+        assert_eq!(
+            assigned(&coordinator, "UC-ACC-001"),
+            [Some("shopper".to_string()), Some("shopper".to_string())]
+        );
+
+        assert!(coordinator
+            .reassign_persona("shopper", "nobody", None)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_review_is_rescheduled_only_when_content_changes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        env::set_current_dir(&temp_dir)?;
+        init_test_project(None)?;
+
+        let mut coordinator = UseCaseCoordinator::load()?;
+        coordinator.config.metadata.review_interval_days = Some(30);
+        let views = format!(
+            "{}:normal",
+            coordinator.config.templates.default_methodology
+        );
+        let id = coordinator.create_use_case_with_views(
+            "Checkout".to_string(),
+            "orders".to_string(),
+            None,
+            &views,
+        )?;
+        let overdue = chrono::NaiveDate::from_ymd_opt(2020, 1, 1);
+        let mut use_case = coordinator.repository.load_by_id(&id)?.unwrap();
+        use_case.metadata.review_by = overdue;
+        coordinator.repository.save(&use_case)?;
+        let review_by = |coordinator: &UseCaseCoordinator| {
+            let use_case = coordinator.repository.load_by_id(&id).unwrap().unwrap();
+            use_case.metadata.review_by
+        };
+
+        coordinator.update_use_case(&id, Some("Checkout"), None, None, None)?;
+        assert_eq!(review_by(&coordinator), overdue);
+
+        coordinator.update_use_case(&id, None, None, Some("Pay for the cart"), None)?;
+        assert!(review_by(&coordinator) > overdue);
+        assert!(coordinator.take_save_warnings().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_reimporting_an_unchanged_scenario_outline_changes_nothing() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        env::set_current_dir(&temp_dir)?;
+        init_test_project(None)?;
+        fs::create_dir_all("features/auth")?;
+        fs::write(
+            "features/auth/login.feature",
+            "Feature: Login\n\n  Scenario Outline: Wrong password\n    When the user enters \"<password>\"\n    Then an error is shown\n\n    Examples:\n      | password |\n      | wrong    |\n",
+        )?;
+
+        let mut coordinator = UseCaseCoordinator::load()?;
+        let report = coordinator.import_gherkin(Path::new("features"), None)?;
+        assert_eq!(report.created.len(), 1);
+        assert!(coordinator.take_save_warnings().is_empty());
+
+        let mut coordinator = UseCaseCoordinator::load()?;
+        let report = coordinator.import_gherkin(Path::new("features"), None)?;
+        assert!(report.updated.is_empty());
+        assert_eq!(report.unchanged, vec!["UC-AUT-001"]);
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_export_with_a_tag_keeps_only_the_tagged_scenarios() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        env::set_current_dir(&temp_dir)?;
+        init_test_project(None)?;
+
+        let mut coordinator = UseCaseCoordinator::load()?;
+        let views = format!(
+            "{}:normal",
+            coordinator.config.templates.default_methodology
+        );
+        for title in ["Checkout", "Refund"] {
+            coordinator.create_use_case_with_views(
+                title.to_string(),
+                "orders".to_string(),
+                None,
+                &views,
+            )?;
+        }
+        for (title, tags) in [("Pay", vec!["@smoke".to_string()]), ("Retry", vec![])] {
+            coordinator.add_scenario(
+                "UC-ORD-001",
+                title.to_string(),
+                ScenarioType::HappyPath,
+                None,
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                &tags,
+            )?;
+        }
+        let saved = coordinator.repository.load_by_id("UC-ORD-001")?.unwrap();
+        assert_eq!(saved.scenarios[0].tags, vec!["smoke"]);
+
+        let (path, count) = coordinator.export_json("smoke.json", Some("smoke"))?;
+        assert_eq!(count, 1);
+        let json = fs::read_to_string(path)?;
+        assert!(json.contains("UC-ORD-001-S01"));
+        assert!(!json.contains("UC-ORD-001-S02"));
+        assert!(!json.contains("UC-ORD-002"));
+
+        assert!(coordinator.export_csv("none.csv", Some("nightly")).is_err());
+        assert_eq!(coordinator.export_csv("all.csv", None)?.1, 2);
+        Ok(())
+    }
 }
@@ -0,0 +1,405 @@
+//! Fluent builders for use cases and scenarios.
+//!
+//! Meant for code embedding the crate: entities are assembled field by field
+//! and checked once, in `build()`, instead of going through the coordinator's
+//! CLI-shaped methods.
+//!
+//! ```
+//! use markdown_use_case_manager::core::{Priority, Scenario, ScenarioType, UseCase};
+//!
+//! let login = UseCase::builder("UC-AUT-001", "Login")
+//!     .category("Auth")
+//!     .priority(Priority::High)
+//!     .view("feature", "normal")
+//!     .precondition("User has an account")
+//!     .scenario(
+//!         Scenario::builder("Valid password")
+//!             .step("User", "enters", "their email and password")
+//!             .step("System", "shows", "the dashboard"),
+//!     )
+//!     .scenario(Scenario::builder("Wrong password").scenario_type(ScenarioType::ExceptionFlow))
+//!     .build()
+//!     .unwrap();
+//!
+//! assert_eq!(login.scenarios[1].id, "UC-AUT-001-S02");
+//! ```
+
+use crate::core::{MucmError, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+
+use super::{
+    Actor, Condition, MethodologyView, Priority, Scenario, ScenarioReference, ScenarioStep,
+    ScenarioType, Status, UseCase, UseCaseReference,
+};
+
+impl UseCase {
+    /// Start building a use case with the given ID and title
+    pub fn builder(id: impl Into<String>, title: impl Into<String>) -> UseCaseBuilder {
+        UseCaseBuilder::new(id, title)
+    }
+}
+
+impl Scenario {
+    /// Start building a scenario with the given title
+    pub fn builder(title: impl Into<String>) -> ScenarioBuilder {
+        ScenarioBuilder::new(title)
+    }
+}
+
+/// Builder for [`UseCase`]; see the module documentation
+#[derive(Debug, Clone)]
+pub struct UseCaseBuilder {
+    use_case: UseCase,
+    scenarios: Vec<ScenarioBuilder>,
+}
+
+impl UseCaseBuilder {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        let use_case = UseCase::new(
+            id.into(),
+            title.into(),
+            "General".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .expect("medium is a valid priority");
+        Self {
+            use_case,
+            scenarios: Vec::new(),
+        }
+    }
+
+    /// Category (defaults to "General")
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.use_case.category = category.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.use_case.description = description.into();
+        self
+    }
+
+    /// Priority (defaults to medium)
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.use_case.priority = priority;
+        self
+    }
+
+    /// Add a documentation view, e.g. `view("feature", "normal")`
+    pub fn view(mut self, methodology: impl Into<String>, level: impl Into<String>) -> Self {
+        self.use_case
+            .views
+            .push(MethodologyView::new(methodology, level));
+        self
+    }
+
+    pub fn precondition(mut self, condition: impl Into<Condition>) -> Self {
+        self.use_case.preconditions.push(condition.into());
+        self
+    }
+
+    pub fn postcondition(mut self, condition: impl Into<Condition>) -> Self {
+        self.use_case.postconditions.push(condition.into());
+        self
+    }
+
+    /// Reference another use case, e.g. `reference("UC-AUT-002", "depends_on")`
+    pub fn reference(
+        mut self,
+        target_id: impl Into<String>,
+        relationship: impl Into<String>,
+    ) -> Self {
+        self.use_case
+            .use_case_references
+            .push(UseCaseReference::new(target_id.into(), relationship.into()));
+        self
+    }
+
+    /// Link an architecture decision record (path relative to the project root)
+    pub fn adr(mut self, path: impl Into<String>) -> Self {
+        self.use_case.adrs.push(path.into());
+        self
+    }
+
+    /// Set a methodology field, e.g. `field("business", "business_value", "Fewer tickets")`
+    pub fn field(
+        mut self,
+        methodology: impl Into<String>,
+        name: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Self {
+        self.use_case
+            .methodology_fields
+            .entry(methodology.into())
+            .or_default()
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Set an extra (non-methodology) field such as `author`
+    pub fn extra(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.use_case.extra.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add a scenario; scenarios without an ID are numbered `<use case ID>-S01`, `-S02`, ...
+    pub fn scenario(mut self, scenario: ScenarioBuilder) -> Self {
+        self.scenarios.push(scenario);
+        self
+    }
+
+    /// Validate and build the use case
+    ///
+    /// # Errors
+    /// Returns an error for an empty ID, title or category, an ID containing
+    /// whitespace, a view or scenario ID given twice, or an invalid scenario
+    pub fn build(self) -> Result<UseCase> {
+        let mut use_case = self.use_case;
+        if use_case.id.trim().is_empty() {
+            return Err(MucmError::Validation(
+                "Use case ID must not be empty".to_string(),
+            ));
+        }
+        if use_case.id.chars().any(char::is_whitespace) {
+            return Err(MucmError::Validation(format!(
+                "Use case ID '{}' must not contain whitespace",
+                use_case.id
+            )));
+        }
+        if use_case.title.trim().is_empty() {
+            return Err(MucmError::Validation(format!(
+                "Use case {} needs a title",
+                use_case.id
+            )));
+        }
+        if use_case.category.trim().is_empty() {
+            return Err(MucmError::Validation(format!(
+                "Use case {} needs a category",
+                use_case.id
+            )));
+        }
+
+        let mut views = HashSet::new();
+        for view in &use_case.views {
+            if !views.insert(view.key()) {
+                return Err(MucmError::Conflict(format!(
+                    "Use case {} has view {} twice",
+                    use_case.id,
+                    view.key()
+                )));
+            }
+        }
+
+        for builder in self.scenarios {
+            let builder = if builder.scenario.id.is_empty() {
+                builder.id(use_case.next_scenario_id())
+            } else {
+                builder
+            };
+            let scenario = builder.build()?;
+            if use_case.scenarios.iter().any(|s| s.id == scenario.id) {
+                return Err(MucmError::Conflict(format!(
+                    "Use case {} has scenario {} twice",
+                    use_case.id, scenario.id
+                )));
+            }
+            use_case.add_scenario(scenario);
+        }
+        Ok(use_case)
+    }
+}
+
+/// Builder for [`Scenario`]; see the module documentation
+///
+/// Steps are numbered in the order they are added.
+#[derive(Debug, Clone)]
+pub struct ScenarioBuilder {
+    scenario: Scenario,
+}
+
+impl ScenarioBuilder {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            scenario: Scenario::new(
+                String::new(),
+                title.into(),
+                String::new(),
+                ScenarioType::HappyPath,
+            ),
+        }
+    }
+
+    /// Scenario ID; required when the scenario is built on its own
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.scenario.id = id.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.scenario.description = description.into();
+        self
+    }
+
+    /// Scenario type (defaults to happy path)
+    pub fn scenario_type(mut self, scenario_type: ScenarioType) -> Self {
+        self.scenario.scenario_type = scenario_type;
+        self
+    }
+
+    /// Status (defaults to planned)
+    pub fn status(mut self, status: Status) -> Self {
+        self.scenario.status = status;
+        self
+    }
+
+    pub fn persona(mut self, persona_id: impl Into<String>) -> Self {
+        self.scenario.persona = Some(persona_id.into());
+        self
+    }
+
+    /// Add a step, e.g. `step("User", "enters", "their email and password")`
+    pub fn step(
+        mut self,
+        actor: impl Into<Actor>,
+        action: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        let order = self.scenario.steps.len() + 1;
+        self.scenario.steps.push(ScenarioStep::new(
+            order,
+            actor.into(),
+            action.into(),
+            description.into(),
+        ));
+        self
+    }
+
+    pub fn precondition(mut self, condition: impl Into<Condition>) -> Self {
+        self.scenario.preconditions.push(condition.into());
+        self
+    }
+
+    pub fn postcondition(mut self, condition: impl Into<Condition>) -> Self {
+        self.scenario.postconditions.push(condition.into());
+        self
+    }
+
+    /// Reference another scenario or use case
+    pub fn reference(mut self, reference: ScenarioReference) -> Self {
+        self.scenario.references.push(reference);
+        self
+    }
+
+    /// Tag the scenario (a leading '@' is dropped)
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.scenario.add_tag(tag);
+        self
+    }
+
+    /// Validate and build the scenario
+    ///
+    /// # Errors
+    /// Returns an error for an empty ID or title, an empty persona, or a step
+    /// without an action
+    pub fn build(self) -> Result<Scenario> {
+        let scenario = self.scenario;
+        if scenario.id.trim().is_empty() {
+            return Err(MucmError::Validation(format!(
+                "Scenario '{}' needs an ID (or add it to a use case builder)",
+                scenario.title
+            )));
+        }
+        if scenario.title.trim().is_empty() {
+            return Err(MucmError::Validation(format!(
+                "Scenario {} needs a title",
+                scenario.id
+            )));
+        }
+        if scenario
+            .persona
+            .as_deref()
+            .is_some_and(|persona| persona.trim().is_empty())
+        {
+            return Err(MucmError::Validation(format!(
+                "Scenario {} has an empty persona",
+                scenario.id
+            )));
+        }
+        if let Some(step) = scenario
+            .steps
+            .iter()
+            .find(|step| step.action.trim().is_empty())
+        {
+            return Err(MucmError::Validation(format!(
+                "Step {} of scenario {} has no action",
+                step.order, scenario.id
+            )));
+        }
+        Ok(scenario)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scenarios_are_numbered_after_explicit_ids() {
+        let use_case = UseCase::builder("UC-AUT-001", "Login")
+            .scenario(Scenario::builder("Imported").id("UC-AUT-001-S05"))
+            .scenario(Scenario::builder("Valid password"))
+            .build()
+            .unwrap();
+
+        assert_eq!(use_case.scenarios[0].id, "UC-AUT-001-S05");
+        assert_eq!(use_case.scenarios[1].id, "UC-AUT-001-S06");
+        assert_eq!(use_case.category, "General");
+    }
+
+    #[test]
+    fn test_steps_are_numbered_in_order() {
+        let scenario = Scenario::builder("Valid password")
+            .id("UC-AUT-001-S01")
+            .step("User", "enters", "credentials")
+            .step("System", "verifies", "them")
+            .tag("@smoke")
+            .build()
+            .unwrap();
+
+        let orders: Vec<usize> = scenario.steps.iter().map(|s| s.order).collect();
+        assert_eq!(orders, vec![1, 2]);
+        assert_eq!(scenario.tags, vec!["smoke"]);
+    }
+
+    #[test]
+    fn test_build_rejects_invalid_entities() {
+        let errors = [
+            UseCase::builder("", "Login").build().unwrap_err(),
+            UseCase::builder("UC AUT", "Login").build().unwrap_err(),
+            UseCase::builder("UC-AUT-001", " ").build().unwrap_err(),
+            UseCase::builder("UC-AUT-001", "Login")
+                .view("feature", "normal")
+                .view("feature", "normal")
+                .build()
+                .unwrap_err(),
+            UseCase::builder("UC-AUT-001", "Login")
+                .scenario(Scenario::builder("A").id("UC-AUT-001-S01"))
+                .scenario(Scenario::builder("B").id("UC-AUT-001-S01"))
+                .build()
+                .unwrap_err(),
+            Scenario::builder("No ID").build().unwrap_err(),
+            Scenario::builder("Empty step")
+                .id("UC-AUT-001-S01")
+                .step("User", " ", "")
+                .build()
+                .unwrap_err(),
+        ];
+
+        assert!(errors[0].to_string().contains("must not be empty"));
+        assert!(errors[3].to_string().contains("feature-normal"));
+        assert!(errors[4].to_string().contains("UC-AUT-001-S01 twice"));
+        assert!(errors[6].to_string().contains("no action"));
+    }
+}
@@ -125,6 +125,33 @@ impl ActorEntity {
         Self::new(id, name, ActorType::System, emoji)
     }
 
+    /// Extra field holding the ID of the parent actor (e.g. the department of a role)
+    ///
+    /// Kept among the extra fields, like a persona's `function`, so every
+    /// backend stores it without a schema change.
+    pub const PARENT_FIELD: &'static str = "parent";
+
+    /// ID of the parent actor, if any
+    pub fn parent(&self) -> Option<&str> {
+        self.extra
+            .get(Self::PARENT_FIELD)
+            .and_then(|value| value.as_str())
+            .filter(|parent| !parent.is_empty())
+    }
+
+    /// Set or clear the parent actor
+    pub fn set_parent(&mut self, parent: Option<String>) {
+        match parent {
+            Some(parent) => {
+                self.extra
+                    .insert(Self::PARENT_FIELD.to_string(), serde_json::json!(parent));
+            }
+            None => {
+                self.extra.remove(Self::PARENT_FIELD);
+            }
+        }
+    }
+
     /// Validate actor ID format (kebab-case recommended)
     ///
     /// # Arguments
@@ -1,10 +1,16 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Days, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Metadata {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Date by which the use case should be reviewed again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_by: Option<NaiveDate>,
+    /// ID of the Confluence page the use case is published to (`mucm publish confluence`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confluence_page_id: Option<String>,
 }
 
 impl Metadata {
@@ -13,6 +19,8 @@ impl Metadata {
         Self {
             created_at: now,
             updated_at: now,
+            review_by: None,
+            confluence_page_id: None,
         }
     }
 
@@ -20,6 +28,24 @@ impl Metadata {
     pub fn touch(&mut self) {
         self.updated_at = Utc::now();
     }
+
+    /// Schedule the next review `interval_days` after the last update.
+    ///
+    /// A later review date that is already set (e.g. by hand) is kept.
+    pub fn schedule_review(&mut self, interval_days: u32) {
+        let due = self
+            .updated_at
+            .date_naive()
+            .checked_add_days(Days::new(u64::from(interval_days)));
+        if due > self.review_by {
+            self.review_by = due;
+        }
+    }
+
+    /// Whether the review date has been reached on `today`
+    pub fn is_review_due(&self, today: NaiveDate) -> bool {
+        self.review_by.is_some_and(|review_by| review_by <= today)
+    }
 }
 
 impl Default for Metadata {
@@ -193,6 +219,24 @@ mod tests {
         assert!(debug_str.contains("updated_at"));
     }
 
+    /// Test review scheduling follows the last update and keeps later dates
+    #[test]
+    fn test_metadata_schedule_review() {
+        let mut metadata = Metadata::new();
+        let today = metadata.updated_at.date_naive();
+        assert!(!metadata.is_review_due(today));
+
+        metadata.schedule_review(90);
+        let scheduled = metadata.review_by.unwrap();
+        assert_eq!(scheduled, today + Days::new(90));
+        assert!(!metadata.is_review_due(today));
+        assert!(metadata.is_review_due(scheduled));
+
+        // A shorter interval never pulls an existing review date forward
+        metadata.schedule_review(30);
+        assert_eq!(metadata.review_by, Some(scheduled));
+    }
+
     /// Test Metadata timestamp boundaries
     #[test]
     fn test_metadata_version_boundaries() {
@@ -19,6 +19,11 @@ pub struct MethodologyView {
     /// Whether this view is currently active for output generation
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+
+    /// Whether this is the designated primary view, which is also generated
+    /// under the canonical `UC-001.md` filename
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub primary: bool,
 }
 
 fn default_enabled() -> bool {
@@ -32,6 +37,7 @@ impl MethodologyView {
             methodology: methodology.into(),
             level: level.into(),
             enabled: true,
+            primary: false,
         }
     }
 
@@ -41,6 +47,7 @@ impl MethodologyView {
             methodology: methodology.into(),
             level: level.into(),
             enabled: false,
+            primary: false,
         }
     }
 
@@ -48,6 +55,15 @@ impl MethodologyView {
     pub fn key(&self) -> String {
         format!("{}-{}", self.methodology, self.level)
     }
+
+    /// Position of this view's methodology in a precedence list
+    /// (`templates.view_order`); unlisted methodologies rank last
+    pub fn precedence(&self, order: &[String]) -> usize {
+        order
+            .iter()
+            .position(|methodology| *methodology == self.methodology)
+            .unwrap_or(order.len())
+    }
 }
 
 #[cfg(test)]
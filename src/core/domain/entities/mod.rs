@@ -30,4 +30,4 @@ pub use scenario_step::ScenarioStep;
 pub use scenario_type::ScenarioType;
 pub use status::Status;
 pub use use_case::{Priority, UseCase};
-pub use use_case_reference::UseCaseReference;
+pub use use_case_reference::{UseCaseReference, GITHUB_ISSUE_RELATIONSHIP};
@@ -42,34 +42,18 @@ impl Persona {
         }
     }
 
-    /// Create a persona from config fields
-    /// This initializes extra fields based on the persona configuration
-    pub fn from_config_fields(
+    /// Create a persona with the given custom field values
+    ///
+    /// The values are expected to match the persona fields of the project
+    /// config; the persona service builds them from user input.
+    pub fn with_fields(
         id: String,
         name: String,
         function: String,
-        config_fields: &HashMap<String, crate::core::CustomFieldConfig>,
+        fields: HashMap<String, serde_json::Value>,
     ) -> Self {
         let mut persona = Self::new(id, name, function);
-
-        // Initialize extra fields with empty/default values based on config
-        for (field_name, field_config) in config_fields {
-            let default_value = if let Some(default) = &field_config.default {
-                // Use the default value from config if provided
-                serde_json::Value::String(default.clone())
-            } else {
-                // Otherwise initialize with empty/zero values based on type
-                match field_config.field_type.as_str() {
-                    "string" => serde_json::Value::String(String::new()),
-                    "array" => serde_json::Value::Array(vec![]),
-                    "number" => serde_json::Value::Number(serde_json::Number::from(0)),
-                    "boolean" => serde_json::Value::Bool(false),
-                    _ => serde_json::Value::String(String::new()),
-                }
-            };
-            persona.extra.insert(field_name.clone(), default_value);
-        }
-
+        persona.extra = fields;
         persona
     }
 
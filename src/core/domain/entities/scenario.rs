@@ -12,6 +12,10 @@ pub struct Scenario {
     pub scenario_type: ScenarioType,
     pub status: Status,
 
+    /// Manual sort key; scenarios without one keep their insertion order after ordered ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<u32>,
+
     /// Persona this scenario is designed for (placeholder)
     #[serde(default)]
     pub persona: Option<String>,
@@ -28,10 +32,23 @@ pub struct Scenario {
     #[serde(default)]
     pub postconditions: Vec<Condition>,
 
+    /// Whether the use case preconditions also apply to this scenario
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub inherit_preconditions: bool,
+
+    /// Whether the use case postconditions also apply to this scenario
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub inherit_postconditions: bool,
+
     /// References to other scenarios or use cases
     #[serde(default)]
     pub references: Vec<ScenarioReference>,
 
+    /// Tags grouping scenarios across use cases (e.g. "smoke", "regression"),
+    /// stored without the leading '@'
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     /// Flexible extra fields
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
@@ -50,11 +67,15 @@ impl Scenario {
             description,
             scenario_type,
             status: Status::Planned,
+            order: None,
             persona: None,
             steps: Vec::new(),
             preconditions: Vec::new(),
             postconditions: Vec::new(),
+            inherit_preconditions: false,
+            inherit_postconditions: false,
             references: Vec::new(),
+            tags: Vec::new(),
             extra: HashMap::new(),
         }
     }
@@ -89,6 +110,50 @@ impl Scenario {
         }
     }
 
+    /// Preconditions that apply to the scenario
+    ///
+    /// When the scenario inherits them, the use case preconditions come first,
+    /// followed by the scenario-specific ones that are not already listed.
+    pub fn effective_preconditions(&self, use_case_preconditions: &[Condition]) -> Vec<Condition> {
+        Self::effective_conditions(
+            self.inherit_preconditions,
+            use_case_preconditions,
+            &self.preconditions,
+        )
+    }
+
+    /// Postconditions that apply to the scenario, see [`Scenario::effective_preconditions`]
+    pub fn effective_postconditions(
+        &self,
+        use_case_postconditions: &[Condition],
+    ) -> Vec<Condition> {
+        Self::effective_conditions(
+            self.inherit_postconditions,
+            use_case_postconditions,
+            &self.postconditions,
+        )
+    }
+
+    fn effective_conditions(
+        inherit: bool,
+        inherited: &[Condition],
+        own: &[Condition],
+    ) -> Vec<Condition> {
+        let inherited = if inherit { inherited } else { &[] };
+        let mut conditions: Vec<Condition> = Vec::with_capacity(inherited.len() + own.len());
+        for condition in inherited.iter().chain(own) {
+            // Same duplicate rule as add_precondition/add_postcondition
+            if !conditions.iter().any(|c| {
+                c.text == condition.text
+                    && c.target_id == condition.target_id
+                    && c.target_type == condition.target_type
+            }) {
+                conditions.push(condition.clone());
+            }
+        }
+        conditions
+    }
+
     /// Remove a precondition by text
     pub fn remove_precondition(&mut self, text: &str) {
         self.preconditions.retain(|c| c.text != text);
@@ -99,6 +164,35 @@ impl Scenario {
         self.postconditions.retain(|c| c.text != text);
     }
 
+    /// Normalize a tag as written on the command line or in Gherkin ("@Smoke" -> "smoke")
+    pub fn normalize_tag(tag: &str) -> String {
+        tag.trim().trim_start_matches('@').to_lowercase()
+    }
+
+    /// Whether the scenario carries a tag (with or without the leading '@')
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = Self::normalize_tag(tag);
+        self.tags.contains(&tag)
+    }
+
+    /// Add a tag; returns false if the scenario already had it or it is empty
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        let tag = Self::normalize_tag(tag);
+        if tag.is_empty() || self.tags.contains(&tag) {
+            return false;
+        }
+        self.tags.push(tag);
+        true
+    }
+
+    /// Remove a tag; returns false if the scenario did not have it
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let tag = Self::normalize_tag(tag);
+        let before = self.tags.len();
+        self.tags.retain(|t| *t != tag);
+        self.tags.len() != before
+    }
+
     /// Update scenario status
     pub fn set_status(&mut self, status: Status) {
         self.status = status;
@@ -280,4 +374,62 @@ mod tests {
         assert_eq!(scenario.preconditions, deserialized.preconditions);
         assert_eq!(scenario.extra["test_field"], json!("test_value"));
     }
+
+    #[test]
+    fn test_scenario_tags_are_normalized() {
+        let mut scenario = Scenario::new(
+            "UC-AUTH-001-S01".to_string(),
+            "Successful login".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        );
+
+        assert!(scenario.add_tag("@Smoke"));
+        assert!(!scenario.add_tag("smoke"));
+        assert!(!scenario.add_tag("@"));
+        assert!(scenario.add_tag("regression"));
+        assert_eq!(scenario.tags, vec!["smoke", "regression"]);
+        assert!(scenario.has_tag("@smoke"));
+
+        assert!(scenario.remove_tag("@SMOKE"));
+        assert!(!scenario.remove_tag("smoke"));
+        assert_eq!(scenario.tags, vec!["regression"]);
+    }
+
+    #[test]
+    fn test_effective_conditions_inherit_use_case_conditions() {
+        let mut scenario = Scenario::new(
+            "UC-AUTH-001-S01".to_string(),
+            "Successful login".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        );
+        scenario.add_precondition(Condition::new("User is registered"));
+        scenario.add_precondition(Condition::new("Account is unlocked"));
+        let use_case_conditions = vec![
+            Condition::new("System is online"),
+            Condition::new("User is registered"),
+        ];
+
+        let texts = |conditions: Vec<Condition>| -> Vec<String> {
+            conditions.into_iter().map(|c| c.text).collect()
+        };
+        assert_eq!(
+            texts(scenario.effective_preconditions(&use_case_conditions)),
+            vec!["User is registered", "Account is unlocked"]
+        );
+
+        scenario.inherit_preconditions = true;
+        assert_eq!(
+            texts(scenario.effective_preconditions(&use_case_conditions)),
+            vec![
+                "System is online",
+                "User is registered",
+                "Account is unlocked"
+            ]
+        );
+        assert!(scenario
+            .effective_postconditions(&use_case_conditions)
+            .is_empty());
+    }
 }
@@ -23,6 +23,10 @@ pub struct ScenarioStep {
     /// Additional notes or technical details
     #[serde(default)]
     pub notes: Option<String>,
+
+    /// Gherkin keyword (Given, When, Then, And, But) of steps imported from feature files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
 }
 
 impl ScenarioStep {
@@ -35,6 +39,7 @@ impl ScenarioStep {
             action,
             description,
             notes: None,
+            keyword: None,
         }
     }
 
@@ -53,6 +58,7 @@ impl ScenarioStep {
             action,
             description,
             notes: None,
+            keyword: None,
         }
     }
 
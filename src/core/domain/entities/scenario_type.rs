@@ -4,16 +4,24 @@ use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[derive(Default)]
 pub enum ScenarioType {
-    HappyPath,       // Main success scenario
+    #[default]
+    HappyPath, // Main success scenario
     AlternativeFlow, // Valid alternative path
     ExceptionFlow,   // Error/exception handling
     Extension,       // Extension point
 }
 
-impl Default for ScenarioType {
-    fn default() -> Self {
-        ScenarioType::HappyPath
+impl ScenarioType {
+    /// Position of this type when scenarios are grouped by type
+    pub fn group_rank(&self) -> u8 {
+        match self {
+            ScenarioType::HappyPath => 0,
+            ScenarioType::AlternativeFlow => 1,
+            ScenarioType::Extension => 2,
+            ScenarioType::ExceptionFlow => 3,
+        }
     }
 }
 
@@ -13,6 +13,16 @@ pub enum Status {
 }
 
 impl Status {
+    /// All statuses, in lifecycle order
+    pub const ALL: [Status; 6] = [
+        Status::Planned,
+        Status::InProgress,
+        Status::Implemented,
+        Status::Tested,
+        Status::Deployed,
+        Status::Deprecated,
+    ];
+
     pub fn emoji(&self) -> &'static str {
         match self {
             Status::Planned => "📋",
@@ -35,6 +45,7 @@ impl Status {
     }
 
     /// Parse status from string
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(status_str: &str) -> Result<Self, String> {
         match status_str.to_lowercase().as_str() {
             "planned" => Ok(Status::Planned),
@@ -1,4 +1,5 @@
 use super::{Condition, Metadata, MethodologyView, Scenario, Status, UseCaseReference};
+use crate::core::{MucmError, Result};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
@@ -62,6 +63,11 @@ pub struct UseCase {
     #[serde(default)]
     pub use_case_references: Vec<UseCaseReference>,
 
+    /// Architecture decision records this use case relies on, as paths
+    /// relative to the project root (e.g. "docs/adr/0007-use-event-sourcing.md")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub adrs: Vec<String>,
+
     // NEW: Scenarios for this use case
     #[serde(default)]
     pub scenarios: Vec<Scenario>,
@@ -98,6 +104,7 @@ impl UseCase {
             preconditions: Vec::new(),
             postconditions: Vec::new(),
             use_case_references: Vec::new(),
+            adrs: Vec::new(),
             scenarios: Vec::new(),
             methodology_fields: std::collections::HashMap::new(),
             extra: std::collections::HashMap::new(),
@@ -167,69 +174,165 @@ impl UseCase {
         }
     }
 
+    /// Link an architecture decision record; returns false if already linked
+    pub fn add_adr(&mut self, path: &str) -> bool {
+        if self.adrs.iter().any(|adr| adr == path) {
+            return false;
+        }
+        self.adrs.push(path.to_string());
+        self.metadata.touch();
+        true
+    }
+
+    /// Unlink an architecture decision record; returns false if it was not linked
+    pub fn remove_adr(&mut self, path: &str) -> bool {
+        let before = self.adrs.len();
+        self.adrs.retain(|adr| adr != path);
+        if self.adrs.len() == before {
+            return false;
+        }
+        self.metadata.touch();
+        true
+    }
+
     /// Get next scenario ID for this use case
+    ///
+    /// Numbers continue after the highest existing one, so IDs freed by moving
+    /// or deleting scenarios are never handed out twice.
     pub fn next_scenario_id(&self) -> String {
-        let next_num = self.scenarios.len() + 1;
+        let prefix = format!("{}-S", self.id);
+        let highest = self
+            .scenarios
+            .iter()
+            .filter_map(|s| s.id.strip_prefix(&prefix)?.parse::<usize>().ok())
+            .max()
+            .unwrap_or(0);
+        let next_num = highest.max(self.scenarios.len()) + 1;
         format!("{}-S{:02}", self.id, next_num)
     }
 
     /// Add a scenario
-    pub fn add_scenario(&mut self, scenario: Scenario) {
+    ///
+    /// Once any scenario has a manual `order`, new scenarios are placed last.
+    pub fn add_scenario(&mut self, mut scenario: Scenario) {
+        if scenario.order.is_none() {
+            if let Some(max_order) = self.scenarios.iter().filter_map(|s| s.order).max() {
+                scenario.order = Some(max_order + 1);
+            }
+        }
         self.scenarios.push(scenario);
         self.metadata.touch();
     }
 
+    /// Sort scenarios by their manual `order`, keeping insertion order for ties.
+    ///
+    /// With `group_by_type`, happy paths come first, then alternative flows,
+    /// extensions and exception flows, each group sorted by `order`.
+    pub fn sort_scenarios(&mut self, group_by_type: bool) {
+        self.scenarios.sort_by_key(|s| {
+            let group = if group_by_type {
+                s.scenario_type.group_rank()
+            } else {
+                0
+            };
+            (group, s.order.unwrap_or(u32::MAX))
+        });
+    }
+
+    /// Move a scenario directly before or after another one.
+    ///
+    /// Every scenario gets an explicit `order` afterwards so the manual order is
+    /// stable regardless of insertion order.
+    pub fn move_scenario(
+        &mut self,
+        scenario_id: &str,
+        target_id: &str,
+        before: bool,
+    ) -> Result<()> {
+        if scenario_id == target_id {
+            return Err(MucmError::Validation(format!(
+                "Cannot move scenario '{}' relative to itself",
+                scenario_id
+            )));
+        }
+        for id in [scenario_id, target_id] {
+            if !self.scenarios.iter().any(|s| s.id == id) {
+                return Err(MucmError::NotFound(format!(
+                    "Scenario with ID '{}' not found",
+                    id
+                )));
+            }
+        }
+
+        self.sort_scenarios(false);
+        let index = self
+            .scenarios
+            .iter()
+            .position(|s| s.id == scenario_id)
+            .expect("scenario checked above");
+        let scenario = self.scenarios.remove(index);
+        let target_index = self
+            .scenarios
+            .iter()
+            .position(|s| s.id == target_id)
+            .expect("target scenario checked above");
+        let insert_at = if before {
+            target_index
+        } else {
+            target_index + 1
+        };
+        self.scenarios.insert(insert_at, scenario);
+
+        for (position, scenario) in self.scenarios.iter_mut().enumerate() {
+            scenario.order = Some(position as u32 + 1);
+        }
+        self.metadata.touch();
+        Ok(())
+    }
+
     /// Add a step to a specific scenario
     pub fn add_step_to_scenario(
         &mut self,
         scenario_id: &str,
         step: super::ScenarioStep,
-    ) -> anyhow::Result<()> {
+    ) -> Result<()> {
         if let Some(scenario) = self.scenarios.iter_mut().find(|s| s.id == scenario_id) {
             scenario.add_step(step);
             self.metadata.touch();
             Ok(())
         } else {
-            Err(anyhow::anyhow!(
+            Err(MucmError::NotFound(format!(
                 "Scenario with ID '{}' not found",
                 scenario_id
-            ))
+            )))
         }
     }
 
     /// Update the status of a specific scenario
-    pub fn update_scenario_status(
-        &mut self,
-        scenario_id: &str,
-        new_status: Status,
-    ) -> anyhow::Result<()> {
+    pub fn update_scenario_status(&mut self, scenario_id: &str, new_status: Status) -> Result<()> {
         if let Some(scenario) = self.scenarios.iter_mut().find(|s| s.id == scenario_id) {
             scenario.set_status(new_status);
             self.metadata.touch();
             Ok(())
         } else {
-            Err(anyhow::anyhow!(
+            Err(MucmError::NotFound(format!(
                 "Scenario with ID '{}' not found",
                 scenario_id
-            ))
+            )))
         }
     }
 
     /// Remove a step from a specific scenario
-    pub fn remove_step_from_scenario(
-        &mut self,
-        scenario_id: &str,
-        step_order: u32,
-    ) -> anyhow::Result<()> {
+    pub fn remove_step_from_scenario(&mut self, scenario_id: &str, step_order: u32) -> Result<()> {
         if let Some(scenario) = self.scenarios.iter_mut().find(|s| s.id == scenario_id) {
             scenario.remove_step(step_order);
             self.metadata.touch();
             Ok(())
         } else {
-            Err(anyhow::anyhow!(
+            Err(MucmError::NotFound(format!(
                 "Scenario with ID '{}' not found",
                 scenario_id
-            ))
+            )))
         }
     }
 
@@ -271,7 +374,19 @@ impl UseCase {
         }
     }
 
-    /// Get all enabled views
+    /// Order views by methodology precedence
+    ///
+    /// Views whose methodology is listed in `order` come first, in that order;
+    /// the others keep their relative order after them. An empty `order`
+    /// changes nothing.
+    pub fn sort_views(&mut self, order: &[String]) {
+        if order.is_empty() {
+            return;
+        }
+        self.views.sort_by_key(|view| view.precedence(order));
+    }
+
+    /// Get all enabled views, in stored order (see [`UseCase::sort_views`])
     ///
     /// Note: Every use case must have at least one enabled view.
     pub fn enabled_views(&self) -> impl Iterator<Item = &MethodologyView> {
@@ -283,12 +398,35 @@ impl UseCase {
         &self.views
     }
 
-    /// Get the primary (first enabled) view for this use case
+    /// Get the primary view for this use case
     ///
-    /// Returns the first enabled view, or None if all views are disabled
-    /// (which should not happen in normal operation).
+    /// Returns the designated primary view if it is enabled, otherwise the
+    /// first enabled view, or None if all views are disabled (which should not
+    /// happen in normal operation).
     pub fn primary_view(&self) -> Option<&MethodologyView> {
-        self.enabled_views().next()
+        self.enabled_views()
+            .find(|v| v.primary)
+            .or_else(|| self.enabled_views().next())
+    }
+
+    /// The view designated as primary, if one is designated and enabled
+    pub fn designated_primary_view(&self) -> Option<&MethodologyView> {
+        self.enabled_views().find(|v| v.primary)
+    }
+
+    /// Designate the enabled view of `methodology` as the primary view
+    pub fn set_primary_view(&mut self, methodology: &str) -> Result<()> {
+        if !self.enabled_views().any(|v| v.methodology == methodology) {
+            return Err(MucmError::Validation(format!(
+                "Use case {} has no enabled {} view",
+                self.id, methodology
+            )));
+        }
+        for view in &mut self.views {
+            view.primary = view.methodology == methodology;
+        }
+        self.metadata.touch();
+        Ok(())
     }
 }
 
@@ -1092,6 +1230,32 @@ mod use_case_tests {
         assert_eq!(enabled[1].methodology, "tester");
     }
 
+    /// Test sort_views() follows the precedence and keeps unlisted views in order
+    #[test]
+    fn test_sort_views() {
+        let mut use_case = UseCase::new(
+            "UC-TEST-001".to_string(),
+            "Test Use Case".to_string(),
+            "Test".to_string(),
+            "A test use case".to_string(),
+            "medium".to_string(),
+        )
+        .unwrap();
+
+        use_case.add_view(MethodologyView::new("feature", "simple"));
+        use_case.add_view(MethodologyView::new("developer", "normal"));
+        use_case.add_view(MethodologyView::new("tester", "detailed"));
+        use_case.add_view(MethodologyView::new("business", "normal"));
+
+        use_case.sort_views(&["business".to_string(), "tester".to_string()]);
+        let order: Vec<_> = use_case
+            .enabled_views()
+            .map(|v| v.methodology.as_str())
+            .collect();
+        assert_eq!(order, vec!["business", "tester", "feature", "developer"]);
+        assert_eq!(use_case.primary_view().unwrap().methodology, "business");
+    }
+
     /// Test views() and primary_view() methods
     #[test]
     fn test_views_and_primary_view() {
@@ -1120,6 +1284,35 @@ mod use_case_tests {
         assert_eq!(use_case.primary_view().unwrap().methodology, "feature");
     }
 
+    /// Test designating a primary view
+    #[test]
+    fn test_set_primary_view() {
+        let mut use_case = UseCase::new(
+            "UC-TEST-001".to_string(),
+            "Test Use Case".to_string(),
+            "Test".to_string(),
+            "A test use case".to_string(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        use_case.add_view(MethodologyView::new("feature", "simple"));
+        use_case.add_view(MethodologyView::new("business", "normal"));
+        use_case.add_view(MethodologyView::new_disabled("tester", "simple"));
+        assert!(use_case.designated_primary_view().is_none());
+
+        use_case.set_primary_view("business").unwrap();
+        assert_eq!(use_case.primary_view().unwrap().methodology, "business");
+        use_case.set_primary_view("feature").unwrap();
+        assert_eq!(use_case.views.iter().filter(|v| v.primary).count(), 1);
+        assert_eq!(
+            use_case.designated_primary_view().unwrap().methodology,
+            "feature"
+        );
+
+        assert!(use_case.set_primary_view("tester").is_err());
+        assert!(use_case.set_primary_view("unknown").is_err());
+    }
+
     /// Test views field serialization
     #[test]
     fn test_views_serialization() {
@@ -1140,4 +1333,110 @@ mod use_case_tests {
         assert!(toml.contains("methodology = \"feature\""));
         assert!(toml.contains("level = \"simple\""));
     }
+
+    fn use_case_with_typed_scenarios(types: &[ScenarioType]) -> UseCase {
+        let mut use_case = UseCase::new(
+            "UC-TEST-001".to_string(),
+            "Test Use Case".to_string(),
+            "Test".to_string(),
+            "A test use case".to_string(),
+            "medium".to_string(),
+        )
+        .unwrap();
+        for scenario_type in types {
+            let id = use_case.next_scenario_id();
+            use_case.add_scenario(Scenario::new(
+                id,
+                "Scenario".to_string(),
+                String::new(),
+                *scenario_type,
+            ));
+        }
+        use_case
+    }
+
+    fn scenario_ids(use_case: &UseCase) -> Vec<&str> {
+        use_case.scenarios.iter().map(|s| s.id.as_str()).collect()
+    }
+
+    /// Test moving a scenario assigns explicit orders
+    #[test]
+    fn test_move_scenario_before_and_after() {
+        let mut use_case = use_case_with_typed_scenarios(&[
+            ScenarioType::HappyPath,
+            ScenarioType::AlternativeFlow,
+            ScenarioType::ExceptionFlow,
+        ]);
+
+        use_case
+            .move_scenario("UC-TEST-001-S03", "UC-TEST-001-S01", true)
+            .unwrap();
+        assert_eq!(
+            scenario_ids(&use_case),
+            ["UC-TEST-001-S03", "UC-TEST-001-S01", "UC-TEST-001-S02"]
+        );
+        assert_eq!(use_case.scenarios[0].order, Some(1));
+
+        use_case
+            .move_scenario("UC-TEST-001-S03", "UC-TEST-001-S02", false)
+            .unwrap();
+        assert_eq!(
+            scenario_ids(&use_case),
+            ["UC-TEST-001-S01", "UC-TEST-001-S02", "UC-TEST-001-S03"]
+        );
+
+        assert!(use_case
+            .move_scenario("UC-TEST-001-S01", "UC-TEST-001-S09", true)
+            .is_err());
+        assert!(use_case
+            .move_scenario("UC-TEST-001-S01", "UC-TEST-001-S01", true)
+            .is_err());
+    }
+
+    /// Test scenarios added after a move are placed last
+    #[test]
+    fn test_add_scenario_after_manual_order() {
+        let mut use_case =
+            use_case_with_typed_scenarios(&[ScenarioType::HappyPath, ScenarioType::HappyPath]);
+        use_case
+            .move_scenario("UC-TEST-001-S02", "UC-TEST-001-S01", true)
+            .unwrap();
+
+        let id = use_case.next_scenario_id();
+        use_case.add_scenario(Scenario::new(
+            id,
+            "Late".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        ));
+        assert_eq!(use_case.scenarios[2].order, Some(3));
+    }
+
+    /// Test grouping by type keeps manual order within each group
+    #[test]
+    fn test_sort_scenarios_by_type() {
+        let mut use_case = use_case_with_typed_scenarios(&[
+            ScenarioType::ExceptionFlow,
+            ScenarioType::HappyPath,
+            ScenarioType::AlternativeFlow,
+            ScenarioType::HappyPath,
+        ]);
+        use_case
+            .move_scenario("UC-TEST-001-S04", "UC-TEST-001-S02", true)
+            .unwrap();
+
+        use_case.sort_scenarios(true);
+        assert_eq!(
+            scenario_ids(&use_case),
+            [
+                "UC-TEST-001-S04",
+                "UC-TEST-001-S02",
+                "UC-TEST-001-S03",
+                "UC-TEST-001-S01"
+            ]
+        );
+
+        use_case.sort_scenarios(false);
+        assert_eq!(use_case.scenarios[0].id, "UC-TEST-001-S01");
+    }
 }
@@ -1,6 +1,10 @@
 // UseCaseReference entity - represents relationships between use cases
 use serde::{Deserialize, Serialize};
 
+/// Relationship of the GitHub issue tracking a use case (`mucm sync github`);
+/// its target is `owner/repo#number` rather than a use case ID
+pub const GITHUB_ISSUE_RELATIONSHIP: &str = "github_issue";
+
 /// Reference to another use case with relationship type
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct UseCaseReference {
@@ -50,6 +54,11 @@ impl UseCaseReference {
     pub fn is_alternative(&self) -> bool {
         self.relationship == "alternative_to"
     }
+
+    /// Check if this points to a GitHub issue rather than a use case
+    pub fn is_github_issue(&self) -> bool {
+        self.relationship == GITHUB_ISSUE_RELATIONSHIP
+    }
 }
 
 #[cfg(test)]
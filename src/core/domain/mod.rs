@@ -1,14 +1,18 @@
 // Domain layer - Pure business logic, framework agnostic
 
+mod builders;
 mod entities;
+mod project;
 mod repositories;
 mod services;
 
 // Re-exports
+pub use builders::{ScenarioBuilder, UseCaseBuilder};
 pub use entities::{
     Actor, ActorEntity, ActorType, Condition, Metadata, MethodologyView, Persona, Priority,
     ReferenceType, Scenario, ScenarioReference, ScenarioStep, ScenarioType, Status, UseCase,
-    UseCaseReference,
+    UseCaseReference, GITHUB_ISSUE_RELATIONSHIP,
 };
+pub use project::Project;
 pub use repositories::{ActorRepository, PersonaRepository};
-pub use services::{ScenarioReferenceValidator, UseCaseService};
+pub use services::{ActorHierarchy, ActorRollup, ScenarioReferenceValidator, UseCaseService};
@@ -0,0 +1,299 @@
+//! The project aggregate: every use case and actor of a project, with the
+//! lookups derived from them.
+//!
+//! Indices (by ID, category, status, persona, and the reference graph) are
+//! built on first use and dropped whenever the use cases are changed through
+//! the aggregate, so they are computed at most once between mutations.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+
+use super::{ActorEntity, ReferenceType, Status, UseCase};
+
+/// Use cases and actors of one project
+#[derive(Debug, Clone, Default)]
+pub struct Project {
+    use_cases: Vec<UseCase>,
+    actors: Vec<ActorEntity>,
+    index: OnceLock<ProjectIndex>,
+}
+
+/// Lookups derived from the use cases; positions refer to `Project::use_cases`
+#[derive(Debug, Clone, Default)]
+struct ProjectIndex {
+    by_id: HashMap<String, usize>,
+    by_scenario_id: HashMap<String, usize>,
+    by_category: BTreeMap<String, Vec<usize>>,
+    by_status: BTreeMap<Status, Vec<usize>>,
+    by_persona: HashMap<String, Vec<usize>>,
+    references: HashMap<String, Vec<String>>,
+    referenced_by: HashMap<String, Vec<String>>,
+}
+
+impl Project {
+    pub fn new(use_cases: Vec<UseCase>) -> Self {
+        Self {
+            use_cases,
+            ..Self::default()
+        }
+    }
+
+    /// The same project with its actors (personas and system actors)
+    pub fn with_actors(mut self, actors: Vec<ActorEntity>) -> Self {
+        self.actors = actors;
+        self
+    }
+
+    // ========== Use Cases ==========
+
+    pub fn use_cases(&self) -> &[UseCase] {
+        &self.use_cases
+    }
+
+    /// Mutable access to the use cases; drops the derived indices
+    pub fn use_cases_mut(&mut self) -> &mut Vec<UseCase> {
+        self.index.take();
+        &mut self.use_cases
+    }
+
+    pub fn add_use_case(&mut self, use_case: UseCase) {
+        self.use_cases_mut().push(use_case);
+    }
+
+    /// Replace every use case, e.g. after reloading them from the repository
+    pub fn set_use_cases(&mut self, use_cases: Vec<UseCase>) {
+        *self.use_cases_mut() = use_cases;
+    }
+
+    pub fn len(&self) -> usize {
+        self.use_cases.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.use_cases.is_empty()
+    }
+
+    /// Position of a use case in `use_cases()`
+    pub fn position(&self, use_case_id: &str) -> Option<usize> {
+        self.index().by_id.get(use_case_id).copied()
+    }
+
+    pub fn get(&self, use_case_id: &str) -> Option<&UseCase> {
+        self.position(use_case_id).map(|i| &self.use_cases[i])
+    }
+
+    /// Use case that has a scenario with this ID
+    pub fn owner_of_scenario(&self, scenario_id: &str) -> Option<&UseCase> {
+        self.index()
+            .by_scenario_id
+            .get(scenario_id)
+            .map(|&i| &self.use_cases[i])
+    }
+
+    /// Categories in alphabetical order
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.index().by_category.keys().map(String::as_str)
+    }
+
+    pub fn in_category(&self, category: &str) -> Vec<&UseCase> {
+        self.select(self.index().by_category.get(category))
+    }
+
+    /// Use cases whose overall status (derived from their scenarios) is `status`
+    pub fn with_status(&self, status: Status) -> Vec<&UseCase> {
+        self.select(self.index().by_status.get(&status))
+    }
+
+    /// Use cases with at least one scenario played by the persona
+    pub fn for_persona(&self, persona_id: &str) -> Vec<&UseCase> {
+        self.select(self.index().by_persona.get(persona_id))
+    }
+
+    /// IDs of the use cases this one references, directly or through its scenarios
+    pub fn references_from(&self, use_case_id: &str) -> &[String] {
+        self.index()
+            .references
+            .get(use_case_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// IDs of the use cases that reference this one
+    pub fn referenced_by(&self, use_case_id: &str) -> &[String] {
+        self.index()
+            .referenced_by
+            .get(use_case_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    // ========== Actors ==========
+
+    pub fn actors(&self) -> &[ActorEntity] {
+        &self.actors
+    }
+
+    pub fn personas(&self) -> impl Iterator<Item = &ActorEntity> {
+        self.actors
+            .iter()
+            .filter(|actor| actor.actor_type.is_human())
+    }
+
+    pub fn set_actors(&mut self, actors: Vec<ActorEntity>) {
+        self.actors = actors;
+    }
+
+    // ========== Indices ==========
+
+    fn select(&self, positions: Option<&Vec<usize>>) -> Vec<&UseCase> {
+        positions
+            .map(|positions| positions.iter().map(|&i| &self.use_cases[i]).collect())
+            .unwrap_or_default()
+    }
+
+    fn index(&self) -> &ProjectIndex {
+        self.index
+            .get_or_init(|| ProjectIndex::build(&self.use_cases))
+    }
+}
+
+impl ProjectIndex {
+    fn build(use_cases: &[UseCase]) -> Self {
+        let mut index = Self::default();
+        for (i, use_case) in use_cases.iter().enumerate() {
+            index.by_id.insert(use_case.id.clone(), i);
+            index
+                .by_category
+                .entry(use_case.category.clone())
+                .or_default()
+                .push(i);
+            index
+                .by_status
+                .entry(use_case.status())
+                .or_default()
+                .push(i);
+            for scenario in &use_case.scenarios {
+                index.by_scenario_id.insert(scenario.id.clone(), i);
+                if let Some(persona) = &scenario.persona {
+                    let positions = index.by_persona.entry(persona.clone()).or_default();
+                    if positions.last() != Some(&i) {
+                        positions.push(i);
+                    }
+                }
+            }
+        }
+
+        for use_case in use_cases {
+            let scenario_targets = use_case
+                .scenarios
+                .iter()
+                .flat_map(|scenario| &scenario.references)
+                .map(|reference| match reference.ref_type {
+                    ReferenceType::UseCase => Some(reference.target_id.clone()),
+                    ReferenceType::Scenario => index
+                        .by_scenario_id
+                        .get(&reference.target_id)
+                        .map(|&i| use_cases[i].id.clone()),
+                });
+            let mut targets: Vec<String> = use_case
+                .use_case_references
+                .iter()
+                .filter(|reference| !reference.is_github_issue())
+                .map(|reference| Some(reference.target_id.clone()))
+                .chain(scenario_targets)
+                .flatten()
+                .filter(|target| *target != use_case.id)
+                .collect();
+            targets.sort();
+            targets.dedup();
+
+            for target in &targets {
+                index
+                    .referenced_by
+                    .entry(target.clone())
+                    .or_default()
+                    .push(use_case.id.clone());
+            }
+            if !targets.is_empty() {
+                index.references.insert(use_case.id.clone(), targets);
+            }
+        }
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::{Scenario, ScenarioReference, ScenarioType};
+
+    fn use_case(id: &str, category: &str) -> UseCase {
+        UseCase::builder(id, format!("Use case {}", id))
+            .category(category)
+            .build()
+            .unwrap()
+    }
+
+    fn project() -> Project {
+        let mut login = use_case("UC-AUT-001", "Auth");
+        let mut scenario = Scenario::new(
+            "UC-AUT-001-S01".to_string(),
+            "Valid password".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        );
+        scenario.persona = Some("admin".to_string());
+        scenario.references.push(ScenarioReference::new(
+            ReferenceType::Scenario,
+            "UC-PAY-001-S01".to_string(),
+            "depends_on".to_string(),
+        ));
+        login.add_scenario(scenario);
+
+        let mut pay = use_case("UC-PAY-001", "Payments");
+        pay.add_scenario(Scenario::new(
+            "UC-PAY-001-S01".to_string(),
+            "Card".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        ));
+
+        Project::new(vec![login, pay, use_case("UC-AUT-002", "Auth")])
+    }
+
+    #[test]
+    fn test_lookups() {
+        let project = project();
+        assert_eq!(project.position("UC-AUT-002"), Some(2));
+        assert_eq!(
+            project.owner_of_scenario("UC-PAY-001-S01").unwrap().id,
+            "UC-PAY-001"
+        );
+        assert_eq!(
+            project.categories().collect::<Vec<_>>(),
+            vec!["Auth", "Payments"]
+        );
+        assert_eq!(project.in_category("Auth").len(), 2);
+        assert_eq!(project.for_persona("admin")[0].id, "UC-AUT-001");
+        assert_eq!(project.with_status(Status::Planned).len(), 3);
+    }
+
+    #[test]
+    fn test_reference_graph_follows_scenario_references() {
+        let project = project();
+        assert_eq!(project.references_from("UC-AUT-001"), ["UC-PAY-001"]);
+        assert_eq!(project.referenced_by("UC-PAY-001"), ["UC-AUT-001"]);
+        assert!(project.referenced_by("UC-AUT-001").is_empty());
+    }
+
+    #[test]
+    fn test_mutation_invalidates_indices() {
+        let mut project = project();
+        assert!(project.get("UC-SEC-001").is_none());
+
+        project.add_use_case(use_case("UC-SEC-001", "Security"));
+        assert_eq!(project.position("UC-SEC-001"), Some(3));
+
+        project.use_cases_mut().remove(0);
+        assert_eq!(project.position("UC-SEC-001"), Some(2));
+        assert!(project.referenced_by("UC-PAY-001").is_empty());
+    }
+}
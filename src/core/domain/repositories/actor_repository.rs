@@ -1,6 +1,6 @@
 // Repository trait for actor persistence (personas and system actors)
 use crate::core::domain::{ActorEntity, Persona};
-use anyhow::Result;
+use crate::core::Result;
 
 /// Repository abstraction for actor persistence
 /// This trait defines the contract for storing and retrieving actors (personas and system actors)
@@ -1,6 +1,6 @@
 // Repository trait for persona persistence
 use crate::core::domain::Persona;
-use anyhow::Result;
+use crate::core::Result;
 
 /// Repository abstraction for persona persistence
 /// This trait defines the contract for storing and retrieving personas
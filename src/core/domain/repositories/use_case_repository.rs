@@ -1,6 +1,6 @@
 // Repository trait for use case persistence
 use crate::core::domain::UseCase;
-use anyhow::Result;
+use crate::core::Result;
 
 /// Repository abstraction for use case persistence
 /// This trait defines the contract for storing and retrieving use cases
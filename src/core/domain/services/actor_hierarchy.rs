@@ -0,0 +1,284 @@
+//! Parent/child relations between actors (e.g. department → role) and the
+//! use case counts rolled up over each actor's subtree.
+//!
+//! A use case involves an actor when one of its scenarios is played by the
+//! actor as persona, or a step names it as actor or receiver. Roll-ups count
+//! distinct use cases, so a use case involving a role and its department is
+//! counted once for the department.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::core::domain::entities::{Actor, ActorEntity, UseCase};
+use crate::core::{MucmError, Result};
+
+/// Use cases of one actor
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActorRollup {
+    /// Use cases involving the actor itself
+    pub direct: BTreeSet<String>,
+    /// Use cases involving the actor or any actor below it
+    pub total: BTreeSet<String>,
+}
+
+/// The actor tree of a project
+///
+/// Actors whose parent is missing are treated as roots, so a deleted
+/// department never hides its roles.
+pub struct ActorHierarchy<'a> {
+    actors: BTreeMap<&'a str, &'a ActorEntity>,
+    children: HashMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> ActorHierarchy<'a> {
+    pub fn new(actors: &'a [ActorEntity]) -> Self {
+        let actors: BTreeMap<&str, &ActorEntity> = actors
+            .iter()
+            .map(|actor| (actor.id.as_str(), actor))
+            .collect();
+        let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (id, actor) in &actors {
+            if let Some(parent) = actor.parent().filter(|p| actors.contains_key(p)) {
+                children.entry(parent).or_default().push(id);
+            }
+        }
+        Self { actors, children }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&'a ActorEntity> {
+        self.actors.get(id).copied()
+    }
+
+    /// Parent of `id`, if it exists
+    pub fn parent(&self, id: &str) -> Option<&'a ActorEntity> {
+        self.get(id)?.parent().and_then(|parent| self.get(parent))
+    }
+
+    /// Direct children of `id`, ordered by ID
+    pub fn children(&self, id: &str) -> Vec<&'a ActorEntity> {
+        self.children
+            .get(id)
+            .map(|ids| ids.iter().filter_map(|child| self.get(child)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Actors without a (known) parent, ordered by ID
+    pub fn roots(&self) -> Vec<&'a ActorEntity> {
+        self.actors
+            .values()
+            .filter(|actor| self.parent(&actor.id).is_none())
+            .copied()
+            .collect()
+    }
+
+    /// `id` and every actor below it, depth first (empty for an unknown actor)
+    pub fn subtree(&self, id: &str) -> Vec<&'a str> {
+        let mut subtree = Vec::new();
+        let mut stack: Vec<&str> = self
+            .actors
+            .get_key_value(id)
+            .map(|(&id, _)| id)
+            .into_iter()
+            .collect();
+        while let Some(current) = stack.pop() {
+            // Stored data may contain a cycle; visit each actor once
+            if subtree.contains(&current) {
+                continue;
+            }
+            subtree.push(current);
+            if let Some(children) = self.children.get(current) {
+                stack.extend(children.iter().rev());
+            }
+        }
+        subtree
+    }
+
+    /// Actors in tree order with their depth, roots first
+    ///
+    /// Actors caught in a parent cycle have no root; they follow at depth 0.
+    pub fn walk(&self) -> Vec<(&'a ActorEntity, usize)> {
+        let mut walk = Vec::new();
+        for actor in self
+            .roots()
+            .into_iter()
+            .chain(self.actors.values().copied())
+        {
+            self.walk_from(actor, 0, &mut walk);
+        }
+        walk
+    }
+
+    fn walk_from(
+        &self,
+        actor: &'a ActorEntity,
+        depth: usize,
+        walk: &mut Vec<(&'a ActorEntity, usize)>,
+    ) {
+        if walk.iter().any(|(visited, _)| visited.id == actor.id) {
+            return;
+        }
+        walk.push((actor, depth));
+        for child in self.children(&actor.id) {
+            self.walk_from(child, depth + 1, walk);
+        }
+    }
+
+    /// Check that `parent` can become the parent of `id`
+    ///
+    /// # Errors
+    /// Returns an error when either actor does not exist, or when `parent` is
+    /// `id` itself or one of its descendants
+    pub fn check_parent(&self, id: &str, parent: &str) -> Result<()> {
+        for actor in [id, parent] {
+            if self.get(actor).is_none() {
+                return Err(MucmError::NotFound(format!("Actor '{}' not found", actor)));
+            }
+        }
+        if self.subtree(id).contains(&parent) {
+            return Err(MucmError::Validation(format!(
+                "'{}' cannot be the parent of '{}': it is '{}' or one of its sub-actors",
+                parent, id, id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Direct and rolled-up use cases of every actor
+    pub fn rollups<'u>(
+        &self,
+        use_cases: impl IntoIterator<Item = &'u UseCase>,
+    ) -> BTreeMap<&'a str, ActorRollup> {
+        let mut direct: HashMap<&str, BTreeSet<String>> = HashMap::new();
+        for use_case in use_cases {
+            for id in involved_actors(use_case) {
+                if let Some((&id, _)) = self.actors.get_key_value(id) {
+                    direct.entry(id).or_default().insert(use_case.id.clone());
+                }
+            }
+        }
+
+        self.actors
+            .keys()
+            .map(|&id| {
+                let total = self
+                    .subtree(id)
+                    .into_iter()
+                    .filter_map(|member| direct.get(member))
+                    .flatten()
+                    .cloned()
+                    .collect();
+                let rollup = ActorRollup {
+                    direct: direct.get(id).cloned().unwrap_or_default(),
+                    total,
+                };
+                (id, rollup)
+            })
+            .collect()
+    }
+}
+
+/// IDs of the managed actors a use case involves
+fn involved_actors(use_case: &UseCase) -> BTreeSet<&str> {
+    let mut ids = BTreeSet::new();
+    for scenario in &use_case.scenarios {
+        if let Some(persona) = &scenario.persona {
+            ids.insert(persona.as_str());
+        }
+        for step in &scenario.steps {
+            for actor in std::iter::once(&step.actor).chain(&step.receiver) {
+                if let Actor::ActorRef(id) = actor {
+                    ids.insert(id.as_str());
+                }
+            }
+        }
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::entities::{Priority, Scenario};
+
+    fn actor(id: &str, parent: Option<&str>) -> ActorEntity {
+        let mut actor = ActorEntity::persona(id.to_string(), id.to_string());
+        actor.set_parent(parent.map(str::to_string));
+        actor
+    }
+
+    fn use_case(id: &str, personas: &[&str]) -> UseCase {
+        personas
+            .iter()
+            .fold(
+                UseCase::builder(id, "Title")
+                    .category("Auth")
+                    .priority(Priority::Low),
+                |builder, persona| builder.scenario(Scenario::builder("Main").persona(*persona)),
+            )
+            .build()
+            .unwrap()
+    }
+
+    fn actors() -> Vec<ActorEntity> {
+        vec![
+            actor("sales", None),
+            actor("sales-rep", Some("sales")),
+            actor("sales-lead", Some("sales")),
+            actor("intern", Some("sales-rep")),
+            actor("orphan", Some("deleted-department")),
+        ]
+    }
+
+    #[test]
+    fn test_rollups_count_distinct_use_cases_of_the_subtree() {
+        let actors = actors();
+        let hierarchy = ActorHierarchy::new(&actors);
+        let use_cases = vec![
+            use_case("UC-AUT-001", &["sales-rep", "sales-lead"]),
+            use_case("UC-AUT-002", &["intern"]),
+            use_case("UC-AUT-003", &["sales"]),
+        ];
+
+        let rollups = hierarchy.rollups(&use_cases);
+        assert_eq!(rollups["sales"].direct.len(), 1);
+        assert_eq!(rollups["sales"].total.len(), 3);
+        assert_eq!(rollups["sales-rep"].total.len(), 2);
+        assert_eq!(rollups["sales-lead"].total.len(), 1);
+        assert!(rollups["orphan"].total.is_empty());
+
+        let walk: Vec<(&str, usize)> = hierarchy
+            .walk()
+            .into_iter()
+            .map(|(actor, depth)| (actor.id.as_str(), depth))
+            .collect();
+        assert_eq!(
+            walk,
+            vec![
+                ("orphan", 0),
+                ("sales", 0),
+                ("sales-lead", 1),
+                ("sales-rep", 1),
+                ("intern", 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_parent_rejects_cycles() {
+        let actors = actors();
+        let hierarchy = ActorHierarchy::new(&actors);
+
+        assert!(hierarchy.check_parent("sales-lead", "sales-rep").is_ok());
+        assert!(matches!(
+            hierarchy.check_parent("sales", "intern"),
+            Err(MucmError::Validation(_))
+        ));
+        assert!(matches!(
+            hierarchy.check_parent("sales", "sales"),
+            Err(MucmError::Validation(_))
+        ));
+        assert!(matches!(
+            hierarchy.check_parent("sales", "nobody"),
+            Err(MucmError::NotFound(_))
+        ));
+    }
+}
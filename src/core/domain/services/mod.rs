@@ -1,7 +1,9 @@
 // Domain services - Business logic
 
+mod actor_hierarchy;
 mod scenario_reference_validator;
 mod use_case_service;
 
+pub use actor_hierarchy::{ActorHierarchy, ActorRollup};
 pub use scenario_reference_validator::ScenarioReferenceValidator;
 pub use use_case_service::UseCaseService;
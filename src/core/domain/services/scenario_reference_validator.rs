@@ -1,5 +1,6 @@
 use crate::core::domain::entities::{ReferenceType, UseCase};
-use anyhow::Result;
+use crate::core::MucmError;
+use crate::core::Result;
 use std::collections::{HashMap, HashSet};
 
 /// Validates scenario references and prevents circular dependencies
@@ -17,11 +18,10 @@ impl ScenarioReferenceValidator {
 
         // Check if adding this edge would create a cycle
         if Self::would_create_cycle(&graph, from_scenario_id, to_scenario_id) {
-            anyhow::bail!(
+            return Err(MucmError::Validation(format!(
                 "Adding reference from {} to {} would create a circular dependency",
-                from_scenario_id,
-                to_scenario_id
-            );
+                from_scenario_id, to_scenario_id
+            )));
         }
 
         Ok(())
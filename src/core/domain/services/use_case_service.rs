@@ -6,11 +6,26 @@ use std::path::Path;
 /// Core business logic for use case management
 /// This service focuses purely on domain operations without I/O concerns
 #[derive(Clone)]
-pub struct UseCaseService;
+pub struct UseCaseService {
+    /// Leading part of every generated ID (the "UC" in UC-AUT-001)
+    id_prefix: String,
+}
 
 impl UseCaseService {
     pub fn new() -> Self {
-        Self
+        Self::with_prefix("UC")
+    }
+
+    /// Create a service that generates IDs with a custom prefix (e.g., "PAY" gives PAY-AUT-001)
+    pub fn with_prefix(id_prefix: &str) -> Self {
+        Self {
+            id_prefix: id_prefix.to_string(),
+        }
+    }
+
+    /// Abbreviation of a category used in IDs (e.g. "Authentication" -> "AUT")
+    pub fn category_code(category: &str) -> String {
+        category.to_uppercase().chars().take(3).collect()
     }
 
     /// Generate a use case ID based on category and existing use cases
@@ -21,7 +36,7 @@ impl UseCaseService {
         use_cases: &[UseCase],
         use_case_dir: &str,
     ) -> String {
-        let category_prefix = category.to_uppercase().chars().take(3).collect::<String>();
+        let category_prefix = Self::category_code(category);
         let category_dir = Path::new(use_case_dir).join(to_snake_case(category));
 
         // Find the highest existing number by checking both in-memory and filesystem
@@ -42,7 +57,8 @@ impl UseCaseService {
                 for entry in entries.flatten() {
                     if let Some(file_name) = entry.file_name().to_str() {
                         if file_name.ends_with(".md")
-                            && file_name.starts_with(&format!("UC-{}-", category_prefix))
+                            && file_name
+                                .starts_with(&format!("{}-{}-", self.id_prefix, category_prefix))
                         {
                             let id_part = file_name.trim_end_matches(".md");
                             if let Some(num) =
@@ -56,12 +72,17 @@ impl UseCaseService {
             }
         }
 
-        format!("UC-{}-{:03}", category_prefix, max_number + 1)
+        format!(
+            "{}-{}-{:03}",
+            self.id_prefix,
+            category_prefix,
+            max_number + 1
+        )
     }
 
     /// Extract number from ID like "UC-CON-001" -> Some(1)
     fn extract_number_from_id(&self, id: &str, category_prefix: &str) -> Option<usize> {
-        let expected_prefix = format!("UC-{}-", category_prefix);
+        let expected_prefix = format!("{}-{}-", self.id_prefix, category_prefix);
         if id.starts_with(&expected_prefix) {
             let number_part = &id[expected_prefix.len()..];
             number_part.parse::<usize>().ok()
@@ -155,4 +176,23 @@ mod tests {
         let not_found = find_use_case_by_id(&use_cases, "UC-MISSING-001");
         assert!(not_found.is_none());
     }
+
+    #[test]
+    fn test_use_case_service_custom_prefix() {
+        let service = UseCaseService::with_prefix("PAY");
+        let existing_use_cases = vec![create_test_use_case(
+            "PAY-SEC-004".to_string(),
+            "Login".to_string(),
+            "Security".to_string(),
+            "".to_string(),
+        )];
+        let temp_dir = std::env::temp_dir().join("mucm_test_use_case_service_prefix");
+
+        let new_id = service.generate_unique_use_case_id(
+            "Security",
+            &existing_use_cases,
+            &temp_dir.to_string_lossy(),
+        );
+        assert_eq!(new_id, "PAY-SEC-005");
+    }
 }
@@ -0,0 +1,164 @@
+//! Error type of the core library.
+//!
+//! Every fallible core and infrastructure function returns [`Result`], so
+//! library consumers can match on the kind of failure. The CLI converts these
+//! errors into `anyhow::Error` at its boundary.
+
+use std::fmt;
+use std::io;
+
+/// What went wrong, by kind; the message is meant for the user
+#[derive(Debug, thiserror::Error)]
+pub enum MucmError {
+    /// A use case, scenario, actor, file or other named item does not exist
+    #[error("{0}")]
+    NotFound(String),
+    /// The change clashes with existing data (duplicate IDs, name taken, ...)
+    #[error("{0}")]
+    Conflict(String),
+    /// The input or the data is invalid
+    #[error("{0}")]
+    Validation(String),
+    /// Reading or writing a file failed
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// Loading or rendering a template failed
+    #[error("{0}")]
+    Template(String),
+    /// A storage backend failed or holds data it cannot read
+    #[error("{0}")]
+    Storage(String),
+}
+
+/// Result type of the core library
+pub type Result<T, E = MucmError> = std::result::Result<T, E>;
+
+impl MucmError {
+    /// The same error with `context` in front of its message, keeping its kind
+    pub fn context(self, context: impl fmt::Display) -> Self {
+        let message = |message: String| format!("{}: {}", context, message);
+        match self {
+            MucmError::NotFound(m) => MucmError::NotFound(message(m)),
+            MucmError::Conflict(m) => MucmError::Conflict(message(m)),
+            MucmError::Validation(m) => MucmError::Validation(message(m)),
+            MucmError::Io(e) => MucmError::Io(io::Error::new(e.kind(), message(e.to_string()))),
+            MucmError::Template(m) => MucmError::Template(message(m)),
+            MucmError::Storage(m) => MucmError::Storage(message(m)),
+        }
+    }
+}
+
+/// Add context to errors, keeping their kind
+///
+/// `None` becomes [`MucmError::NotFound`] with the context as its message.
+pub trait Context<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T>;
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, context: F) -> Result<T>;
+}
+
+impl<T, E: Into<MucmError>> Context<T> for std::result::Result<T, E> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.map_err(|e| e.into().context(context))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, context: F) -> Result<T> {
+        self.map_err(|e| e.into().context(context()))
+    }
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.ok_or_else(|| MucmError::NotFound(context.to_string()))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, context: F) -> Result<T> {
+        self.ok_or_else(|| MucmError::NotFound(context().to_string()))
+    }
+}
+
+macro_rules! storage_errors {
+    ($($error:ty),* $(,)?) => {
+        $(impl From<$error> for MucmError {
+            fn from(error: $error) -> Self {
+                MucmError::Storage(error.to_string())
+            }
+        })*
+    };
+}
+
+storage_errors!(
+    serde_json::Error,
+    serde_yaml::Error,
+    toml::de::Error,
+    toml::ser::Error,
+    toml_edit::TomlError,
+    rusqlite::Error,
+    std::string::FromUtf8Error,
+    walkdir::Error,
+    rust_xlsxwriter::XlsxError,
+);
+
+impl From<handlebars::RenderError> for MucmError {
+    fn from(error: handlebars::RenderError) -> Self {
+        MucmError::Template(error.to_string())
+    }
+}
+
+impl From<handlebars::TemplateError> for MucmError {
+    fn from(error: handlebars::TemplateError) -> Self {
+        MucmError::Template(error.to_string())
+    }
+}
+
+impl From<std::fmt::Error> for MucmError {
+    fn from(error: std::fmt::Error) -> Self {
+        MucmError::Io(io::Error::other(error))
+    }
+}
+
+macro_rules! validation_errors {
+    ($($error:ty),* $(,)?) => {
+        $(impl From<$error> for MucmError {
+            fn from(error: $error) -> Self {
+                MucmError::Validation(error.to_string())
+            }
+        })*
+    };
+}
+
+validation_errors!(
+    regex::Error,
+    std::num::ParseIntError,
+    std::path::StripPrefixError,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_keeps_the_kind() {
+        let error = MucmError::NotFound("UC-AUT-009".to_string()).context("Loading");
+        assert!(matches!(&error, MucmError::NotFound(_)));
+        assert_eq!(error.to_string(), "Loading: UC-AUT-009");
+
+        let io: Result<()> = Err(io::Error::from(io::ErrorKind::PermissionDenied).into());
+        let error = io.context("Writing README.md").unwrap_err();
+        assert!(matches!(&error, MucmError::Io(e) if e.kind() == io::ErrorKind::PermissionDenied));
+    }
+
+    #[test]
+    fn test_none_is_not_found() {
+        let error = None::<()>
+            .context("Use case UC-AUT-009 not found")
+            .unwrap_err();
+        assert!(matches!(error, MucmError::NotFound(_)));
+    }
+
+    #[test]
+    fn test_parse_errors_are_storage_errors() {
+        let error: MucmError = serde_json::from_str::<u32>("x").unwrap_err().into();
+        assert!(matches!(error, MucmError::Storage(_)));
+    }
+}
@@ -0,0 +1,248 @@
+//! HTTP requests to the REST APIs the project syncs with (Confluence, Jira,
+//! GitHub).
+//!
+//! Requests go through `curl`, so no TLS stack is linked in, with the request
+//! written to curl's stdin so credentials never show up in the process list.
+
+use serde_json::Value;
+use std::io::{ErrorKind, Write};
+use std::process::{Command, Stdio};
+
+use crate::core::{MucmError, Result};
+
+/// A REST API reached through `curl` with the credentials of one account
+pub struct RestClient {
+    service: &'static str,
+    base_url: String,
+    credentials: String,
+}
+
+impl RestClient {
+    /// Creates a client for `service` at `base_url`, authenticating `user`
+    /// with the token in the environment variable `token_env`
+    ///
+    /// # Errors
+    /// Returns an error when the token variable is not set.
+    pub fn from_env(
+        service: &'static str,
+        base_url: &str,
+        user: &str,
+        token_env: &str,
+    ) -> Result<Self> {
+        let token = std::env::var(token_env).map_err(|_| {
+            MucmError::Validation(format!(
+                "Set the {} environment variable to a {} API token",
+                token_env, service
+            ))
+        })?;
+        Ok(Self {
+            service,
+            base_url: base_url.trim().trim_end_matches('/').to_string(),
+            credentials: format!("{}:{}", user.trim(), token),
+        })
+    }
+
+    /// Sends a request and returns the HTTP status and the JSON response
+    pub fn request(&self, method: &str, path: &str, body: Option<&Value>) -> Result<(u16, Value)> {
+        curl_json(
+            self.service,
+            method,
+            &format!("{}{}", self.base_url, path),
+            &self.credentials,
+            body,
+        )
+    }
+
+    /// Sends a request that must succeed and returns the JSON response
+    pub fn expect(&self, method: &str, path: &str, body: Option<&Value>) -> Result<Value> {
+        let (status, response) = self.request(method, path, body)?;
+        if !(200..300).contains(&status) {
+            return Err(MucmError::Validation(format!(
+                "{} answered {} to {} {}: {}",
+                self.service,
+                status,
+                method,
+                path,
+                error_message(&response)
+            )));
+        }
+        Ok(response)
+    }
+}
+
+/// The error details of an error response
+///
+/// Confluence and GitHub answer with a `message`; Jira with `errorMessages`
+/// and per-field `errors`.
+pub fn error_message(response: &Value) -> String {
+    if let Some(message) = response["message"].as_str() {
+        return message.to_string();
+    }
+    let mut messages: Vec<String> = response["errorMessages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(str::to_string)
+        .collect();
+    if let Some(errors) = response["errors"].as_object() {
+        for (field, message) in errors {
+            messages.push(format!(
+                "{}: {}",
+                field,
+                message.as_str().unwrap_or_default()
+            ));
+        }
+    }
+    if messages.is_empty() {
+        "no details".to_string()
+    } else {
+        messages.join("; ")
+    }
+}
+
+/// Sends a JSON request to `service` through `curl`, authenticated with
+/// `user:token` credentials, and returns the HTTP status and the JSON response
+fn curl_json(
+    service: &str,
+    method: &str,
+    url: &str,
+    credentials: &str,
+    body: Option<&Value>,
+) -> Result<(u16, Value)> {
+    // Everything goes through a curl config on stdin, so the token never
+    // shows up in the process list
+    let mut config = format!(
+        "url = \"{}\"\nuser = \"{}\"\nrequest = \"{}\"\nheader = \"Accept: application/json\"\n",
+        curl_quote(url),
+        curl_quote(credentials),
+        method
+    );
+    if let Some(body) = body {
+        config.push_str("header = \"Content-Type: application/json\"\n");
+        config.push_str(&format!(
+            "data-binary = \"{}\"\n",
+            curl_quote(&body.to_string())
+        ));
+    }
+
+    let child = Command::new("curl")
+        .args(["--silent", "--show-error", "--config", "-"])
+        .args(["--write-out", "\\n%{http_code}"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            return Err(MucmError::NotFound(format!(
+                "curl not found; it is needed to reach {}",
+                service
+            )))
+        }
+        Err(e) => return Err(MucmError::Io(e).context("Failed to run curl")),
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(config.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(MucmError::Validation(format!(
+            "Request to {} failed: {}",
+            service,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    parse_response(service, &String::from_utf8_lossy(&output.stdout))
+}
+
+/// Splits curl's output into the HTTP status written last and the JSON body
+///
+/// An empty body (e.g. `204 No Content`) is `null`; a body that is not JSON,
+/// such as the HTML error page of a proxy, is an error.
+fn parse_response(service: &str, stdout: &str) -> Result<(u16, Value)> {
+    let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", stdout));
+    let status: u16 = status.trim().parse().unwrap_or(0);
+    if body.trim().is_empty() {
+        return Ok((status, Value::Null));
+    }
+    let response = serde_json::from_str(body).map_err(|_| {
+        let excerpt: String = body.trim().chars().take(200).collect();
+        MucmError::Validation(format!(
+            "{} answered {} with a response that is not JSON: {}",
+            service, status, excerpt
+        ))
+    })?;
+    Ok((status, response))
+}
+
+/// Escapes a value for a double-quoted string of a curl config file
+fn curl_quote(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+}
+
+/// Percent-encodes a URL query value
+pub fn query_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                escaped.push(byte as char)
+            }
+            _ => escaped.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(
+            query_escape("UC-AUT-001: Log in"),
+            "UC-AUT-001%3A%20Log%20in"
+        );
+        assert_eq!(curl_quote(r#"{"a":"b\c"}"#), r#"{\"a\":\"b\\c\"}"#);
+    }
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(
+            error_message(&json!({ "message": "Not Found" })),
+            "Not Found"
+        );
+        assert_eq!(
+            error_message(&json!({
+                "errorMessages": ["Issue does not exist"],
+                "errors": { "summary": "required" }
+            })),
+            "Issue does not exist; summary: required"
+        );
+        assert_eq!(error_message(&Value::Null), "no details");
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("Jira", "{\"key\":\"UC-1\"}\n201").unwrap(),
+            (201, json!({ "key": "UC-1" }))
+        );
+        assert_eq!(parse_response("Jira", "\n204").unwrap(), (204, Value::Null));
+
+        let error = parse_response("Jira", "<html>Bad Gateway</html>\n502").unwrap_err();
+        assert!(error.to_string().contains(
+            "Jira answered 502 with a response that is not JSON: <html>Bad Gateway</html>"
+        ));
+    }
+}
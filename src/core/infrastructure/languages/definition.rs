@@ -2,7 +2,9 @@
 //!
 //! Provides concrete implementation of the Language trait loaded from TOML files.
 
-use anyhow::Context;
+use crate::core::Context;
+use crate::core::{MucmError, Result};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
@@ -26,8 +28,24 @@ pub struct LanguageDefinition {
     file_extension: String,
     /// The test template content loaded from the template file
     test_template: String,
+    /// Template file name, relative to the language directory
+    template_file: String,
+    /// Framework variants, with their templates composed from the base
+    variants: BTreeMap<String, LanguageVariant>,
 }
 
+/// A framework variant of a language (e.g. pytest for python)
+#[derive(Debug, Clone)]
+struct LanguageVariant {
+    description: String,
+    test_template: String,
+}
+
+/// Line opening a named section in a base test template
+const SECTION_START: &str = "{{!-- section:";
+/// Line closing a section
+const SECTION_END: &str = "{{!-- endsection --}}";
+
 impl LanguageDefinition {
     /// Creates a new language definition by loading from a TOML configuration file.
     ///
@@ -46,7 +64,7 @@ impl LanguageDefinition {
     /// - The TOML file cannot be read or parsed
     /// - The template file specified in the TOML cannot be found or read
     /// - The `info_path` is not a valid path
-    pub fn from_toml<P: AsRef<Path>>(info_path: P) -> anyhow::Result<Self> {
+    pub fn from_toml<P: AsRef<Path>>(info_path: P) -> Result<Self> {
         // Deserialize directly into a temporary struct for TOML data
         #[derive(serde::Deserialize)]
         struct TomlData {
@@ -54,30 +72,130 @@ impl LanguageDefinition {
             aliases: Vec<String>,
             file_extension: String,
             template_file: String,
+            #[serde(default)]
+            variants: BTreeMap<String, VariantData>,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct VariantData {
+            #[serde(default)]
+            description: String,
+            /// Replaces the base template before sections are applied
+            template_file: Option<String>,
+            /// Section name to the file whose content replaces that section
+            #[serde(default)]
+            sections: BTreeMap<String, String>,
         }
 
         let content = fs::read_to_string(&info_path)?;
         let data: TomlData = toml::from_str(&content)?;
 
-        // Read the template file relative to the info.toml location
-        let template_path = info_path
-            .as_ref()
-            .parent()
-            .ok_or_else(|| anyhow::anyhow!("Invalid info.toml path: no parent directory"))?
-            .join(&data.template_file);
-        let test_template = fs::read_to_string(&template_path).with_context(|| {
-            format!("Failed to read template file: {}", template_path.display())
+        // Read template files relative to the info.toml location
+        let language_dir = info_path.as_ref().parent().ok_or_else(|| {
+            MucmError::Validation("Invalid info.toml path: no parent directory".to_string())
         })?;
+        let read_template = |file: &str| {
+            let template_path = language_dir.join(file);
+            fs::read_to_string(&template_path).with_context(|| {
+                format!("Failed to read template file: {}", template_path.display())
+            })
+        };
+        let base = read_template(&data.template_file)?;
+
+        let mut variants = BTreeMap::new();
+        for (variant_name, variant) in data.variants {
+            let variant_base = match &variant.template_file {
+                Some(file) => read_template(file)?,
+                None => base.clone(),
+            };
+            let mut overrides = BTreeMap::new();
+            for (section, file) in &variant.sections {
+                overrides.insert(section.clone(), read_template(file)?);
+            }
+            let test_template = compose_sections(&variant_base, &overrides).with_context(|| {
+                format!(
+                    "Invalid variant '{}' of language '{}'",
+                    variant_name, data.name
+                )
+            })?;
+            variants.insert(
+                variant_name,
+                LanguageVariant {
+                    description: variant.description,
+                    test_template,
+                },
+            );
+        }
 
         Ok(Self {
             name: data.name,
             aliases: data.aliases,
             file_extension: data.file_extension,
-            test_template,
+            test_template: compose_sections(&base, &BTreeMap::new())?,
+            template_file: data.template_file,
+            variants,
         })
     }
 }
 
+/// Build a template from a base whose sections may be overridden.
+///
+/// Sections are marked in the base by a line `{{!-- section:<name> --}}` and
+/// closed by a line `{{!-- endsection --}}`. The marker lines are removed; a
+/// section's content is kept unless `overrides` replaces it.
+fn compose_sections(base: &str, overrides: &BTreeMap<String, String>) -> Result<String> {
+    let mut output = String::with_capacity(base.len());
+    let mut used = Vec::new();
+    let mut current: Option<&str> = None;
+
+    for line in base.split_inclusive('\n') {
+        let marker = line.trim();
+        if let Some(name) = marker
+            .strip_prefix(SECTION_START)
+            .and_then(|rest| rest.strip_suffix("--}}"))
+        {
+            if let Some(open) = current {
+                return Err(MucmError::Validation(format!(
+                    "Section '{}' starts inside section '{}'",
+                    name.trim(),
+                    open
+                )));
+            }
+            let name = name.trim();
+            if let Some(replacement) = overrides.get(name) {
+                output.push_str(replacement);
+                if !replacement.is_empty() && !replacement.ends_with('\n') {
+                    output.push('\n');
+                }
+                used.push(name);
+            }
+            current = Some(name);
+        } else if marker == SECTION_END {
+            if current.take().is_none() {
+                return Err(MucmError::Validation(
+                    "'endsection' without a matching section".to_string(),
+                ));
+            }
+        } else if !current.is_some_and(|name| overrides.contains_key(name)) {
+            output.push_str(line);
+        }
+    }
+
+    if let Some(open) = current {
+        return Err(MucmError::Validation(format!(
+            "Section '{}' is not closed",
+            open
+        )));
+    }
+    if let Some(unknown) = overrides.keys().find(|name| !used.contains(&name.as_str())) {
+        return Err(MucmError::Template(format!(
+            "The base template has no section '{}'",
+            unknown
+        )));
+    }
+    Ok(output)
+}
+
 /// Implements the Language trait for LanguageDefinition.
 impl Language for LanguageDefinition {
     fn name(&self) -> &str {
@@ -95,6 +213,23 @@ impl Language for LanguageDefinition {
     fn test_template(&self) -> &str {
         &self.test_template
     }
+
+    fn template_file(&self) -> &str {
+        &self.template_file
+    }
+
+    fn variants(&self) -> Vec<(&str, &str)> {
+        self.variants
+            .iter()
+            .map(|(name, variant)| (name.as_str(), variant.description.as_str()))
+            .collect()
+    }
+
+    fn variant_template(&self, variant: &str) -> Option<&str> {
+        self.variants
+            .get(variant)
+            .map(|variant| variant.test_template.as_str())
+    }
 }
 
 #[cfg(test)]
@@ -147,7 +282,7 @@ template_file = "test.hbs""#,
     fn test_language_definition_from_toml() {
         let temp_dir = TempDir::new().unwrap();
         let lang_dir = create_test_language(
-            &temp_dir.path(),
+            temp_dir.path(),
             "testlang",
             &["tl"],
             "tl",
@@ -198,6 +333,40 @@ template_file = "test.hbs""#;
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_variant_overrides_sections() {
+        let temp_dir = TempDir::new().unwrap();
+        let lang_dir = create_test_language(
+            temp_dir.path(),
+            "testlang",
+            &[],
+            "tl",
+            "head\n{{!-- section:imports --}}\nimport base\n{{!-- endsection --}}\n{{!-- section:body --}}\nbody\n{{!-- endsection --}}\n",
+        );
+        fs::write(lang_dir.join("imports.hbs"), "import variant\n").unwrap();
+        let mut info = fs::read_to_string(lang_dir.join("info.toml")).unwrap();
+        info.push_str(
+            "\n[variants.fast]\ndescription = \"Fast runner\"\nsections = { imports = \"imports.hbs\" }\n",
+        );
+        fs::write(lang_dir.join("info.toml"), info).unwrap();
+
+        let lang = LanguageDefinition::from_toml(lang_dir.join("info.toml")).unwrap();
+        assert_eq!(lang.test_template(), "head\nimport base\nbody\n");
+        assert_eq!(
+            lang.variant_template("fast").unwrap(),
+            "head\nimport variant\nbody\n"
+        );
+        assert_eq!(lang.variants(), vec![("fast", "Fast runner")]);
+        assert!(lang.variant_template("slow").is_none());
+    }
+
+    #[test]
+    fn test_compose_rejects_unknown_and_unclosed_sections() {
+        let overrides = BTreeMap::from([("missing".to_string(), "x".to_string())]);
+        assert!(compose_sections("plain\n", &overrides).is_err());
+        assert!(compose_sections("{{!-- section:open --}}\nbody\n", &BTreeMap::new()).is_err());
+    }
+
     #[test]
     fn test_language_definition_from_toml_missing_info_file() {
         let temp_dir = TempDir::new().unwrap();
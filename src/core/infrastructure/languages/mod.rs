@@ -23,6 +23,20 @@
 //!
 //! The `template_file` references a Handlebars template in the same directory that
 //! contains the test generation template for that language.
+//!
+//! ## Framework Variants
+//!
+//! A language can support several test frameworks. The base template marks
+//! sections with `{{!-- section:<name> --}}` ... `{{!-- endsection --}}` lines,
+//! and each variant overrides some of them (or replaces the whole template):
+//!
+//! ```toml
+//! [variants.pytest]
+//! description = "pytest functions and fixtures"
+//! sections = { imports = "variants/pytest/imports.hbs", scenario = "variants/pytest/scenario.hbs" }
+//! ```
+//!
+//! The variant is selected with `generation.test_framework`.
 
 mod definition;
 mod registry;
@@ -2,7 +2,8 @@
 //!
 //! Provides lookup and discovery of languages loaded from the filesystem.
 
-use anyhow::Context;
+use crate::core::Context;
+use crate::core::{MucmError, Result};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
@@ -49,7 +50,7 @@ impl LanguageRegistry {
     /// This function will return an error if:
     /// - The languages directory cannot be read
     /// - Any language's `info.toml` file cannot be loaded
-    pub fn new_dynamic<P: AsRef<Path>>(templates_dir: P) -> anyhow::Result<Self> {
+    pub fn new_dynamic<P: AsRef<Path>>(templates_dir: P) -> Result<Self> {
         let mut languages: HashMap<String, Box<dyn Language>> = HashMap::new();
         let languages_dir = templates_dir.as_ref().join("languages");
 
@@ -86,7 +87,10 @@ impl LanguageRegistry {
                 }
             }
         } else {
-            anyhow::bail!("Languages directory not found: {}", languages_dir.display());
+            return Err(MucmError::NotFound(format!(
+                "Languages directory not found: {}",
+                languages_dir.display()
+            )));
         }
 
         Ok(Self { languages })
@@ -142,7 +146,7 @@ impl LanguageRegistry {
     /// This function will return an error if:
     /// - The templates directory cannot be found or read
     /// - Language loading fails
-    pub fn discover_available<P: AsRef<Path>>(templates_dir: P) -> anyhow::Result<Vec<String>> {
+    pub fn discover_available<P: AsRef<Path>>(templates_dir: P) -> Result<Vec<String>> {
         let registry = Self::new_dynamic(templates_dir)?;
         Ok(registry.available_languages())
     }
@@ -203,7 +207,7 @@ template_file = "test.hbs""#,
         create_test_language(&languages_dir, "lang1", &["l1"], "l1", "template1");
         create_test_language(&languages_dir, "lang2", &["l2", "alt"], "l2", "template2");
 
-        let result = LanguageRegistry::new_dynamic(&temp_dir.path());
+        let result = LanguageRegistry::new_dynamic(temp_dir.path());
         assert!(result.is_ok());
 
         let registry = result.unwrap();
@@ -227,7 +231,7 @@ template_file = "test.hbs""#,
     fn test_language_registry_new_dynamic_no_languages_dir() {
         let temp_dir = TempDir::new().unwrap();
 
-        let result = LanguageRegistry::new_dynamic(&temp_dir.path());
+        let result = LanguageRegistry::new_dynamic(temp_dir.path());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -241,7 +245,7 @@ template_file = "test.hbs""#,
         let languages_dir = temp_dir.path().join("languages");
         fs::create_dir(&languages_dir).unwrap();
 
-        let result = LanguageRegistry::new_dynamic(&temp_dir.path());
+        let result = LanguageRegistry::new_dynamic(temp_dir.path());
         assert!(result.is_ok());
 
         let registry = result.unwrap();
@@ -256,7 +260,7 @@ template_file = "test.hbs""#,
 
         create_test_language(&languages_dir, "rust", &["rs"], "rs", "fn main() {}");
 
-        let registry = LanguageRegistry::new_dynamic(&temp_dir.path()).unwrap();
+        let registry = LanguageRegistry::new_dynamic(temp_dir.path()).unwrap();
 
         // Test primary name
         let lang = registry.get("rust").unwrap();
@@ -289,7 +293,7 @@ template_file = "test.hbs""#,
             "template2",
         );
 
-        let registry = LanguageRegistry::new_dynamic(&temp_dir.path()).unwrap();
+        let registry = LanguageRegistry::new_dynamic(temp_dir.path()).unwrap();
         let available = registry.available_languages();
 
         // Should contain primary names only, not aliases
@@ -322,7 +326,7 @@ template_file = "test.hbs""#,
         .unwrap();
         // Don't create the template file
 
-        let result = LanguageRegistry::new_dynamic(&temp_dir.path());
+        let result = LanguageRegistry::new_dynamic(temp_dir.path());
         assert!(result.is_ok()); // Should succeed despite one bad language
 
         let registry = result.unwrap();
@@ -344,7 +348,7 @@ template_file = "test.hbs""#,
         create_test_language(&languages_dir, "lang1", &["alias1"], "ext1", "template1");
         create_test_language(&languages_dir, "lang2", &["alias2"], "ext2", "template2");
 
-        let result = LanguageRegistry::discover_available(&temp_dir.path());
+        let result = LanguageRegistry::discover_available(temp_dir.path());
         assert!(result.is_ok());
 
         let languages = result.unwrap();
@@ -16,10 +16,24 @@ pub trait Language {
     fn aliases(&self) -> Vec<&str>;
 
     /// Returns the file extension typically used for this language (e.g., "rs", "py").
-    /// Currently unused but reserved for future test file generation features.
     fn file_extension(&self) -> &str;
 
     /// Returns the Handlebars template content used for generating test files
     /// for this language.
     fn test_template(&self) -> &str;
+
+    /// Returns the file of the language directory the test template is read from
+    /// (e.g. "test.hbs").
+    fn template_file(&self) -> &str;
+
+    /// Returns the framework variants of this language as (name, description) pairs.
+    fn variants(&self) -> Vec<(&str, &str)> {
+        Vec::new()
+    }
+
+    /// Returns the test template of a framework variant (e.g. "pytest"), composed
+    /// from the base template and the variant's section overrides.
+    fn variant_template(&self, _variant: &str) -> Option<&str> {
+        None
+    }
 }
@@ -2,13 +2,15 @@
 //!
 //! Provides concrete implementation of the Methodology trait loaded from TOML files.
 
-use anyhow::Context;
-use std::collections::HashMap;
+use crate::config::OverviewSection;
+use crate::core::Context;
+use crate::core::Result;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
 use super::r#trait::{DocumentationLevel, Methodology};
-use super::types::CustomFieldConfig;
+use super::types::{CustomFieldConfig, EstimationFieldConfig, LifecycleConfig};
 
 /// A methodology definition loaded from external TOML configuration.
 ///
@@ -31,6 +33,8 @@ pub struct MethodologyDefinition {
     when_to_use: Vec<String>,
     /// Key features of this methodology
     key_features: Vec<String>,
+    /// Practices recommended when documenting with this methodology
+    best_practices: Vec<String>,
     /// Available documentation levels
     levels: Vec<DocumentationLevel>,
     /// Preferred documentation style
@@ -39,6 +43,12 @@ pub struct MethodologyDefinition {
     custom_fields: HashMap<String, CustomFieldConfig>,
     /// Per-level configuration (for field resolution with inheritance)
     pub(crate) level_configs: HashMap<String, LevelConfig>,
+    /// Estimation fields collected per scenario by `mucm estimate`
+    estimation_fields: BTreeMap<String, EstimationFieldConfig>,
+    /// Overview sections used when the project config does not choose them
+    overview_sections: Option<Vec<OverviewSection>>,
+    /// Defaults applied to use cases created with this methodology
+    lifecycle: LifecycleConfig,
 }
 
 /// Configuration for a specific documentation level
@@ -70,7 +80,7 @@ impl MethodologyDefinition {
     /// - The TOML file cannot be read or parsed
     /// - Required fields are missing from the TOML
     /// - The `methodology_dir` is not a valid path
-    pub fn from_toml<P: AsRef<Path>>(methodology_dir: P) -> anyhow::Result<Self> {
+    pub fn from_toml<P: AsRef<Path>>(methodology_dir: P) -> Result<Self> {
         let methodology_dir = methodology_dir.as_ref();
 
         // Single unified structure for methodology.toml
@@ -81,6 +91,23 @@ impl MethodologyDefinition {
             usage: UsageConfig,
             #[serde(default)]
             levels: HashMap<String, LevelWithCustomFields>,
+            #[serde(default)]
+            estimation: EstimationConfig,
+            #[serde(default)]
+            overview: OverviewConfig,
+            #[serde(default)]
+            lifecycle: LifecycleConfig,
+        }
+
+        #[derive(serde::Deserialize, Default)]
+        struct OverviewConfig {
+            sections: Option<Vec<OverviewSection>>,
+        }
+
+        #[derive(serde::Deserialize, Default)]
+        struct EstimationConfig {
+            #[serde(default)]
+            fields: BTreeMap<String, EstimationFieldConfig>,
         }
 
         #[derive(serde::Deserialize)]
@@ -98,6 +125,8 @@ impl MethodologyDefinition {
         struct UsageConfig {
             when_to_use: Vec<String>,
             key_features: Vec<String>,
+            #[serde(default)]
+            best_practices: Vec<String>,
         }
 
         #[derive(serde::Deserialize)]
@@ -124,8 +153,8 @@ impl MethodologyDefinition {
         // Convert levels to the expected format
         let levels: Vec<DocumentationLevel> = data
             .levels
-            .iter()
-            .map(|(_level_name, level_data)| DocumentationLevel {
+            .values()
+            .map(|level_data| DocumentationLevel {
                 name: level_data.name.clone(),
                 abbreviation: level_data.abbreviation.clone(),
                 filename: level_data.filename.clone(),
@@ -151,7 +180,7 @@ impl MethodologyDefinition {
 
         // Flatten all custom fields from all levels for backward compatibility
         let mut all_custom_fields = HashMap::new();
-        for (_level_name, level_data) in &data.levels {
+        for level_data in data.levels.values() {
             all_custom_fields.extend(level_data.custom_fields.clone());
         }
 
@@ -161,12 +190,51 @@ impl MethodologyDefinition {
             description: data.methodology.description,
             when_to_use: data.usage.when_to_use,
             key_features: data.usage.key_features,
+            best_practices: data.usage.best_practices,
             levels,
             preferred_style: data.template.preferred_style,
             custom_fields: all_custom_fields,
             level_configs,
+            estimation_fields: data.estimation.fields,
+            overview_sections: data.overview.sections,
+            lifecycle: data.lifecycle,
         })
     }
+
+    /// Estimation fields declared in the `[estimation]` section, ordered by name
+    pub fn estimation_fields(&self) -> &BTreeMap<String, EstimationFieldConfig> {
+        &self.estimation_fields
+    }
+
+    /// Overview sections chosen in the `[overview]` section, if any
+    pub fn overview_sections(&self) -> Option<&[OverviewSection]> {
+        self.overview_sections.as_deref()
+    }
+
+    /// Creation defaults declared in the `[lifecycle]` section
+    pub fn lifecycle(&self) -> &LifecycleConfig {
+        &self.lifecycle
+    }
+
+    /// Best practices listed in the `[usage]` section
+    pub fn best_practices(&self) -> &[String] {
+        &self.best_practices
+    }
+
+    /// Custom fields declared by the level itself, without inherited ones
+    pub fn level_fields(&self, level_name: &str) -> Option<&HashMap<String, CustomFieldConfig>> {
+        self.level_configs
+            .get(&level_name.to_lowercase())
+            .map(|config| &config.custom_fields)
+    }
+
+    /// Scenario template of the level, if it names one
+    pub fn scenario_template(&self, level_name: &str) -> Option<&str> {
+        self.level_configs
+            .get(&level_name.to_lowercase())?
+            .scenario_template
+            .as_deref()
+    }
 }
 
 impl Methodology for MethodologyDefinition {
@@ -206,6 +274,7 @@ impl Methodology for MethodologyDefinition {
 #[cfg(test)]
 mod tests {
     use super::super::r#trait::Methodology;
+    use super::super::types::EstimateAggregate;
     use super::*;
     use std::fs;
     use tempfile::TempDir;
@@ -277,7 +346,7 @@ inherits = ["Normal"]
     fn test_methodology_definition_from_toml() {
         let temp_dir = TempDir::new().unwrap();
         let methodology_dir = create_test_methodology(
-            &temp_dir.path(),
+            temp_dir.path(),
             "testmethod",
             "Test Methodology",
             "Test description",
@@ -402,20 +471,20 @@ hypothesis = { label = "Product Hypothesis", type = "text", required = false, de
         let user_segment = custom_fields.get("user_segment").unwrap();
         assert_eq!(user_segment.label, Some("Target User Segment".to_string()));
         assert_eq!(user_segment.field_type, "string");
-        assert_eq!(user_segment.required, true);
+        assert!(user_segment.required);
         assert_eq!(user_segment.default, None);
 
         // Check success_metrics field
         let success_metrics = custom_fields.get("success_metrics").unwrap();
         assert_eq!(success_metrics.label, Some("Success Metrics".to_string()));
         assert_eq!(success_metrics.field_type, "array");
-        assert_eq!(success_metrics.required, true);
+        assert!(success_metrics.required);
 
         // Check hypothesis field (with default)
         let hypothesis = custom_fields.get("hypothesis").unwrap();
         assert_eq!(hypothesis.label, Some("Product Hypothesis".to_string()));
         assert_eq!(hypothesis.field_type, "text");
-        assert_eq!(hypothesis.required, false);
+        assert!(!hypothesis.required);
         assert_eq!(hypothesis.default, Some("To be defined".to_string()));
     }
 
@@ -481,5 +550,126 @@ overwrite_test_documentation = false
         // Custom fields should be empty (thanks to #[serde(default)])
         let custom_fields = methodology.custom_fields();
         assert_eq!(custom_fields.len(), 0);
+        assert!(methodology.estimation_fields().is_empty());
+        assert!(methodology.overview_sections().is_none());
+        assert_eq!(methodology.lifecycle(), &LifecycleConfig::default());
+    }
+
+    #[test]
+    fn test_methodology_with_estimation_fields() {
+        let temp_dir = TempDir::new().unwrap();
+        let methodology_dir = temp_dir.path().join("estimated");
+        fs::create_dir(&methodology_dir).unwrap();
+
+        fs::write(
+            methodology_dir.join("methodology.toml"),
+            r#"
+[methodology]
+name = "estimated"
+description = "Methodology with estimation fields"
+
+[template]
+preferred_style = "simple"
+
+[usage]
+when_to_use = ["Planning"]
+key_features = ["Estimates"]
+
+[estimation.fields.effort]
+label = "Effort (story points)"
+scale = [1, 2, 3, 5, 8]
+
+[estimation.fields.complexity]
+aggregate = "max"
+
+[overview]
+sections = ["summary", "risks", "table"]
+"#,
+        )
+        .unwrap();
+
+        let methodology = MethodologyDefinition::from_toml(&methodology_dir).unwrap();
+        assert_eq!(
+            methodology.overview_sections(),
+            Some(
+                &[
+                    OverviewSection::Summary,
+                    OverviewSection::Risks,
+                    OverviewSection::Table
+                ][..]
+            )
+        );
+        let fields = methodology.estimation_fields();
+        assert_eq!(
+            fields.keys().collect::<Vec<_>>(),
+            vec!["complexity", "effort"]
+        );
+
+        let effort = &fields["effort"];
+        assert_eq!(effort.label_or("effort"), "Effort (story points)");
+        assert_eq!(effort.scale, vec![1.0, 2.0, 3.0, 5.0, 8.0]);
+        assert_eq!(effort.aggregate, EstimateAggregate::Sum);
+
+        let complexity = &fields["complexity"];
+        assert_eq!(complexity.label_or("complexity"), "complexity");
+        assert!(complexity.scale.is_empty());
+        assert_eq!(complexity.aggregate, EstimateAggregate::Max);
+    }
+
+    #[test]
+    fn test_methodology_with_lifecycle() {
+        use crate::core::domain::{ScenarioType, Status};
+
+        let temp_dir = TempDir::new().unwrap();
+        let methodology_dir = temp_dir.path().join("guarded");
+        fs::create_dir(&methodology_dir).unwrap();
+
+        fs::write(
+            methodology_dir.join("methodology.toml"),
+            r#"
+[methodology]
+name = "guarded"
+description = "Methodology with lifecycle defaults"
+
+[template]
+preferred_style = "simple"
+
+[usage]
+when_to_use = ["Always"]
+key_features = ["Defaults"]
+
+[lifecycle]
+initial_status = "in_progress"
+
+[lifecycle.views]
+advanced = ["feature:normal"]
+
+[[lifecycle.scenarios]]
+title = "Error handling"
+type = "exception_flow"
+
+[[lifecycle.scenarios]]
+title = "Main flow"
+"#,
+        )
+        .unwrap();
+
+        let lifecycle = MethodologyDefinition::from_toml(&methodology_dir)
+            .unwrap()
+            .lifecycle()
+            .clone();
+        assert_eq!(lifecycle.initial_status, Some(Status::InProgress));
+        assert_eq!(lifecycle.views["advanced"], vec!["feature:normal"]);
+        assert_eq!(lifecycle.scenarios.len(), 2);
+        assert_eq!(lifecycle.scenarios[0].title, "Error handling");
+        assert_eq!(
+            lifecycle.scenarios[0].scenario_type,
+            ScenarioType::ExceptionFlow
+        );
+        assert_eq!(
+            lifecycle.scenarios[1].scenario_type,
+            ScenarioType::HappyPath
+        );
+        assert!(lifecycle.scenarios[1].description.is_empty());
     }
 }
@@ -4,6 +4,7 @@
 //! taking into account level inheritance (e.g., normal inherits from simple).
 
 use super::{CustomFieldConfig, DocumentationLevel, Methodology, MethodologyDefinition};
+use crate::core::{MucmError, Result};
 use std::collections::{HashMap, HashSet};
 
 /// Resolves custom fields for a specific methodology/level with inheritance
@@ -23,13 +24,13 @@ impl<'a> FieldResolver<'a> {
     pub fn resolve_fields_for_level(
         &self,
         level_name: &str,
-    ) -> anyhow::Result<HashMap<String, CustomFieldConfig>> {
+    ) -> Result<HashMap<String, CustomFieldConfig>> {
         let level = self
             .methodology
             .levels()
             .iter()
             .find(|l| l.name == level_name)
-            .ok_or_else(|| anyhow::anyhow!("Level '{}' not found", level_name))?;
+            .ok_or_else(|| MucmError::NotFound(format!("Level '{}' not found", level_name)))?;
 
         // Get inheritance chain (parents first, target level last)
         let chain = self.get_inheritance_chain(level)?;
@@ -54,10 +55,7 @@ impl<'a> FieldResolver<'a> {
     ///
     /// Uses a depth-first traversal to build the chain, ensuring parent fields
     /// are processed before child fields.
-    fn get_inheritance_chain(
-        &self,
-        level: &DocumentationLevel,
-    ) -> anyhow::Result<Vec<DocumentationLevel>> {
+    fn get_inheritance_chain(&self, level: &DocumentationLevel) -> Result<Vec<DocumentationLevel>> {
         let mut chain = Vec::new();
         let mut visited = HashSet::new();
         self.build_inheritance_chain(level, &mut chain, &mut visited)?;
@@ -70,13 +68,13 @@ impl<'a> FieldResolver<'a> {
         level: &DocumentationLevel,
         chain: &mut Vec<DocumentationLevel>,
         visited: &mut HashSet<String>,
-    ) -> anyhow::Result<()> {
+    ) -> Result<()> {
         // Detect cycles
         if visited.contains(&level.name) {
-            return Err(anyhow::anyhow!(
+            return Err(MucmError::Validation(format!(
                 "Circular inheritance detected at level '{}'",
                 level.name
-            ));
+            )));
         }
         visited.insert(level.name.clone());
 
@@ -88,11 +86,10 @@ impl<'a> FieldResolver<'a> {
                 .iter()
                 .find(|l| l.name == *parent_name)
                 .ok_or_else(|| {
-                    anyhow::anyhow!(
+                    MucmError::NotFound(format!(
                         "Parent level '{}' not found for level '{}'",
-                        parent_name,
-                        level.name
-                    )
+                        parent_name, level.name
+                    ))
                 })?;
 
             self.build_inheritance_chain(parent, chain, visited)?;
@@ -19,6 +19,7 @@
 //! - Template configuration (preferred style, generation options)
 //! - Documentation levels with custom fields
 //! - Usage guidance (when to use, key features, best practices)
+//! - Optional estimation fields collected per scenario
 //!
 //! The methodology directory also contains Handlebars templates for different documentation styles.
 
@@ -33,4 +34,6 @@ pub use definition::MethodologyDefinition;
 pub use field_resolver::FieldResolver;
 pub use r#trait::{DocumentationLevel, Methodology};
 pub use registry::MethodologyRegistry;
-pub use types::CustomFieldConfig;
+pub use types::{
+    CustomFieldConfig, EstimateAggregate, EstimationFieldConfig, LifecycleConfig, LifecycleScenario,
+};
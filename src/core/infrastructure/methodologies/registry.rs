@@ -2,7 +2,8 @@
 //!
 //! Provides lookup and discovery of methodologies loaded from the filesystem.
 
-use anyhow::Context;
+use crate::core::Context;
+use crate::core::Result;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
@@ -36,7 +37,7 @@ impl MethodologyRegistry {
     /// This function will return an error if:
     /// - The methodologies directory cannot be found or read
     /// - Methodology loading fails for any methodology
-    pub fn new_dynamic<P: AsRef<Path>>(templates_dir: P) -> anyhow::Result<Self> {
+    pub fn new_dynamic<P: AsRef<Path>>(templates_dir: P) -> Result<Self> {
         let methodologies_dir = templates_dir.as_ref().join("methodologies");
 
         if !methodologies_dir.exists() {
@@ -127,7 +128,7 @@ impl MethodologyRegistry {
     /// This function will return an error if:
     /// - The templates directory cannot be found or read
     /// - Methodology loading fails
-    pub fn discover_available<P: AsRef<Path>>(templates_dir: P) -> anyhow::Result<Vec<String>> {
+    pub fn discover_available<P: AsRef<Path>>(templates_dir: P) -> Result<Vec<String>> {
         let registry = Self::new_dynamic(templates_dir)?;
         Ok(registry.available_methodologies())
     }
@@ -228,7 +229,7 @@ inherits = ["Normal"]
             "detailed",
         );
 
-        let result = MethodologyRegistry::new_dynamic(&temp_dir.path());
+        let result = MethodologyRegistry::new_dynamic(temp_dir.path());
         assert!(result.is_ok());
 
         let registry = result.unwrap();
@@ -249,7 +250,7 @@ inherits = ["Normal"]
     fn test_methodology_registry_new_dynamic_no_methodologies_dir() {
         let temp_dir = TempDir::new().unwrap();
 
-        let result = MethodologyRegistry::new_dynamic(&temp_dir.path());
+        let result = MethodologyRegistry::new_dynamic(temp_dir.path());
         assert!(result.is_ok());
 
         let registry = result.unwrap();
@@ -270,7 +271,7 @@ inherits = ["Normal"]
             "detailed",
         );
 
-        let registry = MethodologyRegistry::new_dynamic(&temp_dir.path()).unwrap();
+        let registry = MethodologyRegistry::new_dynamic(temp_dir.path()).unwrap();
 
         // Test primary name
         let methodology = registry.get("business").unwrap();
@@ -305,7 +306,7 @@ inherits = ["Normal"]
             "detailed",
         );
 
-        let registry = MethodologyRegistry::new_dynamic(&temp_dir.path()).unwrap();
+        let registry = MethodologyRegistry::new_dynamic(temp_dir.path()).unwrap();
         let available = registry.available_methodologies();
 
         assert!(available.contains(&"method1".to_string()));
@@ -333,7 +334,7 @@ inherits = ["Normal"]
         fs::create_dir(&bad_methodology_dir).unwrap();
         fs::write(bad_methodology_dir.join("methodology.toml"), "invalid toml").unwrap();
 
-        let result = MethodologyRegistry::new_dynamic(&temp_dir.path());
+        let result = MethodologyRegistry::new_dynamic(temp_dir.path());
         assert!(result.is_ok()); // Should succeed despite one bad methodology
 
         let registry = result.unwrap();
@@ -366,7 +367,7 @@ inherits = ["Normal"]
             "detailed",
         );
 
-        let result = MethodologyRegistry::discover_available(&temp_dir.path());
+        let result = MethodologyRegistry::discover_available(temp_dir.path());
         assert!(result.is_ok());
 
         let methodologies = result.unwrap();
@@ -4,6 +4,9 @@
 //! in specific methodologies. They allow methodologies to capture specialized
 //! information relevant to their documentation style.
 
+use crate::core::domain::{ScenarioType, Status};
+use std::collections::HashMap;
+
 /// Configuration for custom fields specific to a methodology.
 ///
 /// Custom fields extend the standard use case fields and are only available
@@ -34,9 +37,13 @@ pub struct CustomFieldConfig {
     /// If not provided, the field name will be used (converted to title case)
     #[serde(default)]
     pub label: Option<String>,
-    /// Data type of the field: "string", "array", "number", "boolean", "text"
+    /// Data type of the field: "string", "array", "number", "boolean", "text", "table"
     #[serde(rename = "type")]
     pub field_type: String,
+    /// Column names of a "table" field, whose value is a list of rows keyed
+    /// by column name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<String>,
     /// Whether this field must be provided when creating a use case with this methodology
     #[serde(default)]
     pub required: bool,
@@ -51,3 +58,109 @@ pub struct CustomFieldConfig {
     #[serde(default)]
     pub example: Option<String>,
 }
+
+/// An estimate collected per scenario and rolled up onto the use case.
+///
+/// Declared in the optional `[estimation]` section of `methodology.toml`.
+/// Values are numbers; `scale` restricts them to a fixed set (e.g. story points).
+///
+/// # Example
+///
+/// ```toml
+/// [estimation.fields.effort]
+/// label = "Effort (story points)"
+/// scale = [1, 2, 3, 5, 8, 13]
+/// aggregate = "sum"
+///
+/// [estimation.fields.complexity]
+/// label = "Complexity (1-5)"
+/// scale = [1, 2, 3, 4, 5]
+/// aggregate = "max"
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct EstimationFieldConfig {
+    /// Human-readable label displayed in prompts and reports
+    /// If not provided, the field name will be used
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Description of the field, shown as help text in prompts
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Allowed values; empty allows any non-negative number
+    #[serde(default)]
+    pub scale: Vec<f64>,
+    /// How scenario estimates are combined into the use case estimate
+    #[serde(default)]
+    pub aggregate: EstimateAggregate,
+}
+
+impl EstimationFieldConfig {
+    /// Label to display, falling back to the field name
+    pub fn label_or<'a>(&'a self, name: &'a str) -> &'a str {
+        self.label.as_deref().unwrap_or(name)
+    }
+}
+
+/// How scenario estimates are combined into the use case estimate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum EstimateAggregate {
+    /// Total of all scenario estimates (effort, story points)
+    #[default]
+    Sum,
+    /// Highest scenario estimate (complexity, risk)
+    Max,
+    /// Mean of the scenario estimates
+    Average,
+}
+
+impl std::fmt::Display for EstimateAggregate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            EstimateAggregate::Sum => "sum",
+            EstimateAggregate::Max => "max",
+            EstimateAggregate::Average => "average",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Defaults applied to use cases created with a methodology.
+///
+/// # Example
+///
+/// ```toml
+/// [lifecycle]
+/// initial_status = "planned"
+///
+/// # Views added next to a level's own view
+/// [lifecycle.views]
+/// advanced = ["feature:normal"]
+///
+/// [[lifecycle.scenarios]]
+/// title = "Error handling"
+/// type = "exception_flow"
+/// description = "What happens when the main flow fails"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+pub struct LifecycleConfig {
+    /// Status of the scenarios added on creation, and so of the new use case
+    #[serde(default)]
+    pub initial_status: Option<Status>,
+    /// Extra views per level, as `methodology:level` (or a level of this methodology)
+    #[serde(default)]
+    pub views: HashMap<String, Vec<String>>,
+    /// Scenarios added to every new use case
+    #[serde(default)]
+    pub scenarios: Vec<LifecycleScenario>,
+}
+
+/// A scenario added to new use cases by a methodology's lifecycle
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub struct LifecycleScenario {
+    pub title: String,
+    #[serde(rename = "type", default)]
+    pub scenario_type: ScenarioType,
+    #[serde(default)]
+    pub description: String,
+}
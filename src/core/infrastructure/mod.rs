@@ -1,5 +1,6 @@
 // Infrastructure layer - Implementation details
 
+pub(crate) mod http;
 mod languages;
 mod methodologies;
 mod persistence;
@@ -8,11 +9,15 @@ mod template_engine;
 // Re-exports
 pub use languages::LanguageRegistry;
 pub use methodologies::{
-    CustomFieldConfig, DocumentationLevel, FieldResolver, Methodology, MethodologyDefinition,
-    MethodologyRegistry,
+    CustomFieldConfig, DocumentationLevel, EstimateAggregate, EstimationFieldConfig, FieldResolver,
+    LifecycleConfig, LifecycleScenario, Methodology, MethodologyDefinition, MethodologyRegistry,
 };
 pub use persistence::{
-    file_operations, RepositoryFactory, SqliteActorRepository, SqliteUseCaseRepository,
-    TomlActorRepository, TomlUseCaseRepository, UseCaseRepository,
+    block_on, file_operations, AsyncUseCaseRepository, BackupSnapshot, BackupStore,
+    BlockingRepository, EventUseCaseRepository, InMemoryActorRepository, InMemoryUseCaseRepository,
+    LoadFailure, LoadReport, ProjectLock, RepairAction, RepairOutcome, RepositoryFactory,
+    RepositoryFuture, RepositoryTransaction, SqliteActorRepository, SqliteUseCaseRepository,
+    SyncRepositoryAdapter, TomlActorRepository, TomlUseCaseRepository, UseCaseRepository,
+    EVENT_LOG,
 };
-pub use template_engine::TemplateEngine;
+pub use template_engine::{TemplateEngine, TemplateFile};
@@ -0,0 +1,311 @@
+//! Adapters between the synchronous and asynchronous repository traits.
+//!
+//! - [`SyncRepositoryAdapter`] exposes a local (blocking) backend through
+//!   [`AsyncUseCaseRepository`].
+//! - [`BlockingRepository`] lets synchronous code use any
+//!   [`AsyncUseCaseRepository`] by driving its futures to completion.
+//!
+//! Every backend shipped with mucm is synchronous. The synchronous
+//! [`UseCaseCoordinator`](crate::core::UseCaseCoordinator) reaches async
+//! repositories through [`BlockingRepository`]; servers use
+//! [`AsyncUseCaseCoordinator`](crate::core::AsyncUseCaseCoordinator), which
+//! awaits them instead.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use crate::core::utils::span;
+use crate::core::Result;
+
+use super::load_report::{LoadReport, RepairAction};
+use super::project_lock::ProjectLock;
+use super::traits::{AsyncUseCaseRepository, RepositoryFuture, UseCaseRepository};
+use super::transaction::RepositoryTransaction;
+use crate::core::domain::UseCase;
+
+/// Exposes a synchronous repository through the async trait.
+///
+/// The wrapped calls still block while their future is polled; this is meant
+/// for local storage where calls finish quickly.
+pub struct SyncRepositoryAdapter<R: ?Sized> {
+    inner: Box<R>,
+}
+
+impl<R: UseCaseRepository + Send + Sync> SyncRepositoryAdapter<R> {
+    pub fn new(inner: R) -> Self {
+        Self::from_boxed(Box::new(inner))
+    }
+}
+
+impl<R: UseCaseRepository + Send + Sync + ?Sized> SyncRepositoryAdapter<R> {
+    /// Wraps an already boxed repository, such as one made by
+    /// [`RepositoryFactory::create`](super::RepositoryFactory::create)
+    pub fn from_boxed(inner: Box<R>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<R: UseCaseRepository + Send + Sync + ?Sized> AsyncUseCaseRepository
+    for SyncRepositoryAdapter<R>
+{
+    fn save<'a>(&'a self, use_case: &'a UseCase) -> RepositoryFuture<'a, ()> {
+        Box::pin(async move { self.inner.save(use_case) })
+    }
+
+    fn load_all(&self) -> RepositoryFuture<'_, Vec<UseCase>> {
+        Box::pin(async move { self.inner.load_all() })
+    }
+
+    fn load_all_with_report(&self) -> RepositoryFuture<'_, LoadReport> {
+        Box::pin(async move { self.inner.load_all_with_report() })
+    }
+
+    fn load_by_id<'a>(&'a self, id: &'a str) -> RepositoryFuture<'a, Option<UseCase>> {
+        Box::pin(async move { self.inner.load_by_id(id) })
+    }
+
+    fn delete<'a>(&'a self, id: &'a str) -> RepositoryFuture<'a, ()> {
+        Box::pin(async move { self.inner.delete(id) })
+    }
+
+    fn commit(&self, transaction: RepositoryTransaction) -> RepositoryFuture<'_, ()> {
+        Box::pin(async move { self.inner.commit(transaction) })
+    }
+
+    fn repair(&self) -> RepositoryFuture<'_, Vec<RepairAction>> {
+        Box::pin(async move { self.inner.repair() })
+    }
+
+    fn save_markdown<'a>(
+        &'a self,
+        use_case_id: &'a str,
+        content: &'a str,
+    ) -> RepositoryFuture<'a, ()> {
+        Box::pin(async move { self.inner.save_markdown(use_case_id, content) })
+    }
+
+    fn save_markdown_with_filename<'a>(
+        &'a self,
+        use_case: &'a UseCase,
+        filename: &'a str,
+        content: &'a str,
+    ) -> RepositoryFuture<'a, ()> {
+        Box::pin(async move {
+            self.inner
+                .save_markdown_with_filename(use_case, filename, content)
+        })
+    }
+}
+
+/// Synchronous view of an async repository, for existing callers.
+///
+/// Each call blocks the current thread until the future completes. Futures
+/// that depend on a specific runtime's reactor (e.g. tokio sockets) must be
+/// created inside that runtime; this adapter only parks and wakes the thread.
+pub struct BlockingRepository {
+    inner: Box<dyn AsyncUseCaseRepository>,
+    /// Root of the project whose lock guards the data, if any
+    lock_root: Option<PathBuf>,
+}
+
+impl BlockingRepository {
+    pub fn new(inner: Box<dyn AsyncUseCaseRepository>) -> Self {
+        Self {
+            inner,
+            lock_root: None,
+        }
+    }
+
+    /// Lock the data of the project at `root` around every read and write of
+    /// use case data.
+    ///
+    /// Writes nest inside the command lock of a command that holds it. Reads
+    /// only wait for a write in progress, never for a whole command. Writes also bump the
+    /// project revision, so other processes holding the use cases in memory
+    /// reload them when they next compare revisions.
+    pub fn with_project_lock(mut self, root: impl Into<PathBuf>) -> Self {
+        self.lock_root = Some(root.into());
+        self
+    }
+
+    /// Run `operation` under the project lock, recording a change if it `writes`
+    fn locked<T>(&self, writes: bool, operation: impl FnOnce() -> Result<T>) -> Result<T> {
+        let Some(root) = &self.lock_root else {
+            return operation();
+        };
+        let lock = if writes {
+            ProjectLock::for_writing(root)?
+        } else {
+            ProjectLock::for_reading(root)?
+        };
+        let result = operation()?;
+        if writes {
+            lock.bump_revision()?;
+        }
+        Ok(result)
+    }
+}
+
+impl UseCaseRepository for BlockingRepository {
+    fn save(&self, use_case: &UseCase) -> Result<()> {
+        let _span = span(|| "write".to_string());
+        self.locked(true, || block_on(self.inner.save(use_case)))
+    }
+
+    fn load_all(&self) -> Result<Vec<UseCase>> {
+        let _span = span(|| "load".to_string());
+        self.locked(false, || block_on(self.inner.load_all()))
+    }
+
+    fn load_all_with_report(&self) -> Result<LoadReport> {
+        let _span = span(|| "load".to_string());
+        self.locked(false, || block_on(self.inner.load_all_with_report()))
+    }
+
+    fn load_by_id(&self, id: &str) -> Result<Option<UseCase>> {
+        let _span = span(|| "load".to_string());
+        self.locked(false, || block_on(self.inner.load_by_id(id)))
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        let _span = span(|| "write".to_string());
+        self.locked(true, || block_on(self.inner.delete(id)))
+    }
+
+    fn commit(&self, transaction: RepositoryTransaction) -> Result<()> {
+        let _span = span(|| "write".to_string());
+        self.locked(true, || block_on(self.inner.commit(transaction)))
+    }
+
+    fn repair(&self) -> Result<Vec<RepairAction>> {
+        self.locked(true, || block_on(self.inner.repair()))
+    }
+
+    fn save_markdown(&self, use_case_id: &str, content: &str) -> Result<()> {
+        let _span = span(|| "write".to_string());
+        block_on(self.inner.save_markdown(use_case_id, content))
+    }
+
+    fn save_markdown_with_filename(
+        &self,
+        use_case: &UseCase,
+        filename: &str,
+        content: &str,
+    ) -> Result<()> {
+        let _span = span(|| "write".to_string());
+        block_on(
+            self.inner
+                .save_markdown_with_filename(use_case, filename, content),
+        )
+    }
+}
+
+/// Wakes a parked thread
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run a future to completion on the current thread.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// In-memory repository standing in for a local backend
+    #[derive(Default)]
+    struct MemoryRepository {
+        use_cases: Mutex<Vec<UseCase>>,
+    }
+
+    impl UseCaseRepository for MemoryRepository {
+        fn save(&self, use_case: &UseCase) -> Result<()> {
+            let mut use_cases = self.use_cases.lock().unwrap();
+            use_cases.retain(|uc| uc.id != use_case.id);
+            use_cases.push(use_case.clone());
+            Ok(())
+        }
+
+        fn load_all(&self) -> Result<Vec<UseCase>> {
+            Ok(self.use_cases.lock().unwrap().clone())
+        }
+
+        fn load_by_id(&self, id: &str) -> Result<Option<UseCase>> {
+            Ok(self.load_all()?.into_iter().find(|uc| uc.id == id))
+        }
+
+        fn delete(&self, id: &str) -> Result<()> {
+            self.use_cases.lock().unwrap().retain(|uc| uc.id != id);
+            Ok(())
+        }
+
+        fn save_markdown(&self, _use_case_id: &str, _content: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn save_markdown_with_filename(
+            &self,
+            _use_case: &UseCase,
+            _filename: &str,
+            _content: &str,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_blocking_round_trip_through_async_adapter() {
+        let repository = BlockingRepository::new(Box::new(SyncRepositoryAdapter::new(
+            MemoryRepository::default(),
+        )));
+        let use_case = UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "high".to_string(),
+        )
+        .unwrap();
+
+        repository.save(&use_case).unwrap();
+        assert_eq!(repository.load_all().unwrap().len(), 1);
+        assert!(repository.load_by_id("UC-AUT-001").unwrap().is_some());
+
+        repository.delete("UC-AUT-001").unwrap();
+        assert!(repository.load_by_id("UC-AUT-001").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_block_on_waits_for_wake() {
+        let mut polled = false;
+        let value = block_on(std::future::poll_fn(|cx| {
+            if polled {
+                Poll::Ready(42)
+            } else {
+                polled = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }));
+        assert_eq!(value, 42);
+    }
+}
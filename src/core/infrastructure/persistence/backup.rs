@@ -0,0 +1,317 @@
+//! Timestamped backups of data files.
+//!
+//! Before a data file is overwritten or removed, its previous version is
+//! copied into a snapshot directory under `backup.directory`. A store creates
+//! at most one snapshot, on the first backup, so one command (one bulk
+//! operation) ends up in one snapshot that can be restored as a whole.
+//! Old snapshots are pruned by count and age whenever a new one is created.
+
+use crate::core::{Context, MucmError, Result};
+use chrono::{Duration, Local, NaiveDateTime};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::config::{BackupConfig, Config};
+
+/// Format of snapshot directory names (local time)
+const SNAPSHOT_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// A snapshot directory and the number of files it holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupSnapshot {
+    pub name: String,
+    pub path: PathBuf,
+    pub files: usize,
+}
+
+/// Creates, lists, prunes and restores snapshots of the data directory
+#[derive(Debug)]
+pub struct BackupStore {
+    settings: BackupConfig,
+    data_dir: PathBuf,
+    snapshot: OnceLock<PathBuf>,
+}
+
+impl BackupStore {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            settings: config.backup.clone(),
+            data_dir: PathBuf::from(&config.directories.data_dir),
+            snapshot: OnceLock::new(),
+        }
+    }
+
+    /// Directory holding the snapshots
+    pub fn directory(&self) -> &Path {
+        Path::new(&self.settings.directory)
+    }
+
+    /// Copy the current version of a data file into this store's snapshot.
+    ///
+    /// Does nothing when backups are disabled, the file does not exist, or the
+    /// snapshot already holds a version of it (the oldest one wins).
+    pub fn backup_file(&self, path: &Path) -> Result<()> {
+        if !self.settings.enabled || !path.is_file() {
+            return Ok(());
+        }
+        self.copy_into_snapshot(path)
+    }
+
+    /// Copy every data file into this store's snapshot, regardless of `enabled`
+    pub fn snapshot_all(&self) -> Result<BackupSnapshot> {
+        if !self.data_dir.exists() {
+            return Err(MucmError::NotFound(format!(
+                "Data directory {} does not exist",
+                self.data_dir.display()
+            )));
+        }
+        for entry in walkdir::WalkDir::new(&self.data_dir)
+            .into_iter()
+            .filter_entry(|entry| {
+                entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.')
+            })
+        {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                self.copy_into_snapshot(entry.path())?;
+            }
+        }
+        let path = self.snapshot_dir()?;
+        Ok(Self::describe(path.to_path_buf()))
+    }
+
+    /// All snapshots, newest first
+    pub fn list(&self) -> Result<Vec<BackupSnapshot>> {
+        let directory = self.directory();
+        if !directory.exists() {
+            return Ok(Vec::new());
+        }
+        let mut snapshots: Vec<BackupSnapshot> = fs::read_dir(directory)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| Self::describe(entry.path()))
+            .collect();
+        snapshots.sort_by(|a, b| b.name.cmp(&a.name));
+        Ok(snapshots)
+    }
+
+    /// Copy the files of a snapshot back into the data directory.
+    ///
+    /// With `use_case_id`, only that use case's file is restored. The files
+    /// being replaced are backed up first, so a restore can itself be undone.
+    /// Returns the restored paths.
+    pub fn restore(&self, name: &str, use_case_id: Option<&str>) -> Result<Vec<PathBuf>> {
+        let source = self.directory().join(name);
+        if name.is_empty() || name.contains(['/', '\\']) || !source.is_dir() {
+            return Err(MucmError::NotFound(format!(
+                "Backup snapshot '{}' not found",
+                name
+            )));
+        }
+
+        let mut restored = Vec::new();
+        for entry in walkdir::WalkDir::new(&source) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            if let Some(id) = use_case_id {
+                if entry.path().file_stem() != Some(OsStr::new(id)) {
+                    continue;
+                }
+            }
+            let relative = entry.path().strip_prefix(&source)?;
+            let target = self.data_dir.join(relative);
+            if target.is_file() {
+                self.copy_into_snapshot(&target)?;
+            }
+            if let Some(dir) = target.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::copy(entry.path(), &target)
+                .with_context(|| format!("Failed to restore {}", target.display()))?;
+            restored.push(target);
+        }
+
+        if restored.is_empty() {
+            match use_case_id {
+                Some(id) => {
+                    return Err(MucmError::NotFound(format!(
+                        "Backup snapshot '{}' does not contain {}",
+                        name, id
+                    )))
+                }
+                None => {
+                    return Err(MucmError::Validation(format!(
+                        "Backup snapshot '{}' is empty",
+                        name
+                    )))
+                }
+            }
+        }
+        Ok(restored)
+    }
+
+    /// Remove snapshots beyond `keep` or older than `max_age_days`.
+    ///
+    /// The snapshot of this store is never removed. Returns the removed names.
+    pub fn prune(&self) -> Result<Vec<String>> {
+        let current = self.snapshot.get();
+        let oldest = (self.settings.max_age_days > 0).then(|| {
+            Local::now().naive_local() - Duration::days(i64::from(self.settings.max_age_days))
+        });
+
+        let mut removed = Vec::new();
+        for (index, snapshot) in self.list()?.into_iter().enumerate() {
+            if current.is_some_and(|path| *path == snapshot.path) {
+                continue;
+            }
+            let too_many = self.settings.keep > 0 && index >= self.settings.keep;
+            let too_old = oldest
+                .zip(Self::created(&snapshot.name))
+                .is_some_and(|(oldest, created)| created < oldest);
+            if too_many || too_old {
+                fs::remove_dir_all(&snapshot.path)?;
+                removed.push(snapshot.name);
+            }
+        }
+        Ok(removed)
+    }
+
+    fn copy_into_snapshot(&self, path: &Path) -> Result<()> {
+        let relative = path
+            .strip_prefix(&self.data_dir)
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|_| PathBuf::from(path.file_name().unwrap_or_default()));
+        let target = self.snapshot_dir()?.join(relative);
+        if target.exists() {
+            return Ok(());
+        }
+        if let Some(dir) = target.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::copy(path, &target).with_context(|| format!("Failed to back up {}", path.display()))?;
+        Ok(())
+    }
+
+    /// This store's snapshot directory, created (and old ones pruned) on first use
+    fn snapshot_dir(&self) -> Result<&Path> {
+        if let Some(path) = self.snapshot.get() {
+            return Ok(path);
+        }
+        let stamp = Local::now().format(SNAPSHOT_FORMAT).to_string();
+        let mut path = self.directory().join(&stamp);
+        let mut counter = 2;
+        while path.exists() {
+            path = self.directory().join(format!("{}-{}", stamp, counter));
+            counter += 1;
+        }
+        fs::create_dir_all(&path)
+            .with_context(|| format!("Failed to create backup {}", path.display()))?;
+        let path = self.snapshot.get_or_init(|| path);
+        self.prune()?;
+        Ok(path)
+    }
+
+    /// Creation time encoded in a snapshot name
+    fn created(name: &str) -> Option<NaiveDateTime> {
+        let stamp = name.get(..15)?;
+        NaiveDateTime::parse_from_str(stamp, SNAPSHOT_FORMAT).ok()
+    }
+
+    fn describe(path: PathBuf) -> BackupSnapshot {
+        let files = walkdir::WalkDir::new(&path)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .count();
+        BackupSnapshot {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            path,
+            files,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn store(root: &Path, keep: usize) -> BackupStore {
+        let mut config = Config::default();
+        config.directories.data_dir = root.join("data").to_string_lossy().into_owned();
+        config.backup.directory = root.join("backups").to_string_lossy().into_owned();
+        config.backup.keep = keep;
+        BackupStore::new(&config)
+    }
+
+    fn write(root: &Path, relative: &str, content: &str) -> PathBuf {
+        let path = root.join("data").join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_backup_keeps_first_version_per_snapshot() {
+        let root = TempDir::new().unwrap();
+        let file = write(root.path(), "auth/UC-AUT-001.toml", "v1");
+        let store = store(root.path(), 20);
+
+        store.backup_file(&file).unwrap();
+        fs::write(&file, "v2").unwrap();
+        store.backup_file(&file).unwrap();
+
+        let snapshots = store.list().unwrap();
+        assert_eq!(snapshots.len(), 1);
+        let backup = snapshots[0].path.join("auth/UC-AUT-001.toml");
+        assert_eq!(fs::read_to_string(backup).unwrap(), "v1");
+    }
+
+    #[test]
+    fn test_restore_single_use_case() {
+        let root = TempDir::new().unwrap();
+        let first = write(root.path(), "auth/UC-AUT-001.toml", "one");
+        let second = write(root.path(), "auth/UC-AUT-002.toml", "two");
+        let name = store(root.path(), 20).snapshot_all().unwrap().name;
+
+        fs::write(&first, "broken").unwrap();
+        fs::write(&second, "changed").unwrap();
+        let restored = store(root.path(), 20)
+            .restore(&name, Some("UC-AUT-001"))
+            .unwrap();
+
+        assert_eq!(restored, vec![first.clone()]);
+        assert_eq!(fs::read_to_string(&first).unwrap(), "one");
+        assert_eq!(fs::read_to_string(&second).unwrap(), "changed");
+        // The replaced version went into a new snapshot
+        assert_eq!(store(root.path(), 20).list().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_snapshots() {
+        let root = TempDir::new().unwrap();
+        for name in ["20200101-000000", "20200102-000000", "20200103-000000"] {
+            fs::create_dir_all(root.path().join("backups").join(name)).unwrap();
+        }
+        let mut store = store(root.path(), 2);
+        store.settings.max_age_days = 0;
+
+        assert_eq!(store.prune().unwrap(), vec!["20200101-000000"]);
+        store.settings.max_age_days = 30;
+        assert_eq!(store.prune().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_restore_unknown_snapshot_fails() {
+        let root = TempDir::new().unwrap();
+        let error = store(root.path(), 20).restore("nope", None).unwrap_err();
+        assert!(error.to_string().contains("'nope' not found"));
+    }
+}
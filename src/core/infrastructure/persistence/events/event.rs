@@ -0,0 +1,134 @@
+//! Domain events recorded by the event-sourced backend
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::domain::{Scenario, Status, UseCase};
+
+/// A change to the project's use cases
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum DomainEvent {
+    UseCaseCreated {
+        use_case: UseCase,
+    },
+    ScenarioAdded {
+        use_case_id: String,
+        scenario: Scenario,
+    },
+    StatusChanged {
+        use_case_id: String,
+        scenario_id: String,
+        from: Status,
+        to: Status,
+    },
+    /// Any other change; carries the whole use case as saved
+    UseCaseUpdated {
+        use_case: UseCase,
+    },
+    UseCaseDeleted {
+        use_case_id: String,
+    },
+    /// Ends the events that undid the change recorded as `first_seq..=last_seq`
+    ChangeUndone {
+        first_seq: u64,
+        last_seq: u64,
+    },
+}
+
+/// One line of the event log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Position in the log, starting at 1
+    pub seq: u64,
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: DomainEvent,
+}
+
+impl DomainEvent {
+    /// Events that turn `old` (None for a new use case) into `new`.
+    ///
+    /// Added scenarios and scenario status changes get their own events;
+    /// whatever else changed is recorded as one `UseCaseUpdated`. A save that
+    /// only touches `updated_at` records nothing; replayed events set
+    /// `updated_at` to the record time instead.
+    pub fn diff(old: Option<&UseCase>, new: &UseCase, at: DateTime<Utc>) -> Vec<DomainEvent> {
+        let Some(old) = old else {
+            return vec![DomainEvent::UseCaseCreated {
+                use_case: new.clone(),
+            }];
+        };
+
+        let mut events = Vec::new();
+        for scenario in &new.scenarios {
+            match old.scenarios.iter().find(|s| s.id == scenario.id) {
+                None => events.push(DomainEvent::ScenarioAdded {
+                    use_case_id: new.id.clone(),
+                    scenario: scenario.clone(),
+                }),
+                Some(previous) if previous.status != scenario.status => {
+                    events.push(DomainEvent::StatusChanged {
+                        use_case_id: new.id.clone(),
+                        scenario_id: scenario.id.clone(),
+                        from: previous.status,
+                        to: scenario.status,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        let mut replayed = old.clone();
+        for event in &events {
+            event.apply_to(&mut replayed, at);
+        }
+        let mut expected = new.clone();
+        expected.metadata.updated_at = replayed.metadata.updated_at;
+        if !same(&replayed, &expected) {
+            expected.metadata.updated_at = at;
+            events.push(DomainEvent::UseCaseUpdated { use_case: expected });
+        }
+        events
+    }
+
+    /// ID of the use case the event is about, `None` for markers
+    pub fn use_case_id(&self) -> Option<&str> {
+        match self {
+            DomainEvent::UseCaseCreated { use_case } | DomainEvent::UseCaseUpdated { use_case } => {
+                Some(&use_case.id)
+            }
+            DomainEvent::ScenarioAdded { use_case_id, .. }
+            | DomainEvent::StatusChanged { use_case_id, .. }
+            | DomainEvent::UseCaseDeleted { use_case_id } => Some(use_case_id),
+            DomainEvent::ChangeUndone { .. } => None,
+        }
+    }
+
+    /// Apply an event about an existing use case to it
+    pub(super) fn apply_to(&self, use_case: &mut UseCase, at: DateTime<Utc>) {
+        match self {
+            DomainEvent::ScenarioAdded { scenario, .. } => {
+                use_case.scenarios.push(scenario.clone());
+                use_case.metadata.updated_at = at;
+            }
+            DomainEvent::StatusChanged {
+                scenario_id, to, ..
+            } => {
+                if let Some(scenario) = use_case.scenarios.iter_mut().find(|s| &s.id == scenario_id)
+                {
+                    scenario.status = *to;
+                }
+                use_case.metadata.updated_at = at;
+            }
+            DomainEvent::UseCaseCreated { use_case: saved }
+            | DomainEvent::UseCaseUpdated { use_case: saved } => *use_case = saved.clone(),
+            DomainEvent::UseCaseDeleted { .. } | DomainEvent::ChangeUndone { .. } => {}
+        }
+    }
+}
+
+/// Compare use cases by their serialized form (UseCase has no PartialEq)
+fn same(a: &UseCase, b: &UseCase) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
@@ -0,0 +1,12 @@
+//! Event-sourced persistence (experimental).
+//!
+//! Every change is appended to `events.jsonl` in the data directory as a
+//! domain event (`UseCaseCreated`, `ScenarioAdded`, `StatusChanged`, ...).
+//! The current state is rebuilt by replaying the log from the latest snapshot,
+//! and any earlier state by replaying it up to a point in time. Undo appends
+//! events restoring the state before the latest change, ended by `ChangeUndone`.
+
+mod event;
+mod repository;
+
+pub use repository::{EventUseCaseRepository, EVENT_LOG};
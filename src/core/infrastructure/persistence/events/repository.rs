@@ -0,0 +1,618 @@
+// Event-sourced implementation of UseCaseRepository
+use super::event::{DomainEvent, EventRecord};
+use crate::config::Config;
+use crate::core::infrastructure::persistence::file_operations::FileOperations;
+use crate::core::infrastructure::persistence::traits::UseCaseRepository;
+use crate::core::infrastructure::persistence::transaction::RepositoryTransaction;
+use crate::core::MucmError;
+use crate::core::{to_snake_case, UseCase};
+use crate::core::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Event log file in the data directory
+pub const EVENT_LOG: &str = "events.jsonl";
+
+/// Directory under the data directory holding state snapshots
+pub const SNAPSHOT_DIR: &str = "snapshots";
+
+/// A snapshot of the whole state is written every this many events
+const SNAPSHOT_INTERVAL: u64 = 100;
+
+/// Use cases by ID, as rebuilt from the log
+type State = BTreeMap<String, UseCase>;
+
+/// State after the event `seq`, so replay can start there
+#[derive(Debug, Serialize, Deserialize)]
+struct Snapshot {
+    seq: u64,
+    at: DateTime<Utc>,
+    /// Length of the log after event `seq`; replay reads on from there
+    /// (missing in snapshots of older versions, which replay the whole log)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    offset: Option<u64>,
+    use_cases: Vec<UseCase>,
+}
+
+/// How far to replay the log
+#[derive(Debug, Clone, Copy)]
+enum Until {
+    End,
+    /// Events recorded at or before this time
+    Time(DateTime<Utc>),
+    /// Events up to and including this sequence number
+    Seq(u64),
+}
+
+impl Until {
+    fn includes(self, seq: u64, at: &DateTime<Utc>) -> bool {
+        match self {
+            Until::End => true,
+            Until::Time(cutoff) => *at <= cutoff,
+            Until::Seq(last) => seq <= last,
+        }
+    }
+}
+
+/// Repository that records every change as a domain event
+///
+/// Architecture:
+/// - `events.jsonl` is the source of truth and is only ever appended to
+/// - Snapshots are a cache: deleting them only makes loading slower
+/// - Markdown files are generated documentation, as with the TOML backend
+pub struct EventUseCaseRepository {
+    config: Config,
+}
+
+impl EventUseCaseRepository {
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    fn data_dir(&self) -> &Path {
+        Path::new(&self.config.directories.data_dir)
+    }
+
+    /// Path of the event log
+    pub fn log_path(&self) -> PathBuf {
+        self.data_dir().join(EVENT_LOG)
+    }
+
+    /// Every recorded event, oldest first
+    pub fn events(&self) -> Result<Vec<EventRecord>> {
+        self.events_from(0)
+    }
+
+    /// Events recorded after the first `offset` bytes of the log, oldest first
+    fn events_from(&self, offset: u64) -> Result<Vec<EventRecord>> {
+        let path = self.log_path();
+        let mut file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+        let mut content = String::new();
+        file.seek(SeekFrom::Start(offset))
+            .and_then(|_| file.read_to_string(&mut content))
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        let mut records = Vec::new();
+        let mut position = offset;
+        for line in content.split_inclusive('\n') {
+            if !line.trim().is_empty() {
+                let record = serde_json::from_str(line).with_context(|| {
+                    format!("{}: invalid event at byte {}", path.display(), position)
+                })?;
+                records.push(record);
+            }
+            position += line.len() as u64;
+        }
+        Ok(records)
+    }
+
+    /// Use cases as they were at `cutoff`
+    pub fn load_at(&self, cutoff: DateTime<Utc>) -> Result<Vec<UseCase>> {
+        let (state, _) = self.state(Until::Time(cutoff))?;
+        Ok(state.into_values().collect())
+    }
+
+    /// Undo the latest change that is neither an undo nor undone already.
+    ///
+    /// A change is what one save or transaction commit recorded. Undoing it
+    /// appends events that put every use case it touched back the way it was
+    /// before, so the undo itself stays in the history. Returns the IDs of
+    /// those use cases, or `None` when there is nothing left to undo.
+    pub fn undo(&self) -> Result<Option<Vec<String>>> {
+        let records = self.events()?;
+        let Some(change) = latest_undoable(&records) else {
+            return Ok(None);
+        };
+        let first_seq = change[0].seq;
+        let last_seq = change[change.len() - 1].seq;
+        let ids: BTreeSet<String> = change
+            .iter()
+            .filter_map(|record| record.event.use_case_id())
+            .map(str::to_string)
+            .collect();
+        let (before, _) = self.state(Until::Seq(first_seq - 1))?;
+
+        let mut removed = Vec::new();
+        self.append(|state, at| {
+            let mut events = Vec::new();
+            for id in &ids {
+                match (before.get(id), state.get(id)) {
+                    (Some(previous), Some(_)) => {
+                        let mut use_case = previous.clone();
+                        use_case.metadata.updated_at = at;
+                        events.push(DomainEvent::UseCaseUpdated { use_case });
+                    }
+                    (Some(previous), None) => events.push(DomainEvent::UseCaseCreated {
+                        use_case: previous.clone(),
+                    }),
+                    (None, Some(current)) => {
+                        removed.push(current.clone());
+                        events.push(DomainEvent::UseCaseDeleted {
+                            use_case_id: id.clone(),
+                        });
+                    }
+                    (None, None) => {}
+                }
+            }
+            events.push(DomainEvent::ChangeUndone {
+                first_seq,
+                last_seq,
+            });
+            events
+        })?;
+
+        for use_case in &removed {
+            self.remove_generated_markdown(use_case)?;
+        }
+        Ok(Some(ids.into_iter().collect()))
+    }
+
+    /// State replayed `until` a point and the sequence number of its last event
+    ///
+    /// Replay starts at the latest snapshot within that point and reads only
+    /// the part of the log written after it.
+    fn state(&self, until: Until) -> Result<(State, u64)> {
+        let snapshot = self.snapshots()?.into_iter().rev().find_map(|path| {
+            let snapshot = Self::read_snapshot(&path).ok()?;
+            until
+                .includes(snapshot.seq, &snapshot.at)
+                .then_some(snapshot)
+        });
+        let (mut state, mut last_seq, offset) = match snapshot {
+            Some(snapshot) => (
+                snapshot
+                    .use_cases
+                    .into_iter()
+                    .map(|uc| (uc.id.clone(), uc))
+                    .collect(),
+                snapshot.seq,
+                snapshot.offset,
+            ),
+            None => (State::new(), 0, Some(0)),
+        };
+
+        // An offset that does not land right after the snapshot's last
+        // event (e.g. the log was edited by hand) falls back to a full read
+        let records = match offset.map(|offset| self.events_from(offset)) {
+            Some(Ok(records))
+                if records
+                    .first()
+                    .is_none_or(|record| record.seq == last_seq + 1) =>
+            {
+                records
+            }
+            _ => self.events()?,
+        };
+        for record in records {
+            if record.seq <= last_seq {
+                continue;
+            }
+            if !until.includes(record.seq, &record.at) {
+                break;
+            }
+            apply(&mut state, &record);
+            last_seq = record.seq;
+        }
+        Ok((state, last_seq))
+    }
+
+    /// Snapshot files, oldest first
+    fn snapshots(&self) -> Result<Vec<PathBuf>> {
+        let dir = self.data_dir().join(SNAPSHOT_DIR);
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut paths: Vec<PathBuf> = fs::read_dir(&dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn read_snapshot(path: &Path) -> Result<Snapshot> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Append the events produced by `changes` from the current state in one write
+    fn append(
+        &self,
+        changes: impl FnOnce(&State, DateTime<Utc>) -> Vec<DomainEvent>,
+    ) -> Result<()> {
+        let (mut state, last_seq) = self.state(Until::End)?;
+        let at = Utc::now();
+        let events = changes(&state, at);
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let mut lines = String::new();
+        let mut seq = last_seq;
+        for event in events {
+            seq += 1;
+            let record = EventRecord { seq, at, event };
+            lines.push_str(&serde_json::to_string(&record)?);
+            lines.push('\n');
+            apply(&mut state, &record);
+        }
+
+        fs::create_dir_all(self.data_dir())?;
+        let path = self.log_path();
+        let offset = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| {
+                file.write_all(lines.as_bytes())?;
+                file.metadata()
+            })
+            .with_context(|| format!("Failed to append to {}", path.display()))?
+            .len();
+
+        if seq / SNAPSHOT_INTERVAL > last_seq / SNAPSHOT_INTERVAL {
+            self.write_snapshot(seq, at, offset, state)?;
+        }
+        Ok(())
+    }
+
+    fn write_snapshot(&self, seq: u64, at: DateTime<Utc>, offset: u64, state: State) -> Result<()> {
+        let dir = self.data_dir().join(SNAPSHOT_DIR);
+        fs::create_dir_all(&dir)?;
+        let snapshot = Snapshot {
+            seq,
+            at,
+            offset: Some(offset),
+            use_cases: state.into_values().collect(),
+        };
+        fs::write(
+            dir.join(format!("{:010}.json", seq)),
+            serde_json::to_string(&snapshot)?,
+        )?;
+        Ok(())
+    }
+
+    fn markdown_dir(&self, use_case: &UseCase) -> PathBuf {
+        Path::new(&self.config.directories.use_case_dir).join(to_snake_case(&use_case.category))
+    }
+
+    /// Remove generated documents for every view ({id}.md and {id}-{view}.md, or .adoc)
+    fn remove_generated_markdown(&self, use_case: &UseCase) -> Result<()> {
+        FileOperations::remove_generated_documents(&self.markdown_dir(use_case), &use_case.id)
+    }
+}
+
+/// Events of the latest change that is neither an undo nor undone already
+///
+/// The events of one change share their record time.
+fn latest_undoable(records: &[EventRecord]) -> Option<&[EventRecord]> {
+    let mut undone = Vec::new();
+    let mut end = records.len();
+    while end > 0 {
+        let at = records[end - 1].at;
+        let start = records[..end]
+            .iter()
+            .rposition(|record| record.at != at)
+            .map_or(0, |i| i + 1);
+        let change = &records[start..end];
+        match &change[change.len() - 1].event {
+            DomainEvent::ChangeUndone {
+                first_seq,
+                last_seq,
+            } => undone.push(*first_seq..=*last_seq),
+            _ if undone.iter().any(|range| range.contains(&change[0].seq)) => {}
+            _ => return Some(change),
+        }
+        end = start;
+    }
+    None
+}
+
+/// Apply one recorded event to the state
+fn apply(state: &mut State, record: &EventRecord) {
+    match &record.event {
+        DomainEvent::UseCaseCreated { use_case } | DomainEvent::UseCaseUpdated { use_case } => {
+            state.insert(use_case.id.clone(), use_case.clone());
+        }
+        DomainEvent::UseCaseDeleted { use_case_id } => {
+            state.remove(use_case_id);
+        }
+        event => {
+            if let Some(use_case) = event.use_case_id().and_then(|id| state.get_mut(id)) {
+                event.apply_to(use_case, record.at);
+            }
+        }
+    }
+}
+
+impl UseCaseRepository for EventUseCaseRepository {
+    fn save(&self, use_case: &UseCase) -> Result<()> {
+        self.append(|state, at| DomainEvent::diff(state.get(&use_case.id), use_case, at))
+    }
+
+    fn load_all(&self) -> Result<Vec<UseCase>> {
+        let (state, _) = self.state(Until::End)?;
+        Ok(state.into_values().collect())
+    }
+
+    fn load_by_id(&self, id: &str) -> Result<Option<UseCase>> {
+        let (mut state, _) = self.state(Until::End)?;
+        Ok(state.remove(id))
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        let Some(use_case) = self.load_by_id(id)? else {
+            return Ok(());
+        };
+        self.append(|_, _| {
+            vec![DomainEvent::UseCaseDeleted {
+                use_case_id: id.to_string(),
+            }]
+        })?;
+        self.remove_generated_markdown(&use_case)
+    }
+
+    fn commit(&self, transaction: RepositoryTransaction) -> Result<()> {
+        let existing = self.load_all()?;
+        self.append(|state, at| {
+            let mut state = state.clone();
+            let mut events = Vec::new();
+            for id in transaction.deletes() {
+                if state.remove(id).is_some() {
+                    events.push(DomainEvent::UseCaseDeleted {
+                        use_case_id: id.clone(),
+                    });
+                }
+            }
+            for use_case in transaction.saves() {
+                events.extend(DomainEvent::diff(state.get(&use_case.id), use_case, at));
+                state.insert(use_case.id.clone(), use_case.clone());
+            }
+            events
+        })?;
+
+        for use_case in existing
+            .iter()
+            .filter(|uc| transaction.deletes().contains(&uc.id))
+        {
+            self.remove_generated_markdown(use_case)?;
+        }
+        Ok(())
+    }
+
+    fn save_markdown(&self, use_case_id: &str, content: &str) -> Result<()> {
+        let use_case = self
+            .load_by_id(use_case_id)?
+            .ok_or_else(|| MucmError::NotFound(format!("Use case {} not found", use_case_id)))?;
+        self.save_markdown_with_filename(&use_case, &format!("{}.md", use_case_id), content)
+    }
+
+    fn save_markdown_with_filename(
+        &self,
+        use_case: &UseCase,
+        filename: &str,
+        content: &str,
+    ) -> Result<()> {
+        let md_dir = self.markdown_dir(use_case);
+        fs::create_dir_all(&md_dir)?;
+        fs::write(md_dir.join(filename), content)?;
+        Ok(())
+    }
+
+    fn write_markdown_with_filename(
+        &self,
+        use_case: &UseCase,
+        filename: &str,
+        render: &mut dyn FnMut(&mut dyn Write) -> Result<()>,
+    ) -> Result<()> {
+        let md_dir = self.markdown_dir(use_case);
+        fs::create_dir_all(&md_dir)?;
+        FileOperations::write_streamed(&md_dir.join(filename), render)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::{Scenario, ScenarioType, Status};
+    use tempfile::TempDir;
+
+    fn repository(dir: &TempDir) -> EventUseCaseRepository {
+        let mut config = Config::default();
+        config.directories.data_dir = dir.path().join("data").to_string_lossy().into_owned();
+        config.directories.use_case_dir = dir.path().join("docs").to_string_lossy().into_owned();
+        EventUseCaseRepository::new(config)
+    }
+
+    fn login() -> UseCase {
+        UseCase::new(
+            "UC-AUT-001".to_string(),
+            "Login".to_string(),
+            "Auth".to_string(),
+            String::new(),
+            "medium".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn event_names(repo: &EventUseCaseRepository) -> Vec<String> {
+        repo.events()
+            .unwrap()
+            .iter()
+            .map(|record| {
+                serde_json::to_value(&record.event).unwrap()["event"]
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_changes_are_recorded_as_domain_events() {
+        let dir = TempDir::new().unwrap();
+        let repo = repository(&dir);
+
+        let mut use_case = login();
+        repo.save(&use_case).unwrap();
+        use_case.add_scenario(Scenario::new(
+            "UC-AUT-001-S01".to_string(),
+            "Valid password".to_string(),
+            String::new(),
+            ScenarioType::HappyPath,
+        ));
+        repo.save(&use_case).unwrap();
+        use_case.scenarios[0].status = Status::Implemented;
+        repo.save(&use_case).unwrap();
+        repo.delete("UC-AUT-001").unwrap();
+
+        assert_eq!(
+            event_names(&repo),
+            vec![
+                "UseCaseCreated",
+                "ScenarioAdded",
+                "StatusChanged",
+                "UseCaseDeleted"
+            ]
+        );
+        assert!(repo.load_all().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_other_changes_become_use_case_updated() {
+        let dir = TempDir::new().unwrap();
+        let repo = repository(&dir);
+
+        let mut use_case = login();
+        repo.save(&use_case).unwrap();
+        use_case.description = "Sign in with a password".to_string();
+        repo.save(&use_case).unwrap();
+
+        assert_eq!(event_names(&repo), vec!["UseCaseCreated", "UseCaseUpdated"]);
+        let loaded = repo.load_by_id("UC-AUT-001").unwrap().unwrap();
+        assert_eq!(loaded.description, "Sign in with a password");
+
+        use_case.metadata.updated_at = Utc::now();
+        repo.save(&use_case).unwrap();
+        assert_eq!(repo.events().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_load_at_replays_up_to_the_cutoff() {
+        let dir = TempDir::new().unwrap();
+        let repo = repository(&dir);
+
+        repo.save(&login()).unwrap();
+        let before_delete = Utc::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        repo.delete("UC-AUT-001").unwrap();
+
+        assert_eq!(repo.load_at(before_delete).unwrap().len(), 1);
+        assert!(repo.load_all().unwrap().is_empty());
+        assert!(repo
+            .load_at(before_delete - chrono::Duration::days(1))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_snapshots_do_not_change_the_state() {
+        let dir = TempDir::new().unwrap();
+        let repo = repository(&dir);
+
+        let mut use_case = login();
+        for i in 0..SNAPSHOT_INTERVAL + 5 {
+            use_case.description = format!("revision {}", i);
+            repo.save(&use_case).unwrap();
+        }
+
+        assert_eq!(repo.snapshots().unwrap().len(), 1);
+        let loaded = repo.load_by_id("UC-AUT-001").unwrap().unwrap();
+        assert_eq!(
+            loaded.description,
+            format!("revision {}", SNAPSHOT_INTERVAL + 4)
+        );
+    }
+
+    #[test]
+    fn test_replay_reads_only_the_log_after_the_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let repo = repository(&dir);
+
+        let mut use_case = login();
+        for i in 0..SNAPSHOT_INTERVAL + 5 {
+            use_case.description = format!("revision {}", i);
+            repo.save(&use_case).unwrap();
+        }
+        // Garble the first event without moving the ones after it
+        let log = fs::read_to_string(repo.log_path()).unwrap();
+        let first_line = log.lines().next().unwrap().len();
+        let garbled = format!("{}{}", "x".repeat(first_line), &log[first_line..]);
+        fs::write(repo.log_path(), garbled).unwrap();
+
+        assert!(repo.events().is_err());
+        let loaded = repo.load_by_id("UC-AUT-001").unwrap().unwrap();
+        assert_eq!(
+            loaded.description,
+            format!("revision {}", SNAPSHOT_INTERVAL + 4)
+        );
+    }
+
+    #[test]
+    fn test_undo_steps_back_through_changes() {
+        let dir = TempDir::new().unwrap();
+        let repo = repository(&dir);
+
+        let mut use_case = login();
+        repo.save(&use_case).unwrap();
+        use_case.description = "Sign in with a password".to_string();
+        repo.save(&use_case).unwrap();
+
+        assert_eq!(repo.undo().unwrap(), Some(vec!["UC-AUT-001".to_string()]));
+        let loaded = repo.load_by_id("UC-AUT-001").unwrap().unwrap();
+        assert_eq!(loaded.description, "");
+
+        assert_eq!(repo.undo().unwrap(), Some(vec!["UC-AUT-001".to_string()]));
+        assert!(repo.load_all().unwrap().is_empty());
+        assert_eq!(repo.undo().unwrap(), None);
+        assert_eq!(
+            event_names(&repo),
+            vec![
+                "UseCaseCreated",
+                "UseCaseUpdated",
+                "UseCaseUpdated",
+                "ChangeUndone",
+                "UseCaseDeleted",
+                "ChangeUndone"
+            ]
+        );
+    }
+}
@@ -1,9 +1,11 @@
 // File operation utilities for persistence layer
 use crate::config::Config;
+use crate::core::utils::span;
+use crate::core::Result;
 use crate::core::{to_snake_case, UseCase};
-use anyhow::Result;
 use std::fs;
-use std::path::Path;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 
 /// Helper functions for file operations
 pub struct FileOperations {
@@ -38,14 +40,94 @@ impl FileOperations {
         Ok(())
     }
 
-    /// Save overview file
-    pub fn save_overview(&self, content: &str) -> Result<()> {
-        let overview_path = Path::new(&self.config.directories.use_case_dir).join("README.md");
-        fs::write(&overview_path, content)?;
+    /// Write the overview file, streaming the output of `render` into it
+    pub fn write_overview(&self, render: impl FnOnce(&mut dyn Write) -> Result<()>) -> Result<()> {
+        let use_case_dir = Path::new(&self.config.directories.use_case_dir);
+        fs::create_dir_all(use_case_dir)?;
+        let overview_path = use_case_dir.join("README.md");
+        Self::write_streamed(&overview_path, render)?;
         println!("Generated overview at: {}", overview_path.display());
         Ok(())
     }
 
+    /// Write a named overview (`<name>-overview.md`, next to the overview) and return its path
+    pub fn write_named_overview(
+        &self,
+        name: &str,
+        render: impl FnOnce(&mut dyn Write) -> Result<()>,
+    ) -> Result<PathBuf> {
+        let use_case_dir = Path::new(&self.config.directories.use_case_dir);
+        fs::create_dir_all(use_case_dir)?;
+        let path = use_case_dir.join(format!("{}-overview.md", name));
+        Self::write_streamed(&path, render)?;
+        Ok(path)
+    }
+
+    /// Write an overview page of one category into its directory: `README.md`
+    /// when the overview is sharded, `overview.md` with `generation.category_overviews`
+    pub fn write_category_overview(
+        &self,
+        category: &str,
+        file_name: &str,
+        render: impl FnOnce(&mut dyn Write) -> Result<()>,
+    ) -> Result<()> {
+        let category_dir =
+            Path::new(&self.config.directories.use_case_dir).join(to_snake_case(category));
+        fs::create_dir_all(&category_dir)?;
+        Self::write_streamed(&category_dir.join(file_name), render)
+    }
+
+    /// Whether `name` is a generated document of a use case, for any view and
+    /// output format (`{id}.md`, `{id}-{view}.md`, `{id}.adoc`, ...)
+    pub fn is_generated_document(name: &str, use_case_id: &str) -> bool {
+        let Some(stem) = name
+            .strip_suffix(".md")
+            .or_else(|| name.strip_suffix(".adoc"))
+        else {
+            return false;
+        };
+        stem == use_case_id || stem.starts_with(&format!("{}-", use_case_id))
+    }
+
+    /// Remove the generated documents of a use case from `dir`, for every view
+    /// and output format
+    pub fn remove_generated_documents(dir: &Path, use_case_id: &str) -> Result<()> {
+        if !dir.exists() {
+            return Ok(());
+        }
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let is_generated = path.file_name().is_some_and(|name| {
+                Self::is_generated_document(&name.to_string_lossy(), use_case_id)
+            });
+            if is_generated {
+                fs::remove_file(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Create `path` and stream the output of `render` into it through a buffered writer
+    pub fn write_streamed(
+        path: &Path,
+        render: impl FnOnce(&mut dyn Write) -> Result<()>,
+    ) -> Result<()> {
+        let _span = span(|| "write".to_string());
+        let mut writer = BufWriter::new(fs::File::create(path)?);
+        render(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Save the JSON manifest next to the overview file
+    pub fn save_manifest(&self, content: &str) -> Result<()> {
+        let use_case_dir = Path::new(&self.config.directories.use_case_dir);
+        fs::create_dir_all(use_case_dir)?;
+        let manifest_path = use_case_dir.join("mucm-manifest.json");
+        fs::write(&manifest_path, content)?;
+        Ok(())
+    }
+
     /// Check if a test file exists for a given use case
     pub fn test_file_exists(&self, use_case: &UseCase, file_extension: &str) -> bool {
         let test_dir =
@@ -61,6 +143,51 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_is_generated_document() {
+        assert!(FileOperations::is_generated_document(
+            "UC-AUT-001.md",
+            "UC-AUT-001"
+        ));
+        assert!(FileOperations::is_generated_document(
+            "UC-AUT-001-business-advanced.adoc",
+            "UC-AUT-001"
+        ));
+        assert!(!FileOperations::is_generated_document(
+            "UC-AUT-0012.md",
+            "UC-AUT-001"
+        ));
+        assert!(!FileOperations::is_generated_document(
+            "UC-AUT-001.toml",
+            "UC-AUT-001"
+        ));
+    }
+
+    #[test]
+    fn test_remove_generated_documents_keeps_other_files() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in [
+            "UC-AUT-001.md",
+            "UC-AUT-001-feature-normal.md",
+            "UC-AUT-0012.md",
+            "notes.md",
+        ] {
+            fs::write(temp_dir.path().join(name), "").unwrap();
+        }
+
+        FileOperations::remove_generated_documents(temp_dir.path(), "UC-AUT-001").unwrap();
+
+        let mut left: Vec<String> = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        left.sort();
+        assert_eq!(left, ["UC-AUT-0012.md", "notes.md"]);
+        // A missing directory has nothing to remove
+        FileOperations::remove_generated_documents(&temp_dir.path().join("none"), "UC-AUT-001")
+            .unwrap();
+    }
+
     #[test]
     fn test_test_file_exists() {
         // Create a temporary directory for testing
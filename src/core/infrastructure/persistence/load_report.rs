@@ -0,0 +1,39 @@
+//! Lenient loading and repair results.
+//!
+//! A single malformed data file must not make the whole project unusable.
+//! Repositories load what they can and report the rest as failures, which
+//! `mucm repair` then tries to recover or moves out of the way.
+
+use crate::core::domain::UseCase;
+use std::path::PathBuf;
+
+/// Data that could not be loaded
+#[derive(Debug, Clone)]
+pub struct LoadFailure {
+    /// Data file (TOML) or database (SQLite) the use case was read from
+    pub path: PathBuf,
+    pub error: String,
+}
+
+/// Use cases that loaded, plus the data that did not
+#[derive(Debug, Clone, Default)]
+pub struct LoadReport {
+    pub use_cases: Vec<UseCase>,
+    pub failures: Vec<LoadFailure>,
+}
+
+/// What `repair` did with data that failed to load
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepairOutcome {
+    /// The file was fixed in place and loads again
+    Recovered { use_case_id: String },
+    /// The file could not be fixed and was moved aside
+    Quarantined { to: PathBuf, reason: String },
+}
+
+/// One repaired file
+#[derive(Debug, Clone)]
+pub struct RepairAction {
+    pub path: PathBuf,
+    pub outcome: RepairOutcome,
+}
@@ -0,0 +1,161 @@
+// In-memory implementation of ActorRepository and PersonaRepository
+use crate::core::domain::{ActorEntity, ActorRepository, Persona, PersonaRepository};
+use crate::core::Result;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Repository that keeps actors (personas and system actors) in memory
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryActorRepository {
+    actors: Arc<Mutex<Vec<ActorEntity>>>,
+    markdown: Arc<Mutex<BTreeMap<String, String>>>,
+}
+
+impl InMemoryActorRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A repository holding these actors
+    pub fn with_actors(actors: Vec<ActorEntity>) -> Self {
+        Self {
+            actors: Arc::new(Mutex::new(actors)),
+            ..Self::default()
+        }
+    }
+
+    /// Generated markdown of an actor
+    pub fn markdown(&self, actor_id: &str) -> Option<String> {
+        self.markdown_files().get(actor_id).cloned()
+    }
+
+    fn actors(&self) -> MutexGuard<'_, Vec<ActorEntity>> {
+        self.actors.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn markdown_files(&self) -> MutexGuard<'_, BTreeMap<String, String>> {
+        self.markdown.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+// === ActorRepository implementation (new unified actor system) ===
+
+impl ActorRepository for InMemoryActorRepository {
+    fn save_actor(&self, actor: &ActorEntity) -> Result<()> {
+        let mut actors = self.actors();
+        match actors.iter_mut().find(|a| a.id == actor.id) {
+            Some(existing) => *existing = actor.clone(),
+            None => actors.push(actor.clone()),
+        }
+        Ok(())
+    }
+
+    fn load_all_actors(&self) -> Result<Vec<ActorEntity>> {
+        Ok(self.actors().clone())
+    }
+
+    fn load_actor_by_id(&self, id: &str) -> Result<Option<ActorEntity>> {
+        Ok(self.actors().iter().find(|a| a.id == id).cloned())
+    }
+
+    fn delete_actor(&self, id: &str) -> Result<()> {
+        self.actors().retain(|a| a.id != id);
+        self.markdown_files().remove(id);
+        Ok(())
+    }
+
+    fn actor_exists(&self, id: &str) -> Result<bool> {
+        Ok(self.actors().iter().any(|a| a.id == id))
+    }
+
+    fn save_actor_markdown(&self, actor_id: &str, markdown_content: &str) -> Result<()> {
+        self.markdown_files()
+            .insert(actor_id.to_string(), markdown_content.to_string());
+        Ok(())
+    }
+
+    // === Persona compatibility methods (backward compatibility) ===
+
+    fn save_persona(&self, persona: &Persona) -> Result<()> {
+        self.save_actor(&persona.to_actor())
+    }
+
+    fn load_all_personas(&self) -> Result<Vec<Persona>> {
+        Ok(self
+            .actors()
+            .iter()
+            .filter_map(Persona::from_actor)
+            .collect())
+    }
+
+    fn load_persona_by_id(&self, id: &str) -> Result<Option<Persona>> {
+        let actor = self.load_actor_by_id(id)?;
+        Ok(actor.and_then(|a| Persona::from_actor(&a)))
+    }
+
+    fn delete_persona(&self, id: &str) -> Result<()> {
+        self.delete_actor(id)
+    }
+
+    fn persona_exists(&self, id: &str) -> Result<bool> {
+        self.actor_exists(id)
+    }
+
+    fn save_persona_markdown(&self, persona_id: &str, markdown_content: &str) -> Result<()> {
+        self.save_actor_markdown(persona_id, markdown_content)
+    }
+}
+
+// === PersonaRepository implementation (for backward compatibility with existing code) ===
+
+impl PersonaRepository for InMemoryActorRepository {
+    fn save(&self, persona: &Persona) -> Result<()> {
+        self.save_persona(persona)
+    }
+
+    fn load_all(&self) -> Result<Vec<Persona>> {
+        self.load_all_personas()
+    }
+
+    fn load_by_id(&self, id: &str) -> Result<Option<Persona>> {
+        self.load_persona_by_id(id)
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        self.delete_persona(id)
+    }
+
+    fn exists(&self, id: &str) -> Result<bool> {
+        self.persona_exists(id)
+    }
+
+    fn save_markdown(&self, persona_id: &str, markdown_content: &str) -> Result<()> {
+        self.save_persona_markdown(persona_id, markdown_content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_personas_are_actors() {
+        let repo = InMemoryActorRepository::new();
+        let persona = Persona::new(
+            "admin".to_string(),
+            "Admin".to_string(),
+            "Manages users".to_string(),
+        );
+
+        PersonaRepository::save(&repo, &persona).unwrap();
+        repo.save_persona_markdown("admin", "# Admin").unwrap();
+
+        assert!(repo.actor_exists("admin").unwrap());
+        assert_eq!(repo.load_all_personas().unwrap()[0].name, "Admin");
+        assert_eq!(repo.markdown("admin").as_deref(), Some("# Admin"));
+
+        repo.delete_persona("admin").unwrap();
+        assert!(repo.load_all_actors().unwrap().is_empty());
+        assert!(repo.markdown("admin").is_none());
+    }
+}
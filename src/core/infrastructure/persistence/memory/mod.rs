@@ -0,0 +1,13 @@
+//! In-memory persistence.
+//!
+//! Repositories that keep everything, generated markdown included, in
+//! process memory. They are never selected by configuration: library
+//! consumers and tests construct them directly to work without touching the
+//! filesystem. Clones share their storage, so a clone kept by the caller sees
+//! what was saved through the one handed to a coordinator.
+
+mod actor_repository;
+mod repository;
+
+pub use actor_repository::InMemoryActorRepository;
+pub use repository::InMemoryUseCaseRepository;
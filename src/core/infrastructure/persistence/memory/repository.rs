@@ -0,0 +1,180 @@
+// In-memory implementation of UseCaseRepository
+use crate::core::infrastructure::persistence::file_operations::FileOperations;
+use crate::core::infrastructure::persistence::traits::UseCaseRepository;
+use crate::core::infrastructure::persistence::transaction::RepositoryTransaction;
+use crate::core::MucmError;
+use crate::core::Result;
+use crate::core::{to_snake_case, UseCase};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Repository that keeps use cases and their markdown in memory
+///
+/// Markdown is keyed by its path relative to the use case directory
+/// (`<category>/<filename>`), matching the layout of the file backends.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryUseCaseRepository {
+    use_cases: Arc<Mutex<Vec<UseCase>>>,
+    markdown: Arc<Mutex<BTreeMap<String, String>>>,
+}
+
+impl InMemoryUseCaseRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A repository holding these use cases
+    pub fn with_use_cases(use_cases: Vec<UseCase>) -> Self {
+        Self {
+            use_cases: Arc::new(Mutex::new(use_cases)),
+            ..Self::default()
+        }
+    }
+
+    /// Generated markdown at `<category>/<filename>`
+    pub fn markdown(&self, path: &str) -> Option<String> {
+        self.markdown_files().get(path).cloned()
+    }
+
+    /// Paths of all generated markdown, in order
+    pub fn markdown_paths(&self) -> Vec<String> {
+        self.markdown_files().keys().cloned().collect()
+    }
+
+    fn use_cases(&self) -> MutexGuard<'_, Vec<UseCase>> {
+        self.use_cases.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn markdown_files(&self) -> MutexGuard<'_, BTreeMap<String, String>> {
+        self.markdown.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn markdown_key(use_case: &UseCase, filename: &str) -> String {
+        format!("{}/{}", to_snake_case(&use_case.category), filename)
+    }
+
+    fn upsert(use_cases: &mut Vec<UseCase>, use_case: &UseCase) {
+        match use_cases.iter_mut().find(|uc| uc.id == use_case.id) {
+            Some(existing) => *existing = use_case.clone(),
+            None => use_cases.push(use_case.clone()),
+        }
+    }
+
+    /// Remove generated documents for every view ({id}.md and {id}-{view}.md, or .adoc)
+    fn remove_generated_markdown(&self, use_case: &UseCase) {
+        let prefix = format!("{}/", to_snake_case(&use_case.category));
+        self.markdown_files().retain(|path, _| {
+            let Some(name) = path.strip_prefix(&prefix) else {
+                return true;
+            };
+            !FileOperations::is_generated_document(name, &use_case.id)
+        });
+    }
+}
+
+impl UseCaseRepository for InMemoryUseCaseRepository {
+    fn save(&self, use_case: &UseCase) -> Result<()> {
+        Self::upsert(&mut self.use_cases(), use_case);
+        Ok(())
+    }
+
+    fn load_all(&self) -> Result<Vec<UseCase>> {
+        Ok(self.use_cases().clone())
+    }
+
+    fn load_by_id(&self, id: &str) -> Result<Option<UseCase>> {
+        Ok(self.use_cases().iter().find(|uc| uc.id == id).cloned())
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        let removed = {
+            let mut use_cases = self.use_cases();
+            let position = use_cases.iter().position(|uc| uc.id == id);
+            position.map(|i| use_cases.remove(i))
+        };
+        if let Some(use_case) = removed {
+            self.remove_generated_markdown(&use_case);
+        }
+        Ok(())
+    }
+
+    fn commit(&self, transaction: RepositoryTransaction) -> Result<()> {
+        let removed: Vec<UseCase> = {
+            let mut use_cases = self.use_cases();
+            let (removed, kept) = use_cases
+                .drain(..)
+                .partition(|uc| transaction.deletes().contains(&uc.id));
+            *use_cases = kept;
+            for use_case in transaction.saves() {
+                Self::upsert(&mut use_cases, use_case);
+            }
+            removed
+        };
+        for use_case in &removed {
+            self.remove_generated_markdown(use_case);
+        }
+        Ok(())
+    }
+
+    fn save_markdown(&self, use_case_id: &str, content: &str) -> Result<()> {
+        let use_case = self
+            .load_by_id(use_case_id)?
+            .ok_or_else(|| MucmError::NotFound(format!("Use case {} not found", use_case_id)))?;
+        self.save_markdown_with_filename(&use_case, &format!("{}.md", use_case_id), content)
+    }
+
+    fn save_markdown_with_filename(
+        &self,
+        use_case: &UseCase,
+        filename: &str,
+        content: &str,
+    ) -> Result<()> {
+        self.markdown_files()
+            .insert(Self::markdown_key(use_case, filename), content.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn use_case(id: &str) -> UseCase {
+        UseCase::builder(id, format!("Use case {}", id))
+            .category("User Auth")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_clones_share_storage() {
+        let repo = InMemoryUseCaseRepository::new();
+        let handle = repo.clone();
+
+        repo.save(&use_case("UC-USE-001")).unwrap();
+        repo.save_markdown("UC-USE-001", "# Login").unwrap();
+
+        assert_eq!(handle.load_all().unwrap().len(), 1);
+        assert_eq!(
+            handle.markdown("user_auth/UC-USE-001.md").as_deref(),
+            Some("# Login")
+        );
+    }
+
+    #[test]
+    fn test_delete_removes_generated_markdown() {
+        let repo = InMemoryUseCaseRepository::with_use_cases(vec![
+            use_case("UC-USE-001"),
+            use_case("UC-USE-002"),
+        ]);
+        let first = use_case("UC-USE-001");
+        repo.save_markdown_with_filename(&first, "UC-USE-001-feature-normal.md", "a")
+            .unwrap();
+        repo.save_markdown("UC-USE-002", "b").unwrap();
+
+        repo.delete("UC-USE-001").unwrap();
+
+        assert_eq!(repo.markdown_paths(), vec!["user_auth/UC-USE-002.md"]);
+        assert_eq!(repo.load_all().unwrap()[0].id, "UC-USE-002");
+    }
+}
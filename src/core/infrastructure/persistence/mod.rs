@@ -1,16 +1,32 @@
 //! Persistence layer for use case storage.
 //!
-//! This module provides different storage backends (TOML, SQLite)
-//! with a unified interface through the UseCaseRepository trait.
+//! This module provides different storage backends (TOML, SQLite, events, and
+//! in-memory for embedding and tests)
+//! with a unified interface through the UseCaseRepository trait, plus an
+//! async counterpart (AsyncUseCaseRepository) for remote backends.
 
+pub mod async_adapter;
+pub mod backup;
+pub mod events;
 pub mod file_operations;
+pub mod load_report;
+pub mod memory;
+pub mod project_lock;
 pub mod repository_factory;
 pub mod sqlite;
 pub mod toml;
 pub mod traits;
+pub mod transaction;
 
 // Re-export for convenience
+pub use async_adapter::{block_on, BlockingRepository, SyncRepositoryAdapter};
+pub use backup::{BackupSnapshot, BackupStore};
+pub use events::{EventUseCaseRepository, EVENT_LOG};
+pub use load_report::{LoadFailure, LoadReport, RepairAction, RepairOutcome};
+pub use memory::{InMemoryActorRepository, InMemoryUseCaseRepository};
+pub use project_lock::ProjectLock;
 pub use repository_factory::RepositoryFactory;
 pub use sqlite::{SqliteActorRepository, SqliteUseCaseRepository};
 pub use toml::{TomlActorRepository, TomlUseCaseRepository};
-pub use traits::UseCaseRepository;
+pub use traits::{AsyncUseCaseRepository, RepositoryFuture, UseCaseRepository};
+pub use transaction::RepositoryTransaction;
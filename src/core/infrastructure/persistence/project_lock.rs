@@ -0,0 +1,258 @@
+//! Coordination between mucm processes working on the same project.
+//!
+//! Commands that change the project hold its command lock, an OS file lock on
+//! `mucm.lock` under `.config/.mucm`, from loading the use cases to their last
+//! write, so two processes never both load, modify and save the same data with
+//! one overwriting the other. Read-only commands take no command lock.
+//!
+//! The repository also takes the data lock (`data.lock`) around each read
+//! (shared) and write (exclusive), so a reader never sees a half-written file
+//! but only waits for the write in progress, not for a long command. A write
+//! takes the command lock as well, which nests inside the command's own lock and
+//! keeps a long-lived process (the interactive mode) from writing in the middle
+//! of another command. The OS releases every lock when the process ends,
+//! however it ends, so a crash never leaves the project locked.
+//!
+//! Every write also bumps the project revision, a counter next to the locks.
+//! Nothing is pushed to other processes: a long-running process compares the
+//! revision with the one it loaded when it next needs the data (the language
+//! server does so before handling each message) and reloads when it moved.
+
+use crate::core::{MucmError, Result};
+use std::cell::RefCell;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+
+/// Command lock file, relative to the config directory
+const LOCK_FILE: &str = "mucm.lock";
+/// Data lock file, relative to the config directory
+const DATA_LOCK_FILE: &str = "data.lock";
+/// Revision counter, relative to the config directory
+const REVISION_FILE: &str = "revision";
+
+/// How long to wait for another process to release a lock
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+/// Delay between attempts to take a lock
+const LOCK_RETRY: Duration = Duration::from_millis(20);
+
+thread_local! {
+    /// Lock files held by the current thread and whether exclusively, for
+    /// re-entrant acquisition
+    static HELD: RefCell<Vec<(PathBuf, bool)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Access to a project's use case data, released when dropped
+#[derive(Debug)]
+pub struct ProjectLock {
+    path: PathBuf,
+    revision_path: PathBuf,
+    /// The locked file of the outermost guard; `None` for a nested one
+    file: Option<File>,
+    /// The command lock a write holds along with the data lock
+    command: Option<Box<ProjectLock>>,
+}
+
+impl ProjectLock {
+    /// Files under `.config/.mucm` that coordinate the processes working on
+    /// one checkout (the locks and the revision counter), not project data
+    pub const LOCAL_FILES: &'static [&'static str] = &[LOCK_FILE, DATA_LOCK_FILE, REVISION_FILE];
+
+    /// Take the command lock of the project at `root`, waiting for other processes
+    ///
+    /// When the current thread already holds it, a nested guard is returned
+    /// at once; the lock is released with the outermost guard.
+    pub fn acquire(root: &Path) -> Result<Self> {
+        Self::take(root, LOCK_FILE, true)
+    }
+
+    /// Lock the data of the project at `root` for one read, alongside other readers
+    pub fn for_reading(root: &Path) -> Result<Self> {
+        Self::take(root, DATA_LOCK_FILE, false)
+    }
+
+    /// Lock the data of the project at `root` for one write
+    ///
+    /// Takes the command lock too, unless the current thread holds it already.
+    pub fn for_writing(root: &Path) -> Result<Self> {
+        let command = Self::acquire(root)?;
+        let mut lock = Self::take(root, DATA_LOCK_FILE, true)?;
+        lock.command = Some(Box::new(command));
+        Ok(lock)
+    }
+
+    fn take(root: &Path, name: &str, exclusive: bool) -> Result<Self> {
+        let dir = root.join(Config::CONFIG_DIR);
+        fs::create_dir_all(&dir)?;
+        let dir = fs::canonicalize(&dir).unwrap_or(dir);
+        let path = dir.join(name);
+        let revision_path = dir.join(REVISION_FILE);
+        let held = HELD.with(|held| {
+            held.borrow()
+                .iter()
+                .find(|(held_path, _)| *held_path == path)
+                .map(|(_, held_exclusively)| *held_exclusively)
+        });
+        match held {
+            Some(held_exclusively) if held_exclusively || !exclusive => {
+                return Ok(Self {
+                    path,
+                    revision_path,
+                    file: None,
+                    command: None,
+                });
+            }
+            Some(_) => {
+                return Err(MucmError::Conflict(
+                    "Cannot change the project while reading it".to_string(),
+                ))
+            }
+            None => {}
+        }
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+        let start = Instant::now();
+        loop {
+            let attempt = if exclusive {
+                file.try_lock()
+            } else {
+                file.try_lock_shared()
+            };
+            match attempt {
+                Ok(()) => break,
+                Err(TryLockError::WouldBlock) if start.elapsed() < LOCK_TIMEOUT => {
+                    thread::sleep(LOCK_RETRY)
+                }
+                Err(TryLockError::WouldBlock) => {
+                    return Err(MucmError::Conflict(format!(
+                        "Another mucm process is using the project (lock file {})",
+                        path.display()
+                    )))
+                }
+                Err(TryLockError::Error(e)) => return Err(e.into()),
+            }
+        }
+        HELD.with(|held| held.borrow_mut().push((path.clone(), exclusive)));
+        Ok(Self {
+            path,
+            revision_path,
+            file: Some(file),
+            command: None,
+        })
+    }
+
+    /// Bump the project revision, so processes holding the use cases in memory
+    /// reload them the next time they compare revisions
+    pub fn bump_revision(&self) -> Result<()> {
+        let next = read_revision(&self.revision_path) + 1;
+        fs::write(&self.revision_path, next.to_string())?;
+        Ok(())
+    }
+
+    /// Current revision of the project at `root` (0 before the first change)
+    pub fn revision(root: &Path) -> u64 {
+        read_revision(&root.join(Config::CONFIG_DIR).join(REVISION_FILE))
+    }
+}
+
+impl Drop for ProjectLock {
+    fn drop(&mut self) {
+        let Some(file) = &self.file else {
+            return;
+        };
+        HELD.with(|held| held.borrow_mut().retain(|(path, _)| *path != self.path));
+        let _ = file.unlock();
+    }
+}
+
+fn read_revision(path: &Path) -> u64 {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| content.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tempfile::TempDir;
+
+    /// Whether some guard holds the command lock of the project at `root`
+    fn is_locked(root: &Path) -> bool {
+        let path = root.join(Config::CONFIG_DIR).join(LOCK_FILE);
+        let file = File::open(path).unwrap();
+        matches!(file.try_lock(), Err(TryLockError::WouldBlock))
+    }
+
+    #[test]
+    fn test_lock_is_exclusive_and_counts_changes() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        assert_eq!(ProjectLock::revision(&root), 0);
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let lock = ProjectLock::acquire(&root).unwrap();
+        let waiter = {
+            let (root, order) = (root.clone(), Arc::clone(&order));
+            thread::spawn(move || {
+                let lock = ProjectLock::acquire(&root).unwrap();
+                order.lock().unwrap().push("second");
+                lock.bump_revision().unwrap();
+            })
+        };
+        thread::sleep(Duration::from_millis(100));
+        order.lock().unwrap().push("first");
+        lock.bump_revision().unwrap();
+        drop(lock);
+        waiter.join().unwrap();
+
+        assert_eq!(*order.lock().unwrap(), ["first", "second"]);
+        assert_eq!(ProjectLock::revision(&root), 2);
+        assert!(!is_locked(&root));
+    }
+
+    #[test]
+    fn test_lock_is_reentrant_on_a_thread() {
+        let dir = TempDir::new().unwrap();
+
+        let outer = ProjectLock::acquire(dir.path()).unwrap();
+        let write = ProjectLock::for_writing(dir.path()).unwrap();
+        let read = ProjectLock::for_reading(dir.path()).unwrap();
+        write.bump_revision().unwrap();
+        drop(read);
+        drop(write);
+        assert!(is_locked(dir.path()), "a nested guard keeps the lock");
+
+        drop(outer);
+        assert!(!is_locked(dir.path()));
+        assert_eq!(ProjectLock::revision(dir.path()), 1);
+    }
+
+    #[test]
+    fn test_reads_do_not_wait_for_a_command() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+
+        let command = ProjectLock::acquire(&root).unwrap();
+        let read = ProjectLock::for_reading(&root).unwrap();
+        let other_reader = {
+            let root = root.clone();
+            thread::spawn(move || ProjectLock::for_reading(&root).map(drop).is_ok())
+        };
+        assert!(other_reader.join().unwrap());
+        assert!(ProjectLock::for_writing(&root).is_err());
+
+        drop(read);
+        drop(command);
+        assert!(!is_locked(&root));
+    }
+}
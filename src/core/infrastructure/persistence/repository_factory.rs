@@ -1,17 +1,20 @@
 //! Repository Factory
 //!
 //! This module provides a factory for creating use case repositories based on
-//! configuration settings. It supports both TOML and SQLite backends with
-//! identical interfaces through the UseCaseRepository trait.
+//! configuration settings. It supports TOML, SQLite and (experimental) event-log
+//! backends with identical interfaces through the UseCaseRepository trait.
+//! The event-log backend keeps actors in TOML files.
 
 use crate::config::{Config, StorageBackend};
-use crate::core::domain::PersonaRepository;
+use crate::core::domain::{ActorRepository, PersonaRepository};
+use crate::core::infrastructure::persistence::async_adapter::SyncRepositoryAdapter;
+use crate::core::infrastructure::persistence::events::EventUseCaseRepository;
 use crate::core::infrastructure::persistence::sqlite::{
     SqliteActorRepository, SqliteUseCaseRepository,
 };
 use crate::core::infrastructure::persistence::toml::{TomlActorRepository, TomlUseCaseRepository};
-use crate::core::infrastructure::persistence::traits::UseCaseRepository;
-use anyhow::{Context, Result};
+use crate::core::infrastructure::persistence::traits::{AsyncUseCaseRepository, UseCaseRepository};
+use crate::core::{Context, Result};
 use rusqlite::Connection;
 use std::sync::{Arc, Mutex};
 
@@ -26,7 +29,7 @@ impl RepositoryFactory {
     ///
     /// # Returns
     /// A boxed trait object implementing UseCaseRepository, or an error if creation fails
-    pub fn create(config: &Config) -> Result<Box<dyn UseCaseRepository>> {
+    pub fn create(config: &Config) -> Result<Box<dyn UseCaseRepository + Send + Sync>> {
         match config.storage.backend {
             StorageBackend::Toml => {
                 let repo = TomlUseCaseRepository::new(config.clone());
@@ -44,12 +47,31 @@ impl RepositoryFactory {
                     })?;
                 }
 
-                let repo = SqliteUseCaseRepository::new(&db_path)?;
+                let repo = SqliteUseCaseRepository::new(&db_path)?
+                    .with_default_methodology(&config.templates.default_methodology);
                 Ok(Box::new(repo))
             }
+            StorageBackend::Events => Ok(Box::new(EventUseCaseRepository::new(config.clone()))),
         }
     }
 
+    /// Create an async repository based on the provided configuration
+    ///
+    /// The repository made by [`RepositoryFactory::create`], wrapped in a
+    /// [`SyncRepositoryAdapter`]: its calls still block. Remote backends would
+    /// implement [`AsyncUseCaseRepository`] directly.
+    ///
+    /// # Arguments
+    /// * `config` - The application configuration containing storage backend settings
+    ///
+    /// # Returns
+    /// A boxed trait object implementing AsyncUseCaseRepository, or an error if creation fails
+    pub fn create_async(config: &Config) -> Result<Box<dyn AsyncUseCaseRepository>> {
+        Ok(Box::new(SyncRepositoryAdapter::from_boxed(Self::create(
+            config,
+        )?)))
+    }
+
     /// Create a repository with a custom database path (SQLite only)
     ///
     /// This is useful for testing or when you want to specify a custom database location.
@@ -70,9 +92,11 @@ impl RepositoryFactory {
                 Ok(Box::new(repo))
             }
             StorageBackend::Sqlite => {
-                let repo = SqliteUseCaseRepository::new(db_path)?;
+                let repo = SqliteUseCaseRepository::new(db_path)?
+                    .with_default_methodology(&config.templates.default_methodology);
                 Ok(Box::new(repo))
             }
+            StorageBackend::Events => Ok(Box::new(EventUseCaseRepository::new(config.clone()))),
         }
     }
 
@@ -85,7 +109,7 @@ impl RepositoryFactory {
     /// A boxed trait object implementing PersonaRepository, or an error if creation fails
     pub fn create_persona_repository(config: &Config) -> Result<Box<dyn PersonaRepository>> {
         match config.storage.backend {
-            StorageBackend::Toml => {
+            StorageBackend::Toml | StorageBackend::Events => {
                 let repo = TomlActorRepository::new(config.clone());
                 Ok(Box::new(repo))
             }
@@ -127,7 +151,7 @@ impl RepositoryFactory {
         db_path: P,
     ) -> Result<Box<dyn PersonaRepository>> {
         match config.storage.backend {
-            StorageBackend::Toml => {
+            StorageBackend::Toml | StorageBackend::Events => {
                 let repo = TomlActorRepository::new(config.clone());
                 Ok(Box::new(repo))
             }
@@ -143,6 +167,40 @@ impl RepositoryFactory {
             }
         }
     }
+
+    /// Create an actor repository (personas and system actors) based on the configuration
+    ///
+    /// Uses the same storage as [`RepositoryFactory::create_persona_repository`].
+    ///
+    /// # Arguments
+    /// * `config` - The application configuration containing storage backend settings
+    ///
+    /// # Returns
+    /// A boxed trait object implementing ActorRepository
+    pub fn create_actor_repository(config: &Config) -> Result<Box<dyn ActorRepository>> {
+        match config.storage.backend {
+            StorageBackend::Toml | StorageBackend::Events => {
+                Ok(Box::new(TomlActorRepository::new(config.clone())))
+            }
+            StorageBackend::Sqlite => {
+                let db_path =
+                    std::path::Path::new(&config.directories.data_dir).join("usecases.db");
+                if let Some(parent) = db_path.parent() {
+                    std::fs::create_dir_all(parent).with_context(|| {
+                        format!("Failed to create database directory {:?}", parent)
+                    })?;
+                }
+
+                let conn = Connection::open(&db_path)
+                    .with_context(|| format!("Failed to open database at {:?}", db_path))?;
+                SqliteActorRepository::initialize(&conn)?;
+
+                Ok(Box::new(SqliteActorRepository::new(Arc::new(Mutex::new(
+                    conn,
+                )))))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -5,7 +5,7 @@
 use crate::core::domain::{
     ActorEntity, ActorRepository, ActorType, Metadata, Persona, PersonaRepository,
 };
-use anyhow::Result;
+use crate::core::Result;
 use rusqlite::{params, Connection, OptionalExtension};
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -149,6 +149,8 @@ impl ActorRepository for SqliteActorRepository {
                     metadata: Metadata {
                         created_at,
                         updated_at,
+                        review_by: None,
+                        confluence_page_id: None,
                     },
                     extra,
                 })
@@ -193,6 +195,8 @@ impl ActorRepository for SqliteActorRepository {
                         metadata: Metadata {
                             created_at,
                             updated_at,
+                            review_by: None,
+                            confluence_page_id: None,
                         },
                         extra,
                     })
@@ -234,10 +238,7 @@ impl ActorRepository for SqliteActorRepository {
 
     fn load_all_personas(&self) -> Result<Vec<Persona>> {
         let actors = self.load_all_actors()?;
-        let personas = actors
-            .iter()
-            .filter_map(|actor| Persona::from_actor(actor))
-            .collect();
+        let personas = actors.iter().filter_map(Persona::from_actor).collect();
         Ok(personas)
     }
 
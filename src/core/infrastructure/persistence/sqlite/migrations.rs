@@ -4,7 +4,9 @@
 //! versions to newer ones, ensuring smooth upgrades as the schema evolves.
 
 use super::schema::{Schema, SCHEMA_VERSION};
-use anyhow::Result;
+use crate::core::utils::start_progress;
+use crate::core::MucmError;
+use crate::core::Result;
 use rusqlite::Connection;
 
 /// Database migrator for handling schema upgrades.
@@ -33,24 +35,27 @@ impl Migrator {
 
         if current_version == 0 {
             // Fresh database - initialize with latest schema
-            println!("🔨 Initializing database schema...");
+            eprintln!("🔨 Initializing database schema...");
             Schema::initialize(conn)?;
-            println!("✅ Database schema initialized (v{})", SCHEMA_VERSION);
+            eprintln!("✅ Database schema initialized (v{})", SCHEMA_VERSION);
             return Ok(());
         }
 
-        println!(
+        eprintln!(
             "🔄 Migrating database from v{} to v{}...",
             current_version, SCHEMA_VERSION
         );
 
         // Run migrations in order
+        let progress = start_progress((SCHEMA_VERSION - current_version) as u64, "Migrating");
         for version in (current_version + 1)..=SCHEMA_VERSION {
             Self::run_migration(conn, version)?;
-            println!("   ✅ Migrated to v{}", version);
+            progress.println(&format!("   ✅ Migrated to v{}", version));
+            progress.inc();
         }
+        progress.finish();
 
-        println!("✅ Database migration complete");
+        eprintln!("✅ Database migration complete");
         Ok(())
     }
 
@@ -87,7 +92,17 @@ impl Migrator {
     fn run_migration(conn: &Connection, version: i32) -> Result<()> {
         match version {
             1 => Self::migrate_to_v1(conn),
-            _ => anyhow::bail!("Unknown migration version: {}", version),
+            2 => Self::migrate_to_v2(conn),
+            3 => Self::migrate_to_v3(conn),
+            4 => Self::migrate_to_v4(conn),
+            5 => Self::migrate_to_v5(conn),
+            6 => Self::migrate_to_v6(conn),
+            7 => Self::migrate_to_v7(conn),
+            8 => Self::migrate_to_v8(conn),
+            _ => Err(MucmError::Validation(format!(
+                "Unknown migration version: {}",
+                version
+            ))),
         }
     }
 
@@ -100,19 +115,122 @@ impl Migrator {
         Schema::initialize(conn)
     }
 
+    /// Migration 2: Manual scenario ordering.
+    ///
+    /// Adds the nullable `sort_order` column backing `Scenario::order`.
+    fn migrate_to_v2(conn: &Connection) -> Result<()> {
+        conn.execute("ALTER TABLE scenarios ADD COLUMN sort_order INTEGER", [])?;
+        Schema::set_schema_version(conn, 2)?;
+        Ok(())
+    }
+
+    /// Migration 3: Review dates.
+    ///
+    /// Adds the nullable `review_by` column backing `Metadata::review_by`.
+    fn migrate_to_v3(conn: &Connection) -> Result<()> {
+        conn.execute("ALTER TABLE use_cases ADD COLUMN review_by TEXT", [])?;
+        Schema::set_schema_version(conn, 3)?;
+        Ok(())
+    }
+
+    /// Migration 4: Gherkin step keywords.
+    ///
+    /// Adds the nullable `keyword` column backing `ScenarioStep::keyword`.
+    fn migrate_to_v4(conn: &Connection) -> Result<()> {
+        let has_steps: bool = conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='scenario_steps'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+        if has_steps {
+            conn.execute("ALTER TABLE scenario_steps ADD COLUMN keyword TEXT", [])?;
+        }
+        Schema::set_schema_version(conn, 4)?;
+        Ok(())
+    }
+
+    /// Migration 5: Scenario tags.
+    ///
+    /// Adds the nullable `tags` column (a JSON array) backing `Scenario::tags`.
+    fn migrate_to_v5(conn: &Connection) -> Result<()> {
+        let has_scenarios: bool = conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='scenarios'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+        if has_scenarios {
+            conn.execute("ALTER TABLE scenarios ADD COLUMN tags TEXT", [])?;
+        }
+        Schema::set_schema_version(conn, 5)?;
+        Ok(())
+    }
+
+    /// Migration 6: Architecture decision record links.
+    ///
+    /// Adds the nullable `adrs` column (a JSON array) backing `UseCase::adrs`.
+    fn migrate_to_v6(conn: &Connection) -> Result<()> {
+        let has_use_cases: bool = conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='use_cases'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+        if has_use_cases {
+            conn.execute("ALTER TABLE use_cases ADD COLUMN adrs TEXT", [])?;
+        }
+        Schema::set_schema_version(conn, 6)?;
+        Ok(())
+    }
+
+    /// Migration 7: Scenario condition inheritance.
+    ///
+    /// Adds the `inherit_preconditions` and `inherit_postconditions` flags
+    /// backing the fields of the same name on `Scenario`.
+    fn migrate_to_v7(conn: &Connection) -> Result<()> {
+        let has_scenarios: bool = conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='scenarios'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+        if has_scenarios {
+            conn.execute(
+                "ALTER TABLE scenarios ADD COLUMN inherit_preconditions INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+            conn.execute(
+                "ALTER TABLE scenarios ADD COLUMN inherit_postconditions INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+        Schema::set_schema_version(conn, 7)?;
+        Ok(())
+    }
+
+    /// Migration 8: Confluence publishing.
+    ///
+    /// Adds the nullable `confluence_page_id` column backing
+    /// `Metadata::confluence_page_id`.
+    fn migrate_to_v8(conn: &Connection) -> Result<()> {
+        let has_use_cases: bool = conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='use_cases'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+        if has_use_cases {
+            conn.execute(
+                "ALTER TABLE use_cases ADD COLUMN confluence_page_id TEXT",
+                [],
+            )?;
+        }
+        Schema::set_schema_version(conn, 8)?;
+        Ok(())
+    }
+
     // Future migrations will be added here as needed:
     //
-    // fn migrate_to_v2(conn: &Connection) -> Result<()> {
-    //     // Add new column, table, or index
-    //     conn.execute("ALTER TABLE use_cases ADD COLUMN status TEXT DEFAULT 'draft'", [])?;
-    //     Schema::set_schema_version(conn, 2)?;
-    //     Ok(())
-    // }
-    //
-    // fn migrate_to_v3(conn: &Connection) -> Result<()> {
+    // fn migrate_to_v9(conn: &Connection) -> Result<()> {
     //     // Example: Add personas table
     //     conn.execute("CREATE TABLE personas (...)", [])?;
-    //     Schema::set_schema_version(conn, 3)?;
+    //     Schema::set_schema_version(conn, 9)?;
     //     Ok(())
     // }
 }
@@ -199,4 +317,94 @@ mod tests {
         assert!(tables.contains(&"use_case_postconditions".to_string()));
         assert!(tables.contains(&"use_case_references".to_string()));
     }
+
+    /// Columns added by each migration, as (version, table, column)
+    const ADDED_COLUMNS: &[(i32, &str, &str)] = &[
+        (2, "scenarios", "sort_order"),
+        (3, "use_cases", "review_by"),
+        (4, "scenario_steps", "keyword"),
+        (5, "scenarios", "tags"),
+        (6, "use_cases", "adrs"),
+        (7, "scenarios", "inherit_preconditions"),
+        (7, "scenarios", "inherit_postconditions"),
+        (8, "use_cases", "confluence_page_id"),
+    ];
+
+    fn columns(conn: &Connection, table: &str) -> Vec<String> {
+        conn.prepare(&format!("SELECT name FROM pragma_table_info('{}')", table))
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_migrations_add_their_columns() {
+        assert_eq!(
+            ADDED_COLUMNS.last().map(|(version, _, _)| *version),
+            Some(SCHEMA_VERSION),
+            "every migration needs an entry in ADDED_COLUMNS"
+        );
+
+        for from in 1..SCHEMA_VERSION {
+            // A database at `from`, with the columns of the migrations it already ran
+            let conn = create_test_db();
+            let mut sql = format!(
+                "CREATE TABLE _metadata (key TEXT PRIMARY KEY, value TEXT NOT NULL, updated_at TEXT NOT NULL);
+                 INSERT INTO _metadata VALUES ('schema_version', '{}', datetime('now'));",
+                from
+            );
+            for (table, key) in [
+                ("use_cases", "id TEXT PRIMARY KEY"),
+                (
+                    "scenarios",
+                    "id TEXT PRIMARY KEY, use_case_id TEXT NOT NULL",
+                ),
+                (
+                    "scenario_steps",
+                    "id INTEGER PRIMARY KEY, scenario_id TEXT NOT NULL",
+                ),
+            ] {
+                let existing: Vec<String> = ADDED_COLUMNS
+                    .iter()
+                    .filter(|(version, added, _)| *version <= from && *added == table)
+                    .map(|(_, _, column)| format!(", {} INTEGER NOT NULL DEFAULT 0", column))
+                    .collect();
+                sql.push_str(&format!(
+                    "CREATE TABLE {} ({}{});",
+                    table,
+                    key,
+                    existing.concat()
+                ));
+            }
+            sql.push_str(
+                "INSERT INTO scenarios (id, use_case_id) VALUES ('UC-AUT-001-S01', 'UC-AUT-001');",
+            );
+            conn.execute_batch(&sql).unwrap();
+
+            Migrator::migrate(&conn).unwrap();
+
+            assert_eq!(Schema::get_version(&conn).unwrap(), SCHEMA_VERSION);
+            for (version, table, column) in ADDED_COLUMNS {
+                assert!(
+                    columns(&conn, table).contains(&column.to_string()),
+                    "{}.{} (v{}) missing after migrating from v{}",
+                    table,
+                    column,
+                    version,
+                    from
+                );
+            }
+            // Rows that existed before keep working with the new columns
+            let inherits: bool = conn
+                .query_row(
+                    "SELECT inherit_preconditions OR inherit_postconditions FROM scenarios",
+                    [],
+                    |row| row.get(0),
+                )
+                .unwrap();
+            assert!(!inherits);
+        }
+    }
 }
@@ -5,10 +5,14 @@
 //! transaction support and error handling.
 
 use crate::core::domain::{Condition, UseCase};
+use crate::core::infrastructure::persistence::file_operations::FileOperations;
+use crate::core::infrastructure::persistence::load_report::{LoadFailure, LoadReport};
 use crate::core::infrastructure::persistence::sqlite::Migrator;
 use crate::core::infrastructure::persistence::traits::UseCaseRepository;
-use anyhow::{anyhow, Context, Result};
+use crate::core::infrastructure::persistence::transaction::RepositoryTransaction;
+use crate::core::{Context, MucmError, Result};
 use rusqlite::{params, Connection, Transaction};
+use std::io::Write;
 use std::path::Path;
 use std::sync::Mutex;
 
@@ -21,7 +25,8 @@ use std::sync::Mutex;
 /// Uses `Mutex<Connection>` for thread-safe database access.
 ///
 /// # Error Handling
-/// All methods return `anyhow::Result` with descriptive error messages.
+/// All methods return [`crate::core::Result`]; SQLite failures become a
+/// [`MucmError`](crate::core::MucmError) with context naming the failed operation.
 ///
 /// # Performance
 /// - Indexed queries for fast lookups
@@ -32,6 +37,8 @@ pub struct SqliteUseCaseRepository {
     conn: Mutex<Connection>,
     /// Path to the database file (used for relative markdown storage)
     db_path: std::path::PathBuf,
+    /// Methodology of the view given to use cases stored without any view
+    default_methodology: String,
 }
 
 impl SqliteUseCaseRepository {
@@ -57,14 +64,23 @@ impl SqliteUseCaseRepository {
         Ok(Self {
             conn: Mutex::new(conn),
             db_path: db_path_buf,
+            default_methodology: crate::config::Config::default()
+                .templates
+                .default_methodology,
         })
     }
 
+    /// Set the methodology used for the default view of legacy use cases without views.
+    pub fn with_default_methodology(mut self, methodology: impl Into<String>) -> Self {
+        self.default_methodology = methodology.into();
+        self
+    }
+
     /// Get a connection from the mutex (internal helper).
     fn get_conn(&self) -> Result<std::sync::MutexGuard<'_, Connection>> {
         self.conn
             .lock()
-            .map_err(|e| anyhow!("Failed to acquire database lock: {}", e))
+            .map_err(|e| MucmError::Storage(format!("Failed to acquire database lock: {}", e)))
     }
 
     /// Execute a query within a database transaction (internal helper).
@@ -79,6 +95,12 @@ impl SqliteUseCaseRepository {
         Ok(result)
     }
 
+    /// Remove generated documents for every view ({id}.md and {id}-{view}.md, or .adoc)
+    fn remove_generated_markdown(&self, id: &str) -> Result<()> {
+        let db_dir = self.db_path.parent().unwrap_or(std::path::Path::new("."));
+        FileOperations::remove_generated_documents(&db_dir.join("markdown"), id)
+    }
+
     /// Load scenarios for a use case from relational tables (Connection version).
     fn load_scenarios_for_use_case(
         conn: &Connection,
@@ -90,8 +112,9 @@ impl SqliteUseCaseRepository {
 
         // Load all scenarios for this use case
         let mut stmt = conn.prepare(
-            "SELECT id, title, description, scenario_type, status, persona, created_at, updated_at, extra_json
-             FROM scenarios WHERE use_case_id = ? ORDER BY id"
+            "SELECT id, title, description, scenario_type, status, persona, created_at, updated_at, extra_json, sort_order, tags,
+                    inherit_preconditions, inherit_postconditions
+             FROM scenarios WHERE use_case_id = ? ORDER BY sort_order IS NULL, sort_order, id"
         )?;
 
         let scenario_rows = stmt.query_map([use_case_id], |row| {
@@ -103,13 +126,17 @@ impl SqliteUseCaseRepository {
             Ok((
                 scenario_id,
                 (
-                    row.get::<_, String>(1)?,         // title
-                    row.get::<_, String>(2)?,         // description
-                    row.get::<_, String>(3)?,         // scenario_type
-                    row.get::<_, String>(4)?,         // status
-                    row.get::<_, Option<String>>(5)?, // persona
-                    row.get::<_, String>(6)?,         // created_at
-                    row.get::<_, String>(7)?,         // updated_at
+                    row.get::<_, String>(1)?,          // title
+                    row.get::<_, String>(2)?,          // description
+                    row.get::<_, String>(3)?,          // scenario_type
+                    row.get::<_, String>(4)?,          // status
+                    row.get::<_, Option<String>>(5)?,  // persona
+                    row.get::<_, String>(6)?,          // created_at
+                    row.get::<_, String>(7)?,          // updated_at
+                    row.get::<_, Option<u32>>(9)?,     // sort_order
+                    row.get::<_, Option<String>>(10)?, // tags (JSON array)
+                    row.get::<_, bool>(11)?,           // inherit_preconditions
+                    row.get::<_, bool>(12)?,           // inherit_postconditions
                     extra,
                 ),
             ))
@@ -126,9 +153,16 @@ impl SqliteUseCaseRepository {
                     persona,
                     _created_at_str,
                     _updated_at_str,
+                    order,
+                    tags_json,
+                    inherit_preconditions,
+                    inherit_postconditions,
                     extra,
                 ),
             ) = scenario_result?;
+            let tags: Vec<String> = tags_json
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default();
 
             // Parse scenario type and status
             let scenario_type = scenario_type_str.parse().unwrap_or_default();
@@ -137,7 +171,7 @@ impl SqliteUseCaseRepository {
 
             // Load steps
             let mut steps_stmt = conn.prepare(
-                "SELECT step_order, actor, receiver, action, description, notes FROM scenario_steps WHERE scenario_id = ? ORDER BY step_order"
+                "SELECT step_order, actor, receiver, action, description, notes, keyword FROM scenario_steps WHERE scenario_id = ? ORDER BY step_order"
             )?;
             let step_rows = steps_stmt.query_map([&scenario_id], |row| {
                 Ok(ScenarioStep {
@@ -147,6 +181,7 @@ impl SqliteUseCaseRepository {
                     action: row.get(3)?,
                     description: row.get(4)?,
                     notes: row.get(5)?,
+                    keyword: row.get(6)?,
                 })
             })?;
             let steps: Vec<ScenarioStep> = step_rows.collect::<Result<Vec<_>, _>>()?;
@@ -217,11 +252,15 @@ impl SqliteUseCaseRepository {
                 description,
                 scenario_type,
                 status,
+                order,
                 persona,
                 steps,
                 preconditions,
                 postconditions,
+                inherit_preconditions,
+                inherit_postconditions,
                 references,
+                tags,
                 extra,
             });
         }
@@ -234,14 +273,19 @@ impl SqliteUseCaseRepository {
         // Serialize extra fields to JSON (scenarios are now in separate tables)
         let extra_json = serde_json::to_string(&use_case.extra)
             .context("Failed to serialize extra fields to JSON")?;
+        let adrs_json = if use_case.adrs.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&use_case.adrs)?)
+        };
 
         // Insert or replace the main use case record
         tx.execute(
             r#"
             INSERT OR REPLACE INTO use_cases (
                 id, title, category, description, priority,
-                created_at, updated_at, extra_json
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                created_at, updated_at, extra_json, review_by, adrs, confluence_page_id
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
             params![
                 use_case.id,
@@ -252,6 +296,9 @@ impl SqliteUseCaseRepository {
                 use_case.metadata.created_at.to_rfc3339(),
                 use_case.metadata.updated_at.to_rfc3339(),
                 extra_json,
+                use_case.metadata.review_by.map(|date| date.to_string()),
+                adrs_json,
+                use_case.metadata.confluence_page_id,
             ],
         )
         .context("Failed to save use case")?;
@@ -326,10 +373,15 @@ impl SqliteUseCaseRepository {
             // Serialize scenario extra fields
             let scenario_extra_json = serde_json::to_string(&scenario.extra)
                 .context("Failed to serialize scenario extra fields")?;
+            let scenario_tags_json = if scenario.tags.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&scenario.tags)?)
+            };
 
             tx.execute(
-                "INSERT INTO scenarios (id, use_case_id, title, description, scenario_type, status, persona, extra_json)
-                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                "INSERT INTO scenarios (id, use_case_id, title, description, scenario_type, status, persona, created_at, updated_at, extra_json, sort_order, tags, inherit_preconditions, inherit_postconditions)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
                 params![
                     scenario.id,
                     use_case.id,
@@ -338,7 +390,14 @@ impl SqliteUseCaseRepository {
                     scenario.scenario_type.to_string(),
                     scenario.status.to_string(),
                     scenario.persona,
+                    // Scenarios carry no timestamps of their own
+                    use_case.metadata.created_at.to_rfc3339(),
+                    use_case.metadata.updated_at.to_rfc3339(),
                     scenario_extra_json,
+                    scenario.order,
+                    scenario_tags_json,
+                    scenario.inherit_preconditions,
+                    scenario.inherit_postconditions,
                 ],
             )
             .context("Failed to save scenario")?;
@@ -346,9 +405,9 @@ impl SqliteUseCaseRepository {
             // Insert scenario steps
             for step in &scenario.steps {
                 tx.execute(
-                    "INSERT INTO scenario_steps (scenario_id, step_order, actor, receiver, action, description, notes)
-                     VALUES (?, ?, ?, ?, ?, ?, ?)",
-                    params![scenario.id, step.order, step.actor, step.receiver, step.action, step.description, step.notes],
+                    "INSERT INTO scenario_steps (scenario_id, step_order, actor, receiver, action, description, notes, keyword)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                    params![scenario.id, step.order, step.actor, step.receiver, step.action, step.description, step.notes, step.keyword],
                 )
                 .context("Failed to save scenario step")?;
             }
@@ -414,7 +473,7 @@ impl SqliteUseCaseRepository {
             .prepare(
                 r#"
             SELECT id, title, category, description, priority,
-                   created_at, updated_at, extra_json
+                   created_at, updated_at, extra_json, review_by, adrs, confluence_page_id
             FROM use_cases WHERE id = ?
             "#,
             )
@@ -459,12 +518,28 @@ impl SqliteUseCaseRepository {
                                 Box::new(e),
                             )
                         })?,
+                        review_by: row
+                            .get::<_, Option<String>>(8)?
+                            .map(|date| date.parse())
+                            .transpose()
+                            .map_err(|e| {
+                                rusqlite::Error::FromSqlConversionFailure(
+                                    8,
+                                    rusqlite::types::Type::Text,
+                                    Box::new(e),
+                                )
+                            })?,
+                        confluence_page_id: row.get(10)?,
                     },
                     views: Vec::new(), // Will be populated below (multi-view support)
                     preconditions: Vec::new(), // Will be populated below
                     postconditions: Vec::new(), // Will be populated below
                     methodology_fields: std::collections::HashMap::new(), // New field for methodology-specific fields
                     use_case_references: Vec::new(),                      // Will be populated below
+                    adrs: row
+                        .get::<_, Option<String>>(9)?
+                        .and_then(|json| serde_json::from_str(&json).ok())
+                        .unwrap_or_default(),
                     scenarios: Vec::new(), // Will be loaded from relational tables
                     extra,
                 })
@@ -567,6 +642,10 @@ impl UseCaseRepository for SqliteUseCaseRepository {
     }
 
     fn load_all(&self) -> Result<Vec<UseCase>> {
+        Ok(self.load_all_with_report()?.use_cases)
+    }
+
+    fn load_all_with_report(&self) -> Result<LoadReport> {
         let conn = self.get_conn()?;
         let mut stmt = conn
             .prepare("SELECT id FROM use_cases ORDER BY id")
@@ -578,29 +657,32 @@ impl UseCaseRepository for SqliteUseCaseRepository {
             .collect::<Result<Vec<String>, _>>()
             .context("Failed to collect use case IDs")?;
 
-        let mut use_cases = Vec::new();
+        let mut report = LoadReport::default();
         for id in ids {
-            if let Some(mut use_case) = Self::load_by_id_internal_conn(&conn, &id)
-                .with_context(|| format!("Failed to load use case {}", id))?
-            {
-                // Migration: If use case has no views, add default view
-                if use_case.views.is_empty() {
-                    use crate::core::MethodologyView;
-                    let config = crate::config::Config::load()
-                        .unwrap_or_else(|_| crate::config::Config::default());
-                    let default_methodology = &config.templates.default_methodology;
-                    let default_view = MethodologyView::new(default_methodology, "normal");
-                    use_case.views.push(default_view);
-
-                    // Note: Auto-save happens via the application service layer
-                    // which will detect the change and persist it
+            match Self::load_by_id_internal_conn(&conn, &id) {
+                Ok(Some(mut use_case)) => {
+                    // Migration: If use case has no views, add default view
+                    if use_case.views.is_empty() {
+                        use crate::core::MethodologyView;
+                        let default_view =
+                            MethodologyView::new(&self.default_methodology, "normal");
+                        use_case.views.push(default_view);
+
+                        // Note: Auto-save happens via the application service layer
+                        // which will detect the change and persist it
+                    }
+
+                    report.use_cases.push(use_case);
                 }
-
-                use_cases.push(use_case);
+                Ok(None) => {}
+                Err(e) => report.failures.push(LoadFailure {
+                    path: self.db_path.clone(),
+                    error: format!("use case {}: {:#}", id, e),
+                }),
             }
         }
 
-        Ok(use_cases)
+        Ok(report)
     }
 
     fn load_by_id(&self, id: &str) -> Result<Option<UseCase>> {
@@ -608,6 +690,35 @@ impl UseCaseRepository for SqliteUseCaseRepository {
         Self::load_by_id_internal_conn(&conn, id)
     }
 
+    fn delete(&self, id: &str) -> Result<()> {
+        // Scenarios, conditions, and references cascade via foreign keys
+        self.with_db_transaction(|tx| {
+            tx.execute("DELETE FROM use_cases WHERE id = ?1", params![id])
+                .with_context(|| format!("Failed to delete use case {}", id))?;
+            Ok(())
+        })?;
+
+        self.remove_generated_markdown(id)
+    }
+
+    fn commit(&self, transaction: RepositoryTransaction) -> Result<()> {
+        self.with_db_transaction(|tx| {
+            for id in transaction.deletes() {
+                tx.execute("DELETE FROM use_cases WHERE id = ?1", params![id])
+                    .with_context(|| format!("Failed to delete use case {}", id))?;
+            }
+            for use_case in transaction.saves() {
+                Self::save_internal(tx, use_case)?;
+            }
+            Ok(())
+        })?;
+
+        for id in transaction.deletes() {
+            self.remove_generated_markdown(id)?;
+        }
+        Ok(())
+    }
+
     fn save_markdown(&self, use_case_id: &str, content: &str) -> Result<()> {
         // Save markdown files in a directory relative to the database location
         // This ensures test isolation and proper organization
@@ -624,6 +735,22 @@ impl UseCaseRepository for SqliteUseCaseRepository {
         Ok(())
     }
 
+    fn write_markdown_with_filename(
+        &self,
+        _use_case: &UseCase,
+        filename: &str,
+        render: &mut dyn FnMut(&mut dyn Write) -> Result<()>,
+    ) -> Result<()> {
+        let db_dir = self.db_path.parent().unwrap_or(std::path::Path::new("."));
+        let markdown_dir = db_dir.join("markdown");
+        std::fs::create_dir_all(&markdown_dir)
+            .with_context(|| format!("Failed to create markdown directory {:?}", markdown_dir))?;
+
+        let filepath = markdown_dir.join(filename);
+        FileOperations::write_streamed(&filepath, render)
+            .with_context(|| format!("Failed to write markdown file {:?}", filepath))
+    }
+
     fn save_markdown_with_filename(
         &self,
         _use_case: &UseCase,
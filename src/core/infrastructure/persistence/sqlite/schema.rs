@@ -3,14 +3,14 @@
 //! This module defines the complete database schema for storing use cases
 //! in SQLite, including all tables, indexes, and version management.
 
-use anyhow::Result;
+use crate::core::Result;
 use rusqlite::Connection;
 
 /// Current schema version.
 ///
 /// Increment this when making schema changes and add corresponding
 /// migration in migrations.rs.
-pub const SCHEMA_VERSION: i32 = 1;
+pub const SCHEMA_VERSION: i32 = 8;
 
 /// Schema manager for creating and validating database structure.
 pub struct Schema;
@@ -71,7 +71,10 @@ impl Schema {
                 priority TEXT NOT NULL,
                 created_at TEXT NOT NULL,
                 updated_at TEXT NOT NULL,
-                extra_json TEXT NOT NULL DEFAULT '{}'
+                extra_json TEXT NOT NULL DEFAULT '{}',
+                review_by TEXT,
+                adrs TEXT,
+                confluence_page_id TEXT
             )",
             [],
         )?;
@@ -178,7 +181,7 @@ impl Schema {
     }
 
     /// Set the schema version in metadata table.
-    fn set_schema_version(conn: &Connection, version: i32) -> Result<()> {
+    pub(super) fn set_schema_version(conn: &Connection, version: i32) -> Result<()> {
         conn.execute(
             "INSERT OR REPLACE INTO _metadata (key, value, updated_at)
              VALUES ('schema_version', ?1, datetime('now'))",
@@ -202,6 +205,10 @@ impl Schema {
                 updated_at TEXT NOT NULL,
                 version INTEGER NOT NULL DEFAULT 1,
                 extra_json TEXT NOT NULL DEFAULT '{}',
+                sort_order INTEGER,
+                tags TEXT,
+                inherit_preconditions INTEGER NOT NULL DEFAULT 0,
+                inherit_postconditions INTEGER NOT NULL DEFAULT 0,
                 FOREIGN KEY (use_case_id) REFERENCES use_cases(id) ON DELETE CASCADE
             )",
             [],
@@ -241,6 +248,7 @@ impl Schema {
                 action TEXT NOT NULL,
                 description TEXT NOT NULL,
                 notes TEXT,
+                keyword TEXT,
                 FOREIGN KEY (scenario_id) REFERENCES scenarios(id) ON DELETE CASCADE
             )",
             [],
@@ -1,7 +1,8 @@
 // TOML-based implementation of ActorRepository
+use super::canonical::{canonical_document, carry_comments};
 use crate::config::Config;
 use crate::core::domain::{ActorEntity, ActorRepository, Persona, PersonaRepository};
-use anyhow::Result;
+use crate::core::Result;
 use std::fs;
 use std::path::Path;
 
@@ -48,9 +49,15 @@ impl ActorRepository for TomlActorRepository {
         actor_for_toml.extra.retain(|_, v| !v.is_null());
 
         // Save TOML file (source of truth in data directory)
+        // Canonical layout with extra fields sorted, keeping any comments already in the file
         let toml_path = data_dir.join(format!("{}.toml", actor.id));
-        let toml_content = toml::to_string_pretty(&actor_for_toml)?;
-        fs::write(&toml_path, toml_content)?;
+        let mut skeleton = actor_for_toml.clone();
+        skeleton.extra.clear();
+        let mut document = canonical_document(&actor_for_toml, &skeleton, toml_edit::Table::new())?;
+        if let Ok(existing) = fs::read_to_string(&toml_path) {
+            carry_comments(&existing, &mut document);
+        }
+        fs::write(&toml_path, document.to_string())?;
 
         Ok(())
     }
@@ -141,10 +148,7 @@ impl ActorRepository for TomlActorRepository {
 
     fn load_all_personas(&self) -> Result<Vec<Persona>> {
         let actors = self.load_all_actors()?;
-        let personas = actors
-            .iter()
-            .filter_map(|actor| Persona::from_actor(actor))
-            .collect();
+        let personas = actors.iter().filter_map(Persona::from_actor).collect();
         Ok(personas)
     }
 
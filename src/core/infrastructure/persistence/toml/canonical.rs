@@ -0,0 +1,214 @@
+//! Canonical formatting for TOML data files.
+//!
+//! Data files are written in one stable layout so that saving an unchanged
+//! entity never produces a diff:
+//! - keys declared by the model keep their declaration order,
+//! - keys that come from maps (extra fields, methodology fields) follow in
+//!   alphabetical order instead of hash order,
+//! - quoting and array style are those of `toml::to_string_pretty`.
+//!
+//! When a file is rewritten, comments the user added to the previous version
+//! are carried over to the matching keys and tables.
+
+use crate::core::Result;
+use serde::Serialize;
+use toml_edit::{ArrayOfTables, Decor, DocumentMut, Item, Table};
+
+/// Serialize `value` into a canonically ordered document.
+///
+/// `skeleton` is the same entity with its map-backed fields cleared; its key
+/// order defines the declared order. Keys in `header` are written first.
+pub fn canonical_document<T: Serialize>(
+    value: &T,
+    skeleton: &T,
+    header: Table,
+) -> Result<DocumentMut> {
+    let document: DocumentMut = toml::to_string_pretty(value)?.parse()?;
+    let skeleton: DocumentMut = toml::to_string_pretty(skeleton)?.parse()?;
+
+    let mut root = header;
+    for (key, item) in sorted_items(document.as_table(), Some(skeleton.as_table())) {
+        root.insert(&key, item);
+    }
+    Ok(DocumentMut::from(root))
+}
+
+/// Copy comments from `existing` (a previous version of the file) onto `document`.
+///
+/// Unparseable previous content is ignored; the canonical document is kept as is.
+pub fn carry_comments(existing: &str, document: &mut DocumentMut) {
+    let Ok(previous) = existing.parse::<DocumentMut>() else {
+        return;
+    };
+    carry_table_comments(previous.as_table(), document.as_table_mut());
+    if has_comment(previous.trailing().as_str()) {
+        document.set_trailing(previous.trailing().clone());
+    }
+}
+
+/// Items of `table`, rebuilt recursively in canonical order
+fn sorted_items(table: &Table, skeleton: Option<&Table>) -> Vec<(String, Item)> {
+    let rank = |key: &str| {
+        skeleton
+            .and_then(|s| s.iter().position(|(k, _)| k == key))
+            .unwrap_or(usize::MAX)
+    };
+
+    let mut items: Vec<(String, Item)> = table
+        .iter()
+        .map(|(key, item)| {
+            let skeleton_item = skeleton.and_then(|s| s.get(key));
+            (key.to_string(), rebuild_item(item, skeleton_item))
+        })
+        .collect();
+    items.sort_by(|(a, _), (b, _)| rank(a).cmp(&rank(b)).then_with(|| a.cmp(b)));
+    items
+}
+
+fn rebuild_item(item: &Item, skeleton: Option<&Item>) -> Item {
+    match item {
+        Item::Table(table) => Item::Table(rebuild_table(table, skeleton.and_then(Item::as_table))),
+        Item::ArrayOfTables(array) => {
+            let skeleton_tables = skeleton.and_then(Item::as_array_of_tables);
+            let mut rebuilt = ArrayOfTables::new();
+            for (i, table) in array.iter().enumerate() {
+                let skeleton_table = skeleton_tables.and_then(|s| s.get(i));
+                rebuilt.push(rebuild_table(table, skeleton_table));
+            }
+            Item::ArrayOfTables(rebuilt)
+        }
+        other => other.clone(),
+    }
+}
+
+/// Fresh table (without source positions) holding the sorted items of `table`
+fn rebuild_table(table: &Table, skeleton: Option<&Table>) -> Table {
+    let mut rebuilt = Table::new();
+    rebuilt.set_implicit(table.is_implicit());
+    for (key, item) in sorted_items(table, skeleton) {
+        rebuilt.insert(&key, item);
+    }
+    rebuilt
+}
+
+fn carry_table_comments(previous: &Table, table: &mut Table) {
+    copy_comments(previous.decor(), table.decor_mut());
+
+    let keys: Vec<String> = table.iter().map(|(key, _)| key.to_string()).collect();
+    for key in keys {
+        let (Some(previous_key), Some(previous_item)) = (previous.key(&key), previous.get(&key))
+        else {
+            continue;
+        };
+        if let Some(mut key_mut) = table.key_mut(&key) {
+            copy_comments(previous_key.leaf_decor(), key_mut.leaf_decor_mut());
+        }
+
+        let Some(item) = table.get_mut(&key) else {
+            continue;
+        };
+        match (previous_item, item) {
+            (Item::Table(previous_table), Item::Table(table)) => {
+                carry_table_comments(previous_table, table);
+            }
+            (Item::ArrayOfTables(previous_array), Item::ArrayOfTables(array)) => {
+                for (i, table) in array.iter_mut().enumerate() {
+                    if let Some(previous_table) = matching_table(previous_array, table, i) {
+                        carry_table_comments(previous_table, table);
+                    }
+                }
+            }
+            (Item::Value(previous_value), Item::Value(value)) => {
+                copy_comments(previous_value.decor(), value.decor_mut());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Previous version of an array entry: same `id` if entries have one, otherwise same index
+fn matching_table<'a>(
+    previous: &'a ArrayOfTables,
+    table: &Table,
+    index: usize,
+) -> Option<&'a Table> {
+    match table.get("id").and_then(Item::as_str) {
+        Some(id) => previous
+            .iter()
+            .find(|t| t.get("id").and_then(Item::as_str) == Some(id)),
+        None => previous.get(index),
+    }
+}
+
+/// Copy the parts of a decor that contain comments, keeping canonical whitespace otherwise
+fn copy_comments(previous: &Decor, decor: &mut Decor) {
+    if let Some(prefix) = previous.prefix().filter(|p| has_comment(p.as_str())) {
+        decor.set_prefix(prefix.clone());
+    }
+    if let Some(suffix) = previous.suffix().filter(|s| has_comment(s.as_str())) {
+        decor.set_suffix(suffix.clone());
+    }
+}
+
+fn has_comment(raw: Option<&str>) -> bool {
+    raw.is_some_and(|raw| raw.contains('#'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Serialize, Clone)]
+    struct Entity {
+        id: String,
+        title: String,
+        #[serde(flatten)]
+        extra: HashMap<String, String>,
+    }
+
+    fn entity() -> Entity {
+        Entity {
+            id: "E-1".to_string(),
+            title: "Entity".to_string(),
+            extra: HashMap::from([
+                ("zeta".to_string(), "z".to_string()),
+                ("alpha".to_string(), "a".to_string()),
+                ("mid".to_string(), "m".to_string()),
+            ]),
+        }
+    }
+
+    fn format(entity: &Entity) -> DocumentMut {
+        let mut skeleton = entity.clone();
+        skeleton.extra.clear();
+        canonical_document(entity, &skeleton, Table::new()).unwrap()
+    }
+
+    #[test]
+    fn test_declared_keys_first_then_sorted_extras() {
+        let output = format(&entity()).to_string();
+        let keys: Vec<&str> = output
+            .lines()
+            .filter_map(|line| line.split_once(" = ").map(|(key, _)| key))
+            .collect();
+        assert_eq!(keys, vec!["id", "title", "alpha", "mid", "zeta"]);
+    }
+
+    #[test]
+    fn test_output_is_stable() {
+        assert_eq!(format(&entity()).to_string(), format(&entity()).to_string());
+    }
+
+    #[test]
+    fn test_carry_comments() {
+        let existing =
+            "# Reviewed with the product team\nid = \"E-1\"\ntitle = \"Old\" # working title\n";
+        let mut document = format(&entity());
+        carry_comments(existing, &mut document);
+
+        let output = document.to_string();
+        assert!(output.starts_with("# Reviewed with the product team\nid = \"E-1\""));
+        assert!(output.contains("title = \"Entity\" # working title"));
+    }
+}
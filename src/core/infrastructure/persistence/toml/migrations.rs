@@ -0,0 +1,115 @@
+//! Format migration system for TOML data files.
+//!
+//! Every use case TOML file records the `format_version` it was written with.
+//! When the on-disk layout of a use case changes (new required fields, renamed
+//! fields), bump `FORMAT_VERSION` and add a step to `run_migration` that rewrites
+//! the parsed document. Files are migrated on load, so existing projects keep
+//! working without manual edits.
+
+use crate::core::{MucmError, Result};
+use toml::{Table, Value};
+
+/// Current format version of TOML use case files
+pub const FORMAT_VERSION: i64 = 1;
+
+/// Top-level key holding the format version in each TOML file
+pub const FORMAT_VERSION_KEY: &str = "format_version";
+
+/// Migrator for upgrading parsed TOML use case documents.
+pub struct TomlMigrator;
+
+impl TomlMigrator {
+    /// Run all necessary migrations to bring a document up to the current version.
+    ///
+    /// The `format_version` key is removed from the table so it can be
+    /// deserialized straight into a `UseCase`.
+    ///
+    /// # Returns
+    /// `Ok(true)` if any migration ran and the file should be written back,
+    /// error if the file comes from a newer mucm or a migration fails
+    pub fn migrate(table: &mut Table) -> Result<bool> {
+        let current_version = Self::current_version(table)?;
+        if current_version > FORMAT_VERSION {
+            return Err(MucmError::Validation(format!(
+                "File format v{} is newer than this mucm supports (v{}); please upgrade mucm",
+                current_version, FORMAT_VERSION
+            )));
+        }
+
+        for version in (current_version + 1)..=FORMAT_VERSION {
+            Self::run_migration(table, version)?;
+        }
+
+        Ok(current_version < FORMAT_VERSION)
+    }
+
+    /// Take the format version out of a document.
+    ///
+    /// Returns 0 for files written before versioning was introduced.
+    fn current_version(table: &mut Table) -> Result<i64> {
+        match table.remove(FORMAT_VERSION_KEY) {
+            None => Ok(0),
+            Some(Value::Integer(version)) if version >= 0 => Ok(version),
+            Some(other) => Err(MucmError::Validation(format!(
+                "Invalid {}: {}",
+                FORMAT_VERSION_KEY, other
+            ))),
+        }
+    }
+
+    /// Run a specific migration version.
+    fn run_migration(table: &mut Table, version: i64) -> Result<()> {
+        match version {
+            1 => Self::migrate_to_v1(table),
+            _ => Err(MucmError::Validation(format!(
+                "Unknown TOML format version: {}",
+                version
+            ))),
+        }
+    }
+
+    /// Migrate to v1: make `methodology_fields` present.
+    ///
+    /// Unversioned files from before methodology fields existed have no such
+    /// table, which the model requires.
+    fn migrate_to_v1(table: &mut Table) -> Result<()> {
+        table
+            .entry("methodology_fields")
+            .or_insert_with(|| Value::Table(Table::new()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_unversioned_file() {
+        let mut table: Table = toml::from_str("id = \"UC-001\"").unwrap();
+
+        assert!(TomlMigrator::migrate(&mut table).unwrap());
+        assert!(table["methodology_fields"].is_table());
+        assert!(!table.contains_key(FORMAT_VERSION_KEY));
+    }
+
+    #[test]
+    fn test_migrate_already_up_to_date() {
+        let mut table: Table = toml::from_str(&format!(
+            "format_version = {}\n[methodology_fields]\n",
+            FORMAT_VERSION
+        ))
+        .unwrap();
+
+        assert!(!TomlMigrator::migrate(&mut table).unwrap());
+        assert!(!table.contains_key(FORMAT_VERSION_KEY));
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_format() {
+        let mut table: Table =
+            toml::from_str(&format!("format_version = {}", FORMAT_VERSION + 1)).unwrap();
+
+        assert!(TomlMigrator::migrate(&mut table).is_err());
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! This module provides TOML file-based storage for use cases and actors (personas and system actors).
 //! Each entity is stored as a separate TOML file, making it
-//! git-friendly and human-readable.
+//! git-friendly and human-readable. Use case files carry a `format_version`
+//! and are upgraded on load by the `TomlMigrator`.
 
 mod actor_repository;
+mod canonical;
+pub mod migrations;
 mod repository;
 
 pub use actor_repository::TomlActorRepository;
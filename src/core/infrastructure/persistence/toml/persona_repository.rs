@@ -1,7 +1,7 @@
 // TOML-based implementation of PersonaRepository
 use crate::config::Config;
 use crate::core::domain::{Persona, PersonaRepository};
-use anyhow::Result;
+use crate::core::Result;
 use std::fs;
 use std::path::Path;
 
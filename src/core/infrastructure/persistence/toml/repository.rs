@@ -1,23 +1,39 @@
 // TOML-based implementation of UseCaseRepository
+use super::canonical::{canonical_document, carry_comments};
+use super::migrations::{TomlMigrator, FORMAT_VERSION, FORMAT_VERSION_KEY};
 use crate::config::Config;
+use crate::core::infrastructure::persistence::backup::BackupStore;
+use crate::core::infrastructure::persistence::file_operations::FileOperations;
+use crate::core::infrastructure::persistence::load_report::{
+    LoadFailure, LoadReport, RepairAction, RepairOutcome,
+};
 use crate::core::infrastructure::persistence::traits::UseCaseRepository;
+use crate::core::infrastructure::persistence::transaction::RepositoryTransaction;
+use crate::core::MucmError;
+use crate::core::Result;
 use crate::core::{to_snake_case, UseCase};
-use anyhow::Result;
 use std::fs;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Directory under the data directory that holds files `repair` could not recover
+pub const QUARANTINE_DIR: &str = ".quarantine";
 
 /// Repository implementation that persists use cases to TOML files
 ///
 /// Architecture:
 /// - TOML files (.toml) are the source of truth - users edit these
 /// - Markdown files (.md) are generated documentation - regenerated from TOML
+/// - Previous versions of changed TOML files go into one backup snapshot per repository
 pub struct TomlUseCaseRepository {
     config: Config,
+    backups: BackupStore,
 }
 
 impl TomlUseCaseRepository {
     pub fn new(config: Config) -> Self {
-        Self { config }
+        let backups = BackupStore::new(&config);
+        Self { config, backups }
     }
 }
 
@@ -44,82 +60,425 @@ impl UseCaseRepository for TomlUseCaseRepository {
         Ok(())
     }
 
+    fn write_markdown_with_filename(
+        &self,
+        use_case: &UseCase,
+        filename: &str,
+        render: &mut dyn FnMut(&mut dyn Write) -> Result<()>,
+    ) -> Result<()> {
+        let md_dir = Path::new(&self.config.directories.use_case_dir)
+            .join(to_snake_case(&use_case.category));
+        fs::create_dir_all(&md_dir)?;
+        FileOperations::write_streamed(&md_dir.join(filename), render)
+    }
+
     fn load_all(&self) -> Result<Vec<UseCase>> {
-        let toml_dir = Path::new(&self.config.directories.data_dir);
-        let mut use_cases = Vec::new();
+        Ok(self.load_all_with_report()?.use_cases)
+    }
+
+    fn load_all_with_report(&self) -> Result<LoadReport> {
+        let mut report = LoadReport::default();
+        for path in self.data_files()? {
+            match self.load_file(&path) {
+                Ok(use_case) => report.use_cases.push(use_case),
+                Err(e) => report.failures.push(LoadFailure {
+                    path,
+                    error: format!("{:#}", e),
+                }),
+            }
+        }
+        Ok(report)
+    }
+
+    fn load_by_id(&self, id: &str) -> Result<Option<UseCase>> {
+        let all_cases = self.load_all()?;
+        Ok(all_cases.into_iter().find(|uc| uc.id == id))
+    }
+
+    fn delete(&self, id: &str) -> Result<()> {
+        let Some(use_case) = self.load_by_id(id)? else {
+            return Ok(());
+        };
 
+        let toml_path = self.toml_path(&use_case);
+        if toml_path.exists() {
+            self.backups.backup_file(&toml_path)?;
+            fs::remove_file(&toml_path)?;
+        }
+        self.remove_generated_markdown(&use_case)
+    }
+
+    fn commit(&self, transaction: RepositoryTransaction) -> Result<()> {
+        // Resolve the files of deleted use cases before anything changes
+        let existing = self.load_all()?;
+        let removed: Vec<&UseCase> = transaction
+            .deletes()
+            .iter()
+            .filter_map(|id| existing.iter().find(|uc| &uc.id == id))
+            .collect();
+
+        // Stage every save next to its target; on failure nothing has been replaced
+        let mut staged: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for use_case in transaction.saves() {
+            let target = self.toml_path(use_case);
+            let staging = target.with_file_name(format!(".{}.toml.tmp", use_case.id));
+            if let Err(e) = Self::stage_toml(use_case, &staging, &target) {
+                let _ = fs::remove_file(&staging);
+                for (staging, _) in &staged {
+                    let _ = fs::remove_file(staging);
+                }
+                return Err(e);
+            }
+            staged.push((staging, target));
+        }
+
+        // Files of deleted use cases that no save replaces
+        let deleted: Vec<PathBuf> = removed
+            .iter()
+            .map(|use_case| self.toml_path(use_case))
+            .filter(|path| path.exists() && !staged.iter().any(|(_, target)| target == path))
+            .collect();
+
+        // Commit: move every original aside, then the staged files into place.
+        // A failure part-way puts the originals back, so either every file
+        // changes or none does.
+        let mut moved: Vec<(PathBuf, Option<PathBuf>)> = Vec::new();
+        let applied = self.apply_staged(&staged, &deleted, &mut moved);
+        for (staging, _) in &staged {
+            let _ = fs::remove_file(staging);
+        }
+        if let Err(e) = applied {
+            return Err(match Self::roll_back(&moved) {
+                Ok(()) => e.context("Transaction rolled back, no use case was changed"),
+                Err(unrestored) => e.context(format!(
+                    "Transaction only partly rolled back; check {}",
+                    unrestored
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            });
+        }
+        for (_, aside) in moved {
+            if let Some(aside) = aside {
+                let _ = fs::remove_file(aside);
+            }
+        }
+        for use_case in removed {
+            self.remove_generated_markdown(use_case)?;
+        }
+
+        Ok(())
+    }
+
+    fn repair(&self) -> Result<Vec<RepairAction>> {
+        let mut actions = Vec::new();
+        for failure in self.load_all_with_report()?.failures {
+            let outcome = match self.recover(&failure.path) {
+                Ok(use_case) => {
+                    self.save_toml_only(&use_case)?;
+                    if self.toml_path(&use_case) != failure.path {
+                        self.backups.backup_file(&failure.path)?;
+                        fs::remove_file(&failure.path)?;
+                    }
+                    RepairOutcome::Recovered {
+                        use_case_id: use_case.id,
+                    }
+                }
+                Err(e) => RepairOutcome::Quarantined {
+                    to: self.quarantine(&failure.path)?,
+                    reason: format!("{:#}", e),
+                },
+            };
+            actions.push(RepairAction {
+                path: failure.path,
+                outcome,
+            });
+        }
+        Ok(actions)
+    }
+}
+
+impl TomlUseCaseRepository {
+    /// Use case TOML files in the data directory
+    ///
+    /// Hidden directories (such as the quarantine) are skipped, and only files
+    /// whose name starts with the ID prefix are considered use cases.
+    fn data_files(&self) -> Result<Vec<PathBuf>> {
+        let toml_dir = Path::new(&self.config.directories.data_dir);
         if !toml_dir.exists() {
-            return Ok(use_cases); // No use cases yet
+            return Ok(Vec::new()); // No use cases yet
         }
 
-        for entry in walkdir::WalkDir::new(toml_dir) {
+        let id_prefix = format!("{}-", self.config.project.id_prefix);
+        let mut files = Vec::new();
+        let entries = walkdir::WalkDir::new(toml_dir)
+            .into_iter()
+            .filter_entry(|entry| {
+                entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.')
+            });
+        for entry in entries {
             let entry = entry?;
-
-            // Only process .toml files that start with "UC-" (use case ID pattern)
             if entry.file_type().is_file()
                 && entry.path().extension().is_some_and(|ext| ext == "toml")
-                && entry
-                    .path()
-                    .file_name()
-                    .is_some_and(|name| name.to_string_lossy().starts_with("UC-"))
+                && entry.file_name().to_string_lossy().starts_with(&id_prefix)
             {
-                let content = fs::read_to_string(entry.path())?;
-                // Parse TOML to intermediate value, then convert to JSON value to ensure
-                // extra fields are serde_json::Value instead of toml::Value
-                let toml_value: toml::Value = toml::from_str(&content)?;
-                let json_str = serde_json::to_string(&toml_value)?;
-                let mut use_case: UseCase = serde_json::from_str(&json_str)?;
-
-                // Migration: If use case has no views, add default view
-                if use_case.views.is_empty() {
-                    use crate::core::MethodologyView;
-                    let default_methodology = &self.config.templates.default_methodology;
-                    let default_view = MethodologyView::new(default_methodology, "normal");
-                    use_case.views.push(default_view);
-
-                    // Auto-save the migrated use case
-                    self.save_toml_only(&use_case)?;
+                files.push(entry.into_path());
+            }
+        }
+        Ok(files)
+    }
+
+    /// Load one use case file, migrating and rewriting it when needed
+    fn load_file(&self, path: &Path) -> Result<UseCase> {
+        let content = fs::read_to_string(path)?;
+        let (use_case, migrated) = Self::parse(&content)?;
+        let (use_case, viewless) = self.with_default_view(use_case);
+
+        // Auto-save the migrated use case
+        if migrated || viewless {
+            self.save_toml_only(&use_case)?;
+        }
+        Ok(use_case)
+    }
+
+    /// Migration: give a use case without views the default view
+    ///
+    /// Returns the use case and whether a view was added.
+    fn with_default_view(&self, mut use_case: UseCase) -> (UseCase, bool) {
+        if !use_case.views.is_empty() {
+            return (use_case, false);
+        }
+        use crate::core::MethodologyView;
+        let default_methodology = &self.config.templates.default_methodology;
+        use_case
+            .views
+            .push(MethodologyView::new(default_methodology, "normal"));
+        (use_case, true)
+    }
+
+    /// Try to read a use case from a file that fails to load
+    ///
+    /// Handles a byte order mark, invalid UTF-8, and missing required fields
+    /// (restored from defaults, with the ID taken from the file name). Files
+    /// that are not valid TOML cannot be recovered.
+    fn recover(&self, path: &Path) -> Result<UseCase> {
+        let bytes = fs::read(path)?;
+        let content = String::from_utf8_lossy(&bytes);
+        let content = content.trim_start_matches('\u{feff}');
+
+        let use_case = match Self::parse(content) {
+            Ok((use_case, _)) => use_case,
+            Err(_) => {
+                let mut table: toml::Table = toml::from_str(content).map_err(|e| {
+                    MucmError::Validation(format!("not valid TOML: {}", e.message()))
+                })?;
+
+                let id = path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let category = path
+                    .parent()
+                    .and_then(Path::file_name)
+                    .map(|dir| dir.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "general".to_string());
+                let defaults = UseCase::new(
+                    id.clone(),
+                    id,
+                    category,
+                    String::new(),
+                    "medium".to_string(),
+                )
+                .map_err(MucmError::Validation)?;
+                let defaults: toml::Table = toml::from_str(&toml::to_string(&defaults)?)?;
+                for (key, value) in defaults {
+                    table.entry(key).or_insert(value);
                 }
 
-                use_cases.push(use_case);
+                Self::parse(&toml::to_string(&table)?)
+                    .map_err(|e| {
+                        MucmError::Validation(format!("fields could not be restored: {:#}", e))
+                    })?
+                    .0
             }
-        }
+        };
+        Ok(self.with_default_view(use_case).0)
+    }
 
-        Ok(use_cases)
+    /// Move a data file into the quarantine directory, keeping its relative path
+    ///
+    /// Returns where the file was moved to.
+    fn quarantine(&self, path: &Path) -> Result<PathBuf> {
+        let data_dir = Path::new(&self.config.directories.data_dir);
+        let relative = path
+            .strip_prefix(data_dir)
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|_| PathBuf::from(path.file_name().unwrap_or_default()));
+        let mut target = data_dir.join(QUARANTINE_DIR).join(relative);
+        if target.exists() {
+            let stamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+            let name = target.file_name().unwrap_or_default().to_string_lossy();
+            target = target.with_file_name(format!("{}.{}", name, stamp));
+        }
+        if let Some(dir) = target.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::rename(path, &target)?;
+        Ok(target)
     }
 
-    fn load_by_id(&self, id: &str) -> Result<Option<UseCase>> {
-        let all_cases = self.load_all()?;
-        Ok(all_cases.into_iter().find(|uc| uc.id == id))
+    /// Parse the contents of a use case TOML file, migrating older formats
+    ///
+    /// Returns the use case and whether a migration ran (so the file should be rewritten).
+    pub fn parse(content: &str) -> Result<(UseCase, bool)> {
+        let mut table: toml::Table = toml::from_str(content)?;
+        let migrated = TomlMigrator::migrate(&mut table)?;
+
+        // Convert to a JSON value to ensure extra fields are serde_json::Value
+        // instead of toml::Value
+        let json_str = serde_json::to_string(&table)?;
+        let use_case: UseCase = serde_json::from_str(&json_str)?;
+        Ok((use_case, migrated))
     }
-}
 
-impl TomlUseCaseRepository {
     fn save_toml_only(&self, use_case: &UseCase) -> Result<()> {
-        let category_snake = to_snake_case(&use_case.category);
+        // Create TOML directory structure (source files) and save the source of truth
+        let toml_path = self.toml_path(use_case);
+        if let Some(toml_dir) = toml_path.parent() {
+            fs::create_dir_all(toml_dir)?;
+        }
+        let existing = fs::read_to_string(&toml_path).ok();
+        let content = Self::toml_content(use_case, existing.as_deref())?;
+        if existing.as_deref() == Some(content.as_str()) {
+            return Ok(());
+        }
+        if toml_path.exists() {
+            self.backups.backup_file(&toml_path)?;
+        }
+        fs::write(&toml_path, content)?;
+
+        Ok(())
+    }
 
-        // Create TOML directory structure (source files)
-        let toml_dir = Path::new(&self.config.directories.data_dir).join(&category_snake);
-        fs::create_dir_all(&toml_dir)?;
+    /// Path of a use case's TOML data file
+    fn toml_path(&self, use_case: &UseCase) -> PathBuf {
+        Path::new(&self.config.directories.data_dir)
+            .join(to_snake_case(&use_case.category))
+            .join(format!("{}.toml", use_case.id))
+    }
 
+    /// Serialize a use case for its TOML data file
+    ///
+    /// The output is canonically formatted; comments from `existing` (the
+    /// current file content, if any) are kept.
+    fn toml_content(use_case: &UseCase, existing: Option<&str>) -> Result<String> {
         // Filter out Null values from extra fields before serialization
         // TOML doesn't support null values like JSON does
         let mut use_case_for_toml = use_case.clone();
-        use_case_for_toml.extra.retain(|_, v| !v.is_null());
+        use_case_for_toml
+            .extra
+            .retain(|k, v| !v.is_null() && k != FORMAT_VERSION_KEY);
 
-        // Save TOML file (source of truth)
-        let toml_path = toml_dir.join(format!("{}.toml", use_case.id));
-        let toml_content = toml::to_string_pretty(&use_case_for_toml)?;
-        fs::write(&toml_path, toml_content)?;
+        // Map-backed fields are cleared in the skeleton so they sort after declared fields
+        let mut skeleton = use_case_for_toml.clone();
+        skeleton.extra.clear();
+        skeleton.methodology_fields.clear();
+        for scenario in &mut skeleton.scenarios {
+            scenario.extra.clear();
+        }
+
+        let mut header = toml_edit::Table::new();
+        header.insert(FORMAT_VERSION_KEY, toml_edit::value(FORMAT_VERSION));
+        let mut document = canonical_document(&use_case_for_toml, &skeleton, header)?;
+        if let Some(existing) = existing {
+            carry_comments(existing, &mut document);
+        }
+        Ok(document.to_string())
+    }
 
+    /// Write a use case's TOML for `target` to a staging file
+    fn stage_toml(use_case: &UseCase, staging: &Path, target: &Path) -> Result<()> {
+        if let Some(dir) = staging.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let existing = fs::read_to_string(target).ok();
+        fs::write(staging, Self::toml_content(use_case, existing.as_deref())?)?;
         Ok(())
     }
 
+    /// Moves the originals of `staged` targets and the `deleted` files aside,
+    /// and the staged files into place
+    ///
+    /// Every file touched is recorded in `moved` with where its original went,
+    /// for [`Self::roll_back`].
+    fn apply_staged(
+        &self,
+        staged: &[(PathBuf, PathBuf)],
+        deleted: &[PathBuf],
+        moved: &mut Vec<(PathBuf, Option<PathBuf>)>,
+    ) -> Result<()> {
+        for (staging, target) in staged {
+            let aside = self.move_aside(target)?;
+            moved.push((target.clone(), aside));
+            fs::rename(staging, target)?;
+        }
+        for path in deleted {
+            let aside = self.move_aside(path)?;
+            moved.push((path.clone(), aside));
+        }
+        Ok(())
+    }
+
+    /// Backs up `path` and renames it to a hidden `.orig` file next to it,
+    /// returning where it went, or `None` when there is no such file
+    fn move_aside(&self, path: &Path) -> Result<Option<PathBuf>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        self.backups.backup_file(path)?;
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let aside = path.with_file_name(format!(".{}.orig", name));
+        fs::rename(path, &aside)?;
+        Ok(Some(aside))
+    }
+
+    /// Undoes [`Self::apply_staged`], newest first: originals go back in place
+    /// and files that did not exist before are removed
+    ///
+    /// Returns the paths that could not be restored.
+    fn roll_back(moved: &[(PathBuf, Option<PathBuf>)]) -> std::result::Result<(), Vec<PathBuf>> {
+        let mut unrestored = Vec::new();
+        for (path, aside) in moved.iter().rev() {
+            let restored = match aside {
+                Some(aside) => fs::rename(aside, path),
+                None if path.exists() => fs::remove_file(path),
+                None => Ok(()),
+            };
+            if restored.is_err() {
+                unrestored.push(path.clone());
+            }
+        }
+        if unrestored.is_empty() {
+            Ok(())
+        } else {
+            Err(unrestored)
+        }
+    }
+
+    /// Remove generated documents for every view ({id}.md and {id}-{view}.md, or .adoc)
+    fn remove_generated_markdown(&self, use_case: &UseCase) -> Result<()> {
+        let md_dir = Path::new(&self.config.directories.use_case_dir)
+            .join(to_snake_case(&use_case.category));
+        FileOperations::remove_generated_documents(&md_dir, &use_case.id)
+    }
+
     fn save_markdown_only(&self, use_case_id: &str, markdown_content: &str) -> Result<()> {
         // Load the use case from TOML to get category
-        let use_case = self
-            .load_by_id(use_case_id)?
-            .ok_or_else(|| anyhow::anyhow!("Use case {} not found in TOML", use_case_id))?;
+        let use_case = self.load_by_id(use_case_id)?.ok_or_else(|| {
+            MucmError::NotFound(format!("Use case {} not found in TOML", use_case_id))
+        })?;
 
         let category_snake = to_snake_case(&use_case.category);
 
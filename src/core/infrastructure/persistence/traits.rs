@@ -4,8 +4,13 @@
 //! (TOML, SQLite, etc.) must implement to ensure consistency and
 //! interchangeability.
 
+use super::load_report::{LoadReport, RepairAction};
+use super::transaction::RepositoryTransaction;
 use crate::core::domain::UseCase;
-use anyhow::Result;
+use crate::core::Result;
+use std::future::Future;
+use std::io::Write;
+use std::pin::Pin;
 
 /// Repository abstraction for use case persistence.
 ///
@@ -45,6 +50,19 @@ pub trait UseCaseRepository {
     /// - SQLite: Single query with JOINs
     fn load_all(&self) -> Result<Vec<UseCase>>;
 
+    /// Load all use cases, reporting data that fails to load instead of aborting.
+    ///
+    /// The default treats every error as fatal, so its report never has failures.
+    ///
+    /// # Returns
+    /// The loaded use cases and the data that could not be loaded
+    fn load_all_with_report(&self) -> Result<LoadReport> {
+        Ok(LoadReport {
+            use_cases: self.load_all()?,
+            failures: Vec::new(),
+        })
+    }
+
     /// Load a single use case by ID.
     ///
     /// # Arguments
@@ -54,6 +72,46 @@ pub trait UseCaseRepository {
     /// `Some(UseCase)` if found, `None` if not found, error on failure
     fn load_by_id(&self, id: &str) -> Result<Option<UseCase>>;
 
+    /// Delete a use case and its generated markdown.
+    ///
+    /// # Arguments
+    /// * `id` - The use case ID
+    ///
+    /// # Returns
+    /// `Ok(())` on success (including when the use case does not exist), error on failure
+    fn delete(&self, id: &str) -> Result<()>;
+
+    /// Apply several saves and deletes as one unit.
+    ///
+    /// Backends override this to make the change atomic: TOML stages every
+    /// file before renaming them into place, SQLite uses a single database
+    /// transaction. The default applies the changes one by one.
+    ///
+    /// # Arguments
+    /// * `transaction` - The staged changes
+    ///
+    /// # Returns
+    /// `Ok(())` once every change is stored, error if any change fails
+    fn commit(&self, transaction: RepositoryTransaction) -> Result<()> {
+        for id in transaction.deletes() {
+            self.delete(id)?;
+        }
+        for use_case in transaction.saves() {
+            self.save(use_case)?;
+        }
+        Ok(())
+    }
+
+    /// Recover data that fails to load, moving aside what cannot be recovered.
+    ///
+    /// The default has nothing to repair.
+    ///
+    /// # Returns
+    /// One action per file that failed to load
+    fn repair(&self) -> Result<Vec<RepairAction>> {
+        Ok(Vec::new())
+    }
+
     // === Markdown Generation ===
 
     /// Save generated markdown for a use case.
@@ -87,4 +145,91 @@ pub trait UseCaseRepository {
         filename: &str,
         content: &str,
     ) -> Result<()>;
+
+    /// Stream markdown into the file with a specific filename.
+    ///
+    /// `render` writes the document to the given writer, so large documents
+    /// never have to be held in memory. The default implementation buffers the
+    /// output and delegates to `save_markdown_with_filename`; file-based
+    /// backends override it to write directly.
+    ///
+    /// # Arguments
+    /// * `use_case` - The use case (for category/path resolution)
+    /// * `filename` - The full filename (e.g., "UC-001-feat-s.md")
+    /// * `render` - Writes the markdown content
+    ///
+    /// # Returns
+    /// `Ok(())` on success, error if rendering or the write fails
+    fn write_markdown_with_filename(
+        &self,
+        use_case: &UseCase,
+        filename: &str,
+        render: &mut dyn FnMut(&mut dyn Write) -> Result<()>,
+    ) -> Result<()> {
+        let mut buffer = Vec::new();
+        render(&mut buffer)?;
+        self.save_markdown_with_filename(use_case, filename, &String::from_utf8(buffer)?)
+    }
+}
+
+/// Boxed future returned by [`AsyncUseCaseRepository`] methods.
+pub type RepositoryFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// Asynchronous counterpart of [`UseCaseRepository`].
+///
+/// Remote backends (HTTP, Postgres, ...) implement this trait so they never
+/// block a thread while waiting on the network. Futures are boxed so the trait
+/// stays object safe and can be stored as `Box<dyn AsyncUseCaseRepository>`.
+///
+/// No backend shipped with mucm implements it natively: the local backends are
+/// exposed through [`SyncRepositoryAdapter`](super::SyncRepositoryAdapter),
+/// whose futures block. [`AsyncUseCaseCoordinator`](crate::core::AsyncUseCaseCoordinator)
+/// awaits the repository; synchronous callers such as
+/// [`UseCaseCoordinator`](crate::core::UseCaseCoordinator) go through
+/// [`BlockingRepository`](super::BlockingRepository).
+pub trait AsyncUseCaseRepository: Send + Sync {
+    /// Save a use case (insert if new, update if exists).
+    fn save<'a>(&'a self, use_case: &'a UseCase) -> RepositoryFuture<'a, ()>;
+
+    /// Load all use cases from storage.
+    fn load_all(&self) -> RepositoryFuture<'_, Vec<UseCase>>;
+
+    /// Load all use cases, reporting data that fails to load instead of aborting.
+    fn load_all_with_report(&self) -> RepositoryFuture<'_, LoadReport> {
+        Box::pin(async move {
+            Ok(LoadReport {
+                use_cases: self.load_all().await?,
+                failures: Vec::new(),
+            })
+        })
+    }
+
+    /// Load a single use case by ID.
+    fn load_by_id<'a>(&'a self, id: &'a str) -> RepositoryFuture<'a, Option<UseCase>>;
+
+    /// Delete a use case and its generated markdown.
+    fn delete<'a>(&'a self, id: &'a str) -> RepositoryFuture<'a, ()>;
+
+    /// Apply several saves and deletes as one unit.
+    fn commit(&self, transaction: RepositoryTransaction) -> RepositoryFuture<'_, ()>;
+
+    /// Recover data that fails to load, moving aside what cannot be recovered.
+    fn repair(&self) -> RepositoryFuture<'_, Vec<RepairAction>> {
+        Box::pin(async { Ok(Vec::new()) })
+    }
+
+    /// Save generated markdown for a use case.
+    fn save_markdown<'a>(
+        &'a self,
+        use_case_id: &'a str,
+        content: &'a str,
+    ) -> RepositoryFuture<'a, ()>;
+
+    /// Save markdown file with a specific filename.
+    fn save_markdown_with_filename<'a>(
+        &'a self,
+        use_case: &'a UseCase,
+        filename: &'a str,
+        content: &'a str,
+    ) -> RepositoryFuture<'a, ()>;
 }
@@ -0,0 +1,82 @@
+//! Staged multi-entity changes committed in one step.
+//!
+//! Operations such as split, merge, import, and project rename touch several
+//! use cases. They collect their changes in a [`RepositoryTransaction`] and
+//! hand it to [`UseCaseRepository::commit`](super::UseCaseRepository::commit),
+//! so a failure part-way leaves the stored data untouched.
+
+use crate::core::domain::UseCase;
+
+/// Saves and deletes to apply together.
+///
+/// Deletes are applied before saves, so deleting and saving the same ID
+/// replaces the use case (e.g. when its category changes).
+#[derive(Debug, Default, Clone)]
+pub struct RepositoryTransaction {
+    saves: Vec<UseCase>,
+    deletes: Vec<String>,
+}
+
+impl RepositoryTransaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stage a use case to be saved; a later save of the same ID replaces it
+    pub fn save(&mut self, use_case: UseCase) {
+        self.saves.retain(|uc| uc.id != use_case.id);
+        self.saves.push(use_case);
+    }
+
+    /// Stage a use case to be deleted
+    pub fn delete(&mut self, id: impl Into<String>) {
+        let id = id.into();
+        if !self.deletes.contains(&id) {
+            self.deletes.push(id);
+        }
+    }
+
+    /// Staged saves, in staging order
+    pub fn saves(&self) -> &[UseCase] {
+        &self.saves
+    }
+
+    /// Staged deletes, in staging order
+    pub fn deletes(&self) -> &[String] {
+        &self.deletes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.saves.is_empty() && self.deletes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Priority;
+
+    fn use_case(id: &str, title: &str) -> UseCase {
+        UseCase::builder(id, title)
+            .category("Auth")
+            .priority(Priority::Low)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_transaction_keeps_last_save_per_id() {
+        let mut transaction = RepositoryTransaction::new();
+        assert!(transaction.is_empty());
+
+        transaction.save(use_case("UC-AUT-001", "Login"));
+        transaction.save(use_case("UC-AUT-002", "Logout"));
+        transaction.save(use_case("UC-AUT-001", "Sign in"));
+        transaction.delete("UC-AUT-003");
+        transaction.delete("UC-AUT-003");
+
+        assert_eq!(transaction.saves().len(), 2);
+        assert_eq!(transaction.saves()[1].title, "Sign in");
+        assert_eq!(transaction.deletes(), ["UC-AUT-003".to_string()]);
+    }
+}
@@ -1,11 +1,25 @@
-use anyhow::{Context, Result};
+use crate::core::MucmError;
+use crate::core::{Context, Result};
 use handlebars::Handlebars;
 use serde_json::Value;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
+use super::sandbox::{check_partial_depth, RenderGuard};
+use crate::config::RenderLimitsConfig;
+use crate::core::utils::{span, LineEndingWriter};
+
+/// A template file and whether it is the project's own copy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateFile {
+    pub path: PathBuf,
+    /// True for files under `.config/.mucm/template-assets`, false for `source-templates`
+    pub project_override: bool,
+}
+
 #[derive(Debug)]
 pub struct TemplateEngine {
     handlebars: RefCell<Handlebars<'static>>,
@@ -13,21 +27,41 @@ pub struct TemplateEngine {
     /// TODO: Use this when implementing test file generation feature
     test_templates: HashMap<String, String>,
     methodologies: Vec<String>,
+    /// Names of the named overview templates (`overviews/<name>.hbs`)
+    overviews: Vec<String>,
+    /// Line terminator of rendered output (see `generation.line_endings`)
+    line_ending: &'static str,
+    /// Project root templates are loaded from (empty for the current directory)
+    root: PathBuf,
+    /// Source of every registered template and partial, for the partial depth check
+    sources: RefCell<HashMap<String, String>>,
+    /// Limits enforced on every render (see `render_limits`)
+    limits: RenderLimitsConfig,
+    /// Framework variant of the test language (see `generation.test_framework`)
+    test_framework: Option<String>,
 }
 
 impl TemplateEngine {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_root(Path::new(""))
+    }
+
+    /// Load templates of the project at `root`
+    fn from_root(root: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let _span = span(|| "compile templates".to_string());
         let mut handlebars = Handlebars::new();
+        let mut sources = HashMap::new();
 
         // Register custom helpers for actor and persona support
         super::helpers::register_helpers(&mut handlebars);
 
         // First try to load templates from user's config directory
         // Then fall back to source-templates if not found
-        let user_templates_path = Path::new(".config/.mucm")
+        let user_templates_path = root
+            .join(crate::config::Config::CONFIG_DIR)
             .join(crate::config::Config::TEMPLATES_DIR)
             .join("methodologies");
-        let source_templates_path = Path::new("source-templates/methodologies").to_path_buf();
+        let source_templates_path = root.join("source-templates/methodologies");
 
         let methodologies_path = if user_templates_path.exists() {
             &user_templates_path
@@ -63,7 +97,9 @@ impl TemplateEngine {
                                             .unwrap_or(filename);
 
                                         let template = fs::read_to_string(&template_path)?;
-                                        handlebars.register_template_string(
+                                        register(
+                                            &mut handlebars,
+                                            &mut sources,
                                             &format!("{}-{}", methodology_name, level_name),
                                             template,
                                         )?;
@@ -85,11 +121,11 @@ impl TemplateEngine {
         {
             user_templates_path.parent().unwrap().join("overview.hbs") // .config/.mucm/{TEMPLATES_DIR}/overview.hbs
         } else {
-            Path::new("source-templates/overview.hbs").to_path_buf()
+            root.join("source-templates/overview.hbs")
         };
         if overview_path.exists() {
-            let template = fs::read_to_string(overview_path)?;
-            handlebars.register_template_string("overview", template)?;
+            let template = fs::read_to_string(&overview_path)?;
+            register(&mut handlebars, &mut sources, "overview", template)?;
         } else {
             // If no overview template found, register a default one
             let default_overview_template = r#"# {{project_name}} - Use Cases Overview
@@ -104,12 +140,161 @@ Total Use Cases: {{total_use_cases}}
 ### {{category_name}}
 
 {{#each use_cases}}
-- **[{{id}}]** {{title}} - Priority: {{priority}}, Status: {{aggregated_status}}
+- {{#if link}}[{{id}}]({{link}}){{else}}**[{{id}}]**{{/if}} {{title}} - Priority: {{priority}}, Status: {{aggregated_status}}
+{{/each}}
+
+{{/each}}
+"#;
+            register(
+                &mut handlebars,
+                &mut sources,
+                "overview",
+                default_overview_template,
+            )?;
+        }
+
+        // Register the index page used when the overview is sharded per category
+        let overview_index_path = overview_path.with_file_name("overview-index.hbs");
+        if overview_index_path.exists() {
+            let template = fs::read_to_string(overview_index_path)?;
+            register(&mut handlebars, &mut sources, "overview_index", template)?;
+        } else {
+            let default_overview_index_template = r#"# {{project_name}} - Use Cases Overview
+
+Generated on {{generated_date}}
+
+Total Use Cases: {{total_use_cases}}
+
+## Categories
+
+{{#each categories}}
+- [{{category_name}}]({{page}}) ({{use_case_count}} use cases)
+{{/each}}
+"#;
+            register(
+                &mut handlebars,
+                &mut sources,
+                "overview_index",
+                default_overview_index_template,
+            )?;
+        }
+
+        // Register the actor page template (actor.hbs), next to the overview
+        let actor_path = overview_path.with_file_name("actor.hbs");
+        if actor_path.exists() {
+            let template = fs::read_to_string(actor_path)?;
+            register(&mut handlebars, &mut sources, "actor", template)?;
+        } else {
+            let default_actor_template = r#"# {{emoji}} {{name}}
+
+**ID:** `{{id}}`
+**Type:** {{actor_type}}
+{{#if parent}}
+**Parent:** [{{parent.emoji}} {{parent.name}}]({{parent.page}})
+{{/if}}
+
+## Use Cases
+- **Direct:** {{use_case_count}}
+- **Including sub-actors:** {{rollup_count}}
+{{#each children}}
+- [{{emoji}} {{name}}]({{page}}): {{rollup_count}}
 {{/each}}
+"#;
+            register(
+                &mut handlebars,
+                &mut sources,
+                "actor",
+                default_actor_template,
+            )?;
+        }
 
+        // Register the page layout of the HTML export (html-page.hbs)
+        let html_page_path = overview_path.with_file_name("html-page.hbs");
+        if html_page_path.exists() {
+            let template = fs::read_to_string(html_page_path)?;
+            register(&mut handlebars, &mut sources, "html_page", template)?;
+        } else {
+            let default_html_page_template = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{{title}} - {{project_name}}</title>
+</head>
+<body>
+<nav>
+<ul>
+{{#each nav}}
+<li><a href="{{href}}">{{name}}</a></li>
+{{/each}}
+</ul>
+</nav>
+<main>
+{{{content}}}
+</main>
+</body>
+</html>
+"#;
+            register(
+                &mut handlebars,
+                &mut sources,
+                "html_page",
+                default_html_page_template,
+            )?;
+        }
+
+        // Register the document layout of the PDF export (pdf-document.hbs)
+        let pdf_document_path = overview_path.with_file_name("pdf-document.hbs");
+        if pdf_document_path.exists() {
+            let template = fs::read_to_string(pdf_document_path)?;
+            register(&mut handlebars, &mut sources, "pdf_document", template)?;
+        } else {
+            let default_pdf_document_template = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{{title}} - {{project_name}}</title>
+</head>
+<body>
+<h1>{{title}}</h1>
+<p>{{project_name}} - {{date}}</p>
+{{#each use_cases}}
+<section style="page-break-before: always">
+{{{content}}}
+</section>
 {{/each}}
+</body>
+</html>
 "#;
-            handlebars.register_template_string("overview", default_overview_template)?;
+            register(
+                &mut handlebars,
+                &mut sources,
+                "pdf_document",
+                default_pdf_document_template,
+            )?;
+        }
+
+        // Register named overview templates (overviews/<name>.hbs), rendered on demand
+        // by `mucm regenerate --overview <name>`
+        let mut overviews = Vec::new();
+        let overviews_dir = overview_path.with_file_name("overviews");
+        if overviews_dir.is_dir() {
+            for entry in fs::read_dir(&overviews_dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("hbs") {
+                    continue;
+                }
+                if let Some(name) = path.file_stem().and_then(|n| n.to_str()) {
+                    let template = fs::read_to_string(&path)?;
+                    register(
+                        &mut handlebars,
+                        &mut sources,
+                        &named_overview_template(name),
+                        template,
+                    )?;
+                    overviews.push(name.to_string());
+                }
+            }
+            overviews.sort();
         }
 
         // Register language test templates using LanguageRegistry
@@ -126,11 +311,30 @@ Total Use Cases: {{total_use_cases}}
                         for language_name in language_registry.available_languages() {
                             if let Some(language) = language_registry.get(&language_name) {
                                 let template_name = format!("{}_test", language.name());
-                                handlebars.register_template_string(
+                                register(
+                                    &mut handlebars,
+                                    &mut sources,
                                     &template_name,
                                     language.test_template(),
                                 )?;
                                 test_templates.insert(language.name().to_string(), template_name);
+                                for (variant, _) in language.variants() {
+                                    let Some(template) = language.variant_template(variant) else {
+                                        continue;
+                                    };
+                                    let template_name =
+                                        format!("{}_{}_test", language.name(), variant);
+                                    register(
+                                        &mut handlebars,
+                                        &mut sources,
+                                        &template_name,
+                                        template,
+                                    )?;
+                                    test_templates.insert(
+                                        format!("{}/{}", language.name(), variant),
+                                        template_name,
+                                    );
+                                }
                             }
                         }
                     }
@@ -166,7 +370,12 @@ Generated at: {{generated_at}}
 
             for lang in default_languages {
                 let template_name = format!("{}_test", lang);
-                handlebars.register_template_string(&template_name, default_test_template)?;
+                register(
+                    &mut handlebars,
+                    &mut sources,
+                    &template_name,
+                    default_test_template,
+                )?;
                 test_templates.insert(lang.to_string(), template_name);
             }
         }
@@ -200,15 +409,21 @@ Generated at: {{generated_at}}
 "#;
 
             for methodology in &methodologies {
-                handlebars.register_template_string(
+                register(
+                    &mut handlebars,
+                    &mut sources,
                     &format!("{}-simple", methodology),
                     default_template,
                 )?;
-                handlebars.register_template_string(
+                register(
+                    &mut handlebars,
+                    &mut sources,
                     &format!("{}-normal", methodology),
                     default_template,
                 )?;
-                handlebars.register_template_string(
+                register(
+                    &mut handlebars,
+                    &mut sources,
                     &format!("{}-detailed", methodology),
                     default_template,
                 )?;
@@ -219,43 +434,175 @@ Generated at: {{generated_at}}
             handlebars: RefCell::new(handlebars),
             test_templates,
             methodologies,
+            overviews,
+            line_ending: crate::config::LineEnding::default().as_str(),
+            root: root.to_path_buf(),
+            sources: RefCell::new(sources),
+            limits: RenderLimitsConfig::default(),
+            test_framework: None,
         })
     }
 
-    pub fn with_config(_config: Option<&crate::config::Config>) -> Self {
-        Self::new().unwrap()
+    pub fn with_config(config: Option<&crate::config::Config>) -> Self {
+        let mut engine = Self::new().unwrap();
+        if let Some(config) = config {
+            engine.apply_config(config);
+        }
+        engine
+    }
+
+    /// Engine for the project described by `context`, independent of the working directory
+    pub fn for_project(context: &crate::config::ProjectContext) -> Self {
+        let mut engine = Self::from_root(context.root()).unwrap();
+        engine.apply_config(context.config());
+        engine
+    }
+
+    fn apply_config(&mut self, config: &crate::config::Config) {
+        self.line_ending = config.generation.line_endings.as_str();
+        self.limits = config.render_limits.clone();
+        self.test_framework = config.generation.test_framework.clone();
+        super::helpers::register_icon_helper(
+            &mut self.handlebars.borrow_mut(),
+            config.presentation.icon_theme,
+        );
     }
 
     pub fn render_overview(&self, data: &HashMap<String, Value>) -> Result<String> {
-        self.handlebars
-            .borrow()
-            .render("overview", data)
+        self.render_to_string("overview", data)
             .context("Failed to render overview template")
     }
 
+    /// Render the overview into `writer` without building the page in memory
+    pub fn render_overview_to(
+        &self,
+        data: &HashMap<String, Value>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.render_guarded("overview", data, writer)
+            .context("Failed to render overview template")
+    }
+
+    /// Render the index page of a sharded overview
+    pub fn render_overview_index(&self, data: &HashMap<String, Value>) -> Result<String> {
+        self.render_to_string("overview_index", data)
+            .context("Failed to render overview index template")
+    }
+
+    /// Render the index page of a sharded overview into `writer`
+    pub fn render_overview_index_to(
+        &self,
+        data: &HashMap<String, Value>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.render_guarded("overview_index", data, writer)
+            .context("Failed to render overview index template")
+    }
+
+    /// Render the page of an actor (persona or system actor) into `writer`
+    pub fn render_actor_to(
+        &self,
+        data: &HashMap<String, Value>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.render_guarded("actor", data, writer)
+            .context("Failed to render actor template")
+    }
+
+    /// Render a page of the HTML export into `writer`
+    pub fn render_html_page_to(
+        &self,
+        data: &HashMap<String, Value>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.render_guarded("html_page", data, writer)
+            .context("Failed to render HTML page template")
+    }
+
+    /// Render the HTML document of the PDF export into `writer`
+    pub fn render_pdf_document_to(
+        &self,
+        data: &HashMap<String, Value>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.render_guarded("pdf_document", data, writer)
+            .context("Failed to render PDF document template")
+    }
+
+    /// Names of the named overview templates, sorted
+    pub fn overview_names(&self) -> &[String] {
+        &self.overviews
+    }
+
+    /// Fail with the available names unless `name` is a named overview template
+    pub fn check_overview_name(&self, name: &str) -> Result<()> {
+        if !self.overviews.iter().any(|overview| overview == name) {
+            let available = if self.overviews.is_empty() {
+                "none; add templates to template-assets/overviews/".to_string()
+            } else {
+                self.overviews.join(", ")
+            };
+            return Err(MucmError::NotFound(format!(
+                "Overview template '{}' not found (available: {})",
+                name, available
+            )));
+        }
+        Ok(())
+    }
+
+    /// Render the named overview template `name` into `writer`
+    pub fn render_named_overview_to(
+        &self,
+        name: &str,
+        data: &HashMap<String, Value>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.check_overview_name(name)?;
+        self.render_guarded(&named_overview_template(name), data, writer)
+            .with_context(|| format!("Failed to render overview template '{}'", name))
+    }
+
     /// Render use case with specific template
     pub fn render_use_case_with_template(
         &self,
         template_name: &str,
         data: &HashMap<String, Value>,
     ) -> Result<String> {
-        self.handlebars
-            .borrow()
-            .render(template_name, data)
+        let mut buffer = Vec::new();
+        self.render_use_case_with_template_to(template_name, data, &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Render use case with specific template into `writer`
+    pub fn render_use_case_with_template_to(
+        &self,
+        template_name: &str,
+        data: &HashMap<String, Value>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        self.render_guarded(template_name, data, writer)
             .with_context(|| format!("Failed to render use case with template: {}", template_name))
     }
 
     /// Render test file for a specific language
     pub fn render_test(&self, language: &str, data: &HashMap<String, Value>) -> Result<String> {
         let language_lower = language.to_lowercase();
-        let template_key = self
-            .test_templates
-            .get(&language_lower)
-            .ok_or_else(|| anyhow::anyhow!("Unsupported language: {}", language))?;
+        let template_key = match &self.test_framework {
+            Some(framework) => {
+                let key = format!("{}/{}", language_lower, framework);
+                self.test_templates.get(&key).ok_or_else(|| {
+                    MucmError::Validation(format!(
+                        "Language '{}' has no test framework '{}' (generation.test_framework)",
+                        language, framework
+                    ))
+                })?
+            }
+            None => self.test_templates.get(&language_lower).ok_or_else(|| {
+                MucmError::Validation(format!("Unsupported language: {}", language))
+            })?,
+        };
 
-        self.handlebars
-            .borrow()
-            .render(template_key, data)
+        self.render_to_string(template_key, data)
             .with_context(|| format!("Failed to render {} test template", language))
     }
 
@@ -278,6 +625,22 @@ Generated at: {{generated_at}}
         methodology: &str,
         level: &str,
     ) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.render_use_case_with_methodology_and_level_to(data, methodology, level, &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Render a use case with specific methodology and level into `writer`
+    ///
+    /// Output goes straight to the writer, so detailed documents are never held
+    /// in memory as a whole.
+    pub fn render_use_case_with_methodology_and_level_to(
+        &self,
+        data: &HashMap<String, Value>,
+        methodology: &str,
+        level: &str,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
         let template_name = format!("{}-{}", methodology, level);
         if self
             .handlebars
@@ -285,17 +648,75 @@ Generated at: {{generated_at}}
             .get_template(&template_name)
             .is_none()
         {
-            anyhow::bail!(
-                "Invalid source-templates: Methodology '{}' does not have a valid uc_{}.hbs template. \
+            return Err(MucmError::Template(format!("Invalid source-templates: Methodology '{}' does not have a valid uc_{}.hbs template. \
                 Check source-templates/methodologies/{}/uc_{}.hbs exists and is valid.",
-                methodology, level, methodology, level
-            );
+                methodology, level, methodology, level)));
         }
 
         // Load scenario template for this level and register as partial
         self.register_scenario_partial_for_level(methodology, level)?;
 
-        self.render_use_case_with_template(&template_name, data)
+        self.render_use_case_with_template_to(&template_name, data, writer)
+    }
+
+    /// Render a use case with the template `source` in place of the template of a level
+    ///
+    /// The source is compiled under a scratch name, rendered with the scenario
+    /// partial of the level and the usual render limits, and dropped again, so
+    /// a template can be tried before it is saved.
+    pub fn render_use_case_source(
+        &self,
+        source: &str,
+        data: &HashMap<String, Value>,
+        methodology: &str,
+        level: &str,
+    ) -> Result<String> {
+        let template_name = format!("{}-{}-preview", methodology, level);
+        self.handlebars
+            .borrow_mut()
+            .register_template_string(&template_name, source)
+            .map_err(|e| MucmError::Template(e.to_string()))?;
+        self.sources
+            .borrow_mut()
+            .insert(template_name.clone(), source.to_string());
+
+        let mut buffer = Vec::new();
+        let result = self
+            .register_scenario_partial_for_level(methodology, level)
+            .and_then(|_| self.render_guarded(&template_name, data, &mut buffer));
+        self.handlebars
+            .borrow_mut()
+            .unregister_template(&template_name);
+        self.sources.borrow_mut().remove(&template_name);
+        result?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Render a use case with the template of a level written for another
+    /// output format (`uc_<level>.<extension>.hbs`), if the methodology has one
+    ///
+    /// Returns false, without writing anything, when there is no such template.
+    pub fn render_use_case_variant_to(
+        &self,
+        data: &HashMap<String, Value>,
+        methodology: &str,
+        level: &str,
+        extension: &str,
+        writer: &mut dyn Write,
+    ) -> Result<bool> {
+        let template_name = format!("{}-{}.{}", methodology, level, extension);
+        if self
+            .handlebars
+            .borrow()
+            .get_template(&template_name)
+            .is_none()
+        {
+            return Ok(false);
+        }
+
+        self.register_scenario_partial_for_level(methodology, level)?;
+        self.render_use_case_with_template_to(&template_name, data, writer)?;
+        Ok(true)
     }
 
     /// Resolve the path to a scenario template based on the template path specification
@@ -329,16 +750,8 @@ Generated at: {{generated_at}}
     fn register_scenario_partial_for_level(&self, methodology: &str, level: &str) -> Result<()> {
         use super::super::methodologies::MethodologyDefinition;
 
-        // Determine templates directory
-        let user_templates_path =
-            Path::new(".config/.mucm").join(crate::config::Config::TEMPLATES_DIR);
-        let source_templates_path = Path::new("source-templates").to_path_buf();
-
-        let templates_dir = if user_templates_path.exists() {
-            &user_templates_path
-        } else {
-            &source_templates_path
-        };
+        let (templates_dir, _) = self.templates_dir();
+        let templates_dir = &templates_dir;
 
         // Load methodology definition to get scenario_template config
         let methodology_dir = templates_dir.join("methodologies").join(methodology);
@@ -387,8 +800,11 @@ Generated at: {{generated_at}}
 
             self.handlebars
                 .borrow_mut()
-                .register_partial("scenario", content)
+                .register_partial("scenario", &content)
                 .context("Failed to register scenario partial")?;
+            self.sources
+                .borrow_mut()
+                .insert("scenario".to_string(), content);
         } else {
             // Scenario template not found - this is optional, so just warn
             eprintln!("Warning: Scenario template not found at {:?}", full_path);
@@ -397,10 +813,133 @@ Generated at: {{generated_at}}
         Ok(())
     }
 
+    /// Render a template into `writer` within the configured render limits.
+    ///
+    /// Output is converted to the configured line endings on the way out.
+    fn render_guarded(
+        &self,
+        template_name: &str,
+        data: &HashMap<String, Value>,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        let _span = span(|| format!("render {}", template_name));
+        check_partial_depth(
+            template_name,
+            &self.sources.borrow(),
+            self.limits.max_partial_depth,
+        )?;
+
+        let mut guard = RenderGuard::new(writer, &self.limits);
+        let result = self.handlebars.borrow().render_to_write(
+            template_name,
+            data,
+            LineEndingWriter::new(&mut guard, self.line_ending),
+        );
+        if let Some(violation) = guard.violation() {
+            return Err(MucmError::Template(format!(
+                "Template '{}' stopped: {}",
+                template_name, violation
+            )));
+        }
+        Ok(result?)
+    }
+
+    /// Render a template into a string within the configured render limits
+    fn render_to_string(
+        &self,
+        template_name: &str,
+        data: &HashMap<String, Value>,
+    ) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.render_guarded(template_name, data, &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
     /// Get available methodologies
     pub fn available_methodologies(&self) -> Vec<String> {
         self.methodologies.clone()
     }
+
+    /// Directory templates are read from, and whether it is the project's own copy
+    fn templates_dir(&self) -> (PathBuf, bool) {
+        let user_templates_path = self
+            .root
+            .join(crate::config::Config::CONFIG_DIR)
+            .join(crate::config::Config::TEMPLATES_DIR);
+        if user_templates_path.exists() {
+            (user_templates_path, true)
+        } else {
+            (self.root.join("source-templates"), false)
+        }
+    }
+
+    /// File the use case template of a methodology level is loaded from
+    pub fn use_case_template_file(&self, methodology: &str, level: &str) -> TemplateFile {
+        let (templates_dir, project_override) = self.templates_dir();
+        TemplateFile {
+            path: templates_dir
+                .join("methodologies")
+                .join(methodology)
+                .join(format!("uc_{}.hbs", level)),
+            project_override,
+        }
+    }
+
+    /// File the scenario partial of a methodology level is loaded from
+    ///
+    /// Returns None when the methodology definition or level cannot be read.
+    pub fn scenario_template_file(&self, methodology: &str, level: &str) -> Option<TemplateFile> {
+        use super::super::methodologies::MethodologyDefinition;
+
+        let (templates_dir, project_override) = self.templates_dir();
+        let definition =
+            MethodologyDefinition::from_toml(templates_dir.join("methodologies").join(methodology))
+                .ok()?;
+        let configured = definition
+            .level_configs
+            .get(level)?
+            .scenario_template
+            .as_deref()
+            .unwrap_or("scenarios/scenario.hbs");
+        Some(TemplateFile {
+            path: Self::resolve_scenario_template_path(&templates_dir, methodology, configured),
+            project_override,
+        })
+    }
+
+    /// File the overview (or, with `index`, the sharded overview index) is loaded from
+    ///
+    /// Returns None when the built-in default template is used.
+    pub fn overview_template_file(&self, index: bool) -> Option<TemplateFile> {
+        let (templates_dir, project_override) = self.templates_dir();
+        let name = if index {
+            "overview-index.hbs"
+        } else {
+            "overview.hbs"
+        };
+        let path = templates_dir.join(name);
+        path.exists().then_some(TemplateFile {
+            path,
+            project_override,
+        })
+    }
+}
+
+/// Register a template and remember its source for the partial depth check
+fn register(
+    handlebars: &mut Handlebars<'static>,
+    sources: &mut HashMap<String, String>,
+    name: &str,
+    source: impl AsRef<str>,
+) -> Result<(), handlebars::TemplateError> {
+    handlebars.register_template_string(name, source.as_ref())?;
+    sources.insert(name.to_string(), source.as_ref().to_string());
+    Ok(())
+}
+
+/// Handlebars name of the named overview template `name`
+fn named_overview_template(name: &str) -> String {
+    format!("overview-{}", name)
 }
 
 impl Default for TemplateEngine {
@@ -1,15 +1,157 @@
 use handlebars::{
-    Context, Handlebars, Helper, HelperResult, Output, RenderContext, RenderError,
-    RenderErrorReason,
+    handlebars_helper, Context, Handlebars, Helper, HelperDef, HelperResult, JsonRender, Output,
+    RenderContext, RenderError, RenderErrorReason,
 };
 use serde_json::Value;
 use std::collections::HashSet;
 
+use crate::config::IconTheme;
+use crate::core::utils::markdown_table;
+use crate::core::{Actor, Status};
+use crate::presentation::{actor_icon, status_icon};
+
 /// Register all custom Handlebars helpers for actor and persona support
 pub fn register_helpers(handlebars: &mut Handlebars) {
     handlebars.register_helper("unique_actors", Box::new(unique_actors_helper));
     handlebars.register_helper("has_personas", Box::new(has_personas_helper));
     handlebars.register_helper("unique_personas", Box::new(unique_personas_helper));
+    handlebars.register_helper("category_name", Box::new(category_name_helper));
+    handlebars.register_helper("is_array", Box::new(is_array));
+    handlebars.register_helper("is_object", Box::new(is_object));
+    handlebars.register_helper("json", Box::new(json));
+    handlebars.register_helper("markdown_table", Box::new(markdown_table_helper));
+    register_icon_helper(handlebars, IconTheme::default());
+}
+
+// Type checks and pretty-printed JSON for free-form fields, such as the extra
+// fields of actor pages
+// Usage: {{#if (is_array value)}}...{{/if}}, {{{json value}}}
+handlebars_helper!(is_array: |value: Value| value.is_array());
+handlebars_helper!(is_object: |value: Value| value.is_object());
+handlebars_helper!(json: |value: Value| serde_json::to_string_pretty(&value).unwrap_or_default());
+
+/// Helper to render the rows of a `table` field as a markdown table
+/// Usage: {{{markdown_table stakeholders "Stakeholder" "Interest"}}}
+///
+/// The column names after the rows set the column order; without them the
+/// keys of the rows are used. Writes nothing when there are no rows.
+fn markdown_table_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let Some(rows) = h.param(0).and_then(|p| p.value().as_array()) else {
+        return Ok(());
+    };
+    let columns: Vec<String> = h
+        .params()
+        .iter()
+        .skip(1)
+        .map(|p| p.value().render())
+        .collect();
+    out.write(&markdown_table(&columns, rows))?;
+    Ok(())
+}
+
+/// Register (or replace) the `status_icon` and `actor_emoji` helpers for the given icon theme
+pub fn register_icon_helper(handlebars: &mut Handlebars, theme: IconTheme) {
+    handlebars.register_helper("status_icon", Box::new(StatusIconHelper { theme }));
+    handlebars.register_helper("actor_emoji", Box::new(ActorIconHelper { theme }));
+}
+
+/// Helper to render the icon for a status in the configured icon theme
+/// Usage: {{status_icon status}} (accepts "in_progress" or "IN_PROGRESS")
+///
+/// Writes nothing for unknown statuses or when the theme is "none".
+struct StatusIconHelper {
+    theme: IconTheme,
+}
+
+impl HelperDef for StatusIconHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let status = h.param(0).and_then(|p| p.value().as_str()).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::Other(
+                "status_icon requires a status parameter".to_string(),
+            ))
+        })?;
+
+        if let Ok(status) = Status::from_str(status) {
+            out.write(status_icon(status, self.theme))?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper to render the icon for a scenario actor in the configured icon theme
+/// Usage: {{actor_emoji actor}} (accepts "User", "System", "@persona-id", ...)
+///
+/// Writes nothing without an actor or when the theme has no actor icons.
+struct ActorIconHelper {
+    theme: IconTheme,
+}
+
+impl HelperDef for ActorIconHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        if let Some(actor) = h.param(0).and_then(|p| p.value().as_str()) {
+            out.write(actor_icon(&Actor::from(actor), self.theme))?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper to resolve a category code, or the code of a use case ID, to the
+/// full category name
+/// Usage: {{category_name "AUT"}} or {{category_name id}} -> "Authentication"
+///
+/// Codes are looked up in the root `category_codes` data; unknown codes are
+/// written unchanged. Without a parameter `{{category_name}}` renders the
+/// `category_name` value of the current context, as the overview templates do.
+fn category_name_helper(
+    h: &Helper,
+    registry: &Handlebars,
+    ctx: &Context,
+    rc: &mut RenderContext,
+    out: &mut dyn Output,
+) -> HelperResult {
+    let escape = registry.get_escape_fn();
+
+    let Some(param) = h.param(0) else {
+        let value = rc.evaluate(ctx, "category_name")?;
+        out.write(&escape(&value.as_json().render()))?;
+        return Ok(());
+    };
+
+    let input = param.value().render();
+    // "UC-AUT-001" -> "AUT"; a bare code is used as is
+    let code = input
+        .rsplit_once('-')
+        .map(|(prefix, _)| prefix.rsplit('-').next().unwrap_or(prefix))
+        .unwrap_or(&input);
+
+    let name = ctx
+        .data()
+        .get("category_codes")
+        .and_then(|codes| codes.get(code.to_uppercase()))
+        .and_then(Value::as_str)
+        .unwrap_or(&input);
+    out.write(&escape(name))?;
+    Ok(())
 }
 
 /// Helper to extract unique actors from scenarios  
@@ -203,6 +345,27 @@ mod tests {
         assert_eq!(result, r#"["Database","System","User"]"#);
     }
 
+    #[test]
+    fn test_markdown_table_helper() {
+        let mut handlebars = Handlebars::new();
+        register_helpers(&mut handlebars);
+        handlebars
+            .register_template_string(
+                "test",
+                r#"{{{markdown_table stakeholders "Stakeholder" "Interest"}}}"#,
+            )
+            .unwrap();
+
+        let data = json!({
+            "stakeholders": [{ "Interest": "Fewer tickets", "Stakeholder": "Support" }]
+        });
+        assert_eq!(
+            handlebars.render("test", &data).unwrap(),
+            "| Stakeholder | Interest |\n| --- | --- |\n| Support | Fewer tickets |"
+        );
+        assert_eq!(handlebars.render("test", &json!({})).unwrap(), "");
+    }
+
     #[test]
     fn test_has_personas_helper_true() {
         let mut handlebars = Handlebars::new();
@@ -270,4 +433,59 @@ mod tests {
         // The helper returns a JSON array
         assert_eq!(result, r#"["admin","customer"]"#);
     }
+
+    #[test]
+    fn test_status_icon_helper_respects_theme() {
+        let mut handlebars = Handlebars::new();
+        register_helpers(&mut handlebars);
+        handlebars
+            .register_template_string("test", "{{status_icon status}}")
+            .unwrap();
+        let data = json!({ "status": "in_progress" });
+
+        assert_eq!(handlebars.render("test", &data).unwrap(), "🔄");
+
+        register_icon_helper(&mut handlebars, IconTheme::Ascii);
+        assert_eq!(handlebars.render("test", &data).unwrap(), "[~]");
+
+        register_icon_helper(&mut handlebars, IconTheme::None);
+        assert_eq!(handlebars.render("test", &data).unwrap(), "");
+    }
+
+    #[test]
+    fn test_actor_emoji_helper_respects_theme() {
+        let mut handlebars = Handlebars::new();
+        register_helpers(&mut handlebars);
+        handlebars
+            .register_template_string("test", "{{#each actors}}{{actor_emoji this}}|{{/each}}")
+            .unwrap();
+        let data = json!({ "actors": ["User", "System", "shopper"] });
+
+        assert_eq!(handlebars.render("test", &data).unwrap(), "👤|🖥️|🙂|");
+
+        register_icon_helper(&mut handlebars, IconTheme::Ascii);
+        assert_eq!(handlebars.render("test", &data).unwrap(), "|||");
+    }
+
+    #[test]
+    fn test_category_name_helper() {
+        let mut handlebars = Handlebars::new();
+        register_helpers(&mut handlebars);
+        handlebars
+            .register_template_string(
+                "test",
+                "{{category_name id}}|{{category_name \"pay\"}}|{{category_name \"XYZ\"}}|{{#each categories}}{{category_name}}{{/each}}",
+            )
+            .unwrap();
+        let data = json!({
+            "id": "UC-AUT-001",
+            "category_codes": { "AUT": "Authentication", "PAY": "Payments" },
+            "categories": [{ "category_name": "Billing & Invoices" }]
+        });
+
+        assert_eq!(
+            handlebars.render("test", &data).unwrap(),
+            "Authentication|Payments|XYZ|Billing &amp; Invoices"
+        );
+    }
 }
@@ -2,6 +2,7 @@
 
 mod engine;
 mod helpers;
+mod sandbox;
 
 // Public exports
-pub use engine::TemplateEngine;
+pub use engine::{TemplateEngine, TemplateFile};
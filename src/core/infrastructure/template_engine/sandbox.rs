@@ -0,0 +1,217 @@
+// Render limits for templates
+//
+// Templates are user-editable, so a render must not be able to hang the
+// process or fill the disk. Output size and render time are checked while
+// output is written; partial nesting is checked from the template sources
+// before rendering starts.
+
+use crate::core::{MucmError, Result};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::config::RenderLimitsConfig;
+
+/// Writer that stops a render once it exceeds the output size or time limit.
+///
+/// The first violation is kept so the engine can report it instead of the
+/// generic I/O error handlebars wraps it in.
+pub struct RenderGuard<W: Write> {
+    inner: W,
+    written: u64,
+    max_output_bytes: Option<u64>,
+    deadline: Option<(Instant, Duration)>,
+    violation: Option<String>,
+}
+
+impl<W: Write> RenderGuard<W> {
+    pub fn new(inner: W, limits: &RenderLimitsConfig) -> Self {
+        Self {
+            inner,
+            written: 0,
+            max_output_bytes: (limits.max_output_bytes > 0).then_some(limits.max_output_bytes),
+            deadline: (limits.max_render_ms > 0).then(|| {
+                let budget = Duration::from_millis(limits.max_render_ms);
+                (Instant::now() + budget, budget)
+            }),
+            violation: None,
+        }
+    }
+
+    /// Description of the limit that stopped the render, if any
+    pub fn violation(&self) -> Option<&str> {
+        self.violation.as_deref()
+    }
+
+    fn fail(&mut self, message: String) -> io::Error {
+        let error = io::Error::other(message.clone());
+        self.violation.get_or_insert(message);
+        error
+    }
+}
+
+impl<W: Write> Write for RenderGuard<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some((deadline, budget)) = self.deadline {
+            if Instant::now() > deadline {
+                return Err(self.fail(format!(
+                    "rendering took longer than render_limits.max_render_ms ({} ms)",
+                    budget.as_millis()
+                )));
+            }
+        }
+        if let Some(max) = self.max_output_bytes {
+            if self.written + buf.len() as u64 > max {
+                return Err(self.fail(format!(
+                    "rendered output exceeds render_limits.max_output_bytes ({} bytes)",
+                    max
+                )));
+            }
+        }
+        self.inner.write_all(buf)?;
+        self.written += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Names of the partials a template source includes (`{{> name}}` and `{{#> name}}`)
+pub fn partial_names(source: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("{{") {
+        rest = &rest[start + 2..];
+        let tag = rest.trim_start_matches('~').trim_start_matches('#');
+        let Some(tag) = tag.strip_prefix('>') else {
+            continue;
+        };
+        let tag = tag.trim_start();
+        let end = tag
+            .find(|c: char| c.is_whitespace() || c == '}' || c == '~')
+            .unwrap_or(tag.len());
+        if end > 0 {
+            names.push(&tag[..end]);
+        }
+    }
+    names
+}
+
+/// Fail if rendering `template` would nest partials deeper than `max_depth`.
+///
+/// `sources` maps every registered template and partial name to its source.
+/// Partials that are not registered are left to handlebars to report.
+pub fn check_partial_depth(
+    template: &str,
+    sources: &HashMap<String, String>,
+    max_depth: usize,
+) -> Result<()> {
+    if max_depth == 0 {
+        return Ok(());
+    }
+    let mut chain = vec![template.to_string()];
+    if !within_depth(&mut chain, sources, max_depth) {
+        return Err(MucmError::Template(format!(
+            "Template '{}' nests partials deeper than render_limits.max_partial_depth ({}): {}",
+            template,
+            max_depth,
+            chain.join(" > ")
+        )));
+    }
+    Ok(())
+}
+
+/// Depth-first walk of the partial graph; on failure `chain` holds the offending path
+fn within_depth(
+    chain: &mut Vec<String>,
+    sources: &HashMap<String, String>,
+    max_depth: usize,
+) -> bool {
+    let current = chain.last().expect("chain starts with the template");
+    let Some(source) = sources.get(current) else {
+        return true;
+    };
+    for partial in partial_names(source) {
+        if !sources.contains_key(partial) {
+            continue;
+        }
+        let cycle = chain.iter().any(|name| name == partial);
+        chain.push(partial.to_string());
+        if cycle || chain.len() > max_depth + 1 || !within_depth(chain, sources, max_depth) {
+            return false;
+        }
+        chain.pop();
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(name, source)| (name.to_string(), source.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_partial_names() {
+        assert_eq!(
+            partial_names(
+                "{{> scenario}} {{~> footer ~}} {{#> layout title=x}}{{/layout}} {{name}}"
+            ),
+            vec!["scenario", "footer", "layout"]
+        );
+    }
+
+    #[test]
+    fn test_partial_depth_within_limit() {
+        let sources = sources(&[("page", "{{> scenario}}"), ("scenario", "{{title}}")]);
+        assert!(check_partial_depth("page", &sources, 2).is_ok());
+        assert!(check_partial_depth("page", &sources, 1).is_ok());
+    }
+
+    #[test]
+    fn test_partial_cycle_is_rejected() {
+        let sources = sources(&[("a", "{{> b}}"), ("b", "{{> a}}")]);
+        let error = check_partial_depth("a", &sources, 8)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("max_partial_depth (8)"));
+        assert!(error.contains("a > b > a"));
+    }
+
+    #[test]
+    fn test_guard_limits_output_size() {
+        let limits = RenderLimitsConfig {
+            max_output_bytes: 8,
+            ..RenderLimitsConfig::default()
+        };
+        let mut buffer = Vec::new();
+        let mut guard = RenderGuard::new(&mut buffer, &limits);
+
+        assert!(guard.write_all(b"12345").is_ok());
+        assert!(guard.write_all(b"6789").is_err());
+        assert!(guard
+            .violation()
+            .unwrap()
+            .contains("max_output_bytes (8 bytes)"));
+    }
+
+    #[test]
+    fn test_guard_limits_render_time() {
+        let limits = RenderLimitsConfig {
+            max_render_ms: 1,
+            ..RenderLimitsConfig::default()
+        };
+        let mut guard = RenderGuard::new(Vec::new(), &limits);
+        std::thread::sleep(Duration::from_millis(5));
+
+        assert!(guard.write_all(b"late").is_err());
+        assert!(guard.violation().unwrap().contains("max_render_ms (1 ms)"));
+    }
+}
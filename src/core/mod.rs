@@ -3,21 +3,49 @@
 // Private with explicit exports:
 mod application;
 mod domain;
+mod error;
 mod infrastructure;
 pub mod utils; // Public utilities
 
+// Error type of every fallible core operation
+pub use error::{Context, MucmError, Result};
+
 // Explicit public exports from private modules
 // Public exports - Explicit API surface
 pub use application::{
     methodology_field_collector::{CollectedField, FieldCollection, MethodologyFieldCollector},
-    UseCaseCoordinator,
+    AsyncUseCaseCoordinator, UseCaseCoordinator,
+};
+
+// ID strategies of new use cases (project.id_strategy)
+pub use application::creators::{
+    id_generator, IdGenerator, PatternIdGenerator, SequentialIdGenerator, ShortUuidIdGenerator,
+    UlidIdGenerator, DEFAULT_ID_PATTERN,
 };
 
+// Result types returned by UseCaseCoordinator operations
+pub use application::generators::TestName;
+pub use application::services::{
+    AggregateReport, BatchFailure, BatchOperation, BatchProgress, BatchReport, BenchReport,
+    ConflictResolution, DemoProject, EstimateReport, EstimateRequest, EstimateTotal, ExecutionPlan,
+    Explanation, GeneratedTestFile, GherkinImportReport, GitHubSyncReport, GrepMatch,
+    HealthComponent, HealthReport, IdReport, IdSequence, ImportReport, JiraSyncReport,
+    LanguageDetails, MarkdownImportReport, MergeChoice, MergeConflict, OverviewExplanation,
+    PersonaService, PlanAction, PlannedMutation, PlannedOperation, ProjectSnapshot, ReleaseNote,
+    ReleaseNoteKind, ReleaseNotes, RtmImportReport, Sandbox, SandboxChange, SandboxChangeKind,
+    TemplateDraft, TemplatePreview, TestFileAction, TestReconciliation, UseCaseExplanation,
+    ValidationIssue, ViewProvenance,
+};
+
+// Exported for benchmarks (appear unused to lib but required by benches/)
+pub use application::generators::{MarkdownGenerator, OutputManager, OverviewGenerator};
+pub use application::services::{language_details, synthetic_use_cases};
+
 // Re-export domain types (from domain's public interface)
 pub use domain::{
-    Actor, ActorEntity, ActorRepository, ActorType, Condition, MethodologyView, Persona,
-    PersonaRepository, ReferenceType, ScenarioReference, ScenarioReferenceValidator, ScenarioType,
-    Status, UseCase,
+    Actor, ActorEntity, ActorHierarchy, ActorRepository, ActorType, Condition, MethodologyView,
+    Persona, PersonaRepository, Priority, Project, ReferenceType, ScenarioBuilder,
+    ScenarioReference, ScenarioReferenceValidator, ScenarioType, Status, UseCase, UseCaseBuilder,
 };
 
 // Exported for integration tests (appear unused to lib but required by tests/)
@@ -25,9 +53,13 @@ pub use domain::Scenario;
 
 // Re-export infrastructure types (from infrastructure's public interface)
 pub use infrastructure::{
-    file_operations, CustomFieldConfig, DocumentationLevel, FieldResolver, LanguageRegistry,
-    Methodology, MethodologyDefinition, MethodologyRegistry, RepositoryFactory, TemplateEngine,
-    UseCaseRepository,
+    block_on, file_operations, AsyncUseCaseRepository, BackupSnapshot, BackupStore,
+    BlockingRepository, CustomFieldConfig, DocumentationLevel, EstimateAggregate,
+    EstimationFieldConfig, EventUseCaseRepository, FieldResolver, InMemoryActorRepository,
+    InMemoryUseCaseRepository, LanguageRegistry, LifecycleConfig, LifecycleScenario, LoadFailure,
+    LoadReport, Methodology, MethodologyDefinition, MethodologyRegistry, ProjectLock, RepairAction,
+    RepairOutcome, RepositoryFactory, RepositoryFuture, RepositoryTransaction,
+    SyncRepositoryAdapter, TemplateEngine, TemplateFile, UseCaseRepository,
 };
 
 // Exported for integration tests (appear unused to lib but required by tests/)
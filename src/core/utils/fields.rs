@@ -0,0 +1,53 @@
+// Lookup of dotted field paths in serialized use cases
+
+use serde_json::Value;
+
+/// Values of a dotted field path (e.g. `methodology_fields.business.domain`)
+/// in a serialized use case
+///
+/// Lists yield one value per non-empty item; missing, null and empty values
+/// yield nothing.
+pub fn field_values(use_case: &Value, path: &str) -> Vec<String> {
+    let mut value = use_case;
+    for segment in path.split('.') {
+        match value.get(segment) {
+            Some(next) => value = next,
+            None => return Vec::new(),
+        }
+    }
+    let items: Vec<&Value> = match value {
+        Value::Array(items) => items.iter().collect(),
+        _ => vec![value],
+    };
+    items
+        .into_iter()
+        .filter_map(|item| match item {
+            Value::Null => None,
+            Value::String(text) => Some(text.trim().to_string()),
+            other => Some(other.to_string()),
+        })
+        .filter(|text| !text.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_values_follow_dotted_paths() {
+        let use_case = json!({
+            "category": "Auth",
+            "tags": ["security", "", "login"],
+            "methodology_fields": { "business": { "domain": "Identity", "owner": null } }
+        });
+        assert_eq!(
+            field_values(&use_case, "methodology_fields.business.domain"),
+            vec!["Identity"]
+        );
+        assert_eq!(field_values(&use_case, "tags"), vec!["security", "login"]);
+        assert!(field_values(&use_case, "methodology_fields.business.owner").is_empty());
+        assert!(field_values(&use_case, "sprint").is_empty());
+    }
+}
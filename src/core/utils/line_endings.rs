@@ -0,0 +1,95 @@
+// Line ending normalization for generated files
+
+use std::io::{self, Write};
+
+/// Converts every line break in `text` (`\n` or `\r\n`) to `eol`.
+pub fn normalize_line_endings(text: &str, eol: &str) -> String {
+    let unix = text.replace("\r\n", "\n");
+    if eol == "\n" {
+        unix
+    } else {
+        unix.replace('\n', eol)
+    }
+}
+
+/// Writer adapter that converts line breaks to a fixed terminator while streaming.
+///
+/// Both `\n` and `\r\n` in the input become `eol`, even when a `\r\n` pair is
+/// split across two writes. A lone `\r` is passed through unchanged once the
+/// following byte arrives.
+pub struct LineEndingWriter<W: Write> {
+    inner: W,
+    eol: &'static str,
+    pending_cr: bool,
+}
+
+impl<W: Write> LineEndingWriter<W> {
+    pub fn new(inner: W, eol: &'static str) -> Self {
+        Self {
+            inner,
+            eol,
+            pending_cr: false,
+        }
+    }
+}
+
+impl<W: Write> Write for LineEndingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut out = Vec::with_capacity(buf.len() + buf.len() / 32);
+        for &byte in buf {
+            match byte {
+                b'\n' => {
+                    out.extend_from_slice(self.eol.as_bytes());
+                    self.pending_cr = false;
+                }
+                b'\r' => {
+                    if self.pending_cr {
+                        out.push(b'\r');
+                    }
+                    self.pending_cr = true;
+                }
+                _ => {
+                    if self.pending_cr {
+                        out.push(b'\r');
+                        self.pending_cr = false;
+                    }
+                    out.push(byte);
+                }
+            }
+        }
+        self.inner.write_all(&out)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_line_endings() {
+        assert_eq!(normalize_line_endings("a\r\nb\nc", "\n"), "a\nb\nc");
+        assert_eq!(normalize_line_endings("a\r\nb\nc", "\r\n"), "a\r\nb\r\nc");
+    }
+
+    #[test]
+    fn test_writer_handles_split_crlf() {
+        let mut output = Vec::new();
+        {
+            let mut writer = LineEndingWriter::new(&mut output, "\r\n");
+            writer.write_all(b"one\r").unwrap();
+            writer.write_all(b"\ntwo\nthree").unwrap();
+        }
+        assert_eq!(output, b"one\r\ntwo\r\nthree");
+
+        let mut output = Vec::new();
+        LineEndingWriter::new(&mut output, "\n")
+            .write_all(b"one\r\ntwo\n")
+            .unwrap();
+        assert_eq!(output, b"one\ntwo\n");
+    }
+}
@@ -0,0 +1,230 @@
+// Validation and normalization of markdown written into text fields
+
+/// A problem found in the markdown of a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownIssue {
+    /// Line of the field the problem starts on (1-based)
+    pub line: usize,
+    /// What is wrong and, when it was repaired, how
+    pub message: String,
+}
+
+/// Repairs markdown that would break the document it is rendered into
+///
+/// Line endings become `\n`, an unclosed code fence is closed at the end,
+/// tables get a missing header separator row and their short rows are padded
+/// with empty cells. Rows with more cells than the header cannot be repaired
+/// without losing text; they are only reported. Returns the normalized text
+/// and the problems found, in order.
+pub fn normalize_markdown(text: &str) -> (String, Vec<MarkdownIssue>) {
+    let text = text.replace("\r\n", "\n");
+    let mut issues = Vec::new();
+    let mut lines: Vec<String> = Vec::new();
+    // Opening fence: marker (e.g. "```") and line number
+    let mut fence: Option<(String, usize)> = None;
+    let mut table: Vec<(usize, String)> = Vec::new();
+
+    for (index, line) in text.split('\n').enumerate() {
+        let number = index + 1;
+        let trimmed = line.trim_start();
+
+        if let Some((marker, _)) = &fence {
+            if is_closing_fence(trimmed, marker) {
+                fence = None;
+            }
+            lines.push(line.to_string());
+            continue;
+        }
+
+        if trimmed.starts_with('|') {
+            table.push((number, line.to_string()));
+            continue;
+        }
+        flush_table(&mut table, &mut lines, &mut issues);
+
+        if let Some(marker) = fence_marker(trimmed) {
+            fence = Some((marker, number));
+        }
+        lines.push(line.to_string());
+    }
+    flush_table(&mut table, &mut lines, &mut issues);
+
+    if let Some((marker, opened)) = fence {
+        issues.push(MarkdownIssue {
+            line: opened,
+            message: "code fence is never closed; closed it at the end".to_string(),
+        });
+        if lines.last().is_some_and(|last| last.is_empty()) {
+            lines.pop();
+            lines.push(marker);
+            lines.push(String::new());
+        } else {
+            lines.push(marker);
+        }
+    }
+
+    (lines.join("\n"), issues)
+}
+
+/// The fence marker (three or more backticks or tildes) opening a code block
+fn fence_marker(line: &str) -> Option<String> {
+    let first = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let marker: String = line.chars().take_while(|c| *c == first).collect();
+    (marker.len() >= 3).then_some(marker)
+}
+
+/// Whether `line` closes the code block opened by `marker`
+fn is_closing_fence(line: &str, marker: &str) -> bool {
+    let first = marker.chars().next().unwrap_or('`');
+    let run = line.chars().take_while(|c| *c == first).count();
+    run >= marker.len() && line[run..].trim().is_empty()
+}
+
+/// Cells of a table row, without the outer pipes
+fn table_cells(row: &str) -> Vec<String> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = row
+        .strip_suffix('|')
+        .filter(|_| !row.ends_with("\\|"))
+        .unwrap_or(row);
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut escaped = false;
+    for c in row.chars() {
+        if c == '|' && !escaped {
+            cells.push(cell.trim().to_string());
+            cell.clear();
+        } else {
+            cell.push(c);
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+/// Whether a table row is the separator row below the header (`| --- | :-: |`)
+fn is_separator_row(cells: &[String]) -> bool {
+    cells.iter().all(|cell| {
+        let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+        !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+    })
+}
+
+fn table_row(cells: &[String]) -> String {
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Appends the table collected in `table` to `lines`, repaired
+fn flush_table(
+    table: &mut Vec<(usize, String)>,
+    lines: &mut Vec<String>,
+    issues: &mut Vec<MarkdownIssue>,
+) {
+    let rows = std::mem::take(table);
+    let Some(((header_line, header), body)) = rows.split_first().map(|(h, b)| (h.clone(), b))
+    else {
+        return;
+    };
+    let columns = table_cells(&header).len();
+    let mut body = body.iter().peekable();
+
+    lines.push(header);
+    match body.next_if(|(_, row)| is_separator_row(&table_cells(row))) {
+        Some((line, row)) => {
+            if table_cells(row).len() == columns {
+                lines.push(row.clone());
+            } else {
+                issues.push(MarkdownIssue {
+                    line: *line,
+                    message: format!(
+                        "table separator row does not match the {} header cells; rebuilt it",
+                        columns
+                    ),
+                });
+                lines.push(table_row(&vec!["---".to_string(); columns]));
+            }
+        }
+        None => {
+            issues.push(MarkdownIssue {
+                line: header_line,
+                message: "table has no separator row below its header; added one".to_string(),
+            });
+            lines.push(table_row(&vec!["---".to_string(); columns]));
+        }
+    }
+
+    for (line, row) in body {
+        let mut cells = table_cells(row);
+        if cells.len() < columns {
+            issues.push(MarkdownIssue {
+                line: *line,
+                message: format!(
+                    "table row has {} of {} cells; padded it with empty cells",
+                    cells.len(),
+                    columns
+                ),
+            });
+            cells.resize(columns, String::new());
+            lines.push(table_row(&cells));
+        } else {
+            if cells.len() > columns {
+                issues.push(MarkdownIssue {
+                    line: *line,
+                    message: format!(
+                        "table row has {} cells but the header has {}; the extra cells are not shown",
+                        cells.len(),
+                        columns
+                    ),
+                });
+            }
+            lines.push(row.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_markdown_leaves_valid_markdown_alone() {
+        let text =
+            "Intro\n\n| A | B |\n|:--|--:|\n| 1 | 2 \\| 3 |\n\n```rust\nlet x = \"|\";\n```\n";
+        let (normalized, issues) = normalize_markdown(text);
+        assert_eq!(normalized, text);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_markdown_closes_code_fences() {
+        let (normalized, issues) = normalize_markdown("Run:\r\n````sh\r\n```\r\nmucm list\r\n");
+        assert_eq!(normalized, "Run:\n````sh\n```\nmucm list\n````\n");
+        assert_eq!(
+            issues,
+            vec![MarkdownIssue {
+                line: 2,
+                message: "code fence is never closed; closed it at the end".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_normalize_markdown_repairs_tables() {
+        let (normalized, issues) = normalize_markdown(
+            "| Role | Goal | Notes |\n| Admin | Audit |\n| User | Log in | x | y |",
+        );
+        assert_eq!(
+            normalized,
+            "| Role | Goal | Notes |\n| --- | --- | --- |\n| Admin | Audit |  |\n| User | Log in | x | y |"
+        );
+        let lines: Vec<usize> = issues.iter().map(|issue| issue.line).collect();
+        assert_eq!(lines, vec![1, 2, 3]);
+        assert!(issues[2].message.contains("not shown"));
+
+        let (normalized, issues) = normalize_markdown("| A | B |\n| --- |\n| 1 | 2 |");
+        assert_eq!(normalized, "| A | B |\n| --- | --- |\n| 1 | 2 |");
+        assert_eq!(issues.len(), 1);
+    }
+}
@@ -1,6 +1,20 @@
 // src/core/utils/mod.rs
+mod fields;
 mod fuzzy_match;
+mod line_endings;
+mod markdown;
+mod paths;
+mod progress;
 mod string_utils;
+mod tables;
+mod timings;
 
-pub use fuzzy_match::suggest_alternatives;
+pub use fields::field_values;
+pub use fuzzy_match::{find_closest_matches, suggest_alternatives};
+pub use line_endings::{normalize_line_endings, LineEndingWriter};
+pub use markdown::{normalize_markdown, MarkdownIssue};
+pub use paths::{link_path, portable_path, relative_link};
+pub use progress::{set_progress_reporter, start_progress, ProgressReporter, ProgressTask};
 pub use string_utils::{slugify_for_id, to_snake_case};
+pub use tables::{markdown_table, table_columns};
+pub use timings::{enable_timings, span, timing_summary, timings_enabled, Span, TimingEntry};
@@ -0,0 +1,73 @@
+// Platform-independent rendering of paths
+
+use std::path::Path;
+
+/// Renders a path with `/` separators.
+///
+/// Use this for every path written into generated content (links, manifests),
+/// so output is identical on Windows and Unix.
+pub fn portable_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Joins path segments into a relative link with `/` separators.
+pub fn link_path<S: AsRef<str>>(segments: &[S]) -> String {
+    segments
+        .iter()
+        .map(|segment| segment.as_ref().trim_matches(|c| c == '/' || c == '\\'))
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Relative link from the directory `from_dir` to the file `to`.
+///
+/// Both paths are relative to the same root, e.g. the project directory.
+pub fn relative_link(from_dir: &str, to: &str) -> String {
+    fn segments(path: &str) -> Vec<&str> {
+        path.split(['/', '\\'])
+            .filter(|segment| !segment.is_empty() && *segment != ".")
+            .collect()
+    }
+    let from = segments(from_dir);
+    let to = segments(to);
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut link = vec![".."; from.len() - common];
+    link.extend(&to[common..]);
+    link.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_portable_path() {
+        assert_eq!(
+            portable_path(Path::new("docs\\use-cases\\auth")),
+            "docs/use-cases/auth"
+        );
+        assert_eq!(
+            portable_path(&Path::new("docs").join("README.md")),
+            "docs/README.md"
+        );
+    }
+
+    #[test]
+    fn test_link_path() {
+        assert_eq!(link_path(&["auth/", "README.md"]), "auth/README.md");
+        assert_eq!(
+            link_path(&["", "auth", "UC-AUT-001.md"]),
+            "auth/UC-AUT-001.md"
+        );
+    }
+
+    #[test]
+    fn test_relative_link() {
+        assert_eq!(
+            relative_link("docs/use-cases/auth", "docs/actors/jack.md"),
+            "../../actors/jack.md"
+        );
+        assert_eq!(relative_link("./docs/", "docs/jack.md"), "jack.md");
+    }
+}
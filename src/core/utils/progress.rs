@@ -0,0 +1,91 @@
+//! Progress of long-running operations (regeneration, import, export, migrations).
+//!
+//! Core code starts a [`ProgressTask`] with [`start_progress`] and reports its
+//! steps; how, and whether, progress is shown is up to the [`ProgressReporter`]
+//! the front end registers with [`set_progress_reporter`]. Without one, tasks
+//! report nothing.
+
+use std::sync::OnceLock;
+
+static REPORTER: OnceLock<Box<dyn ProgressReporter>> = OnceLock::new();
+
+/// Shows the progress of operations, e.g. as terminal progress bars
+pub trait ProgressReporter: Send + Sync {
+    /// Start showing an operation of `len` steps labelled with `message`
+    fn start(&self, len: u64, message: &str) -> Box<dyn ProgressTask>;
+}
+
+/// A running operation
+pub trait ProgressTask {
+    /// Advance by one step
+    fn inc(&self);
+
+    /// Report a line of output without breaking the progress display
+    fn println(&self, line: &str);
+
+    /// Hide the progress display while `f` runs, e.g. to prompt the user;
+    /// `f` must be called exactly once
+    fn suspend(&self, f: &mut dyn FnMut());
+
+    /// Remove the progress display
+    fn finish(self: Box<Self>);
+}
+
+impl dyn ProgressTask {
+    /// [`ProgressTask::suspend`] for a closure returning a value
+    pub fn suspend_with<R>(&self, f: impl FnOnce() -> R) -> R {
+        let mut f = Some(f);
+        let mut result = None;
+        self.suspend(&mut || result = f.take().map(|f| f()));
+        result.expect("suspend calls the closure")
+    }
+}
+
+/// Report progress through `reporter` for the rest of the process
+///
+/// Only the first registration counts.
+pub fn set_progress_reporter(reporter: Box<dyn ProgressReporter>) {
+    let _ = REPORTER.set(reporter);
+}
+
+/// Start an operation of `len` steps labelled with `message`
+pub fn start_progress(len: u64, message: &str) -> Box<dyn ProgressTask> {
+    match REPORTER.get() {
+        Some(reporter) => reporter.start(len, message),
+        None => Box::new(SilentTask),
+    }
+}
+
+/// The task of operations nobody watches; lines still go to stderr
+struct SilentTask;
+
+impl ProgressTask for SilentTask {
+    fn inc(&self) {}
+
+    fn println(&self, line: &str) {
+        eprintln!("{}", line);
+    }
+
+    fn suspend(&self, f: &mut dyn FnMut()) {
+        f()
+    }
+
+    fn finish(self: Box<Self>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspend_with_returns_the_closure_result() {
+        let task: Box<dyn ProgressTask> = Box::new(SilentTask);
+        let mut calls = 0;
+        let answer = task.suspend_with(|| {
+            calls += 1;
+            42
+        });
+        assert_eq!((answer, calls), (42, 1));
+        task.finish();
+    }
+}
@@ -0,0 +1,86 @@
+// Markdown tables of structured (table-typed) field values
+
+use serde_json::Value;
+
+/// Column names of table rows: `columns` when given, otherwise the keys of
+/// all rows in first-seen order
+pub fn table_columns(columns: &[String], rows: &[Value]) -> Vec<String> {
+    if !columns.is_empty() {
+        return columns.to_vec();
+    }
+    let mut found: Vec<String> = Vec::new();
+    for row in rows {
+        if let Some(cells) = row.as_object() {
+            for key in cells.keys() {
+                if !found.contains(key) {
+                    found.push(key.clone());
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Renders rows (objects keyed by column name) as a markdown table
+///
+/// Missing cells stay empty; pipes and line breaks in cells are escaped so
+/// they cannot break the table. Returns an empty string without rows.
+pub fn markdown_table(columns: &[String], rows: &[Value]) -> String {
+    let columns = table_columns(columns, rows);
+    if rows.is_empty() || columns.is_empty() {
+        return String::new();
+    }
+
+    let mut lines = vec![
+        format!("| {} |", columns.join(" | ")),
+        format!("|{}", " --- |".repeat(columns.len())),
+    ];
+    for row in rows {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| table_cell(row.get(column)))
+            .collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+    lines.join("\n")
+}
+
+fn table_cell(value: Option<&Value>) -> String {
+    let text = match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) => text.clone(),
+        Some(other) => other.to_string(),
+    };
+    text.trim()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_markdown_table_follows_column_order() {
+        let rows = vec![
+            json!({ "Stakeholder": "Customer", "Interest": "Fast | easy login" }),
+            json!({ "Stakeholder": "Support", "Interest": "Fewer tickets\nper week" }),
+            json!({ "Stakeholder": "Security" }),
+        ];
+        let columns = vec!["Stakeholder".to_string(), "Interest".to_string()];
+
+        assert_eq!(
+            markdown_table(&columns, &rows),
+            "| Stakeholder | Interest |\n\
+             | --- | --- |\n\
+             | Customer | Fast \\| easy login |\n\
+             | Support | Fewer tickets<br>per week |\n\
+             | Security |  |"
+        );
+        assert_eq!(markdown_table(&columns, &[]), "");
+        // Without declared columns, the keys of the rows are used
+        assert_eq!(table_columns(&[], &rows), vec!["Interest", "Stakeholder"]);
+    }
+}
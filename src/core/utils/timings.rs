@@ -0,0 +1,122 @@
+//! Opt-in timing of the phases of a command (`mucm --timings`).
+//!
+//! Code wraps a phase in a [`span`]; when timings are enabled the span records
+//! how long it ran, minus the time of the spans opened inside it, so nested
+//! phases are not counted twice: rendering into a file counts as rendering,
+//! not writing. Disabled spans cost one atomic load.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDS: Mutex<Vec<TimingEntry>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// Time spent in child spans, for each open span of this thread
+    static OPEN: RefCell<Vec<Duration>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Total time of one phase over a command
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimingEntry {
+    pub name: String,
+    /// Time spent in the phase itself, excluding nested phases
+    pub duration: Duration,
+    /// Number of times the phase ran
+    pub count: usize,
+}
+
+/// Start recording spans
+pub fn enable_timings() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether spans are being recorded
+pub fn timings_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// A running phase, recorded when dropped
+pub struct Span {
+    active: Option<(String, Instant)>,
+}
+
+/// Open a span named `name`; the name is only built when timings are enabled
+pub fn span(name: impl FnOnce() -> String) -> Span {
+    if !timings_enabled() {
+        return Span { active: None };
+    }
+    OPEN.with(|open| open.borrow_mut().push(Duration::ZERO));
+    Span {
+        active: Some((name(), Instant::now())),
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some((name, start)) = self.active.take() else {
+            return;
+        };
+        let elapsed = start.elapsed();
+        let children = OPEN.with(|open| {
+            let mut open = open.borrow_mut();
+            let children = open.pop().unwrap_or_default();
+            if let Some(parent) = open.last_mut() {
+                *parent += elapsed;
+            }
+            children
+        });
+        record(name, elapsed.saturating_sub(children));
+    }
+}
+
+fn record(name: String, duration: Duration) {
+    let mut records = RECORDS.lock().unwrap_or_else(|e| e.into_inner());
+    match records.iter_mut().find(|entry| entry.name == name) {
+        Some(entry) => {
+            entry.duration += duration;
+            entry.count += 1;
+        }
+        None => records.push(TimingEntry {
+            name,
+            duration,
+            count: 1,
+        }),
+    }
+}
+
+/// The recorded phases, slowest first
+pub fn timing_summary() -> Vec<TimingEntry> {
+    let mut entries = RECORDS.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.duration));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_spans_record_their_own_time() {
+        enable_timings();
+        {
+            let _outer = span(|| "test write".to_string());
+            std::thread::sleep(Duration::from_millis(5));
+            for _ in 0..2 {
+                let _inner = span(|| "test render".to_string());
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        }
+
+        let summary = timing_summary();
+        let entry = |name: &str| summary.iter().find(|e| e.name == name).unwrap().clone();
+        let (write, render) = (entry("test write"), entry("test render"));
+        assert_eq!(write.count, 1);
+        assert_eq!(render.count, 2);
+        assert!(render.duration >= Duration::from_millis(20));
+        assert!(write.duration >= Duration::from_millis(5));
+        assert!(write.duration < render.duration);
+    }
+}
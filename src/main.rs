@@ -41,12 +41,14 @@
 
 use anyhow::Result;
 use markdown_use_case_manager::cli;
+use std::process::ExitCode;
 
 /// Application entry point.
 ///
 /// Initializes and runs the Markdown Use Case Manager CLI application.
 /// This function serves as the main entry point for the binary and delegates
 /// all functionality to the CLI module for command processing and execution.
-fn main() -> Result<()> {
+/// The process exits here, once the command has released the project lock.
+fn main() -> Result<ExitCode> {
     cli::run()
 }
@@ -1,5 +1,5 @@
 // Formatter for displaying DisplayResult objects
-use colored::Colorize;
+use crate::presentation::{paint, Style};
 
 /// Handles formatting and display of DisplayResult objects
 pub struct DisplayResultFormatter;
@@ -7,7 +7,8 @@ pub struct DisplayResultFormatter;
 impl DisplayResultFormatter {
     /// Format a DisplayResult with appropriate colors for CLI output
     ///
-    /// Success messages are displayed in green, error messages in red.
+    /// Success messages use the success style, error messages the error style.
+    /// Whether colors are emitted at all is decided by the theme's color mode.
     ///
     /// # Arguments
     /// * `result` - The DisplayResult to format
@@ -15,13 +16,10 @@ impl DisplayResultFormatter {
     /// # Returns
     /// A colored string representation of the result
     pub fn format_colored(result: &crate::controller::DisplayResult) -> colored::ColoredString {
-        // Force colors to be enabled
-        colored::control::set_override(true);
-
         if result.success {
-            result.message.green()
+            paint(&result.message, Style::Success)
         } else {
-            result.message.red()
+            paint(&result.message, Style::Error)
         }
     }
 
@@ -0,0 +1,302 @@
+// Formatter for displaying methodology details (`mucm methodology-info`)
+use crate::core::{
+    CustomFieldConfig, DocumentationLevel, FieldResolver, Methodology, MethodologyDefinition,
+};
+use serde_json::{json, Value};
+
+/// Handles formatting of the levels, fields and guidance of a methodology
+pub struct MethodologyFormatter;
+
+impl MethodologyFormatter {
+    /// Methodology details as text, the fields of each level as a table
+    pub fn info_text(methodology: &MethodologyDefinition) -> String {
+        let mut result = format!(
+            "=== {} ===\n\n{}\n",
+            methodology.title(),
+            methodology.description().trim()
+        );
+        for (heading, items) in [
+            ("When to Use", methodology.when_to_use()),
+            ("Key Features", methodology.key_features()),
+            ("Best Practices", methodology.best_practices()),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            result.push_str(&format!("\n{}:\n", heading));
+            for item in items {
+                result.push_str(&format!("  • {}\n", item));
+            }
+        }
+
+        result.push_str("\nDocumentation Levels:\n");
+        for level in ordered_levels(methodology) {
+            result.push_str(&format!(
+                "\n  {} ({}): {}\n",
+                level.name, level.abbreviation, level.description
+            ));
+            result.push_str(&format!("    Template: {}\n", level.filename));
+            if let Some(template) = methodology.scenario_template(&level.name) {
+                result.push_str(&format!("    Scenario template: {}\n", template));
+            }
+            if !level.inherits.is_empty() {
+                result.push_str(&format!("    Inherits: {}\n", level.inherits.join(", ")));
+            }
+
+            let fields = level_fields(methodology, level);
+            if fields.is_empty() {
+                result.push_str("    Fields: none\n");
+                continue;
+            }
+            let rows: Vec<[String; 5]> = fields
+                .iter()
+                .map(|(name, field, source)| {
+                    [
+                        name.clone(),
+                        field.field_type.clone(),
+                        if field.required { "yes" } else { "no" }.to_string(),
+                        source.clone(),
+                        field_summary(field),
+                    ]
+                })
+                .collect();
+            let header = [
+                "Field".to_string(),
+                "Type".to_string(),
+                "Required".to_string(),
+                "From".to_string(),
+                "Description".to_string(),
+            ];
+            let widths: Vec<usize> = (0..4)
+                .map(|column| {
+                    rows.iter()
+                        .chain([&header])
+                        .map(|row| row[column].chars().count())
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+            for row in [&header].into_iter().chain(&rows) {
+                let mut line = String::from("    ");
+                for (column, width) in widths.iter().enumerate() {
+                    line.push_str(&format!("{:<width$}  ", row[column], width = *width));
+                }
+                line.push_str(&row[4]);
+                result.push_str(line.trim_end());
+                result.push('\n');
+            }
+        }
+
+        result.push_str(&format!(
+            "\nPreferred Style: {}\n",
+            methodology.preferred_style()
+        ));
+        result
+    }
+
+    /// Methodology details as JSON
+    pub fn info_json(methodology: &MethodologyDefinition) -> Value {
+        let levels: Vec<Value> = ordered_levels(methodology)
+            .into_iter()
+            .map(|level| {
+                let fields: Vec<Value> = level_fields(methodology, level)
+                    .into_iter()
+                    .map(|(name, field, source)| {
+                        json!({
+                            "name": name,
+                            "label": field.label,
+                            "type": field.field_type,
+                            "required": field.required,
+                            "default": field.default,
+                            "description": field.description,
+                            "example": field.example,
+                            "columns": field.columns,
+                            "defined_in": source,
+                        })
+                    })
+                    .collect();
+                json!({
+                    "name": level.name,
+                    "abbreviation": level.abbreviation,
+                    "description": level.description,
+                    "template": level.filename,
+                    "scenario_template": methodology.scenario_template(&level.name),
+                    "inherits": level.inherits,
+                    "fields": fields,
+                })
+            })
+            .collect();
+
+        json!({
+            "name": methodology.name(),
+            "title": methodology.title(),
+            "description": methodology.description().trim(),
+            "preferred_style": methodology.preferred_style(),
+            "usage": {
+                "when_to_use": methodology.when_to_use(),
+                "key_features": methodology.key_features(),
+                "best_practices": methodology.best_practices(),
+            },
+            "levels": levels,
+        })
+    }
+}
+
+/// Levels from the least to the most detailed: by depth of inheritance, then name
+fn ordered_levels(methodology: &MethodologyDefinition) -> Vec<&DocumentationLevel> {
+    let mut levels: Vec<&DocumentationLevel> = methodology.levels().iter().collect();
+    levels.sort_by_key(|level| (depth(methodology, level, 0), level.name.clone()));
+    levels
+}
+
+/// Number of ancestors on the longest inheritance path of `level`
+fn depth(methodology: &MethodologyDefinition, level: &DocumentationLevel, guard: usize) -> usize {
+    if guard > methodology.levels().len() {
+        return guard;
+    }
+    level
+        .inherits
+        .iter()
+        .filter_map(|parent| methodology.levels().iter().find(|l| l.name == *parent))
+        .map(|parent| 1 + depth(methodology, parent, guard + 1))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Resolved fields of `level` by name, with the level that declares each
+fn level_fields(
+    methodology: &MethodologyDefinition,
+    level: &DocumentationLevel,
+) -> Vec<(String, CustomFieldConfig, String)> {
+    let resolved = FieldResolver::new(methodology)
+        .resolve_fields_for_level(&level.name)
+        .unwrap_or_default();
+    let mut fields: Vec<(String, CustomFieldConfig, String)> = resolved
+        .into_iter()
+        .map(|(name, field)| {
+            let source =
+                declaring_level(methodology, level, &name, 0).unwrap_or_else(|| level.name.clone());
+            (name, field, source)
+        })
+        .collect();
+    fields.sort_by(|a, b| a.0.cmp(&b.0));
+    fields
+}
+
+/// Nearest level, starting at `level`, that declares the field `name`
+fn declaring_level(
+    methodology: &MethodologyDefinition,
+    level: &DocumentationLevel,
+    name: &str,
+    guard: usize,
+) -> Option<String> {
+    if methodology
+        .level_fields(&level.name)
+        .is_some_and(|fields| fields.contains_key(name))
+    {
+        return Some(level.name.clone());
+    }
+    if guard > methodology.levels().len() {
+        return None;
+    }
+    level
+        .inherits
+        .iter()
+        .filter_map(|parent| methodology.levels().iter().find(|l| l.name == *parent))
+        .find_map(|parent| declaring_level(methodology, parent, name, guard + 1))
+}
+
+/// Label and description of a field on one line
+fn field_summary(field: &CustomFieldConfig) -> String {
+    match (field.label.as_deref(), field.description.as_deref()) {
+        (Some(label), Some(description)) => format!("{}: {}", label, description),
+        (Some(text), None) | (None, Some(text)) => text.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn methodology() -> (tempfile::TempDir, MethodologyDefinition) {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("methodology.toml"),
+            r#"
+[methodology]
+name = "feature"
+description = "Product features"
+
+[template]
+preferred_style = "Normal"
+
+[usage]
+when_to_use = ["Product work"]
+key_features = ["Agile"]
+best_practices = ["Keep features small"]
+
+[levels.advanced]
+name = "Advanced"
+abbreviation = "a"
+filename = "uc_advanced.hbs"
+description = "Everything"
+inherits = ["Normal"]
+
+[levels.advanced.custom_fields]
+design_assets = { label = "Design Assets", type = "array", description = "Mockups" }
+
+[levels.normal]
+name = "Normal"
+abbreviation = "n"
+filename = "uc_normal.hbs"
+scenario_template = "scenarios/scenario.hbs"
+description = "Everyday use"
+
+[levels.normal.custom_fields]
+hypothesis = { type = "text", required = true, description = "What we expect" }
+"#,
+        )
+        .unwrap();
+        let methodology = MethodologyDefinition::from_toml(dir.path()).unwrap();
+        (dir, methodology)
+    }
+
+    #[test]
+    fn test_info_json_lists_levels_with_inherited_fields() {
+        let (_dir, methodology) = methodology();
+        let info = MethodologyFormatter::info_json(&methodology);
+
+        assert_eq!(
+            info["usage"]["best_practices"],
+            json!(["Keep features small"])
+        );
+        assert_eq!(info["levels"][0]["name"], "Normal");
+        assert_eq!(
+            info["levels"][0]["scenario_template"],
+            "scenarios/scenario.hbs"
+        );
+        let advanced = &info["levels"][1];
+        assert_eq!(advanced["template"], "uc_advanced.hbs");
+        assert_eq!(advanced["fields"][0]["name"], "design_assets");
+        assert_eq!(advanced["fields"][0]["defined_in"], "Advanced");
+        assert_eq!(advanced["fields"][1]["name"], "hypothesis");
+        assert_eq!(advanced["fields"][1]["required"], true);
+        assert_eq!(advanced["fields"][1]["defined_in"], "Normal");
+    }
+
+    #[test]
+    fn test_info_text_tabulates_fields() {
+        let (_dir, methodology) = methodology();
+        let text = MethodologyFormatter::info_text(&methodology);
+
+        assert!(text.contains("Best Practices:\n  • Keep features small\n"));
+        assert!(text.contains(
+            "    Field          Type   Required  From      Description\n\
+             \x20   design_assets  array  no        Advanced  Design Assets: Mockups\n\
+             \x20   hypothesis     text   yes       Normal    What we expect\n"
+        ));
+        assert!(text.find("Normal (n)").unwrap() < text.find("Advanced (a)").unwrap());
+    }
+}
@@ -1,10 +1,12 @@
 // CLI formatters - Presentation layer for displaying data
 
 mod display_result_formatter;
+mod methodology_formatter;
 mod status_formatter;
 mod use_case_formatter;
 
 // Explicit public exports
 pub use display_result_formatter::DisplayResultFormatter;
+pub use methodology_formatter::MethodologyFormatter;
 pub use status_formatter::StatusFormatter;
 pub use use_case_formatter::UseCaseFormatter;
@@ -1,7 +1,11 @@
 // Formatter for displaying project status
-use crate::core::{Status, UseCase};
-use colored::Colorize;
-use std::collections::HashMap;
+use crate::core::{HealthReport, Status, UseCase};
+use crate::presentation::{format_status, icon, icon_theme, paint, rule, with_icon, Icon, Style};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// Failing use cases listed per health component before "and N more"
+const MAX_FAILING_SHOWN: usize = 5;
 
 /// Handles formatting and display of project status
 pub struct StatusFormatter;
@@ -16,13 +20,116 @@ impl StatusFormatter {
             *status_counts.entry(use_case.status()).or_insert(0) += 1;
         }
 
-        println!("\n{}", "📊 Project Status".bold().blue());
-        println!("{}", "━".repeat(50));
-        println!("Total Use Cases: {}", total_use_cases.to_string().cyan());
+        println!(
+            "\n{}",
+            paint(
+                &with_icon(icon(Icon::Stats, icon_theme()), "Project Status"),
+                Style::Heading
+            )
+        );
+        println!("{}", rule(50));
+        println!(
+            "Total Use Cases: {}",
+            paint(&total_use_cases.to_string(), Style::Count)
+        );
         println!();
 
         for (status, count) in status_counts {
-            println!("{}: {}", status, count.to_string().cyan());
+            println!(
+                "{}: {}",
+                format_status(status),
+                paint(&count.to_string(), Style::Count)
+            );
+        }
+
+        let today = chrono::Utc::now().date_naive();
+        let overdue: Vec<&str> = use_cases
+            .iter()
+            .filter(|uc| uc.metadata.is_review_due(today))
+            .map(|uc| uc.id.as_str())
+            .collect();
+        if !overdue.is_empty() {
+            println!();
+            println!(
+                "{}",
+                with_icon(
+                    icon(Icon::Warning, icon_theme()),
+                    &format!(
+                        "{} use case(s) past their review date: {} (see 'mucm list --needs-review')",
+                        overdue.len(),
+                        overdue.join(", ")
+                    )
+                )
+            );
+        }
+    }
+
+    /// Display the health score with the breakdown of its components
+    pub fn display_health(report: &HealthReport) {
+        println!();
+        let score = report.score.to_string() + "/100";
+        let style = match report.score {
+            80.. => Style::Success,
+            50.. => Style::Warning,
+            _ => Style::Error,
+        };
+        println!("Health Score: {}", paint(&score, style));
+        println!();
+        println!(
+            "  {:<12} {:>6} {:>7} {:>9}",
+            "Check", "Score", "Weight", "Passing"
+        );
+        for component in &report.components {
+            let (score, passing) = match component.score {
+                Some(score) => (
+                    score.to_string(),
+                    format!("{}/{}", component.passing, component.total),
+                ),
+                None => ("n/a".to_string(), "-".to_string()),
+            };
+            println!(
+                "  {:<12} {:>6} {:>7} {:>9}",
+                component.name, score, component.weight, passing
+            );
         }
+
+        for component in report.components.iter().filter(|c| !c.failing.is_empty()) {
+            let mut shown = component
+                .failing
+                .iter()
+                .take(MAX_FAILING_SHOWN)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ");
+            if component.failing.len() > MAX_FAILING_SHOWN {
+                shown.push_str(&format!(
+                    " and {} more",
+                    component.failing.len() - MAX_FAILING_SHOWN
+                ));
+            }
+            println!("  {} failing: {}", component.name, shown);
+        }
+    }
+
+    /// The project status and health as JSON, for dashboards
+    pub fn project_status_json(use_cases: &[UseCase], health: &HealthReport) -> Value {
+        let mut statuses: BTreeMap<Status, usize> =
+            Status::ALL.iter().map(|status| (*status, 0)).collect();
+        for use_case in use_cases {
+            *statuses.entry(use_case.status()).or_insert(0) += 1;
+        }
+        let today = chrono::Utc::now().date_naive();
+        let review_due: Vec<&str> = use_cases
+            .iter()
+            .filter(|uc| uc.metadata.is_review_due(today))
+            .map(|uc| uc.id.as_str())
+            .collect();
+
+        json!({
+            "total_use_cases": use_cases.len(),
+            "statuses": statuses,
+            "review_due": review_due,
+            "health": health,
+        })
     }
 }
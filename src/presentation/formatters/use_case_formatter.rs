@@ -1,6 +1,6 @@
 // Formatter for displaying use case information
-use crate::core::UseCase;
-use colored::Colorize;
+use crate::core::{GrepMatch, UseCase};
+use crate::presentation::{format_status, icon, icon_theme, paint, rule, with_icon, Icon, Style};
 
 /// Handles formatting and display of use cases
 pub struct UseCaseFormatter;
@@ -13,40 +13,131 @@ impl UseCaseFormatter {
             return;
         }
 
-        println!("\n{}", "📋 Use Cases".bold().blue());
-        println!("{}", "━".repeat(50));
+        println!(
+            "\n{}",
+            paint(
+                &with_icon(icon(Icon::List, icon_theme()), "Use Cases"),
+                Style::Heading
+            )
+        );
+        println!("{}", rule(50));
 
         for use_case in use_cases {
-            let status_display = format!("{}", use_case.status());
+            let status_display = format_status(use_case.status());
             println!(
                 "{} {} [{}] - {}",
                 status_display,
-                use_case.id.cyan(),
-                use_case.category.yellow(),
-                use_case.title.bold()
+                paint(&use_case.id, Style::Identifier),
+                paint(&use_case.category, Style::Category),
+                paint(&use_case.title, Style::Title)
             );
             println!();
         }
     }
 
+    /// Display use cases that are due for review, with their review dates
+    pub fn display_review_list(use_cases: &[&UseCase]) {
+        if use_cases.is_empty() {
+            println!("No use cases need review.");
+            return;
+        }
+
+        println!(
+            "\n{}",
+            paint(
+                &with_icon(icon(Icon::Warning, icon_theme()), "Needs Review"),
+                Style::Heading
+            )
+        );
+        println!("{}", rule(50));
+
+        for use_case in use_cases {
+            let review_by = use_case
+                .metadata
+                .review_by
+                .map(|date| date.to_string())
+                .unwrap_or_default();
+            println!(
+                "{} {} [{}] - {}",
+                paint(&review_by, Style::Count),
+                paint(&use_case.id, Style::Identifier),
+                paint(&use_case.category, Style::Category),
+                paint(&use_case.title, Style::Title)
+            );
+        }
+    }
+
+    /// Display search matches, one line each, with the matched text highlighted
+    pub fn display_grep_matches(matches: &[GrepMatch]) {
+        if matches.is_empty() {
+            println!("No matches found.");
+            return;
+        }
+
+        for grep_match in matches {
+            let mut line = String::new();
+            let mut last = 0;
+            for &(start, end) in &grep_match.ranges {
+                line.push_str(&grep_match.line[last..start]);
+                line.push_str(&paint(&grep_match.line[start..end], Style::Match).to_string());
+                last = end;
+            }
+            line.push_str(&grep_match.line[last..]);
+
+            let location = match grep_match.line_number {
+                Some(number) => format!("{}:{}", grep_match.field, number),
+                None => grep_match.field.clone(),
+            };
+            println!(
+                "{} {}: {}",
+                paint(&grep_match.use_case_id, Style::Identifier),
+                paint(&location, Style::Category),
+                line.trim()
+            );
+        }
+
+        let use_cases: std::collections::HashSet<&str> =
+            matches.iter().map(|m| m.use_case_id.as_str()).collect();
+        println!(
+            "\n{} match(es) in {} use case(s)",
+            paint(&matches.len().to_string(), Style::Count),
+            paint(&use_cases.len().to_string(), Style::Count)
+        );
+    }
+
     /// Display a success message for use case creation
     pub fn display_created(use_case_id: &str, methodology: &str) {
-        println!("💾 Saved {} with {} methodology", use_case_id, methodology);
+        println!(
+            "{}",
+            with_icon(
+                icon(Icon::Saved, icon_theme()),
+                &format!("Saved {} with {} methodology", use_case_id, methodology)
+            )
+        );
     }
 
     /// Display a success message for use case regeneration
     pub fn display_regenerated(use_case_id: &str, methodology: &str) {
         println!(
-            "✅ Regenerated {} with {} methodology",
-            use_case_id, methodology
+            "{}",
+            with_icon(
+                icon(Icon::Success, icon_theme()),
+                &format!(
+                    "Regenerated {} with {} methodology",
+                    use_case_id, methodology
+                )
+            )
         );
     }
 
     /// Display a success message for markdown regeneration
     pub fn display_markdown_regenerated(use_case_id: &str) {
         println!(
-            "📝 Regenerated {}.md from {}.toml",
-            use_case_id, use_case_id
+            "{}",
+            with_icon(
+                icon(Icon::Document, icon_theme()),
+                &format!("Regenerated {}.md from {}.toml", use_case_id, use_case_id)
+            )
         );
     }
 
@@ -55,7 +146,10 @@ impl UseCaseFormatter {
         for _ in 0..count {
             // Individual messages shown during iteration
         }
-        println!("📝 Regenerated overview");
+        println!(
+            "{}",
+            with_icon(icon(Icon::Document, icon_theme()), "Regenerated overview")
+        );
     }
 
     /// Display test generation info
@@ -63,9 +157,15 @@ impl UseCaseFormatter {
     /// TODO: Call this when test generation is re-implemented
     pub fn display_test_generated(use_case_id: &str, test_file_path: &str) {
         println!(
-            "✅ Generated test: {} -> {}",
-            use_case_id.cyan(),
-            test_file_path
+            "{}",
+            with_icon(
+                icon(Icon::Success, icon_theme()),
+                &format!(
+                    "Generated test: {} -> {}",
+                    paint(use_case_id, Style::Identifier),
+                    test_file_path
+                )
+            )
         );
     }
 
@@ -73,6 +173,12 @@ impl UseCaseFormatter {
     /// Display message when test generation is skipped
     /// TODO: Call this when auto_generate_tests is false
     pub fn display_test_skipped() {
-        println!("⚠️  Test file exists and overwrite_test_documentation=false, skipping");
+        println!(
+            "{}",
+            with_icon(
+                icon(Icon::Warning, icon_theme()),
+                "Test file exists and overwrite_test_documentation=false, skipping"
+            )
+        );
     }
 }
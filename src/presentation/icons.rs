@@ -0,0 +1,149 @@
+// Icon theme lookup shared by formatters and template helpers
+use crate::config::IconTheme;
+use crate::core::{Actor, Status};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Process-wide icon theme used by the terminal formatters
+static ICON_THEME: AtomicU8 = AtomicU8::new(0);
+
+/// Message prefix icons used by the formatters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Icon {
+    Stats,
+    List,
+    Saved,
+    Success,
+    Document,
+    Warning,
+}
+
+/// Set the icon theme used by the terminal formatters.
+pub fn set_icon_theme(theme: IconTheme) {
+    let value = match theme {
+        IconTheme::Unicode => 0,
+        IconTheme::Ascii => 1,
+        IconTheme::None => 2,
+    };
+    ICON_THEME.store(value, Ordering::Relaxed);
+}
+
+/// The icon theme currently used by the terminal formatters.
+pub fn icon_theme() -> IconTheme {
+    match ICON_THEME.load(Ordering::Relaxed) {
+        1 => IconTheme::Ascii,
+        2 => IconTheme::None,
+        _ => IconTheme::Unicode,
+    }
+}
+
+/// Glyph for a message icon in the given theme (empty for `none`)
+pub fn icon(icon: Icon, theme: IconTheme) -> &'static str {
+    match theme {
+        IconTheme::Unicode => match icon {
+            Icon::Stats => "📊",
+            Icon::List => "📋",
+            Icon::Saved => "💾",
+            Icon::Success => "✅",
+            Icon::Document => "📝",
+            Icon::Warning => "⚠️ ",
+        },
+        IconTheme::Ascii => match icon {
+            Icon::Stats => "[#]",
+            Icon::List => "[=]",
+            Icon::Saved => "[+]",
+            Icon::Success => "[ok]",
+            Icon::Document => "[md]",
+            Icon::Warning => "[!]",
+        },
+        IconTheme::None => "",
+    }
+}
+
+/// Glyph for a status in the given theme (empty for `none`)
+pub fn status_icon(status: Status, theme: IconTheme) -> &'static str {
+    match theme {
+        IconTheme::Unicode => status.emoji(),
+        IconTheme::Ascii => match status {
+            Status::Planned => "[ ]",
+            Status::InProgress => "[~]",
+            Status::Implemented => "[*]",
+            Status::Tested => "[+]",
+            Status::Deployed => "[^]",
+            Status::Deprecated => "[x]",
+        },
+        IconTheme::None => "",
+    }
+}
+
+/// Glyph for a scenario actor in the given theme
+///
+/// Only the unicode theme has actor icons: the actor's name always follows,
+/// so ASCII markers would add nothing.
+pub fn actor_icon(actor: &Actor, theme: IconTheme) -> &'static str {
+    match theme {
+        IconTheme::Unicode => match actor {
+            Actor::User => "👤",
+            Actor::System => "🖥️",
+            Actor::Server => "🗄️",
+            Actor::ExternalAPI => "🔌",
+            Actor::Database => "💾",
+            Actor::ActorRef(_) | Actor::Custom(_) => "🙂",
+        },
+        IconTheme::Ascii | IconTheme::None => "",
+    }
+}
+
+/// Prefix text with an icon from the current theme, without a dangling space for `none`
+pub fn with_icon(icon_glyph: &str, text: &str) -> String {
+    if icon_glyph.is_empty() {
+        text.to_string()
+    } else {
+        format!("{} {}", icon_glyph, text)
+    }
+}
+
+/// Horizontal rule under headings, ASCII unless the theme is `unicode`
+pub fn rule(width: usize) -> String {
+    match icon_theme() {
+        IconTheme::Unicode => "━".repeat(width),
+        IconTheme::Ascii | IconTheme::None => "-".repeat(width),
+    }
+}
+
+/// Format a status with its icon from the current theme (e.g. `[~] IN_PROGRESS`)
+pub fn format_status(status: Status) -> String {
+    with_icon(status_icon(status, icon_theme()), status.display_name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_icon_per_theme() {
+        assert_eq!(status_icon(Status::InProgress, IconTheme::Unicode), "🔄");
+        assert_eq!(status_icon(Status::InProgress, IconTheme::Ascii), "[~]");
+        assert_eq!(status_icon(Status::InProgress, IconTheme::None), "");
+    }
+
+    #[test]
+    fn test_icon_glyphs_are_ascii_in_ascii_theme() {
+        for i in [
+            Icon::Stats,
+            Icon::List,
+            Icon::Saved,
+            Icon::Success,
+            Icon::Document,
+            Icon::Warning,
+        ] {
+            assert!(icon(i, IconTheme::Ascii).is_ascii());
+            assert!(icon(i, IconTheme::None).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_with_icon_skips_empty_glyph() {
+        assert_eq!(with_icon("", "PLANNED"), "PLANNED");
+        assert_eq!(with_icon("[ ]", "PLANNED"), "[ ] PLANNED");
+    }
+}
@@ -1,6 +1,16 @@
 // Presentation layer - Formatters and display logic
 
 mod formatters;
+mod icons;
+mod progress;
+mod theme;
 
 // Explicit public exports
-pub use formatters::{DisplayResultFormatter, StatusFormatter, UseCaseFormatter};
+pub use formatters::{
+    DisplayResultFormatter, MethodologyFormatter, StatusFormatter, UseCaseFormatter,
+};
+pub use icons::{
+    actor_icon, format_status, icon, icon_theme, rule, set_icon_theme, status_icon, with_icon, Icon,
+};
+pub use progress::{set_progress_enabled, Progress, TerminalProgress};
+pub use theme::{apply_color_mode, paint, Style};
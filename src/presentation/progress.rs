@@ -0,0 +1,99 @@
+// Progress reporting for long-running operations
+use indicatif::{ProgressBar, ProgressStyle};
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::core::utils::{ProgressReporter, ProgressTask};
+
+/// Whether progress bars may be shown at all (turned off for machine-readable output)
+static PROGRESS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable progress bars for the rest of the process.
+///
+/// Commands that write JSON or other machine-readable output should disable
+/// progress so nothing but their payload reaches the terminal.
+pub fn set_progress_enabled(enabled: bool) {
+    PROGRESS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Progress bar with counts and ETA, drawn on stderr.
+///
+/// Hidden when progress is disabled or stderr is not a terminal, in which case
+/// every method is a no-op apart from `println`, which falls back to stderr
+/// so stdout only carries the command's output.
+pub struct Progress {
+    bar: Option<ProgressBar>,
+}
+
+impl Progress {
+    /// Start a progress bar for `len` steps labelled with `message`.
+    pub fn new(len: u64, message: &str) -> Self {
+        if !PROGRESS_ENABLED.load(Ordering::Relaxed) || !std::io::stderr().is_terminal() {
+            return Self { bar: None };
+        }
+
+        let style = ProgressStyle::with_template("{msg} [{bar:30}] {pos}/{len} (ETA {eta})")
+            .unwrap_or_else(|_| ProgressStyle::default_bar())
+            .progress_chars("=> ");
+        let bar = ProgressBar::new(len).with_style(style);
+        bar.set_message(message.to_string());
+        Self { bar: Some(bar) }
+    }
+
+    /// Advance by one step.
+    pub fn inc(&self) {
+        if let Some(bar) = &self.bar {
+            bar.inc(1);
+        }
+    }
+
+    /// Print a line without breaking the bar.
+    pub fn println(&self, message: &str) {
+        match &self.bar {
+            Some(bar) => bar.println(message),
+            None => eprintln!("{}", message),
+        }
+    }
+
+    /// Hide the bar while `f` runs, e.g. to prompt the user.
+    pub fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        match &self.bar {
+            Some(bar) => bar.suspend(f),
+            None => f(),
+        }
+    }
+
+    /// Remove the bar from the terminal.
+    pub fn finish(self) {
+        if let Some(bar) = self.bar {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+impl ProgressTask for Progress {
+    fn inc(&self) {
+        Progress::inc(self)
+    }
+
+    fn println(&self, line: &str) {
+        Progress::println(self, line)
+    }
+
+    fn suspend(&self, f: &mut dyn FnMut()) {
+        Progress::suspend(self, f)
+    }
+
+    fn finish(self: Box<Self>) {
+        Progress::finish(*self)
+    }
+}
+
+/// Shows the progress of core operations as terminal progress bars
+pub struct TerminalProgress;
+
+impl ProgressReporter for TerminalProgress {
+    fn start(&self, len: u64, message: &str) -> Box<dyn ProgressTask> {
+        Box::new(Progress::new(len, message))
+    }
+}
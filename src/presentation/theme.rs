@@ -0,0 +1,88 @@
+// Terminal styling shared by all formatters
+use crate::config::ColorMode;
+use colored::{ColoredString, Colorize};
+use std::io::IsTerminal;
+
+/// Semantic styles, so formatters never pick colors themselves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Section headings such as "Use Cases"
+    Heading,
+    /// Successful outcomes
+    Success,
+    /// Errors and failures
+    Error,
+    /// Problems that don't stop the command
+    Warning,
+    /// Use case and scenario IDs
+    Identifier,
+    /// Category names
+    Category,
+    /// Use case titles
+    Title,
+    /// Numbers in summaries
+    Count,
+    /// Matched text in search results
+    Match,
+}
+
+/// Apply a style to text.
+pub fn paint(text: &str, style: Style) -> ColoredString {
+    match style {
+        Style::Heading => text.bold().blue(),
+        Style::Success => text.green(),
+        Style::Error => text.red(),
+        Style::Warning => text.yellow(),
+        Style::Identifier => text.cyan(),
+        Style::Category => text.yellow(),
+        Style::Title => text.bold(),
+        Style::Count => text.cyan(),
+        Style::Match => text.bold().red(),
+    }
+}
+
+/// Enable or disable colors for the rest of the process.
+///
+/// `--no-color` and a non-empty `NO_COLOR` environment variable always disable
+/// colors; otherwise the configured mode decides.
+pub fn apply_color_mode(mode: ColorMode, no_color_flag: bool) {
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let enabled = colors_enabled(
+        mode,
+        no_color_flag || no_color_env,
+        std::io::stdout().is_terminal(),
+    );
+    colored::control::set_override(enabled);
+}
+
+/// Decide whether to color output (see https://no-color.org)
+fn colors_enabled(mode: ColorMode, no_color: bool, is_terminal: bool) -> bool {
+    if no_color {
+        return false;
+    }
+    match mode {
+        ColorMode::Auto => is_terminal,
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_color_overrides_every_mode() {
+        for mode in [ColorMode::Auto, ColorMode::Always, ColorMode::Never] {
+            assert!(!colors_enabled(mode, true, true));
+        }
+    }
+
+    #[test]
+    fn test_color_modes() {
+        assert!(colors_enabled(ColorMode::Auto, false, true));
+        assert!(!colors_enabled(ColorMode::Auto, false, false));
+        assert!(colors_enabled(ColorMode::Always, false, false));
+        assert!(!colors_enabled(ColorMode::Never, false, true));
+    }
+}
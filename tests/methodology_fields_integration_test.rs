@@ -58,7 +58,7 @@ fn test_methodology_fields_storage() -> Result<()> {
 
     // Verify the structure exists (fields may be empty if no values provided)
     assert!(
-        use_case.methodology_fields.get("business").is_some(),
+        use_case.methodology_fields.contains_key("business"),
         "Business methodology entry should exist in methodology_fields HashMap"
     );
 
@@ -221,8 +221,8 @@ fn test_multi_methodology_storage() -> Result<()> {
     assert!(use_case.methodology_fields.contains_key("feature"));
 
     // Verify structure exists (even if empty when no field values provided)
-    assert!(use_case.methodology_fields.get("business").is_some());
-    assert!(use_case.methodology_fields.get("feature").is_some());
+    assert!(use_case.methodology_fields.contains_key("business"));
+    assert!(use_case.methodology_fields.contains_key("feature"));
 
     Ok(())
 }
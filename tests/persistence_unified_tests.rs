@@ -1,9 +1,12 @@
 //! Unified persistence layer tests
 //!
-//! This module tests both TOML and SQLite backends with identical test suites
+//! This module tests the TOML, SQLite and in-memory backends with identical test suites
 //! to ensure feature parity and correctness.
 
-use markdown_use_case_manager::core::{SqliteUseCaseRepository, UseCase, UseCaseRepository};
+use markdown_use_case_manager::core::{
+    InMemoryUseCaseRepository, RepositoryTransaction, SqliteUseCaseRepository, UseCase,
+    UseCaseRepository,
+};
 use serial_test::serial;
 use std::env;
 use tempfile::TempDir;
@@ -71,6 +74,8 @@ fn run_all_tests(repo: &dyn UseCaseRepository) {
     test_save_with_extra_fields(repo);
     test_load_all(repo);
     test_save_markdown(repo);
+    test_delete(repo);
+    test_commit_transaction(repo);
 }
 
 #[test]
@@ -87,6 +92,11 @@ fn test_sqlite_backend() {
     run_all_tests(&*repo);
 }
 
+#[test]
+fn test_in_memory_backend() {
+    run_all_tests(&InMemoryUseCaseRepository::new());
+}
+
 // ===== Individual Test Functions =====
 
 fn test_save_and_load(repo: &dyn UseCaseRepository) {
@@ -135,7 +145,6 @@ fn test_save_with_extra_fields(repo: &dyn UseCaseRepository) {
 }
 
 // Deleted tests (methods removed in PR #11):
-// - test_delete (delete method)
 // - test_exists (exists method)
 // - test_find_by_category (find_by_category method)
 // - test_find_by_priority (find_by_priority method)
@@ -190,5 +199,158 @@ fn test_save_markdown(repo: &dyn UseCaseRepository) {
 
     // Note: We can't easily verify markdown content without backend-specific code
     // This test mainly ensures the method doesn't panic
-    // Note: No cleanup since delete() was removed in PR #11
+}
+
+fn test_delete(repo: &dyn UseCaseRepository) {
+    let use_case = UseCase::new(
+        "UC-DEL-001".to_string(),
+        "Delete Test".to_string(),
+        "delete".to_string(),
+        "".to_string(),
+        "low".to_string(),
+    )
+    .unwrap();
+    repo.save(&use_case).unwrap();
+    assert!(repo.load_by_id(&use_case.id).unwrap().is_some());
+
+    repo.delete(&use_case.id).expect("Delete should succeed");
+    assert!(repo.load_by_id(&use_case.id).unwrap().is_none());
+
+    // Deleting a missing use case is not an error
+    repo.delete(&use_case.id)
+        .expect("Deleting a missing use case should succeed");
+}
+
+fn test_commit_transaction(repo: &dyn UseCaseRepository) {
+    let new_use_case = |id: &str, title: &str| {
+        UseCase::new(
+            id.to_string(),
+            title.to_string(),
+            "transaction".to_string(),
+            "".to_string(),
+            "medium".to_string(),
+        )
+        .unwrap()
+    };
+    let original = new_use_case("UC-TRX-001", "Original");
+    repo.save(&original).unwrap();
+
+    let mut transaction = RepositoryTransaction::new();
+    transaction.delete(original.id.as_str());
+    transaction.save(new_use_case("UC-TRX-002", "First Part"));
+    transaction.save(new_use_case("UC-TRX-003", "Second Part"));
+    repo.commit(transaction).expect("Commit should succeed");
+
+    assert!(repo.load_by_id("UC-TRX-001").unwrap().is_none());
+    let first = repo.load_by_id("UC-TRX-002").unwrap().unwrap();
+    assert_eq!(first.title, "First Part");
+    assert!(repo.load_by_id("UC-TRX-003").unwrap().is_some());
+}
+
+#[test]
+#[serial]
+fn test_toml_commit_rolls_back_when_a_rename_fails() {
+    let (_temp_dir, repo) = create_toml_repository();
+    let config = markdown_use_case_manager::config::Config::load().unwrap();
+    let dir = std::path::Path::new(&config.directories.data_dir).join("test");
+
+    let mut first = create_test_use_case();
+    repo.save(&first).unwrap();
+    let mut second = create_test_use_case();
+    second.id = "UC-TEST-002".to_string();
+    repo.save(&second).unwrap();
+
+    // A non-empty directory where the second original would be moved aside
+    // makes that rename fail after the first file was replaced
+    std::fs::create_dir_all(dir.join(".UC-TEST-002.toml.orig/blocker")).unwrap();
+
+    first.title = "Changed".to_string();
+    second.title = "Changed too".to_string();
+    let mut transaction = RepositoryTransaction::new();
+    transaction.save(first);
+    transaction.save(second);
+    let error = repo.commit(transaction).unwrap_err();
+
+    assert!(error.to_string().contains("rolled back"), "{}", error);
+    for id in ["UC-TEST-001", "UC-TEST-002"] {
+        let use_case = repo.load_by_id(id).unwrap().unwrap();
+        assert_eq!(use_case.title, "Test Use Case");
+    }
+    let leftovers: Vec<String> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".tmp") || name == ".UC-TEST-001.toml.orig")
+        .collect();
+    assert!(leftovers.is_empty(), "{:?}", leftovers);
+}
+
+#[test]
+#[serial]
+fn test_toml_save_is_canonical_and_keeps_comments() {
+    let (_temp_dir, repo) = create_toml_repository();
+    let config = markdown_use_case_manager::config::Config::load().unwrap();
+    let toml_path = std::path::Path::new(&config.directories.data_dir)
+        .join("test")
+        .join("UC-TEST-001.toml");
+
+    let use_case = create_test_use_case_with_extra();
+    repo.save(&use_case).unwrap();
+    let first = std::fs::read_to_string(&toml_path).unwrap();
+    repo.save(&use_case).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&toml_path).unwrap(),
+        first,
+        "Saving an unchanged use case should not change the file"
+    );
+
+    let edited = first.replace("title =", "# Agreed with the product team\ntitle =");
+    std::fs::write(&toml_path, edited).unwrap();
+    let mut use_case = repo.load_by_id("UC-TEST-001").unwrap().unwrap();
+    use_case.title = "Renamed Use Case".to_string();
+    repo.save(&use_case).unwrap();
+
+    let saved = std::fs::read_to_string(&toml_path).unwrap();
+    assert!(saved.contains("# Agreed with the product team\ntitle = \"Renamed Use Case\""));
+}
+
+#[test]
+#[serial]
+fn test_toml_load_skips_corrupt_files_and_repair_fixes_them() {
+    use markdown_use_case_manager::core::RepairOutcome;
+
+    let (_temp_dir, repo) = create_toml_repository();
+    let config = markdown_use_case_manager::config::Config::load().unwrap();
+    let data_dir = std::path::Path::new(&config.directories.data_dir);
+
+    repo.save(&create_test_use_case()).unwrap();
+    std::fs::write(data_dir.join("test").join("UC-TEST-002.toml"), "id = [").unwrap();
+    std::fs::write(
+        data_dir.join("test").join("UC-TEST-003.toml"),
+        "title = \"Partial\"\ncategory = \"test\"\n",
+    )
+    .unwrap();
+
+    let report = repo.load_all_with_report().unwrap();
+    assert_eq!(report.use_cases.len(), 1);
+    assert_eq!(report.failures.len(), 2);
+    assert_eq!(repo.load_all().unwrap().len(), 1);
+
+    let actions = repo.repair().unwrap();
+    assert_eq!(actions.len(), 2);
+    for action in &actions {
+        match &action.outcome {
+            RepairOutcome::Recovered { use_case_id } => assert_eq!(use_case_id, "UC-TEST-003"),
+            RepairOutcome::Quarantined { to, .. } => {
+                assert!(action.path.ends_with("UC-TEST-002.toml"));
+                assert!(to.ends_with(".quarantine/test/UC-TEST-002.toml"));
+                assert!(to.exists());
+            }
+        }
+    }
+
+    let report = repo.load_all_with_report().unwrap();
+    assert!(report.failures.is_empty());
+    assert_eq!(report.use_cases.len(), 2);
+    let recovered = repo.load_by_id("UC-TEST-003").unwrap().unwrap();
+    assert_eq!(recovered.title, "Partial");
 }
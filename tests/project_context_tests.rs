@@ -0,0 +1,167 @@
+/// Integration tests for opening projects through a ProjectContext
+///
+/// These tests never change the working directory, so they run without `#[serial]`.
+use anyhow::Result;
+use markdown_use_case_manager::config::{Config, ProjectContext, StorageBackend};
+use markdown_use_case_manager::core::{
+    BlockingRepository, InMemoryActorRepository, InMemoryUseCaseRepository, ProjectLock,
+    RepositoryFactory, UseCase, UseCaseCoordinator, UseCaseRepository,
+};
+use std::path::Path;
+use std::thread;
+use tempfile::TempDir;
+
+/// Create a project under `root` holding one use case with the given ID
+fn create_project(root: &Path, backend: StorageBackend, use_case_id: &str) -> Result<()> {
+    let mut config = Config::default();
+    config.storage.backend = backend;
+    config.save_in_dir(&root.to_string_lossy())?;
+
+    let context = ProjectContext::load(root)?;
+    let use_case = UseCase::new(
+        use_case_id.to_string(),
+        format!("Use case {}", use_case_id),
+        "General".to_string(),
+        String::new(),
+        "medium".to_string(),
+    )
+    .map_err(anyhow::Error::msg)?;
+    RepositoryFactory::create(&context.resolved_config())?.save(&use_case)?;
+    Ok(())
+}
+
+#[test]
+fn test_projects_open_concurrently_without_changing_cwd() -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let toml_project = TempDir::new()?;
+    let sqlite_project = TempDir::new()?;
+    create_project(toml_project.path(), StorageBackend::Toml, "UC-GEN-001")?;
+    create_project(sqlite_project.path(), StorageBackend::Sqlite, "UC-GEN-002")?;
+
+    let handles: Vec<_> = [
+        (toml_project.path().to_path_buf(), "UC-GEN-001"),
+        (sqlite_project.path().to_path_buf(), "UC-GEN-002"),
+    ]
+    .into_iter()
+    .map(|(root, expected_id)| {
+        thread::spawn(move || -> Result<()> {
+            let coordinator = UseCaseCoordinator::for_project(ProjectContext::load(root)?)?;
+            let ids: Vec<&str> = coordinator
+                .get_all_use_cases()
+                .iter()
+                .map(|uc| uc.id.as_str())
+                .collect();
+            assert_eq!(ids, vec![expected_id]);
+            Ok(())
+        })
+    })
+    .collect();
+
+    for handle in handles {
+        handle.join().expect("project thread panicked")?;
+    }
+
+    // The data stayed inside each project root
+    let toml_data = Path::new(&Config::default().directories.data_dir).join("general");
+    assert!(toml_project
+        .path()
+        .join(&toml_data)
+        .join("UC-GEN-001.toml")
+        .exists());
+    assert_eq!(std::env::current_dir()?, cwd);
+    Ok(())
+}
+
+#[test]
+fn test_in_memory_coordinator_keeps_data_out_of_the_project() -> Result<()> {
+    let project = TempDir::new()?;
+    let context = ProjectContext::new(project.path(), Config::default());
+    let repository = InMemoryUseCaseRepository::new();
+    let mut coordinator = UseCaseCoordinator::in_memory(
+        context,
+        repository.clone(),
+        &InMemoryActorRepository::new(),
+    )?;
+
+    let id = coordinator.create_use_case_with_views(
+        "Login".to_string(),
+        "Auth".to_string(),
+        None,
+        "feature:normal",
+    )?;
+
+    assert_eq!(repository.load_all()?[0].id, id);
+    assert!(repository
+        .markdown(&format!("auth/{}-feature-normal.md", id))
+        .is_some());
+    assert!(!project
+        .path()
+        .join(&Config::default().directories.data_dir)
+        .exists());
+    Ok(())
+}
+
+#[test]
+fn test_locked_writes_bump_the_project_revision() -> Result<()> {
+    let project = TempDir::new()?;
+    create_project(project.path(), StorageBackend::Toml, "UC-GEN-001")?;
+    let context = ProjectContext::load(project.path())?;
+    let before = ProjectLock::revision(project.path());
+
+    let repository =
+        BlockingRepository::new(RepositoryFactory::create_async(&context.resolved_config())?)
+            .with_project_lock(project.path());
+    let mut use_case = repository
+        .load_by_id("UC-GEN-001")?
+        .expect("use case saved");
+    assert_eq!(ProjectLock::revision(project.path()), before);
+
+    use_case.title = "Renamed".to_string();
+    repository.save(&use_case)?;
+    assert_eq!(ProjectLock::revision(project.path()), before + 1);
+
+    // The lock is released after each call
+    drop(ProjectLock::acquire(project.path())?);
+    Ok(())
+}
+
+#[test]
+fn test_commands_holding_the_project_lock_keep_both_changes() -> Result<()> {
+    let project = TempDir::new()?;
+    create_project(project.path(), StorageBackend::Toml, "UC-GEN-001")?;
+    let context = ProjectContext::load(project.path())?;
+
+    // Each thread stands in for a mucm command: lock, load, modify, save
+    let command = |tag: &'static str| {
+        let root = project.path().to_path_buf();
+        let config = context.resolved_config();
+        move || -> Result<()> {
+            let _lock = ProjectLock::acquire(&root)?;
+            let repository = BlockingRepository::new(RepositoryFactory::create_async(&config)?)
+                .with_project_lock(&root);
+            let mut use_case = repository
+                .load_by_id("UC-GEN-001")?
+                .expect("use case saved");
+            thread::sleep(std::time::Duration::from_millis(50));
+            use_case.description.push_str(tag);
+            repository.save(&use_case)?;
+            Ok(())
+        }
+    };
+    let first = thread::spawn(command("A"));
+    let second = thread::spawn(command("B"));
+    first.join().expect("first command")?;
+    second.join().expect("second command")?;
+
+    let repository = RepositoryFactory::create(&context.resolved_config())?;
+    let description = repository
+        .load_by_id("UC-GEN-001")?
+        .expect("use case saved")
+        .description;
+    assert!(
+        description == "AB" || description == "BA",
+        "a change was lost: {:?}",
+        description
+    );
+    Ok(())
+}